@@ -1,16 +1,21 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod notifications;
+
 use std::sync::Mutex;
 
+use notifications::{NotificationSettings, Notifier};
+use rusty_runways_core::diff::ObservationPatch;
+use rusty_runways_core::events::EventFilter;
 use rusty_runways_core::game::Observation;
 use rusty_runways_core::statistics::DailyStats;
-use rusty_runways_core::utils::airplanes::models::AirplaneModel;
+use rusty_runways_core::utils::airplanes::models::{AirplaneModel, CruiseRegime, FuelAmount};
 use rusty_runways_core::Game;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 use strum::IntoEnumIterator;
-use tauri::State;
+use tauri::{AppHandle, Emitter, Manager, State};
 
 #[derive(Serialize)]
 struct PlayerSnapshotDto {
@@ -25,6 +30,7 @@ struct PlayerSnapshotDto {
 #[derive(Default)]
 struct AppState {
     game: Mutex<Option<Game>>,
+    notifier: Mutex<Notifier>,
 }
 
 fn default_starting_cash() -> f32 {
@@ -52,6 +58,7 @@ fn new_game(state: State<AppState>, args: NewGameArgs) -> Result<(), String> {
     // schedule initial events as in Game::new already does
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     *guard = Some(game);
+    state.notifier.lock().map_err(|_| "state poisoned")?.reset();
     Ok(())
 }
 
@@ -60,6 +67,7 @@ fn load_game_cmd(state: State<AppState>, name: String) -> Result<(), String> {
     let game = Game::load_game(&name).map_err(|e| e.to_string())?;
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     *guard = Some(game);
+    state.notifier.lock().map_err(|_| "state poisoned")?.reset();
     Ok(())
 }
 
@@ -78,13 +86,62 @@ fn observe(state: State<AppState>) -> Result<Observation, String> {
 }
 
 #[tauri::command]
-fn advance(state: State<AppState>, hours: u64) -> Result<Observation, String> {
+fn observe_since(state: State<AppState>, version: u64) -> Result<ObservationPatch, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    Ok(game.observe_diff(version))
+}
+
+#[tauri::command]
+fn state_version(state: State<AppState>) -> Result<u64, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    Ok(game.state_version())
+}
+
+#[tauri::command]
+fn advance(app: AppHandle, state: State<AppState>, hours: u64) -> Result<Observation, String> {
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     let game = guard.as_mut().ok_or("no game running")?;
+    let events = game.subscribe(EventFilter::all());
     game.advance(hours);
+    let events: Vec<_> = events.try_iter().collect();
+    // push what happened so the UI can react without diffing observations
+    for event in &events {
+        app.emit("game-event", event).map_err(|e| e.to_string())?;
+    }
+    let due = state
+        .notifier
+        .lock()
+        .map_err(|_| "state poisoned")?
+        .collect(game, &events);
+    notifications::show(&app, due);
     Ok(game.observe())
 }
 
+#[tauri::command]
+fn notification_settings(state: State<AppState>) -> Result<NotificationSettings, String> {
+    let notifier = state.notifier.lock().map_err(|_| "state poisoned")?;
+    Ok(notifier.settings.clone())
+}
+
+#[tauri::command]
+fn set_notification_settings(
+    app: AppHandle,
+    state: State<AppState>,
+    settings: NotificationSettings,
+) -> Result<(), String> {
+    if let Some(path) = notifications::settings_path(&app) {
+        settings.save(&path)?;
+    }
+    state
+        .notifier
+        .lock()
+        .map_err(|_| "state poisoned")?
+        .settings = settings;
+    Ok(())
+}
+
 #[tauri::command]
 fn stats_cmd(state: State<AppState>) -> Result<Vec<DailyStats>, String> {
     let guard = state.game.lock().map_err(|_| "state poisoned")?;
@@ -107,10 +164,97 @@ fn player_snapshot(state: State<AppState>) -> Result<PlayerSnapshotDto, String>
 }
 
 #[tauri::command]
-fn depart_plane(state: State<AppState>, plane: usize, dest: usize) -> Result<(), String> {
+fn depart_plane(
+    state: State<AppState>,
+    plane: usize,
+    dest: usize,
+    speed: Option<String>,
+) -> Result<(), String> {
+    let regime = match speed {
+        Some(s) => s.parse::<CruiseRegime>()?,
+        None => CruiseRegime::Normal,
+    };
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     let game = guard.as_mut().ok_or("no game running")?;
-    game.depart_plane(plane, dest).map_err(|e| e.to_string())
+    game.depart_plane_with_speed(plane, dest, regime)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn reroute_plane(state: State<AppState>, plane: usize, dest: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.reroute_plane(plane, dest).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn emergency_land(state: State<AppState>, plane: usize) -> Result<usize, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.emergency_land(plane).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn repair_plane(state: State<AppState>, plane: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.repair_plane(plane).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn buy_spare_parts(state: State<AppState>, airport: usize, kits: u32) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.buy_spare_parts(airport, kits)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn ship_spare_parts(
+    state: State<AppState>,
+    kits: u32,
+    dest: usize,
+    plane: usize,
+) -> Result<usize, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.ship_spare_parts(kits, dest, plane)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn hire_pilot(state: State<AppState>, airport: usize) -> Result<usize, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.hire_pilot(airport).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn fire_pilot(state: State<AppState>, pilot: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.fire_pilot(pilot).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn assign_pilot(state: State<AppState>, pilot: usize, plane: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.assign_pilot(pilot, plane).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn hold_plane(state: State<AppState>, plane: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.hold_plane(plane).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn release_plane(state: State<AppState>, plane: usize) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.release_plane(plane).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -142,10 +286,19 @@ fn unload_all(state: State<AppState>, plane: usize) -> Result<(), String> {
 }
 
 #[tauri::command]
-fn refuel_plane(state: State<AppState>, plane: usize) -> Result<(), String> {
+fn refuel_plane(
+    state: State<AppState>,
+    plane: usize,
+    amount: Option<String>,
+) -> Result<(), String> {
+    let amount = match amount {
+        Some(s) => s.parse::<FuelAmount>()?,
+        None => FuelAmount::Full,
+    };
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     let game = guard.as_mut().ok_or("no game running")?;
-    game.refuel_plane(plane).map_err(|e| e.to_string())
+    game.refuel_plane_with(plane, amount)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -163,12 +316,36 @@ fn sell_plane_cmd(state: State<AppState>, plane: usize) -> Result<f32, String> {
     game.sell_plane(plane).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+fn appraise_plane_cmd(state: State<AppState>, plane: usize) -> Result<f32, String> {
+    let guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_ref().ok_or("no game running")?;
+    game.appraise_plane(plane).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn rename_plane_cmd(state: State<AppState>, plane: usize, name: String) -> Result<(), String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.rename_plane(plane, &name).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn buy_used_plane_cmd(state: State<AppState>, listing: usize) -> Result<usize, String> {
+    let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
+    let game = guard.as_mut().ok_or("no game running")?;
+    game.buy_used_plane(listing).map_err(|e| e.to_string())
+}
+
 #[derive(Serialize)]
 struct OrderDto {
     id: usize,
     destination_id: usize,
     value: f32,
     deadline: u64,
+    tier: String,
+    damage: f32,
+    rare: Option<String>,
     payload_kind: String,
     cargo_type: Option<String>,
     weight: Option<f32>,
@@ -178,8 +355,11 @@ struct OrderDto {
 #[derive(Serialize)]
 struct PlaneInfoDto {
     id: usize,
+    name: String,
     model: String,
     status: String,
+    on_hold: bool,
+    awaiting_repair: bool,
     x: f32,
     y: f32,
     fuel_current: f32,
@@ -188,6 +368,9 @@ struct PlaneInfoDto {
     payload_capacity: f32,
     passenger_current: u32,
     passenger_capacity: u32,
+    takeoff_weight: f32,
+    mtow: f32,
+    resale_value: f32,
     current_airport_id: Option<usize>,
     manifest: Vec<OrderDto>,
 }
@@ -207,7 +390,8 @@ fn plane_info(state: State<AppState>, plane_id: usize) -> Result<PlaneInfoDto, S
     let current_airport_id = game
         .airports()
         .iter()
-        .position(|(_, coord)| *coord == plane.location);
+        .find(|(_, coord)| *coord == plane.location)
+        .map(|(airport, _)| airport.id);
 
     let manifest = plane
         .manifest
@@ -217,6 +401,9 @@ fn plane_info(state: State<AppState>, plane_id: usize) -> Result<PlaneInfoDto, S
             destination_id: o.destination_id,
             value: o.value,
             deadline: o.deadline,
+            tier: o.tier.to_string(),
+            damage: o.damage,
+            rare: o.rare.map(|kind| kind.to_string()),
             payload_kind: o.payload.kind_label().to_string(),
             cargo_type: o.cargo_type().map(|c| format!("{:?}", c)),
             weight: o.cargo_weight(),
@@ -226,6 +413,9 @@ fn plane_info(state: State<AppState>, plane_id: usize) -> Result<PlaneInfoDto, S
 
     Ok(PlaneInfoDto {
         id: plane.id,
+        name: plane.name.clone(),
+        on_hold: plane.on_hold,
+        awaiting_repair: plane.awaiting_repair,
         model: format!("{:?}", plane.model),
         status: format!("{:?}", plane.status),
         x: plane.location.x,
@@ -234,8 +424,11 @@ fn plane_info(state: State<AppState>, plane_id: usize) -> Result<PlaneInfoDto, S
         fuel_capacity: plane.specs.fuel_capacity,
         payload_current: plane.current_payload,
         payload_capacity: plane.specs.payload_capacity,
-        passenger_current: plane.current_passengers,
+        passenger_current: plane.seated_passengers(),
         passenger_capacity: plane.specs.passenger_capacity,
+        takeoff_weight: plane.takeoff_weight(),
+        mtow: plane.specs.mtow,
+        resale_value: game.appraise_plane(plane.id).map_err(|e| e.to_string())?,
         current_airport_id,
         manifest,
     })
@@ -246,9 +439,9 @@ fn airport_orders(state: State<AppState>, airport_id: usize) -> Result<Vec<Order
     let guard = state.game.lock().map_err(|_| "state poisoned")?;
     let game = guard.as_ref().ok_or("no game running")?;
     let (airport, _) = game
-        .airports()
-        .iter()
-        .find(|(a, _)| a.id == airport_id)
+        .map
+        .airports
+        .get(airport_id)
         .ok_or_else(|| "airport not found".to_string())?;
     let orders = airport
         .orders
@@ -258,6 +451,9 @@ fn airport_orders(state: State<AppState>, airport_id: usize) -> Result<Vec<Order
             destination_id: o.destination_id,
             value: o.value,
             deadline: o.deadline,
+            tier: o.tier.to_string(),
+            damage: o.damage,
+            rare: o.rare.map(|kind| kind.to_string()),
             payload_kind: o.payload.kind_label().to_string(),
             cargo_type: o.cargo_type().map(|c| format!("{:?}", c)),
             weight: o.cargo_weight(),
@@ -348,11 +544,7 @@ fn plane_can_fly_to(
         .iter()
         .find(|p| p.id == plane_id)
         .ok_or("plane not found")?;
-    let (airport, coord) = game
-        .airports()
-        .iter()
-        .find(|(a, _)| a.id == dest_id)
-        .ok_or("airport not found")?;
+    let (airport, coord) = game.map.airports.get(dest_id).ok_or("airport not found")?;
     Ok(plane.can_fly_to(airport, coord).is_ok())
 }
 
@@ -375,11 +567,7 @@ fn plane_reachability(
         .iter()
         .find(|p| p.id == plane_id)
         .ok_or("plane not found")?;
-    let (airport, coord) = game
-        .airports()
-        .iter()
-        .find(|(a, _)| a.id == dest_id)
-        .ok_or("airport not found")?;
+    let (airport, coord) = game.map.airports.get(dest_id).ok_or("airport not found")?;
     match plane.can_fly_to(airport, coord) {
         Ok(_) => Ok(FeasibilityDto {
             ok: true,
@@ -399,6 +587,7 @@ fn start_from_config_yaml(state: State<AppState>, yaml: String) -> Result<(), St
     let game = rusty_runways_core::Game::from_config(cfg).map_err(|e| e.to_string())?;
     let mut guard = state.game.lock().map_err(|_| "state poisoned")?;
     *guard = Some(game);
+    state.notifier.lock().map_err(|_| "state poisoned")?.reset();
     Ok(())
 }
 
@@ -408,6 +597,11 @@ fn start_from_config_path(state: State<AppState>, path: String) -> Result<(), St
     start_from_config_yaml(state, text)
 }
 
+#[tauri::command]
+fn config_schema() -> serde_json::Value {
+    rusty_runways_core::config::world_config_schema()
+}
+
 #[tauri::command]
 fn list_saves() -> Result<Vec<String>, String> {
     let dir = Path::new("save_games");
@@ -430,14 +624,37 @@ fn list_saves() -> Result<Vec<String>, String> {
 
 fn main() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_notification::init())
         .manage(AppState::default())
+        .setup(|app| {
+            let settings = notifications::settings_path(app.handle())
+                .map(|path| NotificationSettings::load(&path))
+                .unwrap_or_default();
+            let state = app.state::<AppState>();
+            *state.notifier.lock().expect("state poisoned") = Notifier::new(settings);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             new_game,
             load_game_cmd,
             save_game_cmd,
             observe,
+            observe_since,
+            state_version,
             advance,
+            notification_settings,
+            set_notification_settings,
             depart_plane,
+            reroute_plane,
+            emergency_land,
+            repair_plane,
+            buy_spare_parts,
+            ship_spare_parts,
+            hire_pilot,
+            fire_pilot,
+            assign_pilot,
+            hold_plane,
+            release_plane,
             load_order,
             unload_order,
             unload_orders,
@@ -445,6 +662,9 @@ fn main() {
             refuel_plane,
             maintenance,
             sell_plane_cmd,
+            appraise_plane_cmd,
+            buy_used_plane_cmd,
+            rename_plane_cmd,
             plane_info,
             airport_orders,
             list_models,
@@ -453,6 +673,7 @@ fn main() {
             plane_reachability,
             start_from_config_yaml,
             start_from_config_path,
+            config_schema,
             list_saves,
             stats_cmd,
             player_snapshot,
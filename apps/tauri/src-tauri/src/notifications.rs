@@ -0,0 +1,159 @@
+//! Desktop notifications for events worth a look while the window is in the background.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use rusty_runways_core::events::GameEvent;
+use rusty_runways_core::tr;
+use rusty_runways_core::Game;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_notification::NotificationExt;
+
+/// Which notifications the player wants; saved next to the app's other config.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// A plane landed
+    pub arrivals: bool,
+    /// Cargo or passengers on board are close to their deadline
+    pub expiring_orders: bool,
+    /// Cash dropped below zero
+    pub negative_cash: bool,
+    /// How close to the deadline an order counts as expiring
+    pub expiring_within_hours: u64,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        NotificationSettings {
+            arrivals: true,
+            expiring_orders: true,
+            negative_cash: true,
+            expiring_within_hours: 6,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Read settings from `path`, falling back to the defaults if it is missing or invalid.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write settings to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| e.to_string())
+    }
+}
+
+/// Settings plus what was already announced, so each warning shows up once.
+#[derive(Debug, Default)]
+pub struct Notifier {
+    pub settings: NotificationSettings,
+    warned_orders: HashSet<usize>,
+    cash_negative: bool,
+}
+
+impl Notifier {
+    pub fn new(settings: NotificationSettings) -> Self {
+        Notifier {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Forget earlier warnings, e.g. after starting or loading another game.
+    pub fn reset(&mut self) {
+        self.warned_orders.clear();
+        self.cash_negative = false;
+    }
+
+    /// Title and body of every notification due after `events`.
+    pub fn collect(&mut self, game: &Game, events: &[GameEvent]) -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        if self.settings.arrivals {
+            for event in events {
+                if let GameEvent::Arrived { plane, airport, .. } = event {
+                    out.push((
+                        tr!("desktop-arrived-title"),
+                        tr!(
+                            "desktop-arrived-body",
+                            plane = plane_name(game, *plane),
+                            airport = airport_name(game, *airport),
+                        ),
+                    ));
+                }
+            }
+        }
+        if self.settings.expiring_orders {
+            for plane in &game.airplanes {
+                for order in &plane.manifest {
+                    if order.deadline <= self.settings.expiring_within_hours
+                        && self.warned_orders.insert(order.id)
+                    {
+                        out.push((
+                            tr!("desktop-expiring-title"),
+                            tr!(
+                                "desktop-expiring-body",
+                                order = order.id,
+                                plane = plane.name.as_str(),
+                                hours = order.deadline,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+        let negative = game.player.cash < 0.0;
+        if negative && !self.cash_negative && self.settings.negative_cash {
+            out.push((
+                tr!("desktop-negative-cash-title"),
+                tr!(
+                    "desktop-negative-cash-body",
+                    cash = format!("{:.0}", game.player.cash),
+                ),
+            ));
+        }
+        self.cash_negative = negative;
+        out
+    }
+}
+
+fn plane_name(game: &Game, id: usize) -> String {
+    game.airplanes
+        .iter()
+        .find(|p| p.id == id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn airport_name(game: &Game, id: usize) -> String {
+    game.map
+        .airport(id)
+        .map(|a| a.name.clone())
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Where [`NotificationSettings`] are kept.
+pub fn settings_path<R: Runtime>(app: &AppHandle<R>) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join("notifications.json"))
+}
+
+/// Show `notifications` as OS notifications; failures are ignored so a missing
+/// notification daemon never stops the game.
+pub fn show<R: Runtime>(app: &AppHandle<R>, notifications: Vec<(String, String)>) {
+    for (title, body) in notifications {
+        let _ = app.notification().builder().title(title).body(body).show();
+    }
+}
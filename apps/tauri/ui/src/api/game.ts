@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 import { isTauri } from '@/lib/tauri'
 
 // Compute a URL that works in both dev (vite, base "/") and
@@ -18,9 +19,13 @@ function wasmModulePath(): string {
 export type Observation = {
   time: number
   cash: number
-  airports: { id: number; name: string; x: number; y: number; fuel_price: number; runway_length: number; num_orders: number }[]
+  company_value?: number
+  airports: { id: number; name: string; code?: string | null; class?: 'hub' | 'regional' | 'strip'; x: number; y: number; fuel_price: number; fuel_stock?: number | null; has_hangar?: boolean; spare_parts?: number; runway_length: number; num_orders: number; country?: string | null; player_built?: boolean; has_concession?: boolean; staged_weight?: number }[]
   planes: {
     id: number
+    name?: string
+    on_hold?: boolean
+    awaiting_repair?: boolean
     model: string
     x: number
     y: number
@@ -31,10 +36,40 @@ export type Observation = {
     payload: {
       cargo_current: number
       cargo_capacity: number
+      cargo_orders?: number
+      cargo_slots?: number
       passenger_current: number
       passenger_capacity: number
+      takeoff_weight?: number
+      mtow?: number
     }
   }[]
+  used_market?: UsedListing[]
+  pilots?: Pilot[]
+}
+
+export type Pilot = {
+  id: number
+  name: string
+  airport_id: number
+  plane: number | null
+  duty_hours: number
+  off_duty_since: number
+  experience_hours?: number
+  type_hours?: Record<string, number>
+  route_flights?: Record<string, number>
+}
+
+export type UsedListing = {
+  id: number
+  airport_id: number
+  model: string
+  airframe_hours: number
+  hours_since_maintenance: number
+  needs_maintenance: boolean
+  discount: number
+  price: number
+  expires_at: number
 }
 
 export async function newGame(seed: string | undefined, airportCount: number, startingCash: number): Promise<void> {
@@ -70,7 +105,67 @@ export async function observe(): Promise<Observation> {
   }
 }
 
-export type DailyStats = { day: number; income: number; expenses: number; net_cash: number; fleet_size: number; total_deliveries: number }
+export type ObservationPatch = {
+  version: number
+  time: number
+  cash: number
+  company_value: number
+  planes: Observation['planes']
+  removed_planes: number[]
+  airports: Observation['airports']
+  used_market: UsedListing[] | null
+  pilots: Pilot[] | null
+  events: GameEvent[]
+}
+
+// What changed since state version `version` (0 for everything); pass the returned `version` next time.
+export async function observeSince(version: number): Promise<ObservationPatch> {
+  if (isTauri()) {
+    return await invoke<ObservationPatch>('observe_since', { version })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return (await wasm.observe_since(BigInt(version))) as ObservationPatch
+  }
+}
+
+// Increases whenever the observation would change; poll this to skip unchanged frames.
+export async function stateVersion(): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('state_version')
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return Number(await wasm.state_version())
+  }
+}
+
+// Returns a copy of `obs` with `patch` applied, mirroring Observation::apply in core.
+export function applyObservationPatch(obs: Observation, patch: ObservationPatch): Observation {
+  const planes = obs.planes.filter((p) => !patch.removed_planes.includes(p.id))
+  for (const plane of patch.planes) {
+    const i = planes.findIndex((p) => p.id === plane.id)
+    if (i >= 0) planes[i] = plane
+    else planes.push(plane)
+  }
+  const airports = [...obs.airports]
+  for (const airport of patch.airports) {
+    const i = airports.findIndex((a) => a.id === airport.id)
+    if (i >= 0) airports[i] = airport
+    else airports.push(airport)
+  }
+  return {
+    ...obs,
+    time: patch.time,
+    cash: patch.cash,
+    company_value: patch.company_value,
+    planes,
+    airports,
+    used_market: patch.used_market ?? obs.used_market,
+    pilots: patch.pilots ?? obs.pilots,
+  }
+}
+
+export type DailyStats = { day: number; income: number; expenses: number; net_cash: number; fleet_size: number; total_deliveries: number; fuel?: number; fees?: number; interest?: number; investing?: number; salaries?: number; crew_wages?: number; hangarage?: number; overhead?: number; taxes?: number; co2_tons?: number; total_co2_tons?: number; carbon_tax?: number; company_value?: number }
+export type MonthlyReport = { year: number; month: number; revenue: number; fuel: number; fees: number; payroll: number; overhead: number; taxes: number; interest: number; other: number; net: number; closing_cash: number }
 export async function stats(): Promise<DailyStats[]> {
   if (isTauri()) {
     return await invoke<DailyStats[]>('stats_cmd')
@@ -99,30 +194,78 @@ export async function playerSnapshot(): Promise<PlayerSnapshot> {
   }
 }
 
+export type GameEvent =
+  | { Departed: { time: number; plane: number; origin: number; destination: number } }
+  | { Arrived: { time: number; plane: number; airport: number } }
+  | { Delivered: { time: number; order: number; airport: number; payout: number } }
+  | { CashChanged: { time: number; delta: number; balance: number } }
+  | { OverdraftWarning: { time: number; balance: number; limit: number } }
+  | { LaneOffered: { time: number; deal: number; forwarder: number } }
+  | { AuctionResolved: { time: number; auction: number; won: boolean; winning_bid: number } }
+  | { RareCargo: { time: number; order: number; airport: number; kind: 'organ_transport' | 'concert_equipment' | 'satellite_parts' } }
+  | { AirportOpened: { time: number; airport: number; near: number } }
+  | { ConstructionComplete: { time: number; construction: number; airport: number } }
+  | { ConcessionIncome: { time: number; airport: number; amount: number } }
+  | { DailyReport: DailyStats }
+  | { MonthlyReport: MonthlyReport }
+// Events pushed by the desktop app while advancing; the web build has none. Returns an unsubscribe fn.
+export async function onGameEvent(handler: (event: GameEvent) => void): Promise<() => void> {
+  if (!isTauri()) return () => {}
+  return await listen<GameEvent>('game-event', (e) => handler(e.payload))
+}
+
+export type NotificationSettings = {
+  arrivals: boolean
+  expiring_orders: boolean
+  negative_cash: boolean
+  expiring_within_hours: number
+}
+// Which OS notifications the desktop app shows; the web build shows none.
+export async function notificationSettings(): Promise<NotificationSettings | null> {
+  if (!isTauri()) return null
+  return await invoke<NotificationSettings>('notification_settings')
+}
+
+export async function setNotificationSettings(settings: NotificationSettings): Promise<void> {
+  if (!isTauri()) return
+  await invoke('set_notification_settings', { settings })
+}
+
 export async function advance(hours = 1): Promise<Observation> {
   if (isTauri()) {
     return await invoke<Observation>('advance', { hours })
   } else {
     const wasm = await import(/* @vite-ignore */ wasmModulePath())
-    return (await wasm.advance(hours)) as Observation
+    // long advances yield between simulated days so the page keeps repainting
+    return (await wasm.advance_async(BigInt(hours))) as Observation
   }
 }
 
-export async function departPlane(plane: number, dest: number): Promise<void> {
+export async function departPlane(plane: number, dest: number, speed?: 'econ' | 'normal' | 'dash'): Promise<void> {
   if (isTauri()) {
-    await invoke('depart_plane', { plane, dest })
+    await invoke('depart_plane', { plane, dest, speed })
   } else {
     const wasm = await import(/* @vite-ignore */ wasmModulePath())
-    await wasm.depart_plane(plane, dest)
+    await wasm.depart_plane(plane, dest, speed)
   }
 }
 
-export async function refuelPlane(plane: number): Promise<void> {
+export async function reroutePlane(plane: number, dest: number): Promise<void> {
   if (isTauri()) {
-    await invoke('refuel_plane', { plane })
+    await invoke('reroute_plane', { plane, dest })
   } else {
     const wasm = await import(/* @vite-ignore */ wasmModulePath())
-    await wasm.refuel_plane(plane)
+    await wasm.reroute_plane(plane, dest)
+  }
+}
+
+// `amount` is liters like `'500'` or a target level like `'80%'`; omit it to fill the tank.
+export async function refuelPlane(plane: number, amount?: string): Promise<void> {
+  if (isTauri()) {
+    await invoke('refuel_plane', { plane, amount })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.refuel_plane(plane, amount)
   }
 }
 
@@ -135,6 +278,114 @@ export async function sellPlane(plane: number): Promise<number> {
   }
 }
 
+export async function appraisePlane(plane: number): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('appraise_plane_cmd', { plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return await wasm.appraise_plane(plane)
+  }
+}
+
+export async function renamePlane(plane: number, name: string): Promise<void> {
+  if (isTauri()) {
+    await invoke('rename_plane_cmd', { plane, name })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.rename_plane(plane, name)
+  }
+}
+
+export async function emergencyLand(plane: number): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('emergency_land', { plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return await wasm.emergency_land(plane)
+  }
+}
+
+export async function repairPlane(plane: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('repair_plane', { plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.repair_plane(plane)
+  }
+}
+
+export async function buySpareParts(airport: number, kits: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('buy_spare_parts', { airport, kits })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.buy_spare_parts(airport, kits)
+  }
+}
+
+export async function shipSpareParts(kits: number, dest: number, plane: number): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('ship_spare_parts', { kits, dest, plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return await wasm.ship_spare_parts(kits, dest, plane)
+  }
+}
+
+export async function hirePilot(airport: number): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('hire_pilot', { airport })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return await wasm.hire_pilot(airport)
+  }
+}
+
+export async function firePilot(pilot: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('fire_pilot', { pilot })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.fire_pilot(pilot)
+  }
+}
+
+export async function assignPilot(pilot: number, plane: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('assign_pilot', { pilot, plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.assign_pilot(pilot, plane)
+  }
+}
+
+export async function holdPlane(plane: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('hold_plane', { plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.hold_plane(plane)
+  }
+}
+
+export async function releasePlane(plane: number): Promise<void> {
+  if (isTauri()) {
+    await invoke('release_plane', { plane })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    await wasm.release_plane(plane)
+  }
+}
+
+export async function buyUsedPlane(listing: number): Promise<number> {
+  if (isTauri()) {
+    return await invoke<number>('buy_used_plane_cmd', { listing })
+  } else {
+    const wasm = await import(/* @vite-ignore */ wasmModulePath())
+    return await wasm.buy_used_plane(listing)
+  }
+}
+
 export async function maintenance(plane: number): Promise<void> {
   if (isTauri()) {
     await invoke('maintenance', { plane })
@@ -182,8 +433,11 @@ export async function unloadOrders(orderIds: number[], plane: number): Promise<v
 
 export type PlaneInfo = {
   id: number
+  name: string
   model: string
   status: string
+  on_hold: boolean
+  awaiting_repair: boolean
   x: number
   y: number
   fuel_current: number
@@ -192,6 +446,9 @@ export type PlaneInfo = {
   payload_capacity: number
   passenger_current: number
   passenger_capacity: number
+  takeoff_weight: number
+  mtow: number
+  resale_value: number
   current_airport_id: number | null
   manifest: OrderDto[]
 }
@@ -201,6 +458,9 @@ export type OrderDto = {
   destination_id: number
   value: number
   deadline: number
+  tier?: string
+  damage?: number
+  rare?: string | null
   payload_kind: string
   cargo_type?: string
   weight?: number
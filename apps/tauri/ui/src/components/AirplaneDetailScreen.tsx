@@ -19,10 +19,12 @@ import {
   Minus,
   MapPin,
   CircleDollarSign,
+  Pencil,
   Play,
-  Pause
+  Pause,
+  Anchor
 } from "lucide-react";
-import { airportOrders as apiAirportOrders, planeInfo as apiPlaneInfo, departPlane as apiDepart, loadOrder as apiLoad, unloadOrder as apiUnload, unloadAll as apiUnloadAll, unloadOrders as apiUnloadOrders, refuelPlane as apiRefuel, maintenance as apiMaint, canFly as apiCanFly, reachability as apiReach, sellPlane as apiSell, advance as apiAdvance } from "@/api/game";
+import { airportOrders as apiAirportOrders, planeInfo as apiPlaneInfo, departPlane as apiDepart, reroutePlane as apiReroute, loadOrder as apiLoad, unloadOrder as apiUnload, unloadAll as apiUnloadAll, unloadOrders as apiUnloadOrders, refuelPlane as apiRefuel, maintenance as apiMaint, canFly as apiCanFly, reachability as apiReach, sellPlane as apiSell, renamePlane as apiRename, holdPlane as apiHold, emergencyLand as apiEmergency, releasePlane as apiRelease, repairPlane as apiRepair, advance as apiAdvance } from "@/api/game";
 
 type PayloadKind = 'cargo' | 'passengers';
 
@@ -34,6 +36,7 @@ interface Order {
   passengerCount?: number;
   destination: string;
   deadline: string;
+  tier?: string;
   value: number;
 }
 
@@ -73,15 +76,21 @@ export const AirplaneDetailScreen = ({
   // Live data
   const [airplane, setAirplane] = useState({
     id: airplaneId,
+    name: "",
     model: "",
     location: "",
     status: "parked",
+    onHold: false,
+    awaitingRepair: false,
     fuel: 0,
     maxFuel: 100,
     cargoCapacity: 0,
     currentCargo: 0,
     passengerCapacity: 0,
     currentPassengers: 0,
+    takeoffWeight: 0,
+    mtow: 0,
+    resaleValue: 0,
     condition: 100,
     loadedOrders: [] as Order[],
   });
@@ -166,15 +175,21 @@ export const AirplaneDetailScreen = ({
       setAirplane(prev => ({
         ...prev,
         id: String(info.id),
+        name: info.name,
         model: info.model,
         location: info.current_airport_id != null ? String(info.current_airport_id) : "",
         status: info.status,
+        onHold: info.on_hold,
+        awaitingRepair: info.awaiting_repair,
         fuel: fuelPct,
         maxFuel: 100,
         cargoCapacity: info.payload_capacity,
         currentCargo: info.payload_current,
         passengerCapacity: info.passenger_capacity,
         currentPassengers: info.passenger_current,
+        takeoffWeight: info.takeoff_weight,
+        mtow: info.mtow,
+        resaleValue: info.resale_value,
         loadedOrders: info.manifest.map(o => ({
           id: String(o.id),
           payloadKind: (o.payload_kind || 'cargo') as PayloadKind,
@@ -183,6 +198,7 @@ export const AirplaneDetailScreen = ({
           passengerCount: o.passenger_count ?? undefined,
           destination: String(o.destination_id),
           deadline: String(o.deadline),
+          tier: o.tier,
           value: o.value,
         }))
       }));
@@ -197,6 +213,7 @@ export const AirplaneDetailScreen = ({
           passengerCount: o.passenger_count ?? undefined,
           destination: String(o.destination_id),
           deadline: String(o.deadline),
+          tier: o.tier,
           value: o.value,
         })));
       } else {
@@ -263,11 +280,33 @@ export const AirplaneDetailScreen = ({
     await refresh();
   }
 
+  async function handleRepair() {
+    try {
+      setError(null);
+      await apiRepair(parseInt(airplane.id, 10));
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
   async function handleRefuel() {
     await apiRefuel(parseInt(airplane.id, 10));
     await refresh();
   }
 
+  async function handlePartialRefuel() {
+    const amount = window.prompt("Liters to pump (e.g. 500) or target level (e.g. 80%)");
+    if (amount == null || amount.trim() === "") return;
+    try {
+      setError(null);
+      await apiRefuel(parseInt(airplane.id, 10), amount.trim());
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
   async function handleSell() {
     try {
       setError(null);
@@ -280,6 +319,33 @@ export const AirplaneDetailScreen = ({
     }
   }
 
+  async function handleRename() {
+    const name = window.prompt("New name or registration", airplane.name);
+    if (name == null) return;
+    try {
+      setError(null);
+      await apiRename(parseInt(airplane.id, 10), name);
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
+  async function handleToggleHold() {
+    try {
+      setError(null);
+      const id = parseInt(airplane.id, 10);
+      if (airplane.onHold) {
+        await apiRelease(id);
+      } else {
+        await apiHold(id);
+      }
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
   async function handleDispatch() {
     if (!selectedDestination) return;
     if (airplane.status.toLowerCase() !== 'parked') {
@@ -290,6 +356,27 @@ export const AirplaneDetailScreen = ({
     await refresh();
   }
 
+  async function handleReroute() {
+    if (!selectedDestination) return;
+    try {
+      setError(null);
+      await apiReroute(parseInt(airplane.id, 10), parseInt(selectedDestination, 10));
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
+  async function handleEmergency() {
+    try {
+      setError(null);
+      await apiEmergency(parseInt(airplane.id, 10));
+      await refresh();
+    } catch (e: unknown) {
+      setError(e instanceof Error ? e.message : String(e));
+    }
+  }
+
   async function handleUnload(orderId: string) {
     await apiUnload(parseInt(orderId, 10), parseInt(airplane.id, 10));
     await refresh();
@@ -352,8 +439,8 @@ export const AirplaneDetailScreen = ({
                 <Plane className="w-5 h-5 text-aviation-blue" />
               </div>
               <div>
-                <h1 className="text-2xl font-bold text-foreground">{airplane.model}</h1>
-                <p className="text-muted-foreground">ID: {airplane.id} • Location: {airplane.location}</p>
+                <h1 className="text-2xl font-bold text-foreground">{airplane.name || airplane.model}</h1>
+                <p className="text-muted-foreground">{airplane.model} • ID: {airplane.id} • Location: {airplane.location}{airplane.onHold ? ' • On hold' : ''}</p>
               </div>
             </div>
           </div>
@@ -363,20 +450,42 @@ export const AirplaneDetailScreen = ({
               <Wrench className="w-4 h-4 mr-1" />
               Maintenance
             </Button>
+            {airplane.awaitingRepair && (
+              <Button variant="destructive" onClick={handleRepair}>
+                <Wrench className="w-4 h-4 mr-1" />
+                Repair
+              </Button>
+            )}
             <Button variant="runway" onClick={handleRefuel}>
               <Fuel className="w-4 h-4 mr-1" />
               Refuel
             </Button>
+            <Button variant="control" onClick={handlePartialRefuel}>
+              <Fuel className="w-4 h-4 mr-1" />
+              Partial
+            </Button>
             <Button variant="runway" size="sm" onClick={handlePlay}><Play className="w-3 h-3 mr-1" />Auto</Button>
             <Button variant="control" size="sm" onClick={handlePause}><Pause className="w-3 h-3 mr-1" />Pause</Button>
             <Button variant="control" size="sm" onClick={handleAdvanceTime}>+1h</Button>
+            <Button variant="control" onClick={handleRename}>
+              <Pencil className="w-4 h-4 mr-1" />
+              Rename
+            </Button>
+            <Button
+              variant="control"
+              onClick={handleToggleHold}
+              disabled={!airplane.onHold && airplane.status.includes('InTransit')}
+            >
+              <Anchor className="w-4 h-4 mr-1" />
+              {airplane.onHold ? 'Release' : 'Hold'}
+            </Button>
             <Button
               variant="destructive"
               onClick={handleSell}
               disabled={airplane.loadedOrders.length > 0 || airplane.status.toLowerCase() !== 'parked'}
             >
               <CircleDollarSign className="w-4 h-4 mr-1" />
-              Sell
+              Sell (${Math.round(airplane.resaleValue).toLocaleString()})
             </Button>
           </div>
         </div>
@@ -445,6 +554,18 @@ export const AirplaneDetailScreen = ({
                     </div>
                   </div>
 
+                  <div className="space-y-2">
+                    <div className="flex justify-between">
+                      <span className="text-muted-foreground">Takeoff Weight</span>
+                      <span className={airplane.takeoffWeight > airplane.mtow ? "text-destructive" : undefined}>
+                        {airplane.mtow > 0 ? Math.round((airplane.takeoffWeight / airplane.mtow) * 100) : 0}%
+                      </span>
+                    </div>
+                    <div className="text-xs text-muted-foreground">
+                      {Math.round(airplane.takeoffWeight).toLocaleString()} / {airplane.mtow.toLocaleString()} kg MTOW
+                    </div>
+                  </div>
+
                   <div className="space-y-2">
                     <div className="flex justify-between">
                       <span className="text-muted-foreground">Condition</span>
@@ -505,13 +626,28 @@ export const AirplaneDetailScreen = ({
                 <Button 
                   className="w-full" 
                   variant="runway"
-                  disabled={!selectedDestination || airplane.status.toLowerCase() !== 'parked'}
+                  disabled={!selectedDestination || airplane.status.toLowerCase() !== 'parked' || airplane.onHold}
                   onClick={handleDispatch}
                 >
                   <Send className="w-4 h-4 mr-2" />
                   Dispatch to {selectedDestination}
                 </Button>
-                {airplane.status.toLowerCase() !== 'parked' && (
+                {airplane.status.includes('InTransit') ? (
+                  <>
+                    <Button
+                      className="w-full"
+                      variant="control"
+                      disabled={!selectedDestination}
+                      onClick={handleReroute}
+                    >
+                      <Send className="w-4 h-4 mr-2" />
+                      Reroute to {selectedDestination}
+                    </Button>
+                    <Button className="w-full" variant="destructive" onClick={handleEmergency}>
+                      Emergency landing
+                    </Button>
+                  </>
+                ) : airplane.status.toLowerCase() !== 'parked' && (
                   <div className="text-xs text-red-400">Plane must be Parked to depart. Complete loading/unloading/refueling first.</div>
                 )}
               </CardContent>
@@ -572,7 +708,7 @@ export const AirplaneDetailScreen = ({
                                     <MapPin className="w-3 h-3" />
                                     {order.destination}
                                   </span>
-                                  <span>Due: {order.deadline}</span>
+                                  <span>Due: {order.deadline}{order.tier ? ` (${order.tier})` : ''}</span>
                                 </div>
                               </div>
                               <div className="text-right space-y-2">
@@ -667,7 +803,7 @@ export const AirplaneDetailScreen = ({
                                     <MapPin className="w-3 h-3" />
                                     {order.destination}
                                   </span>
-                                  <span>Due: {order.deadline}</span>
+                                  <span>Due: {order.deadline}{order.tier ? ` (${order.tier})` : ''}</span>
                                 </div>
                               </div>
                               <div className="text-right space-y-2">
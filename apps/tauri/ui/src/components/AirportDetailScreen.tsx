@@ -30,11 +30,13 @@ interface Order {
   passengerCount?: number;
   destination: string;
   deadline: string;
+  tier?: string;
   value: number;
 }
 
 interface Airplane {
   id: string;
+  name: string;
   model: string;
   status: string;
   fuel: number;
@@ -45,7 +47,7 @@ interface AirportDetailScreenProps {
   airportId: string;
   onBack: () => void;
   onAirplaneClick: (airplaneId: string) => void;
-  airportsData: { id: number; name: string; x: number; y: number; fuel_price: number; runway_length: number; num_orders: number }[];
+  airportsData: { id: number; name: string; x: number; y: number; fuel_price: number; has_hangar?: boolean; runway_length: number; num_orders: number }[];
   planesData: { id: number; model: string; x: number; y: number; status: string }[];
 }
 
@@ -78,7 +80,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
     const ay = airportObj.y;
     return planesData
       .filter(p => !p.status.includes('InTransit') && p.x === ax && p.y === ay)
-      .map(p => ({ id: String(p.id), model: p.model, status: p.status, fuel: 0, cargoLoad: 0 }));
+      .map(p => ({ id: String(p.id), name: p.name ?? String(p.id), model: p.model, status: p.status, fuel: 0, cargoLoad: 0 }));
   }, [airportObj, planesData]);
 
   const [planeInfoMap, setPlaneInfoMap] = useState<
@@ -126,6 +128,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
         passengerCount: o.passenger_count ?? undefined,
         destination: String(o.destination_id),
         deadline: String(o.deadline),
+        tier: o.tier,
         value: o.value,
       })));
     }
@@ -156,6 +159,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
           passengerCount: o.passenger_count ?? undefined,
           destination: String(o.destination_id),
           deadline: String(o.deadline),
+          tier: o.tier,
           value: o.value,
         })));
         // compute canFly for unique destinations
@@ -185,6 +189,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
       passengerCount: o.passenger_count ?? undefined,
       destination: String(o.destination_id),
       deadline: String(o.deadline),
+      tier: o.tier,
       value: o.value,
     })));
     if (selectedPlaneId) {
@@ -205,6 +210,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
           passengerCount: o.passenger_count ?? undefined,
           destination: String(o.destination_id),
           deadline: String(o.deadline),
+          tier: o.tier,
           value: o.value,
         })));
       } catch (_) { void 0 }
@@ -235,6 +241,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
     code: String(airportObj?.id ?? airportId),
     location: "",
     fuelPrice: airportObj?.fuel_price ?? 0,
+    hasHangar: airportObj?.has_hangar ?? true,
     orderCount: orders.length,
     aircraftCount: airplanesAtAirport.length
   };
@@ -320,6 +327,9 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
               <Package className="w-3 h-3 mr-1" />
               {airport.orderCount} Orders
             </Badge>
+            <Badge variant="outline" className="bg-aviation-radar/10 border-aviation-radar/30">
+              {airport.hasHangar ? 'Hangar' : 'No hangar'}
+            </Badge>
             <Badge variant="outline" className="bg-aviation-radar/10 border-aviation-radar/30">
               <Plane className="w-3 h-3 mr-1" />
               {airport.aircraftCount} Aircraft
@@ -351,7 +361,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                       >
                         <div className="space-y-2">
                           <div className="flex items-center justify-between">
-                            <div className="font-semibold">{airplane.id}</div>
+                            <div className="font-semibold">{airplane.name} <span className="text-muted-foreground">#{airplane.id}</span></div>
                             <Badge variant="outline" className={getStatusColor(airplane.status)}>
                               {airplane.status}
                             </Badge>
@@ -486,6 +496,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                           passengerCount: o.passenger_count ?? undefined,
                           destination: String(o.destination_id),
                           deadline: String(o.deadline),
+                          tier: o.tier,
                           value: o.value,
                         })));
                         setSelectedManifest({});
@@ -529,6 +540,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                               passengerCount: o.passenger_count ?? undefined,
                               destination: String(o.destination_id),
                               deadline: String(o.deadline),
+                              tier: o.tier,
                               value: o.value,
                             })));
                             if (airportObj) {
@@ -541,6 +553,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                                 passengerCount: o.passenger_count ?? undefined,
                                 destination: String(o.destination_id),
                                 deadline: String(o.deadline),
+                                tier: o.tier,
                                 value: o.value,
                               })));
                             }
@@ -644,6 +657,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                           passengerCount: o.passenger_count ?? undefined,
                           destination: String(o.destination_id),
                           deadline: String(o.deadline),
+                          tier: o.tier,
                           value: o.value,
                         })));
                       }}
@@ -702,7 +716,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                               <div className="flex items-center gap-1">
                                 <Clock className="w-3 h-3 text-muted-foreground" />
                                 <span className="text-muted-foreground">Due:</span>
-                                <span className="font-medium">{order.deadline}</span>
+                                <span className="font-medium">{order.deadline}{order.tier ? ` (${order.tier})` : ''}</span>
                               </div>
                             </div>
                           </div>
@@ -745,6 +759,7 @@ export const AirportDetailScreen = ({ airportId, onBack, onAirplaneClick, airpor
                                     passengerCount: o.passenger_count ?? undefined,
                                     destination: String(o.destination_id),
                                     deadline: String(o.deadline),
+                                    tier: o.tier,
                                     value: o.value,
                                   })));
                                 }}
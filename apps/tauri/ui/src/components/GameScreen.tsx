@@ -2,6 +2,7 @@ import { useEffect, useRef, useState } from "react";
 import { Button } from "@/components/ui/button";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
+import { Switch } from "@/components/ui/switch";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import { WorldMap } from "@/components/WorldMap";
 import { GameLogger } from "@/components/GameLogger";
@@ -32,8 +33,8 @@ import {
   ChartLegendContent,
 } from "@/components/ui/chart";
 import { BarChart, Bar, CartesianGrid, XAxis, YAxis, LineChart, Line } from "recharts";
-import { observe, advance as apiAdvance, saveGame as apiSave, listSaves as apiListSaves, loadGame as apiLoadGame, stats as apiStats, playerSnapshot as apiPlayerSnapshot } from "@/api/game";
-import type { Observation } from "@/api/game";
+import { observe, advance as apiAdvance, saveGame as apiSave, listSaves as apiListSaves, loadGame as apiLoadGame, stats as apiStats, playerSnapshot as apiPlayerSnapshot, notificationSettings as apiNotificationSettings, setNotificationSettings as apiSetNotificationSettings } from "@/api/game";
+import type { NotificationSettings, Observation } from "@/api/game";
 
 interface GameScreenProps {
   onMainMenu: () => void;
@@ -82,6 +83,7 @@ export const GameScreen = ({ onMainMenu }: GameScreenProps) => {
   const [loadOpen, setLoadOpen] = useState(false);
   const [saveName, setSaveName] = useState("");
   const [availableSaves, setAvailableSaves] = useState<string[]>([]);
+  const [notifySettings, setNotifySettings] = useState<NotificationSettings | null>(null);
   
   const [logs, setLogs] = useState<LogEntry[]>([]);
   const [dailyStats, setDailyStats] = useState<{ day: number; income: number; expenses: number; net_cash: number; fleet_size: number; total_deliveries: number }[]>([]);
@@ -304,7 +306,11 @@ export const GameScreen = ({ onMainMenu }: GameScreenProps) => {
               <FolderOpen className="w-4 h-4 mr-1" />
               Load
             </Button>
-            <Button variant="control" size="sm">
+            <Button variant="control" size="sm" onClick={async () => {
+              const settings = await apiNotificationSettings();
+              if (settings) setNotifySettings(settings);
+              else addLog('info', 'Desktop notifications are only available in the desktop app');
+            }}>
               <Settings className="w-4 h-4" />
             </Button>
             <Button variant="warning" size="sm" onClick={onMainMenu}>
@@ -725,6 +731,41 @@ export const GameScreen = ({ onMainMenu }: GameScreenProps) => {
         </div>
       )}
 
+      {notifySettings && (
+        <div className="fixed inset-0 bg-black/50 flex items-center justify-center">
+          <div className="bg-card p-4 rounded border border-aviation-blue/20 w-96">
+            <div className="text-lg font-semibold mb-2">Notifications</div>
+            {([
+              ['arrivals', 'Plane landed'],
+              ['expiring_orders', 'Order about to expire'],
+              ['negative_cash', 'Cash below zero'],
+            ] as const).map(([key, label]) => (
+              <div key={key} className="flex items-center justify-between py-1">
+                <span className="text-sm">{label}</span>
+                <Switch
+                  checked={notifySettings[key]}
+                  onCheckedChange={checked => setNotifySettings({ ...notifySettings, [key]: checked })}
+                />
+              </div>
+            ))}
+            <div className="flex items-center justify-between py-1 mb-3">
+              <span className="text-sm">Warn hours before deadline</span>
+              <input
+                type="number"
+                min={1}
+                className="w-20 bg-secondary/50 border border-aviation-blue/20 rounded px-2 py-1"
+                value={notifySettings.expiring_within_hours}
+                onChange={e => setNotifySettings({ ...notifySettings, expiring_within_hours: Math.max(1, Number(e.target.value) || 1) })}
+              />
+            </div>
+            <div className="flex justify-end gap-2">
+              <Button variant="ghost" size="sm" onClick={() => setNotifySettings(null)}>Cancel</Button>
+              <Button variant="runway" size="sm" onClick={async () => { await apiSetNotificationSettings(notifySettings); addLog('success', 'Notification settings saved'); setNotifySettings(null); }}>Save</Button>
+            </div>
+          </div>
+        </div>
+      )}
+
     </div>
   );
 };
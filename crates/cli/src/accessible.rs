@@ -0,0 +1,237 @@
+//! Screen-reader-friendly listings.
+//!
+//! Each line stands on its own and reads as a short sentence, e.g.
+//! `Plane 2: parked at AAB, fuel 60 percent, cargo 0 of 500 kilograms`, instead of the
+//! pipe-separated rows and aligned tables printed by default.
+
+use rusty_runways_core::Game;
+use rusty_runways_core::tr;
+use rusty_runways_core::utils::airplanes::airplane::Airplane;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::airport::Airport;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::order::{Order, OrderPayload};
+
+fn airport_name(game: &Game, id: usize) -> String {
+    game.map
+        .airport(id)
+        .map(|a| a.name.clone())
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn energy(plane: &Airplane) -> String {
+    let percent = if plane.specs.fuel_capacity > 0.0 {
+        (plane.current_fuel / plane.specs.fuel_capacity * 100.0).round()
+    } else {
+        0.0
+    };
+    if plane.specs.is_electric() {
+        tr!("cli-a11y-battery", percent = percent)
+    } else {
+        tr!("cli-a11y-fuel", percent = percent)
+    }
+}
+
+fn status(status: &AirplaneStatus) -> String {
+    match status {
+        AirplaneStatus::Parked => tr!("cli-a11y-status-parked"),
+        AirplaneStatus::Refueling => tr!("cli-a11y-status-refueling"),
+        AirplaneStatus::Charging => tr!("cli-a11y-status-charging"),
+        AirplaneStatus::Maintenance => tr!("cli-a11y-status-maintenance"),
+        AirplaneStatus::Loading => tr!("cli-a11y-status-loading"),
+        AirplaneStatus::Unloading => tr!("cli-a11y-status-unloading"),
+        AirplaneStatus::Broken => tr!("cli-a11y-status-broken"),
+        AirplaneStatus::InTransit { .. } => tr!("cli-a11y-status-flying"),
+    }
+}
+
+/// One sentence describing `plane`.
+pub fn plane_line(game: &Game, plane: &Airplane) -> String {
+    let cargo = format!("{:.0}", plane.current_payload);
+    let capacity = format!("{:.0}", plane.specs.payload_capacity);
+    let mut line = match plane.status {
+        AirplaneStatus::InTransit {
+            hours_remaining,
+            destination,
+            ..
+        } => tr!(
+            "cli-a11y-plane-flying",
+            id = plane.id,
+            airport = airport_name(game, destination),
+            hours = hours_remaining,
+            energy = energy(plane),
+            cargo = cargo,
+            capacity = capacity,
+        ),
+        _ => {
+            let airport = game
+                .map
+                .airports
+                .iter()
+                .find(|(_, coord)| *coord == plane.location)
+                .map(|(a, _)| a.name.clone())
+                .unwrap_or_default();
+            tr!(
+                "cli-a11y-plane-on-ground",
+                id = plane.id,
+                status = status(&plane.status),
+                airport = airport,
+                energy = energy(plane),
+                cargo = cargo,
+                capacity = capacity,
+            )
+        }
+    };
+    if plane.on_hold {
+        line.push_str(&tr!("cli-a11y-on-hold"));
+    }
+    line
+}
+
+/// One sentence describing `order`.
+pub fn order_line(game: &Game, order: &Order) -> String {
+    let payload = match &order.payload {
+        OrderPayload::Cargo { cargo_type, weight } => tr!(
+            "cli-a11y-cargo",
+            weight = format!("{:.0}", weight),
+            cargo = format!("{:?}", cargo_type),
+        ),
+        OrderPayload::Passengers { count } => tr!("cli-a11y-passengers", count = *count),
+    };
+    tr!(
+        "cli-a11y-order",
+        id = order.id,
+        payload = payload,
+        airport = airport_name(game, order.destination_id),
+        tier = order.tier.to_string(),
+        value = format!("{:.0}", order.value),
+        hours = order.deadline,
+    )
+}
+
+fn airport_lines(game: &Game, airport: &Airport, with_orders: bool) -> Vec<String> {
+    let mut lines = vec![tr!(
+        "cli-a11y-airport",
+        id = airport.id,
+        name = airport.name.as_str(),
+        runway = format!("{:.0}", airport.runway_length),
+        fuel = format!("{:.2}", airport.fuel_price),
+        landing = format!("{:.2}", airport.landing_fee),
+        orders = airport.orders.len(),
+    )];
+    if with_orders {
+        lines.extend(airport.orders.iter().map(|o| order_line(game, o)));
+    }
+    lines
+}
+
+/// Every plane in the fleet.
+pub fn planes(game: &Game) -> Vec<String> {
+    let mut lines = vec![tr!("cli-a11y-plane-count", count = game.airplanes.len())];
+    lines.extend(game.airplanes.iter().map(|p| plane_line(game, p)));
+    lines
+}
+
+/// A single plane followed by its cargo and passengers.
+pub fn plane(game: &Game, plane_id: usize) -> Result<Vec<String>, GameError> {
+    let plane = game
+        .airplanes
+        .iter()
+        .find(|p| p.id == plane_id)
+        .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+    let mut lines = vec![plane_line(game, plane)];
+    lines.extend(plane.manifest.iter().map(|o| order_line(game, o)));
+    Ok(lines)
+}
+
+/// Every airport, optionally with the orders waiting there.
+pub fn airports(game: &Game, with_orders: bool) -> Vec<String> {
+    let mut lines = vec![tr!("cli-a11y-airport-count", count = game.map.num_airports)];
+    for (airport, _) in &game.map.airports {
+        lines.extend(airport_lines(game, airport, with_orders));
+    }
+    lines
+}
+
+/// A single airport, optionally with the orders waiting there.
+pub fn airport(
+    game: &Game,
+    airport_id: usize,
+    with_orders: bool,
+) -> Result<Vec<String>, GameError> {
+    let airport = game
+        .map
+        .airport(airport_id)
+        .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+    Ok(airport_lines(game, airport, with_orders))
+}
+
+/// Airports the plane could fly to, with their distance.
+pub fn distances(game: &Game, plane_id: usize) -> Result<Vec<String>, GameError> {
+    let plane = game
+        .airplanes
+        .iter()
+        .find(|p| p.id == plane_id)
+        .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+    if let AirplaneStatus::InTransit { .. } = plane.status {
+        return Ok(vec![tr!("cli-a11y-in-transit", id = plane_id)]);
+    }
+    Ok(game
+        .map
+        .airports
+        .iter()
+        .filter(|(_, coord)| *coord != plane.location)
+        .map(|(airport, coord)| {
+            let message = if plane.can_fly_to(airport, coord).is_ok() {
+                "cli-a11y-distance-reachable"
+            } else {
+                "cli-a11y-distance-unreachable"
+            };
+            tr!(
+                message,
+                id = airport.id,
+                name = airport.name.as_str(),
+                distance = format!("{:.0}", plane.distance_to(coord)),
+            )
+        })
+        .collect())
+}
+
+/// The airplane models for sale.
+pub fn models(game: &Game) -> Vec<String> {
+    game.available_models()
+        .into_iter()
+        .map(|(name, s)| {
+            tr!(
+                "cli-a11y-model",
+                name = name,
+                price = format!("{:.0}", s.purchase_price),
+                speed = format!("{:.0}", s.cruise_speed),
+                payload = format!("{:.0}", s.payload_capacity),
+                fuel = format!("{:.0}", s.fuel_capacity),
+                runway = format!("{:.0}", s.min_runway_length),
+            )
+        })
+        .collect()
+}
+
+/// One line per recorded day.
+pub fn stats(game: &Game) -> Vec<String> {
+    if game.stats.is_empty() {
+        return vec![tr!("cli-a11y-no-stats")];
+    }
+    game.stats
+        .iter()
+        .map(|s| {
+            tr!(
+                "cli-a11y-day",
+                day = s.day,
+                income = format!("{:.0}", s.income),
+                expenses = format!("{:.0}", s.expenses),
+                cash = format!("{:.0}", s.net_cash),
+                fleet = s.fleet_size,
+                deliveries = s.total_deliveries,
+            )
+        })
+        .collect()
+}
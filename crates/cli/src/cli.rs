@@ -1,7 +1,13 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use rand::Rng;
+use std::io::{BufRead, Write};
+use std::path::Path;
 
+use crate::daemon::DEFAULT_SOCKET;
 use rusty_runways_core::Game;
+use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::tr;
+use rusty_runways_core::utils::map::WorldStyle;
 
 /// Command line arguments for configuring the game.
 #[derive(Parser, Debug)]
@@ -9,6 +15,18 @@ pub struct Cli {
     /// Load world from YAML config file
     #[arg(long)]
     pub config: Option<String>,
+    /// Start from the save `save_games/<NAME>.json` instead of a new world
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["config", "seed", "n"])]
+    pub load: Option<String>,
+    /// Run this command and exit instead of starting the REPL; repeat to run several in
+    /// order
+    #[arg(
+        short = 'c',
+        long = "command",
+        value_name = "COMMAND",
+        conflicts_with = "daemon"
+    )]
+    pub commands: Vec<String>,
     /// Seed used for deterministic world generation
     #[arg(long)]
     pub seed: Option<u64>,
@@ -18,27 +36,189 @@ pub struct Cli {
     /// Starting cash for the player
     #[arg(long, default_value_t = 650_000.0)]
     pub c: f32,
+    /// Layout for generated airports (`clustered` or `continents`)
+    #[arg(long)]
+    pub world_style: Option<WorldStyle>,
+    /// Print the JSON Schema for world config files and exit
+    #[arg(long)]
+    pub schema: bool,
+    /// WASM plugin to hook into the game; repeat to load several
+    #[arg(long = "plugin")]
+    pub plugins: Vec<String>,
+    /// SQLite file to append daily stats, cash movements and deliveries to
+    #[arg(long)]
+    pub history: Option<String>,
+    /// Language for messages, e.g. `de`; overrides `RUSTY_RUNWAYS_LANG`
+    #[arg(long)]
+    pub lang: Option<String>,
+    /// Screen-reader-friendly output without the banner or tables; remembered for later
+    /// sessions, `--accessible=false` turns it off again
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub accessible: Option<bool>,
+    /// Save the game on exit and offer to resume it at the next launch; remembered for
+    /// later sessions, `--autosave=false` turns it off again
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub autosave: Option<bool>,
+    /// Run headless and serve the game on a local socket instead of the REPL; the
+    /// socket path (an address on Windows) defaults to `rusty_runways.sock`
+    #[arg(long, num_args = 0..=1, default_missing_value = DEFAULT_SOCKET)]
+    pub daemon: Option<String>,
+    /// Serve Prometheus metrics on this address (e.g. `127.0.0.1:9100`) in daemon mode
+    #[arg(long, requires = "daemon")]
+    pub metrics: Option<String>,
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+}
+
+/// One-off subcommands that run instead of the REPL.
+#[derive(Subcommand, Debug)]
+pub enum CliCommand {
+    /// Check a YAML world config and list every problem without starting a game
+    Validate {
+        /// Config file to check
+        path: String,
+    },
 }
 
 /// Initialize a [`Game`] from command line arguments.
 ///
+/// * `load` resumes a save and `config` builds the world from a YAML file.
 /// * If both `seed` and `n` are provided, they are used verbatim.
 /// * If neither are provided, random values are generated.
 /// * Supplying only one of `seed` or `n` results in an error.
+/// * `world_style` overrides the layout of generated airports, including configs.
 pub fn init_game_from_cli(cli: Cli) -> Result<Game, String> {
+    if let Some(name) = cli.load {
+        return Game::load_game_with(&name, None)
+            .map_err(|e| tr!("cli-load-game-failed", error = e.to_string()));
+    }
     if let Some(path) = cli.config {
-        let text = std::fs::read_to_string(&path)
-            .map_err(|e| format!("failed to read config {}: {}", path, e))?;
-        let cfg: rusty_runways_core::config::WorldConfig =
-            serde_yaml::from_str(&text).map_err(|e| format!("invalid yaml: {}", e))?;
+        let text = std::fs::read_to_string(&path).map_err(|e| {
+            tr!(
+                "cli-config-read-failed",
+                path = path.as_str(),
+                error = e.to_string()
+            )
+        })?;
+        let mut cfg: WorldConfig = serde_yaml::from_str(&text)
+            .map_err(|e| tr!("cli-invalid-yaml", error = e.to_string()))?;
+        if let Some(style) = cli.world_style {
+            cfg.world_style = style;
+        }
         return rusty_runways_core::Game::from_config(cfg).map_err(|e| e.to_string());
     }
+    let style = cli.world_style.unwrap_or_default();
     match (cli.seed, cli.n) {
-        (Some(seed), Some(n)) => Ok(Game::new(seed, Some(n), cli.c)),
+        (Some(seed), Some(n)) => Ok(Game::new_with_style(seed, Some(n), cli.c, style)),
         (None, None) => {
             let seed = rand::thread_rng().r#gen();
-            Ok(Game::new(seed, None, cli.c))
+            Ok(Game::new_with_style(seed, None, cli.c, style))
+        }
+        _ => Err(tr!("cli-seed-and-n-required")),
+    }
+}
+
+impl Cli {
+    /// Whether the arguments leave the world up to the CLI, so an autosaved session
+    /// may be offered instead of a random new one.
+    pub fn picks_no_world(&self) -> bool {
+        self.config.is_none() && self.load.is_none() && self.seed.is_none() && self.n.is_none()
+    }
+}
+
+/// Offer to resume the session autosaved at `path`, reading the answer from `input`.
+///
+/// Returns
+/// - `Some(Game)`: The saved game, if there is one and the player didn't answer `n`.
+/// - `None`: No saved session, the player declined, or the save could not be loaded.
+pub fn offer_resume(path: &Path, input: &mut impl BufRead) -> Option<Game> {
+    if !path.exists() {
+        return None;
+    }
+    print!("{} ", tr!("cli-resume-prompt"));
+    std::io::stdout().flush().ok();
+    let mut answer = String::new();
+    input.read_line(&mut answer).ok()?;
+    if answer.trim().to_lowercase().starts_with('n') {
+        return None;
+    }
+    match Game::load_game_from(path, None) {
+        Ok(game) => Some(game),
+        Err(e) => {
+            eprintln!("{}", tr!("cli-resume-failed", error = e.to_string()));
+            None
         }
-        _ => Err("Both --seed and --n must be specified".to_string()),
+    }
+}
+
+/// Write the current world to `path` as a YAML [`WorldConfig`](rusty_runways_core::config::WorldConfig).
+///
+/// The file can be edited and loaded again with `--config` or `LOAD CONFIG`.
+pub fn export_config_to_file(game: &Game, path: &str) -> Result<(), String> {
+    let yaml = serde_yaml::to_string(&game.export_config())
+        .map_err(|e| tr!("cli-serialize-config-failed", error = e.to_string()))?;
+    std::fs::write(path, yaml)
+        .map_err(|e| tr!("cli-write-failed", path = path, error = e.to_string()))
+}
+
+/// Load each WASM plugin in `paths` into `game`, named after its file.
+pub fn load_plugins(game: &mut Game, paths: &[String]) -> Result<(), String> {
+    for path in paths {
+        let wasm = std::fs::read(path).map_err(|e| {
+            tr!(
+                "cli-plugin-read-failed",
+                path = path.as_str(),
+                error = e.to_string()
+            )
+        })?;
+        let name = std::path::Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.clone());
+        game.load_plugin(&name, &wasm).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Write the run summary of `game` to `path` as JSON, for comparing runs on a leaderboard.
+pub fn export_run_to_file(game: &Game, path: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&game.run_summary())
+        .map_err(|e| tr!("cli-serialize-run-failed", error = e.to_string()))?;
+    std::fs::write(path, json)
+        .map_err(|e| tr!("cli-write-failed", path = path, error = e.to_string()))
+}
+
+/// Check the YAML world config at `path`.
+///
+/// Reports YAML syntax and type errors (serde_yaml includes line and column), then every
+/// [`WorldConfig::validate`] issue with its YAML path. If those pass, the world is built
+/// once in memory to catch problems that depend on the map (e.g. starting fleet runways)
+/// or on the airport dataset file.
+///
+/// Returns
+/// - `Vec<String>`: One line per problem; empty when the config is valid.
+pub fn validate_config_file(path: &str) -> Vec<String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            return vec![tr!(
+                "cli-config-read-failed",
+                path = path,
+                error = e.to_string()
+            )];
+        }
+    };
+    let cfg: WorldConfig = match serde_yaml::from_str(&text) {
+        Ok(cfg) => cfg,
+        Err(e) => return vec![tr!("cli-invalid-yaml", error = e.to_string())],
+    };
+
+    let issues = cfg.validate();
+    if !issues.is_empty() {
+        return issues.iter().map(|issue| issue.to_string()).collect();
+    }
+    match Game::from_config(cfg) {
+        Ok(_) => Vec::new(),
+        Err(e) => vec![e.to_string()],
     }
 }
@@ -0,0 +1,223 @@
+//! Headless mode: serve one long-running game to bots or a separate UI process.
+//!
+//! Clients connect to a Unix domain socket (a loopback TCP address on other platforms)
+//! and send the same commands as the REPL, one per line. Every line gets JSON lines
+//! back: one `{"event": ...}` per game event it caused, then a single
+//! `{"ok": true, "data": ...}` or `{"ok": false, "error": "..."}`.
+//!
+//! Besides the REPL commands the daemon understands `OBSERVE` (the full observation) and
+//! `OBSERVE SINCE <version>` (an incremental patch, see [`Game::observe_diff`]). `SHOW`
+//! commands print nothing here; read the observation instead. `EXIT` closes the
+//! connection while the game keeps running for the next client.
+//!
+//! With `--metrics <address>` the daemon also answers `GET /metrics` there for Prometheus,
+//! see [`rusty_runways_core::metrics`]; the game is reported as `game="0"`.
+
+use std::io::{BufRead, BufReader, Write};
+use std::sync::{Arc, Mutex};
+
+use rusty_runways_commands::{Command, parse_command};
+use rusty_runways_core::Game;
+use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::events::EventFilter;
+use rusty_runways_core::metrics::{self, ServerMetrics};
+use rusty_runways_core::tr;
+use serde_json::{Value, json};
+
+use crate::cli::{export_config_to_file, export_run_to_file, load_plugins};
+
+/// Socket used when `--daemon` is given without a path.
+#[cfg(unix)]
+pub const DEFAULT_SOCKET: &str = "rusty_runways.sock";
+/// Address used when `--daemon` is given without one.
+#[cfg(not(unix))]
+pub const DEFAULT_SOCKET: &str = "127.0.0.1:7420";
+
+/// What to send back for one line.
+pub enum Reply {
+    /// Send these JSON lines and wait for the next command
+    Lines(Vec<Value>),
+    /// Close the connection
+    Close,
+}
+
+/// Run `line` against `game` and build the JSON lines to send back.
+///
+/// `plugins` are loaded again whenever the line replaces the game (`LOAD`, `LOAD CONFIG`).
+pub fn respond(game: &mut Game, line: &str, plugins: &[String]) -> Reply {
+    let line = line.trim();
+    if line.is_empty() {
+        return Reply::Lines(Vec::new());
+    }
+    if line.eq_ignore_ascii_case("EXIT") {
+        return Reply::Close;
+    }
+    let events = game.subscribe(EventFilter::all());
+    let result = run(game, line, plugins);
+    let mut lines: Vec<Value> = events
+        .try_iter()
+        .map(|event| json!({ "event": event }))
+        .collect();
+    lines.push(match result {
+        Ok(data) => json!({ "ok": true, "data": data }),
+        Err(error) => json!({ "ok": false, "error": error }),
+    });
+    Reply::Lines(lines)
+}
+
+fn run(game: &mut Game, line: &str, plugins: &[String]) -> Result<Option<Value>, String> {
+    let words: Vec<String> = line
+        .split_whitespace()
+        .map(|w| w.to_ascii_uppercase())
+        .collect();
+    match words.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["OBSERVE"] => return Ok(Some(json!(game.observe()))),
+        ["OBSERVE", "SINCE", version] => {
+            let since = version
+                .parse()
+                .map_err(|_| tr!("cli-syntax-error", error = line))?;
+            return Ok(Some(json!(game.observe_diff(since))));
+        }
+        _ => {}
+    }
+
+    let command =
+        parse_command(line).map_err(|e| tr!("cli-syntax-error", error = e.to_string()))?;
+    match command {
+        Command::LoadConfig { path } => {
+            let text = std::fs::read_to_string(&path).map_err(|e| {
+                tr!(
+                    "cli-read-failed",
+                    path = path.as_str(),
+                    error = e.to_string()
+                )
+            })?;
+            let cfg: WorldConfig = serde_yaml::from_str(&text)
+                .map_err(|e| tr!("cli-yaml-error", error = e.to_string()))?;
+            *game = Game::from_config(cfg)
+                .map_err(|e| tr!("cli-config-invalid", error = e.to_string()))?;
+            load_plugins(game, plugins)?;
+        }
+        Command::ExportRun { path } => export_run_to_file(game, &path)?,
+        Command::ExportConfig { path } => export_config_to_file(game, &path)?,
+        command @ Command::LoadGame { .. } => {
+            game.execute(command).map_err(|e| e.to_string())?;
+            load_plugins(game, plugins)?;
+        }
+        command => game.execute(command).map_err(|e| e.to_string())?,
+    }
+    Ok(None)
+}
+
+/// Answer commands from one client until it sends `EXIT` or disconnects, counting them
+/// in `metrics`.
+pub fn serve_connection<S: std::io::Read + Write>(
+    game: &Mutex<Game>,
+    stream: S,
+    plugins: &[String],
+    metrics: &ServerMetrics,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        let reply = {
+            let mut game = game.lock().expect("game lock poisoned");
+            let before = game.time;
+            let reply = respond(&mut game, &line, plugins);
+            if let Reply::Lines(lines) = &reply {
+                let events = lines.iter().filter(|l| l.get("event").is_some()).count();
+                // a LOAD can move the clock backwards
+                metrics.record(game.time.saturating_sub(before), events as u64);
+            }
+            reply
+        };
+        line.clear();
+        match reply {
+            Reply::Close => break,
+            Reply::Lines(lines) => {
+                let out = reader.get_mut();
+                for value in lines {
+                    writeln!(out, "{}", value)?;
+                }
+                out.flush()?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Serve `game` on `address` until the process is stopped, one thread per client, and
+/// Prometheus metrics on `metrics_address` if given.
+#[cfg(unix)]
+pub fn run_daemon(
+    game: Game,
+    address: &str,
+    plugins: Vec<String>,
+    metrics_address: Option<&str>,
+) -> std::io::Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    // a socket left behind by an earlier run would make bind fail
+    if std::fs::metadata(address).is_ok() {
+        std::fs::remove_file(address)?;
+    }
+    let listener = UnixListener::bind(address)?;
+    serve(game, listener.incoming(), address, plugins, metrics_address)
+}
+
+/// Serve `game` on `address` until the process is stopped, one thread per client, and
+/// Prometheus metrics on `metrics_address` if given.
+#[cfg(not(unix))]
+pub fn run_daemon(
+    game: Game,
+    address: &str,
+    plugins: Vec<String>,
+    metrics_address: Option<&str>,
+) -> std::io::Result<()> {
+    let listener = std::net::TcpListener::bind(address)?;
+    serve(game, listener.incoming(), address, plugins, metrics_address)
+}
+
+fn serve<S, I>(
+    game: Game,
+    incoming: I,
+    address: &str,
+    plugins: Vec<String>,
+    metrics_address: Option<&str>,
+) -> std::io::Result<()>
+where
+    S: std::io::Read + Write + Send + 'static,
+    I: Iterator<Item = std::io::Result<S>>,
+{
+    eprintln!("{}", tr!("cli-daemon-listening", address = address));
+    let game = Arc::new(Mutex::new(game));
+    let plugins = Arc::new(plugins);
+    let metrics = Arc::new(ServerMetrics::default());
+    if let Some(metrics_address) = metrics_address {
+        let listener = std::net::TcpListener::bind(metrics_address)?;
+        eprintln!(
+            "{}",
+            tr!("cli-metrics-listening", address = metrics_address)
+        );
+        let game = Arc::clone(&game);
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || {
+            metrics::serve(listener, || {
+                let game = game.lock().expect("game lock poisoned");
+                metrics.render([(0, &*game)])
+            })
+        });
+    }
+    for stream in incoming {
+        let stream = stream?;
+        let game = Arc::clone(&game);
+        let plugins = Arc::clone(&plugins);
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || {
+            if let Err(e) = serve_connection(&game, stream, &plugins, &metrics) {
+                eprintln!("{}", tr!("cli-daemon-client-failed", error = e.to_string()));
+            }
+        });
+    }
+    Ok(())
+}
@@ -1,5 +1,8 @@
 #![allow(non_snake_case)]
 
+pub mod accessible;
 pub mod cli;
+pub mod daemon;
 pub mod read;
+pub mod settings;
 pub use rusty_runways_commands as commands;
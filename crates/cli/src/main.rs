@@ -1,207 +1,842 @@
 use clap::Parser;
-use rusty_runways_cli::cli::{Cli, init_game_from_cli};
+use rusty_runways_cli::accessible;
+use rusty_runways_cli::cli::{
+    Cli, CliCommand, export_config_to_file, export_run_to_file, init_game_from_cli, load_plugins,
+    offer_resume, validate_config_file,
+};
+use rusty_runways_cli::daemon::run_daemon;
 use rusty_runways_cli::read::{LineReaderHelper, print_banner};
+use rusty_runways_cli::settings::{CliSettings, history_path, session_path, settings_path};
 use rusty_runways_commands::Command;
 use rusty_runways_commands::parse_command;
 use rusty_runways_core::Game;
-use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::config::{WorldConfig, world_config_schema};
+use rusty_runways_core::i18n;
+use rusty_runways_core::save::SaveOptions;
+use rusty_runways_core::tr;
+use rusty_runways_core::utils::airplanes::checks::CheckType;
+use rusty_runways_core::utils::airplanes::models::{CruiseRegime, FuelAmount};
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::OrderBoardFilter;
+use rustyline::error::ReadlineError;
 use rustyline::{ColorMode, CompletionType, Config, Editor};
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    print_banner();
     let cli = Cli::parse();
-    let mut game = match init_game_from_cli(cli) {
+    let locale = match &cli.lang {
+        Some(lang) => i18n::load_locale(lang, &i18n::locale_dir()),
+        None => i18n::init_from_env(),
+    };
+    if let Err(e) = locale {
+        eprintln!("{}", e);
+    }
+    if cli.schema {
+        println!("{:#}", world_config_schema());
+        return Ok(());
+    }
+    if let Some(CliCommand::Validate { path }) = &cli.command {
+        let problems = validate_config_file(path);
+        if problems.is_empty() {
+            println!("{}", tr!("cli-config-valid", path = path.to_string()));
+            return Ok(());
+        }
+        for problem in &problems {
+            eprintln!("{}", problem);
+        }
+        eprintln!(
+            "{}",
+            tr!(
+                "cli-config-problems",
+                count = problems.len(),
+                path = path.to_string()
+            )
+        );
+        std::process::exit(1);
+    }
+
+    let mut settings = CliSettings::load(&settings_path());
+    if cli.accessible.is_some() || cli.autosave.is_some() {
+        settings.accessible = cli.accessible.unwrap_or(settings.accessible);
+        settings.autosave = cli.autosave.unwrap_or(settings.autosave);
+        if let Err(e) = settings.save(&settings_path()) {
+            eprintln!("{}", e);
+        }
+    }
+    let accessible = settings.accessible;
+    let autosave = settings.autosave;
+
+    // a daemon's clients read the socket, not stdout
+    let daemon = cli.daemon.clone();
+    let metrics = cli.metrics.clone();
+    // one-shot runs print only what their commands print
+    let commands = cli.commands.clone();
+    if daemon.is_none() && commands.is_empty() {
+        if accessible {
+            println!("{}", tr!("cli-a11y-banner"));
+        } else {
+            print_banner();
+        }
+    }
+    let plugins = cli.plugins.clone();
+    let history = cli.history.clone();
+    let may_resume = autosave && daemon.is_none() && commands.is_empty() && cli.picks_no_world();
+    let resumed = may_resume
+        .then(|| offer_resume(&session_path(), &mut std::io::stdin().lock()))
+        .flatten();
+    let mut game = match resumed.map_or_else(|| init_game_from_cli(cli), Ok) {
         Ok(game) => game,
         Err(e) => {
             eprintln!("{}", e);
             std::process::exit(1);
         }
     };
+    if let Err(e) = load_plugins(&mut game, &plugins) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    if let Some(path) = history {
+        if let Err(e) = game.attach_history(&path) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+    if let Some(address) = daemon {
+        run_daemon(game, &address, plugins, metrics.as_deref())?;
+        return Ok(());
+    }
+
+    if !commands.is_empty() {
+        for line in &commands {
+            if !run_line(&mut game, line, &plugins, accessible) {
+                break;
+            }
+        }
+        return Ok(());
+    }
 
     // line parser
     let config = Config::builder()
         .completion_type(CompletionType::Circular)
-        .color_mode(ColorMode::Enabled)
+        .color_mode(if accessible {
+            ColorMode::Disabled
+        } else {
+            ColorMode::Enabled
+        })
         .build();
     let mut line_reader = Editor::with_config(config)?;
     line_reader.set_helper(Some(LineReaderHelper::new()));
+    // a missing history file just means a first session
+    let _ = line_reader.load_history(&history_path());
 
     loop {
-        let line = line_reader.readline("> ")?;
+        let line = match line_reader.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
         let _ = line_reader.add_history_entry(line.as_str());
 
-        if line.to_uppercase().starts_with("LOAD CONFIG ") {
-            let path = line[12..].trim();
-            match std::fs::read_to_string(path) {
-                Ok(text) => match serde_yaml::from_str::<WorldConfig>(&text) {
-                    Ok(cfg) => match Game::from_config(cfg) {
-                        Ok(new_game) => {
-                            game = new_game;
-                            println!("Loaded config from {}", path);
+        if !run_line(&mut game, &line, &plugins, accessible) {
+            break;
+        }
+    }
+
+    let history_file = history_path();
+    if let Some(dir) = history_file.parent().filter(|d| !d.as_os_str().is_empty()) {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Err(e) = line_reader.save_history(&history_file) {
+        eprintln!(
+            "{}",
+            tr!(
+                "cli-write-failed",
+                path = history_file.display().to_string(),
+                error = e.to_string()
+            )
+        );
+    }
+    if autosave {
+        match game.save_game_to(&session_path(), &SaveOptions::plain()) {
+            Ok(()) => println!("{}", tr!("cli-session-saved")),
+            Err(e) => eprintln!("{}", tr!("cli-save-failed", error = e.to_string())),
+        }
+    }
+
+    Ok(())
+}
+
+/// Run one line typed at the prompt or passed with `-c`, printing its outcome.
+///
+/// Returns `false` once the player asked to exit.
+fn run_line(game: &mut Game, line: &str, plugins: &[String], accessible: bool) -> bool {
+    if line.to_uppercase().starts_with("LOAD CONFIG ") {
+        let path = line[12..].trim();
+        match std::fs::read_to_string(path) {
+            Ok(text) => match serde_yaml::from_str::<WorldConfig>(&text) {
+                Ok(cfg) => match Game::from_config(cfg) {
+                    Ok(new_game) => {
+                        *game = new_game;
+                        if let Err(e) = load_plugins(game, plugins) {
+                            println!("{}", e);
                         }
-                        Err(e) => println!("Invalid config: {}", e),
-                    },
-                    Err(e) => println!("YAML parse error: {}", e),
+                        println!("{}", tr!("cli-config-loaded", path = path.to_string()));
+                    }
+                    Err(e) => println!("{}", tr!("cli-config-invalid", error = e.to_string())),
                 },
-                Err(e) => println!("Failed to read {}: {}", path, e),
-            }
-            continue;
+                Err(e) => println!("{}", tr!("cli-yaml-error", error = e.to_string())),
+            },
+            Err(e) => println!(
+                "{}",
+                tr!(
+                    "cli-read-failed",
+                    path = path.to_string(),
+                    error = e.to_string()
+                )
+            ),
         }
+        return true;
+    }
 
-        match parse_command(&line) {
-            Ok(Command::ShowModels) => {
-                // Print airplane models table based on current game's catalog
-                println!(
-                    "{:<16} {:>8} {:>8} {:>7} {:>8} {:>10} {:>12} {:>12}",
-                    "Model", "Cruise", "Fuel", "Burn", "Oper/h", "Payload", "Price", "Runway"
-                );
+    let parsed = parse_command(line);
+    if parsed.is_ok() {
+        game.record_command();
+    }
+    match parsed {
+        Ok(Command::ShowModels) if accessible => print_lines(accessible::models(game)),
+
+        Ok(Command::ShowModels) => {
+            // Print airplane models table based on current game's catalog
+            println!(
+                "{:<16} {:>8} {:>8} {:>7} {:>8} {:>10} {:>12} {:>12}",
+                "Model", "Cruise", "Fuel", "Burn", "Oper/h", "Payload", "Price", "Runway"
+            );
+            println!(
+                "{:-<16} {:-<8} {:-<8} {:-<7} {:-<8} {:-<10} {:-<12} {:-<12}",
+                "", "", "", "", "", "", "", ""
+            );
+            for (name, s) in game.available_models() {
                 println!(
-                    "{:-<16} {:-<8} {:-<8} {:-<7} {:-<8} {:-<10} {:-<12} {:-<12}",
-                    "", "", "", "", "", "", "", ""
+                    "{:<16} {:>8.0} {:>8.0} {:>7.0} {:>8.0} {:>10.0} {:>12.0} {:>12.0}",
+                    name,
+                    s.cruise_speed,
+                    s.fuel_capacity,
+                    s.fuel_consumption,
+                    s.operating_cost,
+                    s.payload_capacity,
+                    s.purchase_price,
+                    s.min_runway_length,
                 );
-                for (name, s) in game.available_models() {
-                    println!(
-                        "{:<16} {:>8.0} {:>8.0} {:>7.0} {:>8.0} {:>10.0} {:>12.0} {:>12.0}",
-                        name,
-                        s.cruise_speed,
-                        s.fuel_capacity,
-                        s.fuel_consumption,
-                        s.operating_cost,
-                        s.payload_capacity,
-                        s.purchase_price,
-                        s.min_runway_length,
-                    );
-                }
             }
+        }
 
-            Ok(Command::ShowAirports { with_orders }) => game.list_airports(with_orders),
+        Ok(Command::ShowMap { width, height }) => {
+            print!("{}", game.render_ascii(width, height))
+        }
 
-            Ok(Command::ShowAirport { id, with_orders }) => {
-                if let Err(e) = game.list_airport(id, with_orders) {
-                    println!("{}", e);
-                }
+        Ok(Command::ShowAirports { with_orders }) if accessible => {
+            print_lines(accessible::airports(game, with_orders))
+        }
+
+        Ok(Command::ShowAirports { with_orders }) => game.list_airports(with_orders),
+
+        Ok(Command::ShowAirport { id, with_orders }) if accessible => {
+            match accessible::airport(game, id, with_orders) {
+                Ok(lines) => print_lines(lines),
+                Err(e) => println!("{}", e),
             }
+        }
 
-            Ok(Command::ShowAirplanes) => {
-                if let Err(e) = game.list_airplanes() {
-                    println!("{}", e)
-                }
+        Ok(Command::ShowAirport { id, with_orders }) => {
+            if let Err(e) = game.list_airport(id, with_orders) {
+                println!("{}", e);
             }
+        }
 
-            Ok(Command::ShowAirplane { id }) => {
-                if let Err(e) = game.list_airplane(id) {
-                    println!("{}", e);
-                }
+        Ok(Command::ShowAirplanes) if accessible => print_lines(accessible::planes(game)),
+
+        Ok(Command::ShowAirplanes) => {
+            if let Err(e) = game.list_airplanes() {
+                println!("{}", e)
             }
+        }
 
-            Ok(Command::ShowDistances { plane_id }) => {
-                if let Err(e) = game.show_distances(plane_id) {
-                    println!("{}", e);
-                }
+        Ok(Command::ShowAirplane { id }) if accessible => match accessible::plane(game, id) {
+            Ok(lines) => print_lines(lines),
+            Err(e) => println!("{}", e),
+        },
+
+        Ok(Command::ShowAirplane { id }) => {
+            if let Err(e) = game.list_airplane(id) {
+                println!("{}", e);
             }
+        }
 
-            Ok(Command::BuyPlane { model, airport }) => match game.buy_plane(&model, airport) {
-                Ok(()) => {
-                    println!("Airplane was bought!")
-                }
-                Err(e) => {
-                    println!("{:?}", e)
-                }
-            },
+        Ok(Command::ShowDistances { plane_id }) if accessible => {
+            match accessible::distances(game, plane_id) {
+                Ok(lines) => print_lines(lines),
+                Err(e) => println!("{}", e),
+            }
+        }
 
-            Ok(Command::SellPlane { plane }) => match game.sell_plane(plane) {
-                Ok(refund) => println!("Plane {} sold. Refunded ${:.2}", plane, refund),
-                Err(e) => println!("Sell failed: {}", e),
-            },
+        Ok(Command::ShowDistances { plane_id }) => {
+            if let Err(e) = game.show_distances(plane_id) {
+                println!("{}", e);
+            }
+        }
 
-            Ok(Command::LoadOrder { order, plane }) => {
-                if let Err(e) = game.load_order(order, plane) {
-                    println!("Load failed: {}", e);
-                } else {
-                    println!("Loading order {:?} onto plane {:?}", order, plane);
-                }
+        Ok(Command::BuyPlane { model, airport }) => match game.buy_plane(&model, airport) {
+            Ok(()) => {
+                println!("{}", tr!("cli-plane-bought"))
+            }
+            Err(e) => {
+                println!("{:?}", e)
             }
+        },
 
-            Ok(Command::LoadOrders { orders, plane }) => {
-                for o in orders {
-                    if let Err(e) = game.load_order(o, plane) {
-                        println!("Load failed: {}", e);
-                    } else {
-                        println!("Loading order {:?} onto plane {:?}", o, plane);
-                    }
-                }
+        Ok(Command::SellPlane { plane }) => match game.sell_plane(plane) {
+            Ok(refund) => println!(
+                "{}",
+                tr!(
+                    "cli-plane-sold",
+                    plane = plane,
+                    refund = format!("{:.2}", refund)
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-sell-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowMarket) => game.list_used_market(),
+
+        Ok(Command::GroupPlane { plane, group }) => match game.group_plane(plane, &group) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-plane-grouped",
+                    plane = plane,
+                    group = group.to_lowercase()
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-group-failed", error = e.to_string())),
+        },
+
+        Ok(Command::UngroupPlane { plane, group }) => match game.ungroup_plane(plane, &group) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-plane-ungrouped",
+                    plane = plane,
+                    group = group.to_lowercase()
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-ungroup-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowGroup { group }) => {
+            if let Err(e) = game.list_group(&group) {
+                println!("{}", e);
             }
+        }
 
-            Ok(Command::UnloadAll { plane }) => {
-                if let Err(e) = game.unload_all(plane) {
-                    println!("Unloading failed: {}", e)
-                }
+        Ok(Command::ShowGroups) => game.list_groups(),
+        Ok(Command::ShowMaintenance) => game.list_maintenance_plan(),
+        Ok(Command::ShowParts) => game.list_spare_parts(),
+        Ok(Command::ShowPilots) => game.list_pilots(),
+
+        Ok(Command::HirePilot { airport }) => match game.hire_pilot(airport) {
+            Ok(pilot) => println!(
+                "{}",
+                tr!("cli-pilot-hired", pilot = pilot, airport = airport)
+            ),
+            Err(e) => println!("{}", tr!("cli-hire-failed", error = e.to_string())),
+        },
+
+        Ok(Command::FirePilot { pilot }) => match game.fire_pilot(pilot) {
+            Ok(()) => println!("{}", tr!("cli-pilot-fired", pilot = pilot)),
+            Err(e) => println!("{}", tr!("cli-fire-pilot-failed", error = e.to_string())),
+        },
+
+        Ok(Command::HireCrew { airport, count }) => match game.hire_crew(airport, count) {
+            Ok(crew) => println!("{}", tr!("cli-ground-crew", airport = airport, crew = crew)),
+            Err(e) => println!("{}", tr!("cli-hire-failed", error = e.to_string())),
+        },
+
+        Ok(Command::FireCrew { airport, count }) => match game.fire_crew(airport, count) {
+            Ok(crew) => println!("{}", tr!("cli-ground-crew", airport = airport, crew = crew)),
+            Err(e) => println!("{}", tr!("cli-fire-crew-failed", error = e.to_string())),
+        },
+
+        Ok(Command::AssignPilot { pilot, plane }) => match game.assign_pilot(pilot, plane) {
+            Ok(()) => println!(
+                "{}",
+                tr!("cli-pilot-assigned", pilot = pilot, plane = plane)
+            ),
+            Err(e) => println!("{}", tr!("cli-assign-failed", error = e.to_string())),
+        },
+
+        Ok(Command::BuyParts { airport, kits }) => match game.buy_spare_parts(airport, kits) {
+            Ok(()) => println!(
+                "{}",
+                tr!("cli-parts-bought", kits = kits, airport = airport)
+            ),
+            Err(e) => println!("{}", tr!("cli-purchase-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShipParts { kits, dest, plane }) => {
+            match game.ship_spare_parts(kits, dest, plane) {
+                Ok(order) => println!(
+                    "{}",
+                    tr!(
+                        "cli-parts-shipped",
+                        kits = kits,
+                        airport = dest,
+                        plane = plane,
+                        order = order
+                    )
+                ),
+                Err(e) => println!("{}", tr!("cli-shipment-failed", error = e.to_string())),
             }
+        }
 
-            Ok(Command::UnloadOrder { order, plane }) => {
-                if let Err(e) = game.unload_order(order, plane) {
-                    println!("Unloading failed: {}", e)
-                }
+        Ok(Command::RefuelGroup { group }) => match game.refuel_group(&group) {
+            Ok(planes) => println!(
+                "{}",
+                tr!("cli-group-refueling", planes = format!("{:?}", planes))
+            ),
+            Err(e) => println!("{}", tr!("cli-refuel-group-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ChargePlane { plane }) => match game.charge_plane(plane) {
+            Ok(hours) => println!(
+                "{}",
+                tr!("cli-plane-charging", plane = plane, hours = hours)
+            ),
+            Err(e) => println!("{}", tr!("cli-charge-failed", error = e.to_string())),
+        },
+
+        Ok(Command::InstallCharger { airport }) => match game.install_charger(airport) {
+            Ok(()) => println!("{}", tr!("cli-charger-installed", airport = airport)),
+            Err(e) => println!("{}", tr!("cli-install-failed", error = e.to_string())),
+        },
+
+        Ok(Command::BuildAirport { x, y }) => {
+            let started = game.build_airport(x, y);
+            print_construction(game, started);
+        }
+
+        Ok(Command::ExtendRunway { airport }) => {
+            let started = game.extend_runway(airport);
+            print_construction(game, started);
+        }
+
+        Ok(Command::BuyConcession { airport }) => match game.buy_concession(airport) {
+            Ok(cost) => println!(
+                "{}",
+                tr!(
+                    "cli-concession-bought",
+                    airport = airport,
+                    cost = format!("{:.2}", cost)
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-concession-failed", error = e.to_string())),
+        },
+
+        Ok(Command::RenamePlane { plane, name }) => match game.rename_plane(plane, &name) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-plane-renamed",
+                    plane = plane,
+                    name = name.trim().to_string()
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-rename-failed", error = e.to_string())),
+        },
+
+        Ok(Command::BuyUsed { listing }) => match game.buy_used_plane(listing) {
+            Ok(plane) => println!(
+                "{}",
+                tr!("cli-listing-bought", listing = listing, plane = plane)
+            ),
+            Err(e) => println!("{}", tr!("cli-purchase-failed", error = e.to_string())),
+        },
+
+        Ok(Command::LoadOrder { order, plane }) => {
+            if let Err(e) = game.load_order(order, plane) {
+                println!("{}", tr!("cli-load-failed", error = e.to_string()));
+            } else {
+                println!("{}", tr!("cli-order-loading", order = order, plane = plane));
             }
+        }
 
-            Ok(Command::UnloadOrders { orders, plane }) => {
-                for o in orders {
-                    if let Err(e) = game.unload_order(o, plane) {
-                        println!("Unloading failed: {}", e);
-                    }
+        Ok(Command::LoadOrders { orders, plane }) => {
+            for o in orders {
+                if let Err(e) = game.load_order(o, plane) {
+                    println!("{}", tr!("cli-load-failed", error = e.to_string()));
+                } else {
+                    println!("{}", tr!("cli-order-loading", order = o, plane = plane));
                 }
             }
+        }
 
-            Ok(Command::Refuel { plane }) => {
-                if let Err(e) = game.refuel_plane(plane) {
-                    println!("Failed to refuel: {}", e);
-                }
+        Ok(Command::UnloadAll { plane }) => {
+            if let Err(e) = game.unload_all(plane) {
+                println!("{}", tr!("cli-unload-failed", error = e.to_string()))
             }
+        }
 
-            Ok(Command::DepartPlane { plane, dest }) => {
-                if let Err(e) = game.depart_plane(plane, dest) {
-                    println!("Cannot depart: {}", e);
-                }
+        Ok(Command::ShowContracts) => game.list_contracts(),
+
+        Ok(Command::LoadContract {
+            contract,
+            weight,
+            plane,
+        }) => match game.load_contract_cargo(contract, weight, plane) {
+            Ok(order) => println!(
+                "{}",
+                tr!(
+                    "cli-contract-loaded",
+                    weight = format!("{:.0}", weight),
+                    contract = contract,
+                    order = order
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-load-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowAllOrders {
+            country,
+            min_value_per_kg,
+            within_hours,
+        }) => game.list_all_orders(&OrderBoardFilter {
+            destination_country: country,
+            min_value_per_kg,
+            max_deadline: within_hours,
+        }),
+
+        Ok(Command::ReserveOrder { order }) => match game.reserve_order(order) {
+            Ok(fee) => println!(
+                "{}",
+                tr!(
+                    "cli-order-reserved",
+                    order = order,
+                    fee = format!("{:.2}", fee)
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-reserve-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowCharters) => game.list_charters(),
+
+        Ok(Command::AcceptCharter { charter, plane }) => {
+            match game.accept_charter(charter, plane) {
+                Ok(()) => println!(
+                    "{}",
+                    tr!("cli-charter-boarded", charter = charter, plane = plane)
+                ),
+                Err(e) => println!("{}", tr!("cli-charter-failed", error = e.to_string())),
             }
+        }
+
+        Ok(Command::ShowLanes) => game.list_lanes(),
+
+        Ok(Command::AcceptLane { deal }) => match game.accept_lane_deal(deal) {
+            Ok(()) => println!("{}", tr!("cli-lane-accepted", deal = deal)),
+            Err(e) => println!("{}", tr!("cli-accept-lane-failed", error = e.to_string())),
+        },
 
-            Ok(Command::ShowCash) => {
-                game.show_cash();
+        Ok(Command::DeclineLane { deal }) => match game.decline_lane_deal(deal) {
+            Ok(()) => println!("{}", tr!("cli-lane-declined", deal = deal)),
+            Err(e) => println!("{}", tr!("cli-decline-lane-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowAuctions) => game.list_auctions(),
+        Ok(Command::ShowConstruction) => game.list_construction(),
+        Ok(Command::ShowConcessions) => game.list_concessions(),
+        Ok(Command::ShowWarehouse) => game.list_warehouse(),
+
+        Ok(Command::BidAuction { auction, amount }) => match game.bid_auction(auction, amount) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-bid-placed",
+                    amount = format!("{:.2}", amount),
+                    auction = auction
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-bid-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowDemand { airport }) => {
+            if let Err(e) = game.list_demand(airport) {
+                println!("{}", e);
             }
+        }
+
+        Ok(Command::ShowFares) => game.list_fares(),
+
+        Ok(Command::SetFare {
+            origin,
+            destination,
+            fare,
+        }) => match game.set_route_fare(origin, destination, fare) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-fare-set",
+                    origin = origin,
+                    destination = destination,
+                    fare = format!("{:.2}", fare)
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-set-fare-failed", error = e.to_string())),
+        },
 
-            Ok(Command::ShowTime) => {
-                game.show_time();
+        Ok(Command::ClearFare {
+            origin,
+            destination,
+        }) => match game.clear_route_fare(origin, destination) {
+            Ok(()) => println!(
+                "{}",
+                tr!(
+                    "cli-fare-cleared",
+                    origin = origin,
+                    destination = destination
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-clear-fare-failed", error = e.to_string())),
+        },
+
+        Ok(Command::SplitOrder { order, weight }) => {
+            if let Err(e) = game.split_order(order, weight) {
+                println!("{}", tr!("cli-split-failed", error = e.to_string()))
             }
+        }
 
-            Ok(Command::ShowStats) => {
-                game.show_stats();
+        Ok(Command::BuildPallet { orders }) => {
+            if let Err(e) = game.build_pallet(&orders) {
+                println!("{}", tr!("cli-pallet-failed", error = e.to_string()))
             }
+        }
 
-            Ok(Command::Advance { hours }) => game.advance(hours),
+        Ok(Command::BreakPallet { pallet }) => match game.break_pallet(pallet) {
+            Ok(orders) => println!(
+                "{}",
+                tr!(
+                    "cli-pallet-broken",
+                    pallet = pallet,
+                    orders = format!("{:?}", orders)
+                )
+            ),
+            Err(e) => println!("{}", tr!("cli-pallet-failed", error = e.to_string())),
+        },
+
+        Ok(Command::UnloadOrder { order, plane }) => {
+            if let Err(e) = game.unload_order(order, plane) {
+                println!("{}", tr!("cli-unload-failed", error = e.to_string()))
+            }
+        }
 
-            Ok(Command::Exit) => break,
+        Ok(Command::TransferOrder { order, from, to }) => {
+            match game.transfer_order(order, from, to) {
+                Ok(()) => println!(
+                    "{}",
+                    tr!("cli-order-transferred", order = order, from = from, to = to)
+                ),
+                Err(e) => println!("{}", tr!("cli-transfer-failed", error = e.to_string())),
+            }
+        }
 
-            Ok(Command::SaveGame { name }) => {
-                if let Err(e) = game.save_game(&name) {
-                    println!("Failed to save: {}", e);
-                } else {
-                    println!("Successfully loaded game: {name}");
+        Ok(Command::UnloadOrders { orders, plane }) => {
+            for o in orders {
+                if let Err(e) = game.unload_order(o, plane) {
+                    println!("{}", tr!("cli-unload-failed", error = e.to_string()));
                 }
             }
+        }
 
-            Ok(Command::LoadGame { name }) => match Game::load_game(&name) {
-                Ok(loaded_game) => {
-                    game = loaded_game;
+        Ok(Command::Refuel { plane, amount }) => {
+            let amount = match amount.as_deref().map(str::parse::<FuelAmount>) {
+                Some(Ok(amount)) => amount,
+                Some(Err(e)) => {
+                    println!("{}", tr!("cli-refuel-failed", error = e.to_string()));
+                    return true;
                 }
-                Err(e) => {
-                    println!("Failed to load game: {}", e);
+                None => FuelAmount::Full,
+            };
+            if let Err(e) = game.refuel_plane_with(plane, amount) {
+                println!("{}", tr!("cli-refuel-failed", error = e.to_string()));
+            }
+        }
+
+        Ok(Command::DepartPlane { plane, dest, speed }) => {
+            let regime = match speed.as_deref().map(str::parse::<CruiseRegime>) {
+                Some(Ok(regime)) => regime,
+                Some(Err(e)) => {
+                    println!("{}", tr!("cli-depart-failed", error = e.to_string()));
+                    return true;
                 }
+                None => CruiseRegime::Normal,
+            };
+            if let Err(e) = game.depart_plane_with_speed(plane, dest, regime) {
+                println!("{}", tr!("cli-depart-failed", error = e.to_string()));
+            }
+        }
+
+        Ok(Command::ReroutePlane { plane, dest }) => match game.reroute_plane(plane, dest) {
+            Ok(()) => println!(
+                "{}",
+                tr!("cli-plane-rerouted", plane = plane, airport = dest)
+            ),
+            Err(e) => println!("{}", tr!("cli-reroute-failed", error = e.to_string())),
+        },
+
+        Ok(Command::RepairPlane { plane }) => match game.repair_plane(plane) {
+            Ok(()) => println!("{}", tr!("cli-plane-repairing", plane = plane)),
+            Err(e) => println!("{}", tr!("cli-repair-failed", error = e.to_string())),
+        },
+
+        Ok(Command::PerformCheck { plane, check }) => match check.parse::<CheckType>() {
+            Ok(check) => match game.perform_check(plane, check) {
+                Ok(()) => println!(
+                    "{}",
+                    tr!(
+                        "cli-check-started",
+                        plane = plane,
+                        check = check.to_string()
+                    )
+                ),
+                Err(e) => println!("{}", tr!("cli-check-failed", error = e.to_string())),
             },
+            Err(e) => println!("{}", tr!("cli-check-failed", error = e.to_string())),
+        },
+
+        Ok(Command::EmergencyLand { plane }) => match game.emergency_land(plane) {
+            Ok(airport) => println!(
+                "{}",
+                tr!("cli-emergency-landing", plane = plane, airport = airport)
+            ),
+            Err(e) => println!("{}", tr!("cli-land-failed", error = e.to_string())),
+        },
+
+        Ok(Command::HoldPlane { plane }) => match game.hold_plane(plane) {
+            Ok(()) => println!("{}", tr!("cli-plane-held", plane = plane)),
+            Err(e) => println!("{}", tr!("cli-hold-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ReleasePlane { plane }) => match game.release_plane(plane) {
+            Ok(()) => println!("{}", tr!("cli-plane-released", plane = plane)),
+            Err(e) => println!("{}", tr!("cli-release-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ShowCash) => {
+            game.show_cash();
+        }
+
+        Ok(Command::ShowValue) => {
+            game.show_value();
+        }
+
+        Ok(Command::ShowHash) => {
+            game.show_hash();
+        }
+
+        Ok(Command::ShowTime) => {
+            game.show_time();
+        }
+
+        Ok(Command::ShowStats) if accessible => print_lines(accessible::stats(game)),
+
+        Ok(Command::ShowStats) => {
+            game.show_stats();
+        }
+
+        Ok(Command::ShowReport) => {
+            game.show_report();
+        }
+
+        Ok(Command::Advance { hours }) => game.advance(hours),
+
+        #[cfg(feature = "sandbox")]
+        Ok(
+            cmd @ (Command::GiveCash { .. }
+            | Command::TeleportPlane { .. }
+            | Command::SpawnOrder { .. }
+            | Command::SetTime { .. }),
+        ) => {
+            if let Err(e) = game.execute(cmd) {
+                println!("{}", e);
+            }
+        }
+
+        Ok(Command::Exit) => return false,
+
+        Ok(Command::SaveGame {
+            name,
+            sealed,
+            passphrase,
+        }) => {
+            let options = SaveOptions {
+                checksum: sealed,
+                passphrase,
+            };
+            if let Err(e) = game.save_game_with(&name, &options) {
+                println!("{}", tr!("cli-save-failed", error = e.to_string()));
+            } else {
+                println!("{}", tr!("cli-game-saved", name = name.as_str()));
+            }
+        }
 
-            Err(e) => println!("Syntax error: {}", e),
-            _ => println!("Not yet implemented"),
+        Ok(Command::ExportRun { path }) => match export_run_to_file(game, &path) {
+            Ok(()) => println!("{}", tr!("cli-run-exported", path = path.to_string())),
+            Err(e) => println!("{}", tr!("cli-export-failed", error = e.to_string())),
+        },
+
+        Ok(Command::ExportConfig { path }) => match export_config_to_file(game, &path) {
+            Ok(()) => println!("{}", tr!("cli-config-exported", path = path.to_string())),
+            Err(e) => println!("{}", tr!("cli-export-failed", error = e.to_string())),
+        },
+
+        Ok(Command::LoadGame { name, passphrase }) => {
+            match Game::load_game_with(&name, passphrase.as_deref()) {
+                Ok(loaded_game) => {
+                    *game = loaded_game;
+                    if let Err(e) = load_plugins(game, plugins) {
+                        println!("{}", e);
+                    }
+                }
+                Err(e) => {
+                    println!("{}", tr!("cli-load-game-failed", error = e.to_string()));
+                }
+            }
         }
+
+        Err(e) => println!("{}", tr!("cli-syntax-error", error = e.to_string())),
+        _ => println!("{}", tr!("cli-not-implemented")),
     }
+    true
+}
 
-    Ok(())
+fn print_lines(lines: Vec<String>) {
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+/// Report a construction project that was just started, or why it wasn't.
+fn print_construction(game: &Game, started: Result<usize, GameError>) {
+    match started {
+        Ok(id) => {
+            let hours = game
+                .constructions
+                .iter()
+                .find(|c| c.id == id)
+                .map_or(0, |c| c.done_at.saturating_sub(game.time));
+            println!(
+                "{}",
+                tr!("cli-construction-started", construction = id, hours = hours)
+            );
+        }
+        Err(e) => println!("{}", tr!("cli-construction-failed", error = e.to_string())),
+    }
 }
@@ -15,20 +15,91 @@ const KEYWORDS: &[&str] = &[
     "PLANE",
     "BUY",
     "SELL",
+    "RENAME",
+    "GROUP",
+    "GROUPS",
+    "UNGROUP",
+    "USED",
+    "MARKET",
     "LOAD",
     "ORDER",
     "ORDERS",
     "UNLOAD",
     "ALL",
+    "SPLIT",
+    "RESERVE",
+    "MIN",
+    "WITHIN",
+    "INTO",
+    "CONTRACT",
+    "CONTRACTS",
+    "CHARTER",
+    "CHARTERS",
+    "LANE",
+    "LANES",
+    "MAP",
+    "AUCTION",
+    "AUCTIONS",
+    "BID",
+    "ACCEPT",
+    "DECLINE",
+    "DEMAND",
+    "FARE",
+    "FARES",
+    "SET",
+    "CLEAR",
     "FROM",
+    "TRANSFER",
+    "PALLET",
+    "BREAK",
     "ON",
     "DEPART",
+    "REROUTE",
+    "TO",
+    "EMERGENCY",
+    "LAND",
+    "SPEED",
+    "ECON",
+    "NORMAL",
+    "DASH",
     "HOLD",
+    "RELEASE",
+    "MAINTENANCE",
+    "CHECK",
+    "REPAIR",
+    "CHARGE",
+    "CHARGER",
+    "INSTALL",
+    "BUILD",
+    "AIRPORT",
+    "EXTEND",
+    "RUNWAY",
+    "CONSTRUCTION",
+    "CONCESSION",
+    "CONCESSIONS",
+    "WAREHOUSE",
+    "PARTS",
+    "SHIP",
+    "PILOT",
+    "PILOTS",
+    "CREW",
+    "HIRE",
+    "FIRE",
+    "ASSIGN",
     "ADVANCE",
     "CASH",
+    "VALUE",
+    "HASH",
     "TIME",
     "STATS",
+    "REPORT",
     "EXIT",
+    "SAVE",
+    "SEALED",
+    "PASSPHRASE",
+    "EXPORT",
+    "CONFIG",
+    "RUN",
     "SparrowLight",
     "FalconJet",
     "CometRegional",
@@ -37,8 +108,16 @@ const KEYWORDS: &[&str] = &[
     "Goliath",
     "Zephyr",
     "Lightning",
+    "VoltCommuter",
+    "AmpereHauler",
 ];
 
+/// Developer commands, only completed when built with the `sandbox` feature
+#[cfg(feature = "sandbox")]
+const SANDBOX_KEYWORDS: &[&str] = &["GIVE", "TELEPORT", "SPAWN", "DEADLINE"];
+#[cfg(not(feature = "sandbox"))]
+const SANDBOX_KEYWORDS: &[&str] = &[];
+
 pub fn print_banner() {
     println!(
         r#"
@@ -63,7 +142,11 @@ pub struct LineReaderHelper {
 
 impl LineReaderHelper {
     pub fn new() -> Self {
-        let commands = KEYWORDS.iter().map(|&s| s.to_string()).collect();
+        let commands = KEYWORDS
+            .iter()
+            .chain(SANDBOX_KEYWORDS)
+            .map(|&s| s.to_string())
+            .collect();
         LineReaderHelper { commands }
     }
 }
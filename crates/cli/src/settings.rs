@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use rusty_runways_core::tr;
+
+/// Environment variable pointing at the settings file.
+pub const SETTINGS_ENV: &str = "RUSTY_RUNWAYS_CLI_SETTINGS";
+
+/// Preferences kept between CLI sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CliSettings {
+    /// Print short labeled lines instead of the banner and tables
+    pub accessible: bool,
+    /// Save the game on exit and offer to resume it at the next launch
+    pub autosave: bool,
+}
+
+impl CliSettings {
+    /// Read settings from `path`, falling back to the defaults if the file is missing
+    /// or unreadable.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write settings to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let write_failed = |e: std::io::Error| {
+            tr!(
+                "cli-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            )
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(write_failed)?;
+        }
+        let text = serde_json::to_string_pretty(self).expect("settings serialize");
+        std::fs::write(path, text).map_err(write_failed)
+    }
+}
+
+/// Location of the settings file.
+///
+/// [`SETTINGS_ENV`] if set, else `rusty_runways/cli.json` in `$XDG_CONFIG_HOME` or
+/// `~/.config`, else `rusty_runways_cli.json` in the working directory.
+pub fn settings_path() -> PathBuf {
+    if let Ok(path) = std::env::var(SETTINGS_ENV) {
+        return path.into();
+    }
+    let config_dir = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| Path::new(&home).join(".config")));
+    match config_dir {
+        Ok(dir) => dir.join("rusty_runways").join("cli.json"),
+        Err(_) => PathBuf::from("rusty_runways_cli.json"),
+    }
+}
+
+/// Location of the REPL's command history, next to the settings file
+/// (`cli_history.txt` beside `cli.json`).
+pub fn history_path() -> PathBuf {
+    beside_settings("history.txt")
+}
+
+/// Location of the autosaved session, next to the settings file
+/// (`cli_session.json` beside `cli.json`).
+pub fn session_path() -> PathBuf {
+    beside_settings("session.json")
+}
+
+fn beside_settings(suffix: &str) -> PathBuf {
+    let settings = settings_path();
+    let stem = settings
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    settings.with_file_name(format!("{}_{}", stem, suffix))
+}
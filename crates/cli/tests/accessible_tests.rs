@@ -0,0 +1,70 @@
+use rusty_runways_cli::accessible;
+use rusty_runways_cli::settings::CliSettings;
+use rusty_runways_core::Game;
+
+#[test]
+fn settings_round_trip_through_a_file() {
+    let dir = std::env::temp_dir().join(format!("rr_settings_{}", std::process::id()));
+    let path = dir.join("cli.json");
+    assert_eq!(CliSettings::load(&path), CliSettings::default());
+
+    let settings = CliSettings {
+        accessible: true,
+        ..Default::default()
+    };
+    settings.save(&path).unwrap();
+    let loaded = CliSettings::load(&path);
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(loaded.accessible);
+}
+
+#[test]
+fn planes_read_as_labeled_sentences() {
+    let mut game = Game::new(1, Some(5), 1_000_000.0);
+    let (home, _) = game
+        .map
+        .airports
+        .iter()
+        .find(|(_, c)| *c == game.airplanes[0].location)
+        .unwrap();
+    let home = home.name.clone();
+    let lines = accessible::plane(&game, 0).unwrap();
+    assert!(
+        lines[0].starts_with(&format!("Plane 0: parked at {}, fuel 100 percent", home)),
+        "{}",
+        lines[0]
+    );
+
+    let (destination, _) = game
+        .map
+        .airports
+        .iter()
+        .find(|(_, c)| *c != game.airplanes[0].location)
+        .unwrap();
+    game.execute_str(&format!("DEPART PLANE 0 {}", destination.id))
+        .ok();
+    let lines = accessible::planes(&game);
+    assert_eq!(lines[0], "1 planes");
+    assert!(lines.iter().all(|l| !l.contains('|')));
+    assert!(accessible::plane(&game, 9).is_err());
+}
+
+#[test]
+fn listings_have_no_tables() {
+    let mut game = Game::new(1, Some(5), 1_000_000.0);
+    game.advance(48);
+    let lines = [
+        accessible::airports(&game, true),
+        accessible::models(&game),
+        accessible::stats(&game),
+        accessible::distances(&game, 0).unwrap(),
+    ]
+    .concat();
+    assert!(lines.iter().any(|l| l.starts_with("Airport 0: ")));
+    assert!(lines.iter().any(|l| l.starts_with("Day 1: income ")));
+    assert!(
+        lines
+            .iter()
+            .all(|l| !l.contains('|') && !l.contains("---") && !l.contains("  "))
+    );
+}
@@ -1,5 +1,13 @@
 use clap::Parser;
-use rusty_runways_cli::cli::{Cli, init_game_from_cli};
+use rusty_runways_cli::cli::{
+    Cli, CliCommand, export_config_to_file, export_run_to_file, init_game_from_cli, offer_resume,
+    validate_config_file,
+};
+use rusty_runways_cli::daemon::DEFAULT_SOCKET;
+use rusty_runways_core::Game;
+use rusty_runways_core::save::SaveOptions;
+use rusty_runways_core::statistics::RunSummary;
+use rusty_runways_core::utils::map::WorldStyle;
 
 #[test]
 fn cli_requires_seed_and_n() {
@@ -10,6 +18,33 @@ fn cli_requires_seed_and_n() {
     );
 }
 
+#[test]
+fn cli_one_shot_commands_and_load() {
+    let cli = Cli::try_parse_from([
+        "test",
+        "--load",
+        "mysave",
+        "-c",
+        "SHOW PLANES",
+        "--command",
+        "ADVANCE 24",
+    ])
+    .unwrap();
+    assert_eq!(cli.load.as_deref(), Some("mysave"));
+    assert_eq!(cli.commands, ["SHOW PLANES", "ADVANCE 24"]);
+    assert!(!cli.picks_no_world());
+
+    assert!(Cli::try_parse_from(["test", "--load", "a", "--seed", "1"]).is_err());
+    assert!(Cli::try_parse_from(["test", "--daemon", "-c", "SHOW TIME"]).is_err());
+
+    let cli = Cli::try_parse_from(["test", "--load", "rr_no_such_save"]).unwrap();
+    assert!(
+        init_game_from_cli(cli)
+            .unwrap_err()
+            .contains("rr_no_such_save")
+    );
+}
+
 #[test]
 fn cli_requires_n_and_seed() {
     let cli = Cli::try_parse_from(["test", "--n", "5"]).unwrap();
@@ -72,3 +107,156 @@ fn cli_rejects_non_numeric_cash() {
     let res = Cli::try_parse_from(["test", "--seed", "1", "--n", "5", "--c", "abc"]);
     assert!(res.is_err());
 }
+
+#[test]
+fn cli_accepts_world_style() {
+    let cli = Cli::try_parse_from([
+        "test",
+        "--seed",
+        "4",
+        "--n",
+        "10",
+        "--world-style",
+        "continents",
+    ])
+    .unwrap();
+    let game = init_game_from_cli(cli).unwrap();
+    assert_eq!(game.map.world_style, WorldStyle::Continents);
+    assert_eq!(game.map.num_airports, 10);
+}
+
+#[test]
+fn cli_rejects_unknown_world_style() {
+    let res = Cli::try_parse_from(["test", "--world-style", "islands"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn cli_accepts_lang() {
+    let cli = Cli::try_parse_from(["test", "--lang", "de"]).unwrap();
+    assert_eq!(cli.lang.as_deref(), Some("de"));
+    let cli = Cli::try_parse_from(["test"]).unwrap();
+    assert!(cli.lang.is_none());
+}
+
+#[test]
+fn cli_accessible_flag_is_optional_bool() {
+    let cli = Cli::try_parse_from(["test", "--accessible"]).unwrap();
+    assert_eq!(cli.accessible, Some(true));
+    let cli = Cli::try_parse_from(["test", "--accessible=false"]).unwrap();
+    assert_eq!(cli.accessible, Some(false));
+    let cli = Cli::try_parse_from(["test"]).unwrap();
+    assert!(cli.accessible.is_none());
+}
+
+#[test]
+fn cli_autosave_flag_and_resume_offer() {
+    let cli = Cli::try_parse_from(["test", "--autosave"]).unwrap();
+    assert_eq!(cli.autosave, Some(true));
+    assert!(cli.picks_no_world());
+    let cli = Cli::try_parse_from(["test", "--seed", "1", "--n", "5"]).unwrap();
+    assert!(cli.autosave.is_none());
+    assert!(!cli.picks_no_world());
+
+    let path = std::env::temp_dir()
+        .join(format!("rr_session_{}", std::process::id()))
+        .join("cli_session.json");
+    assert!(offer_resume(&path, &mut "y\n".as_bytes()).is_none());
+
+    let mut game = Game::new(4, Some(5), 800_000.0);
+    game.advance(30);
+    game.save_game_to(&path, &SaveOptions::plain()).unwrap();
+    let declined = offer_resume(&path, &mut "n\n".as_bytes());
+    let resumed = offer_resume(&path, &mut "\n".as_bytes());
+    std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    assert!(declined.is_none());
+    let resumed = resumed.unwrap();
+    assert_eq!(resumed.time, 30);
+    assert_eq!(resumed.player.cash, game.player.cash);
+}
+
+#[test]
+fn cli_daemon_socket_defaults() {
+    let cli = Cli::try_parse_from(["test", "--daemon"]).unwrap();
+    assert_eq!(cli.daemon.as_deref(), Some(DEFAULT_SOCKET));
+    let cli = Cli::try_parse_from(["test", "--daemon", "/tmp/rr.sock"]).unwrap();
+    assert_eq!(cli.daemon.as_deref(), Some("/tmp/rr.sock"));
+    let cli = Cli::try_parse_from(["test"]).unwrap();
+    assert!(cli.daemon.is_none());
+    assert!(Cli::try_parse_from(["test", "--metrics", "127.0.0.1:9100"]).is_err());
+}
+
+#[test]
+fn exported_config_reloads_same_world() {
+    let game = Game::new(11, Some(6), 900_000.0);
+    let path = std::env::temp_dir().join(format!("rr_export_{}.yaml", std::process::id()));
+    let path_str = path.to_string_lossy().to_string();
+    export_config_to_file(&game, &path_str).unwrap();
+
+    let cli = Cli::try_parse_from(["test", "--config", path_str.as_str()]).unwrap();
+    let reloaded = init_game_from_cli(cli).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(reloaded.map.num_airports, 6);
+    assert_eq!(reloaded.player.cash, 900_000.0);
+    for ((a, ca), (b, cb)) in game.map.airports.iter().zip(reloaded.map.airports.iter()) {
+        assert_eq!(a.name, b.name);
+        assert_eq!(ca, cb);
+        assert_eq!(a.orders.len(), b.orders.len());
+    }
+}
+
+#[test]
+fn exported_run_summary_verifies() {
+    let mut game = Game::new(11, Some(6), 900_000.0);
+    game.execute_str("ADVANCE 48").unwrap();
+    let path = std::env::temp_dir().join(format!("rr_run_{}.json", std::process::id()));
+    let path_str = path.to_string_lossy().to_string();
+    export_run_to_file(&game, &path_str).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    let summary: RunSummary = serde_json::from_str(&text).unwrap();
+    assert!(summary.verify());
+    assert_eq!(summary.seed, 11);
+    assert_eq!(summary.days_played, 2);
+    assert_eq!(summary.commands, 1);
+}
+
+#[test]
+fn cli_parses_validate_subcommand() {
+    let cli = Cli::try_parse_from(["test", "validate", "world.yaml"]).unwrap();
+    assert!(matches!(cli.command, Some(CliCommand::Validate { path }) if path == "world.yaml"));
+}
+
+#[test]
+fn validate_config_file_lists_problems() {
+    let dir = std::env::temp_dir();
+    let bad = dir.join(format!("rr_validate_bad_{}.yaml", std::process::id()));
+    std::fs::write(
+        &bad,
+        "num_airports: 0\ngameplay:\n  fuel_interval_hours: 0\nstarting_fleet:\n  - { model: SparrowLight, home_airport: 0, fuel_level: 2.0 }\n",
+    )
+    .unwrap();
+    let problems = validate_config_file(bad.to_str().unwrap());
+    std::fs::remove_file(&bad).ok();
+    assert_eq!(problems.len(), 3);
+    assert!(problems[0].starts_with("gameplay.fuel_interval_hours:"));
+    assert!(problems[1].starts_with("num_airports:"));
+    assert!(problems[2].starts_with("starting_fleet[0].fuel_level:"));
+
+    let good = dir.join(format!("rr_validate_good_{}.yaml", std::process::id()));
+    std::fs::write(&good, "seed: 3\nnum_airports: 4\n").unwrap();
+    let problems = validate_config_file(good.to_str().unwrap());
+    std::fs::remove_file(&good).ok();
+    assert!(problems.is_empty());
+
+    let missing = validate_config_file("definitely/not/here.yaml");
+    assert!(missing[0].contains("failed to read config"));
+}
+
+#[test]
+fn cli_accepts_schema_flag() {
+    let cli = Cli::try_parse_from(["test", "--schema"]).unwrap();
+    assert!(cli.schema);
+}
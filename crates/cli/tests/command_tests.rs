@@ -34,6 +34,20 @@ fn parse_invalid_command() {
     assert!(err.is_err());
 }
 
+#[test]
+fn parse_suggests_close_commands() {
+    let err = parse_command("DEPRT PLANE 2 3").unwrap_err().to_string();
+    assert!(err.ends_with("did you mean DEPART PLANE?"), "{}", err);
+    let err = parse_command("show planes").unwrap_err().to_string();
+    assert!(err.ends_with("did you mean SHOW PLANES?"), "{}", err);
+
+    // nothing close, or a known command with bad arguments
+    for line in ["DO SOMETHING", "DEPART PLANE 2"] {
+        let err = parse_command(line).unwrap_err().to_string();
+        assert!(!err.contains("did you mean"), "{}", err);
+    }
+}
+
 #[test]
 fn parse_maintenance_command() {
     let cmd = parse_command("MAINTENANCE 3").unwrap();
@@ -46,6 +60,204 @@ fn parse_unload_all_command() {
     assert!(matches!(cmd, Command::UnloadAll { plane } if plane == 2));
 }
 
+#[test]
+fn parse_split_order_command() {
+    let cmd = parse_command("SPLIT ORDER 12 INTO 3000kg").unwrap();
+    assert!(matches!(cmd, Command::SplitOrder { order: 12, weight } if weight == 3000.0));
+    let cmd = parse_command("SPLIT ORDER 12 INTO 250").unwrap();
+    assert!(matches!(cmd, Command::SplitOrder { order: 12, weight } if weight == 250.0));
+}
+
+#[test]
+fn parse_order_board_commands() {
+    let cmd = parse_command("SHOW ORDERS ALL").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ShowAllOrders {
+            country: None,
+            min_value_per_kg: None,
+            within_hours: None
+        }
+    ));
+    let cmd = parse_command("SHOW ORDERS ALL TO France MIN 2.5 WITHIN 48").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ShowAllOrders {
+            country: Some(ref c),
+            min_value_per_kg: Some(min),
+            within_hours: Some(48)
+        } if c == "France" && min == 2.5
+    ));
+    assert!(parse_command("SHOW ORDERS ALL MIN").is_err());
+    assert!(parse_command("SHOW ORDERS ALL NEAR 3").is_err());
+    let cmd = parse_command("RESERVE ORDER 17").unwrap();
+    assert!(matches!(cmd, Command::ReserveOrder { order: 17 }));
+}
+
+#[test]
+fn parse_contract_commands() {
+    let cmd = parse_command("SHOW CONTRACTS").unwrap();
+    assert!(matches!(cmd, Command::ShowContracts));
+    let cmd = parse_command("LOAD CONTRACT 4 1500kg ON 2").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::LoadContract { contract: 4, weight, plane: 2 } if weight == 1500.0
+    ));
+}
+
+#[test]
+fn parse_charter_commands() {
+    let cmd = parse_command("SHOW CHARTERS").unwrap();
+    assert!(matches!(cmd, Command::ShowCharters));
+    let cmd = parse_command("ACCEPT CHARTER 3 ON 1").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::AcceptCharter {
+            charter: 3,
+            plane: 1
+        }
+    ));
+    assert!(parse_command("ACCEPT CHARTER three ON 1").is_err());
+}
+
+#[test]
+fn parse_lane_commands() {
+    let cmd = parse_command("SHOW LANES").unwrap();
+    assert!(matches!(cmd, Command::ShowLanes));
+    let cmd = parse_command("ACCEPT LANE 4").unwrap();
+    assert!(matches!(cmd, Command::AcceptLane { deal: 4 }));
+    let cmd = parse_command("DECLINE LANE 2").unwrap();
+    assert!(matches!(cmd, Command::DeclineLane { deal: 2 }));
+    assert!(parse_command("ACCEPT LANE four").is_err());
+}
+
+#[test]
+fn parse_auction_commands() {
+    let cmd = parse_command("SHOW AUCTIONS").unwrap();
+    assert!(matches!(cmd, Command::ShowAuctions));
+    let cmd = parse_command("BID AUCTION 3 $12500").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::BidAuction { auction: 3, amount } if amount == 12_500.0
+    ));
+    assert!(parse_command("BID AUCTION 3 cheap").is_err());
+}
+
+#[test]
+fn parse_show_demand() {
+    let cmd = parse_command("SHOW DEMAND FROM 4").unwrap();
+    assert!(matches!(cmd, Command::ShowDemand { airport: 4 }));
+    assert!(parse_command("SHOW DEMAND FROM AAA").is_err());
+}
+
+#[test]
+fn parse_fare_commands() {
+    let cmd = parse_command("SHOW FARES").unwrap();
+    assert!(matches!(cmd, Command::ShowFares));
+    let cmd = parse_command("SET FARE 0 2 $150.5").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::SetFare {
+            origin: 0,
+            destination: 2,
+            fare
+        } if fare == 150.5
+    ));
+    let cmd = parse_command("CLEAR FARE 0 2").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ClearFare {
+            origin: 0,
+            destination: 2
+        }
+    ));
+    assert!(parse_command("SET FARE 0 2 cheap").is_err());
+}
+
+#[test]
+fn parse_used_market_commands() {
+    let cmd = parse_command("SHOW MARKET").unwrap();
+    assert!(matches!(cmd, Command::ShowMarket));
+    let cmd = parse_command("BUY USED 7").unwrap();
+    assert!(matches!(cmd, Command::BuyUsed { listing: 7 }));
+    assert!(parse_command("BUY USED seven").is_err());
+}
+
+#[test]
+fn parse_rename_plane_keeps_spaces_in_name() {
+    let cmd = parse_command("RENAME PLANE 2 \"Spirit of AAB\"").unwrap();
+    assert!(matches!(cmd, Command::RenamePlane { plane: 2, ref name } if name == "Spirit of AAB"));
+    let cmd = parse_command("RENAME PLANE 0 N123RR").unwrap();
+    assert!(matches!(cmd, Command::RenamePlane { plane: 0, ref name } if name == "N123RR"));
+    assert!(parse_command("RENAME PLANE 2").is_err());
+}
+
+#[test]
+fn parse_group_commands() {
+    let cmd = parse_command("GROUP PLANE 3 feeders").unwrap();
+    assert!(matches!(cmd, Command::GroupPlane { plane: 3, ref group } if group == "feeders"));
+    let cmd = parse_command("UNGROUP PLANE 3 feeders").unwrap();
+    assert!(matches!(cmd, Command::UngroupPlane { plane: 3, ref group } if group == "feeders"));
+    let cmd = parse_command("REFUEL GROUP heavies").unwrap();
+    assert!(matches!(cmd, Command::RefuelGroup { ref group } if group == "heavies"));
+    let cmd = parse_command("SHOW GROUP heavies").unwrap();
+    assert!(matches!(cmd, Command::ShowGroup { ref group } if group == "heavies"));
+    let cmd = parse_command("SHOW GROUPS").unwrap();
+    assert!(matches!(cmd, Command::ShowGroups));
+}
+
+#[test]
+fn parse_reroute_plane() {
+    let cmd = parse_command("REROUTE PLANE 3 TO 7").unwrap();
+    assert!(matches!(cmd, Command::ReroutePlane { plane: 3, dest: 7 }));
+    assert!(parse_command("REROUTE PLANE 3 7").is_err());
+}
+
+#[test]
+fn parse_maintenance_checks() {
+    let cmd = parse_command("CHECK PLANE 1 B").unwrap();
+    assert!(matches!(cmd, Command::PerformCheck { plane: 1, ref check } if check == "B"));
+    let cmd = parse_command("HIRE PILOT 3").unwrap();
+    assert!(matches!(cmd, Command::HirePilot { airport: 3 }));
+    let cmd = parse_command("ASSIGN PILOT 1 TO 2").unwrap();
+    assert!(matches!(cmd, Command::AssignPilot { pilot: 1, plane: 2 }));
+    let cmd = parse_command("FIRE PILOT 1").unwrap();
+    assert!(matches!(cmd, Command::FirePilot { pilot: 1 }));
+    assert!(matches!(
+        parse_command("SHOW PILOTS").unwrap(),
+        Command::ShowPilots
+    ));
+    let cmd = parse_command("SHOW PARTS").unwrap();
+    assert!(matches!(cmd, Command::ShowParts));
+    let cmd = parse_command("BUY PARTS 2 5").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::BuyParts {
+            airport: 2,
+            kits: 5
+        }
+    ));
+    let cmd = parse_command("SHIP PARTS 3 TO 1 ON 0").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ShipParts {
+            kits: 3,
+            dest: 1,
+            plane: 0
+        }
+    ));
+    let cmd = parse_command("REPAIR PLANE 4").unwrap();
+    assert!(matches!(cmd, Command::RepairPlane { plane: 4 }));
+    let cmd = parse_command("SHOW MAINTENANCE").unwrap();
+    assert!(matches!(cmd, Command::ShowMaintenance));
+}
+
+#[test]
+fn parse_emergency_land() {
+    let cmd = parse_command("EMERGENCY LAND 2").unwrap();
+    assert!(matches!(cmd, Command::EmergencyLand { plane: 2 }));
+}
+
 #[test]
 fn parse_empty_advances_one_hour() {
     let cmd = parse_command("").unwrap();
@@ -81,7 +293,19 @@ fn parse_sell_plane_command() {
 #[test]
 fn parse_depart_plane_command() {
     let cmd = parse_command("DEPART PLANE 4 1").unwrap();
-    assert!(matches!(cmd, Command::DepartPlane { plane, dest } if plane == 4 && dest == 1));
+    assert!(matches!(
+        cmd,
+        Command::DepartPlane { plane, dest, speed: None } if plane == 4 && dest == 1
+    ));
+}
+
+#[test]
+fn parse_depart_plane_with_speed() {
+    let cmd = parse_command("DEPART PLANE 2 5 SPEED ECON").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::DepartPlane { plane: 2, dest: 5, speed: Some(ref s) } if s == "ECON"
+    ));
 }
 
 #[test]
@@ -112,7 +336,19 @@ fn parse_show_distances_command() {
 #[test]
 fn parse_refuel_plane_command() {
     let cmd = parse_command("REFUEL PLANE 3").unwrap();
-    assert!(matches!(cmd, Command::Refuel { plane: 3 }));
+    assert!(matches!(
+        cmd,
+        Command::Refuel {
+            plane: 3,
+            amount: None
+        }
+    ));
+    let cmd = parse_command("REFUEL PLANE 2 500").unwrap();
+    assert!(matches!(cmd, Command::Refuel { plane: 2, amount: Some(ref a) } if a == "500"));
+    let cmd = parse_command("REFUEL PLANE 2 TO 80%").unwrap();
+    assert!(matches!(cmd, Command::Refuel { plane: 2, amount: Some(ref a) } if a == "80%"));
+    assert!(parse_command("REFUEL PLANE 2 TO 80").is_err());
+    assert!(parse_command("REFUEL PLANE 2 lots").is_err());
 }
 
 #[test]
@@ -129,6 +365,8 @@ fn parse_unload_order_commands() {
 fn parse_hold_plane_command() {
     let cmd = parse_command("HOLD PLANE 4").unwrap();
     assert!(matches!(cmd, Command::HoldPlane { plane: 4 }));
+    let cmd = parse_command("RELEASE PLANE 4").unwrap();
+    assert!(matches!(cmd, Command::ReleasePlane { plane: 4 }));
 }
 
 #[test]
@@ -137,6 +375,14 @@ fn parse_show_info_commands() {
         parse_command("SHOW CASH").unwrap(),
         Command::ShowCash
     ));
+    assert!(matches!(
+        parse_command("SHOW VALUE").unwrap(),
+        Command::ShowValue
+    ));
+    assert!(matches!(
+        parse_command("SHOW HASH").unwrap(),
+        Command::ShowHash
+    ));
     assert!(matches!(
         parse_command("SHOW TIME").unwrap(),
         Command::ShowTime
@@ -145,17 +391,172 @@ fn parse_show_info_commands() {
         parse_command("SHOW STATS").unwrap(),
         Command::ShowStats
     ));
+    assert!(matches!(
+        parse_command("SHOW REPORT").unwrap(),
+        Command::ShowReport
+    ));
 }
 
 #[test]
 fn parse_save_and_load_commands() {
     let cmd = parse_command("SAVE testgame").unwrap();
-    assert!(matches!(cmd, Command::SaveGame { name } if name == "testgame"));
+    assert!(matches!(
+        cmd,
+        Command::SaveGame { name, sealed: false, passphrase: None } if name == "testgame"
+    ));
     let cmd = parse_command("LOAD testgame").unwrap();
-    assert!(matches!(cmd, Command::LoadGame { name } if name == "testgame"));
+    assert!(matches!(
+        cmd,
+        Command::LoadGame { name, passphrase: None } if name == "testgame"
+    ));
+}
+
+#[test]
+fn parse_sealed_and_encrypted_saves() {
+    let cmd = parse_command("SAVE cup SEALED").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::SaveGame {
+            sealed: true,
+            passphrase: None,
+            ..
+        }
+    ));
+    let cmd = parse_command("SAVE cup WITH PASSPHRASE hunter2").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::SaveGame { sealed: true, passphrase: Some(p), .. } if p == "hunter2"
+    ));
+    let cmd = parse_command("LOAD cup WITH PASSPHRASE hunter2").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::LoadGame { name, passphrase: Some(p) } if name == "cup" && p == "hunter2"
+    ));
+}
+
+#[test]
+fn parse_export_config_command() {
+    let cmd = parse_command("EXPORT CONFIG world.yaml").unwrap();
+    assert!(matches!(cmd, Command::ExportConfig { path } if path == "world.yaml"));
+    assert!(parse_command("EXPORT CONFIG").is_err());
+}
+
+#[test]
+fn parse_export_run_command() {
+    let cmd = parse_command("EXPORT RUN run.json").unwrap();
+    assert!(matches!(cmd, Command::ExportRun { path } if path == "run.json"));
+    assert!(parse_command("EXPORT RUN").is_err());
 }
 
 #[test]
 fn parse_advance_invalid_number_errors() {
     assert!(parse_command("ADVANCE two").is_err());
 }
+
+#[test]
+fn parse_ground_crew() {
+    let cmd = parse_command("HIRE CREW 4").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::HireCrew {
+            airport: 4,
+            count: 1
+        }
+    ));
+    let cmd = parse_command("HIRE CREW 4 3").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::HireCrew {
+            airport: 4,
+            count: 3
+        }
+    ));
+    let cmd = parse_command("FIRE CREW 4 2").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::FireCrew {
+            airport: 4,
+            count: 2
+        }
+    ));
+    assert!(parse_command("HIRE CREW 4 many").is_err());
+}
+
+#[test]
+fn parse_charging() {
+    let cmd = parse_command("CHARGE PLANE 3").unwrap();
+    assert!(matches!(cmd, Command::ChargePlane { plane: 3 }));
+    let cmd = parse_command("INSTALL CHARGER 5").unwrap();
+    assert!(matches!(cmd, Command::InstallCharger { airport: 5 }));
+    assert!(parse_command("INSTALL CHARGER x").is_err());
+}
+
+#[test]
+fn parse_construction() {
+    let cmd = parse_command("BUILD AIRPORT 4200 1350.5").unwrap();
+    assert!(matches!(cmd, Command::BuildAirport { x, y } if x == 4200.0 && y == 1350.5));
+    let cmd = parse_command("EXTEND RUNWAY 12").unwrap();
+    assert!(matches!(cmd, Command::ExtendRunway { airport: 12 }));
+    let cmd = parse_command("SHOW CONSTRUCTION").unwrap();
+    assert!(matches!(cmd, Command::ShowConstruction));
+    assert!(parse_command("BUILD AIRPORT here 5").is_err());
+}
+
+#[test]
+fn parse_show_map() {
+    let cmd = parse_command("SHOW MAP").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ShowMap {
+            width: 80,
+            height: 24
+        }
+    ));
+    let cmd = parse_command("SHOW MAP 120 40").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::ShowMap {
+            width: 120,
+            height: 40
+        }
+    ));
+    assert!(parse_command("SHOW MAP wide 40").is_err());
+}
+
+#[test]
+fn parse_concessions() {
+    let cmd = parse_command("BUY CONCESSION 7").unwrap();
+    assert!(matches!(cmd, Command::BuyConcession { airport: 7 }));
+    let cmd = parse_command("SHOW CONCESSIONS").unwrap();
+    assert!(matches!(cmd, Command::ShowConcessions));
+    assert!(parse_command("BUY CONCESSION x").is_err());
+}
+
+#[test]
+fn parse_show_warehouse() {
+    let cmd = parse_command("SHOW WAREHOUSE").unwrap();
+    assert!(matches!(cmd, Command::ShowWarehouse));
+}
+
+#[test]
+fn parse_transfer_order() {
+    let cmd = parse_command("TRANSFER ORDER 12 FROM 2 TO 5").unwrap();
+    assert!(matches!(
+        cmd,
+        Command::TransferOrder {
+            order: 12,
+            from: 2,
+            to: 5
+        }
+    ));
+    assert!(parse_command("TRANSFER ORDER 12 FROM 2").is_err());
+}
+
+#[test]
+fn parse_pallets() {
+    let cmd = parse_command("BUILD PALLET [3,4,9]").unwrap();
+    assert!(matches!(cmd, Command::BuildPallet { ref orders } if orders == &vec![3, 4, 9]));
+    let cmd = parse_command("BREAK PALLET 12").unwrap();
+    assert!(matches!(cmd, Command::BreakPallet { pallet: 12 }));
+    assert!(parse_command("BREAK PALLET x").is_err());
+}
@@ -0,0 +1,83 @@
+use rusty_runways_cli::daemon::{Reply, respond};
+use rusty_runways_core::Game;
+use serde_json::Value;
+
+fn lines(reply: Reply) -> Vec<Value> {
+    match reply {
+        Reply::Lines(lines) => lines,
+        Reply::Close => panic!("connection closed"),
+    }
+}
+
+#[test]
+fn observe_returns_the_observation() {
+    let mut game = Game::new(1, Some(4), 650_000.0);
+    let out = lines(respond(&mut game, "OBSERVE\n", &[]));
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0]["ok"], true);
+    assert_eq!(out[0]["data"]["airports"].as_array().unwrap().len(), 4);
+
+    let version = game.state_version();
+    let out = lines(respond(
+        &mut game,
+        &format!("observe since {}", version),
+        &[],
+    ));
+    assert!(out[0]["data"]["planes"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn commands_report_events_then_result() {
+    let mut game = Game::new(1, Some(4), 650_000.0);
+    let out = lines(respond(&mut game, "ADVANCE 24", &[]));
+    let (result, events) = out.split_last().unwrap();
+    assert_eq!(result["ok"], true);
+    assert!(result["data"].is_null());
+    assert!(events.iter().any(|e| e["event"]["DailyReport"].is_object()));
+    assert_eq!(game.time, 24);
+}
+
+#[test]
+fn failures_are_reported() {
+    let mut game = Game::new(1, Some(4), 650_000.0);
+    let out = lines(respond(&mut game, "FLY AWAY", &[]));
+    assert_eq!(out[0]["ok"], false);
+    assert!(out[0]["error"].is_string());
+
+    let out = lines(respond(&mut game, "SELL PLANE 99", &[]));
+    assert_eq!(out[0]["ok"], false);
+}
+
+#[test]
+fn exit_closes_and_blank_lines_are_ignored() {
+    let mut game = Game::new(1, Some(4), 650_000.0);
+    assert!(lines(respond(&mut game, "  \n", &[])).is_empty());
+    assert!(matches!(respond(&mut game, "exit", &[]), Reply::Close));
+}
+
+#[cfg(unix)]
+#[test]
+fn serves_a_socket_connection() {
+    use rusty_runways_cli::daemon::serve_connection;
+    use rusty_runways_core::metrics::ServerMetrics;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+    use std::sync::Mutex;
+
+    let game = Mutex::new(Game::new(1, Some(4), 650_000.0));
+    let (server, mut client) = UnixStream::pair().unwrap();
+    client.write_all(b"ADVANCE 1\nEXIT\n").unwrap();
+    let metrics = ServerMetrics::default();
+    serve_connection(&game, server, &[], &metrics).unwrap();
+
+    let replies: Vec<Value> = BufReader::new(client)
+        .lines()
+        .map(|l| serde_json::from_str(&l.unwrap()).unwrap())
+        .collect();
+    assert_eq!(replies.last().unwrap()["ok"], true);
+    assert_eq!(game.lock().unwrap().time, 1);
+
+    let text = metrics.render([(0, &*game.lock().unwrap())]);
+    assert!(text.contains("rusty_runways_hours_simulated_total 1\n"));
+    assert!(text.contains("rusty_runways_commands_total 1\n"));
+}
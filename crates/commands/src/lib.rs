@@ -1,32 +1,273 @@
 use std::fmt;
+use strsim::levenshtein;
 
 #[derive(Debug)]
 pub enum Command {
-    ShowAirports { with_orders: bool },
-    ShowAirport { id: usize, with_orders: bool },
+    ShowAirports {
+        with_orders: bool,
+    },
+    ShowAirport {
+        id: usize,
+        with_orders: bool,
+    },
     ShowAirplanes,
-    ShowAirplane { id: usize },
-    ShowDistances { plane_id: usize },
-    BuyPlane { model: String, airport: usize },
-    SellPlane { plane: usize },
-    LoadOrder { order: usize, plane: usize },
-    LoadOrders { orders: Vec<usize>, plane: usize },
-    UnloadOrder { order: usize, plane: usize },
-    UnloadOrders { orders: Vec<usize>, plane: usize },
-    UnloadAll { plane: usize },
-    Refuel { plane: usize },
-    DepartPlane { plane: usize, dest: usize },
-    HoldPlane { plane: usize },
-    Advance { hours: u64 },
+    ShowAirplane {
+        id: usize,
+    },
+    ShowDistances {
+        plane_id: usize,
+    },
+    BuyPlane {
+        model: String,
+        airport: usize,
+    },
+    SellPlane {
+        plane: usize,
+    },
+    ShowMarket,
+    RenamePlane {
+        plane: usize,
+        name: String,
+    },
+    GroupPlane {
+        plane: usize,
+        group: String,
+    },
+    UngroupPlane {
+        plane: usize,
+        group: String,
+    },
+    ShowGroup {
+        group: String,
+    },
+    ShowGroups,
+    RefuelGroup {
+        group: String,
+    },
+    ChargePlane {
+        plane: usize,
+    },
+    InstallCharger {
+        airport: usize,
+    },
+    BuildAirport {
+        x: f32,
+        y: f32,
+    },
+    ExtendRunway {
+        airport: usize,
+    },
+    ShowConstruction,
+    BuyConcession {
+        airport: usize,
+    },
+    ShowConcessions,
+    ShowWarehouse,
+    BuyUsed {
+        listing: usize,
+    },
+    LoadOrder {
+        order: usize,
+        plane: usize,
+    },
+    LoadOrders {
+        orders: Vec<usize>,
+        plane: usize,
+    },
+    UnloadOrder {
+        order: usize,
+        plane: usize,
+    },
+    UnloadOrders {
+        orders: Vec<usize>,
+        plane: usize,
+    },
+    UnloadAll {
+        plane: usize,
+    },
+    TransferOrder {
+        order: usize,
+        from: usize,
+        to: usize,
+    },
+    SplitOrder {
+        order: usize,
+        weight: f32,
+    },
+    BuildPallet {
+        orders: Vec<usize>,
+    },
+    BreakPallet {
+        pallet: usize,
+    },
+    ShowAllOrders {
+        country: Option<String>,
+        min_value_per_kg: Option<f32>,
+        within_hours: Option<u64>,
+    },
+    ReserveOrder {
+        order: usize,
+    },
+    ShowContracts,
+    LoadContract {
+        contract: usize,
+        weight: f32,
+        plane: usize,
+    },
+    ShowCharters,
+    AcceptCharter {
+        charter: usize,
+        plane: usize,
+    },
+    ShowLanes,
+    AcceptLane {
+        deal: usize,
+    },
+    DeclineLane {
+        deal: usize,
+    },
+    ShowAuctions,
+    BidAuction {
+        auction: usize,
+        amount: f32,
+    },
+    ShowDemand {
+        airport: usize,
+    },
+    ShowFares,
+    SetFare {
+        origin: usize,
+        destination: usize,
+        fare: f32,
+    },
+    ClearFare {
+        origin: usize,
+        destination: usize,
+    },
+    Refuel {
+        plane: usize,
+        /// Liters like `500` or a target level like `80%`; `None` fills the tank
+        amount: Option<String>,
+    },
+    DepartPlane {
+        plane: usize,
+        dest: usize,
+        speed: Option<String>,
+    },
+    ReroutePlane {
+        plane: usize,
+        dest: usize,
+    },
+    EmergencyLand {
+        plane: usize,
+    },
+    ShowMaintenance,
+    RepairPlane {
+        plane: usize,
+    },
+    ShowParts,
+    ShowPilots,
+    HirePilot {
+        airport: usize,
+    },
+    FirePilot {
+        pilot: usize,
+    },
+    HireCrew {
+        airport: usize,
+        count: u32,
+    },
+    FireCrew {
+        airport: usize,
+        count: u32,
+    },
+    AssignPilot {
+        pilot: usize,
+        plane: usize,
+    },
+    BuyParts {
+        airport: usize,
+        kits: u32,
+    },
+    ShipParts {
+        kits: u32,
+        dest: usize,
+        plane: usize,
+    },
+    PerformCheck {
+        plane: usize,
+        /// `A`, `B` or `C`
+        check: String,
+    },
+    HoldPlane {
+        plane: usize,
+    },
+    ReleasePlane {
+        plane: usize,
+    },
+    Advance {
+        hours: u64,
+    },
     ShowCash,
+    ShowValue,
+    ShowHash,
     ShowTime,
     ShowStats,
+    ShowReport,
     ShowModels,
-    LoadConfig { path: String },
+    /// Text drawing of the map, `width` x `height` characters
+    ShowMap {
+        width: usize,
+        height: usize,
+    },
+    LoadConfig {
+        path: String,
+    },
+    ExportRun {
+        path: String,
+    },
+    ExportConfig {
+        path: String,
+    },
     Exit,
-    SaveGame { name: String },
-    LoadGame { name: String },
-    Maintenance { plane_id: usize },
+    SaveGame {
+        name: String,
+        /// Add an integrity checksum
+        sealed: bool,
+        /// Encrypt with this passphrase
+        passphrase: Option<String>,
+    },
+    LoadGame {
+        name: String,
+        passphrase: Option<String>,
+    },
+    Maintenance {
+        plane_id: usize,
+    },
+    #[cfg(feature = "sandbox")]
+    GiveCash {
+        amount: f32,
+    },
+    #[cfg(feature = "sandbox")]
+    TeleportPlane {
+        plane: usize,
+        airport: usize,
+    },
+    #[cfg(feature = "sandbox")]
+    SpawnOrder {
+        /// `CargoType` name, case-insensitive
+        cargo: String,
+        weight: f32,
+        origin: usize,
+        destination: usize,
+        value: f32,
+        /// Hours until the deadline; a default is used when missing
+        deadline: Option<u64>,
+    },
+    #[cfg(feature = "sandbox")]
+    SetTime {
+        hours: u64,
+    },
 }
 
 #[derive(Debug)]
@@ -64,9 +305,248 @@ fn parse_id_list(s: &str) -> Result<Vec<usize>> {
         .collect()
 }
 
+/// Parse a weight in kg, with or without a trailing `kg`.
+fn parse_weight(s: &str) -> Result<f32> {
+    let digits = s
+        .strip_suffix("kg")
+        .or_else(|| s.strip_suffix("KG"))
+        .unwrap_or(s);
+    digits
+        .parse()
+        .map_err(|_| CommandError::Syntax("bad weight".into()))
+}
+
+/// Parse the filters of `SHOW ORDERS ALL [TO <country>] [MIN <value/kg>] [WITHIN <hours>]`.
+fn parse_order_board(filters: &[&str]) -> Result<Command> {
+    let mut country = None;
+    let mut min_value_per_kg = None;
+    let mut within_hours = None;
+    for pair in filters.chunks(2) {
+        match pair {
+            ["TO", name] => country = Some(name.to_string()),
+            ["MIN", value] => {
+                min_value_per_kg = Some(
+                    value
+                        .trim_start_matches('$')
+                        .parse()
+                        .map_err(|_| CommandError::Syntax("bad value per kg".into()))?,
+                )
+            }
+            ["WITHIN", hours] => {
+                within_hours = Some(
+                    hours
+                        .parse()
+                        .map_err(|_| CommandError::Syntax("bad hours".into()))?,
+                )
+            }
+            _ => {
+                return Err(CommandError::Syntax(
+                    "expected TO <country>, MIN <value/kg> or WITHIN <hours>".into(),
+                ));
+            }
+        }
+    }
+    Ok(Command::ShowAllOrders {
+        country,
+        min_value_per_kg,
+        within_hours,
+    })
+}
+
+/// Parse the developer commands enabled by the `sandbox` feature.
+#[cfg(feature = "sandbox")]
+fn parse_sandbox(toks: &[&str]) -> Option<Result<Command>> {
+    let id = |s: &str, what: &str| {
+        s.parse::<usize>()
+            .map_err(|_| CommandError::Syntax(format!("bad {} id", what)))
+    };
+    let amount = |s: &str| {
+        s.parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| CommandError::Syntax(format!("bad amount: `{}`", s)))
+    };
+    let spawn = |cargo: &str, weight, origin, destination, value, deadline: Option<&str>| {
+        Ok(Command::SpawnOrder {
+            cargo: cargo.to_string(),
+            weight: parse_weight(weight)?,
+            origin: id(origin, "airport")?,
+            destination: id(destination, "airport")?,
+            value: amount(value)?,
+            deadline: deadline
+                .map(|d| {
+                    d.parse()
+                        .map_err(|_| CommandError::Syntax("bad deadline hours".into()))
+                })
+                .transpose()?,
+        })
+    };
+
+    let cmd = match toks {
+        ["GIVE", "CASH", value] => amount(value).map(|amount| Command::GiveCash { amount }),
+        ["TELEPORT", "PLANE", plane, airport] => id(plane, "plane").and_then(|plane| {
+            Ok(Command::TeleportPlane {
+                plane,
+                airport: id(airport, "airport")?,
+            })
+        }),
+        [
+            "SPAWN",
+            "ORDER",
+            cargo,
+            weight,
+            "FROM",
+            origin,
+            "TO",
+            dest,
+            "VALUE",
+            value,
+        ] => spawn(cargo, weight, origin, dest, value, None),
+        [
+            "SPAWN",
+            "ORDER",
+            cargo,
+            weight,
+            "FROM",
+            origin,
+            "TO",
+            dest,
+            "VALUE",
+            value,
+            "DEADLINE",
+            hours,
+        ] => spawn(cargo, weight, origin, dest, value, Some(hours)),
+        ["SET", "TIME", hours] => hours
+            .parse()
+            .map(|hours| Command::SetTime { hours })
+            .map_err(|_| CommandError::Syntax("bad hours".into())),
+        _ => return None,
+    };
+    Some(cmd)
+}
+
+/// Every command's leading keywords, for suggesting one when the input is mistyped.
+const COMMANDS: &[&str] = &[
+    "SHOW AIRPORTS",
+    "SHOW AIRPORTS WITH ORDERS",
+    "SHOW PLANES",
+    "SHOW DISTANCES",
+    "SHOW MODELS",
+    "SHOW MARKET",
+    "SHOW GROUP",
+    "SHOW GROUPS",
+    "SHOW MAINTENANCE",
+    "SHOW PARTS",
+    "SHOW PILOTS",
+    "SHOW CONSTRUCTION",
+    "SHOW CONCESSIONS",
+    "SHOW WAREHOUSE",
+    "SHOW CASH",
+    "SHOW VALUE",
+    "SHOW HASH",
+    "SHOW TIME",
+    "SHOW STATS",
+    "SHOW REPORT",
+    "SHOW MAP",
+    "SHOW ORDERS ALL",
+    "SHOW CONTRACTS",
+    "SHOW CHARTERS",
+    "SHOW LANES",
+    "SHOW AUCTIONS",
+    "SHOW DEMAND FROM",
+    "SHOW FARES",
+    "BUY PLANE",
+    "BUY USED",
+    "SELL PLANE",
+    "RENAME PLANE",
+    "GROUP PLANE",
+    "UNGROUP PLANE",
+    "REFUEL GROUP",
+    "REFUEL PLANE",
+    "CHARGE PLANE",
+    "INSTALL CHARGER",
+    "BUILD AIRPORT",
+    "EXTEND RUNWAY",
+    "BUY CONCESSION",
+    "HIRE PILOT",
+    "FIRE PILOT",
+    "HIRE CREW",
+    "FIRE CREW",
+    "ASSIGN PILOT",
+    "BUY PARTS",
+    "SHIP PARTS",
+    "REPAIR PLANE",
+    "CHECK PLANE",
+    "MAINTENANCE",
+    "DEPART PLANE",
+    "REROUTE PLANE",
+    "HOLD PLANE",
+    "RELEASE PLANE",
+    "EMERGENCY LAND",
+    "LOAD ORDER",
+    "LOAD ORDERS",
+    "UNLOAD ORDER",
+    "UNLOAD ORDERS",
+    "UNLOAD ALL FROM",
+    "TRANSFER ORDER",
+    "SPLIT ORDER",
+    "RESERVE ORDER",
+    "BUILD PALLET",
+    "BREAK PALLET",
+    "LOAD CONTRACT",
+    "ACCEPT CHARTER",
+    "ACCEPT LANE",
+    "DECLINE LANE",
+    "BID AUCTION",
+    "SET FARE",
+    "CLEAR FARE",
+    "ADVANCE",
+    "SAVE",
+    "LOAD",
+    "LOAD CONFIG",
+    "EXPORT CONFIG",
+    "EXPORT RUN",
+    "EXIT",
+];
+
+/// Developer commands, only suggested when built with the `sandbox` feature
+#[cfg(feature = "sandbox")]
+const SANDBOX_COMMANDS: &[&str] = &["GIVE CASH", "TELEPORT PLANE", "SPAWN ORDER", "SET TIME"];
+#[cfg(not(feature = "sandbox"))]
+const SANDBOX_COMMANDS: &[&str] = &[];
+
+/// The known command closest to the start of `toks`, if the edit distance is small:
+/// at most 3, and under half the command's length so short words don't match anything.
+///
+/// Returns `None` when the input already starts with that command, so a command with
+/// bad arguments isn't "corrected" to itself.
+fn suggest_command(toks: &[&str]) -> Option<&'static str> {
+    let mut best: Option<(usize, &'static str)> = None;
+    for &command in COMMANDS.iter().chain(SANDBOX_COMMANDS) {
+        let words = command.split(' ').count().min(toks.len());
+        let typed = toks[..words].join(" ").to_uppercase();
+        let dist = levenshtein(&typed, command);
+        match best {
+            Some((best_dist, _)) if best_dist <= dist => {}
+            _ => best = Some((dist, command)),
+        }
+    }
+    let (dist, command) = best?;
+    let typed = toks.iter().take(command.split(' ').count()).copied();
+    if dist > 3 || dist * 2 >= command.len() || typed.eq(command.split(' ')) {
+        return None;
+    }
+    Some(command)
+}
+
 pub fn parse_command(line: &str) -> Result<Command> {
     let toks: Vec<&str> = line.split_whitespace().collect();
 
+    #[cfg(feature = "sandbox")]
+    if let Some(cmd) = parse_sandbox(&toks) {
+        return cmd;
+    }
+
     if toks.len() >= 5 && toks[0] == "LOAD" && toks[1] == "ORDERS" {
         if let Some(on_idx) = toks.iter().position(|&t| t == "ON") {
             let orders_str = toks[2..on_idx].join(" ");
@@ -119,17 +599,188 @@ pub fn parse_command(line: &str) -> Result<Command> {
                 .parse()
                 .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
         }),
+        ["SHOW", "MARKET"] => Ok(Command::ShowMarket),
+        ["SHOW", "GROUPS"] => Ok(Command::ShowGroups),
+        ["SHOW", "MAINTENANCE"] => Ok(Command::ShowMaintenance),
+        ["SHOW", "PARTS"] => Ok(Command::ShowParts),
+        ["SHOW", "PILOTS"] => Ok(Command::ShowPilots),
+        ["HIRE", "PILOT", airport_id] => Ok(Command::HirePilot {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+        }),
+        ["FIRE", "PILOT", pilot_id] => Ok(Command::FirePilot {
+            pilot: pilot_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad pilot id".into()))?,
+        }),
+        ["HIRE", "CREW", airport_id] => Ok(Command::HireCrew {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            count: 1,
+        }),
+        ["HIRE", "CREW", airport_id, count] => Ok(Command::HireCrew {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            count: count
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad crew count".into()))?,
+        }),
+        ["FIRE", "CREW", airport_id] => Ok(Command::FireCrew {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            count: 1,
+        }),
+        ["FIRE", "CREW", airport_id, count] => Ok(Command::FireCrew {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            count: count
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad crew count".into()))?,
+        }),
+        ["ASSIGN", "PILOT", pilot_id, "TO", plane_id] => Ok(Command::AssignPilot {
+            pilot: pilot_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad pilot id".into()))?,
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["BUY", "PARTS", airport_id, kits] => Ok(Command::BuyParts {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            kits: kits
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad kit count".into()))?,
+        }),
+        ["SHIP", "PARTS", kits, "TO", dest, "ON", plane_id] => Ok(Command::ShipParts {
+            kits: kits
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad kit count".into()))?,
+            dest: dest
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["REPAIR", "PLANE", plane_id] => Ok(Command::RepairPlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["CHECK", "PLANE", plane_id, check] => Ok(Command::PerformCheck {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            check: check.to_string(),
+        }),
+        ["SHOW", "GROUP", group] => Ok(Command::ShowGroup {
+            group: group.to_string(),
+        }),
+        ["REFUEL", "GROUP", group] => Ok(Command::RefuelGroup {
+            group: group.to_string(),
+        }),
+        ["CHARGE", "PLANE", plane_id] => Ok(Command::ChargePlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["INSTALL", "CHARGER", airport_id] => Ok(Command::InstallCharger {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+        }),
+        ["BUILD", "AIRPORT", x, y] => Ok(Command::BuildAirport {
+            x: x.parse()
+                .map_err(|_| CommandError::Syntax("bad x coordinate".into()))?,
+            y: y.parse()
+                .map_err(|_| CommandError::Syntax("bad y coordinate".into()))?,
+        }),
+        ["EXTEND", "RUNWAY", airport_id] => Ok(Command::ExtendRunway {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+        }),
+        ["SHOW", "CONSTRUCTION"] => Ok(Command::ShowConstruction),
+        ["BUY", "CONCESSION", airport_id] => Ok(Command::BuyConcession {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+        }),
+        ["SHOW", "CONCESSIONS"] => Ok(Command::ShowConcessions),
+        ["SHOW", "WAREHOUSE"] => Ok(Command::ShowWarehouse),
+        ["GROUP", "PLANE", plane_id, group] => Ok(Command::GroupPlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            group: group.to_string(),
+        }),
+        ["UNGROUP", "PLANE", plane_id, group] => Ok(Command::UngroupPlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            group: group.to_string(),
+        }),
+        ["RENAME", "PLANE", plane_id, name @ ..] if !name.is_empty() => Ok(Command::RenamePlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            name: name.join(" ").trim_matches('"').to_string(),
+        }),
+        ["BUY", "USED", listing_id] => Ok(Command::BuyUsed {
+            listing: listing_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad listing id".into()))?,
+        }),
         ["EXIT"] => Ok(Command::Exit),
         ["SAVE", name] => Ok(Command::SaveGame {
             name: name.to_string(),
+            sealed: false,
+            passphrase: None,
+        }),
+        ["SAVE", name, "SEALED"] => Ok(Command::SaveGame {
+            name: name.to_string(),
+            sealed: true,
+            passphrase: None,
+        }),
+        ["SAVE", name, "WITH", "PASSPHRASE", passphrase] => Ok(Command::SaveGame {
+            name: name.to_string(),
+            sealed: true,
+            passphrase: Some(passphrase.to_string()),
         }),
         ["LOAD", name] => Ok(Command::LoadGame {
             name: name.to_string(),
+            passphrase: None,
+        }),
+        ["LOAD", name, "WITH", "PASSPHRASE", passphrase] => Ok(Command::LoadGame {
+            name: name.to_string(),
+            passphrase: Some(passphrase.to_string()),
         }),
         ["SHOW", "CASH"] => Ok(Command::ShowCash),
+        ["SHOW", "VALUE"] => Ok(Command::ShowValue),
+        ["SHOW", "HASH"] => Ok(Command::ShowHash),
         ["SHOW", "TIME"] => Ok(Command::ShowTime),
         ["SHOW", "STATS"] => Ok(Command::ShowStats),
+        ["SHOW", "REPORT"] => Ok(Command::ShowReport),
         ["SHOW", "MODELS"] => Ok(Command::ShowModels),
+        ["SHOW", "MAP"] => Ok(Command::ShowMap {
+            width: 80,
+            height: 24,
+        }),
+        ["SHOW", "MAP", width, height] => Ok(Command::ShowMap {
+            width: width
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad map width".into()))?,
+            height: height
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad map height".into()))?,
+        }),
         ["ADVANCE", n] => Ok(Command::Advance {
             hours: n
                 .parse()
@@ -138,6 +789,12 @@ pub fn parse_command(line: &str) -> Result<Command> {
         ["LOAD", "CONFIG", path] => Ok(Command::LoadConfig {
             path: path.to_string(),
         }),
+        ["EXPORT", "RUN", path] => Ok(Command::ExportRun {
+            path: path.to_string(),
+        }),
+        ["EXPORT", "CONFIG", path] => Ok(Command::ExportConfig {
+            path: path.to_string(),
+        }),
         [] => Ok(Command::Advance { hours: 1 }),
         ["DEPART", "PLANE", plane_id, destination_airport_id] => Ok(Command::DepartPlane {
             plane: plane_id
@@ -146,12 +803,47 @@ pub fn parse_command(line: &str) -> Result<Command> {
             dest: destination_airport_id
                 .parse()
                 .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            speed: None,
+        }),
+        [
+            "DEPART",
+            "PLANE",
+            plane_id,
+            destination_airport_id,
+            "SPEED",
+            speed,
+        ] => Ok(Command::DepartPlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            dest: destination_airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+            speed: Some(speed.to_string()),
+        }),
+        ["REROUTE", "PLANE", plane_id, "TO", dest] => Ok(Command::ReroutePlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            dest: dest
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
         }),
         ["HOLD", "PLANE", plane_id] => Ok(Command::HoldPlane {
             plane: plane_id
                 .parse()
                 .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
         }),
+        ["EMERGENCY", "LAND", plane_id] => Ok(Command::EmergencyLand {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["RELEASE", "PLANE", plane_id] => Ok(Command::ReleasePlane {
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
         ["MAINTENANCE", plane_id] => Ok(Command::Maintenance {
             plane_id: plane_id
                 .parse()
@@ -183,6 +875,17 @@ pub fn parse_command(line: &str) -> Result<Command> {
                 .parse()
                 .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
         }),
+        ["TRANSFER", "ORDER", order_id, "FROM", from, "TO", to] => Ok(Command::TransferOrder {
+            order: order_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad order id".into()))?,
+            from: from
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            to: to
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
         ["UNLOAD", "ORDERS", orders, "ON", plane_id] => {
             let order_vec = parse_id_list(orders)?;
             let plane = plane_id
@@ -193,6 +896,92 @@ pub fn parse_command(line: &str) -> Result<Command> {
                 plane,
             })
         }
+        ["SPLIT", "ORDER", order_id, "INTO", weight] => Ok(Command::SplitOrder {
+            order: order_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad order id".into()))?,
+            weight: parse_weight(weight)?,
+        }),
+        ["BUILD", "PALLET", orders] => Ok(Command::BuildPallet {
+            orders: parse_id_list(orders)?,
+        }),
+        ["BREAK", "PALLET", pallet_id] => Ok(Command::BreakPallet {
+            pallet: pallet_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad pallet id".into()))?,
+        }),
+        ["SHOW", "ORDERS", "ALL", filters @ ..] => parse_order_board(filters),
+        ["RESERVE", "ORDER", order_id] => Ok(Command::ReserveOrder {
+            order: order_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad order id".into()))?,
+        }),
+        ["SHOW", "CONTRACTS"] => Ok(Command::ShowContracts),
+        ["LOAD", "CONTRACT", contract_id, weight, "ON", plane_id] => Ok(Command::LoadContract {
+            contract: contract_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad contract id".into()))?,
+            weight: parse_weight(weight)?,
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["SHOW", "CHARTERS"] => Ok(Command::ShowCharters),
+        ["ACCEPT", "CHARTER", charter_id, "ON", plane_id] => Ok(Command::AcceptCharter {
+            charter: charter_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad charter id".into()))?,
+            plane: plane_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+        }),
+        ["SHOW", "LANES"] => Ok(Command::ShowLanes),
+        ["ACCEPT", "LANE", deal_id] => Ok(Command::AcceptLane {
+            deal: deal_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad lane id".into()))?,
+        }),
+        ["DECLINE", "LANE", deal_id] => Ok(Command::DeclineLane {
+            deal: deal_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad lane id".into()))?,
+        }),
+        ["SHOW", "AUCTIONS"] => Ok(Command::ShowAuctions),
+        ["BID", "AUCTION", auction_id, amount] => Ok(Command::BidAuction {
+            auction: auction_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad auction id".into()))?,
+            amount: amount
+                .trim_start_matches('$')
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad bid".into()))?,
+        }),
+        ["SHOW", "DEMAND", "FROM", airport_id] => Ok(Command::ShowDemand {
+            airport: airport_id
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad airport id".into()))?,
+        }),
+        ["SHOW", "FARES"] => Ok(Command::ShowFares),
+        ["SET", "FARE", origin, dest, fare] => Ok(Command::SetFare {
+            origin: origin
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad origin airport id".into()))?,
+            destination: dest
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad destination airport id".into()))?,
+            fare: fare
+                .trim_start_matches('$')
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad fare".into()))?,
+        }),
+        ["CLEAR", "FARE", origin, dest] => Ok(Command::ClearFare {
+            origin: origin
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad origin airport id".into()))?,
+            destination: dest
+                .parse()
+                .map_err(|_| CommandError::Syntax("bad destination airport id".into()))?,
+        }),
         ["UNLOAD", "ALL", "FROM", plane_id] => Ok(Command::UnloadAll {
             plane: plane_id
                 .parse::<usize>()
@@ -202,10 +991,38 @@ pub fn parse_command(line: &str) -> Result<Command> {
             plane: plane_id
                 .parse::<usize>()
                 .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+            amount: None,
         }),
-        other => Err(CommandError::Syntax(format!(
-            "Unrecognized command: {:?}",
-            other
-        ))),
+        ["REFUEL", "PLANE", plane_id, "TO", target] => {
+            if !target.ends_with('%') {
+                return Err(CommandError::Syntax(
+                    "refuel target must be a percentage, e.g. TO 80%".into(),
+                ));
+            }
+            Ok(Command::Refuel {
+                plane: plane_id
+                    .parse::<usize>()
+                    .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+                amount: Some(target.to_string()),
+            })
+        }
+        ["REFUEL", "PLANE", plane_id, liters] => {
+            if liters.parse::<f32>().is_err() {
+                return Err(CommandError::Syntax("bad fuel amount".into()));
+            }
+            Ok(Command::Refuel {
+                plane: plane_id
+                    .parse::<usize>()
+                    .map_err(|_| CommandError::Syntax("bad plane id".into()))?,
+                amount: Some(liters.to_string()),
+            })
+        }
+        other => Err(CommandError::Syntax(match suggest_command(other) {
+            Some(command) => format!(
+                "Unrecognized command: {:?}, did you mean {}?",
+                other, command
+            ),
+            None => format!("Unrecognized command: {:?}", other),
+        })),
     }
 }
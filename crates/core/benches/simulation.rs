@@ -0,0 +1,119 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{GameplayConfig, WorldConfig};
+use rusty_runways_core::utils::airplanes::models::AirplaneModel;
+
+const SEED: u64 = 42;
+const AIRPORTS: usize = 50;
+
+/// Generated world with `planes` SparrowLights spread over the airports, half of them airborne.
+fn busy_world(planes: usize) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.pilots.required = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    let cfg = WorldConfig {
+        seed: Some(SEED),
+        starting_cash: 1.0e9,
+        airports: vec![],
+        num_airports: Some(AIRPORTS),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    let mut game = Game::from_config(cfg).expect("benchmark world should be valid");
+
+    let model = "SparrowLight".to_string();
+    let runway = AirplaneModel::SparrowLight.specs().min_runway_length;
+    let hubs: Vec<usize> = game
+        .map
+        .airports
+        .iter()
+        .enumerate()
+        .filter(|(_, (airport, _))| airport.runway_length >= runway)
+        .map(|(idx, _)| idx)
+        .collect();
+    for i in 0..planes {
+        game.buy_plane(&model, hubs[i % hubs.len()]).unwrap();
+    }
+    let planes: Vec<usize> = game.airplanes.iter().map(|p| p.id).collect();
+    for (i, plane) in planes.into_iter().enumerate().step_by(2) {
+        // planes that can't reach the next hub simply stay parked
+        let _ = game.depart_plane(plane, hubs[(i + 1) % hubs.len()]);
+    }
+    game
+}
+
+/// Fresh copy of a saved game, so every iteration starts from the same state.
+fn restore(save: &str) -> Game {
+    serde_json::from_str(save).expect("benchmark save should load")
+}
+
+fn world_generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Game::new");
+    for airports in [10, 100] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(airports),
+            &airports,
+            |b, &airports| b.iter(|| Game::new(black_box(SEED), Some(airports), 650_000.0)),
+        );
+    }
+    group.finish();
+}
+
+fn advance_day(c: &mut Criterion) {
+    let mut group = c.benchmark_group("advance(24)");
+    group.sample_size(20);
+    for planes in [10, 100, 1000] {
+        let save = serde_json::to_string(&busy_world(planes)).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(planes), &save, |b, save| {
+            b.iter_batched(
+                || restore(save),
+                |mut game| {
+                    game.advance(24);
+                    game
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn order_generation(c: &mut Criterion) {
+    let save = serde_json::to_string(&Game::new(SEED, Some(AIRPORTS), 650_000.0)).unwrap();
+    c.bench_function("restock_airports", |b| {
+        b.iter_batched(
+            || restore(&save),
+            |mut game| {
+                game.map.restock_airports();
+                game
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn save_and_load(c: &mut Criterion) {
+    let game = busy_world(100);
+    let save = serde_json::to_string(&game).unwrap();
+    let mut group = c.benchmark_group("save_load");
+    group.bench_function("save", |b| {
+        b.iter(|| serde_json::to_string(black_box(&game)).unwrap())
+    });
+    group.bench_function("load", |b| b.iter(|| restore(black_box(&save))));
+    group.bench_function("fork", |b| b.iter(|| black_box(&game).fork()));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    world_generation,
+    advance_day,
+    order_generation,
+    save_and_load
+);
+criterion_main!(benches);
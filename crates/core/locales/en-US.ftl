@@ -0,0 +1,225 @@
+# Built-in English messages. Translations copy this file to `<locale>.ftl` and replace
+# the text after each `=`; anything left out falls back to English.
+
+## Game errors
+
+error-out-of-range = Distance { $distance } is outside of the airplane range { $range }
+error-runway-too-short = Airplane requires at least { $required } m of runway. Destination has a length of { $available }
+error-max-payload-reached = Cannot load order of weight { $added_weight }. Airplane capacity: { $maximum_capacity }. Current Capacity: { $current_capacity }
+error-passenger-capacity-reached = Cannot board { $added_passengers } passengers. Seats available: { $maximum_capacity }. Currently occupied: { $current_capacity }
+error-cargo-slots-full = Plane { $plane_model } has no free cargo slot. It carries at most { $slots } cargo orders
+error-payload-type-unsupported = Plane { $plane_model } cannot carry { $payload } payloads
+error-order-id-invalid = Order with id { $id } does not exist
+error-contract-id-invalid = Contract with id { $id } does not exist
+error-charter-id-invalid = No open charter with id { $id }
+error-lane-id-invalid = No lane deal on offer with id { $id }
+error-auction-id-invalid = No open auction with id { $id }
+error-listing-id-invalid = Used plane listing with id { $id } does not exist
+error-unknown-group = No plane group called { $name }
+error-plane-on-hold = Plane { $plane_id } is held on the ground; release it with RELEASE PLANE { $plane_id }
+error-check-overdue = Plane { $plane_id } is grounded until its overdue { $check } check is done
+error-pilot-id-invalid = Pilot with id { $id } does not exist
+error-no-rested-pilot = No rested pilot is available to fly plane { $plane_id }; hire one or let the crew rest
+error-missing-spare-parts = Airport { $airport_id } has { $have } spare-part kits but { $need } are needed; buy or ship more in
+error-check-unavailable = The hangar at airport { $airport_id } can't do { $check } checks; fly to a bigger airport
+error-plane-id-invalid = Plan with id { $id } does not exist
+error-plane-not-at-airport = Plane { $plane_id } is not located at any known airport
+error-airport-id-invalid = Airport with id { $id } does not exist
+error-airport-location-invalid = No airport found at coordinate ({ $x }, { $y })
+error-site-off-map = ({ $x }, { $y }) is off the map; pick a site between 0 and 10000 on both axes
+error-site-too-close = The nearest airport or building site is only { $distance }km away; build at least { $min_distance }km from it
+error-airport-not-owned = Airport { $airport_id } isn't yours; only airports you built can be extended
+error-insufficient-funds = Insufficient funds. Need: ${ $need }. Currently have: ${ $have }
+error-insufficient-fuel = Insufficient fuel. Need: { $need }L. Currently have: { $have }L
+error-fuel-shortage = Airport { $airport_id } has run out of fuel until the next resupply
+error-fuel-grade-unavailable = Airport { $airport_id } doesn't sell { $grade }
+error-over-max-takeoff-weight = Takeoff weight { $takeoff_weight }kg exceeds the maximum takeoff weight of { $mtow }kg. Unload cargo or fuel
+error-unknown-model = `{ $input }` doesn't exist.
+error-unknown-model-suggestion = `{ $input }` doesn't exist. Did you mean `{ $suggestion }`?
+error-plane-not-ready = Airplane not ready. Current status: { $status }
+error-no-cargo = No cargo to unload
+error-same-airport = Cannot fly to the airport the plane is currently at
+error-invalid-config = Invalid config: { $msg }
+
+## CLI
+
+cli-config-valid = { $path } is valid
+cli-config-problems = { $count } problem(s) found in { $path }
+cli-config-loaded = Loaded config from { $path }
+cli-config-invalid = Invalid config: { $error }
+cli-yaml-error = YAML parse error: { $error }
+cli-read-failed = Failed to read { $path }: { $error }
+cli-plane-bought = Airplane was bought!
+cli-plane-sold = Plane { $plane } sold. Refunded ${ $refund }
+cli-sell-failed = Sell failed: { $error }
+cli-plane-grouped = Plane { $plane } added to group { $group }
+cli-group-failed = Grouping failed: { $error }
+cli-plane-ungrouped = Plane { $plane } removed from group { $group }
+cli-ungroup-failed = Ungrouping failed: { $error }
+cli-pilot-hired = Hired pilot { $pilot } at airport { $airport }
+cli-hire-failed = Hiring failed: { $error }
+cli-pilot-fired = Pilot { $pilot } has left the company
+cli-fire-pilot-failed = Cannot fire pilot: { $error }
+cli-ground-crew = Airport { $airport } now has { $crew } ground crew
+cli-fire-crew-failed = Cannot let crew go: { $error }
+cli-pilot-assigned = Pilot { $pilot } now flies plane { $plane }
+cli-assign-failed = Assignment failed: { $error }
+cli-parts-bought = Bought { $kits } spare-part kits at airport { $airport }
+cli-purchase-failed = Purchase failed: { $error }
+cli-parts-shipped = Loading { $kits } spare-part kits for airport { $airport } onto plane { $plane } as order { $order }
+cli-shipment-failed = Shipment failed: { $error }
+cli-group-refueling = Refueling planes { $planes }
+cli-refuel-group-failed = Refuel failed: { $error }
+cli-plane-charging = Plane { $plane } is charging for { $hours }h
+cli-charge-failed = Charging failed: { $error }
+cli-charger-installed = Installed a charger at airport { $airport }
+cli-construction-started = Construction { $construction } started; it finishes in { $hours } hours
+cli-construction-failed = Construction failed: { $error }
+cli-concession-bought = Bought the fuel concession at airport { $airport } for ${ $cost }
+cli-concession-failed = Concession purchase failed: { $error }
+cli-install-failed = Installation failed: { $error }
+cli-plane-renamed = Plane { $plane } is now called { $name }
+cli-rename-failed = Rename failed: { $error }
+cli-listing-bought = Bought listing { $listing } as plane { $plane }
+cli-load-failed = Load failed: { $error }
+cli-order-loading = Loading order { $order } onto plane { $plane }
+cli-unload-failed = Unloading failed: { $error }
+cli-order-transferred = Moved order { $order } from plane { $from } to plane { $to }
+cli-transfer-failed = Transfer failed: { $error }
+cli-contract-loaded = Loaded { $weight }kg for contract { $contract } as order { $order }
+cli-order-reserved = Reserved order { $order } for ${ $fee }
+cli-reserve-failed = Reservation failed: { $error }
+cli-charter-boarded = Charter { $charter } boarded plane { $plane }
+cli-charter-failed = Charter failed: { $error }
+cli-lane-accepted = Lane { $deal } accepted
+cli-accept-lane-failed = Accepting lane failed: { $error }
+cli-lane-declined = Lane { $deal } declined
+cli-decline-lane-failed = Declining lane failed: { $error }
+cli-bid-placed = Bid ${ $amount } on auction { $auction }
+cli-bid-failed = Bid failed: { $error }
+cli-fare-set = Tickets from { $origin } to { $destination } now cost ${ $fare }
+cli-set-fare-failed = Setting fare failed: { $error }
+cli-fare-cleared = Stopped selling tickets from { $origin } to { $destination }
+cli-clear-fare-failed = Clearing fare failed: { $error }
+cli-split-failed = Split failed: { $error }
+cli-pallet-broken = Broke pallet { $pallet } into orders { $orders }
+cli-pallet-failed = Pallet failed: { $error }
+cli-refuel-failed = Failed to refuel: { $error }
+cli-depart-failed = Cannot depart: { $error }
+cli-plane-rerouted = Plane { $plane } rerouted to airport { $airport }
+cli-reroute-failed = Cannot reroute: { $error }
+cli-plane-repairing = Plane { $plane } is in the workshop for repairs
+cli-repair-failed = Cannot repair: { $error }
+cli-check-started = Plane { $plane } is in for its { $check } check
+cli-check-failed = Cannot start check: { $error }
+cli-emergency-landing = Plane { $plane } is making an emergency landing at airport { $airport }
+cli-land-failed = Cannot land: { $error }
+cli-plane-held = Plane { $plane } is held on the ground
+cli-hold-failed = Cannot hold: { $error }
+cli-plane-released = Plane { $plane } released
+cli-release-failed = Cannot release: { $error }
+cli-save-failed = Failed to save: { $error }
+cli-run-exported = Exported run summary to { $path }
+cli-export-failed = Export failed: { $error }
+cli-config-exported = Exported world config to { $path }
+cli-load-game-failed = Failed to load game: { $error }
+cli-syntax-error = Syntax error: { $error }
+cli-not-implemented = Not yet implemented
+cli-game-saved = Successfully saved game: { $name }
+cli-config-read-failed = failed to read config { $path }: { $error }
+cli-invalid-yaml = invalid yaml: { $error }
+cli-seed-and-n-required = Both --seed and --n must be specified
+cli-serialize-config-failed = failed to serialize config: { $error }
+cli-write-failed = failed to write { $path }: { $error }
+cli-resume-prompt = Resume your last session? [Y/n]
+cli-resume-failed = Could not resume the last session: { $error }
+cli-session-saved = Session saved, it will be offered at the next launch.
+cli-plugin-read-failed = failed to read plugin { $path }: { $error }
+cli-serialize-run-failed = failed to serialize run summary: { $error }
+cli-daemon-listening = Serving the game on { $address }
+cli-daemon-client-failed = Client connection failed: { $error }
+cli-metrics-listening = Serving metrics on http://{ $address }/metrics
+
+## GUI
+
+gui-tagline = Build your aviation empire · Manage logistics · Conquer the skies
+gui-start-new-game = Start New Game
+gui-game-seed = Game Seed
+gui-number-of-airports = Number of Airports
+gui-starting-cash = Starting Cash ($)
+gui-launch-game = Launch Game
+gui-invalid-seed = Invalid seed: { $error }
+gui-invalid-airports = Invalid # of airports: { $error }
+gui-invalid-cash = Invalid starting cash: { $error }
+gui-load-saved-game = Load Saved Game
+gui-save-game-name = Save Game Name
+gui-recent-saves = Recent Saves
+gui-load-game = Load Game
+gui-start-from-config = Start From Config
+gui-config-path = Config path (.yaml)
+gui-browse = Browse
+gui-preview = Preview
+gui-start = Start
+gui-random-game = Random Game
+gui-config-preview = Config Preview
+gui-start-game = Start Game
+gui-advanced-hour = Advanced 1h
+gui-company-value = Value ${ $value }
+gui-fleet-size = { $count } planes
+gui-save = Save
+gui-load = Load
+gui-menu = Menu
+gui-exit = Exit
+gui-game-stats = Game Stats
+gui-monthly-report = Monthly report
+gui-auctions = Auctions
+gui-fleet-overview = Fleet Overview
+gui-buy-new-plane = Buy new plane
+gui-world-map = World Map
+gui-reset-view = Reset view
+gui-following-plane = Following plane { $plane }
+gui-game-log = Game Log
+gui-daily-stats =
+    Income: ${ $income }
+    Expenses: ${ $expenses }
+    Deliveries: { $deliveries }
+gui-dock = Dock
+gui-pop-out = Pop out
+
+## CLI accessible output
+
+cli-a11y-banner = Rusty Runways. Accessible output is on.
+cli-a11y-fuel = fuel { $percent } percent
+cli-a11y-battery = battery { $percent } percent
+cli-a11y-status-parked = parked
+cli-a11y-status-refueling = refueling
+cli-a11y-status-charging = charging
+cli-a11y-status-maintenance = in maintenance
+cli-a11y-status-loading = loading
+cli-a11y-status-unloading = unloading
+cli-a11y-status-broken = broken down
+cli-a11y-status-flying = flying
+cli-a11y-plane-count = { $count } planes
+cli-a11y-plane-on-ground = Plane { $id }: { $status } at { $airport }, { $energy }, cargo { $cargo } of { $capacity } kilograms
+cli-a11y-plane-flying = Plane { $id }: flying to { $airport }, arriving in { $hours } hours, { $energy }, cargo { $cargo } of { $capacity } kilograms
+cli-a11y-on-hold = , on hold
+cli-a11y-cargo = { $weight } kilograms of { $cargo }
+cli-a11y-passengers = { $count } passengers
+cli-a11y-order = Order { $id }: { $payload } to { $airport }, { $tier }, worth { $value } dollars, due in { $hours } hours
+cli-a11y-airport-count = { $count } airports
+cli-a11y-airport = Airport { $id }: { $name }, runway { $runway } meters, fuel { $fuel } dollars per liter, landing fee { $landing } dollars per ton, { $orders } orders waiting
+cli-a11y-in-transit = Plane { $id } is in flight
+cli-a11y-distance-reachable = Airport { $id }: { $name }, { $distance } kilometers, can land
+cli-a11y-distance-unreachable = Airport { $id }: { $name }, { $distance } kilometers, cannot land
+cli-a11y-model = { $name }: price { $price } dollars, cruise { $speed } kilometers per hour, payload { $payload } kilograms, fuel { $fuel } liters, runway { $runway } meters
+cli-a11y-no-stats = No days recorded yet
+cli-a11y-day = Day { $day }: income { $income } dollars, expenses { $expenses } dollars, cash { $cash } dollars, { $fleet } planes, { $deliveries } deliveries
+
+## Desktop notifications
+
+desktop-arrived-title = Plane landed
+desktop-arrived-body = { $plane } landed at { $airport }
+desktop-expiring-title = Order about to expire
+desktop-expiring-body = Order { $order } on { $plane } is due in { $hours }h
+desktop-negative-cash-title = Cash is negative
+desktop-negative-cash-body = Cash dropped to ${ $cash }
@@ -0,0 +1,282 @@
+//! Every command that would succeed right now.
+//!
+//! [`Game::enumerate_actions`] lists what each plane can do where it stands (depart,
+//! load, unload, refuel or charge, maintenance) and which planes the player can afford
+//! to buy. Bots use it to mask their action space, frontends to suggest the next move.
+//! Each [`Action`] prints as the CLI command that performs it:
+//!
+//! ```
+//! let game = rusty_runways_core::Game::new(1, Some(5), 650_000.0);
+//! for action in game.enumerate_actions() {
+//!     assert!(game.fork().execute_str(&action.to_string()).is_ok());
+//! }
+//! ```
+//!
+//! [`ActionSpace`] numbers the actions for policies with a discrete action head, so every
+//! agent trained on RustyRunways agrees on what action 17 means.
+
+use crate::Game;
+use crate::game::MOBILE_MECHANIC_FEE;
+use crate::utils::airplanes::models::{AirplaneSpecs, AirplaneStatus};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One valid command, tagged by `action` when serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    Depart { plane: usize, destination: usize },
+    LoadOrder { plane: usize, order: usize },
+    UnloadAll { plane: usize },
+    Refuel { plane: usize },
+    Charge { plane: usize },
+    Maintenance { plane: usize },
+    BuyPlane { model: String, airport: usize },
+}
+
+impl Action {
+    /// The command in the syntax of [`Game::execute_str`].
+    pub fn command_line(&self) -> String {
+        match self {
+            Action::Depart { plane, destination } => {
+                format!("DEPART PLANE {} {}", plane, destination)
+            }
+            Action::LoadOrder { plane, order } => format!("LOAD ORDER {} ON {}", order, plane),
+            Action::UnloadAll { plane } => format!("UNLOAD ALL FROM {}", plane),
+            Action::Refuel { plane } => format!("REFUEL PLANE {}", plane),
+            Action::Charge { plane } => format!("CHARGE PLANE {}", plane),
+            Action::Maintenance { plane } => format!("MAINTENANCE {}", plane),
+            Action::BuyPlane { model, airport } => format!("BUY PLANE {} {}", model, airport),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.command_line())
+    }
+}
+
+impl Game {
+    /// Every action that would succeed if issued now, plane by plane in fleet order,
+    /// then the affordable purchases.
+    ///
+    /// Departures, loads and refuels are tried on a [`fork`](Game::fork), so they pass
+    /// exactly the checks the real command runs. Nothing in this game changes.
+    pub fn enumerate_actions(&self) -> Vec<Action> {
+        let mut actions = Vec::new();
+        let funds = self.player.available_funds();
+        let airports = self.airports();
+
+        for plane in self.planes() {
+            let Some((here, _)) = airports.iter().find(|(_, c)| *c == plane.location) else {
+                continue;
+            };
+            let id = plane.id;
+            let succeeds = |f: &dyn Fn(&mut Game) -> bool| f(&mut self.fork());
+
+            for (airport, coord) in airports {
+                if plane.can_fly_to(airport, coord).is_ok()
+                    && succeeds(&|g| g.depart_plane(id, airport.id).is_ok())
+                {
+                    actions.push(Action::Depart {
+                        plane: id,
+                        destination: airport.id,
+                    });
+                }
+            }
+            for order in &here.orders {
+                if succeeds(&|g| g.load_order(order.id, id).is_ok()) {
+                    actions.push(Action::LoadOrder {
+                        plane: id,
+                        order: order.id,
+                    });
+                }
+            }
+            if !plane.manifest.is_empty() {
+                actions.push(Action::UnloadAll { plane: id });
+            }
+            if plane.current_fuel < plane.specs.fuel_capacity {
+                if plane.specs.is_electric() {
+                    if succeeds(&|g| g.charge_plane(id).is_ok()) {
+                        actions.push(Action::Charge { plane: id });
+                    }
+                } else if succeeds(&|g| g.refuel_plane(id).is_ok()) {
+                    actions.push(Action::Refuel { plane: id });
+                }
+            }
+            // checked here rather than on a fork, a mobile mechanic call-out prints
+            let in_transit = matches!(plane.status, AirplaneStatus::InTransit { .. });
+            if !in_transit
+                && !plane.awaiting_repair
+                && (here.has_hangar || funds >= MOBILE_MECHANIC_FEE)
+            {
+                actions.push(Action::Maintenance { plane: id });
+            }
+        }
+
+        for (model, specs) in self.available_models() {
+            if funds < specs.purchase_price {
+                continue;
+            }
+            for (airport, _) in airports {
+                if airport.runway_length >= specs.min_runway_length {
+                    actions.push(Action::BuyPlane {
+                        model: model.clone(),
+                        airport: airport.id,
+                    });
+                }
+            }
+        }
+        actions
+    }
+}
+
+/// A fixed numbering of actions, sized by how many planes, airports, waiting orders and
+/// models it has room for.
+///
+/// Index [`ActionSpace::NOOP`] does nothing. Then each plane slot gets a block of
+/// `3 + max_airports + max_orders` indices: unload all, refuel (or charge, for electric
+/// planes), maintenance, a departure to each airport slot and a load of each order slot.
+/// Purchases come last, one index per model slot and airport slot. Planes are slotted in
+/// fleet order, airports in map order, orders in the order they wait at the plane's
+/// airport and models by purchase price, then name. Whatever doesn't fit has no index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSpace {
+    pub max_planes: usize,
+    pub max_airports: usize,
+    pub max_orders: usize,
+    pub max_models: usize,
+}
+
+impl Default for ActionSpace {
+    fn default() -> Self {
+        ActionSpace {
+            max_planes: 16,
+            max_airports: 64,
+            max_orders: 64,
+            max_models: 16,
+        }
+    }
+}
+
+/// Plane verbs at the start of each plane block, before the departures.
+const PLANE_VERBS: usize = 3;
+
+impl ActionSpace {
+    /// The index that does nothing, e.g. to let time pass.
+    pub const NOOP: usize = 0;
+
+    fn per_plane(&self) -> usize {
+        PLANE_VERBS + self.max_airports + self.max_orders
+    }
+
+    fn purchases_start(&self) -> usize {
+        1 + self.max_planes * self.per_plane()
+    }
+
+    /// Number of indices, the size of the action head.
+    pub fn size(&self) -> usize {
+        self.purchases_start() + self.max_models * self.max_airports
+    }
+
+    /// Index of `action`, or `None` if its plane, airport, order or model has no slot.
+    pub fn encode_action(&self, game: &Game, action: &Action) -> Option<usize> {
+        let plane_index = |plane: usize, offset: usize| {
+            let slot = game.planes().iter().position(|p| p.id == plane)?;
+            (slot < self.max_planes).then(|| 1 + slot * self.per_plane() + offset)
+        };
+        let airport_slot = |airport: usize| {
+            let slot = game.airports().iter().position(|(a, _)| a.id == airport)?;
+            (slot < self.max_airports).then_some(slot)
+        };
+        match action {
+            Action::UnloadAll { plane } => plane_index(*plane, 0),
+            Action::Refuel { plane } | Action::Charge { plane } => plane_index(*plane, 1),
+            Action::Maintenance { plane } => plane_index(*plane, 2),
+            Action::Depart { plane, destination } => {
+                plane_index(*plane, PLANE_VERBS + airport_slot(*destination)?)
+            }
+            Action::LoadOrder { plane, order } => {
+                let location = game.planes().iter().find(|p| p.id == *plane)?.location;
+                let (here, _) = game.airports().iter().find(|(_, c)| *c == location)?;
+                let slot = here.orders.iter().position(|o| o.id == *order)?;
+                if slot >= self.max_orders {
+                    return None;
+                }
+                plane_index(*plane, PLANE_VERBS + self.max_airports + slot)
+            }
+            Action::BuyPlane { model, airport } => {
+                let slot = model_slots(game).iter().position(|(m, _)| m == model)?;
+                (slot < self.max_models).then_some(
+                    self.purchases_start() + slot * self.max_airports + airport_slot(*airport)?,
+                )
+            }
+        }
+    }
+
+    /// The action at `index`, or `None` for [`NOOP`](Self::NOOP), an index past the end
+    /// or an empty slot. The action is not checked; [`action_mask`](Self::action_mask)
+    /// says which ones would succeed.
+    pub fn decode_action(&self, game: &Game, index: usize) -> Option<Action> {
+        if index == Self::NOOP || index >= self.size() {
+            return None;
+        }
+        if index >= self.purchases_start() {
+            let offset = index - self.purchases_start();
+            let (model, _) = model_slots(game)
+                .into_iter()
+                .nth(offset / self.max_airports)?;
+            let (airport, _) = game.airports().get(offset % self.max_airports)?;
+            return Some(Action::BuyPlane {
+                model,
+                airport: airport.id,
+            });
+        }
+        let plane = game.planes().get((index - 1) / self.per_plane())?;
+        let offset = (index - 1) % self.per_plane();
+        let id = plane.id;
+        Some(match offset {
+            0 => Action::UnloadAll { plane: id },
+            1 if plane.specs.is_electric() => Action::Charge { plane: id },
+            1 => Action::Refuel { plane: id },
+            2 => Action::Maintenance { plane: id },
+            _ if offset < PLANE_VERBS + self.max_airports => Action::Depart {
+                plane: id,
+                destination: game.airports().get(offset - PLANE_VERBS)?.0.id,
+            },
+            _ => {
+                let (here, _) = game.airports().iter().find(|(_, c)| *c == plane.location)?;
+                let order = here.orders.get(offset - PLANE_VERBS - self.max_airports)?;
+                Action::LoadOrder {
+                    plane: id,
+                    order: order.id,
+                }
+            }
+        })
+    }
+
+    /// Which indices would succeed now, from [`Game::enumerate_actions`].
+    /// [`NOOP`](Self::NOOP) always does.
+    pub fn action_mask(&self, game: &Game) -> Vec<bool> {
+        let mut mask = vec![false; self.size()];
+        mask[Self::NOOP] = true;
+        for action in game.enumerate_actions() {
+            if let Some(index) = self.encode_action(game, &action) {
+                mask[index] = true;
+            }
+        }
+        mask
+    }
+}
+
+/// Models in slot order: by purchase price, ties by name.
+fn model_slots(game: &Game) -> Vec<(String, AirplaneSpecs)> {
+    let mut models = game.available_models();
+    models.sort_by(|a, b| {
+        a.1.purchase_price
+            .total_cmp(&b.1.purchase_price)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    models
+}
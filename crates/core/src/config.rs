@@ -1,18 +1,126 @@
+use crate::utils::airplanes::checks::{CheckHours, CheckType};
+use crate::utils::airport::{MAX_UTC_OFFSET, MIN_UTC_OFFSET};
+use crate::utils::coordinate::CoordinateSystem;
+use crate::utils::map::WorldStyle;
 use crate::utils::orders::{
     cargo::CargoType,
     order::{
-        DEFAULT_ALPHA, DEFAULT_BETA, DEFAULT_FARE_PER_KM, DEFAULT_MAX_DEADLINE_HOURS,
-        DEFAULT_MAX_WEIGHT, DEFAULT_MIN_WEIGHT, DEFAULT_PASSENGER_ALPHA, DEFAULT_PASSENGER_BETA,
-        DEFAULT_PASSENGER_MAX_COUNT, DEFAULT_PASSENGER_MAX_DEADLINE_HOURS,
-        DEFAULT_PASSENGER_MIN_COUNT, OrderGenerationParams, PassengerGenerationParams,
+        DEFAULT_ALPHA, DEFAULT_BETA, DEFAULT_ECONOMY_MULTIPLIER, DEFAULT_ECONOMY_SHARE,
+        DEFAULT_EXPRESS_MULTIPLIER, DEFAULT_EXPRESS_SHARE, DEFAULT_FARE_PER_KM,
+        DEFAULT_MAX_DEADLINE_HOURS, DEFAULT_MAX_WEIGHT, DEFAULT_MIN_WEIGHT,
+        DEFAULT_PASSENGER_ALPHA, DEFAULT_PASSENGER_BETA, DEFAULT_PASSENGER_MAX_COUNT,
+        DEFAULT_PASSENGER_MAX_DEADLINE_HOURS, DEFAULT_PASSENGER_MIN_COUNT, OrderGenerationParams,
+        OrderTier, PassengerGenerationParams,
     },
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub const DEFAULT_RESTOCK_CYCLE_HOURS: u64 = 168;
+pub const DEFAULT_RESERVATION_SHARE: f32 = 0.05;
 pub const DEFAULT_FUEL_INTERVAL_HOURS: u64 = 6;
+pub const DEFAULT_CUSTOMS_RATE: f32 = 0.08;
+pub const DEFAULT_OVERFLIGHT_FEE_PER_KM: f32 = 0.25;
+pub const DEFAULT_CONTRACT_COUNT: usize = 2;
+pub const DEFAULT_CONTRACT_MIN_WEIGHT: f32 = 20_000.0;
+pub const DEFAULT_CONTRACT_MAX_WEIGHT: f32 = 200_000.0;
+pub const DEFAULT_CONTRACT_DURATION_HOURS: u64 = 240;
+pub const DEFAULT_CONTRACT_RATE_MULTIPLIER: f32 = 0.7;
+pub const DEFAULT_CONTRACT_COMPLETION_BONUS: f32 = 0.25;
+pub const DEFAULT_CHARTER_COUNT: usize = 3;
+pub const DEFAULT_CHARTER_MIN_PASSENGERS: u32 = 4;
+pub const DEFAULT_CHARTER_MAX_PASSENGERS: u32 = 40;
+pub const DEFAULT_CHARTER_OFFER_HOURS: u64 = 48;
+pub const DEFAULT_CHARTER_WINDOW_HOURS: u64 = 36;
+pub const DEFAULT_CHARTER_FARE_MULTIPLIER: f32 = 2.0;
+pub const DEFAULT_FORWARDER_COUNT: usize = 2;
+pub const DEFAULT_FORWARDER_OFFER_INTERVAL_DAYS: u64 = 3;
+pub const DEFAULT_FORWARDER_OFFER_HOURS: u64 = 48;
+pub const DEFAULT_LANE_MIN_LOADS_PER_WEEK: u32 = 3;
+pub const DEFAULT_LANE_MAX_LOADS_PER_WEEK: u32 = 7;
+pub const DEFAULT_LANE_MIN_LOAD_WEIGHT: f32 = 500.0;
+pub const DEFAULT_LANE_MAX_LOAD_WEIGHT: f32 = 2_000.0;
+pub const DEFAULT_LANE_WEEKS: u32 = 4;
+pub const DEFAULT_LANE_RATE_MULTIPLIER: f32 = 0.9;
+pub const DEFAULT_LANE_LOAD_DEADLINE_HOURS: u64 = 72;
+pub const DEFAULT_AUCTION_CHANCE_PER_DAY: f32 = 0.3;
+pub const DEFAULT_AUCTION_BIDDING_HOURS: u64 = 12;
+pub const DEFAULT_AUCTION_MIN_WEIGHT: f32 = 4_000.0;
+pub const DEFAULT_AUCTION_MAX_WEIGHT: f32 = 15_000.0;
+pub const DEFAULT_AUCTION_MIN_DEADLINE_HOURS: u64 = 24;
+pub const DEFAULT_AUCTION_MAX_DEADLINE_HOURS: u64 = 96;
+pub const DEFAULT_AUCTION_COMPETITORS: u32 = 3;
+pub const DEFAULT_AUCTION_BID_SPREAD: f32 = 0.2;
+pub const DEFAULT_AUCTION_URGENCY_PREMIUM: f32 = 0.5;
+pub const DEFAULT_RARE_CARGO_CHANCE_PER_DAY: f32 = 0.1;
+pub const DEFAULT_RARE_CARGO_VALUE_MULTIPLIER: f32 = 3.0;
+pub const DEFAULT_RARE_CARGO_MIN_VALUE: f32 = 50_000.0;
+pub const DEFAULT_WORLD_GROWTH_MAX_NEW_AIRPORTS: usize = 8;
+pub const DEFAULT_WORLD_GROWTH_RADIUS_KM: f32 = 800.0;
+pub const DEFAULT_MARKET_SATURATION_TONS: f32 = 20.0;
+pub const DEFAULT_MARKET_MAX_DISCOUNT: f32 = 0.4;
+pub const DEFAULT_MARKET_BACKLOG_PREMIUM: f32 = 0.1;
+pub const DEFAULT_MARKET_MAX_BACKLOG_PREMIUM: f32 = 0.5;
+pub const DEFAULT_MARKET_MEMORY: f32 = 0.5;
+pub const DEFAULT_LOYALTY_WINDOW_HOURS: u64 = 168;
+pub const DEFAULT_DEMAND_SCALE: f32 = 40.0;
+pub const DEFAULT_DEMAND_DISTANCE_EXPONENT: f32 = 1.0;
+pub const DEFAULT_DEMAND_DAILY_VARIATION: f32 = 0.1;
+pub const DEFAULT_DEMAND_PRICE_ELASTICITY: f32 = 1.5;
+pub const DEFAULT_HANDLING_TONS_PER_HOUR: f32 = 10.0;
+pub const DEFAULT_HANDLING_MIN_HOURS: u64 = 1;
+pub const DEFAULT_CREW_WAGE_PER_DAY: f32 = 250.0;
+pub const DEFAULT_CREW_RATE_BONUS: f32 = 0.5;
+pub const DEFAULT_MAX_CREW: u32 = 4;
+pub const DEFAULT_WAREHOUSE_FEE_PER_TON: f32 = 40.0;
+pub const DEFAULT_WAREHOUSE_FREE_HOURS: u64 = 24;
+pub const DEFAULT_CHARGER_COST: f32 = 400_000.0;
+pub const DEFAULT_CHARGER_KW: f32 = 250.0;
+pub const DEFAULT_AIRSTRIP_COST: f32 = 2_500_000.0;
+pub const DEFAULT_AIRSTRIP_BUILD_HOURS: u64 = 336;
+pub const DEFAULT_AIRSTRIP_RUNWAY_M: f32 = 800.0;
+pub const DEFAULT_MIN_SITE_SPACING_KM: f32 = 50.0;
+pub const DEFAULT_RUNWAY_EXTENSION_COST: f32 = 1_000_000.0;
+pub const DEFAULT_RUNWAY_EXTENSION_HOURS: u64 = 168;
+pub const DEFAULT_RUNWAY_EXTENSION_M: f32 = 700.0;
+pub const DEFAULT_MAX_BUILT_RUNWAY_M: f32 = 3_600.0;
+pub const DEFAULT_CONCESSION_COST_PER_SIZE: f32 = 1_000_000.0;
+pub const DEFAULT_CONCESSION_DISCOUNT: f32 = 0.25;
+pub const DEFAULT_CONCESSION_INCOME_PER_SIZE: f32 = 2_000.0;
+pub const DEFAULT_ELECTRICITY_PRICE_PER_KWH: f32 = 0.3;
+pub const DEFAULT_OVERDRAFT_LIMIT: f32 = 100_000.0;
+pub const DEFAULT_OVERDRAFT_DAILY_INTEREST: f32 = 0.005;
+pub const DEFAULT_OVERDRAFT_WARNING_SHARE: f32 = 0.75;
+pub const DEFAULT_USED_LISTING_COUNT: usize = 3;
+pub const DEFAULT_USED_MIN_RUNWAY_M: f32 = 2_500.0;
+pub const DEFAULT_USED_MAX_DISCOUNT: f32 = 0.25;
+pub const DEFAULT_USED_LISTING_HOURS: u64 = 96;
+pub const DEFAULT_A_CHECK_INTERVAL_HOURS: u64 = 100;
+pub const DEFAULT_B_CHECK_INTERVAL_HOURS: u64 = 500;
+pub const DEFAULT_C_CHECK_INTERVAL_HOURS: u64 = 3_000;
+pub const DEFAULT_LANDING_FAILURE_CHANCE: f32 = 0.005;
+pub const DEFAULT_REPAIR_COST_SHARE: f32 = 0.03;
+pub const DEFAULT_REPAIR_HOURS: u64 = 16;
+pub const DEFAULT_REPAIR_KITS: u32 = 1;
+pub const DEFAULT_SPARE_KIT_PRICE: f32 = 2_500.0;
+pub const DEFAULT_SPARE_KIT_WEIGHT: f32 = 150.0;
+pub const DEFAULT_STARTING_SPARE_KITS: u32 = 4;
+pub const DEFAULT_PILOT_HIRE_FEE: f32 = 2_000.0;
+pub const DEFAULT_PILOT_SALARY_PER_DAY: f32 = 300.0;
+pub const DEFAULT_PILOT_MAX_DUTY_HOURS: u64 = 12;
+pub const DEFAULT_PILOT_REST_HOURS: u64 = 10;
+pub const DEFAULT_PILOT_TYPE_RATING_HOURS: u64 = 50;
+pub const DEFAULT_PILOT_FAMILIAR_ROUTE_FLIGHTS: u32 = 3;
+pub const DEFAULT_PILOT_TYPE_FUEL_SAVING: f32 = 0.03;
+pub const DEFAULT_PILOT_ROUTE_FUEL_SAVING: f32 = 0.02;
+pub const DEFAULT_PILOT_QUICK_TURNAROUND_HOURS: u64 = 150;
+pub const DEFAULT_HANGARAGE_PER_PLANE: f32 = 150.0;
+pub const DEFAULT_OFFICE_BASE_COST: f32 = 250.0;
+pub const DEFAULT_OFFICE_COST_PER_PLANE: f32 = 40.0;
+pub const DEFAULT_TAX_PERIOD_DAYS: u64 = 30;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WorldConfig {
     /// Optional seed to keep deterministic behavior for generated pieces
     #[serde(default)]
@@ -26,6 +134,16 @@ pub struct WorldConfig {
     /// Number of airports to generate randomly when `airports` is empty
     #[serde(default)]
     pub num_airports: Option<usize>,
+    /// Names for the generated airports in id order, e.g. for a themed map; airports past
+    /// the end of the list get generated names
+    #[serde(default)]
+    pub airport_names: Vec<String>,
+    /// Layout used for generated airport locations
+    #[serde(default)]
+    pub world_style: WorldStyle,
+    /// Import airports from a real-world dataset instead of listing or generating them
+    #[serde(default)]
+    pub airport_dataset: Option<AirportDatasetConfig>,
     /// Optional gameplay tuning parameters
     #[serde(default)]
     pub gameplay: GameplayConfig,
@@ -35,19 +153,58 @@ pub struct WorldConfig {
     /// catalog entirely or by adding new models.
     #[serde(default)]
     pub airplanes: Option<AirplanesConfig>,
+    /// Planes the company owns at the start. When empty a single starter plane is
+    /// picked automatically.
+    #[serde(default)]
+    pub starting_fleet: Vec<StartingPlaneConfig>,
+    /// Lua scripts run at scheduler hook points; needs the `scripting` feature
+    #[serde(default)]
+    pub scripts: Vec<ScriptConfig>,
 }
 
 fn default_cash() -> f32 {
     650_000.0
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// JSON Schema (draft 2020-12) describing the YAML/JSON layout of a [`WorldConfig`].
+///
+/// Editors can use it for autocomplete and validation of scenario files. It only covers
+/// the shape of the document; value checks are done by [`WorldConfig::validate`].
+///
+/// Returns
+/// - `serde_json::Value`: The schema document.
+pub fn world_config_schema() -> serde_json::Value {
+    schemars::schema_for!(WorldConfig).to_value()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct GameplayConfig {
     pub restock_cycle_hours: u64,
     pub fuel_interval_hours: u64,
     pub orders: OrdersGameplay,
     pub fuel: FuelGameplay,
+    pub borders: BordersGameplay,
+    pub contracts: ContractsGameplay,
+    pub charters: ChartersGameplay,
+    pub forwarders: ForwardersGameplay,
+    pub auctions: AuctionsGameplay,
+    pub rare_cargo: RareCargoGameplay,
+    pub world_growth: WorldGrowthGameplay,
+    pub market: MarketGameplay,
+    pub loyalty: LoyaltyGameplay,
+    pub passenger_demand: PassengerDemandGameplay,
+    pub handling: HandlingGameplay,
+    pub warehouse: WarehouseGameplay,
+    pub electric: ElectricGameplay,
+    pub construction: ConstructionGameplay,
+    pub concessions: ConcessionsGameplay,
+    pub used_market: UsedMarketGameplay,
+    pub maintenance: MaintenanceGameplay,
+    pub pilots: PilotsGameplay,
+    pub fixed_costs: FixedCostsGameplay,
+    pub taxes: TaxesGameplay,
+    pub overdraft: OverdraftGameplay,
 }
 
 impl Default for GameplayConfig {
@@ -57,11 +214,793 @@ impl Default for GameplayConfig {
             fuel_interval_hours: DEFAULT_FUEL_INTERVAL_HOURS,
             orders: OrdersGameplay::default(),
             fuel: FuelGameplay::default(),
+            borders: BordersGameplay::default(),
+            contracts: ContractsGameplay::default(),
+            charters: ChartersGameplay::default(),
+            forwarders: ForwardersGameplay::default(),
+            auctions: AuctionsGameplay::default(),
+            rare_cargo: RareCargoGameplay::default(),
+            world_growth: WorldGrowthGameplay::default(),
+            market: MarketGameplay::default(),
+            loyalty: LoyaltyGameplay::default(),
+            passenger_demand: PassengerDemandGameplay::default(),
+            handling: HandlingGameplay::default(),
+            warehouse: WarehouseGameplay::default(),
+            electric: ElectricGameplay::default(),
+            construction: ConstructionGameplay::default(),
+            concessions: ConcessionsGameplay::default(),
+            used_market: UsedMarketGameplay::default(),
+            maintenance: MaintenanceGameplay::default(),
+            pilots: PilotsGameplay::default(),
+            fixed_costs: FixedCostsGameplay::default(),
+            taxes: TaxesGameplay::default(),
+            overdraft: OverdraftGameplay::default(),
+        }
+    }
+}
+
+/// Bulk contracts: large shipments fulfilled over many flights.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ContractsGameplay {
+    /// Open contracts kept on offer; new ones are drawn at every restock (0 disables them)
+    pub count: usize,
+    /// Smallest contract size in kg
+    pub min_weight: f32,
+    /// Largest contract size in kg
+    pub max_weight: f32,
+    /// Hours a contract stays open after it is offered
+    pub duration_hours: u64,
+    /// Multiplier applied to the regular cargo rate per kg
+    pub rate_multiplier: f32,
+    /// Share of the full contract value paid once all cargo is delivered
+    pub completion_bonus: f32,
+}
+
+impl Default for ContractsGameplay {
+    fn default() -> Self {
+        ContractsGameplay {
+            count: DEFAULT_CONTRACT_COUNT,
+            min_weight: DEFAULT_CONTRACT_MIN_WEIGHT,
+            max_weight: DEFAULT_CONTRACT_MAX_WEIGHT,
+            duration_hours: DEFAULT_CONTRACT_DURATION_HOURS,
+            rate_multiplier: DEFAULT_CONTRACT_RATE_MULTIPLIER,
+            completion_bonus: DEFAULT_CONTRACT_COMPLETION_BONUS,
+        }
+    }
+}
+
+/// One-off passenger charters: a group flown between two airports within a window.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ChartersGameplay {
+    /// Open charter requests kept on offer; topped up every day (0 disables them)
+    pub count: usize,
+    /// Smallest charter group
+    pub min_passengers: u32,
+    /// Largest charter group
+    pub max_passengers: u32,
+    /// Hours a request stays on offer before it lapses
+    pub offer_hours: u64,
+    /// Hours from acceptance until the group must have arrived
+    pub window_hours: u64,
+    /// Multiplier applied to the regular passenger fare per km
+    pub fare_multiplier: f32,
+}
+
+impl Default for ChartersGameplay {
+    fn default() -> Self {
+        ChartersGameplay {
+            count: DEFAULT_CHARTER_COUNT,
+            min_passengers: DEFAULT_CHARTER_MIN_PASSENGERS,
+            max_passengers: DEFAULT_CHARTER_MAX_PASSENGERS,
+            offer_hours: DEFAULT_CHARTER_OFFER_HOURS,
+            window_hours: DEFAULT_CHARTER_WINDOW_HOURS,
+            fare_multiplier: DEFAULT_CHARTER_FARE_MULTIPLIER,
+        }
+    }
+}
+
+/// Freight forwarders offering weekly cargo lanes at a fixed rate.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ForwardersGameplay {
+    /// Forwarders in the world, each working out of a hub airport (0 disables them)
+    pub count: usize,
+    /// Days between two offers from the same forwarder
+    pub offer_interval_days: u64,
+    /// Hours an offer stays open before it lapses
+    pub offer_hours: u64,
+    /// Fewest loads a week a lane asks for
+    pub min_loads_per_week: u32,
+    /// Most loads a week a lane asks for
+    pub max_loads_per_week: u32,
+    /// Lightest load in kg
+    pub min_load_weight: f32,
+    /// Heaviest load in kg
+    pub max_load_weight: f32,
+    /// Weeks a lane runs once accepted
+    pub weeks: u32,
+    /// Multiplier applied to the regular cargo rate per kg
+    pub rate_multiplier: f32,
+    /// Hours each load has to reach the destination
+    pub load_deadline_hours: u64,
+}
+
+impl Default for ForwardersGameplay {
+    fn default() -> Self {
+        ForwardersGameplay {
+            count: DEFAULT_FORWARDER_COUNT,
+            offer_interval_days: DEFAULT_FORWARDER_OFFER_INTERVAL_DAYS,
+            offer_hours: DEFAULT_FORWARDER_OFFER_HOURS,
+            min_loads_per_week: DEFAULT_LANE_MIN_LOADS_PER_WEEK,
+            max_loads_per_week: DEFAULT_LANE_MAX_LOADS_PER_WEEK,
+            min_load_weight: DEFAULT_LANE_MIN_LOAD_WEIGHT,
+            max_load_weight: DEFAULT_LANE_MAX_LOAD_WEIGHT,
+            weeks: DEFAULT_LANE_WEEKS,
+            rate_multiplier: DEFAULT_LANE_RATE_MULTIPLIER,
+            load_deadline_hours: DEFAULT_LANE_LOAD_DEADLINE_HOURS,
+        }
+    }
+}
+
+/// Spot-charter auctions: exceptional loads the player bids for against other carriers.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AuctionsGameplay {
+    /// Chance each day that a load is put up for auction (0 disables auctions)
+    pub chance_per_day: f32,
+    /// Hours bidding stays open
+    pub bidding_hours: u64,
+    /// Lightest auctioned load in kg
+    pub min_weight: f32,
+    /// Heaviest auctioned load in kg
+    pub max_weight: f32,
+    /// Shortest delivery deadline in hours
+    pub min_deadline_hours: u64,
+    /// Longest delivery deadline in hours
+    pub max_deadline_hours: u64,
+    /// Other carriers bidding for each load
+    pub competitors: u32,
+    /// Largest share a competitor's bid strays from the going rate
+    pub bid_spread: f32,
+    /// Extra on the going rate for a load due within a day; less urgent loads get less
+    pub urgency_premium: f32,
+}
+
+impl Default for AuctionsGameplay {
+    fn default() -> Self {
+        AuctionsGameplay {
+            chance_per_day: DEFAULT_AUCTION_CHANCE_PER_DAY,
+            bidding_hours: DEFAULT_AUCTION_BIDDING_HOURS,
+            min_weight: DEFAULT_AUCTION_MIN_WEIGHT,
+            max_weight: DEFAULT_AUCTION_MAX_WEIGHT,
+            min_deadline_hours: DEFAULT_AUCTION_MIN_DEADLINE_HOURS,
+            max_deadline_hours: DEFAULT_AUCTION_MAX_DEADLINE_HOURS,
+            competitors: DEFAULT_AUCTION_COMPETITORS,
+            bid_spread: DEFAULT_AUCTION_BID_SPREAD,
+            urgency_premium: DEFAULT_AUCTION_URGENCY_PREMIUM,
+        }
+    }
+}
+
+/// Rare cargo: exceptional loads such as organs for transplant that turn up without
+/// warning and must be flown in a hurry.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct RareCargoGameplay {
+    /// Chance each day that a rare load turns up (0 disables rare cargo)
+    pub chance_per_day: f32,
+    /// Multiplier applied to the regular cargo rate per kg
+    pub value_multiplier: f32,
+    /// Least a rare load pays, however light or short the trip
+    pub min_value: f32,
+}
+
+impl RareCargoGameplay {
+    /// No rare cargo, as in saves from before it turned up.
+    pub fn none() -> Self {
+        RareCargoGameplay {
+            chance_per_day: 0.0,
+            ..RareCargoGameplay::default()
+        }
+    }
+}
+
+impl Default for RareCargoGameplay {
+    fn default() -> Self {
+        RareCargoGameplay {
+            chance_per_day: DEFAULT_RARE_CARGO_CHANCE_PER_DAY,
+            value_multiplier: DEFAULT_RARE_CARGO_VALUE_MULTIPLIER,
+            min_value: DEFAULT_RARE_CARGO_MIN_VALUE,
+        }
+    }
+}
+
+/// World growth: new airports opening as the game goes on, on a seeded schedule or
+/// where the player is busy. Off unless a chance or an activity threshold is set.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WorldGrowthGameplay {
+    /// Chance each day that an airport opens near a random one (0 disables the schedule)
+    pub chance_per_day: f32,
+    /// Landings and departures within the loyalty window that make an airport open
+    /// nearby; every airport triggers this once (0 disables it)
+    pub activity_threshold: u32,
+    /// Most airports that open over a game
+    pub max_new_airports: usize,
+    /// Farthest a new airport opens from the one it grows out of, in km
+    pub radius_km: f32,
+}
+
+impl Default for WorldGrowthGameplay {
+    fn default() -> Self {
+        WorldGrowthGameplay {
+            chance_per_day: 0.0,
+            activity_threshold: 0,
+            max_new_airports: DEFAULT_WORLD_GROWTH_MAX_NEW_AIRPORTS,
+            radius_km: DEFAULT_WORLD_GROWTH_RADIUS_KM,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// How cargo rates react to the player: busy lanes pay less, neglected airports more.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MarketGameplay {
+    /// Recently delivered tonnes on a lane at which half of `max_discount` applies
+    pub saturation_tons: f32,
+    /// Largest share a saturated lane's rate can fall by
+    pub max_discount: f32,
+    /// Premium added for every restock an airport goes without a pickup
+    pub backlog_premium: f32,
+    /// Largest backlog premium
+    pub max_backlog_premium: f32,
+    /// Share of a lane's delivered tonnage still remembered after each restock
+    pub memory: f32,
+}
+
+impl MarketGameplay {
+    /// Fixed prices whatever the player does, as in saves from before market pricing.
+    pub fn none() -> Self {
+        MarketGameplay {
+            max_discount: 0.0,
+            backlog_premium: 0.0,
+            max_backlog_premium: 0.0,
+            ..MarketGameplay::default()
+        }
+    }
+}
+
+impl Default for MarketGameplay {
+    fn default() -> Self {
+        MarketGameplay {
+            saturation_tons: DEFAULT_MARKET_SATURATION_TONS,
+            max_discount: DEFAULT_MARKET_MAX_DISCOUNT,
+            backlog_premium: DEFAULT_MARKET_BACKLOG_PREMIUM,
+            max_backlog_premium: DEFAULT_MARKET_MAX_BACKLOG_PREMIUM,
+            memory: DEFAULT_MARKET_MEMORY,
+        }
+    }
+}
+
+/// Discount tier earned by operating often at one airport.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct LoyaltyTier {
+    pub name: String,
+    /// Landings and departures within the window needed to reach the tier
+    pub min_operations: u32,
+    /// Share taken off landing, parking and fuel bills
+    pub discount: f32,
+}
+
+impl LoyaltyTier {
+    fn new(name: &str, min_operations: u32, discount: f32) -> Self {
+        LoyaltyTier {
+            name: name.to_string(),
+            min_operations,
+            discount,
+        }
+    }
+}
+
+/// Airports the player uses a lot grant discounts on their fees.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LoyaltyGameplay {
+    /// Hours of operations counted towards a tier
+    pub window_hours: u64,
+    /// Tiers from least to most operations
+    pub tiers: Vec<LoyaltyTier>,
+}
+
+impl LoyaltyGameplay {
+    /// No tiers, as in saves from before loyalty discounts.
+    pub fn none() -> Self {
+        LoyaltyGameplay {
+            tiers: Vec::new(),
+            ..LoyaltyGameplay::default()
+        }
+    }
+
+    /// Best tier reached with `operations` landings and departures in the window.
+    pub fn tier(&self, operations: u32) -> Option<&LoyaltyTier> {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|t| operations >= t.min_operations)
+    }
+}
+
+impl Default for LoyaltyGameplay {
+    fn default() -> Self {
+        LoyaltyGameplay {
+            window_hours: DEFAULT_LOYALTY_WINDOW_HOURS,
+            tiers: vec![
+                LoyaltyTier::new("Bronze", 5, 0.05),
+                LoyaltyTier::new("Silver", 10, 0.1),
+                LoyaltyTier::new("Gold", 20, 0.15),
+            ],
+        }
+    }
+}
+
+/// Daily passenger demand between airport pairs, drawn from a gravity model.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PassengerDemandGameplay {
+    /// Passengers per day between two 1 km-runway airports 100 km apart (0 disables demand)
+    pub scale: f32,
+    /// How quickly demand falls off with distance
+    pub distance_exponent: f32,
+    /// Largest day-to-day swing around the model value, as a share of it
+    pub daily_variation: f32,
+    /// How strongly scheduled-route demand reacts to a fare away from the reference fare
+    pub price_elasticity: f32,
+}
+
+impl Default for PassengerDemandGameplay {
+    fn default() -> Self {
+        PassengerDemandGameplay {
+            scale: DEFAULT_DEMAND_SCALE,
+            distance_exponent: DEFAULT_DEMAND_DISTANCE_EXPONENT,
+            daily_variation: DEFAULT_DEMAND_DAILY_VARIATION,
+            price_elasticity: DEFAULT_DEMAND_PRICE_ELASTICITY,
+        }
+    }
+}
+
+/// Ground handling: how long loading and unloading keep a plane busy.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HandlingGameplay {
+    /// Tonnes moved per hour at an airport of size 1; larger airports handle more
+    pub tons_per_hour: f32,
+    /// Shortest time any loading or unloading takes
+    pub min_hours: u64,
+    /// Daily wage of one ground crew member hired at an airport
+    pub crew_wage_per_day: f32,
+    /// Extra handling rate each crew member adds, as a share of the base rate
+    pub crew_rate_bonus: f32,
+    /// Most crew members the player can employ at one airport
+    pub max_crew: u32,
+}
+
+impl Default for HandlingGameplay {
+    fn default() -> Self {
+        HandlingGameplay {
+            tons_per_hour: DEFAULT_HANDLING_TONS_PER_HOUR,
+            min_hours: DEFAULT_HANDLING_MIN_HOURS,
+            crew_wage_per_day: DEFAULT_CREW_WAGE_PER_DAY,
+            crew_rate_bonus: DEFAULT_CREW_RATE_BONUS,
+            max_crew: DEFAULT_MAX_CREW,
+        }
+    }
+}
+
+/// Storage fees for cargo left at an airport other than its destination.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WarehouseGameplay {
+    /// Daily fee per tonne of staged cargo
+    pub fee_per_ton_per_day: f32,
+    /// Hours staged cargo is stored for free
+    pub free_hours: u64,
+}
+
+impl Default for WarehouseGameplay {
+    fn default() -> Self {
+        WarehouseGameplay {
+            fee_per_ton_per_day: DEFAULT_WAREHOUSE_FEE_PER_TON,
+            free_hours: DEFAULT_WAREHOUSE_FREE_HOURS,
+        }
+    }
+}
+
+/// Chargers for electric planes.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ElectricGameplay {
+    /// Price of installing a charger at an airport
+    pub charger_cost: f32,
+    /// Charging power (kW) of an installed charger
+    pub charger_kw: f32,
+    /// Price paid per kWh charged
+    pub price_per_kwh: f32,
+}
+
+impl Default for ElectricGameplay {
+    fn default() -> Self {
+        ElectricGameplay {
+            charger_cost: DEFAULT_CHARGER_COST,
+            charger_kw: DEFAULT_CHARGER_KW,
+            price_per_kwh: DEFAULT_ELECTRICITY_PRICE_PER_KWH,
+        }
+    }
+}
+
+/// Airstrips the player builds and the runway extensions that grow them.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ConstructionGameplay {
+    /// Price of building an airstrip
+    pub airstrip_cost: f32,
+    /// Hours until a new airstrip opens
+    pub airstrip_hours: u64,
+    /// Runway length (m) a new airstrip opens with
+    pub airstrip_runway_m: f32,
+    /// Closest (km) an airstrip may be built to another airport
+    pub min_spacing_km: f32,
+    /// Price of one runway extension
+    pub extension_cost: f32,
+    /// Hours one runway extension takes
+    pub extension_hours: u64,
+    /// Meters of runway one extension adds
+    pub extension_m: f32,
+    /// Longest runway (m) extensions can reach
+    pub max_runway_m: f32,
+}
+
+impl Default for ConstructionGameplay {
+    fn default() -> Self {
+        ConstructionGameplay {
+            airstrip_cost: DEFAULT_AIRSTRIP_COST,
+            airstrip_hours: DEFAULT_AIRSTRIP_BUILD_HOURS,
+            airstrip_runway_m: DEFAULT_AIRSTRIP_RUNWAY_M,
+            min_spacing_km: DEFAULT_MIN_SITE_SPACING_KM,
+            extension_cost: DEFAULT_RUNWAY_EXTENSION_COST,
+            extension_hours: DEFAULT_RUNWAY_EXTENSION_HOURS,
+            extension_m: DEFAULT_RUNWAY_EXTENSION_M,
+            max_runway_m: DEFAULT_MAX_BUILT_RUNWAY_M,
+        }
+    }
+}
+
+/// Fuel concessions the player can buy at airports.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ConcessionsGameplay {
+    /// Price of a concession per unit of airport size
+    pub cost_per_size: f32,
+    /// Share (0..1) taken off the airport's base fuel price for the player's planes
+    pub discount: f32,
+    /// Average daily income from third-party traffic per unit of airport size
+    pub income_per_size_per_day: f32,
+}
+
+impl Default for ConcessionsGameplay {
+    fn default() -> Self {
+        ConcessionsGameplay {
+            cost_per_size: DEFAULT_CONCESSION_COST_PER_SIZE,
+            discount: DEFAULT_CONCESSION_DISCOUNT,
+            income_per_size_per_day: DEFAULT_CONCESSION_INCOME_PER_SIZE,
+        }
+    }
+}
+
+/// Second-hand aircraft offered for sale at large airports.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct UsedMarketGameplay {
+    /// Listings kept on offer; new ones are drawn at every restock (0 disables the market)
+    pub count: usize,
+    /// Shortest runway in meters an airport needs to host listings
+    pub min_runway_m: f32,
+    /// Largest discount off a listed plane's fair value (0..1)
+    pub max_discount: f32,
+    /// Hours a listing stays on offer
+    pub listing_hours: u64,
+}
+
+impl Default for UsedMarketGameplay {
+    fn default() -> Self {
+        UsedMarketGameplay {
+            count: DEFAULT_USED_LISTING_COUNT,
+            min_runway_m: DEFAULT_USED_MIN_RUNWAY_M,
+            max_discount: DEFAULT_USED_MAX_DISCOUNT,
+            listing_hours: DEFAULT_USED_LISTING_HOURS,
+        }
+    }
+}
+
+/// Interval, downtime and price of one type of scheduled check.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CheckSettings {
+    /// Flight hours allowed between two checks before the plane is grounded
+    pub interval_hours: u64,
+    /// Hours the plane spends in the check
+    pub downtime_hours: u64,
+    /// Price of the check as a share of the plane's purchase price
+    pub cost_share: f32,
+    /// Spare-part kits the check uses up at the plane's airport
+    #[serde(default)]
+    pub spare_kits: u32,
+}
+
+/// Scheduled A/B/C maintenance checks.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MaintenanceGameplay {
+    pub a_check: CheckSettings,
+    pub b_check: CheckSettings,
+    pub c_check: CheckSettings,
+    /// Chance that a freshly maintained plane breaks down on landing; grows with wear
+    pub landing_failure_chance: f32,
+    /// Parts cost of a repair as a share of the plane's purchase price
+    pub repair_cost_share: f32,
+    /// Hours a repair keeps the plane in the workshop
+    pub repair_hours: u64,
+    /// Spare-part kits a repair uses up at the plane's airport
+    pub repair_kits: u32,
+    /// Price of one spare-part kit at an airport with a hangar
+    pub spare_kit_price: f32,
+    /// Weight in kg of one kit when shipped as cargo
+    pub spare_kit_weight: f32,
+    /// Kits in stock at every airport with a hangar when the game starts
+    pub starting_spare_kits: u32,
+}
+
+impl Default for MaintenanceGameplay {
+    fn default() -> Self {
+        MaintenanceGameplay {
+            a_check: CheckSettings {
+                interval_hours: DEFAULT_A_CHECK_INTERVAL_HOURS,
+                downtime_hours: 2,
+                cost_share: 0.002,
+                spare_kits: 0,
+            },
+            b_check: CheckSettings {
+                interval_hours: DEFAULT_B_CHECK_INTERVAL_HOURS,
+                downtime_hours: 12,
+                cost_share: 0.01,
+                spare_kits: 1,
+            },
+            c_check: CheckSettings {
+                interval_hours: DEFAULT_C_CHECK_INTERVAL_HOURS,
+                downtime_hours: 72,
+                cost_share: 0.04,
+                spare_kits: 3,
+            },
+            landing_failure_chance: DEFAULT_LANDING_FAILURE_CHANCE,
+            repair_cost_share: DEFAULT_REPAIR_COST_SHARE,
+            repair_hours: DEFAULT_REPAIR_HOURS,
+            repair_kits: DEFAULT_REPAIR_KITS,
+            spare_kit_price: DEFAULT_SPARE_KIT_PRICE,
+            spare_kit_weight: DEFAULT_SPARE_KIT_WEIGHT,
+            starting_spare_kits: DEFAULT_STARTING_SPARE_KITS,
+        }
+    }
+}
+
+/// Pilots, their duty-time limits and pay.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PilotsGameplay {
+    /// Whether every departure needs a rested pilot
+    pub required: bool,
+    /// One-off fee to hire a pilot
+    pub hire_fee: f32,
+    /// Salary per pilot, paid once a day
+    pub salary_per_day: f32,
+    /// Flight hours a pilot may log before resting
+    pub max_duty_hours: u64,
+    /// Hours on the ground after which a pilot's duty time starts over
+    pub rest_hours: u64,
+    /// Hours on a model after which a pilot is familiar with it
+    pub type_rating_hours: u64,
+    /// Departures on a route after which a pilot is familiar with it
+    pub familiar_route_flights: u32,
+    /// Share of fuel saved when the pilot is familiar with the model (0..1)
+    pub type_fuel_saving: f32,
+    /// Share of fuel saved when the pilot is familiar with the route (0..1)
+    pub route_fuel_saving: f32,
+    /// Hours on a model after which loading and refueling finish without the hour's wait
+    pub quick_turnaround_hours: u64,
+}
+
+impl Default for PilotsGameplay {
+    fn default() -> Self {
+        PilotsGameplay {
+            required: true,
+            hire_fee: DEFAULT_PILOT_HIRE_FEE,
+            salary_per_day: DEFAULT_PILOT_SALARY_PER_DAY,
+            max_duty_hours: DEFAULT_PILOT_MAX_DUTY_HOURS,
+            rest_hours: DEFAULT_PILOT_REST_HOURS,
+            type_rating_hours: DEFAULT_PILOT_TYPE_RATING_HOURS,
+            familiar_route_flights: DEFAULT_PILOT_FAMILIAR_ROUTE_FLIGHTS,
+            type_fuel_saving: DEFAULT_PILOT_TYPE_FUEL_SAVING,
+            route_fuel_saving: DEFAULT_PILOT_ROUTE_FUEL_SAVING,
+            quick_turnaround_hours: DEFAULT_PILOT_QUICK_TURNAROUND_HOURS,
+        }
+    }
+}
+
+/// Daily overheads charged whether the fleet flies or not.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct FixedCostsGameplay {
+    /// Hangarage per plane in the fleet, per day
+    pub hangarage_per_plane: f32,
+    /// Office overhead per day for running the company at all
+    pub office_base: f32,
+    /// Extra office overhead per plane in the fleet, per day
+    pub office_per_plane: f32,
+}
+
+impl Default for FixedCostsGameplay {
+    fn default() -> Self {
+        FixedCostsGameplay {
+            hangarage_per_plane: DEFAULT_HANGARAGE_PER_PLANE,
+            office_base: DEFAULT_OFFICE_BASE_COST,
+            office_per_plane: DEFAULT_OFFICE_COST_PER_PLANE,
+        }
+    }
+}
+
+impl FixedCostsGameplay {
+    /// Free of any fixed costs, as in games saved before they existed.
+    pub fn none() -> Self {
+        FixedCostsGameplay {
+            hangarage_per_plane: 0.0,
+            office_base: 0.0,
+            office_per_plane: 0.0,
+        }
+    }
+
+    /// Daily hangarage for a fleet of `planes`.
+    pub fn hangarage(&self, planes: usize) -> f32 {
+        self.hangarage_per_plane * planes as f32
+    }
+
+    /// Daily office overhead for a fleet of `planes`.
+    pub fn overhead(&self, planes: usize) -> f32 {
+        self.office_base + self.office_per_plane * planes as f32
+    }
+}
+
+/// Profit tax and regional fee levels.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TaxesGameplay {
+    /// Share of the profit made over a tax period paid as tax (0 disables the tax)
+    pub profit_tax_rate: f32,
+    /// Days in a tax period; losses carry over to the next period
+    pub period_days: u64,
+    /// Multiplier on landing and parking fees at airports in a country, keyed by country name
+    pub fee_multipliers: BTreeMap<String, f32>,
+    /// Tax charged per tonne of CO2 the fleet emits, with the daily report (0 disables it)
+    pub carbon_tax_per_ton: f32,
+}
+
+impl Default for TaxesGameplay {
+    fn default() -> Self {
+        TaxesGameplay {
+            profit_tax_rate: 0.0,
+            period_days: DEFAULT_TAX_PERIOD_DAYS,
+            fee_multipliers: BTreeMap::new(),
+            carbon_tax_per_ton: 0.0,
+        }
+    }
+}
+
+impl TaxesGameplay {
+    /// Multiplier on airport fees in `country`; 1.0 where none is configured.
+    pub fn fee_multiplier(&self, country: Option<&str>) -> f32 {
+        country
+            .and_then(|c| self.fee_multipliers.get(c))
+            .copied()
+            .unwrap_or(1.0)
+    }
+}
+
+/// Overdraft facility letting cash go below zero.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct OverdraftGameplay {
+    /// How far below zero cash may be spent; purchases and refuels beyond it are refused
+    pub limit: f32,
+    /// Share of the overdrawn amount charged as interest with every daily report
+    pub daily_interest_rate: f32,
+    /// Share of the limit in use at which a warning is sent
+    pub warning_share: f32,
+}
+
+impl Default for OverdraftGameplay {
+    fn default() -> Self {
+        OverdraftGameplay {
+            limit: DEFAULT_OVERDRAFT_LIMIT,
+            daily_interest_rate: DEFAULT_OVERDRAFT_DAILY_INTEREST,
+            warning_share: DEFAULT_OVERDRAFT_WARNING_SHARE,
+        }
+    }
+}
+
+impl OverdraftGameplay {
+    /// No overdraft and no interest, as in games saved before the facility existed.
+    pub fn none() -> Self {
+        OverdraftGameplay {
+            limit: 0.0,
+            daily_interest_rate: 0.0,
+            warning_share: DEFAULT_OVERDRAFT_WARNING_SHARE,
+        }
+    }
+}
+
+impl MaintenanceGameplay {
+    /// Settings for one check type.
+    pub fn check(&self, check: CheckType) -> &CheckSettings {
+        match check {
+            CheckType::A => &self.a_check,
+            CheckType::B => &self.b_check,
+            CheckType::C => &self.c_check,
+        }
+    }
+
+    /// Flight hours left before `check` is due; negative once it is overdue.
+    pub fn hours_until_due(&self, hours: &CheckHours, check: CheckType) -> i64 {
+        self.check(check).interval_hours as i64 - hours.since(check) as i64
+    }
+
+    /// Heaviest check a plane with these counters has run past, if any.
+    pub fn overdue(&self, hours: &CheckHours) -> Option<CheckType> {
+        [CheckType::C, CheckType::B, CheckType::A]
+            .into_iter()
+            .find(|check| hours.since(*check) >= self.check(*check).interval_hours)
+    }
+}
+
+/// Countries and the fees charged when crossing between them.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct BordersGameplay {
+    /// Number of countries generated maps are split into (0 keeps a borderless world).
+    /// Explicit airports use their `country` field and datasets their ISO country instead.
+    pub countries: usize,
+    /// Share of an order's value withheld as customs on cross-border deliveries
+    pub customs_rate: f32,
+    /// $ per km charged when a flight lands in a different country than it left
+    pub overflight_fee_per_km: f32,
+    /// Cargo types that are never generated for international orders
+    pub restricted_cargo: Vec<CargoType>,
+}
+
+impl Default for BordersGameplay {
+    fn default() -> Self {
+        BordersGameplay {
+            countries: 0,
+            customs_rate: DEFAULT_CUSTOMS_RATE,
+            overflight_fee_per_km: DEFAULT_OVERFLIGHT_FEE_PER_KM,
+            restricted_cargo: vec![
+                CargoType::Pharmaceuticals,
+                CargoType::Chemicals,
+                CargoType::NitroFuel,
+                CargoType::LiveAlpacas,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct FuelGameplay {
     /// Elasticity applied when prices adjust (fractional step size per interval)
@@ -70,6 +1009,20 @@ pub struct FuelGameplay {
     pub min_price_multiplier: f32,
     /// Upper bound multiplier relative to the base fuel price
     pub max_price_multiplier: f32,
+    /// Give every airport unlimited fuel storage
+    pub unlimited_stock: bool,
+    /// Multiplier applied to each airport's fuel storage size
+    pub stock_multiplier: f32,
+    /// Minutes of burn every plane must keep in the tank on top of its trip fuel
+    pub reserve_minutes: f32,
+    /// Chance per flight hour that headwinds burn extra fuel
+    pub headwind_chance: f32,
+    /// Minutes of extra burn caused by one hour of headwinds
+    pub headwind_burn_minutes: f32,
+    /// Fee charged when a plane declares an emergency landing
+    pub emergency_landing_fee: f32,
+    /// Chance that an emergency landing damages fragile cargo
+    pub emergency_damage_chance: f32,
 }
 
 impl Default for FuelGameplay {
@@ -78,11 +1031,18 @@ impl Default for FuelGameplay {
             elasticity: 0.04,
             min_price_multiplier: 0.6,
             max_price_multiplier: 1.3,
+            unlimited_stock: false,
+            stock_multiplier: 1.0,
+            reserve_minutes: 45.0,
+            headwind_chance: 0.03,
+            headwind_burn_minutes: 30.0,
+            emergency_landing_fee: 5_000.0,
+            emergency_damage_chance: 0.5,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct OrdersGameplay {
     pub regenerate: bool,
@@ -91,6 +1051,8 @@ pub struct OrdersGameplay {
     pub tuning: OrderTuning,
     #[serde(default)]
     pub passengers: PassengerTuning,
+    /// Share of an order's value charged to reserve it from another airport
+    pub reservation_share: f32,
 }
 
 impl Default for OrdersGameplay {
@@ -100,11 +1062,12 @@ impl Default for OrdersGameplay {
             generate_initial: true,
             tuning: OrderTuning::default(),
             passengers: PassengerTuning::default(),
+            reservation_share: DEFAULT_RESERVATION_SHARE,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct OrderTuning {
     pub max_deadline_hours: u64,
@@ -112,6 +1075,14 @@ pub struct OrderTuning {
     pub max_weight: f32,
     pub alpha: f32,
     pub beta: f32,
+    /// Share of cargo orders generated as express (tight deadline, premium value)
+    pub express_share: f32,
+    /// Value multiplier applied to express orders
+    pub express_multiplier: f32,
+    /// Share of cargo orders generated as economy (relaxed deadline, low value)
+    pub economy_share: f32,
+    /// Value multiplier applied to economy orders
+    pub economy_multiplier: f32,
 }
 
 impl Default for OrderTuning {
@@ -122,11 +1093,15 @@ impl Default for OrderTuning {
             max_weight: DEFAULT_MAX_WEIGHT,
             alpha: DEFAULT_ALPHA,
             beta: DEFAULT_BETA,
+            express_share: DEFAULT_EXPRESS_SHARE,
+            express_multiplier: DEFAULT_EXPRESS_MULTIPLIER,
+            economy_share: DEFAULT_ECONOMY_SHARE,
+            economy_multiplier: DEFAULT_ECONOMY_MULTIPLIER,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct PassengerTuning {
     pub max_deadline_hours: u64,
@@ -158,6 +1133,12 @@ impl From<&OrderTuning> for OrderGenerationParams {
             max_weight: value.max_weight,
             alpha: value.alpha,
             beta: value.beta,
+            restricted_cargo: Vec::new(),
+            market: MarketGameplay::default(),
+            express_share: value.express_share,
+            express_multiplier: value.express_multiplier,
+            economy_share: value.economy_share,
+            economy_multiplier: value.economy_multiplier,
         }
     }
 }
@@ -180,7 +1161,7 @@ impl From<&PassengerTuning> for PassengerGenerationParams {
 // ==========================
 
 /// Strategy for applying user-provided airplane models.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum AirplaneCatalogStrategy {
     /// Replace the default airplane catalog entirely.
@@ -191,7 +1172,7 @@ pub enum AirplaneCatalogStrategy {
 }
 
 /// User-provided airplane model configuration. All fields are required.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AirplaneModelConfig {
     /// Unique model name (case-insensitive for matching) — e.g. "MyCargo100".
     pub name: String,
@@ -199,9 +1180,9 @@ pub struct AirplaneModelConfig {
     pub mtow: f32,
     /// Cruise speed (km/h)
     pub cruise_speed: f32,
-    /// Fuel tank capacity (liters)
+    /// Fuel tank capacity (liters), or battery capacity (kWh) for electric models
     pub fuel_capacity: f32,
-    /// Fuel burn rate (liters per hour)
+    /// Fuel burn rate (liters per hour), or power draw (kW) for electric models
     pub fuel_consumption: f32,
     /// Operating cost ($ per hour)
     pub operating_cost: f32,
@@ -209,16 +1190,52 @@ pub struct AirplaneModelConfig {
     pub payload_capacity: f32,
     /// Passenger capacity (people)
     pub passenger_capacity: u32,
+    /// Distinct cargo orders carried at once (default 0, no limit)
+    #[serde(default)]
+    pub cargo_slots: u32,
     /// Purchase price ($)
     pub purchase_price: f32,
     /// Minimum runway length required (meters)
     pub min_runway_length: f32,
     /// Primary mission role ("cargo", "passenger", or "mixed")
     pub role: crate::utils::airplanes::models::AirplaneRole,
+    /// "Fuel" (default) or "Electric"
+    #[serde(default)]
+    pub power: crate::utils::airplanes::models::PowerSource,
+}
+
+/// A plane the player already owns when the game starts.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StartingPlaneConfig {
+    /// Model name from the airplane catalog (case-insensitive)
+    pub model: String,
+    /// Airport id the plane is parked at
+    pub home_airport: usize,
+    /// Fraction of the fuel tank that is filled (0.0-1.0, default full)
+    #[serde(default = "default_fuel_level")]
+    pub fuel_level: f32,
+}
+
+fn default_fuel_level() -> f32 {
+    1.0
+}
+
+/// A Lua script with custom scenario logic, see [`crate::scripting`].
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScriptConfig {
+    /// Label used in messages; defaults to the path
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Path to a `.lua` file
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Inline Lua source, instead of `path`
+    #[serde(default)]
+    pub source: Option<String>,
 }
 
 /// Optional airplane configuration block.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct AirplanesConfig {
     /// Whether to replace the default catalog or add to it.
@@ -236,7 +1253,23 @@ impl Default for AirplanesConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Real-world airport import (OurAirports-style CSV).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AirportDatasetConfig {
+    /// Path to the CSV file
+    pub path: String,
+    /// ISO country codes to keep; empty keeps every country
+    #[serde(default)]
+    pub countries: Vec<String>,
+    /// Keep at most this many airports, preferring the largest ones
+    #[serde(default)]
+    pub max_airports: Option<usize>,
+    /// `planar` projects airports onto the flat map, `geographic` keeps latitude/longitude
+    #[serde(default)]
+    pub coordinates: CoordinateSystem,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AirportConfig {
     pub id: usize,
     pub name: String,
@@ -254,18 +1287,30 @@ pub struct AirportConfig {
     /// $ per hour
     #[serde(default)]
     pub parking_fee_per_hour: Option<f32>,
+    /// Whether the airport has a maintenance hangar; defaults to runways of at least 1500 m
+    #[serde(default)]
+    pub has_hangar: Option<bool>,
+    /// Residents of the catchment area; defaults to 100 per meter of runway
+    #[serde(default)]
+    pub population: Option<u32>,
+    /// Hours ahead of UTC (-12 to 14); defaults to the time zone of the location
+    #[serde(default)]
+    pub utc_offset: Option<i32>,
+    /// Country or region this airport belongs to
+    #[serde(default)]
+    pub country: Option<String>,
     /// Static orders that should exist at the start of the game
     #[serde(default)]
     pub orders: Vec<ManualOrderConfig>,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, Default)]
 pub struct Location {
     pub x: f32,
     pub y: f32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ManualOrderConfig {
     Cargo {
@@ -274,6 +1319,8 @@ pub enum ManualOrderConfig {
         value: f32,
         deadline_hours: u64,
         destination_id: usize,
+        #[serde(default)]
+        tier: OrderTier,
     },
     Passengers {
         passengers: u32,
@@ -282,3 +1329,970 @@ pub enum ManualOrderConfig {
         destination_id: usize,
     },
 }
+
+// ==========================
+// Validation
+// ==========================
+
+/// A single problem found while validating a [`WorldConfig`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    /// YAML path of the offending value, e.g. `airports[2].orders[0].weight`
+    pub path: String,
+    /// Human readable description of the problem
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        ConfigIssue {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl WorldConfig {
+    /// Check the config for problems without building a world.
+    ///
+    /// Covers the airport source (`airports`/`num_airports`/`airport_dataset`), gameplay
+    /// tuning, explicit airports and their manual orders, custom airplane models and
+    /// starting fleet fuel levels. Checks that need the built map (e.g. whether a starting
+    /// plane fits its home runway) or the dataset file are left to [`crate::Game::from_config`].
+    ///
+    /// Returns
+    /// - `Vec<ConfigIssue>`: Every problem found, in document order; empty when valid.
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        self.gameplay.collect_issues(&mut issues);
+
+        let have_explicit_airports = !self.airports.is_empty();
+        if have_explicit_airports && self.num_airports.is_some() {
+            issues.push(ConfigIssue::new(
+                "num_airports",
+                "num_airports cannot be provided when airports are explicitly listed",
+            ));
+        }
+        if !self.airport_names.is_empty() && self.num_airports.is_none() {
+            issues.push(ConfigIssue::new(
+                "airport_names",
+                "airport_names only names generated airports; set num_airports",
+            ));
+        }
+        let mut names = std::collections::HashSet::new();
+        for (idx, name) in self.airport_names.iter().enumerate() {
+            if name.trim().is_empty() {
+                issues.push(ConfigIssue::new(
+                    format!("airport_names[{}]", idx),
+                    "airport name cannot be empty",
+                ));
+            } else if !names.insert(name.to_lowercase()) {
+                issues.push(ConfigIssue::new(
+                    format!("airport_names[{}]", idx),
+                    format!("duplicate airport name '{}'", name),
+                ));
+            }
+        }
+        if self.airport_dataset.is_some() && (have_explicit_airports || self.num_airports.is_some())
+        {
+            issues.push(ConfigIssue::new(
+                "airport_dataset",
+                "airport_dataset cannot be combined with airports or num_airports",
+            ));
+        }
+        if !have_explicit_airports && self.num_airports.is_none() && self.airport_dataset.is_none()
+        {
+            issues.push(ConfigIssue::new(
+                "num_airports",
+                "num_airports must be provided when airports list is empty",
+            ));
+        }
+        if self.num_airports == Some(0) {
+            issues.push(ConfigIssue::new(
+                "num_airports",
+                "num_airports must be greater than 0",
+            ));
+        }
+        if !self.gameplay.orders.regenerate && !have_explicit_airports {
+            issues.push(ConfigIssue::new(
+                "gameplay.orders.regenerate",
+                "orders.regenerate=false requires explicit airports with manual orders",
+            ));
+        }
+
+        self.collect_airport_issues(&mut issues);
+
+        if let Some(airplanes) = &self.airplanes {
+            let mut seen = std::collections::HashSet::new();
+            for (idx, model) in airplanes.models.iter().enumerate() {
+                let path = format!("airplanes.models[{}]", idx);
+                model.collect_issues(&path, &mut issues);
+                if !seen.insert(model.name.trim().to_lowercase()) {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.name", path),
+                        format!("duplicate airplane model name '{}'", model.name),
+                    ));
+                }
+            }
+        }
+
+        for (idx, plane) in self.starting_fleet.iter().enumerate() {
+            if !(0.0..=1.0).contains(&plane.fuel_level) {
+                issues.push(ConfigIssue::new(
+                    format!("starting_fleet[{}].fuel_level", idx),
+                    format!("starting_fleet[{}] fuel_level must be between 0 and 1", idx),
+                ));
+            }
+        }
+
+        for (idx, script) in self.scripts.iter().enumerate() {
+            if script.path.is_some() == script.source.is_some() {
+                issues.push(ConfigIssue::new(
+                    format!("scripts[{}]", idx),
+                    format!("scripts[{}] needs exactly one of path or source", idx),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    fn collect_airport_issues(&self, issues: &mut Vec<ConfigIssue>) {
+        let mut ids = std::collections::HashSet::new();
+        let mut names = std::collections::HashSet::new();
+        for (idx, a) in self.airports.iter().enumerate() {
+            if !ids.insert(a.id) {
+                issues.push(ConfigIssue::new(
+                    format!("airports[{}].id", idx),
+                    format!("duplicate airport id {}", a.id),
+                ));
+            }
+            if !names.insert(a.name.to_lowercase()) {
+                issues.push(ConfigIssue::new(
+                    format!("airports[{}].name", idx),
+                    format!("duplicate airport name '{}'", a.name),
+                ));
+            }
+        }
+
+        let uses_countries = self.airports.iter().any(|a| a.country.is_some());
+        for (idx, a) in self.airports.iter().enumerate() {
+            let path = format!("airports[{}]", idx);
+            if a.runway_length_m.is_some_and(|len| len <= 0.0) {
+                issues.push(ConfigIssue::new(
+                    format!("{}.runway_length_m", path),
+                    format!("airport {} runway_length must be > 0", a.id),
+                ));
+            }
+            if a.population == Some(0) {
+                issues.push(ConfigIssue::new(
+                    format!("{}.population", path),
+                    format!("airport {} population must be > 0", a.id),
+                ));
+            }
+            if a.utc_offset
+                .is_some_and(|offset| !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&offset))
+            {
+                issues.push(ConfigIssue::new(
+                    format!("{}.utc_offset", path),
+                    format!("airport {} utc_offset must be between -12 and 14", a.id),
+                ));
+            }
+            if a.fuel_price_per_l.is_some_and(|price| price <= 0.0) {
+                issues.push(ConfigIssue::new(
+                    format!("{}.fuel_price_per_l", path),
+                    format!("airport {} fuel_price_per_l must be > 0", a.id),
+                ));
+            }
+            if a.landing_fee_per_ton.is_some_and(|fee| fee < 0.0) {
+                issues.push(ConfigIssue::new(
+                    format!("{}.landing_fee_per_ton", path),
+                    format!("airport {} landing_fee_per_ton must be >= 0", a.id),
+                ));
+            }
+            if a.parking_fee_per_hour.is_some_and(|fee| fee < 0.0) {
+                issues.push(ConfigIssue::new(
+                    format!("{}.parking_fee_per_hour", path),
+                    format!("airport {} parking_fee_per_hour must be >= 0", a.id),
+                ));
+            }
+            if let Some(loc) = a.location {
+                if !(0.0..=10000.0).contains(&loc.x) || !(0.0..=10000.0).contains(&loc.y) {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.location", path),
+                        format!(
+                            "airport {} location ({:.2},{:.2}) out of bounds [0,10000]",
+                            a.id, loc.x, loc.y
+                        ),
+                    ));
+                }
+            }
+            if uses_countries && a.country.is_none() {
+                issues.push(ConfigIssue::new(
+                    format!("{}.country", path),
+                    format!("airport {} has no country while others do", a.id),
+                ));
+            }
+            if !self.gameplay.orders.regenerate && a.orders.is_empty() {
+                issues.push(ConfigIssue::new(
+                    format!("{}.orders", path),
+                    format!(
+                        "airport {} must define at least one order when regeneration is disabled",
+                        a.id
+                    ),
+                ));
+            }
+
+            for (order_idx, order) in a.orders.iter().enumerate() {
+                let order_path = format!("{}.orders[{}]", path, order_idx);
+                let (kind, deadline_hours, value, destination_id) = match order {
+                    ManualOrderConfig::Cargo {
+                        weight,
+                        value,
+                        deadline_hours,
+                        destination_id,
+                        ..
+                    } => {
+                        if *weight <= 0.0 {
+                            issues.push(ConfigIssue::new(
+                                format!("{}.weight", order_path),
+                                format!("airport {} has order with non-positive weight", a.id),
+                            ));
+                        }
+                        ("order", deadline_hours, value, destination_id)
+                    }
+                    ManualOrderConfig::Passengers {
+                        passengers,
+                        value,
+                        deadline_hours,
+                        destination_id,
+                    } => {
+                        if *passengers == 0 {
+                            issues.push(ConfigIssue::new(
+                                format!("{}.passengers", order_path),
+                                format!(
+                                    "airport {} has passenger order with zero passengers",
+                                    a.id
+                                ),
+                            ));
+                        }
+                        ("passenger order", deadline_hours, value, destination_id)
+                    }
+                };
+                if *deadline_hours == 0 {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.deadline_hours", order_path),
+                        format!("airport {} has {} with deadline_hours == 0", a.id, kind),
+                    ));
+                }
+                if *value < 0.0 {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.value", order_path),
+                        format!("airport {} has {} with negative value", a.id, kind),
+                    ));
+                }
+                if *destination_id == a.id {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.destination_id", order_path),
+                        format!("airport {} has {} pointing to itself", a.id, kind),
+                    ));
+                } else if !ids.contains(destination_id) {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.destination_id", order_path),
+                        format!(
+                            "airport {} has {} with unknown destination {}",
+                            a.id, kind, destination_id
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl GameplayConfig {
+    fn collect_issues(&self, issues: &mut Vec<ConfigIssue>) {
+        let mut check = |ok: bool, path: &str, message: &str| {
+            if !ok {
+                issues.push(ConfigIssue::new(format!("gameplay.{}", path), message));
+            }
+        };
+        let tuning = &self.orders.tuning;
+        let passengers = &self.orders.passengers;
+        check(
+            tuning.max_deadline_hours > 0,
+            "orders.max_deadline_hours",
+            "orders.max_deadline_hours must be at least 1",
+        );
+        check(
+            (0.0..=1.0).contains(&self.orders.reservation_share),
+            "orders.reservation_share",
+            "orders.reservation_share must be in [0, 1]",
+        );
+        check(
+            tuning.min_weight > 0.0,
+            "orders.min_weight",
+            "orders.min_weight must be greater than 0",
+        );
+        check(
+            tuning.max_weight >= tuning.min_weight,
+            "orders.max_weight",
+            "orders.max_weight must be >= orders.min_weight",
+        );
+        check(
+            (0.0..=1.0).contains(&tuning.express_share)
+                && (0.0..=1.0).contains(&tuning.economy_share)
+                && tuning.express_share + tuning.economy_share <= 1.0,
+            "orders.express_share",
+            "orders.express_share and orders.economy_share must be between 0 and 1 and sum to at most 1",
+        );
+        check(
+            tuning.express_multiplier > 0.0,
+            "orders.express_multiplier",
+            "orders.express_multiplier must be greater than 0",
+        );
+        check(
+            tuning.economy_multiplier > 0.0,
+            "orders.economy_multiplier",
+            "orders.economy_multiplier must be greater than 0",
+        );
+        check(
+            passengers.max_deadline_hours > 0,
+            "orders.passengers.max_deadline_hours",
+            "orders.passengers.max_deadline_hours must be at least 1",
+        );
+        check(
+            passengers.min_count > 0,
+            "orders.passengers.min_count",
+            "orders.passengers.min_count must be greater than 0",
+        );
+        check(
+            passengers.max_count >= passengers.min_count,
+            "orders.passengers.max_count",
+            "orders.passengers.max_count must be >= min_count",
+        );
+        check(
+            passengers.fare_per_km > 0.0,
+            "orders.passengers.fare_per_km",
+            "orders.passengers.fare_per_km must be greater than 0",
+        );
+        check(
+            self.restock_cycle_hours > 0,
+            "restock_cycle_hours",
+            "restock_cycle_hours must be at least 1",
+        );
+        check(
+            self.fuel_interval_hours > 0,
+            "fuel_interval_hours",
+            "fuel_interval_hours must be at least 1",
+        );
+        check(
+            self.fuel.elasticity > 0.0,
+            "fuel.elasticity",
+            "fuel.elasticity must be greater than 0",
+        );
+        check(
+            self.fuel.elasticity < 1.0,
+            "fuel.elasticity",
+            "fuel.elasticity must be less than 1",
+        );
+        check(
+            self.fuel.min_price_multiplier > 0.0,
+            "fuel.min_price_multiplier",
+            "fuel.min_price_multiplier must be greater than 0",
+        );
+        check(
+            self.fuel.max_price_multiplier >= self.fuel.min_price_multiplier,
+            "fuel.max_price_multiplier",
+            "fuel.max_price_multiplier must be >= fuel.min_price_multiplier",
+        );
+        check(
+            self.fuel.max_price_multiplier > 1.0,
+            "fuel.max_price_multiplier",
+            "fuel.max_price_multiplier must be greater than 1",
+        );
+        check(
+            self.fuel.stock_multiplier > 0.0,
+            "fuel.stock_multiplier",
+            "fuel.stock_multiplier must be greater than 0",
+        );
+        check(
+            self.fuel.reserve_minutes >= 0.0,
+            "fuel.reserve_minutes",
+            "fuel.reserve_minutes must be non-negative",
+        );
+        check(
+            (0.0..=1.0).contains(&self.fuel.headwind_chance),
+            "fuel.headwind_chance",
+            "fuel.headwind_chance must be between 0 and 1",
+        );
+        check(
+            self.fuel.headwind_burn_minutes >= 0.0,
+            "fuel.headwind_burn_minutes",
+            "fuel.headwind_burn_minutes must be non-negative",
+        );
+        check(
+            self.fuel.emergency_landing_fee >= 0.0,
+            "fuel.emergency_landing_fee",
+            "fuel.emergency_landing_fee must be non-negative",
+        );
+        check(
+            (0.0..=1.0).contains(&self.fuel.emergency_damage_chance),
+            "fuel.emergency_damage_chance",
+            "fuel.emergency_damage_chance must be between 0 and 1",
+        );
+        check(
+            (0.0..=1.0).contains(&self.borders.customs_rate),
+            "borders.customs_rate",
+            "borders.customs_rate must be between 0 and 1",
+        );
+        check(
+            self.borders.overflight_fee_per_km >= 0.0,
+            "borders.overflight_fee_per_km",
+            "borders.overflight_fee_per_km must be >= 0",
+        );
+        let contracts = &self.contracts;
+        check(
+            contracts.min_weight > 0.0,
+            "contracts.min_weight",
+            "contracts.min_weight must be greater than 0",
+        );
+        check(
+            contracts.max_weight >= contracts.min_weight,
+            "contracts.max_weight",
+            "contracts.max_weight must be >= contracts.min_weight",
+        );
+        check(
+            contracts.duration_hours > 0,
+            "contracts.duration_hours",
+            "contracts.duration_hours must be at least 1",
+        );
+        check(
+            contracts.rate_multiplier > 0.0,
+            "contracts.rate_multiplier",
+            "contracts.rate_multiplier must be greater than 0",
+        );
+        check(
+            contracts.completion_bonus >= 0.0,
+            "contracts.completion_bonus",
+            "contracts.completion_bonus must be >= 0",
+        );
+        let charters = &self.charters;
+        check(
+            charters.min_passengers > 0,
+            "charters.min_passengers",
+            "charters.min_passengers must be at least 1",
+        );
+        check(
+            charters.max_passengers >= charters.min_passengers,
+            "charters.max_passengers",
+            "charters.max_passengers must be >= charters.min_passengers",
+        );
+        check(
+            charters.offer_hours > 0,
+            "charters.offer_hours",
+            "charters.offer_hours must be at least 1",
+        );
+        check(
+            charters.window_hours > 0,
+            "charters.window_hours",
+            "charters.window_hours must be at least 1",
+        );
+        check(
+            charters.fare_multiplier > 0.0,
+            "charters.fare_multiplier",
+            "charters.fare_multiplier must be greater than 0",
+        );
+        let forwarders = &self.forwarders;
+        check(
+            forwarders.offer_interval_days > 0,
+            "forwarders.offer_interval_days",
+            "forwarders.offer_interval_days must be at least 1",
+        );
+        check(
+            forwarders.offer_hours > 0,
+            "forwarders.offer_hours",
+            "forwarders.offer_hours must be at least 1",
+        );
+        check(
+            forwarders.min_loads_per_week > 0,
+            "forwarders.min_loads_per_week",
+            "forwarders.min_loads_per_week must be at least 1",
+        );
+        check(
+            forwarders.max_loads_per_week >= forwarders.min_loads_per_week,
+            "forwarders.max_loads_per_week",
+            "forwarders.max_loads_per_week must be >= forwarders.min_loads_per_week",
+        );
+        check(
+            forwarders.min_load_weight > 0.0,
+            "forwarders.min_load_weight",
+            "forwarders.min_load_weight must be greater than 0",
+        );
+        check(
+            forwarders.max_load_weight >= forwarders.min_load_weight,
+            "forwarders.max_load_weight",
+            "forwarders.max_load_weight must be >= forwarders.min_load_weight",
+        );
+        check(
+            forwarders.weeks > 0,
+            "forwarders.weeks",
+            "forwarders.weeks must be at least 1",
+        );
+        check(
+            forwarders.rate_multiplier > 0.0,
+            "forwarders.rate_multiplier",
+            "forwarders.rate_multiplier must be greater than 0",
+        );
+        check(
+            forwarders.load_deadline_hours > 0,
+            "forwarders.load_deadline_hours",
+            "forwarders.load_deadline_hours must be at least 1",
+        );
+        let auctions = &self.auctions;
+        check(
+            (0.0..=1.0).contains(&auctions.chance_per_day),
+            "auctions.chance_per_day",
+            "auctions.chance_per_day must be between 0 and 1",
+        );
+        check(
+            auctions.bidding_hours > 0,
+            "auctions.bidding_hours",
+            "auctions.bidding_hours must be at least 1",
+        );
+        check(
+            auctions.min_weight > 0.0,
+            "auctions.min_weight",
+            "auctions.min_weight must be greater than 0",
+        );
+        check(
+            auctions.max_weight >= auctions.min_weight,
+            "auctions.max_weight",
+            "auctions.max_weight must be >= auctions.min_weight",
+        );
+        check(
+            auctions.min_deadline_hours > 0,
+            "auctions.min_deadline_hours",
+            "auctions.min_deadline_hours must be at least 1",
+        );
+        check(
+            auctions.max_deadline_hours >= auctions.min_deadline_hours,
+            "auctions.max_deadline_hours",
+            "auctions.max_deadline_hours must be >= auctions.min_deadline_hours",
+        );
+        check(
+            auctions.competitors > 0,
+            "auctions.competitors",
+            "auctions.competitors must be at least 1",
+        );
+        check(
+            (0.0..1.0).contains(&auctions.bid_spread),
+            "auctions.bid_spread",
+            "auctions.bid_spread must be in [0, 1)",
+        );
+        check(
+            auctions.urgency_premium >= 0.0,
+            "auctions.urgency_premium",
+            "auctions.urgency_premium must be >= 0",
+        );
+        let rare = &self.rare_cargo;
+        check(
+            (0.0..=1.0).contains(&rare.chance_per_day),
+            "rare_cargo.chance_per_day",
+            "rare_cargo.chance_per_day must be between 0 and 1",
+        );
+        check(
+            rare.value_multiplier > 0.0,
+            "rare_cargo.value_multiplier",
+            "rare_cargo.value_multiplier must be greater than 0",
+        );
+        check(
+            rare.min_value >= 0.0,
+            "rare_cargo.min_value",
+            "rare_cargo.min_value must be >= 0",
+        );
+        let growth = &self.world_growth;
+        check(
+            (0.0..=1.0).contains(&growth.chance_per_day),
+            "world_growth.chance_per_day",
+            "world_growth.chance_per_day must be between 0 and 1",
+        );
+        check(
+            growth.radius_km > 0.0,
+            "world_growth.radius_km",
+            "world_growth.radius_km must be greater than 0",
+        );
+        let market = &self.market;
+        check(
+            market.saturation_tons > 0.0,
+            "market.saturation_tons",
+            "market.saturation_tons must be greater than 0",
+        );
+        check(
+            (0.0..1.0).contains(&market.max_discount),
+            "market.max_discount",
+            "market.max_discount must be in [0, 1)",
+        );
+        check(
+            market.backlog_premium >= 0.0,
+            "market.backlog_premium",
+            "market.backlog_premium must be >= 0",
+        );
+        check(
+            market.max_backlog_premium >= 0.0,
+            "market.max_backlog_premium",
+            "market.max_backlog_premium must be >= 0",
+        );
+        check(
+            (0.0..=1.0).contains(&market.memory),
+            "market.memory",
+            "market.memory must be between 0 and 1",
+        );
+        let loyalty = &self.loyalty;
+        check(
+            loyalty.window_hours > 0,
+            "loyalty.window_hours",
+            "loyalty.window_hours must be at least 1",
+        );
+        for (i, tier) in loyalty.tiers.iter().enumerate() {
+            check(
+                tier.min_operations > 0,
+                &format!("loyalty.tiers[{}].min_operations", i),
+                "loyalty tiers need at least 1 operation",
+            );
+            check(
+                (0.0..1.0).contains(&tier.discount),
+                &format!("loyalty.tiers[{}].discount", i),
+                "loyalty discounts must be in [0, 1)",
+            );
+            if i > 0 {
+                check(
+                    tier.min_operations > loyalty.tiers[i - 1].min_operations,
+                    &format!("loyalty.tiers[{}].min_operations", i),
+                    "loyalty tiers must be listed with increasing min_operations",
+                );
+            }
+        }
+        let demand = &self.passenger_demand;
+        check(
+            demand.scale >= 0.0,
+            "passenger_demand.scale",
+            "passenger_demand.scale must be >= 0",
+        );
+        check(
+            demand.distance_exponent >= 0.0,
+            "passenger_demand.distance_exponent",
+            "passenger_demand.distance_exponent must be >= 0",
+        );
+        check(
+            (0.0..1.0).contains(&demand.daily_variation),
+            "passenger_demand.daily_variation",
+            "passenger_demand.daily_variation must be in [0, 1)",
+        );
+        check(
+            demand.price_elasticity >= 0.0,
+            "passenger_demand.price_elasticity",
+            "passenger_demand.price_elasticity must be >= 0",
+        );
+        check(
+            self.handling.tons_per_hour > 0.0,
+            "handling.tons_per_hour",
+            "handling.tons_per_hour must be greater than 0",
+        );
+        check(
+            self.handling.crew_wage_per_day >= 0.0,
+            "handling.crew_wage_per_day",
+            "handling.crew_wage_per_day must be >= 0",
+        );
+        check(
+            self.handling.crew_rate_bonus >= 0.0,
+            "handling.crew_rate_bonus",
+            "handling.crew_rate_bonus must be >= 0",
+        );
+        check(
+            self.warehouse.fee_per_ton_per_day >= 0.0,
+            "warehouse.fee_per_ton_per_day",
+            "warehouse.fee_per_ton_per_day must be >= 0",
+        );
+        let electric = &self.electric;
+        check(
+            electric.charger_cost >= 0.0,
+            "electric.charger_cost",
+            "electric.charger_cost must be >= 0",
+        );
+        check(
+            electric.charger_kw > 0.0,
+            "electric.charger_kw",
+            "electric.charger_kw must be greater than 0",
+        );
+        check(
+            electric.price_per_kwh >= 0.0,
+            "electric.price_per_kwh",
+            "electric.price_per_kwh must be >= 0",
+        );
+        let construction = &self.construction;
+        check(
+            construction.airstrip_cost >= 0.0 && construction.extension_cost >= 0.0,
+            "construction.airstrip_cost",
+            "construction.airstrip_cost and construction.extension_cost must be >= 0",
+        );
+        check(
+            construction.airstrip_hours > 0 && construction.extension_hours > 0,
+            "construction.airstrip_hours",
+            "construction.airstrip_hours and construction.extension_hours must be at least 1",
+        );
+        check(
+            construction.airstrip_runway_m > 0.0,
+            "construction.airstrip_runway_m",
+            "construction.airstrip_runway_m must be greater than 0",
+        );
+        check(
+            construction.min_spacing_km >= 0.0,
+            "construction.min_spacing_km",
+            "construction.min_spacing_km must be >= 0",
+        );
+        check(
+            construction.extension_m > 0.0,
+            "construction.extension_m",
+            "construction.extension_m must be greater than 0",
+        );
+        check(
+            construction.max_runway_m >= construction.airstrip_runway_m,
+            "construction.max_runway_m",
+            "construction.max_runway_m must be at least construction.airstrip_runway_m",
+        );
+        let concessions = &self.concessions;
+        check(
+            concessions.cost_per_size >= 0.0,
+            "concessions.cost_per_size",
+            "concessions.cost_per_size must be >= 0",
+        );
+        check(
+            (0.0..1.0).contains(&concessions.discount),
+            "concessions.discount",
+            "concessions.discount must be at least 0 and below 1",
+        );
+        check(
+            concessions.income_per_size_per_day >= 0.0,
+            "concessions.income_per_size_per_day",
+            "concessions.income_per_size_per_day must be >= 0",
+        );
+        let used = &self.used_market;
+        check(
+            used.min_runway_m >= 0.0,
+            "used_market.min_runway_m",
+            "used_market.min_runway_m must be >= 0",
+        );
+        check(
+            (0.0..1.0).contains(&used.max_discount),
+            "used_market.max_discount",
+            "used_market.max_discount must be at least 0 and below 1",
+        );
+        check(
+            used.listing_hours > 0,
+            "used_market.listing_hours",
+            "used_market.listing_hours must be at least 1",
+        );
+        for (name, settings) in [
+            ("a_check", &self.maintenance.a_check),
+            ("b_check", &self.maintenance.b_check),
+            ("c_check", &self.maintenance.c_check),
+        ] {
+            check(
+                settings.interval_hours > 0,
+                &format!("maintenance.{}.interval_hours", name),
+                &format!("maintenance.{}.interval_hours must be at least 1", name),
+            );
+            check(
+                settings.downtime_hours > 0,
+                &format!("maintenance.{}.downtime_hours", name),
+                &format!("maintenance.{}.downtime_hours must be at least 1", name),
+            );
+            check(
+                settings.cost_share >= 0.0,
+                &format!("maintenance.{}.cost_share", name),
+                &format!("maintenance.{}.cost_share must be >= 0", name),
+            );
+        }
+        check(
+            (0.0..=1.0).contains(&self.maintenance.landing_failure_chance),
+            "maintenance.landing_failure_chance",
+            "maintenance.landing_failure_chance must be between 0 and 1",
+        );
+        check(
+            self.maintenance.repair_cost_share >= 0.0,
+            "maintenance.repair_cost_share",
+            "maintenance.repair_cost_share must be >= 0",
+        );
+        check(
+            self.maintenance.repair_hours > 0,
+            "maintenance.repair_hours",
+            "maintenance.repair_hours must be at least 1",
+        );
+        check(
+            self.maintenance.spare_kit_price >= 0.0,
+            "maintenance.spare_kit_price",
+            "maintenance.spare_kit_price must be >= 0",
+        );
+        check(
+            self.maintenance.spare_kit_weight > 0.0,
+            "maintenance.spare_kit_weight",
+            "maintenance.spare_kit_weight must be > 0",
+        );
+        check(
+            self.pilots.hire_fee >= 0.0,
+            "pilots.hire_fee",
+            "pilots.hire_fee must be >= 0",
+        );
+        check(
+            self.pilots.salary_per_day >= 0.0,
+            "pilots.salary_per_day",
+            "pilots.salary_per_day must be >= 0",
+        );
+        check(
+            self.pilots.max_duty_hours > 0,
+            "pilots.max_duty_hours",
+            "pilots.max_duty_hours must be at least 1",
+        );
+        check(
+            (0.0..1.0).contains(&self.pilots.type_fuel_saving),
+            "pilots.type_fuel_saving",
+            "pilots.type_fuel_saving must be in [0, 1)",
+        );
+        check(
+            (0.0..1.0).contains(&self.pilots.route_fuel_saving),
+            "pilots.route_fuel_saving",
+            "pilots.route_fuel_saving must be in [0, 1)",
+        );
+        check(
+            self.pilots.type_fuel_saving + self.pilots.route_fuel_saving < 1.0,
+            "pilots.route_fuel_saving",
+            "pilots.type_fuel_saving + pilots.route_fuel_saving must be < 1",
+        );
+        check(
+            self.fixed_costs.hangarage_per_plane >= 0.0,
+            "fixed_costs.hangarage_per_plane",
+            "fixed_costs.hangarage_per_plane must be >= 0",
+        );
+        check(
+            self.fixed_costs.office_base >= 0.0,
+            "fixed_costs.office_base",
+            "fixed_costs.office_base must be >= 0",
+        );
+        check(
+            self.fixed_costs.office_per_plane >= 0.0,
+            "fixed_costs.office_per_plane",
+            "fixed_costs.office_per_plane must be >= 0",
+        );
+        check(
+            (0.0..=1.0).contains(&self.taxes.profit_tax_rate),
+            "taxes.profit_tax_rate",
+            "taxes.profit_tax_rate must be in [0, 1]",
+        );
+        check(
+            self.taxes.period_days > 0,
+            "taxes.period_days",
+            "taxes.period_days must be at least 1",
+        );
+        check(
+            self.taxes.carbon_tax_per_ton >= 0.0,
+            "taxes.carbon_tax_per_ton",
+            "taxes.carbon_tax_per_ton must be >= 0",
+        );
+        for (country, multiplier) in &self.taxes.fee_multipliers {
+            check(
+                *multiplier >= 0.0,
+                "taxes.fee_multipliers",
+                &format!("fee multiplier for {} must be >= 0", country),
+            );
+        }
+        let overdraft = &self.overdraft;
+        check(
+            overdraft.limit >= 0.0,
+            "overdraft.limit",
+            "overdraft.limit must be >= 0",
+        );
+        check(
+            overdraft.daily_interest_rate >= 0.0,
+            "overdraft.daily_interest_rate",
+            "overdraft.daily_interest_rate must be >= 0",
+        );
+        check(
+            (0.0..=1.0).contains(&overdraft.warning_share),
+            "overdraft.warning_share",
+            "overdraft.warning_share must be in [0, 1]",
+        );
+    }
+
+    /// Check the gameplay tuning on its own.
+    ///
+    /// Returns
+    /// - `Vec<ConfigIssue>`: Problems with paths rooted at `gameplay`.
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        self.collect_issues(&mut issues);
+        issues
+    }
+}
+
+impl AirplaneModelConfig {
+    fn collect_issues(&self, path: &str, issues: &mut Vec<ConfigIssue>) {
+        use crate::utils::airplanes::models::AirplaneRole;
+
+        if self.name.trim().is_empty() {
+            issues.push(ConfigIssue::new(
+                format!("{}.name", path),
+                "airplane model name cannot be empty",
+            ));
+        }
+        if self.mtow <= 0.0
+            || self.cruise_speed <= 0.0
+            || self.fuel_capacity <= 0.0
+            || self.fuel_consumption <= 0.0
+            || self.purchase_price <= 0.0
+            || self.min_runway_length <= 0.0
+        {
+            issues.push(ConfigIssue::new(
+                path,
+                format!(
+                    "airplane '{}' has non-positive required numeric fields",
+                    self.name
+                ),
+            ));
+        }
+        if self.operating_cost < 0.0 || self.payload_capacity < 0.0 {
+            issues.push(ConfigIssue::new(
+                path,
+                format!("airplane '{}' has negative cost or payload", self.name),
+            ));
+        }
+        let role_ok = match self.role {
+            AirplaneRole::Cargo => self.payload_capacity > 0.0,
+            AirplaneRole::Passenger => self.passenger_capacity > 0,
+            AirplaneRole::Mixed => self.payload_capacity > 0.0 && self.passenger_capacity > 0,
+        };
+        if !role_ok {
+            let requirement = match self.role {
+                AirplaneRole::Cargo => "role cargo requires payload_capacity > 0",
+                AirplaneRole::Passenger => "role passenger requires passenger_capacity > 0",
+                AirplaneRole::Mixed => {
+                    "role mixed requires both passenger_capacity and payload_capacity"
+                }
+            };
+            issues.push(ConfigIssue::new(
+                format!("{}.role", path),
+                format!("airplane '{}' {}", self.name, requirement),
+            ));
+        }
+    }
+}
@@ -0,0 +1,445 @@
+//! Incremental observations for frontends that redraw every frame.
+//!
+//! [`Game::observe_diff`](crate::Game::observe_diff) returns an [`ObservationPatch`]
+//! holding only what changed since the state version a client last saw, and
+//! [`Observation::apply`] brings that client's copy up to date. The version only moves
+//! when something observable changes, so polling an idle game is cheap:
+//!
+//! ```
+//! let mut game = rusty_runways_core::Game::new(1, Some(4), 650_000.0);
+//! let mut view = rusty_runways_core::game::Observation::default();
+//! let patch = game.observe_diff(0);
+//! let since = patch.version;
+//! view.apply(patch);
+//! assert_eq!(game.state_version(), since);
+//!
+//! game.advance(5);
+//! let patch = game.observe_diff(since);
+//! assert!(patch.version > since);
+//! view.apply(patch);
+//! assert_eq!(view.time, 5);
+//! ```
+//!
+//! [`Game::diff`](crate::Game::diff) compares two whole games instead, e.g. two runs
+//! that should have played out the same way, and returns a [`StateDiff`].
+
+use crate::Game;
+use crate::events::{GameEvent, GameTime, ScheduledEvent};
+use crate::game::{AirportObs, Observation, PlaneObs};
+use crate::utils::airplanes::market::UsedListing;
+use crate::utils::airplanes::models::AirplaneStatus;
+use crate::utils::coordinate::Coordinate;
+use crate::utils::pilots::Pilot;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+/// Events kept for clients that fall behind; older ones are dropped.
+pub const MAX_PATCH_EVENTS: usize = 1_000;
+
+/// What changed in the [`Observation`] since an earlier state version.
+#[derive(Debug, Clone, Serialize)]
+pub struct ObservationPatch {
+    /// State version this patch brings a client to; pass it as `since` next time
+    pub version: u64,
+    pub time: u64,
+    pub cash: f32,
+    pub company_value: f32,
+    /// Planes that joined the fleet or changed
+    pub planes: Vec<PlaneObs>,
+    /// Planes that left the fleet
+    pub removed_planes: Vec<usize>,
+    /// Airports that changed
+    pub airports: Vec<AirportObs>,
+    /// The whole used market, if it changed
+    pub used_market: Option<Vec<UsedListing>>,
+    /// The whole payroll, if it changed
+    pub pilots: Option<Vec<Pilot>>,
+    /// Game events since `since`, oldest first
+    pub events: Vec<GameEvent>,
+}
+
+/// The last observed state of every entity and the version it last changed in.
+#[derive(Debug, Default)]
+pub(crate) struct ObservationTracker {
+    version: u64,
+    time: u64,
+    cash: f32,
+    company_value: f32,
+    planes: BTreeMap<usize, (u64, PlaneObs)>,
+    removed_planes: BTreeMap<usize, u64>,
+    airports: BTreeMap<usize, (u64, AirportObs)>,
+    used_market: Option<(u64, Vec<UsedListing>)>,
+    pilots: Option<(u64, Vec<Pilot>)>,
+    events: VecDeque<(u64, GameEvent)>,
+}
+
+/// Store `value` under `id`, stamping it with `version` if it is new or changed.
+///
+/// Returns `true` if it was stamped.
+fn track<T: PartialEq>(
+    entries: &mut BTreeMap<usize, (u64, T)>,
+    id: usize,
+    value: T,
+    version: u64,
+) -> bool {
+    match entries.get(&id) {
+        Some((_, old)) if *old == value => false,
+        _ => {
+            entries.insert(id, (version, value));
+            true
+        }
+    }
+}
+
+fn track_list<T: PartialEq>(
+    entry: &mut Option<(u64, Vec<T>)>,
+    value: Vec<T>,
+    version: u64,
+) -> bool {
+    if entry.as_ref().is_none_or(|(_, old)| *old != value) {
+        *entry = Some((version, value));
+        true
+    } else {
+        false
+    }
+}
+
+fn changed_list<T: Clone>(entry: &Option<(u64, Vec<T>)>, since: u64) -> Option<Vec<T>> {
+    entry
+        .as_ref()
+        .filter(|(changed, _)| *changed > since)
+        .map(|(_, list)| list.clone())
+}
+
+impl ObservationTracker {
+    /// Keep `event` for the next patch.
+    pub(crate) fn record_event(&mut self, event: GameEvent) {
+        if self.events.len() == MAX_PATCH_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back((self.version + 1, event));
+    }
+
+    /// Compare `obs` with the last observation and return the state version, which
+    /// moves on only if something changed or an event was recorded.
+    pub(crate) fn update(&mut self, obs: Observation) -> u64 {
+        let next = self.version + 1;
+        let mut changed = self.events.back().is_some_and(|(at, _)| *at == next);
+        if (obs.time, obs.cash, obs.company_value) != (self.time, self.cash, self.company_value) {
+            self.time = obs.time;
+            self.cash = obs.cash;
+            self.company_value = obs.company_value;
+            changed = true;
+        }
+
+        let mut seen = Vec::with_capacity(obs.planes.len());
+        for plane in obs.planes {
+            seen.push(plane.id);
+            self.removed_planes.remove(&plane.id);
+            changed |= track(&mut self.planes, plane.id, plane, next);
+        }
+        let gone: Vec<usize> = self
+            .planes
+            .keys()
+            .copied()
+            .filter(|id| !seen.contains(id))
+            .collect();
+        for id in gone {
+            self.planes.remove(&id);
+            self.removed_planes.insert(id, next);
+            changed = true;
+        }
+        for airport in obs.airports {
+            changed |= track(&mut self.airports, airport.id, airport, next);
+        }
+        changed |= track_list(&mut self.used_market, obs.used_market, next);
+        changed |= track_list(&mut self.pilots, obs.pilots, next);
+
+        if changed {
+            self.version = next;
+        }
+        self.version
+    }
+
+    /// Compare `obs` with the last observation and return the changes after `since`.
+    ///
+    /// A `since` of 0, or one this tracker never handed out, returns everything.
+    pub(crate) fn diff(&mut self, obs: Observation, since: u64) -> ObservationPatch {
+        let version = self.update(obs);
+        let since = if since > version { 0 } else { since };
+        ObservationPatch {
+            version,
+            time: self.time,
+            cash: self.cash,
+            company_value: self.company_value,
+            planes: self
+                .planes
+                .values()
+                .filter(|(changed, _)| *changed > since)
+                .map(|(_, plane)| plane.clone())
+                .collect(),
+            removed_planes: self
+                .removed_planes
+                .iter()
+                .filter(|(_, removed)| **removed > since)
+                .map(|(id, _)| *id)
+                .collect(),
+            airports: self
+                .airports
+                .values()
+                .filter(|(changed, _)| *changed > since)
+                .map(|(_, airport)| airport.clone())
+                .collect(),
+            used_market: changed_list(&self.used_market, since),
+            pilots: changed_list(&self.pilots, since),
+            events: self
+                .events
+                .iter()
+                .filter(|(at, _)| *at > since)
+                .map(|(_, event)| event.clone())
+                .collect(),
+        }
+    }
+}
+
+impl Observation {
+    /// Bring this observation up to date with `patch`.
+    pub fn apply(&mut self, patch: ObservationPatch) {
+        self.time = patch.time;
+        self.cash = patch.cash;
+        self.company_value = patch.company_value;
+        self.planes
+            .retain(|p| !patch.removed_planes.contains(&p.id));
+        for plane in patch.planes {
+            match self.planes.iter_mut().find(|p| p.id == plane.id) {
+                Some(existing) => *existing = plane,
+                None => self.planes.push(plane),
+            }
+        }
+        for airport in patch.airports {
+            match self.airports.iter_mut().find(|a| a.id == airport.id) {
+                Some(existing) => *existing = airport,
+                None => self.airports.push(airport),
+            }
+        }
+        if let Some(market) = patch.used_market {
+            self.used_market = market;
+        }
+        if let Some(pilots) = patch.pilots {
+            self.pilots = pilots;
+        }
+    }
+}
+
+/// Where an order is in a game, see [`StateDiff::orders`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum OrderPlace {
+    /// Waiting at an airport
+    Airport(usize),
+    /// Loaded on a plane
+    Plane(usize),
+    /// Not in the game: delivered, expired or not generated yet
+    Gone,
+}
+
+/// An order that is somewhere else in the second game.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrderMove {
+    pub order: usize,
+    pub from: OrderPlace,
+    pub to: OrderPlace,
+}
+
+/// A plane in both games whose status or position differs; unchanged parts are `None`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlaneChange {
+    pub plane: usize,
+    pub status: Option<(AirplaneStatus, AirplaneStatus)>,
+    pub location: Option<(Coordinate, Coordinate)>,
+}
+
+/// How one game differs from another, as returned by [`Game::diff`].
+///
+/// Pairs are `(first, second)`. Fields that match are `None` or empty, so an identical
+/// pair of games gives a diff that [`is_empty`](StateDiff::is_empty). The `Display` form
+/// prints one line per difference.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StateDiff {
+    pub time: Option<(GameTime, GameTime)>,
+    pub cash: Option<(f32, f32)>,
+    /// Planes in both games that differ, by id
+    pub planes: Vec<PlaneChange>,
+    /// Planes only the second game has
+    pub added_planes: Vec<usize>,
+    /// Planes only the first game has
+    pub removed_planes: Vec<usize>,
+    /// Orders that are somewhere else, by id
+    pub orders: Vec<OrderMove>,
+    /// Events queued in the first game but not the second: they fired (or were dropped)
+    pub fired_events: Vec<ScheduledEvent>,
+    /// Events queued in the second game only
+    pub scheduled_events: Vec<ScheduledEvent>,
+}
+
+impl StateDiff {
+    pub fn is_empty(&self) -> bool {
+        self.time.is_none()
+            && self.cash.is_none()
+            && self.planes.is_empty()
+            && self.added_planes.is_empty()
+            && self.removed_planes.is_empty()
+            && self.orders.is_empty()
+            && self.fired_events.is_empty()
+            && self.scheduled_events.is_empty()
+    }
+}
+
+impl fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((a, b)) = self.time {
+            writeln!(f, "time: {} -> {}", a, b)?;
+        }
+        if let Some((a, b)) = self.cash {
+            writeln!(f, "cash: {:.2} -> {:.2}", a, b)?;
+        }
+        for change in &self.planes {
+            if let Some((a, b)) = &change.status {
+                writeln!(f, "plane {} status: {:?} -> {:?}", change.plane, a, b)?;
+            }
+            if let Some((a, b)) = change.location {
+                writeln!(
+                    f,
+                    "plane {} location: ({:.1}, {:.1}) -> ({:.1}, {:.1})",
+                    change.plane, a.x, a.y, b.x, b.y
+                )?;
+            }
+        }
+        for plane in &self.added_planes {
+            writeln!(f, "plane {} added", plane)?;
+        }
+        for plane in &self.removed_planes {
+            writeln!(f, "plane {} removed", plane)?;
+        }
+        for order in &self.orders {
+            writeln!(
+                f,
+                "order {}: {:?} -> {:?}",
+                order.order, order.from, order.to
+            )?;
+        }
+        for scheduled in &self.fired_events {
+            writeln!(f, "fired at {}: {:?}", scheduled.time, scheduled.event)?;
+        }
+        for scheduled in &self.scheduled_events {
+            writeln!(f, "scheduled for {}: {:?}", scheduled.time, scheduled.event)?;
+        }
+        Ok(())
+    }
+}
+
+fn order_places(game: &Game) -> BTreeMap<usize, OrderPlace> {
+    let mut places = BTreeMap::new();
+    for (airport, _) in game.map.airports.iter() {
+        for order in &airport.orders {
+            places.insert(order.id, OrderPlace::Airport(airport.id));
+        }
+    }
+    for plane in game.planes() {
+        for order in &plane.manifest {
+            places.insert(order.id, OrderPlace::Plane(plane.id));
+        }
+    }
+    places
+}
+
+/// Queued events sorted by time, each with its serialized form for matching.
+fn queued(game: &Game) -> Vec<(String, &ScheduledEvent)> {
+    let mut events: Vec<(String, &ScheduledEvent)> = game
+        .events
+        .iter()
+        .map(|e| (serde_json::to_string(e).expect("event serializes"), e))
+        .collect();
+    events.sort_by(|a, b| a.1.time.cmp(&b.1.time).then_with(|| a.0.cmp(&b.0)));
+    events
+}
+
+/// Events in `from` without a match in `to`, counting duplicates.
+fn unmatched(
+    from: &[(String, &ScheduledEvent)],
+    to: &[(String, &ScheduledEvent)],
+) -> Vec<ScheduledEvent> {
+    let mut available: HashMap<&str, usize> = HashMap::new();
+    for (key, _) in to {
+        *available.entry(key.as_str()).or_default() += 1;
+    }
+    from.iter()
+        .filter(|(key, _)| match available.get_mut(key.as_str()) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                false
+            }
+            _ => true,
+        })
+        .map(|(_, event)| (*event).clone())
+        .collect()
+}
+
+impl Game {
+    /// Describe how `other` differs from this game: cash, plane statuses and positions,
+    /// orders that moved, and events that fired or were scheduled in between.
+    ///
+    /// Handy for finding where two runs that should be identical diverged, for syncing a
+    /// frontend from one snapshot to the next, and for short assertions in tests.
+    pub fn diff(&self, other: &Game) -> StateDiff {
+        let mut diff = StateDiff {
+            time: (self.time != other.time).then_some((self.time, other.time)),
+            cash: (self.player.cash != other.player.cash)
+                .then_some((self.player.cash, other.player.cash)),
+            ..StateDiff::default()
+        };
+
+        let theirs: BTreeMap<usize, _> = other.planes().iter().map(|p| (p.id, p)).collect();
+        let mut ours: Vec<_> = self.planes().iter().collect();
+        ours.sort_by_key(|p| p.id);
+        for plane in &ours {
+            let Some(after) = theirs.get(&plane.id) else {
+                diff.removed_planes.push(plane.id);
+                continue;
+            };
+            let change = PlaneChange {
+                plane: plane.id,
+                status: (plane.status != after.status)
+                    .then(|| (plane.status.clone(), after.status.clone())),
+                location: (plane.location != after.location)
+                    .then_some((plane.location, after.location)),
+            };
+            if change.status.is_some() || change.location.is_some() {
+                diff.planes.push(change);
+            }
+        }
+        diff.added_planes = theirs
+            .keys()
+            .copied()
+            .filter(|id| !ours.iter().any(|p| p.id == *id))
+            .collect();
+
+        let before = order_places(self);
+        let after = order_places(other);
+        let ids: BTreeSet<usize> = before.keys().chain(after.keys()).copied().collect();
+        diff.orders = ids
+            .into_iter()
+            .filter_map(|order| {
+                let from = before.get(&order).copied().unwrap_or(OrderPlace::Gone);
+                let to = after.get(&order).copied().unwrap_or(OrderPlace::Gone);
+                (from != to).then_some(OrderMove { order, from, to })
+            })
+            .collect();
+
+        let ours = queued(self);
+        let theirs = queued(other);
+        diff.fired_events = unmatched(&ours, &theirs);
+        diff.scheduled_events = unmatched(&theirs, &ours);
+        diff
+    }
+}
@@ -0,0 +1,60 @@
+//! MessagePack encoding of game state and observations.
+//!
+//! JSON stays the default everywhere, but the binary form is about a quarter smaller and
+//! faster to parse, which adds up for large worlds and many environments. Values are
+//! written as maps keyed by field name, so any MessagePack library can read them without
+//! the Rust types. Needs the `msgpack` feature.
+
+use crate::Game;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// Errors encoding or decoding MessagePack.
+#[derive(Debug)]
+pub enum EncodingError {
+    Encode(String),
+    /// The bytes are not valid MessagePack for the expected type
+    Decode(String),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::Encode(reason) => write!(f, "Could not encode MessagePack: {}", reason),
+            EncodingError::Decode(reason) => write!(f, "Invalid MessagePack: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for EncodingError {}
+
+/// Encode `value` as MessagePack.
+pub fn to_msgpack<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EncodingError> {
+    rmp_serde::to_vec_named(value).map_err(|e| EncodingError::Encode(e.to_string()))
+}
+
+/// Decode a value written by [`to_msgpack`].
+pub fn from_msgpack<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, EncodingError> {
+    rmp_serde::from_slice(bytes).map_err(|e| EncodingError::Decode(e.to_string()))
+}
+
+impl Game {
+    /// The whole game state as MessagePack; the binary counterpart of serializing the
+    /// game to JSON. Restore it with [`Game::from_state_msgpack`].
+    pub fn state_msgpack(&self) -> Result<Vec<u8>, EncodingError> {
+        to_msgpack(self)
+    }
+
+    /// [`Game::observe`] as MessagePack.
+    pub fn observation_msgpack(&self) -> Result<Vec<u8>, EncodingError> {
+        to_msgpack(&self.observe())
+    }
+
+    /// Rebuild a game from [`Game::state_msgpack`], ready to keep simulating.
+    pub fn from_state_msgpack(bytes: &[u8]) -> Result<Game, EncodingError> {
+        let mut game: Game = from_msgpack(bytes)?;
+        game.reset_runtime();
+        Ok(game)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::statistics::{DailyStats, MonthlyReport};
+use crate::utils::airplanes::checks::CheckType;
+use crate::utils::orders::RareCargo;
 use serde::{Deserialize, Serialize};
 
 /// Global time unit: hours since simulation start.
@@ -24,6 +27,10 @@ pub enum Event {
         plane: usize,
     },
 
+    ChargeComplete {
+        plane: usize,
+    },
+
     OrderDeadline {
         airport: usize,
         order: usize,
@@ -36,6 +43,9 @@ pub enum Event {
     // Event to adjust fuel price every 6 hours based on demand
     DynamicPricing,
 
+    // Refill airport fuel storage on the restock cycle
+    FuelResupply,
+
     // Bigger, world-wide events that cause more fluctuation
     WorldEvent {
         airport: Option<usize>,
@@ -52,6 +62,180 @@ pub enum Event {
     Maintenance {
         plane: usize,
     },
+
+    // A plane that broke down on landing is fixed
+    RepairComplete {
+        plane: usize,
+    },
+
+    // A scheduled A/B/C check finished
+    CheckComplete {
+        plane: usize,
+        check: CheckType,
+    },
+
+    // An accepted forwarder lane hands over its next load
+    LaneLoad {
+        deal: usize,
+    },
+
+    // Bidding on a spot-charter auction closes
+    AuctionClose {
+        auction: usize,
+    },
+
+    // An airstrip or runway extension the player paid for is finished
+    ConstructionComplete {
+        construction: usize,
+    },
+}
+
+/// Something that happened in the game, pushed to subscribers as it happens.
+///
+/// Unlike [`Event`], which drives the simulation, these are notifications for embedders;
+/// see [`Game::subscribe`](crate::Game::subscribe).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// A plane took off
+    Departed {
+        time: GameTime,
+        plane: usize,
+        origin: usize,
+        destination: usize,
+    },
+    /// A plane landed
+    Arrived {
+        time: GameTime,
+        plane: usize,
+        airport: usize,
+    },
+    /// An order reached its destination and was paid out
+    Delivered {
+        time: GameTime,
+        order: usize,
+        airport: usize,
+        payout: f32,
+    },
+    /// Cash changed while processing a scheduled event (fees, salaries, taxes, ...)
+    CashChanged {
+        time: GameTime,
+        delta: f32,
+        balance: f32,
+    },
+    /// Cash sank past the overdraft's warning level
+    OverdraftWarning {
+        time: GameTime,
+        balance: f32,
+        limit: f32,
+    },
+    /// A freight forwarder offered a lane deal
+    LaneOffered {
+        time: GameTime,
+        deal: usize,
+        forwarder: usize,
+    },
+    /// Bidding on an auction closed; `won` is set if the player got the load
+    AuctionResolved {
+        time: GameTime,
+        auction: usize,
+        won: bool,
+        winning_bid: f32,
+    },
+    /// A rare load turned up at `airport`, waiting as order `order`
+    RareCargo {
+        time: GameTime,
+        order: usize,
+        airport: usize,
+        kind: RareCargo,
+    },
+    /// A new airport opened near `near`, growing the map
+    AirportOpened {
+        time: GameTime,
+        airport: usize,
+        near: usize,
+    },
+    /// An airstrip the player built opened, or a runway extension at `airport` finished
+    ConstructionComplete {
+        time: GameTime,
+        construction: usize,
+        airport: usize,
+    },
+    /// A fuel concession the player holds paid out its daily income
+    ConcessionIncome {
+        time: GameTime,
+        airport: usize,
+        amount: f32,
+    },
+    /// The daily report was written
+    DailyReport(DailyStats),
+    /// A calendar month closed and its statement was written
+    MonthlyReport(MonthlyReport),
+}
+
+/// Kinds of [`GameEvent`], used to filter subscriptions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEventKind {
+    Departure,
+    Arrival,
+    Delivery,
+    Cash,
+    Overdraft,
+    LaneOffer,
+    Auction,
+    RareCargo,
+    AirportOpened,
+    Construction,
+    Concession,
+    DailyReport,
+    MonthlyReport,
+}
+
+impl GameEvent {
+    pub fn kind(&self) -> GameEventKind {
+        match self {
+            GameEvent::Departed { .. } => GameEventKind::Departure,
+            GameEvent::Arrived { .. } => GameEventKind::Arrival,
+            GameEvent::Delivered { .. } => GameEventKind::Delivery,
+            GameEvent::CashChanged { .. } => GameEventKind::Cash,
+            GameEvent::OverdraftWarning { .. } => GameEventKind::Overdraft,
+            GameEvent::LaneOffered { .. } => GameEventKind::LaneOffer,
+            GameEvent::AuctionResolved { .. } => GameEventKind::Auction,
+            GameEvent::RareCargo { .. } => GameEventKind::RareCargo,
+            GameEvent::AirportOpened { .. } => GameEventKind::AirportOpened,
+            GameEvent::ConstructionComplete { .. } => GameEventKind::Construction,
+            GameEvent::ConcessionIncome { .. } => GameEventKind::Concession,
+            GameEvent::DailyReport(_) => GameEventKind::DailyReport,
+            GameEvent::MonthlyReport(_) => GameEventKind::MonthlyReport,
+        }
+    }
+}
+
+/// Which [`GameEvent`]s a subscriber receives.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    /// `None` lets everything through
+    kinds: Option<Vec<GameEventKind>>,
+}
+
+impl EventFilter {
+    /// Receive every event.
+    pub fn all() -> Self {
+        EventFilter { kinds: None }
+    }
+
+    /// Receive only events of the given kinds.
+    pub fn only(kinds: &[GameEventKind]) -> Self {
+        EventFilter {
+            kinds: Some(kinds.to_vec()),
+        }
+    }
+
+    /// Return `true` if `event` passes the filter.
+    pub fn matches(&self, event: &GameEvent) -> bool {
+        self.kinds
+            .as_ref()
+            .is_none_or(|kinds| kinds.contains(&event.kind()))
+    }
 }
 
 /// Wraps an `Event` with its scheduled occurrence time.
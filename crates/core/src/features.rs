@@ -0,0 +1,231 @@
+//! Observation features scaled for learning agents.
+//!
+//! [`Game::features`] returns the state as numbers of similar magnitude whatever the
+//! world: positions and distances as fractions of the world size, money on a log scale,
+//! deadlines as fractions of the longest one orders are generated with, fill levels as
+//! fractions of capacity. The constants used are in [`FeatureScales`], so agents and
+//! analysis code can undo or reproduce the scaling.
+
+use crate::Game;
+use crate::utils::airplanes::models::{AirplaneStatus, PASSENGER_WEIGHT_KG};
+use crate::utils::coordinate::{Coordinate, EARTH_RADIUS_KM};
+use serde::{Deserialize, Serialize};
+
+/// Side of the square every planar world fits in, in km.
+pub const WORLD_SIZE: f32 = 10_000.0;
+
+/// Longest great-circle distance on a geographic map, in km.
+pub const GEOGRAPHIC_WORLD_SIZE: f32 = std::f32::consts::PI * EARTH_RADIUS_KM;
+
+/// `ln(1 + |x|)` with the sign of `x`: compresses money into a small range while keeping
+/// debt negative.
+pub fn log_scale(x: f32) -> f32 {
+    x.signum() * x.abs().ln_1p()
+}
+
+/// Constants the features are divided by.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeatureScales {
+    /// Distances, in km: [`WORLD_SIZE`], or [`GEOGRAPHIC_WORLD_SIZE`] on a geographic map
+    pub world_size: f32,
+    /// Positions are longitude and latitude, scaled from -180..180 and -90..90 to 0..1,
+    /// rather than planar coordinates over `world_size`
+    pub geographic: bool,
+    /// Order deadlines, in hours: the longer of the cargo and passenger limits
+    pub max_deadline: u64,
+    /// Order weights, in kg: the heaviest cargo or passenger order generated
+    pub max_order_weight: f32,
+    /// Runway lengths, in m: the longest runway on the map
+    pub max_runway: f32,
+}
+
+/// The scaled state, see [`Game::features`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Features {
+    pub scales: FeatureScales,
+    /// Hour of the day over 24
+    pub time_of_day: f32,
+    /// [`log_scale`] of the cash
+    pub cash: f32,
+    /// [`log_scale`] of the company value
+    pub company_value: f32,
+    pub airports: Vec<AirportFeatures>,
+    pub planes: Vec<PlaneFeatures>,
+    /// Orders waiting at any airport
+    pub orders: Vec<OrderFeatures>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AirportFeatures {
+    pub id: usize,
+    pub x: f32,
+    pub y: f32,
+    pub runway: f32,
+    pub has_hangar: bool,
+    pub has_charger: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlaneFeatures {
+    pub id: usize,
+    pub x: f32,
+    pub y: f32,
+    /// Fuel, or charge for electric planes, over capacity
+    pub fuel: f32,
+    /// Payload weight over capacity
+    pub payload: f32,
+    pub in_transit: bool,
+    /// Share of the current flight already flown; 0 on the ground
+    pub flight_progress: f32,
+    /// Range on the fuel aboard
+    pub range: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrderFeatures {
+    pub id: usize,
+    pub origin: usize,
+    pub destination: usize,
+    /// Distance from origin to destination
+    pub distance: f32,
+    pub weight: f32,
+    /// [`log_scale`] of the value
+    pub value: f32,
+    /// Hours left over the longest deadline
+    pub deadline: f32,
+    pub passengers: bool,
+}
+
+impl FeatureScales {
+    /// `coord` scaled to 0..1 on both axes.
+    pub fn position(&self, coord: &Coordinate) -> (f32, f32) {
+        if coord.system.is_planar() {
+            (coord.x / self.world_size, coord.y / self.world_size)
+        } else {
+            ((coord.x + 180.0) / 360.0, (coord.y + 90.0) / 180.0)
+        }
+    }
+}
+
+impl Game {
+    /// The constants [`features`](Game::features) scales by in this world.
+    pub fn feature_scales(&self) -> FeatureScales {
+        let demand = &self.map.demand_params;
+        let geographic = !self.map.coordinate_system().is_planar();
+        FeatureScales {
+            world_size: if geographic {
+                GEOGRAPHIC_WORLD_SIZE
+            } else {
+                WORLD_SIZE
+            },
+            geographic,
+            max_deadline: demand
+                .cargo
+                .max_deadline_hours
+                .max(demand.passengers.max_deadline_hours)
+                .max(1),
+            max_order_weight: demand
+                .cargo
+                .max_weight
+                .max(demand.passengers.max_count as f32 * PASSENGER_WEIGHT_KG)
+                .max(1.0),
+            max_runway: self
+                .airports()
+                .iter()
+                .map(|(a, _)| a.runway_length)
+                .fold(1.0, f32::max),
+        }
+    }
+
+    /// The observable state as scaled features; see the [module docs](crate::features).
+    pub fn features(&self) -> Features {
+        let scales = self.feature_scales();
+        let fraction = |value: f32, capacity: f32| {
+            if capacity > 0.0 {
+                value / capacity
+            } else {
+                0.0
+            }
+        };
+
+        let airports = self
+            .airports()
+            .iter()
+            .map(|(airport, coord)| {
+                let (x, y) = scales.position(coord);
+                AirportFeatures {
+                    id: airport.id,
+                    x,
+                    y,
+                    runway: airport.runway_length / scales.max_runway,
+                    has_hangar: airport.has_hangar,
+                    has_charger: airport.has_charger,
+                }
+            })
+            .collect();
+
+        let planes = self
+            .planes()
+            .iter()
+            .map(|plane| {
+                let flight_progress = match plane.status {
+                    AirplaneStatus::InTransit {
+                        hours_remaining,
+                        total_hours,
+                        ..
+                    } => 1.0 - fraction(hours_remaining as f32, total_hours as f32),
+                    _ => 0.0,
+                };
+                let (x, y) = scales.position(&plane.location);
+                PlaneFeatures {
+                    id: plane.id,
+                    x,
+                    y,
+                    fuel: fraction(plane.current_fuel, plane.specs.fuel_capacity),
+                    payload: fraction(plane.current_payload, plane.specs.payload_capacity),
+                    in_transit: matches!(plane.status, AirplaneStatus::InTransit { .. }),
+                    flight_progress,
+                    range: plane.max_range() / scales.world_size,
+                }
+            })
+            .collect();
+
+        let orders = self
+            .airports()
+            .iter()
+            .flat_map(|(airport, coord)| {
+                airport
+                    .orders
+                    .iter()
+                    .map(move |order| (airport.id, coord, order))
+            })
+            .map(|(origin, coord, order)| {
+                let distance = self
+                    .map
+                    .airports
+                    .get(order.destination_id)
+                    .map_or(0.0, |(_, to)| coord.distance_to(to));
+                OrderFeatures {
+                    id: order.id,
+                    origin,
+                    destination: order.destination_id,
+                    distance: distance / scales.world_size,
+                    weight: order.payload_weight() / scales.max_order_weight,
+                    value: log_scale(order.value),
+                    deadline: (order.deadline as f32 / scales.max_deadline as f32).min(1.0),
+                    passengers: order.is_passenger(),
+                }
+            })
+            .collect();
+
+        Features {
+            scales,
+            time_of_day: (self.time % 24) as f32 / 24.0,
+            cash: log_scale(self.player.cash),
+            company_value: log_scale(self.company_value()),
+            airports,
+            planes,
+            orders,
+        }
+    }
+}
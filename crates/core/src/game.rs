@@ -1,39 +1,134 @@
 #![allow(unexpected_cfgs)]
 
 use crate::config::{
-    AirplaneCatalogStrategy, AirplaneModelConfig, DEFAULT_FUEL_INTERVAL_HOURS,
-    DEFAULT_RESTOCK_CYCLE_HOURS, FuelGameplay, GameplayConfig, ManualOrderConfig, WorldConfig,
+    AirplaneCatalogStrategy, AirplaneModelConfig, AirplanesConfig, AirportConfig, AuctionsGameplay,
+    BordersGameplay, ChartersGameplay, ConcessionsGameplay, ConstructionGameplay,
+    ContractsGameplay, DEFAULT_FUEL_INTERVAL_HOURS, DEFAULT_RESERVATION_SHARE,
+    DEFAULT_RESTOCK_CYCLE_HOURS, ElectricGameplay, FixedCostsGameplay, ForwardersGameplay,
+    FuelGameplay, GameplayConfig, HandlingGameplay, Location, LoyaltyGameplay, LoyaltyTier,
+    MaintenanceGameplay, ManualOrderConfig, OrderTuning, OrdersGameplay, OverdraftGameplay,
+    PassengerDemandGameplay, PassengerTuning, PilotsGameplay, RareCargoGameplay, ScriptConfig,
+    StartingPlaneConfig, TaxesGameplay, UsedMarketGameplay, WarehouseGameplay, WorldConfig,
+    WorldGrowthGameplay,
 };
-use crate::events::{Event, GameTime, ScheduledEvent};
+use crate::diff::{ObservationPatch, ObservationTracker};
+use crate::events::{Event, EventFilter, GameEvent, GameTime, ScheduledEvent};
+use crate::history::{self, History, HistoryError};
 use crate::player::Player;
-use crate::statistics::DailyStats;
-use crate::utils::airplanes::airplane::Airplane;
-use crate::utils::airplanes::models::{AirplaneModel, AirplaneSpecs, AirplaneStatus};
-use crate::utils::airport::Airport;
+use crate::plugins::{PluginEffects, PluginError, PluginHost};
+use crate::save::{self, SaveError, SaveOptions};
+use crate::scripting::{ScriptHook, ScriptHost, ScriptSource, ScriptState};
+use crate::statistics::{
+    CompanyValue, DailyStats, Emissions, MonthlyReport, QuarterlyStatement, RunSummary,
+    StatsRecord, fingerprint, fnv1a,
+};
+use crate::utils::airplanes::airplane::{Airplane, MAX_PLANE_NAME_LEN, default_registration};
+use crate::utils::airplanes::checks::CheckType;
+use crate::utils::airplanes::market::UsedListing;
+use crate::utils::airplanes::models::{
+    AirplaneModel, AirplaneSpecs, AirplaneStatus, CruiseRegime, FuelAmount, GroundTask,
+    TurnaroundTask,
+};
+use crate::utils::airport::{
+    Airport, AirportClass, HANGAR_MIN_RUNWAY, LocalTime, REMOTE_STAND_MULTIPLIER,
+    default_fuel_capacity, default_population, default_utc_offset,
+};
+use crate::utils::calendar::Date;
+use crate::utils::concession::FuelConcession;
+use crate::utils::construction::{Construction, Project};
 use crate::utils::coordinate::Coordinate;
+use crate::utils::dataset::{read_airport_csv, select_airports};
+use crate::utils::demand::{PassengerDemand, RouteFare, load_factor, priced_demand};
 use crate::utils::errors::GameError;
-use crate::utils::map::Map;
+use crate::utils::loyalty::AirportLoyalty;
+use crate::utils::map::{AirportId, Map, WorldStyle};
+use crate::utils::names;
 use crate::utils::orders::{
-    DemandGenerationParams, OrderGenerationParams, PassengerGenerationParams,
-    order::{Order, OrderPayload},
+    Auction, CargoType, Charter, CharterBooking, Contract, DemandGenerationParams, Forwarder,
+    LaneDeal, OrderBoardFilter, OrderGenerationParams, PassengerGenerationParams,
+    order::{
+        EMERGENCY_LANDING_DAMAGE, HARD_LANDING_DAMAGE, HARD_LANDING_RUNWAY_MARGIN, Order,
+        OrderPayload, OrderTier, POOR_MAINTENANCE_DAMAGE, SPLIT_COMPLETION_SHARE, SplitShipment,
+    },
+    rare::generate_rare_order,
 };
+use crate::utils::pilots::{Pilot, select_pilot};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 use rusty_runways_commands::Command::*;
 use rusty_runways_commands::{Command, parse_command};
 use serde::{Deserialize, Serialize};
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::{fs, io};
 use strum::IntoEnumIterator;
 
 const REPORT_INTERVAL: u64 = 24;
 const DEFAULT_RESTOCK_CYCLE: u64 = DEFAULT_RESTOCK_CYCLE_HOURS;
 const DEFAULT_FUEL_INTERVAL: u64 = DEFAULT_FUEL_INTERVAL_HOURS;
+/// Call-out fee for maintenance at an airport without a hangar
+pub const MOBILE_MECHANIC_FEE: f32 = 5_000.0;
+/// Hours a plane is grounded when serviced by a mobile mechanic
+pub const MOBILE_MECHANIC_HOURS: GameTime = 6;
+/// Deadline given to self-addressed spare-part shipments
+const PARTS_SHIPMENT_DEADLINE: GameTime = 30 * 24;
+/// Deadline of sandbox-spawned orders when none is given
+#[cfg(feature = "sandbox")]
+const SPAWNED_ORDER_DEADLINE_HOURS: GameTime = 72;
+/// Airports smaller than this handle cargo at this size's rate
+const MIN_HANDLING_SIZE: f32 = 0.25;
+/// Share of the usual handling work a plane-to-plane transfer takes: the load crosses
+/// the ramp once instead of going through the warehouse
+const TRANSFER_HANDLING_SHARE: f32 = 0.5;
+/// Airport ids held in game state (plane destinations, order endpoints) are checked
+/// when they are set, so looking them up again cannot fail.
+const ON_MAP: &str = "game state references an airport on the map";
 
 fn default_rng() -> StdRng {
     StdRng::seed_from_u64(0)
 }
 
+/// Floats in [`Game::state_hash`] are rounded to this many decimal places.
+pub const STATE_HASH_PRECISION: i32 = 3;
+
+/// Write `value` with sorted keys and rounded floats, so equal states always give the
+/// same text.
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+    match value {
+        Value::Number(n) if n.is_f64() => {
+            let scale = 10f64.powi(STATE_HASH_PRECISION);
+            let rounded = (n.as_f64().unwrap_or_default() * scale).round() / scale;
+            // no negative zero, which would hash differently from zero
+            out.push_str(&format!("{}", rounded + 0.0));
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(fields) => {
+            // serde_json keeps objects sorted by key
+            out.push('{');
+            for (i, (key, item)) in fields.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(item, out);
+            }
+            out.push('}');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
 fn default_model_catalog() -> HashMap<String, AirplaneSpecs> {
     let mut m = HashMap::new();
     for model in AirplaneModel::iter() {
@@ -46,8 +141,8 @@ fn default_model_catalog() -> HashMap<String, AirplaneSpecs> {
 mod tests {
     use super::*;
     use crate::config::{
-        AirportConfig, FuelGameplay, GameplayConfig, Location, ManualOrderConfig, OrderTuning,
-        OrdersGameplay, PassengerTuning, WorldConfig,
+        AirportConfig, BordersGameplay, FuelGameplay, GameplayConfig, Location, ManualOrderConfig,
+        MarketGameplay, OrderTuning, OrdersGameplay, PassengerTuning, WorldConfig,
     };
     use crate::utils::orders::CargoType;
     use tempfile::tempdir;
@@ -61,8 +156,30 @@ mod tests {
                 generate_initial: true,
                 tuning: OrderTuning::default(),
                 passengers: PassengerTuning::default(),
+                reservation_share: DEFAULT_RESERVATION_SHARE,
             },
             fuel: FuelGameplay::default(),
+            borders: BordersGameplay::default(),
+            contracts: ContractsGameplay::default(),
+            charters: ChartersGameplay::default(),
+            forwarders: ForwardersGameplay::default(),
+            auctions: AuctionsGameplay::default(),
+            rare_cargo: RareCargoGameplay::default(),
+            world_growth: WorldGrowthGameplay::default(),
+            market: MarketGameplay::default(),
+            loyalty: LoyaltyGameplay::default(),
+            passenger_demand: PassengerDemandGameplay::default(),
+            handling: HandlingGameplay::default(),
+            warehouse: WarehouseGameplay::default(),
+            electric: ElectricGameplay::default(),
+            construction: ConstructionGameplay::default(),
+            concessions: ConcessionsGameplay::default(),
+            used_market: UsedMarketGameplay::default(),
+            maintenance: MaintenanceGameplay::default(),
+            pilots: PilotsGameplay::default(),
+            fixed_costs: FixedCostsGameplay::default(),
+            taxes: TaxesGameplay::default(),
+            overdraft: OverdraftGameplay::default(),
         }
     }
 
@@ -80,6 +197,10 @@ mod tests {
             fuel_price_per_l: Some(1.4),
             landing_fee_per_ton: Some(4.3),
             parking_fee_per_hour: Some(12.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders,
         }
     }
@@ -87,7 +208,7 @@ mod tests {
     #[test]
     fn world_event_branches_update_prices() {
         let mut game = Game::new(9, Some(3), 100_000.0);
-        let base = game.map.airports[0].0.fuel_price;
+        let base = game.map.airport(0).unwrap().fuel_price;
         game.schedule(
             game.time,
             Event::WorldEvent {
@@ -97,7 +218,7 @@ mod tests {
             },
         );
         assert!(game.tick_event());
-        assert!((game.map.airports[0].0.fuel_price - base * 1.2).abs() < 1e-3);
+        assert!((game.map.airport(0).unwrap().fuel_price - base * 1.2).abs() < 1e-3);
 
         game.events.clear();
         game.events.push(ScheduledEvent {
@@ -110,20 +231,27 @@ mod tests {
         game.time += 1;
         assert!(game.tick_event());
         game.events.clear();
-        assert!((game.map.airports[0].0.fuel_price - base).abs() < 1e-3);
+        assert!((game.map.airport(0).unwrap().fuel_price - base).abs() < 1e-3);
     }
 
     #[cfg(feature = "ui_prints")]
     #[test]
     fn list_airports_handles_passenger_orders() {
         let mut game = Game::new(10, Some(3), 250_000.0);
-        game.map.airports[0].0.orders.push(Order {
+        game.map.airport_mut(0).unwrap().orders.push(Order {
             id: 123,
             payload: OrderPayload::Passengers { count: 14 },
             value: 5_500.0,
             deadline: 18,
             origin_id: 0,
             destination_id: 1,
+            tier: Default::default(),
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: Vec::new(),
         });
         game.list_airports(true);
     }
@@ -178,6 +306,7 @@ mod tests {
                     value: 6_200.0,
                     deadline_hours: 26,
                     destination_id: 0,
+                    tier: Default::default(),
                 }],
             ),
         ];
@@ -187,26 +316,35 @@ mod tests {
             starting_cash: 550_000.0,
             airports,
             num_airports: None,
+            airport_names: Vec::new(),
+            world_style: Default::default(),
+            airport_dataset: None,
             gameplay,
             airplanes: None,
+            starting_fleet: Vec::new(),
+            scripts: Vec::new(),
         };
 
         let game = Game::from_config(cfg).expect("config should be accepted");
 
         assert_eq!(game.map.num_airports, 2);
-        let (_, generated_coord) = &game.map.airports[1];
+        let generated_coord = game.map.coord(1).unwrap();
         assert!(generated_coord.x != 0.0 || generated_coord.y != 0.0);
 
-        let passenger_order = game.map.airports[0]
-            .0
+        let passenger_order = game
+            .map
+            .airport(0)
+            .unwrap()
             .orders
             .iter()
             .find(|o| matches!(o.payload, OrderPayload::Passengers { .. }))
             .expect("passenger order should exist");
         assert_eq!(passenger_order.origin_id, 0);
 
-        let cargo_order = game.map.airports[1]
-            .0
+        let cargo_order = game
+            .map
+            .airport(1)
+            .unwrap()
             .orders
             .iter()
             .find(|o| matches!(o.payload, OrderPayload::Cargo { .. }))
@@ -231,6 +369,7 @@ mod tests {
                     value: -10.0,
                     deadline_hours: 12,
                     destination_id: 1,
+                    tier: Default::default(),
                 }],
             ),
             manual_airport(1, "AAY", Some(Location { x: 400.0, y: 800.0 }), Vec::new()),
@@ -241,8 +380,13 @@ mod tests {
             starting_cash: 400_000.0,
             airports,
             num_airports: None,
+            airport_names: Vec::new(),
+            world_style: Default::default(),
+            airport_dataset: None,
             gameplay,
             airplanes: None,
+            starting_fleet: Vec::new(),
+            scripts: Vec::new(),
         };
 
         let err = Game::from_config(cfg).unwrap_err();
@@ -284,8 +428,8 @@ mod tests {
     #[test]
     fn observe_reports_plane_payloads() {
         let mut game = Game::new(12, Some(2), 90_000.0);
-        let dest_coord = game.map.airports[1].1;
-        let origin_coord = game.map.airports[0].1;
+        let dest_coord = game.map.coord(1).unwrap();
+        let origin_coord = game.map.coord(0).unwrap();
         game.airplanes[0].current_passengers = 8;
         game.airplanes[0].current_payload = 450.0;
         game.airplanes[0].status = AirplaneStatus::InTransit {
@@ -347,7 +491,7 @@ mod tests {
     fn tick_event_updates_daily_stats_and_pricing() {
         let mut game = Game::new(7, Some(2), 80_000.0);
         game.events.clear();
-        let base = game.map.airports[0].0.fuel_price;
+        let base = game.map.airport(0).unwrap().fuel_price;
         game.daily_income = 600.0;
         game.daily_expenses = 200.0;
         game.schedule(game.time, Event::DailyStats);
@@ -387,13 +531,20 @@ mod tests {
     #[test]
     fn list_airport_valid_and_invalid() {
         let mut game = Game::new(16, Some(2), 65_000.0);
-        game.map.airports[0].0.orders.push(Order {
+        game.map.airport_mut(0).unwrap().orders.push(Order {
             id: 999,
             payload: OrderPayload::Passengers { count: 10 },
             value: 3_500.0,
             deadline: 12,
             origin_id: 0,
             destination_id: 1,
+            tier: Default::default(),
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: Vec::new(),
         });
         game.show_cash();
         game.show_time();
@@ -419,6 +570,10 @@ fn default_regenerate_orders() -> bool {
     true
 }
 
+fn default_reservation_share() -> f32 {
+    DEFAULT_RESERVATION_SHARE
+}
+
 fn default_arrival_times() -> HashMap<usize, GameTime> {
     HashMap::new()
 }
@@ -446,10 +601,76 @@ where
     })
 }
 
+#[cfg(feature = "ui_prints")]
+fn fuel_stock_label(airport: &Airport) -> String {
+    match airport.fuel_capacity {
+        Some(_) if airport.is_out_of_fuel() => "out of fuel".to_string(),
+        Some(_) => format!("{:.0}L in stock", airport.fuel_stock),
+        None => "unlimited".to_string(),
+    }
+}
+
+#[cfg(feature = "ui_prints")]
+fn pallet_label(order: &Order) -> String {
+    format!(
+        "Pallet of {} orders{} | weight: {:.1}kg",
+        order.pallet.len(),
+        if order.is_fragile() { " (fragile)" } else { "" },
+        order.payload_weight()
+    )
+}
+
+/// Size an airport's fuel storage and stock its spare parts from the game's settings.
+fn fit_out_airport(airport: &mut Airport, fuel: &FuelGameplay, kits: u32) {
+    airport.ensure_base_fuel_price();
+    airport.spare_parts = if airport.has_hangar { kits } else { 0 };
+    airport.fuel_capacity = (!fuel.unlimited_stock)
+        .then(|| default_fuel_capacity(airport.runway_length) * fuel.stock_multiplier);
+    airport.resupply_fuel();
+}
+
+/// Tank or battery level of a plane, e.g. `Fuel: 120.00/240.00L`.
+#[cfg(feature = "ui_prints")]
+fn energy_label(plane: &Airplane) -> String {
+    if plane.specs.is_electric() {
+        format!(
+            "Battery: {:.2}/{:.2}kWh",
+            plane.current_fuel, plane.specs.fuel_capacity
+        )
+    } else {
+        format!(
+            "Fuel: {:.2}/{:.2}L",
+            plane.current_fuel, plane.specs.fuel_capacity
+        )
+    }
+}
+
+/// Cargo slots in use for models that limit them, e.g. ` (2/6 orders)`.
+#[cfg(feature = "ui_prints")]
+fn slots_label(plane: &Airplane) -> String {
+    if plane.specs.cargo_slots == 0 {
+        String::new()
+    } else {
+        format!(
+            " ({}/{} orders)",
+            plane.cargo_orders(),
+            plane.specs.cargo_slots
+        )
+    }
+}
+
 fn default_fuel_settings() -> FuelGameplay {
     FuelGameplay::default()
 }
 
+/// Saves from before pilots existed keep flying without them.
+fn legacy_pilot_settings() -> PilotsGameplay {
+    PilotsGameplay {
+        required: false,
+        ..PilotsGameplay::default()
+    }
+}
+
 fn gameplay_settings(
     cfg: &GameplayConfig,
 ) -> Result<
@@ -463,53 +684,15 @@ fn gameplay_settings(
     ),
     String,
 > {
-    let tuning = &cfg.orders.tuning;
-    let passenger_tuning = &cfg.orders.passengers;
-    if tuning.max_deadline_hours == 0 {
-        return Err("orders.max_deadline_hours must be at least 1".into());
-    }
-    if tuning.min_weight <= 0.0 {
-        return Err("orders.min_weight must be greater than 0".into());
-    }
-    if tuning.max_weight < tuning.min_weight {
-        return Err("orders.max_weight must be >= orders.min_weight".into());
-    }
-    if passenger_tuning.max_deadline_hours == 0 {
-        return Err("orders.passengers.max_deadline_hours must be at least 1".into());
-    }
-    if passenger_tuning.min_count == 0 {
-        return Err("orders.passengers.min_count must be greater than 0".into());
-    }
-    if passenger_tuning.max_count < passenger_tuning.min_count {
-        return Err("orders.passengers.max_count must be >= min_count".into());
-    }
-    if passenger_tuning.fare_per_km <= 0.0 {
-        return Err("orders.passengers.fare_per_km must be greater than 0".into());
-    }
-    if cfg.restock_cycle_hours == 0 {
-        return Err("restock_cycle_hours must be at least 1".into());
-    }
-    if cfg.fuel_interval_hours == 0 {
-        return Err("fuel_interval_hours must be at least 1".into());
-    }
-
-    if cfg.fuel.elasticity <= 0.0 {
-        return Err("fuel.elasticity must be greater than 0".into());
-    }
-    if cfg.fuel.elasticity >= 1.0 {
-        return Err("fuel.elasticity must be less than 1".into());
-    }
-    if cfg.fuel.min_price_multiplier <= 0.0 {
-        return Err("fuel.min_price_multiplier must be greater than 0".into());
-    }
-    if cfg.fuel.max_price_multiplier < cfg.fuel.min_price_multiplier {
-        return Err("fuel.max_price_multiplier must be >= fuel.min_price_multiplier".into());
-    }
-    if cfg.fuel.max_price_multiplier <= 1.0 {
-        return Err("fuel.max_price_multiplier must be greater than 1".into());
+    if let Some(issue) = cfg.validate().into_iter().next() {
+        return Err(issue.message);
     }
 
-    let order_params = OrderGenerationParams::from(tuning);
+    let tuning = &cfg.orders.tuning;
+    let passenger_tuning = &cfg.orders.passengers;
+    let mut order_params = OrderGenerationParams::from(tuning);
+    order_params.restricted_cargo = cfg.borders.restricted_cargo.clone();
+    order_params.market = cfg.market.clone();
     let passenger_params = PassengerGenerationParams::from(passenger_tuning);
     let demand_params = DemandGenerationParams {
         cargo: order_params,
@@ -548,8 +731,23 @@ pub struct Game {
     pub daily_income: f32,
     /// Expenses over each day
     pub daily_expenses: f32,
+    /// Fuel and charging bought today, included in `daily_expenses`
+    #[serde(default)]
+    pub daily_fuel: f32,
+    /// Airport, overflight and customs fees paid today, included in `daily_expenses`
+    #[serde(default)]
+    pub daily_fees: f32,
+    /// Net cash from buying and selling planes and chargers today
+    #[serde(default)]
+    pub daily_investing: f32,
     /// History of all stats
     pub stats: Vec<DailyStats>,
+    /// Statements of every closed calendar month
+    #[serde(default)]
+    pub monthly_reports: Vec<MonthlyReport>,
+    /// Balance sheets and cash flows of every closed calendar quarter
+    #[serde(default)]
+    pub quarterly_statements: Vec<QuarterlyStatement>,
     /// Seed used to create the RNG for deterministic behaviour
     pub seed: u64,
     /// Frequency (in hours) for restocking airports
@@ -564,12 +762,177 @@ pub struct Game {
     /// Whether dynamic restocking is enabled for this save
     #[serde(default = "default_regenerate_orders")]
     pub regenerate_orders: bool,
+    /// Share of an order's value charged to reserve it
+    #[serde(default = "default_reservation_share")]
+    pub reservation_share: f32,
+    /// Orders reserved from afar; they stay on offer through restocks until picked up
+    #[serde(default)]
+    pub reserved_orders: BTreeSet<usize>,
+    /// Customs and overflight fees between countries
+    #[serde(default)]
+    pub borders: BordersGameplay,
+    /// Orders split across several flights, keyed by the original order id
+    #[serde(default)]
+    pub split_shipments: HashMap<usize, SplitShipment>,
+    /// Bulk contracts currently on offer
+    #[serde(default)]
+    pub contracts: Vec<Contract>,
+    /// Id handed to the next generated contract
+    #[serde(default)]
+    pub next_contract_id: usize,
+    /// Contract generation parameters
+    #[serde(default)]
+    pub contract_settings: ContractsGameplay,
+    /// Charter requests on offer or in progress
+    #[serde(default)]
+    pub charters: Vec<Charter>,
+    /// Id handed to the next generated charter
+    #[serde(default)]
+    pub next_charter_id: usize,
+    /// Charter generation parameters
+    #[serde(default)]
+    pub charter_settings: ChartersGameplay,
+    /// Freight forwarders that offer lane deals
+    #[serde(default)]
+    pub forwarders: Vec<Forwarder>,
+    /// Lane deals on offer or running
+    #[serde(default)]
+    pub lane_deals: Vec<LaneDeal>,
+    /// Id handed to the next lane deal
+    #[serde(default)]
+    pub next_lane_deal_id: usize,
+    /// Forwarder and lane deal parameters
+    #[serde(default)]
+    pub forwarder_settings: ForwardersGameplay,
+    /// Spot-charter auctions open for bids
+    #[serde(default)]
+    pub auctions: Vec<Auction>,
+    /// Id handed to the next auction
+    #[serde(default)]
+    pub next_auction_id: usize,
+    /// Auction parameters
+    #[serde(default)]
+    pub auction_settings: AuctionsGameplay,
+    /// How often rare loads turn up and what they pay
+    #[serde(default = "RareCargoGameplay::none")]
+    pub rare_cargo_settings: RareCargoGameplay,
+    /// When and how far new airports open
+    #[serde(default)]
+    pub world_growth_settings: WorldGrowthGameplay,
+    /// Airports that opened during the game, in opening order
+    #[serde(default)]
+    pub opened_airports: Vec<AirportId>,
+    /// Busy airports that already had a neighbour open because of the player's activity
+    #[serde(default)]
+    pub growth_anchors: BTreeSet<AirportId>,
+    /// Recent landings and departures per airport
+    #[serde(default)]
+    pub loyalty: AirportLoyalty,
+    /// Loyalty tiers and the window they are counted over
+    #[serde(default = "LoyaltyGameplay::none")]
+    pub loyalty_settings: LoyaltyGameplay,
+    /// Today's passenger demand between airport pairs
+    #[serde(default)]
+    pub passenger_demand: PassengerDemand,
+    /// Gravity model parameters for passenger demand
+    #[serde(default)]
+    pub demand_settings: PassengerDemandGameplay,
+    /// How fast cargo and passengers are moved on and off planes
+    #[serde(default)]
+    pub handling_settings: HandlingGameplay,
+    /// Orders unloaded short of their destination, with the hour each was staged
+    #[serde(default)]
+    pub staged_orders: BTreeMap<usize, GameTime>,
+    /// Storage fees for staged orders
+    #[serde(default)]
+    pub warehouse_settings: WarehouseGameplay,
+    /// Charger prices and power for electric planes
+    #[serde(default)]
+    pub electric_settings: ElectricGameplay,
+    /// Airstrips and runway extensions being built
+    #[serde(default)]
+    pub constructions: Vec<Construction>,
+    /// Id handed to the next construction project
+    #[serde(default)]
+    pub next_construction_id: usize,
+    /// Prices and build times of airstrips and runway extensions
+    #[serde(default)]
+    pub construction_settings: ConstructionGameplay,
+    /// Fuel concessions the player holds
+    #[serde(default)]
+    pub concessions: Vec<FuelConcession>,
+    /// Prices, discount and income of fuel concessions
+    #[serde(default)]
+    pub concession_settings: ConcessionsGameplay,
+    /// Ticket prices set for scheduled passenger routes, sorted by origin and destination
+    #[serde(default)]
+    pub route_fares: Vec<RouteFare>,
+    /// Used airplanes currently for sale
+    #[serde(default)]
+    pub used_market: Vec<UsedListing>,
+    /// Id handed to the next used-plane listing
+    #[serde(default)]
+    pub next_listing_id: usize,
+    /// Second-hand market parameters
+    #[serde(default)]
+    pub market_settings: UsedMarketGameplay,
+    /// Scheduled A/B/C check parameters
+    #[serde(default)]
+    pub maintenance_settings: MaintenanceGameplay,
+    /// Pilot duty-time limits and pay
+    #[serde(default = "legacy_pilot_settings")]
+    pub pilot_settings: PilotsGameplay,
+    /// Daily hangarage and office overhead
+    #[serde(default = "FixedCostsGameplay::none")]
+    pub fixed_costs: FixedCostsGameplay,
+    /// Profit tax and regional fee multipliers
+    #[serde(default)]
+    pub tax_settings: TaxesGameplay,
+    /// Overdraft limit, interest and warning level
+    #[serde(default = "OverdraftGameplay::none")]
+    pub overdraft_settings: OverdraftGameplay,
+    /// Whether the overdraft warning has been sent since cash last recovered
+    #[serde(default)]
+    pub overdraft_warned: bool,
+    /// Profit made so far in the current tax period, net of carried-over losses
+    #[serde(default)]
+    pub taxable_profit: f32,
+    /// CO2 emitted by the fleet
+    #[serde(default)]
+    pub emissions: Emissions,
+    /// Fingerprint of the world the game started from
+    #[serde(default)]
+    pub config_hash: String,
+    /// Commands issued so far, see [`Game::record_command`]
+    #[serde(default)]
+    pub commands_issued: u64,
     /// Game-local random number generator to avoid global RNG usage
     #[serde(skip, default = "default_rng")]
     rng: StdRng,
     /// Log of messages generated during play
     #[serde(skip, default)]
     log: Vec<String>,
+    /// Channels handed out by [`Game::subscribe`]
+    #[serde(skip, default)]
+    subscribers: Vec<(EventFilter, Sender<GameEvent>)>,
+    /// Last observation handed out by [`Game::observe_diff`]
+    #[serde(skip, default)]
+    tracker: ObservationTracker,
+    /// WASM plugins hooked into the simulation, see [`Game::load_plugin`]
+    #[serde(skip, default)]
+    plugins: PluginHost,
+    /// SQLite file the game's history is appended to, see [`Game::attach_history`]
+    #[serde(skip, default)]
+    history: Option<History>,
+    /// Lua scenario scripts, see [`crate::scripting`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    scripts: Vec<ScriptSource>,
+    /// Keys scripts have passed to `game.once`
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    script_flags: BTreeSet<String>,
+    /// Compiled `scripts`; rebuilt on first use after a load
+    #[serde(skip, default)]
+    script_host: ScriptHost,
     /// Available airplane catalog for purchases and starter selection.
     #[serde(default = "default_model_catalog")]
     model_catalog: HashMap<String, AirplaneSpecs>,
@@ -578,28 +941,67 @@ pub struct Game {
     models_replace: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 pub struct Observation {
     pub time: u64,
     pub cash: f32,
+    /// Score of the run so far, see [`Game::company_value`]
+    pub company_value: f32,
     pub airports: Vec<AirportObs>,
     pub planes: Vec<PlaneObs>,
+    /// Used planes currently for sale
+    pub used_market: Vec<UsedListing>,
+    /// Pilots on the payroll
+    pub pilots: Vec<Pilot>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AirportObs {
     pub id: usize,
     pub name: String,
+    /// Three-letter code of a generated airport, ICAO code of an imported one
+    pub code: Option<String>,
+    /// Hub, regional or strip; sets fuel grades, hangar checks, stands and order volume
+    pub class: AirportClass,
     pub x: f32,
     pub y: f32,
     pub fuel_price: f32,
+    /// Liters in storage; `None` when the airport never runs out
+    pub fuel_stock: Option<f32>,
+    pub has_hangar: bool,
+    /// Spare-part kits in stock
+    pub spare_parts: u32,
     pub runway_length: f32,
+    /// Residents of the catchment area, see [`Airport::size`]
+    pub population: u32,
+    /// Planes that park at the standard rate
+    pub parking_stands: usize,
+    /// Ground crew the player employs here
+    pub ground_crew: u32,
+    /// Electric planes can charge here
+    pub has_charger: bool,
+    /// Hours the local clock is ahead of UTC
+    pub utc_offset: i32,
+    /// Hour of the local day, 0-23
+    pub local_hour: u8,
     pub num_orders: usize,
+    pub country: Option<String>,
+    /// Built by the player, see [`Game::build_airport`]
+    pub player_built: bool,
+    /// The player holds the fuel concession, see [`Game::buy_concession`]
+    pub has_concession: bool,
+    /// Weight in kg of the orders staged here, see [`Game::staged_orders_at`]
+    pub staged_weight: f32,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PlaneObs {
     pub id: usize,
+    pub name: String,
+    /// Held on the ground until released
+    pub on_hold: bool,
+    /// Broke down on landing and waits for `REPAIR PLANE`
+    pub awaiting_repair: bool,
     pub model: String,
     pub x: f32,
     pub y: f32,
@@ -608,20 +1010,31 @@ pub struct PlaneObs {
     pub payload: PayloadObs,
     pub destination: Option<usize>,
     pub hours_remaining: Option<u64>,
+    /// Ground tasks under way at the plane's airport
+    pub turnaround: Vec<TurnaroundTask>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct FuelObs {
+    /// Liters, or kWh when `electric`
     pub current: f32,
     pub capacity: f32,
+    /// Battery-powered plane, see [`Game::charge_plane`]
+    pub electric: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PayloadObs {
     pub cargo_current: f32,
     pub cargo_capacity: f32,
+    /// Cargo orders aboard against the model's slots (0 for no limit)
+    pub cargo_orders: u32,
+    pub cargo_slots: u32,
     pub passenger_current: u32,
     pub passenger_capacity: u32,
+    /// Current takeoff weight (kg) against the model's MTOW
+    pub takeoff_weight: f32,
+    pub mtow: f32,
 }
 
 impl Game {
@@ -643,12 +1056,32 @@ impl Game {
     /// game.advance(1);
     /// ```
     pub fn new(seed: u64, num_airports: Option<usize>, starting_cash: f32) -> Self {
-        let mut map = Map::generate_from_seed(seed, num_airports);
+        Self::new_with_style(seed, num_airports, starting_cash, WorldStyle::default())
+    }
+
+    /// Initialize a new game like [`Game::new`], laying out airports with `style`.
+    ///
+    /// Parameters
+    /// - `seed`: Random seed used for deterministic world generation.
+    /// - `num_airports`: Number of airports to generate (use `None` for default).
+    /// - `starting_cash`: Cash balance for the player at the start.
+    /// - `style`: Layout strategy for the generated airports.
+    ///
+    /// Returns
+    /// - `Game`: A fully initialized simulation ready to run.
+    pub fn new_with_style(
+        seed: u64,
+        num_airports: Option<usize>,
+        starting_cash: f32,
+        style: WorldStyle,
+    ) -> Self {
+        let mut map = Map::generate_from_seed_with_style(seed, num_airports, style);
         for (airport, _) in map.airports.iter_mut() {
             airport.ensure_base_fuel_price();
         }
 
-        let player = Player::new(starting_cash, &map);
+        let mut player = Player::new(starting_cash, &map);
+        player.overdraft_limit = OverdraftGameplay::default().limit;
         let airplanes = player.fleet.clone();
         let arrival_times = airplanes
             .iter()
@@ -665,25 +1098,100 @@ impl Game {
             arrival_times,
             daily_income: 0.0,
             daily_expenses: 0.0,
+            daily_fuel: 0.0,
+            daily_fees: 0.0,
+            daily_investing: 0.0,
             stats: Vec::new(),
+            monthly_reports: Vec::new(),
+            quarterly_statements: Vec::new(),
             seed,
             restock_cycle: DEFAULT_RESTOCK_CYCLE,
             fuel_interval: DEFAULT_FUEL_INTERVAL,
             fuel_settings: FuelGameplay::default(),
             regenerate_orders: true,
+            reservation_share: DEFAULT_RESERVATION_SHARE,
+            reserved_orders: BTreeSet::new(),
+            borders: BordersGameplay::default(),
+            split_shipments: HashMap::new(),
+            contracts: Vec::new(),
+            next_contract_id: 0,
+            contract_settings: ContractsGameplay::default(),
+            charters: Vec::new(),
+            next_charter_id: 0,
+            charter_settings: ChartersGameplay::default(),
+            forwarders: Vec::new(),
+            lane_deals: Vec::new(),
+            next_lane_deal_id: 0,
+            forwarder_settings: ForwardersGameplay::default(),
+            auctions: Vec::new(),
+            next_auction_id: 0,
+            auction_settings: AuctionsGameplay::default(),
+            rare_cargo_settings: RareCargoGameplay::default(),
+            world_growth_settings: WorldGrowthGameplay::default(),
+            opened_airports: Vec::new(),
+            growth_anchors: BTreeSet::new(),
+            loyalty: AirportLoyalty::default(),
+            loyalty_settings: LoyaltyGameplay::default(),
+            passenger_demand: PassengerDemand::default(),
+            demand_settings: PassengerDemandGameplay::default(),
+            handling_settings: HandlingGameplay::default(),
+            staged_orders: BTreeMap::new(),
+            warehouse_settings: WarehouseGameplay::default(),
+            electric_settings: ElectricGameplay::default(),
+            constructions: Vec::new(),
+            next_construction_id: 0,
+            construction_settings: ConstructionGameplay::default(),
+            concessions: Vec::new(),
+            concession_settings: ConcessionsGameplay::default(),
+            route_fares: Vec::new(),
+            used_market: Vec::new(),
+            next_listing_id: 0,
+            market_settings: UsedMarketGameplay::default(),
+            maintenance_settings: MaintenanceGameplay::default(),
+            pilot_settings: PilotsGameplay::default(),
+            fixed_costs: FixedCostsGameplay::default(),
+            tax_settings: TaxesGameplay::default(),
+            overdraft_settings: OverdraftGameplay::default(),
+            overdraft_warned: false,
+            taxable_profit: 0.0,
+            emissions: Emissions::default(),
+            config_hash: fingerprint(
+                format!(
+                    "seed={};airports={:?};cash={};style={:?}",
+                    seed, num_airports, starting_cash, style
+                )
+                .as_bytes(),
+            ),
+            commands_issued: 0,
             rng: StdRng::seed_from_u64(seed),
             log: Vec::new(),
+            subscribers: Vec::new(),
+            tracker: ObservationTracker::default(),
+            plugins: PluginHost::default(),
+            history: None,
+            scripts: Vec::new(),
+            script_flags: BTreeSet::new(),
+            script_host: ScriptHost::default(),
             model_catalog: default_model_catalog(),
             models_replace: false,
         };
 
+        let kits = game.maintenance_settings.starting_spare_kits;
         for (airport, _) in game.map.airports.iter_mut() {
             airport.ensure_base_fuel_price();
+            airport.spare_parts = if airport.has_hangar { kits } else { 0 };
         }
+        game.crew_starting_fleet();
+        game.set_up_forwarders();
+        game.refresh_contracts();
+        game.refresh_charters();
+        game.refresh_used_market();
+        game.refresh_passenger_demand();
 
         game.schedule(game.restock_cycle, Event::Restock);
         game.schedule(REPORT_INTERVAL, Event::DailyStats);
         game.schedule(game.fuel_interval, Event::DynamicPricing);
+        game.schedule(game.restock_cycle, Event::FuelResupply);
         game.schedule_world_event();
         game.schedule(1, Event::MaintenanceCheck);
 
@@ -707,13 +1215,27 @@ impl Game {
     ///     starting_cash: 650_000.0,
     ///     airports: vec![],
     ///     num_airports: Some(4),
+    ///     airport_names: Vec::new(),
+    ///     world_style: Default::default(),
+    ///     airport_dataset: None,
     ///     gameplay: GameplayConfig::default(),
     ///     airplanes: None,
+    ///     starting_fleet: Vec::new(),
+    ///     scripts: Vec::new(),
     /// };
     /// let game = Game::from_config(cfg).unwrap();
     /// assert_eq!(game.airports().len(), 4);
     /// ```
     pub fn from_config(cfg: WorldConfig) -> Result<Self, GameError> {
+        if let Some(issue) = cfg.validate().into_iter().next() {
+            return Err(GameError::InvalidConfig { msg: issue.message });
+        }
+        let config_hash = fingerprint(
+            serde_json::to_string(&cfg)
+                .map_err(|e| GameError::InvalidConfig { msg: e.to_string() })?
+                .as_bytes(),
+        );
+
         let seed = cfg.seed.unwrap_or(0);
         let (
             demand_params,
@@ -723,38 +1245,21 @@ impl Game {
             generate_initial_orders,
             fuel_settings,
         ) = gameplay_settings(&cfg.gameplay).map_err(|msg| GameError::InvalidConfig { msg })?;
-
         let have_explicit_airports = !cfg.airports.is_empty();
-        if have_explicit_airports && cfg.num_airports.is_some() {
-            return Err(GameError::InvalidConfig {
-                msg: "num_airports cannot be provided when airports are explicitly listed".into(),
-            });
-        }
-        if !have_explicit_airports && cfg.num_airports.is_none() {
-            return Err(GameError::InvalidConfig {
-                msg: "num_airports must be provided when airports list is empty".into(),
-            });
-        }
-
-        let map = if have_explicit_airports {
-            use std::collections::HashSet;
 
-            let mut ids = HashSet::new();
-            let mut names = HashSet::new();
-            for a in &cfg.airports {
-                if !ids.insert(a.id) {
-                    return Err(GameError::InvalidConfig {
-                        msg: format!("duplicate airport id {}", a.id),
-                    });
-                }
-                let lower = a.name.to_lowercase();
-                if !names.insert(lower) {
-                    return Err(GameError::InvalidConfig {
-                        msg: format!("duplicate airport name '{}'", a.name),
-                    });
-                }
+        let map = if let Some(dataset) = &cfg.airport_dataset {
+            let rows = select_airports(
+                read_airport_csv(&dataset.path)?,
+                &dataset.countries,
+                dataset.max_airports,
+            );
+            let mut imported = Map::from_dataset_airports(rows, seed, dataset.coordinates)?;
+            imported.demand_params = demand_params.clone();
+            if generate_initial_orders {
+                imported.restock_airports();
             }
-
+            imported
+        } else if have_explicit_airports {
             let mut airports_vec = Vec::with_capacity(cfg.airports.len());
             let mut next_order_id = 0usize;
             let missing_coords: Vec<usize> = cfg
@@ -772,57 +1277,14 @@ impl Game {
             let generated_coords = if missing_coords.is_empty() {
                 Vec::new()
             } else {
-                Map::generate_clustered_coordinates(seed.wrapping_add(13), missing_coords.len())
+                Map::generate_coordinates(
+                    seed.wrapping_add(13),
+                    missing_coords.len(),
+                    cfg.world_style,
+                )
             };
 
             for (idx, a) in cfg.airports.iter().enumerate() {
-                if let Some(len) = a.runway_length_m {
-                    if len <= 0.0 {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!("airport {} runway_length must be > 0", a.id),
-                        });
-                    }
-                }
-                if let Some(price) = a.fuel_price_per_l {
-                    if price <= 0.0 {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!("airport {} fuel_price_per_l must be > 0", a.id),
-                        });
-                    }
-                }
-                if let Some(fee) = a.landing_fee_per_ton {
-                    if fee < 0.0 {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!("airport {} landing_fee_per_ton must be >= 0", a.id),
-                        });
-                    }
-                }
-                if let Some(fee) = a.parking_fee_per_hour {
-                    if fee < 0.0 {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!("airport {} parking_fee_per_hour must be >= 0", a.id),
-                        });
-                    }
-                }
-                if let Some(loc) = a.location {
-                    if !(0.0..=10000.0).contains(&loc.x) || !(0.0..=10000.0).contains(&loc.y) {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!(
-                                "airport {} location ({:.2},{:.2}) out of bounds [0,10000]",
-                                a.id, loc.x, loc.y
-                            ),
-                        });
-                    }
-                }
-                if !regenerate_orders && a.orders.is_empty() {
-                    return Err(GameError::InvalidConfig {
-                        msg: format!(
-                            "airport {} must define at least one order when regeneration is disabled",
-                            a.id
-                        ),
-                    });
-                }
-
                 let default_airport = Airport::generate_random(seed, a.id);
                 let coord = if let Some(loc) = a.location {
                     Coordinate::new(loc.x, loc.y)
@@ -835,6 +1297,17 @@ impl Game {
                 };
 
                 let runway_length = a.runway_length_m.unwrap_or(default_airport.runway_length);
+                // fees not given follow the population when it is set explicitly
+                let default_airport = match a.population {
+                    Some(population) => Airport::with_population(
+                        seed,
+                        a.id,
+                        a.name.clone(),
+                        runway_length,
+                        population,
+                    ),
+                    None => default_airport,
+                };
                 let fuel_price = a.fuel_price_per_l.unwrap_or(default_airport.fuel_price);
                 let landing_fee = a.landing_fee_per_ton.unwrap_or(default_airport.landing_fee);
                 let parking_fee = a
@@ -850,42 +1323,8 @@ impl Game {
                             value,
                             deadline_hours,
                             destination_id,
+                            tier,
                         } => {
-                            if *deadline_hours == 0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has order with deadline_hours == 0",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if *weight <= 0.0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has order with non-positive weight",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if *value < 0.0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!("airport {} has order with negative value", a.id),
-                                });
-                            }
-                            if *destination_id == a.id {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!("airport {} has order pointing to itself", a.id),
-                                });
-                            }
-                            if !ids.contains(destination_id) {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has order with unknown destination {}",
-                                        a.id, destination_id
-                                    ),
-                                });
-                            }
-
                             manual_orders.push(Order {
                                 id: next_order_id,
                                 payload: OrderPayload::Cargo {
@@ -896,6 +1335,13 @@ impl Game {
                                 deadline: *deadline_hours,
                                 origin_id: a.id,
                                 destination_id: *destination_id,
+                                tier: *tier,
+                                damage: 0.0,
+                                shipment: None,
+                                contract: None,
+                                spare_parts: 0,
+                                rare: None,
+                                pallet: Vec::new(),
                             });
                         }
                         ManualOrderConfig::Passengers {
@@ -904,47 +1350,6 @@ impl Game {
                             deadline_hours,
                             destination_id,
                         } => {
-                            if *deadline_hours == 0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has passenger order with deadline_hours == 0",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if *passengers == 0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has passenger order with zero passengers",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if *value < 0.0 {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has passenger order with negative value",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if *destination_id == a.id {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has passenger order pointing to itself",
-                                        a.id
-                                    ),
-                                });
-                            }
-                            if !ids.contains(destination_id) {
-                                return Err(GameError::InvalidConfig {
-                                    msg: format!(
-                                        "airport {} has passenger order with unknown destination {}",
-                                        a.id, destination_id
-                                    ),
-                                });
-                            }
-
                             manual_orders.push(Order {
                                 id: next_order_id,
                                 payload: OrderPayload::Passengers { count: *passengers },
@@ -952,6 +1357,13 @@ impl Game {
                                 deadline: *deadline_hours,
                                 origin_id: a.id,
                                 destination_id: *destination_id,
+                                tier: OrderTier::Standard,
+                                damage: 0.0,
+                                shipment: None,
+                                contract: None,
+                                spare_parts: 0,
+                                rare: None,
+                                pallet: Vec::new(),
                             });
                         }
                     }
@@ -961,6 +1373,7 @@ impl Game {
                 let ap = Airport {
                     id: a.id,
                     name: a.name.clone(),
+                    code: None,
                     runway_length,
                     fuel_price,
                     base_fuel_price: fuel_price,
@@ -968,32 +1381,51 @@ impl Game {
                     parking_fee,
                     orders: manual_orders,
                     fuel_sold: 0.0,
+                    fuel_capacity: None,
+                    fuel_stock: 0.0,
+                    has_hangar: a.has_hangar.unwrap_or(runway_length >= HANGAR_MIN_RUNWAY),
+                    country: None,
+                    spare_parts: 0,
+                    ground_crew: 0,
+                    has_charger: false,
+                    utc_offset: a.utc_offset,
+                    population: a
+                        .population
+                        .unwrap_or_else(|| default_population(runway_length)),
+                    player_built: false,
                 };
                 airports_vec.push((ap, coord));
             }
 
             let mut built =
                 Map::from_airports(seed, airports_vec, demand_params.clone(), next_order_id);
+            built.world_style = cfg.world_style;
+            if cfg.airports.iter().any(|a| a.country.is_some()) {
+                for a in &cfg.airports {
+                    if let Some(name) = &a.country {
+                        let country = built.country_index(name);
+                        if let Some(airport) = built.airport_mut(a.id) {
+                            airport.country = Some(country);
+                        }
+                    }
+                }
+            } else {
+                built.assign_countries(cfg.gameplay.borders.countries);
+            }
             if regenerate_orders && generate_initial_orders {
                 built.restock_airports();
             }
             built
         } else {
             let num_airports = cfg.num_airports.unwrap();
-            if num_airports == 0 {
-                return Err(GameError::InvalidConfig {
-                    msg: "num_airports must be greater than 0".into(),
-                });
-            }
-            if !regenerate_orders {
-                return Err(GameError::InvalidConfig {
-                    msg: "orders.regenerate=false requires explicit airports with manual orders"
-                        .into(),
-                });
-            }
 
-            let mut generated = Map::generate_from_seed(seed, Some(num_airports));
+            let mut generated =
+                Map::generate_from_seed_with_style(seed, Some(num_airports), cfg.world_style);
             generated.demand_params = demand_params.clone();
+            for ((airport, _), name) in generated.airports.iter_mut().zip(&cfg.airport_names) {
+                airport.name = name.clone();
+            }
+            generated.assign_countries(cfg.gameplay.borders.countries);
             generated.clear_orders();
             if generate_initial_orders {
                 generated.restock_airports();
@@ -1010,21 +1442,19 @@ impl Game {
                     models_replace = true;
                     catalog.clear();
                 }
-                let mut seen = std::collections::HashSet::new();
                 for m in &acfg.models {
-                    validate_model_config(m)?;
-                    let key = m.name.trim().to_lowercase();
-                    if !seen.insert(key) {
-                        return Err(GameError::InvalidConfig {
-                            msg: format!("duplicate airplane model name '{}'", m.name),
-                        });
-                    }
                     catalog.insert(m.name.clone(), model_specs_from_config(m));
                 }
             }
         }
 
-        let player = Player::new_from_catalog(cfg.starting_cash, &map, &catalog);
+        let mut player = if cfg.starting_fleet.is_empty() {
+            Player::new_from_catalog(cfg.starting_cash, &map, &catalog)
+        } else {
+            let fleet = build_starting_fleet(&cfg.starting_fleet, &map, &catalog)?;
+            Player::with_fleet(cfg.starting_cash, fleet)
+        };
+        player.overdraft_limit = cfg.gameplay.overdraft.limit;
         let airplanes = player.fleet.clone();
         let arrival_times = airplanes
             .iter()
@@ -1041,27 +1471,114 @@ impl Game {
             arrival_times,
             daily_income: 0.0,
             daily_expenses: 0.0,
+            daily_fuel: 0.0,
+            daily_fees: 0.0,
+            daily_investing: 0.0,
             stats: Vec::new(),
+            monthly_reports: Vec::new(),
+            quarterly_statements: Vec::new(),
             seed,
             restock_cycle,
             fuel_interval,
             fuel_settings,
             regenerate_orders,
+            reservation_share: cfg.gameplay.orders.reservation_share,
+            reserved_orders: BTreeSet::new(),
+            borders: cfg.gameplay.borders.clone(),
+            split_shipments: HashMap::new(),
+            contracts: Vec::new(),
+            next_contract_id: 0,
+            contract_settings: cfg.gameplay.contracts.clone(),
+            charters: Vec::new(),
+            next_charter_id: 0,
+            charter_settings: cfg.gameplay.charters.clone(),
+            forwarders: Vec::new(),
+            lane_deals: Vec::new(),
+            next_lane_deal_id: 0,
+            forwarder_settings: cfg.gameplay.forwarders.clone(),
+            auctions: Vec::new(),
+            next_auction_id: 0,
+            auction_settings: cfg.gameplay.auctions.clone(),
+            rare_cargo_settings: cfg.gameplay.rare_cargo.clone(),
+            world_growth_settings: cfg.gameplay.world_growth.clone(),
+            opened_airports: Vec::new(),
+            growth_anchors: BTreeSet::new(),
+            loyalty: AirportLoyalty::default(),
+            loyalty_settings: cfg.gameplay.loyalty.clone(),
+            passenger_demand: PassengerDemand::default(),
+            demand_settings: cfg.gameplay.passenger_demand.clone(),
+            handling_settings: cfg.gameplay.handling.clone(),
+            staged_orders: BTreeMap::new(),
+            warehouse_settings: cfg.gameplay.warehouse.clone(),
+            electric_settings: cfg.gameplay.electric.clone(),
+            constructions: Vec::new(),
+            next_construction_id: 0,
+            construction_settings: cfg.gameplay.construction.clone(),
+            concessions: Vec::new(),
+            concession_settings: cfg.gameplay.concessions.clone(),
+            route_fares: Vec::new(),
+            used_market: Vec::new(),
+            next_listing_id: 0,
+            market_settings: cfg.gameplay.used_market.clone(),
+            maintenance_settings: cfg.gameplay.maintenance.clone(),
+            pilot_settings: cfg.gameplay.pilots.clone(),
+            fixed_costs: cfg.gameplay.fixed_costs.clone(),
+            tax_settings: cfg.gameplay.taxes.clone(),
+            overdraft_settings: cfg.gameplay.overdraft.clone(),
+            overdraft_warned: false,
+            taxable_profit: 0.0,
+            emissions: Emissions::default(),
+            config_hash,
+            commands_issued: 0,
             rng: StdRng::seed_from_u64(seed),
             log: Vec::new(),
+            subscribers: Vec::new(),
+            tracker: ObservationTracker::default(),
+            plugins: PluginHost::default(),
+            history: None,
+            scripts: read_scripts(&cfg.scripts)?,
+            script_flags: BTreeSet::new(),
+            script_host: ScriptHost::default(),
             model_catalog: catalog,
             models_replace,
         };
 
+        for (idx, script) in game.scripts.iter().enumerate() {
+            game.script_host
+                .load(script)
+                .map_err(|e| GameError::InvalidConfig {
+                    msg: format!("scripts[{}] ({}): {}", idx, script.name, e),
+                })?;
+        }
+
+        let fuel = &game.fuel_settings;
+        let kits = game.maintenance_settings.starting_spare_kits;
         for (airport, _) in game.map.airports.iter_mut() {
-            airport.ensure_base_fuel_price();
+            fit_out_airport(airport, fuel, kits);
+        }
+        let reserve_hours = game.fuel_settings.reserve_minutes / 60.0;
+        for plane in game
+            .airplanes
+            .iter_mut()
+            .chain(game.player.fleet.iter_mut())
+        {
+            plane.reserve_hours = reserve_hours;
         }
+        game.crew_starting_fleet();
+        game.set_up_forwarders();
+        if generate_initial_orders {
+            game.refresh_contracts();
+            game.refresh_charters();
+        }
+        game.refresh_used_market();
+        game.refresh_passenger_demand();
 
         if game.regenerate_orders {
             game.schedule(game.restock_cycle, Event::Restock);
         }
         game.schedule(REPORT_INTERVAL, Event::DailyStats);
         game.schedule(game.fuel_interval, Event::DynamicPricing);
+        game.schedule(game.restock_cycle, Event::FuelResupply);
         game.schedule_world_event();
         game.schedule(1, Event::MaintenanceCheck);
 
@@ -1132,7 +1649,8 @@ impl Game {
         let airport = if is_global {
             None
         } else {
-            Some(self.rng.gen_range(0..self.map.num_airports))
+            let pick = self.rng.gen_range(0..self.map.num_airports);
+            self.map.airports.iter().nth(pick).map(|(a, _)| a.id)
         };
 
         // price can spike or crash
@@ -1154,6 +1672,184 @@ impl Game {
         );
     }
 
+    /// Roll for headwinds on a plane that is still in the air and divert it to the
+    /// nearest suitable airport once they have eaten into its fuel reserve.
+    fn check_fuel_reserve(&mut self, plane_idx: usize) {
+        let plane = self.airplanes[plane_idx].id;
+        let AirplaneStatus::InTransit { destination, .. } = self.airplanes[plane_idx].status else {
+            return;
+        };
+
+        // separate RNG so headwinds don't shift the world event sequence
+        let mut rng = StdRng::seed_from_u64(
+            self.seed
+                .wrapping_add(self.time.wrapping_mul(0x9E37_79B9))
+                .wrapping_add(plane as u64),
+        );
+        let settings = &self.fuel_settings;
+        if !rng.gen_bool(settings.headwind_chance.clamp(0.0, 1.0) as f64) {
+            return;
+        }
+
+        let airplane = &mut self.airplanes[plane_idx];
+        let extra_burn = settings.headwind_burn_minutes / 60.0 * airplane.fuel_burn();
+        let fuel_left = airplane.current_fuel - extra_burn;
+        let fuel_before = airplane.current_fuel;
+        airplane.current_fuel = fuel_left.max(0.0);
+        self.emissions
+            .record_use(&airplane.specs, fuel_before - airplane.current_fuel);
+        if airplane.current_fuel >= airplane.reserve_fuel() {
+            return;
+        }
+
+        let Some(alternate) = self.nearest_landing_site(plane_idx) else {
+            return;
+        };
+
+        // fuel left on touchdown at the alternate, counting the trip fuel still in the tank
+        let airplane = &self.airplanes[plane_idx];
+        let remaining_km = airplane.distance_to(&self.airport_coord(destination));
+        let diversion_km = airplane.distance_to(&self.airport_coord(alternate));
+        let landing_fuel = fuel_left
+            + (remaining_km - diversion_km) / airplane.cruise_speed() * airplane.fuel_burn();
+        if landing_fuel < 0.0 {
+            // the plane would run dry before reaching any runway
+            let _ = self.emergency_land(plane);
+            return;
+        }
+        if alternate == destination {
+            return;
+        }
+        self.divert(plane_idx, alternate);
+
+        let message = format!(
+            "Plane {} diverting to {}: headwinds burned into its fuel reserve on the way to {}.",
+            plane,
+            self.airport_name(alternate),
+            self.airport_name(destination)
+        );
+        println!("{}", message);
+        self.log.push(message);
+    }
+
+    /// Location of an airport referenced by game state, which only holds ids on the map.
+    fn airport_coord(&self, id: AirportId) -> Coordinate {
+        self.map.coord(id).expect(ON_MAP)
+    }
+
+    /// Name of an airport referenced by game state.
+    fn airport_name(&self, id: AirportId) -> &str {
+        self.map
+            .airport(id)
+            .map(|airport| airport.name.as_str())
+            .expect(ON_MAP)
+    }
+
+    /// Nearest airport with a runway long enough for the plane to land on.
+    fn nearest_landing_site(&self, plane_idx: usize) -> Option<AirportId> {
+        let airplane = &self.airplanes[plane_idx];
+        self.map
+            .airports
+            .iter()
+            .filter(|(airport, _)| airport.runway_length >= airplane.specs.min_runway_length)
+            .min_by(|(_, a), (_, b)| {
+                airplane
+                    .distance_to(a)
+                    .partial_cmp(&airplane.distance_to(b))
+                    .unwrap()
+            })
+            .map(|(airport, _)| airport.id)
+    }
+
+    /// Turn a plane in transit towards `alternate`, giving back the trip fuel for the
+    /// rest of its planned route and taking out what the new leg needs.
+    fn divert(&mut self, plane_idx: usize, alternate: AirportId) {
+        let AirplaneStatus::InTransit { destination, .. } = self.airplanes[plane_idx].status else {
+            return;
+        };
+        let destination_coord = self.airport_coord(destination);
+        let alternate_coord = self.airport_coord(alternate);
+        let airplane = &mut self.airplanes[plane_idx];
+        let remaining_km = airplane.distance_to(&destination_coord);
+        let speed = airplane.cruise_speed();
+        let burn = airplane.fuel_burn();
+        let diversion_km = airplane.distance_to(&alternate_coord);
+        let fuel_before = airplane.current_fuel;
+        airplane.current_fuel =
+            (airplane.current_fuel + (remaining_km - diversion_km) / speed * burn).max(0.0);
+        self.emissions
+            .record_use(&airplane.specs, fuel_before - airplane.current_fuel);
+
+        let hours = ((diversion_km / speed).ceil() as GameTime).max(1);
+        airplane.status = AirplaneStatus::InTransit {
+            hours_remaining: hours,
+            destination: alternate,
+            origin: airplane.location,
+            total_hours: hours,
+        };
+    }
+
+    /// Declare an emergency and put a plane in the air down at the nearest airport it
+    /// can land on, whether or not that is where it was headed.
+    ///
+    /// The emergency landing fee is charged straight away and, with the configured
+    /// chance, fragile cargo on board is damaged. Planes also declare an emergency on
+    /// their own when headwinds leave them without the fuel to reach any runway.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane in transit.
+    ///
+    /// Returns
+    /// - `Ok(usize)`: Id of the airport the plane is now headed for.
+    /// - `Err(GameError)`: If the plane doesn't exist, isn't flying, or no airport has a
+    ///   long enough runway.
+    pub fn emergency_land(&mut self, plane_id: usize) -> Result<usize, GameError> {
+        let plane_idx = self
+            .airplanes
+            .iter()
+            .position(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+        let AirplaneStatus::InTransit { destination, .. } = self.airplanes[plane_idx].status else {
+            return Err(GameError::PlaneNotReady {
+                plane_state: self.airplanes[plane_idx].status.clone(),
+            });
+        };
+        let alternate =
+            self.nearest_landing_site(plane_idx)
+                .ok_or_else(|| GameError::InvalidCommand {
+                    msg: format!("No airport can take plane {}", plane_id),
+                })?;
+        self.divert(plane_idx, alternate);
+
+        let fee = self.fuel_settings.emergency_landing_fee;
+        self.player.cash -= fee;
+        self.daily_expenses += fee;
+        self.daily_fees += fee;
+
+        // separate RNG so emergencies don't shift the world event sequence
+        let mut rng = StdRng::seed_from_u64(
+            self.seed
+                .wrapping_add(self.time.wrapping_mul(0x85EB_CA6B))
+                .wrapping_add(plane_id as u64),
+        );
+        let damage_chance = self.fuel_settings.emergency_damage_chance.clamp(0.0, 1.0) as f64;
+        if rng.gen_bool(damage_chance) {
+            for order in self.airplanes[plane_idx].manifest.iter_mut() {
+                order.apply_damage(EMERGENCY_LANDING_DAMAGE);
+            }
+        }
+
+        let message = format!(
+            "Plane {} declared an emergency on the way to {} and is landing at {}.",
+            plane_id,
+            self.airport_name(destination),
+            self.airport_name(alternate)
+        );
+        println!("{}", message);
+        self.log.push(message);
+        Ok(alternate)
+    }
+
     /// Write the entire game state to JSON to save.
     ///
     /// Parameters
@@ -1167,16 +1863,40 @@ impl Game {
     /// let game = rusty_runways_core::Game::new(1, Some(3), 0.0);
     /// game.save_game("my-save").unwrap();
     /// ```
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(level = "debug", skip(self))
+    )]
     pub fn save_game(&self, name: &str) -> io::Result<()> {
-        let save_dir = Path::new("save_games");
-        fs::create_dir_all(save_dir)?;
+        self.save_game_with(name, &SaveOptions::plain())
+            .map_err(io::Error::from)
+    }
 
-        let mut path = PathBuf::from(save_dir);
+    /// Save the current game to `save_games/<name>.json`, sealed and/or encrypted.
+    ///
+    /// Parameters
+    /// - `name`: Logical save name (without extension).
+    /// - `options`: Whether to add an integrity checksum and encrypt with a passphrase.
+    ///
+    /// Returns
+    /// - `Err(SaveError)`: If the file cannot be written, or encryption is requested
+    ///   without the `encryption` feature.
+    pub fn save_game_with(&self, name: &str, options: &SaveOptions) -> Result<(), SaveError> {
+        let mut path = PathBuf::from("save_games");
         path.push(format!("{}.json", name));
+        self.save_game_to(&path, options)
+    }
 
-        let file = fs::File::create(&path)?;
-        let writer = io::BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, self).map_err(io::Error::other)
+    /// Save the current game to the file at `path`, creating its directory if needed.
+    ///
+    /// Same format as [`Game::save_game_with`], for saves kept outside `save_games/`.
+    pub fn save_game_to(&self, path: &Path, options: &SaveOptions) -> Result<(), SaveError> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let state = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, save::seal(state, options)?)?;
+        Ok(())
     }
 
     fn refresh_airplane_specs(&mut self) {
@@ -1202,11 +1922,24 @@ impl Game {
         }
     }
 
-    /// Load a game from JSON.
-    ///
-    /// Parameters
-    /// - `name`: Logical save name (without extension) previously used in [`Game::save_game`].
-    ///
+    /// Give planes from saves that predate plane names their default tail number.
+    fn assign_missing_registrations(&mut self) {
+        for plane in self
+            .airplanes
+            .iter_mut()
+            .chain(self.player.fleet.iter_mut())
+        {
+            if plane.name.is_empty() {
+                plane.name = default_registration(plane.id);
+            }
+        }
+    }
+
+    /// Load a game from JSON.
+    ///
+    /// Parameters
+    /// - `name`: Logical save name (without extension) previously used in [`Game::save_game`].
+    ///
     /// Returns
     /// - `Ok(Game)`: Loaded game.
     /// - `Err(io::Error)`: If the file does not exist or has invalid content.
@@ -1215,21 +1948,43 @@ impl Game {
     /// ```no_run
     /// let game = rusty_runways_core::Game::load_game("my-save").unwrap();
     /// ```
+    #[cfg_attr(feature = "telemetry", tracing::instrument(level = "debug"))]
     pub fn load_game(name: &str) -> io::Result<Self> {
+        Game::load_game_with(name, None).map_err(io::Error::from)
+    }
+
+    /// Load a game saved with [`Game::save_game_with`], checking its integrity.
+    ///
+    /// Parameters
+    /// - `name`: Logical save name (without extension).
+    /// - `passphrase`: Needed for encrypted saves, ignored otherwise.
+    ///
+    /// Returns
+    /// - `Ok(Game)`: Loaded game.
+    /// - `Err(SaveError)`: If the file is missing, corrupt, fails its checksum, or cannot
+    ///   be decrypted.
+    pub fn load_game_with(name: &str, passphrase: Option<&str>) -> Result<Self, SaveError> {
         let mut path = PathBuf::from("save_games");
         path.push(format!("{}.json", name));
+        Game::load_game_from(&path, passphrase)
+    }
 
+    /// Load a game from the file at `path`, written by [`Game::save_game_to`] or
+    /// [`Game::save_game_with`].
+    pub fn load_game_from(path: &Path, passphrase: Option<&str>) -> Result<Self, SaveError> {
         if !path.exists() {
-            return Err(io::Error::new(
+            return Err(SaveError::Io(io::Error::new(
                 io::ErrorKind::NotFound,
                 format!("Save file '{}' not found", path.display()),
-            ));
+            )));
         }
 
-        let file = fs::File::open(&path)?;
-        let reader = io::BufReader::new(file);
-        let mut game: Game = serde_json::from_reader(reader).map_err(io::Error::other)?;
+        let state = save::open(fs::read_to_string(path)?, passphrase)?;
+        let mut game: Game = serde_json::from_str(&state).map_err(|e| SaveError::Corrupt {
+            reason: e.to_string(),
+        })?;
         game.refresh_airplane_specs();
+        game.assign_missing_registrations();
         Ok(game)
     }
 
@@ -1248,16 +2003,229 @@ impl Game {
         println!("${}", self.player.cash);
     }
 
+    /// Show what the company is worth and how the value is made up
+    #[cfg(feature = "ui_prints")]
+    pub fn show_value(&self) {
+        let value = self.valuation();
+        println!("Cash:        ${:>14.2}", value.cash);
+        println!("Fleet:       ${:>14.2}", value.fleet_value);
+        println!("Debt:       -${:>14.2}", value.debt);
+        println!("Reputation:  ${:>14.2}", value.reputation);
+        println!("Total:       ${:>14.2}", value.total);
+    }
+
+    /// Count a command towards the run summary.
+    ///
+    /// [`Game::execute`] does this itself; front-ends that call the game methods directly
+    /// record each parsed command instead.
+    pub fn record_command(&mut self) {
+        self.commands_issued += 1;
+    }
+
+    /// Hash of the full saved state, for checking whether two runs ended up identical.
+    ///
+    /// The state is hashed in a canonical form: keys are sorted, so `HashMap` order does
+    /// not matter, and floats are rounded to [`STATE_HASH_PRECISION`] so that the last
+    /// bits of a sum computed in a different order do not either. The hash is stable
+    /// across platforms and Rust versions.
+    pub fn state_hash(&self) -> u64 {
+        let state = serde_json::to_value(self).expect("game state serializes to JSON");
+        let mut canonical = String::new();
+        write_canonical(&state, &mut canonical);
+        fnv1a(canonical.as_bytes())
+    }
+
+    /// [`Game::state_hash`] as 16 hex digits, the way the CLI prints it.
+    pub fn state_hash_hex(&self) -> String {
+        format!("{:016x}", self.state_hash())
+    }
+
+    /// Print the state hash
+    #[cfg(feature = "ui_prints")]
+    pub fn show_hash(&self) {
+        println!("{}", self.state_hash_hex());
+    }
+
+    /// An independent copy of the game for look-ahead search, e.g. rolling out candidate
+    /// moves in MCTS or beam search without a save and load.
+    ///
+    /// The copy continues exactly like the original would, random draws included, so two
+    /// forks given the same commands stay identical. Runtime-only parts start empty: the
+    /// log, event subscribers, observation versions, plugins and the history file are not
+    /// carried over, and scripts are compiled again on first use.
+    pub fn fork(&self) -> Game {
+        Game {
+            time: self.time,
+            map: self.map.clone(),
+            airplanes: self.airplanes.clone(),
+            arrival_times: self.arrival_times.clone(),
+            player: self.player.clone(),
+            events: self.events.clone(),
+            daily_income: self.daily_income,
+            daily_expenses: self.daily_expenses,
+            daily_fuel: self.daily_fuel,
+            daily_fees: self.daily_fees,
+            daily_investing: self.daily_investing,
+            stats: self.stats.clone(),
+            monthly_reports: self.monthly_reports.clone(),
+            quarterly_statements: self.quarterly_statements.clone(),
+            seed: self.seed,
+            restock_cycle: self.restock_cycle,
+            fuel_interval: self.fuel_interval,
+            fuel_settings: self.fuel_settings.clone(),
+            regenerate_orders: self.regenerate_orders,
+            reservation_share: self.reservation_share,
+            reserved_orders: self.reserved_orders.clone(),
+            borders: self.borders.clone(),
+            split_shipments: self.split_shipments.clone(),
+            contracts: self.contracts.clone(),
+            next_contract_id: self.next_contract_id,
+            contract_settings: self.contract_settings.clone(),
+            charters: self.charters.clone(),
+            next_charter_id: self.next_charter_id,
+            charter_settings: self.charter_settings.clone(),
+            forwarders: self.forwarders.clone(),
+            lane_deals: self.lane_deals.clone(),
+            next_lane_deal_id: self.next_lane_deal_id,
+            forwarder_settings: self.forwarder_settings.clone(),
+            auctions: self.auctions.clone(),
+            next_auction_id: self.next_auction_id,
+            auction_settings: self.auction_settings.clone(),
+            rare_cargo_settings: self.rare_cargo_settings.clone(),
+            world_growth_settings: self.world_growth_settings.clone(),
+            opened_airports: self.opened_airports.clone(),
+            growth_anchors: self.growth_anchors.clone(),
+            loyalty: self.loyalty.clone(),
+            loyalty_settings: self.loyalty_settings.clone(),
+            passenger_demand: self.passenger_demand.clone(),
+            demand_settings: self.demand_settings.clone(),
+            handling_settings: self.handling_settings.clone(),
+            staged_orders: self.staged_orders.clone(),
+            warehouse_settings: self.warehouse_settings.clone(),
+            electric_settings: self.electric_settings.clone(),
+            constructions: self.constructions.clone(),
+            next_construction_id: self.next_construction_id,
+            construction_settings: self.construction_settings.clone(),
+            concessions: self.concessions.clone(),
+            concession_settings: self.concession_settings.clone(),
+            route_fares: self.route_fares.clone(),
+            used_market: self.used_market.clone(),
+            next_listing_id: self.next_listing_id,
+            market_settings: self.market_settings.clone(),
+            maintenance_settings: self.maintenance_settings.clone(),
+            pilot_settings: self.pilot_settings.clone(),
+            fixed_costs: self.fixed_costs.clone(),
+            tax_settings: self.tax_settings.clone(),
+            overdraft_settings: self.overdraft_settings.clone(),
+            overdraft_warned: self.overdraft_warned,
+            taxable_profit: self.taxable_profit,
+            emissions: self.emissions,
+            config_hash: self.config_hash.clone(),
+            commands_issued: self.commands_issued,
+            rng: self.rng.clone(),
+            log: Vec::new(),
+            subscribers: Vec::new(),
+            tracker: ObservationTracker::default(),
+            plugins: PluginHost::default(),
+            history: None,
+            scripts: self.scripts.clone(),
+            script_flags: self.script_flags.clone(),
+            script_host: ScriptHost::default(),
+            model_catalog: self.model_catalog.clone(),
+            models_replace: self.models_replace,
+        }
+    }
+
+    /// Summarize the run so far for comparing results on the same seed.
+    ///
+    /// Returns
+    /// - `RunSummary`: Seed, world fingerprint, days played, deliveries, company value and
+    ///   command count, sealed with a checksum.
+    pub fn run_summary(&self) -> RunSummary {
+        RunSummary::new(
+            self.seed,
+            self.config_hash.clone(),
+            self.time / 24,
+            self.player.orders_delivered,
+            self.company_value(),
+            self.commands_issued,
+        )
+    }
+
+    /// Value the company at the current game time.
+    ///
+    /// Returns
+    /// - `CompanyValue`: Cash, fleet book value, debt and reputation bonus with their total.
+    pub fn valuation(&self) -> CompanyValue {
+        let fleet_value = self
+            .airplanes
+            .iter()
+            .map(|plane| plane.resale_value(self.time))
+            .sum();
+        CompanyValue::new(self.player.cash, fleet_value, self.player.orders_delivered)
+    }
+
+    /// Balance sheets and cash flows of the closed calendar quarters, oldest first.
+    ///
+    /// A quarter closes with the monthly report of its last month.
+    pub fn financials(&self) -> &[QuarterlyStatement] {
+        &self.quarterly_statements
+    }
+
+    /// Daily stats with their statement categories, one record per day.
+    pub fn stats_records(&self) -> Vec<StatsRecord> {
+        self.stats.iter().map(StatsRecord::from).collect()
+    }
+
+    /// Company value: cash plus fleet book value, minus debt, plus a reputation bonus.
+    ///
+    /// This is the canonical score of a run, recorded in the daily stats as well.
+    pub fn company_value(&self) -> f32 {
+        self.valuation().total
+    }
+
     /// Show current time
     #[cfg(feature = "ui_prints")]
     pub fn show_time(&self) {
-        println!("{}", self.days_and_hours(self.time));
+        println!(
+            "{} ({})",
+            self.days_and_hours(self.time),
+            Date::from_time(self.time)
+        );
+    }
+
+    /// Show the statement of the last closed month
+    #[cfg(feature = "ui_prints")]
+    pub fn show_report(&self) {
+        let Some(report) = self.monthly_reports.last() else {
+            println!("No month has closed yet.");
+            return;
+        };
+        println!(
+            "Statement for {} year {}",
+            report.date().month_name(),
+            report.year
+        );
+        for (label, amount) in report.lines() {
+            println!("  {:<10} {:>14.2}", label, amount);
+        }
+        println!("  {:<10} {:>14.2}", "Cash", report.closing_cash);
     }
 
     /// Shows the lifetime stats
     #[cfg(feature = "ui_prints")]
     pub fn show_stats(&self) {
-        let headers = ["Day", "Income", "Expense", "End Cash", "Fleet", "Delivered"];
+        let headers = [
+            "Day",
+            "Income",
+            "Expense",
+            "Fixed",
+            "Tax",
+            "CO2 t",
+            "End Cash",
+            "Fleet",
+            "Delivered",
+        ];
 
         //get max width per column
         let mut col_widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
@@ -1268,6 +2236,12 @@ impl Game {
                 s.day.to_string(),
                 format!("{:.2}", s.income),
                 format!("{:.2}", s.expenses),
+                format!(
+                    "{:.2}",
+                    s.salaries + s.crew_wages + s.hangarage + s.overhead
+                ),
+                format!("{:.2}", s.taxes + s.carbon_tax),
+                format!("{:.1}", s.co2_tons),
                 format!("{:.2}", s.net_cash),
                 s.fleet_size.to_string(),
                 s.total_deliveries.to_string(),
@@ -1306,32 +2280,212 @@ impl Game {
     }
 
     /// Process the next scheduled event; advance `self.time`. Returns false if no events remain.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(level = "trace", skip(self))
+    )]
     pub fn tick_event(&mut self) -> bool {
+        let cash_before = self.player.cash;
+        let ticked = self.process_next_event();
+        let delta = self.player.cash - cash_before;
+        if ticked && delta != 0.0 {
+            self.notify(GameEvent::CashChanged {
+                time: self.time,
+                delta,
+                balance: self.player.cash,
+            });
+        }
+        self.check_overdraft();
+        ticked
+    }
+
+    /// Subscribe to [`GameEvent`]s matching `filter`.
+    ///
+    /// Events are sent as they happen, including during [`Game::advance`]. Dropping the
+    /// receiver ends the subscription. Subscriptions are not saved with the game.
+    pub fn subscribe(&mut self, filter: EventFilter) -> Receiver<GameEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push((filter, tx));
+        rx
+    }
+
+    /// Load a WASM plugin; see [`crate::plugins`] for the hooks it can export.
+    ///
+    /// Plugins are not saved with the game and must be loaded again after a load.
+    ///
+    /// Returns
+    /// - `Err(PluginError)`: If the module is invalid, or the `plugins` feature is off.
+    pub fn load_plugin(&mut self, name: &str, wasm: &[u8]) -> Result<(), PluginError> {
+        self.plugins.load(name, wasm)
+    }
+
+    /// Append the game's history to the SQLite file at `path` from now on; see
+    /// [`crate::history`] for what is kept.
+    ///
+    /// Rows after the current time are dropped first, so loading an older save and
+    /// playing on rewrites the file instead of mixing two runs. Like plugins, the
+    /// history is not saved with the game; loading a save picks up
+    /// [`history::path_for_save`] if that file exists.
+    ///
+    /// Returns
+    /// - `Err(HistoryError)`: If the file cannot be opened, or the `history` feature is off.
+    pub fn attach_history(&mut self, path: impl AsRef<Path>) -> Result<(), HistoryError> {
+        let mut history = History::open(path.as_ref())?;
+        history.truncate_after(self.time)?;
+        self.history = Some(history);
+        Ok(())
+    }
+
+    /// Stop writing the history, flushing rows of the unfinished day.
+    pub fn detach_history(&mut self) -> Result<(), HistoryError> {
+        match self.history.take() {
+            Some(mut history) => history.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// The attached history file, for querying past days, cash movements and deliveries.
+    pub fn history(&self) -> Option<&History> {
+        self.history.as_ref()
+    }
+
+    /// Names of the loaded plugins, in the order their hooks run.
+    pub fn plugin_names(&self) -> Vec<&str> {
+        self.plugins.names()
+    }
+
+    /// Let plugins adjust the value of freshly generated orders.
+    fn run_order_plugins(&mut self, ids: std::ops::Range<usize>) {
+        let mut effects = PluginEffects::default();
+        for (airport, _) in self.map.airports.iter_mut() {
+            for order in airport.orders.iter_mut().filter(|o| ids.contains(&o.id)) {
+                let (value, requested) = self.plugins.on_order_generated(order);
+                order.value = value;
+                effects.extend(requested);
+            }
+        }
+        self.apply_plugin_effects(effects);
+    }
+
+    /// Run scenario scripts for `hook`, compiling them first after a load.
+    fn run_scripts(&mut self, hook: ScriptHook) {
+        if self.scripts.is_empty() {
+            return;
+        }
+        let mut effects = PluginEffects::default();
+        if !self.script_host.is_loaded() {
+            effects.failures = self.script_host.load_all(&self.scripts);
+        }
+        let state = ScriptState {
+            time: self.time,
+            day: self.time / 24,
+            cash: self.player.cash,
+            fleet_size: self.player.fleet_size,
+            deliveries: self.player.orders_delivered,
+        };
+        effects.extend(self.script_host.run(hook, &state, &mut self.script_flags));
+        self.apply_plugin_effects(effects);
+    }
+
+    /// Carry out what plugins or scripts asked for during a hook.
+    fn apply_plugin_effects(&mut self, effects: PluginEffects) {
+        if effects.is_empty() {
+            return;
+        }
+        self.player.cash += effects.cash;
+        if effects.cash >= 0.0 {
+            self.daily_income += effects.cash;
+        } else {
+            self.daily_expenses -= effects.cash;
+        }
+        for (airport, factor, duration) in effects.world_events {
+            if airport.is_some_and(|id| !self.map.airports.contains(id)) {
+                continue;
+            }
+            self.schedule(
+                self.time,
+                Event::WorldEvent {
+                    airport,
+                    factor,
+                    duration,
+                },
+            );
+        }
+        for message in effects.messages.into_iter().chain(effects.failures) {
+            println!("{}", message);
+            self.log.push(message);
+        }
+    }
+
+    /// Send `event` to every interested subscriber, dropping those that hung up.
+    fn notify(&mut self, event: GameEvent) {
+        self.tracker.record_event(event.clone());
+        if let Some(history) = &mut self.history {
+            if let Err(e) = history.record(&event) {
+                // stop writing rather than fail the simulation; the game itself is unharmed
+                let message = e.to_string();
+                println!("{}", message);
+                self.log.push(message);
+                self.history = None;
+            }
+        }
+        self.subscribers
+            .retain(|(filter, tx)| !filter.matches(&event) || tx.send(event.clone()).is_ok());
+    }
+
+    fn process_next_event(&mut self) -> bool {
         if let Some(scheduled) = self.events.pop() {
             // advance time
             self.time = scheduled.time;
+            #[cfg(feature = "telemetry")]
+            tracing::trace!(time = scheduled.time, event = ?scheduled.event, "event");
 
             match scheduled.event {
                 // Restock every 14 days
                 Event::Restock => {
                     if self.regenerate_orders {
-                        self.map.restock_airports();
+                        let held = self.take_reserved_orders();
+                        let airport_ids: Vec<usize> =
+                            self.map.airports.iter().map(|(a, _)| a.id).collect();
+                        let memory = self.map.demand_params.cargo.market.memory;
+                        self.map.lane_market.end_cycle(airport_ids, memory);
+                        let new_orders = self.map.restock_airports();
+                        for (airport_id, order) in held {
+                            self.map
+                                .airport_mut(airport_id)
+                                .expect(ON_MAP)
+                                .orders
+                                .push(order);
+                        }
+                        if !self.plugins.is_empty() {
+                            self.run_order_plugins(new_orders);
+                        }
+                        self.run_scripts(ScriptHook::Restock);
+                        self.prune_split_shipments();
+                        self.refresh_contracts();
+                        self.refresh_used_market();
                         self.schedule(self.time + self.restock_cycle, Event::Restock);
                     }
                 }
 
                 // Finished loading, therefore we need to update the status
                 Event::LoadingEvent { plane } => {
-                    self.airplanes[plane].status = AirplaneStatus::Parked;
+                    if let Some(idx) = self.plane_index(plane) {
+                        self.airplanes[idx].finish_tasks(self.time);
+                    }
                 }
 
                 // Update the progress of the flight
                 Event::FlightProgress { plane } => {
+                    let Some(idx) = self.plane_index(plane) else {
+                        return true;
+                    };
                     // buffer for events
                     let mut to_schedule: Vec<(GameTime, Event)> = Vec::new();
+                    let mut arrived_at = None;
 
                     {
-                        let airplane = &mut self.airplanes[plane];
+                        let airplane = &mut self.airplanes[idx];
 
                         if let AirplaneStatus::InTransit {
                             hours_remaining,
@@ -1340,14 +2494,28 @@ impl Game {
                             total_hours,
                         } = airplane.status
                         {
-                            let dest_coord = self.map.airports[destination].1;
+                            airplane.airframe_hours += 1;
+                            airplane.check_hours.add_flight_hour();
+                            let landing = hours_remaining <= 1;
+                            let model = format!("{:?}", airplane.model);
+                            for pilot in self
+                                .player
+                                .pilots
+                                .iter_mut()
+                                .filter(|p| p.plane == Some(plane))
+                            {
+                                pilot.duty_hours += 1;
+                                pilot.log_flight_hour(&model);
+                                if landing {
+                                    pilot.airport_id = destination;
+                                    pilot.off_duty_since = self.time;
+                                }
+                            }
+                            let dest_coord = self.map.coord(destination).expect(ON_MAP);
                             let hours_elapsed = total_hours - hours_remaining + 1;
                             let fraction = (hours_elapsed as f32) / (total_hours as f32);
 
-                            airplane.location = Coordinate {
-                                x: origin.x + (dest_coord.x - origin.x) * fraction,
-                                y: origin.y + (dest_coord.y - origin.y) * fraction,
-                            };
+                            airplane.location = origin.interpolate(&dest_coord, fraction);
 
                             if hours_remaining > 1 {
                                 airplane.status = AirplaneStatus::InTransit {
@@ -1361,17 +2529,81 @@ impl Game {
                                 to_schedule.push((self.time + 1, Event::FlightProgress { plane }));
                             } else {
                                 // landing
-                                let (airport, _) = &self.map.airports[destination];
-                                let landing_fee = airport.landing_fee(airplane);
+                                let airport = self.map.airport(destination).expect(ON_MAP);
+                                let landing_fee = airport.landing_fee(airplane)
+                                    * self
+                                        .tax_settings
+                                        .fee_multiplier(self.map.country_of(airport.id))
+                                    * self.loyalty.fee_factor(
+                                        destination,
+                                        self.time,
+                                        &self.loyalty_settings,
+                                    );
+                                self.loyalty.record(
+                                    destination,
+                                    self.time,
+                                    self.loyalty_settings.window_hours,
+                                );
                                 self.player.cash -= landing_fee;
                                 self.daily_expenses += landing_fee;
+                                self.daily_fees += landing_fee;
 
                                 self.arrival_times.insert(plane, self.time);
-                                airplane.location = self.map.airports[destination].1;
+                                airplane.location = dest_coord;
+                                arrived_at = Some(destination);
+
+                                // fragile cargo suffers from hard landings and worn-out planes
+                                let mut damage = 0.0;
+                                if airport.runway_length
+                                    < airplane.specs.min_runway_length * HARD_LANDING_RUNWAY_MARGIN
+                                {
+                                    damage += HARD_LANDING_DAMAGE;
+                                }
+                                if airplane.needs_maintenance {
+                                    damage += POOR_MAINTENANCE_DAMAGE;
+                                }
+                                if damage > 0.0 {
+                                    for order in airplane.manifest.iter_mut() {
+                                        order.apply_damage(damage);
+                                    }
+                                }
+                                airplane.cruise = CruiseRegime::Normal;
+                                // scheduled-route passengers only fly a single leg
+                                airplane.scheduled_passengers = 0;
+
+                                if let Some(check) =
+                                    self.maintenance_settings.overdue(&airplane.check_hours)
+                                {
+                                    let message = format!(
+                                        "Plane {} is grounded at {} until its overdue {} check is done.",
+                                        plane, airport.name, check
+                                    );
+                                    println!("{}", message);
+                                    self.log.push(message);
+                                }
+
+                                // separate RNG so landings don't shift the world event sequence
+                                let mut landing_rng = StdRng::seed_from_u64(
+                                    self.seed
+                                        .wrapping_add(self.time.wrapping_mul(0xC2B2_AE35))
+                                        .wrapping_add(plane as u64),
+                                );
+                                let failure_chance = airplane.landing_failure_chance(
+                                    self.maintenance_settings.landing_failure_chance,
+                                );
 
                                 if airplane.needs_maintenance {
                                     airplane.status = AirplaneStatus::Broken;
                                     to_schedule.push((self.time + 8, Event::Maintenance { plane }));
+                                } else if landing_rng.gen_bool(failure_chance as f64) {
+                                    airplane.status = AirplaneStatus::Broken;
+                                    airplane.awaiting_repair = true;
+                                    let message = format!(
+                                        "Plane {} broke down on landing at {}; it needs REPAIR PLANE {} before it can fly again.",
+                                        plane, airport.name, plane
+                                    );
+                                    println!("{}", message);
+                                    self.log.push(message);
                                 } else {
                                     airplane.status = AirplaneStatus::Parked;
                                 }
@@ -1379,30 +2611,103 @@ impl Game {
                         }
                     }
 
+                    if let Some(airport) = arrived_at {
+                        self.settle_charters(plane, airport);
+                        self.notify(GameEvent::Arrived {
+                            time: self.time,
+                            plane,
+                            airport,
+                        });
+                        self.run_scripts(ScriptHook::Arrival { plane, airport });
+                    }
+
+                    if matches!(self.airplanes[idx].status, AirplaneStatus::InTransit { .. }) {
+                        self.check_fuel_reserve(idx);
+                    }
+
                     // Schedule new events
                     for (when, ev) in to_schedule {
                         self.schedule(when, ev);
                     }
                 }
 
-                Event::RefuelComplete { plane } => {
-                    self.airplanes[plane].status = AirplaneStatus::Parked;
+                Event::RefuelComplete { plane } | Event::ChargeComplete { plane } => {
+                    if let Some(idx) = self.plane_index(plane) {
+                        self.airplanes[idx].finish_tasks(self.time);
+                    }
                 }
 
                 Event::DailyStats => {
+                    // fixed costs are due whether the fleet flew today or not
+                    let planes = self.airplanes.len();
+                    let salaries =
+                        self.pilot_settings.salary_per_day * self.player.pilots.len() as f32;
+                    let crew_wages =
+                        self.handling_settings.crew_wage_per_day * self.total_ground_crew() as f32;
+                    let hangarage = self.fixed_costs.hangarage(planes);
+                    let overhead = self.fixed_costs.overhead(planes);
+                    let fixed = salaries + crew_wages + hangarage + overhead;
+                    self.player.cash -= fixed;
+                    self.daily_expenses += fixed;
+
+                    let co2_tons = self.emissions.end_day();
+                    let carbon_tax = co2_tons * self.tax_settings.carbon_tax_per_ton;
+                    self.player.cash -= carbon_tax;
+                    self.daily_expenses += carbon_tax;
+
+                    self.charge_warehouse_fees();
+                    self.collect_concession_income();
+
+                    let interest =
+                        (-self.player.cash).max(0.0) * self.overdraft_settings.daily_interest_rate;
+                    self.player.cash -= interest;
+                    self.daily_expenses += interest;
+
+                    self.refresh_charters();
+                    self.refresh_lane_offers();
+                    self.open_auction();
+                    self.spawn_rare_cargo();
+                    self.grow_world();
+                    self.refresh_passenger_demand();
+
                     let day = self.time / 24;
-                    self.stats.push(DailyStats {
+                    let effects = self.plugins.on_day_end(day, self.player.cash);
+                    self.apply_plugin_effects(effects);
+                    self.run_scripts(ScriptHook::DayEnd);
+                    let taxes = self.assess_profit_tax(day);
+                    let report = DailyStats {
                         day,
                         income: self.daily_income,
                         expenses: self.daily_expenses,
                         net_cash: self.player.cash,
                         fleet_size: self.player.fleet_size,
                         total_deliveries: self.player.orders_delivered,
-                    });
+                        fuel: self.daily_fuel,
+                        fees: self.daily_fees,
+                        investing: self.daily_investing,
+                        interest,
+                        salaries,
+                        co2_tons,
+                        total_co2_tons: self.emissions.total_tons,
+                        carbon_tax,
+                        crew_wages,
+                        hangarage,
+                        overhead,
+                        taxes,
+                        company_value: self.company_value(),
+                    };
+                    self.stats.push(report.clone());
+                    self.notify(GameEvent::DailyReport(report));
+                    if self.time > 0 && Date::from_time(self.time).day == 1 {
+                        self.close_month();
+                    }
 
                     //reset
                     self.daily_income = 0.0;
                     self.daily_expenses = 0.0;
+                    self.daily_fuel = 0.0;
+                    self.daily_fees = 0.0;
+                    self.daily_investing = 0.0;
 
                     self.schedule(self.time + REPORT_INTERVAL, Event::DailyStats);
                 }
@@ -1422,6 +2727,13 @@ impl Game {
                     self.schedule(self.time + self.fuel_interval, Event::DynamicPricing);
                 }
 
+                Event::FuelResupply => {
+                    for (airport, _) in self.map.airports.iter_mut() {
+                        airport.resupply_fuel();
+                    }
+                    self.schedule(self.time + self.restock_cycle, Event::FuelResupply);
+                }
+
                 Event::WorldEvent {
                     airport,
                     factor,
@@ -1429,17 +2741,18 @@ impl Game {
                 } => {
                     match airport {
                         Some(airport_id) => {
-                            self.map.airports[airport_id].0.fuel_price *= factor;
-                            let name = &self.map.airports[airport_id].0.name;
-
-                            let pct = (factor - 1.0) * 100.0;
-                            println!(
-                                "Fuel price spike of +{:.0}% at {} for {}h!",
-                                pct, name, duration
-                            );
+                            if let Some(airport) = self.map.airport_mut(airport_id) {
+                                airport.fuel_price *= factor;
+
+                                let pct = (factor - 1.0) * 100.0;
+                                println!(
+                                    "Fuel price spike of +{:.0}% at {} for {}h!",
+                                    pct, airport.name, duration
+                                );
+                            }
                         }
                         None => {
-                            for (airport, _) in &mut self.map.airports {
+                            for (airport, _) in self.map.airports.iter_mut() {
                                 airport.fuel_price *= factor
                             }
 
@@ -1456,14 +2769,18 @@ impl Game {
                 Event::WorldEventEnd { airport, factor } => {
                     match airport {
                         Some(airport_id) => {
-                            self.map.airports[airport_id].0.fuel_price /= factor;
-
-                            let name = &self.map.airports[airport_id].0.name;
-                            let pct = (factor - 1.0) * 100.0;
-                            println!("Fuel price spike of +{:.0}% at {} has ended.", pct, name);
+                            if let Some(airport) = self.map.airport_mut(airport_id) {
+                                airport.fuel_price /= factor;
+
+                                let pct = (factor - 1.0) * 100.0;
+                                println!(
+                                    "Fuel price spike of +{:.0}% at {} has ended.",
+                                    pct, airport.name
+                                );
+                            }
                         }
                         None => {
-                            for (airport, _) in &mut self.map.airports {
+                            for (airport, _) in self.map.airports.iter_mut() {
                                 airport.fuel_price /= factor
                             }
                             let pct = (factor - 1.0) * 100.0;
@@ -1479,7 +2796,7 @@ impl Game {
                     // Collect vec of planes that are broken:
                     let mut just_broke = Vec::new();
 
-                    for (idx, airplane) in self.airplanes.iter_mut().enumerate() {
+                    for airplane in self.airplanes.iter_mut() {
                         if airplane.status != AirplaneStatus::Maintenance {
                             airplane.add_hours_since_maintenance();
                             let p_fail = airplane.risk_of_failure();
@@ -1493,15 +2810,15 @@ impl Game {
                                         | AirplaneStatus::Refueling
                                 ) {
                                     airplane.status = AirplaneStatus::Broken;
-                                    just_broke.push(idx);
+                                    just_broke.push(airplane.id);
                                 }
                             }
                         }
                     }
 
                     // Schedule broken events
-                    for idx in just_broke {
-                        self.schedule(self.time + 8, Event::Maintenance { plane: idx });
+                    for plane in just_broke {
+                        self.schedule(self.time + 8, Event::Maintenance { plane });
                     }
 
                     // next check
@@ -1509,12 +2826,44 @@ impl Game {
                 }
 
                 Event::Maintenance { plane } => {
-                    let airplane = &mut self.airplanes[plane];
+                    let Some(idx) = self.plane_index(plane) else {
+                        return true;
+                    };
+                    let airplane = &mut self.airplanes[idx];
+                    airplane.status = AirplaneStatus::Parked;
+                    airplane.hours_since_maintenance = 0;
+                    airplane.needs_maintenance = false;
+                }
+
+                Event::RepairComplete { plane } => {
+                    let Some(idx) = self.plane_index(plane) else {
+                        return true;
+                    };
+                    let airplane = &mut self.airplanes[idx];
+                    airplane.status = AirplaneStatus::Parked;
+                    airplane.awaiting_repair = false;
+                    airplane.needs_maintenance = false;
+                }
+
+                Event::CheckComplete { plane, check } => {
+                    let Some(idx) = self.plane_index(plane) else {
+                        return true;
+                    };
+                    let airplane = &mut self.airplanes[idx];
                     airplane.status = AirplaneStatus::Parked;
+                    airplane.check_hours.complete(check);
                     airplane.hours_since_maintenance = 0;
                     airplane.needs_maintenance = false;
                 }
 
+                Event::LaneLoad { deal } => self.issue_lane_load(deal),
+
+                Event::AuctionClose { auction } => self.resolve_auction(auction),
+
+                Event::ConstructionComplete { construction } => {
+                    self.complete_construction(construction)
+                }
+
                 _ => {
                     println!("Not implemented!")
                 }
@@ -1555,22 +2904,65 @@ impl Game {
     /// let mut game = rusty_runways_core::Game::new(1, Some(3), 0.0);
     /// game.advance(6);
     /// ```
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(level = "debug", skip(self), fields(from = self.time))
+    )]
     pub fn advance(&mut self, hours: GameTime) {
         let target = self.time + hours;
+        #[cfg(feature = "telemetry")]
+        let mut processed: u64 = 0;
 
         // Keep processing events in time order until we're past `target`
         while let Some(ev) = self.events.peek() {
             if ev.time <= target {
                 self.tick_event();
+                #[cfg(feature = "telemetry")]
+                {
+                    processed += 1;
+                }
             } else {
                 break;
             }
         }
 
+        #[cfg(feature = "telemetry")]
+        tracing::debug!(
+            events_processed = processed,
+            events_pending = self.events.len(),
+            "advanced"
+        );
+
         // Finally bump the clock
         self.time = target;
     }
 
+    #[cfg(feature = "ui_prints")]
+    fn country_label(&self, airport_id: usize) -> String {
+        self.map
+            .country_of(airport_id)
+            .map(|c| format!(" [{}]", c))
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "ui_prints")]
+    fn facilities_label(&self, airport: &Airport) -> String {
+        let mut label = String::new();
+        if airport.has_hangar {
+            label.push_str(" | Hangar");
+        }
+        if airport.has_charger {
+            label.push_str(" | Charger");
+        }
+        if airport.player_built {
+            label.push_str(" | Yours");
+        }
+        if self.concession(airport.id).is_some() {
+            label.push_str(" | Fuel concession");
+        }
+        label
+    }
+
     /// Display a summary of all airports in the map, including their orders.
     /// If with_orders is true, show the orders alongside.
     #[cfg(feature = "ui_prints")]
@@ -1578,15 +2970,26 @@ impl Game {
         println!("Airports ({} total):", self.map.num_airports);
         for (airport, coord) in &self.map.airports {
             println!(
-                "ID: {} | {} at ({:.2}, {:.2}) | Runway: {:.0}m | Fuel: ${:.2}/L | Parking: ${:.2}/hr | Landing Fee: ${:.2}/ton",
+                "ID: {} | {}{}{} at ({:.2}, {:.2}) | {} | Runway: {:.0}m | Population: {} | Fuel: ${:.2}/L ({}) | Parking: ${:.2}/hr ({} stands) | Landing Fee: ${:.2}/ton{}",
                 airport.id,
                 airport.name,
+                airport
+                    .code
+                    .as_ref()
+                    .map(|code| format!(" ({})", code))
+                    .unwrap_or_default(),
+                self.country_label(airport.id),
                 coord.x,
                 coord.y,
+                airport.class(),
                 airport.runway_length,
-                airport.fuel_price,
+                airport.population,
+                self.player_fuel_price(airport),
+                fuel_stock_label(airport),
                 airport.parking_fee,
+                airport.parking_stands(),
                 airport.landing_fee,
+                self.facilities_label(airport),
             );
             if with_orders {
                 if airport.orders.is_empty() {
@@ -1595,18 +2998,40 @@ impl Game {
                     println!("  Orders:");
                     for order in &airport.orders {
                         let payload_info = match &order.payload {
-                            OrderPayload::Cargo { cargo_type, weight } => {
-                                format!("{:?} | weight: {:.1}kg", cargo_type, weight)
-                            }
+                            OrderPayload::Cargo { cargo_type, weight } => format!(
+                                "{:?}{} | weight: {:.1}kg",
+                                cargo_type,
+                                if cargo_type.is_fragile() {
+                                    " (fragile)"
+                                } else {
+                                    ""
+                                },
+                                weight
+                            ),
                             OrderPayload::Passengers { count } => {
                                 format!("Passengers | count: {}", count)
                             }
                         };
+                        let payload_info = if order.is_pallet() {
+                            pallet_label(order)
+                        } else {
+                            payload_info
+                        };
+                        let payload_info = match order.rare {
+                            Some(kind) => format!(
+                                "RARE {}: {} (needs {})",
+                                kind,
+                                payload_info,
+                                kind.handling()
+                            ),
+                            None => payload_info,
+                        };
                         println!(
-                            "    [{}] {} -> {} | value: ${:.2} | deadline: {} | destination: {}",
+                            "    [{}] {} -> {} | tier: {} | value: ${:.2} | deadline: {} | destination: {}",
                             order.id,
                             payload_info,
-                            self.map.airports[order.destination_id].0.name,
+                            self.airport_name(order.destination_id),
+                            order.tier,
                             order.value,
                             order.deadline,
                             order.destination_id
@@ -1621,22 +3046,67 @@ impl Game {
     /// If with_orders is true, show the orders alongside.
     #[cfg(feature = "ui_prints")]
     pub fn list_airport(&self, airport_id: usize, with_orders: bool) -> Result<(), GameError> {
-        if airport_id > (self.map.num_airports - 1) {
-            return Err(GameError::AirportIdInvalid { id: airport_id });
-        }
-
-        let (airport, coord) = &self.map.airports[airport_id];
+        let (airport, coord) = self
+            .map
+            .airports
+            .get(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
         println!(
-            "ID: {} | {} at ({:.2}, {:.2}) | Runway: {:.0}m | Fuel: ${:.2}/L | Parking: ${:.2}/hr | Landing Fee: ${:.2}/ton",
+            "ID: {} | {}{} at ({:.2}, {:.2}) | {} | Runway: {:.0}m | Population: {} | Fuel: ${:.2}/L ({}) | Parking: ${:.2}/hr ({} stands) | Landing Fee: ${:.2}/ton{}",
             airport.id,
             airport.name,
+            self.country_label(airport.id),
             coord.x,
             coord.y,
+            airport.class(),
             airport.runway_length,
-            airport.fuel_price,
+            airport.population,
+            self.player_fuel_price(airport),
+            fuel_stock_label(airport),
             airport.parking_fee,
+            airport.parking_stands(),
             airport.landing_fee,
+            self.facilities_label(airport),
+        );
+        let class = airport.class();
+        println!(
+            "  Sells: {} | Hangar checks up to: {}",
+            class
+                .fuel_grades()
+                .iter()
+                .map(|grade| grade.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            class.max_check()
         );
+        println!("  Local time: {}", self.local_time(airport_id)?);
+        if airport.ground_crew > 0 {
+            println!(
+                "  Ground crew: {} (${:.0}/day)",
+                airport.ground_crew,
+                self.handling_settings.crew_wage_per_day * airport.ground_crew as f32
+            );
+        }
+        let operations = self.airport_operations(airport_id);
+        match self.loyalty_tier(airport_id) {
+            Some(tier) => println!(
+                "  Loyalty: {} ({} operations, {:.0}% off fees)",
+                tier.name,
+                operations,
+                tier.discount * 100.0
+            ),
+            None if operations > 0 => println!("  Loyalty: none ({} operations)", operations),
+            None => {}
+        }
+        let backlog = self.map.lane_market.backlog(airport_id);
+        if backlog > 0 {
+            let market = &self.map.demand_params.cargo.market;
+            println!(
+                "  Backlog: {} restocks without a pickup (+{:.0}% on new cargo)",
+                backlog,
+                (backlog as f32 * market.backlog_premium).min(market.max_backlog_premium) * 100.0
+            );
+        }
         if with_orders {
             if airport.orders.is_empty() {
                 println!("  No pending orders.");
@@ -1644,18 +3114,40 @@ impl Game {
                 println!("  Orders:");
                 for order in &airport.orders {
                     let payload_info = match &order.payload {
-                        OrderPayload::Cargo { cargo_type, weight } => {
-                            format!("{:?} | weight: {:.1}kg", cargo_type, weight)
-                        }
+                        OrderPayload::Cargo { cargo_type, weight } => format!(
+                            "{:?}{} | weight: {:.1}kg",
+                            cargo_type,
+                            if cargo_type.is_fragile() {
+                                " (fragile)"
+                            } else {
+                                ""
+                            },
+                            weight
+                        ),
                         OrderPayload::Passengers { count } => {
                             format!("Passengers | count: {}", count)
                         }
                     };
+                    let payload_info = if order.is_pallet() {
+                        pallet_label(order)
+                    } else {
+                        payload_info
+                    };
+                    let payload_info = match order.rare {
+                        Some(kind) => format!(
+                            "RARE {}: {} (needs {})",
+                            kind,
+                            payload_info,
+                            kind.handling()
+                        ),
+                        None => payload_info,
+                    };
                     println!(
-                        "    [{}] {} -> {} | value: ${:.2} | deadline: {} | destination: {}",
+                        "    [{}] {} -> {} | tier: {} | value: ${:.2} | deadline: {} | destination: {}",
                         order.id,
                         payload_info,
-                        self.map.airports[order.destination_id].0.name,
+                        self.airport_name(order.destination_id),
+                        order.tier,
                         order.value,
                         self.days_and_hours(order.deadline),
                         order.destination_id
@@ -1681,11 +3173,16 @@ impl Game {
         Ok(airport.name.clone())
     }
 
-    /// Locate a plane and the index of the airport where it is currently parked.
+    /// Position of the plane with `plane_id` in the fleet, `None` once it was sold.
+    fn plane_index(&self, plane_id: usize) -> Option<usize> {
+        self.airplanes.iter().position(|p| p.id == plane_id)
+    }
+
+    /// Locate a plane and the id of the airport where it is currently parked.
     ///
     /// Returns [`GameError::PlaneIdInvalid`] if no plane with `plane_id` exists or
     /// [`GameError::PlaneNotAtAirport`] if the plane is not located at any airport.
-    fn plane_and_airport_idx(&self, plane_id: usize) -> Result<(usize, usize), GameError> {
+    fn plane_and_airport(&self, plane_id: usize) -> Result<(usize, AirportId), GameError> {
         let plane_index = self
             .airplanes
             .iter()
@@ -1693,14 +3190,65 @@ impl Game {
             .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
 
         let location = self.airplanes[plane_index].location;
-        let airport_idx = self
+        let airport_id = self
             .map
             .airports
             .iter()
-            .position(|(_, coord)| *coord == location)
+            .find(|(_, coord)| *coord == location)
+            .map(|(airport, _)| airport.id)
             .ok_or(GameError::PlaneNotAtAirport { plane_id })?;
 
-        Ok((plane_index, airport_idx))
+        Ok((plane_index, airport_id))
+    }
+
+    /// Hours an airport's clock is ahead of UTC.
+    ///
+    /// Returns
+    /// - `Ok(i32)`: The configured offset, or the time zone of the airport's location.
+    /// - `Err(GameError::AirportIdInvalid)`: If the airport doesn't exist.
+    pub fn utc_offset(&self, airport_id: usize) -> Result<i32, GameError> {
+        let (airport, coord) = self
+            .map
+            .airports
+            .get(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        Ok(airport
+            .utc_offset
+            .unwrap_or_else(|| default_utc_offset(coord)))
+    }
+
+    /// Wall-clock time at an airport right now.
+    ///
+    /// Returns
+    /// - `Ok(LocalTime)`: Local day and hour at the airport.
+    /// - `Err(GameError::AirportIdInvalid)`: If the airport doesn't exist.
+    pub fn local_time(&self, airport_id: usize) -> Result<LocalTime, GameError> {
+        Ok(LocalTime::at(self.time, self.utc_offset(airport_id)?))
+    }
+
+    /// Landings and departures the player made at `airport_id` within the loyalty window.
+    pub fn airport_operations(&self, airport_id: AirportId) -> u32 {
+        self.loyalty
+            .count(airport_id, self.time, self.loyalty_settings.window_hours)
+    }
+
+    /// Loyalty tier the player holds at `airport_id`, if any.
+    pub fn loyalty_tier(&self, airport_id: AirportId) -> Option<&LoyaltyTier> {
+        self.loyalty
+            .tier(airport_id, self.time, &self.loyalty_settings)
+    }
+
+    /// Number of planes on the ground at `airport_id`, whatever they are doing there.
+    fn planes_on_ground(&self, airport_id: AirportId) -> usize {
+        let Some(coord) = self.map.coord(airport_id) else {
+            return 0;
+        };
+        self.airplanes
+            .iter()
+            .filter(|p| {
+                p.location == coord && !matches!(p.status, AirplaneStatus::InTransit { .. })
+            })
+            .count()
     }
 
     /// Display a summary of all airplanes in the game.
@@ -1714,16 +3262,16 @@ impl Game {
                 ..
             } = plane.status
             {
-                let dest_name = &self.map.airports[destination].0.name;
+                let dest_name = self.airport_name(destination);
                 println!(
-                    "ID: {} | {:?} en-route to airport {} | Location: ({:.2}, {:.2}) | Fuel: {:.2}/{:.2}L | Payload: {:.2}/{:.2}kg | Status: InTransit - arrival in {}",
+                    "ID: {} | {} | {:?} en-route to airport {} | Location: ({:.2}, {:.2}) | {} | Payload: {:.2}/{:.2}kg | Status: InTransit - arrival in {}",
                     plane.id,
+                    plane.name,
                     plane.model,
                     dest_name,
                     plane.location.x,
                     plane.location.y,
-                    plane.current_fuel,
-                    plane.specs.fuel_capacity,
+                    energy_label(plane),
                     plane.current_payload,
                     plane.specs.payload_capacity,
                     self.days_and_hours(hours_remaining)
@@ -1732,17 +3280,18 @@ impl Game {
                 let loc = &plane.location;
                 let airport_name = self.find_associated_airport(loc)?;
                 println!(
-                    "ID: {} | {:?} at airport {} ({:.2}, {:.2}) | Fuel: {:.2}/{:.2}L | Payload: {:.2}/{:.2}kg | Status: {:?}",
+                    "ID: {} | {} | {:?} at airport {} ({:.2}, {:.2}) | {} | Payload: {:.2}/{:.2}kg | Status: {:?}{}",
                     plane.id,
+                    plane.name,
                     plane.model,
                     airport_name,
                     loc.x,
                     loc.y,
-                    plane.current_fuel,
-                    plane.specs.fuel_capacity,
+                    energy_label(plane),
                     plane.current_payload,
                     plane.specs.payload_capacity,
                     plane.status,
+                    if plane.on_hold { " (on hold)" } else { "" },
                 );
             }
         }
@@ -1753,11 +3302,11 @@ impl Game {
     /// Display a summary of a single airplane in the game.
     #[cfg(feature = "ui_prints")]
     pub fn list_airplane(&self, plane_id: usize) -> Result<(), GameError> {
-        if plane_id > (self.airplanes.len() - 1) {
-            return Err(GameError::PlaneIdInvalid { id: plane_id });
-        }
-
-        let plane = &self.airplanes[plane_id];
+        let plane = self
+            .airplanes
+            .iter()
+            .find(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
 
         if let AirplaneStatus::InTransit {
             hours_remaining,
@@ -1765,16 +3314,16 @@ impl Game {
             ..
         } = plane.status
         {
-            let dest_name = &self.map.airports[destination].0.name;
+            let dest_name = self.airport_name(destination);
             println!(
-                "ID: {} | {:?} en-route to airport {} | Location: ({:.2}, {:.2}) | Fuel: {:.2}/{:.2}L | Payload: {:.2}/{:.2}kg | Status: InTransit - arrival in {}",
+                "ID: {} | {} | {:?} en-route to airport {} | Location: ({:.2}, {:.2}) | {} | Payload: {:.2}/{:.2}kg | Status: InTransit - arrival in {}",
                 plane.id,
+                plane.name,
                 plane.model,
                 dest_name,
                 plane.location.x,
                 plane.location.y,
-                plane.current_fuel,
-                plane.specs.fuel_capacity,
+                energy_label(plane),
                 plane.current_payload,
                 plane.specs.payload_capacity,
                 self.days_and_hours(hours_remaining)
@@ -1785,36 +3334,66 @@ impl Game {
             let loc = &plane.location;
             let airport_name = self.find_associated_airport(loc)?;
             println!(
-                "ID: {} | {:?} at airport {} ({:.2}, {:.2}) | Fuel: {:.2}/{:.2}L | Cargo: {:.2}/{:.2}kg | Pax: {}/{} | Status: {:?}",
+                "ID: {} | {} | {:?} at airport {} ({:.2}, {:.2}) | {} | Cargo: {:.2}/{:.2}kg{} | Pax: {}/{} | Takeoff weight: {:.0}/{:.0}kg | Airframe: {}h | Resale: ${:.0} | Status: {:?}{}",
                 plane.id,
+                plane.name,
                 plane.model,
                 airport_name,
                 loc.x,
                 loc.y,
-                plane.current_fuel,
-                plane.specs.fuel_capacity,
+                energy_label(plane),
                 plane.current_payload,
                 plane.specs.payload_capacity,
-                plane.current_passengers,
+                slots_label(plane),
+                plane.seated_passengers(),
                 plane.specs.passenger_capacity,
+                plane.takeoff_weight(),
+                plane.specs.mtow,
+                plane.airframe_hours,
+                plane.resale_value(self.time),
                 plane.status,
+                if plane.on_hold { " (on hold)" } else { "" },
             );
+            for task in &plane.turnaround {
+                println!(
+                    "  {:?}: done in {}",
+                    task.task,
+                    self.days_and_hours(task.until.saturating_sub(self.time))
+                );
+            }
             if !plane.manifest.is_empty() {
                 println!("  Manifest:");
                 for order in plane.manifest.clone() {
-                    let payload_info = match &order.payload {
-                        OrderPayload::Cargo { cargo_type, weight } => {
-                            format!("{:?} | weight: {:.1}kg", cargo_type, weight)
-                        }
+                    let mut payload_info = match &order.payload {
+                        OrderPayload::Cargo { cargo_type, weight } => format!(
+                            "{:?}{} | weight: {:.1}kg",
+                            cargo_type,
+                            if cargo_type.is_fragile() {
+                                " (fragile)"
+                            } else {
+                                ""
+                            },
+                            weight
+                        ),
                         OrderPayload::Passengers { count } => {
                             format!("Passengers | count: {}", count)
                         }
                     };
+                    if order.is_pallet() {
+                        payload_info = pallet_label(&order);
+                    }
+                    if order.damage > 0.0 {
+                        payload_info.push_str(&format!(" | damage: {:.0}%", order.damage * 100.0));
+                    }
+                    if let Some(kind) = order.rare {
+                        payload_info = format!("RARE {}: {}", kind, payload_info);
+                    }
                     println!(
-                        "    [{}] {} -> {} | value: ${:.2} | deadline: {} | destination: {}",
+                        "    [{}] {} -> {} | tier: {} | value: ${:.2} | deadline: {} | destination: {}",
                         order.id,
                         payload_info,
-                        self.map.airports[order.destination_id].0.name,
+                        self.airport_name(order.destination_id),
+                        order.tier,
                         order.value,
                         order.deadline,
                         order.destination_id
@@ -1828,11 +3407,11 @@ impl Game {
 
     #[cfg(feature = "ui_prints")]
     pub fn show_distances(&self, plane_id: usize) -> Result<(), GameError> {
-        if plane_id > (self.airplanes.len() - 1) {
-            return Err(GameError::PlaneIdInvalid { id: plane_id });
-        }
-
-        let plane = &self.airplanes[plane_id];
+        let plane = self
+            .airplanes
+            .iter()
+            .find(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
 
         // If plane is in transit, dont't calc
         if let AirplaneStatus::InTransit { .. } = plane.status {
@@ -1845,9 +3424,10 @@ impl Game {
                 let can_land = plane.can_fly_to(airport, coordinate).is_ok();
 
                 println!(
-                    "ID: {} | {} at ({:.2}, {:.2}) | Runway: {:.0}m | Distance to: {:.2}km | Can land: {:?}",
+                    "ID: {} | {}{} at ({:.2}, {:.2}) | Runway: {:.0}m | Distance to: {:.2}km | Can land: {:?}",
                     airport.id,
                     airport.name,
+                    self.country_label(airport.id),
                     coordinate.x,
                     coordinate.y,
                     airport.runway_length,
@@ -1862,13 +3442,15 @@ impl Game {
     /// Buy an airplane is possible
     pub fn buy_plane(&mut self, model: &String, airport_id: usize) -> Result<(), GameError> {
         // Get copy of home coordinate
-        let home_coord = {
-            let (_airport, coord) = &self.map.airports[airport_id];
-            *coord
-        };
+        let home_coord = self
+            .map
+            .airports
+            .get(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?
+            .1;
 
         // Borrow airport as mut
-        let airport_ref = &mut self.map.airports[airport_id].0;
+        let airport_ref = self.map.airport_mut(airport_id).expect(ON_MAP);
 
         // Try catalog (case-insensitive)
         if let Some((name, specs)) = self
@@ -1891,6 +3473,8 @@ impl Game {
                         (plane.id, plane.specs.purchase_price)
                     };
                     self.daily_expenses += buying_price;
+                    self.daily_investing -= buying_price;
+                    self.prepare_new_plane();
 
                     self.airplanes = self.player.fleet.clone();
                     self.player.fleet = self.airplanes.clone();
@@ -1921,6 +3505,8 @@ impl Game {
                     (plane.id, plane.specs.purchase_price)
                 };
                 self.daily_expenses += buying_price;
+                self.daily_investing -= buying_price;
+                self.prepare_new_plane();
 
                 self.airplanes = self.player.fleet.clone();
                 self.player.fleet = self.airplanes.clone();
@@ -1932,7 +3518,185 @@ impl Game {
         }
     }
 
-    /// Sell an airplane currently owned by the player.
+    /// Buy a plane from the second-hand market.
+    ///
+    /// The plane joins the fleet at the listing's airport with the listed airframe
+    /// hours and maintenance state.
+    ///
+    /// Parameters
+    /// - `listing_id`: Listing to buy.
+    ///
+    /// Returns
+    /// - `Ok(plane_id)`: Id of the purchased plane.
+    /// - `Err(GameError)`: If the listing doesn't exist or the player can't afford it.
+    pub fn buy_used_plane(&mut self, listing_id: usize) -> Result<usize, GameError> {
+        let idx = self
+            .used_market
+            .iter()
+            .position(|l| l.id == listing_id && !l.is_expired(self.time))
+            .ok_or(GameError::ListingIdInvalid { id: listing_id })?;
+        let price = self.used_market[idx].price;
+        if self.player.available_funds() < price {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: price,
+            });
+        }
+
+        let listing = self.used_market.remove(idx);
+        let location = self.airport_coord(listing.airport_id);
+        let plane_id = self.player.allocate_plane_id();
+        let plane = listing.to_airplane(plane_id, location, self.time);
+
+        self.player.cash -= price;
+        self.daily_expenses += price;
+        self.daily_investing -= price;
+        self.player.fleet.push(plane);
+        self.prepare_new_plane();
+
+        self.airplanes = self.player.fleet.clone();
+        self.player.fleet_size = self.player.fleet.len();
+        self.arrival_times.insert(plane_id, self.time);
+        Ok(plane_id)
+    }
+
+    /// Apply game settings to the plane that was just added to the player's fleet.
+    fn prepare_new_plane(&mut self) {
+        if let Some(plane) = self.player.fleet.last_mut() {
+            plane.reserve_hours = self.fuel_settings.reserve_minutes / 60.0;
+            plane.acquired_at = self.time;
+        }
+    }
+
+    /// Tag a plane with a group name such as `feeders` or `heavies`.
+    ///
+    /// A plane can belong to several groups. Names are case-insensitive and may only
+    /// contain letters, digits, `-` and `_`.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to tag.
+    /// - `group`: Group name; the group is created if it doesn't exist yet.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane was added.
+    /// - `Err(GameError)`: If the plane doesn't exist or the name is invalid.
+    pub fn group_plane(&mut self, plane_id: usize, group: &str) -> Result<(), GameError> {
+        if !self.airplanes.iter().any(|p| p.id == plane_id) {
+            return Err(GameError::PlaneIdInvalid { id: plane_id });
+        }
+        let valid = !group.is_empty()
+            && group
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Invalid group name `{}`: use letters, digits, `-` or `_`",
+                    group
+                ),
+            });
+        }
+        self.player.add_to_group(group, plane_id);
+        Ok(())
+    }
+
+    /// Remove a plane from a group.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane was removed.
+    /// - `Err(GameError)`: If the group doesn't exist or the plane isn't in it.
+    pub fn ungroup_plane(&mut self, plane_id: usize, group: &str) -> Result<(), GameError> {
+        if !self.group_planes(group)?.contains(&plane_id) {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} is not in group {}", plane_id, group),
+            });
+        }
+        self.player.remove_from_group(group, plane_id);
+        Ok(())
+    }
+
+    /// Ids of the planes in a group.
+    ///
+    /// Returns
+    /// - `Ok(Vec<usize>)`: Member plane ids in ascending order.
+    /// - `Err(GameError::UnknownGroup)`: If the group doesn't exist.
+    pub fn group_planes(&self, group: &str) -> Result<Vec<usize>, GameError> {
+        self.player
+            .group_members(group)
+            .ok_or_else(|| GameError::UnknownGroup {
+                name: group.to_string(),
+            })
+    }
+
+    /// Give a plane a custom name or registration.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to rename.
+    /// - `name`: New name; surrounding whitespace is trimmed.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane was renamed.
+    /// - `Err(GameError)`: If the plane doesn't exist, or the name is empty, longer than
+    ///   [`MAX_PLANE_NAME_LEN`] characters or already used by another plane.
+    pub fn rename_plane(&mut self, plane_id: usize, name: &str) -> Result<(), GameError> {
+        let name = name.trim();
+        if !self.airplanes.iter().any(|p| p.id == plane_id) {
+            return Err(GameError::PlaneIdInvalid { id: plane_id });
+        }
+        if name.is_empty() {
+            return Err(GameError::InvalidCommand {
+                msg: "Plane name cannot be empty".to_string(),
+            });
+        }
+        if name.chars().count() > MAX_PLANE_NAME_LEN {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane name cannot be longer than {} characters",
+                    MAX_PLANE_NAME_LEN
+                ),
+            });
+        }
+        if let Some(other) = self
+            .airplanes
+            .iter()
+            .find(|p| p.id != plane_id && p.name.eq_ignore_ascii_case(name))
+        {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} is already called {}", other.id, other.name),
+            });
+        }
+
+        for plane in self
+            .airplanes
+            .iter_mut()
+            .chain(self.player.fleet.iter_mut())
+            .filter(|p| p.id == plane_id)
+        {
+            plane.name = name.to_string();
+        }
+        Ok(())
+    }
+
+    /// Appraise a plane at its current resale value.
+    ///
+    /// The value falls with airframe hours, time since purchase and maintenance
+    /// condition; this is the amount [`Game::sell_plane`] pays out.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to appraise.
+    ///
+    /// Returns
+    /// - `Ok(f32)`: Current resale value.
+    /// - `Err(GameError::PlaneIdInvalid)`: If the plane doesn't exist.
+    pub fn appraise_plane(&self, plane_id: usize) -> Result<f32, GameError> {
+        self.airplanes
+            .iter()
+            .find(|plane| plane.id == plane_id)
+            .map(|plane| plane.resale_value(self.time))
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })
+    }
+
+    /// Sell an airplane currently owned by the player at its appraised value.
     pub fn sell_plane(&mut self, plane_id: usize) -> Result<f32, GameError> {
         let plane_index = self
             .airplanes
@@ -1950,22 +3714,41 @@ impl Game {
                 ),
             });
         }
+        if plane_snapshot.charter_passengers > 0 {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} cannot be sold while carrying a charter group",
+                    plane_id
+                ),
+            });
+        }
         if plane_snapshot.status != AirplaneStatus::Parked {
             return Err(GameError::PlaneNotReady {
                 plane_state: plane_snapshot.status,
             });
         }
 
-        let (sold_plane, refund) = self.player.sell_plane(plane_id)?;
+        // the player's fleet copy only syncs on purchases, so refresh it before pricing
+        self.player.fleet = self.airplanes.clone();
+        let (sold_plane, refund) = self.player.sell_plane(plane_id, self.time)?;
         debug_assert_eq!(sold_plane.id, plane_id);
 
         self.airplanes.remove(plane_index);
         self.arrival_times.remove(&plane_id);
+        for pilot in self
+            .player
+            .pilots
+            .iter_mut()
+            .filter(|p| p.plane == Some(plane_id))
+        {
+            pilot.plane = None;
+        }
 
         self.player.fleet = self.airplanes.clone();
         self.player.fleet_size = self.player.fleet.len();
 
         self.daily_income += refund;
+        self.daily_investing += refund;
 
         Ok(refund)
     }
@@ -1980,254 +3763,3621 @@ impl Game {
     /// - `Ok(())` on success.
     /// - `Err(GameError)`: If the plane doesn't exist, isn't parked, or capacity constraints fail.
     pub fn load_order(&mut self, order_id: usize, plane_id: usize) -> Result<(), GameError> {
-        let (plane_idx, airport_idx) = self.plane_and_airport_idx(plane_id)?;
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
         let plane = &mut self.airplanes[plane_idx];
-        let airport = &mut self.map.airports[airport_idx].0;
+        let airport = self.map.airport_mut(airport_id).expect(ON_MAP);
+        let before = plane.handling_weight();
 
         airport.load_order(order_id, plane)?;
-        self.schedule(self.time + 1, Event::LoadingEvent { plane: plane_id });
+        self.map.lane_market.record_pickup(airport_id);
+        let moved = self.airplanes[plane_idx].handling_weight() - before;
+        self.finish_cargo_handling(plane_idx, airport_id, GroundTask::Loading, moved);
 
         Ok(())
     }
 
-    /// Unload all orders from the plane.
+    /// Every order waiting at any airport, in airport order.
+    pub fn all_orders(&self) -> Vec<&Order> {
+        self.map
+            .airports
+            .iter()
+            .flat_map(|(airport, _)| airport.orders.iter())
+            .collect()
+    }
+
+    /// Orders on the world-wide board that pass `filter`, best paying per kg first.
     ///
     /// Parameters
-    /// - `plane_id`: Plane ID.
+    /// - `filter`: Destination country, minimum value per kg and latest deadline wanted.
     ///
     /// Returns
-    /// - `Ok(())` on success.
-    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked at an airport.
-    pub fn unload_all(&mut self, plane_id: usize) -> Result<(), GameError> {
-        let (plane_idx, airport_idx) = self.plane_and_airport_idx(plane_id)?;
-
-        let airport = &mut self.map.airports[airport_idx].0;
-        let plane = &mut self.airplanes[plane_idx];
-        let mut deliveries = plane.unload_all();
-
-        // Check deliveries
-        for delivery in deliveries.drain(..) {
-            // reached the destination and before deadline
-            if delivery.destination_id == airport.id {
-                if delivery.deadline != 0 {
-                    println!("Successfully delivered order {}", delivery.id);
-                    self.player.cash += delivery.value;
-                    self.daily_income += delivery.value;
-                    self.player.record_delivery();
-                } else {
-                    println!("Order {}: Deadline expired", delivery.id)
-                }
-            }
-            // not the destination so it goes into the stock at the airport
-            else {
-                println!(
-                    "Order {} being stored at airport {}",
-                    delivery.id, airport.id
-                );
-                airport.orders.push(delivery);
-            }
-        }
-
-        self.schedule(self.time + 1, Event::LoadingEvent { plane: plane_id });
-
-        Ok(())
+    /// - `Vec<&Order>`: Matching orders sorted by value per kg, highest first.
+    pub fn find_orders(&self, filter: &OrderBoardFilter) -> Vec<&Order> {
+        let mut orders: Vec<&Order> = self
+            .all_orders()
+            .into_iter()
+            .filter(|o| filter.matches(o, self.map.country_of(o.destination_id)))
+            .collect();
+        orders.sort_by(|a, b| b.value_per_kg().total_cmp(&a.value_per_kg()));
+        orders
     }
 
-    /// Unload a list of orders from a plane.
+    /// Reserve an order waiting at any airport so it is still there when a plane arrives.
+    ///
+    /// Reserved orders survive restocks until they are loaded.
+    /// The fee is `orders.reservation_share` of the order's value.
     ///
     /// Parameters
-    /// - `order_id`: A list of order IDs to unload.
-    /// - `plane_id`: Plane ID.
+    /// - `order_id`: Order waiting at an airport.
     ///
     /// Returns
-    /// - `Ok(())` on success.
-    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked at an airport.
-    pub fn unload_orders(
-        &mut self,
-        order_id: Vec<usize>,
-        plane_id: usize,
-    ) -> Result<(), GameError> {
-        let (plane_idx, airport_idx) = self.plane_and_airport_idx(plane_id)?;
+    /// - `Ok(fee)`: The fee paid.
+    /// - `Err(GameError)`: If the order doesn't exist, is already reserved, or the fee
+    ///   can't be paid.
+    pub fn reserve_order(&mut self, order_id: usize) -> Result<f32, GameError> {
+        let order = self
+            .all_orders()
+            .into_iter()
+            .find(|o| o.id == order_id)
+            .ok_or(GameError::OrderIdInvalid { id: order_id })?;
+        if self.reserved_orders.contains(&order_id) {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Order {} is already reserved", order_id),
+            });
+        }
+        let fee = order.value * self.reservation_share;
+        if self.player.available_funds() < fee {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: fee,
+            });
+        }
 
-        let airport = &mut self.map.airports[airport_idx].0;
-        let plane = &mut self.airplanes[plane_idx];
+        self.player.cash -= fee;
+        self.daily_expenses += fee;
+        self.reserved_orders.insert(order_id);
+        Ok(fee)
+    }
 
-        for order in order_id {
-            let delivery = plane.unload_order(order)?;
+    /// Pull the reserved and staged orders still waiting at airports out of the map.
+    ///
+    /// Reservations for orders that were loaded in the meantime are dropped.
+    fn take_reserved_orders(&mut self) -> Vec<(AirportId, Order)> {
+        let mut held = Vec::new();
+        for (airport, _) in self.map.airports.iter_mut() {
+            let (kept, open): (Vec<Order>, Vec<Order>) = std::mem::take(&mut airport.orders)
+                .into_iter()
+                .partition(|o| {
+                    self.reserved_orders.contains(&o.id) || self.staged_orders.contains_key(&o.id)
+                });
+            airport.orders = open;
+            held.extend(kept.into_iter().map(|o| (airport.id, o)));
+        }
+        let ids: BTreeSet<usize> = held.iter().map(|(_, o)| o.id).collect();
+        self.reserved_orders.retain(|id| ids.contains(id));
+        self.staged_orders.retain(|id, _| ids.contains(id));
+        held
+    }
 
-            if delivery.destination_id == airport.id {
-                if delivery.deadline != 0 {
-                    println!("Successfully delivered order {}", delivery.id);
-                    self.player.cash += delivery.value;
-                    self.daily_income += delivery.value;
-                    self.player.record_delivery();
-                } else {
-                    println!("Order {}: Deadline expired", delivery.id)
-                }
+    /// Orders staged at an airport short of their destination.
+    pub fn staged_orders_at(&self, airport_id: usize) -> Vec<&Order> {
+        self.map
+            .airport(airport_id)
+            .map(|airport| {
+                airport
+                    .orders
+                    .iter()
+                    .filter(|o| self.staged_orders.contains_key(&o.id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Daily warehouse fee for the orders staged at an airport:
+    /// `warehouse.fee_per_ton_per_day` per tonne once an order has been there longer
+    /// than `warehouse.free_hours`. Airports the player built store cargo for free.
+    pub fn warehouse_fee(&self, airport_id: usize) -> f32 {
+        if self
+            .map
+            .airport(airport_id)
+            .is_none_or(|airport| airport.player_built)
+        {
+            return 0.0;
+        }
+        let settings = &self.warehouse_settings;
+        let tons: f32 = self
+            .staged_orders_at(airport_id)
+            .into_iter()
+            .filter(|o| self.time.saturating_sub(self.staged_orders[&o.id]) > settings.free_hours)
+            .map(|o| o.payload_weight() / 1000.0)
+            .sum();
+        tons * settings.fee_per_ton_per_day
+    }
+
+    /// Charge the day's warehouse fees at every airport holding staged orders, and
+    /// forget staged orders that have since been loaded or expired.
+    fn charge_warehouse_fees(&mut self) {
+        let present: BTreeSet<usize> = self.all_orders().iter().map(|o| o.id).collect();
+        self.staged_orders.retain(|id, _| present.contains(id));
+
+        let airports: Vec<AirportId> = self.map.airports.iter().map(|(a, _)| a.id).collect();
+        let mut total = 0.0;
+        for airport_id in airports {
+            let fee = self.warehouse_fee(airport_id);
+            if fee > 0.0 {
+                let message = format!(
+                    "Warehouse fees at {}: ${:.2}",
+                    self.airport_name(airport_id),
+                    fee
+                );
+                self.log.push(message);
+                total += fee;
             }
-            // not the destination so it goes into the stock at the airport
-            else {
+        }
+        self.player.cash -= total;
+        self.daily_expenses += total;
+        self.daily_fees += total;
+    }
+
+    /// Print the orders staged at each airport with their weight and daily fee.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_warehouse(&self) {
+        let mut any = false;
+        for (airport, _) in &self.map.airports {
+            let staged = self.staged_orders_at(airport.id);
+            if staged.is_empty() {
+                continue;
+            }
+            any = true;
+            let weight: f32 = staged.iter().map(|o| o.payload_weight()).sum();
+            println!(
+                "{} [{}] | {} orders | {:.0}kg | fee: ${:.2}/day",
+                airport.name,
+                airport.id,
+                staged.len(),
+                weight,
+                self.warehouse_fee(airport.id),
+            );
+            for order in staged {
                 println!(
-                    "Order {} being stored at airport {}",
-                    delivery.id, airport.id
+                    "    [{}] {:.0}kg -> {} | staged for: {} | deadline: {}",
+                    order.id,
+                    order.payload_weight(),
+                    self.airport_name(order.destination_id),
+                    self.days_and_hours(self.time.saturating_sub(self.staged_orders[&order.id])),
+                    self.days_and_hours(order.deadline),
                 );
-                airport.orders.push(delivery);
             }
         }
-        self.schedule(self.time + 1, Event::LoadingEvent { plane: plane_id });
-
-        Ok(())
+        if !any {
+            println!("No cargo is staged at any airport");
+        }
     }
 
-    /// Unload a specific order from a plane.
+    /// Split `weight` kg off a cargo order waiting at an airport into a new part.
+    ///
+    /// The parts can travel on different planes and are paid in proportion to their
+    /// weight. A share of the value is held back and paid once every part is delivered
+    /// on time.
     ///
     /// Parameters
-    /// - `order_id`: Order ID to unload.
-    /// - `plane_id`: Plane ID.
+    /// - `order_id`: Order waiting at an airport.
+    /// - `weight`: Weight in kg moved into the new part.
     ///
     /// Returns
-    /// - `Ok(())` on success.
-    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked.
-    pub fn unload_order(&mut self, order_id: usize, plane_id: usize) -> Result<(), GameError> {
-        let (plane_idx, airport_idx) = self.plane_and_airport_idx(plane_id)?;
-
-        let airport = &mut self.map.airports[airport_idx].0;
-        let plane = &mut self.airplanes[plane_idx];
-
-        let delivery = plane.unload_order(order_id)?;
+    /// - `Ok(new_id)`: Id of the new part.
+    /// - `Err(GameError)`: If the order doesn't exist, carries passengers, or the weight is
+    ///   not strictly between zero and the order's weight.
+    pub fn split_order(&mut self, order_id: usize, weight: f32) -> Result<usize, GameError> {
+        let (airport_id, order_idx) = self
+            .map
+            .airports
+            .iter()
+            .find_map(|(airport, _)| {
+                airport
+                    .orders
+                    .iter()
+                    .position(|o| o.id == order_id)
+                    .map(|o_idx| (airport.id, o_idx))
+            })
+            .ok_or(GameError::OrderIdInvalid { id: order_id })?;
 
-        if delivery.destination_id == airport.id {
-            if delivery.deadline != 0 {
-                println!("Successfully delivered order {}", delivery.id);
-                self.player.cash += delivery.value;
-                self.daily_income += delivery.value;
-                self.player.record_delivery();
-            } else {
-                println!("Order {}: Deadline expired", delivery.id)
-            }
+        let order = &self.map.airport(airport_id).expect(ON_MAP).orders[order_idx];
+        if order.is_pallet() {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Order {} is a pallet; break it up before splitting",
+                    order_id
+                ),
+            });
         }
-        // not the destination so it goes into the stock at the airport
-        else {
-            println!(
-                "Order {} being stored at airport {}",
-                delivery.id, airport.id
-            );
-            airport.orders.push(delivery);
+        if order.spare_parts > 0 {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Order {} carries spare parts and cannot be split", order_id),
+            });
+        }
+        let Some(total) = order.cargo_weight() else {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Order {} carries passengers and cannot be split", order_id),
+            });
+        };
+        if !(weight > 0.0 && weight < total) {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Split weight must be between 0 and {:.0}kg for order {}",
+                    total, order_id
+                ),
+            });
         }
 
-        self.schedule(self.time + 1, Event::LoadingEvent { plane: plane_id });
+        let new_id = self.map.allocate_order_id();
+        let order = &mut self.map.airport_mut(airport_id).expect(ON_MAP).orders[order_idx];
+        let shipment_id = match order.shipment {
+            Some(id) => id,
+            None => {
+                let completion_bonus = order.value * SPLIT_COMPLETION_SHARE;
+                order.value -= completion_bonus;
+                order.shipment = Some(order.id);
+                self.split_shipments.insert(
+                    order.id,
+                    SplitShipment {
+                        parts_remaining: 1,
+                        completion_bonus,
+                        failed: false,
+                    },
+                );
+                order.id
+            }
+        };
+        let part = order
+            .split_off(weight, new_id)
+            .expect("weight was checked against the order");
+        if let Some(shipment) = self.split_shipments.get_mut(&shipment_id) {
+            shipment.parts_remaining += 1;
+        }
+        self.map
+            .airport_mut(airport_id)
+            .expect(ON_MAP)
+            .orders
+            .push(part);
 
-        Ok(())
+        println!(
+            "Split {:.0}kg off order {} into order {}",
+            weight, order_id, new_id
+        );
+        Ok(new_id)
     }
 
-    /// Depart a plane to another airport.
+    /// Consolidate orders waiting at one airport for the same destination into a pallet.
+    ///
+    /// The pallet is loaded and unloaded as one order, which takes less handling time
+    /// than its orders would on their own, and is broken down again at its destination.
+    /// A pallet holding a reserved or staged order is itself reserved or staged.
     ///
     /// Parameters
-    /// - `plane_id`: Plane ID to dispatch.
-    /// - `destination_id`: Destination airport ID.
+    /// - `order_ids`: Two or more cargo orders waiting at the same airport.
     ///
     /// Returns
-    /// - `Ok(())` on success.
-    /// - `Err(GameError)`: Invalid IDs, not parked, insufficient fuel, or runway issues.
-    pub fn depart_plane(
-        &mut self,
-        plane_id: usize,
-        destination_id: usize,
-    ) -> Result<(), GameError> {
-        let (plane_idx, origin_idx) = self.plane_and_airport_idx(plane_id)?;
-        let plane = &mut self.airplanes[plane_idx];
-
-        // Guard rail: only depart when parked
-        if !matches!(plane.status, AirplaneStatus::Parked) {
-            return Err(GameError::PlaneNotReady {
-                plane_state: plane.status.clone(),
+    /// - `Ok(usize)`: Id of the pallet.
+    /// - `Err(GameError)`: If an order doesn't exist, waits at another airport, carries
+    ///   passengers, spare parts or a rare load, or the destinations differ.
+    pub fn build_pallet(&mut self, order_ids: &[usize]) -> Result<usize, GameError> {
+        let ids: BTreeSet<usize> = order_ids.iter().copied().collect();
+        if ids.len() < 2 {
+            return Err(GameError::InvalidCommand {
+                msg: "A pallet needs at least two orders".to_string(),
             });
         }
-        let (dest_airport, dest_coords) = &self
+        let first = *ids.first().expect("checked above");
+        let airport = self
             .map
             .airports
             .iter()
-            .find(|(a, _)| a.id == destination_id)
-            .ok_or(GameError::AirportIdInvalid { id: destination_id })?;
+            .map(|(airport, _)| airport)
+            .find(|airport| airport.orders.iter().any(|o| o.id == first))
+            .ok_or(GameError::OrderIdInvalid { id: first })?;
+        let airport_id = airport.id;
+        let mut destination = None;
+        for &id in &ids {
+            let Some(order) = airport.orders.iter().find(|o| o.id == id) else {
+                if self.all_orders().iter().any(|o| o.id == id) {
+                    return Err(GameError::InvalidCommand {
+                        msg: format!("Order {} is not waiting at airport {}", id, airport_id),
+                    });
+                }
+                return Err(GameError::OrderIdInvalid { id });
+            };
+            if order.is_passenger() || order.spare_parts > 0 || order.rare.is_some() {
+                return Err(GameError::InvalidCommand {
+                    msg: format!("Order {} can't go on a pallet", id),
+                });
+            }
+            if *destination.get_or_insert(order.destination_id) != order.destination_id {
+                return Err(GameError::InvalidCommand {
+                    msg: "Orders on a pallet must share a destination".to_string(),
+                });
+            }
+        }
 
-        // consume fuel & get flight_hours
-        // check before if we can get there, else we don't charge
-        let flight_hours = plane.consume_flight_fuel(dest_airport, dest_coords)?;
-        let origin_coord = plane.location;
+        let pallet_id = self.map.allocate_order_id();
+        let airport = self.map.airport_mut(airport_id).expect(ON_MAP);
+        let (contents, rest): (Vec<Order>, Vec<Order>) = std::mem::take(&mut airport.orders)
+            .into_iter()
+            .partition(|o| ids.contains(&o.id));
+        airport.orders = rest;
+        let pallet =
+            Order::palletize(pallet_id, airport_id, contents).expect("orders were checked");
+        airport.orders.push(pallet);
 
-        // charge parking
-        let parked_since = *self.arrival_times.get(&plane_id).unwrap_or(&self.time);
+        let reserved = ids
+            .iter()
+            .filter(|id| self.reserved_orders.remove(id))
+            .count();
+        if reserved > 0 {
+            self.reserved_orders.insert(pallet_id);
+        }
+        if let Some(since) = ids
+            .iter()
+            .filter_map(|id| self.staged_orders.remove(id))
+            .min()
+        {
+            self.staged_orders.insert(pallet_id, since);
+        }
+
+        println!("Built pallet {} from {} orders", pallet_id, ids.len());
+        Ok(pallet_id)
+    }
+
+    /// Break a pallet waiting at an airport back into its orders.
+    ///
+    /// Parameters
+    /// - `pallet_id`: Pallet waiting at an airport.
+    ///
+    /// Returns
+    /// - `Ok(Vec<usize>)`: Ids of the orders that were on it.
+    /// - `Err(GameError)`: If the pallet isn't waiting at an airport or isn't a pallet.
+    pub fn break_pallet(&mut self, pallet_id: usize) -> Result<Vec<usize>, GameError> {
+        let airport = self
+            .map
+            .airports
+            .iter_mut()
+            .map(|(airport, _)| airport)
+            .find(|airport| airport.orders.iter().any(|o| o.id == pallet_id))
+            .ok_or(GameError::OrderIdInvalid { id: pallet_id })?;
+        let idx = airport
+            .orders
+            .iter()
+            .position(|o| o.id == pallet_id)
+            .expect("found above");
+        if !airport.orders[idx].is_pallet() {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Order {} is not a pallet", pallet_id),
+            });
+        }
+        let contents = airport.orders.remove(idx).pallet;
+        let ids: Vec<usize> = contents.iter().map(|o| o.id).collect();
+        airport.orders.extend(contents);
+
+        if self.reserved_orders.remove(&pallet_id) {
+            self.reserved_orders.extend(ids.iter().copied());
+        }
+        if let Some(since) = self.staged_orders.remove(&pallet_id) {
+            self.staged_orders.extend(ids.iter().map(|id| (*id, since)));
+        }
+        Ok(ids)
+    }
+
+    /// Record that one part of a split shipment reached its destination.
+    ///
+    /// Pays the completion bonus once the last part arrives, unless any part was late.
+    fn settle_split_part(&mut self, shipment_id: usize, on_time: bool) {
+        let Some(shipment) = self.split_shipments.get_mut(&shipment_id) else {
+            return;
+        };
+        shipment.parts_remaining = shipment.parts_remaining.saturating_sub(1);
+        shipment.failed |= !on_time;
+        if shipment.parts_remaining > 0 {
+            return;
+        }
+
+        if let Some(shipment) = self.split_shipments.remove(&shipment_id) {
+            if shipment.failed {
+                println!(
+                    "Order {}: Split shipment incomplete, bonus lost",
+                    shipment_id
+                );
+            } else {
+                println!(
+                    "Order {}: Split shipment complete, bonus ${:.2}",
+                    shipment_id, shipment.completion_bonus
+                );
+                self.player.cash += shipment.completion_bonus;
+                self.daily_income += shipment.completion_bonus;
+            }
+        }
+    }
+
+    /// Drop expired contracts and draw new ones until `contract_settings.count` are open.
+    fn refresh_contracts(&mut self) {
+        let now = self.time;
+        self.contracts.retain(|c| {
+            let open = !c.is_expired(now);
+            if !open {
+                println!("Contract {} expired", c.id);
+            }
+            open
+        });
+
+        let airports = self.map.order_airport_infos();
+        while self.contracts.len() < self.contract_settings.count {
+            let id = self.next_contract_id;
+            let seed = self
+                .seed
+                .wrapping_add(id as u64)
+                .wrapping_add(now)
+                .wrapping_add(31);
+            let Some(contract) =
+                Contract::generate(seed, id, &airports, &self.contract_settings, now)
+            else {
+                break;
+            };
+            self.next_contract_id += 1;
+            self.contracts.push(contract);
+        }
+    }
+
+    /// Drop lapsed charter offers, cancel overdue groups still on the ground and draw new
+    /// requests until `charter_settings.count` are on offer.
+    fn refresh_charters(&mut self) {
+        let now = self.time;
+        let mut cancelled = Vec::new();
+        self.charters.retain(|c| {
+            if c.is_lapsed(now) {
+                return false;
+            }
+            if let Some(booking) = c.booking.filter(|_| c.is_overdue(now)) {
+                cancelled.push((c.id, booking.plane, c.passengers));
+                return false;
+            }
+            true
+        });
+        // a group still in the air is settled when it lands, so only grounded ones give up
+        for (charter_id, plane_id, passengers) in cancelled {
+            if let Some(idx) = self.plane_index(plane_id) {
+                if matches!(self.airplanes[idx].status, AirplaneStatus::InTransit { .. }) {
+                    continue;
+                }
+                self.airplanes[idx].deboard_charter(passengers);
+            }
+            let message = format!(
+                "Charter {}: the group on plane {} gave up waiting and left",
+                charter_id, plane_id
+            );
+            println!("{}", message);
+            self.log.push(message);
+        }
+
+        let airports = self.map.order_airport_infos();
+        let fare_per_km = self.map.demand_params.passengers.fare_per_km;
+        while self.charters.iter().filter(|c| c.is_open(now)).count() < self.charter_settings.count
+        {
+            let id = self.next_charter_id;
+            let seed = self
+                .seed
+                .wrapping_add(id as u64)
+                .wrapping_add(now)
+                .wrapping_add(59);
+            let Some(charter) = Charter::generate(
+                seed,
+                id,
+                &airports,
+                &self.charter_settings,
+                fare_per_km,
+                now,
+            ) else {
+                break;
+            };
+            self.next_charter_id += 1;
+            self.charters.push(charter);
+        }
+    }
+
+    /// Place the freight forwarders at their hubs.
+    fn set_up_forwarders(&mut self) {
+        self.forwarders = Forwarder::generate_all(
+            self.seed,
+            self.forwarder_settings.count,
+            &self.map.order_airport_infos(),
+        );
+    }
+
+    /// Drop lapsed and finished lane deals and let every forwarder whose turn it is
+    /// make a new offer.
+    ///
+    /// Forwarders take turns: forwarder `i` offers on the days where
+    /// `(day + i) % offer_interval_days == 0`, and only while it has no offer pending.
+    fn refresh_lane_offers(&mut self) {
+        let now = self.time;
+        self.lane_deals
+            .retain(|d| d.is_offered(now) || (d.accepted_at.is_some() && !d.is_finished()));
+
+        let day = (now / 24) as usize;
+        let interval = self.forwarder_settings.offer_interval_days.max(1) as usize;
+        let airports = self.map.order_airport_infos();
+        let mut offered = Vec::new();
+        for forwarder in &self.forwarders {
+            if (day + forwarder.id) % interval != 0 {
+                continue;
+            }
+            let pending = self
+                .lane_deals
+                .iter()
+                .any(|d| d.forwarder == forwarder.id && d.is_offered(now));
+            if pending {
+                continue;
+            }
+            let id = self.next_lane_deal_id;
+            let seed = self
+                .seed
+                .wrapping_add(id as u64)
+                .wrapping_add(now)
+                .wrapping_add(83);
+            let Some(deal) = LaneDeal::generate(
+                seed,
+                id,
+                forwarder,
+                &airports,
+                &self.forwarder_settings,
+                now,
+            ) else {
+                continue;
+            };
+            self.next_lane_deal_id += 1;
+            let message = format!(
+                "{} offers lane {}: {} loads/week of {:.0}kg {:?} {} -> {} at ${:.2}/kg for {} weeks",
+                forwarder.name,
+                deal.id,
+                deal.loads_per_week,
+                deal.load_weight,
+                deal.cargo_type,
+                self.airport_name(deal.origin_id),
+                self.airport_name(deal.destination_id),
+                deal.rate_per_kg,
+                deal.weeks,
+            );
+            println!("{}", message);
+            self.log.push(message);
+            offered.push(GameEvent::LaneOffered {
+                time: now,
+                deal: deal.id,
+                forwarder: forwarder.id,
+            });
+            self.lane_deals.push(deal);
+        }
+        for event in offered {
+            self.notify(event);
+        }
+    }
+
+    /// Hand over the next load of an accepted lane at its origin and schedule the one
+    /// after, until the lane has run its course.
+    ///
+    /// Loads are reserved orders, so restocks leave them waiting until a plane picks
+    /// them up.
+    fn issue_lane_load(&mut self, deal_id: usize) {
+        let Some(idx) = self.lane_deals.iter().position(|d| d.id == deal_id) else {
+            return;
+        };
+        let deal = self.lane_deals[idx].clone();
+        if deal.accepted_at.is_none() || deal.is_finished() {
+            return;
+        }
+
+        let order_id = self.map.allocate_order_id();
+        let Some(airport) = self.map.airport_mut(deal.origin_id) else {
+            return;
+        };
+        airport.orders.push(Order {
+            id: order_id,
+            payload: OrderPayload::Cargo {
+                cargo_type: deal.cargo_type,
+                weight: deal.load_weight,
+            },
+            value: deal.load_value(),
+            deadline: self.forwarder_settings.load_deadline_hours.max(1),
+            origin_id: deal.origin_id,
+            destination_id: deal.destination_id,
+            tier: OrderTier::Standard,
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: Vec::new(),
+        });
+        self.reserved_orders.insert(order_id);
+
+        let deal = &mut self.lane_deals[idx];
+        deal.loads_issued += 1;
+        if !deal.is_finished() {
+            let next = self.time + deal.load_interval();
+            self.schedule(next, Event::LaneLoad { deal: deal_id });
+        }
+    }
+
+    /// Lane deals on offer or running.
+    pub fn lane_deals(&self) -> &[LaneDeal] {
+        &self.lane_deals
+    }
+
+    /// Accept a lane deal on offer.
+    ///
+    /// The first load appears at the origin right away and the rest follow at even
+    /// intervals through the week until the lane has run for its number of weeks.
+    ///
+    /// Parameters
+    /// - `deal_id`: Lane deal on offer.
+    ///
+    /// Returns
+    /// - `Ok(())` if the lane is running.
+    /// - `Err(GameError::LaneIdInvalid)`: If no such deal is waiting for an answer.
+    pub fn accept_lane_deal(&mut self, deal_id: usize) -> Result<(), GameError> {
+        let now = self.time;
+        let deal = self
+            .lane_deals
+            .iter_mut()
+            .find(|d| d.id == deal_id && d.is_offered(now))
+            .ok_or(GameError::LaneIdInvalid { id: deal_id })?;
+        deal.accepted_at = Some(now);
+        let forwarder = deal.forwarder;
+        if let Some(f) = self.forwarders.iter_mut().find(|f| f.id == forwarder) {
+            f.lanes_accepted += 1;
+        }
+        self.schedule(now, Event::LaneLoad { deal: deal_id });
+        Ok(())
+    }
+
+    /// Turn down a lane deal on offer.
+    ///
+    /// Parameters
+    /// - `deal_id`: Lane deal on offer.
+    ///
+    /// Returns
+    /// - `Ok(())` if the offer was withdrawn.
+    /// - `Err(GameError::LaneIdInvalid)`: If no such deal is waiting for an answer.
+    pub fn decline_lane_deal(&mut self, deal_id: usize) -> Result<(), GameError> {
+        let now = self.time;
+        let idx = self
+            .lane_deals
+            .iter()
+            .position(|d| d.id == deal_id && d.is_offered(now))
+            .ok_or(GameError::LaneIdInvalid { id: deal_id })?;
+        let deal = self.lane_deals.remove(idx);
+        if let Some(f) = self.forwarders.iter_mut().find(|f| f.id == deal.forwarder) {
+            f.offers_declined += 1;
+        }
+        Ok(())
+    }
+
+    /// Put a load up for auction with the day's `auctions.chance_per_day`.
+    fn open_auction(&mut self) {
+        let now = self.time;
+        let mut roll = StdRng::seed_from_u64(self.seed.wrapping_add(now).wrapping_add(97));
+        if !roll.gen_bool(self.auction_settings.chance_per_day.clamp(0.0, 1.0) as f64) {
+            return;
+        }
+        let id = self.next_auction_id;
+        let seed = self
+            .seed
+            .wrapping_add(id as u64)
+            .wrapping_add(now)
+            .wrapping_add(101);
+        let Some(auction) = Auction::generate(
+            seed,
+            id,
+            &self.map.order_airport_infos(),
+            &self.auction_settings,
+            now,
+        ) else {
+            return;
+        };
+        self.next_auction_id += 1;
+        let message = format!(
+            "Auction {}: {:.0}kg {:?} {} -> {} due within {}; bids close in {}",
+            auction.id,
+            auction.weight,
+            auction.cargo_type,
+            self.airport_name(auction.origin_id),
+            self.airport_name(auction.destination_id),
+            self.days_and_hours(auction.deadline),
+            self.days_and_hours(auction.closes_at - now),
+        );
+        println!("{}", message);
+        self.log.push(message);
+        self.schedule(auction.closes_at, Event::AuctionClose { auction: id });
+        self.auctions.push(auction);
+    }
+
+    /// Put a rare load up at a random airport with the day's
+    /// `rare_cargo.chance_per_day`, and announce it to subscribers.
+    ///
+    /// The load is reserved, so restocks leave it waiting until it is picked up or its
+    /// deadline passes.
+    fn spawn_rare_cargo(&mut self) {
+        let now = self.time;
+        let mut roll = StdRng::seed_from_u64(self.seed.wrapping_add(now).wrapping_add(107));
+        if !roll.gen_bool(self.rare_cargo_settings.chance_per_day.clamp(0.0, 1.0) as f64) {
+            return;
+        }
+        let order_id = self.map.allocate_order_id();
+        let seed = self
+            .seed
+            .wrapping_add(order_id as u64)
+            .wrapping_add(now)
+            .wrapping_add(109);
+        let Some(order) = generate_rare_order(
+            seed,
+            order_id,
+            &self.map.order_airport_infos(),
+            &self.rare_cargo_settings,
+        ) else {
+            return;
+        };
+        let Some(kind) = order.rare else {
+            return;
+        };
+        let message = format!(
+            "Rare cargo: {} ({:.0}kg) {} -> {} worth ${:.2} due within {}; needs {}. Order {}",
+            kind,
+            order.payload_weight(),
+            self.airport_name(order.origin_id),
+            self.airport_name(order.destination_id),
+            order.value,
+            self.days_and_hours(order.deadline),
+            kind.handling(),
+            order.id,
+        );
+        println!("{}", message);
+        self.log.push(message);
+        let event = GameEvent::RareCargo {
+            time: now,
+            order: order.id,
+            airport: order.origin_id,
+            kind,
+        };
+        self.reserved_orders.insert(order.id);
+        self.map
+            .airport_mut(order.origin_id)
+            .expect(ON_MAP)
+            .orders
+            .push(order);
+        self.notify(event);
+    }
+
+    /// Open new airports, up to `world_growth.max_new_airports` over the game: one near
+    /// a random airport with the day's `world_growth.chance_per_day`, and one near every
+    /// airport where the player's landings and departures first reach
+    /// `world_growth.activity_threshold`. Each opening is announced to subscribers.
+    fn grow_world(&mut self) {
+        let settings = self.world_growth_settings.clone();
+        let now = self.time;
+        let mut anchors = Vec::new();
+
+        if settings.activity_threshold > 0 {
+            let busy: Vec<AirportId> = self
+                .map
+                .airports
+                .iter()
+                .map(|(a, _)| a.id)
+                .filter(|id| !self.growth_anchors.contains(id))
+                .filter(|id| self.airport_operations(*id) >= settings.activity_threshold)
+                .collect();
+            for id in busy {
+                self.growth_anchors.insert(id);
+                anchors.push(id);
+            }
+        }
+
+        let mut roll = StdRng::seed_from_u64(self.seed.wrapping_add(now).wrapping_add(113));
+        if roll.gen_bool(settings.chance_per_day.clamp(0.0, 1.0) as f64) {
+            let pick = roll.gen_range(0..self.map.airports.len());
+            if let Some((airport, _)) = self.map.airports.iter().nth(pick) {
+                anchors.push(airport.id);
+            }
+        }
+
+        for near in anchors {
+            if self.opened_airports.len() >= settings.max_new_airports {
+                break;
+            }
+            let distance = roll.gen_range(settings.radius_km / 4.0..=settings.radius_km);
+            let bearing = roll.gen_range(0.0..std::f32::consts::TAU);
+            let Some(airport) = self.map.open_airport(near, distance, bearing) else {
+                continue;
+            };
+            fit_out_airport(
+                self.map.airport_mut(airport).expect(ON_MAP),
+                &self.fuel_settings,
+                self.maintenance_settings.starting_spare_kits,
+            );
+            self.opened_airports.push(airport);
+            let message = format!(
+                "New airport: {} [{}] opened {:.0}km from {}",
+                self.airport_name(airport),
+                airport,
+                distance,
+                self.airport_name(near),
+            );
+            println!("{}", message);
+            self.log.push(message);
+            self.notify(GameEvent::AirportOpened {
+                time: now,
+                airport,
+                near,
+            });
+        }
+    }
+
+    /// Close bidding on an auction. If the player undercut every competitor, the load
+    /// waits at the origin as a reserved order worth the player's bid.
+    fn resolve_auction(&mut self, auction_id: usize) {
+        let Some(idx) = self.auctions.iter().position(|a| a.id == auction_id) else {
+            return;
+        };
+        let auction = self.auctions.remove(idx);
+        let won = auction.player_wins();
+        let winning_bid = auction.winning_bid();
+
+        let message = if won {
+            let order_id = self.map.allocate_order_id();
+            if let Some(airport) = self.map.airport_mut(auction.origin_id) {
+                airport.orders.push(Order {
+                    id: order_id,
+                    payload: OrderPayload::Cargo {
+                        cargo_type: auction.cargo_type,
+                        weight: auction.weight,
+                    },
+                    value: winning_bid,
+                    deadline: auction.deadline,
+                    origin_id: auction.origin_id,
+                    destination_id: auction.destination_id,
+                    tier: OrderTier::Standard,
+                    damage: 0.0,
+                    shipment: None,
+                    contract: None,
+                    spare_parts: 0,
+                    rare: None,
+                    pallet: Vec::new(),
+                });
+                self.reserved_orders.insert(order_id);
+            }
+            format!(
+                "Auction {} won at ${:.2}: order {} waits at {}",
+                auction.id,
+                winning_bid,
+                order_id,
+                self.airport_name(auction.origin_id)
+            )
+        } else {
+            format!(
+                "Auction {} went to another carrier at ${:.2}",
+                auction.id, winning_bid
+            )
+        };
+        println!("{}", message);
+        self.log.push(message);
+        self.notify(GameEvent::AuctionResolved {
+            time: self.time,
+            auction: auction.id,
+            won,
+            winning_bid,
+        });
+    }
+
+    /// Auctions open for bids.
+    pub fn auctions(&self) -> &[Auction] {
+        &self.auctions
+    }
+
+    /// Bid on an open auction, replacing any earlier bid.
+    ///
+    /// The bid is the price asked for flying the load. When bidding closes, the lowest
+    /// bid wins; losing costs nothing.
+    ///
+    /// Parameters
+    /// - `auction_id`: Auction open for bids.
+    /// - `amount`: Price asked.
+    ///
+    /// Returns
+    /// - `Ok(())` if the bid was placed.
+    /// - `Err(GameError)`: If the auction is not open or the amount is not positive.
+    pub fn bid_auction(&mut self, auction_id: usize, amount: f32) -> Result<(), GameError> {
+        let now = self.time;
+        let auction = self
+            .auctions
+            .iter_mut()
+            .find(|a| a.id == auction_id && a.is_open(now))
+            .ok_or(GameError::AuctionIdInvalid { id: auction_id })?;
+        if !(amount > 0.0 && amount.is_finite()) {
+            return Err(GameError::InvalidCommand {
+                msg: "Bid must be a positive amount".to_string(),
+            });
+        }
+        auction.player_bid = Some(amount);
+        Ok(())
+    }
+
+    /// Draw the passenger demand for the current day.
+    fn refresh_passenger_demand(&mut self) {
+        self.passenger_demand = PassengerDemand::generate(
+            self.seed,
+            self.time / 24,
+            &self.map.order_airport_infos(),
+            &self.demand_settings,
+        );
+        for route in self.route_fares.iter_mut() {
+            route.sold_today = 0;
+        }
+    }
+
+    /// Passengers wanting to fly from one airport to another today.
+    ///
+    /// Parameters
+    /// - `origin_id`: Departure airport.
+    /// - `destination_id`: Arrival airport.
+    ///
+    /// Returns
+    /// - `Ok(u32)`: Today's demand on the route.
+    /// - `Err(GameError)`: If either airport does not exist.
+    pub fn route_demand(&self, origin_id: usize, destination_id: usize) -> Result<u32, GameError> {
+        for id in [origin_id, destination_id] {
+            if !self.map.airports.contains(id) {
+                return Err(GameError::AirportIdInvalid { id });
+            }
+        }
+        Ok(self.passenger_demand.passengers(origin_id, destination_id))
+    }
+
+    /// Share of `seats` a departure on the route would fill with today's demand.
+    ///
+    /// Parameters
+    /// - `origin_id`: Departure airport.
+    /// - `destination_id`: Arrival airport.
+    /// - `seats`: Seats offered on the departure.
+    ///
+    /// Returns
+    /// - `Ok(f32)`: Load factor between `0.0` and `1.0`.
+    /// - `Err(GameError)`: If either airport does not exist.
+    pub fn route_load_factor(
+        &self,
+        origin_id: usize,
+        destination_id: usize,
+        seats: u32,
+    ) -> Result<f32, GameError> {
+        self.route_demand(origin_id, destination_id)
+            .map(|demand| load_factor(demand, seats))
+    }
+
+    /// Regular fare for one passenger between two airports, the price route demand is
+    /// calibrated against.
+    ///
+    /// Returns
+    /// - `Ok(f32)`: Distance times the passenger fare per km.
+    /// - `Err(GameError)`: If either airport does not exist.
+    pub fn reference_fare(
+        &self,
+        origin_id: usize,
+        destination_id: usize,
+    ) -> Result<f32, GameError> {
+        let origin = self
+            .map
+            .coord(origin_id)
+            .ok_or(GameError::AirportIdInvalid { id: origin_id })?;
+        let destination = self
+            .map
+            .coord(destination_id)
+            .ok_or(GameError::AirportIdInvalid { id: destination_id })?;
+        Ok(origin.distance_to(&destination) * self.map.demand_params.passengers.fare_per_km)
+    }
+
+    /// Ticket price set on a route, if it is flown as a scheduled route.
+    pub fn route_fare(&self, origin_id: usize, destination_id: usize) -> Option<&RouteFare> {
+        self.route_fares
+            .iter()
+            .find(|r| r.origin_id == origin_id && r.destination_id == destination_id)
+    }
+
+    /// Tickets scheduled departures on a route can still sell today at its current fare.
+    ///
+    /// Returns
+    /// - `Ok(u32)`: Today's demand adjusted for the fare, less tickets already sold.
+    /// - `Err(GameError)`: If either airport does not exist or the route has no fare.
+    pub fn route_tickets_left(
+        &self,
+        origin_id: usize,
+        destination_id: usize,
+    ) -> Result<u32, GameError> {
+        let reference = self.reference_fare(origin_id, destination_id)?;
+        let route = self.route_fare(origin_id, destination_id).ok_or_else(|| {
+            GameError::InvalidCommand {
+                msg: format!("No fare set from {} to {}", origin_id, destination_id),
+            }
+        })?;
+        let demand = priced_demand(
+            self.passenger_demand.passengers(origin_id, destination_id),
+            route.fare,
+            reference,
+            self.demand_settings.price_elasticity,
+        );
+        Ok(demand.saturating_sub(route.sold_today))
+    }
+
+    /// Fly a route as a scheduled passenger service with a ticket price.
+    ///
+    /// Every departure on the route then sells tickets to free seats, up to the day's
+    /// demand adjusted for how `fare` compares to [`Game::reference_fare`]. Tickets are
+    /// paid at departure and the passengers leave at the next landing.
+    ///
+    /// Parameters
+    /// - `origin_id`: Departure airport.
+    /// - `destination_id`: Arrival airport.
+    /// - `fare`: Price of one ticket; replaces any fare already set.
+    ///
+    /// Returns
+    /// - `Ok(())` if the fare is set.
+    /// - `Err(GameError)`: If an airport does not exist, both are the same or the fare is
+    ///   not positive.
+    pub fn set_route_fare(
+        &mut self,
+        origin_id: usize,
+        destination_id: usize,
+        fare: f32,
+    ) -> Result<(), GameError> {
+        self.reference_fare(origin_id, destination_id)?;
+        if origin_id == destination_id {
+            return Err(GameError::InvalidCommand {
+                msg: "A route needs two different airports".into(),
+            });
+        }
+        if !fare.is_finite() || fare <= 0.0 {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Fare must be positive, got {}", fare),
+            });
+        }
+        let key = (origin_id, destination_id);
+        match self
+            .route_fares
+            .binary_search_by_key(&key, |r| (r.origin_id, r.destination_id))
+        {
+            Ok(idx) => self.route_fares[idx].fare = fare,
+            Err(idx) => self.route_fares.insert(
+                idx,
+                RouteFare {
+                    origin_id,
+                    destination_id,
+                    fare,
+                    sold_today: 0,
+                    departures: 0,
+                    revenue: 0.0,
+                    last_load_factor: 0.0,
+                },
+            ),
+        }
+        Ok(())
+    }
+
+    /// Stop selling tickets on a route.
+    ///
+    /// Returns
+    /// - `Ok(())` if the route had a fare.
+    /// - `Err(GameError::InvalidCommand)` otherwise.
+    pub fn clear_route_fare(
+        &mut self,
+        origin_id: usize,
+        destination_id: usize,
+    ) -> Result<(), GameError> {
+        let before = self.route_fares.len();
+        self.route_fares
+            .retain(|r| r.origin_id != origin_id || r.destination_id != destination_id);
+        if self.route_fares.len() == before {
+            return Err(GameError::InvalidCommand {
+                msg: format!("No fare set from {} to {}", origin_id, destination_id),
+            });
+        }
+        Ok(())
+    }
+
+    /// Complete the charters carried by `plane_id` whose destination is `airport_id`.
+    ///
+    /// Groups arriving within their window pay out; late ones leave without paying.
+    fn settle_charters(&mut self, plane_id: usize, airport_id: AirportId) {
+        let now = self.time;
+        let (arrived, rest): (Vec<Charter>, Vec<Charter>) = std::mem::take(&mut self.charters)
+            .into_iter()
+            .partition(|c| {
+                c.destination_id == airport_id && c.booking.is_some_and(|b| b.plane == plane_id)
+            });
+        self.charters = rest;
+
+        for charter in arrived {
+            if let Some(idx) = self.plane_index(plane_id) {
+                self.airplanes[idx].deboard_charter(charter.passengers);
+            }
+            let message = if charter.is_overdue(now) {
+                format!(
+                    "Charter {}: {} passengers arrived late at {}; no payment",
+                    charter.id,
+                    charter.passengers,
+                    self.airport_name(airport_id)
+                )
+            } else {
+                self.player.cash += charter.payout;
+                self.daily_income += charter.payout;
+                format!(
+                    "Charter {}: {} passengers arrived at {}, paid ${:.2}",
+                    charter.id,
+                    charter.passengers,
+                    self.airport_name(airport_id),
+                    charter.payout
+                )
+            };
+            println!("{}", message);
+            self.log.push(message);
+        }
+    }
+
+    /// Accept a charter request and board its group onto a plane at the origin.
+    ///
+    /// The group takes its own seats next to any ticketed passengers and stays aboard
+    /// until the plane lands at the destination. The arrival window starts now.
+    ///
+    /// Parameters
+    /// - `charter_id`: Charter on offer.
+    /// - `plane_id`: Parked plane at the charter's origin.
+    ///
+    /// Returns
+    /// - `Ok(())` if the group boarded.
+    /// - `Err(GameError)`: If the charter is not on offer, the plane is elsewhere or not
+    ///   parked, or it lacks free seats.
+    pub fn accept_charter(&mut self, charter_id: usize, plane_id: usize) -> Result<(), GameError> {
+        let now = self.time;
+        let charter_idx = self
+            .charters
+            .iter()
+            .position(|c| c.id == charter_id && c.is_open(now))
+            .ok_or(GameError::CharterIdInvalid { id: charter_id })?;
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let charter = &self.charters[charter_idx];
+        if airport_id != charter.origin_id {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} is not at airport {}, the origin of charter {}",
+                    plane_id, charter.origin_id, charter_id
+                ),
+            });
+        }
+        let plane = &mut self.airplanes[plane_idx];
+        if plane.status != AirplaneStatus::Parked {
+            return Err(GameError::PlaneNotReady {
+                plane_state: plane.status.clone(),
+            });
+        }
+        let before = plane.payload_weight();
+        plane.board_charter(charter.passengers)?;
+        let moved = plane.payload_weight() - before;
+
+        let charter = &mut self.charters[charter_idx];
+        charter.booking = Some(CharterBooking {
+            plane: plane_id,
+            due_at: now + charter.window_hours,
+        });
+        let origin_id = charter.origin_id;
+        self.finish_cargo_handling(plane_idx, origin_id, GroundTask::Loading, moved);
+        Ok(())
+    }
+
+    /// Withdraw expired used-plane listings and draw new ones until `market_settings.count`
+    /// are on offer.
+    fn refresh_used_market(&mut self) {
+        let now = self.time;
+        self.used_market.retain(|l| !l.is_expired(now));
+
+        let airports = self.map.order_airport_infos();
+        let mut catalog: Vec<(String, AirplaneSpecs)> = self
+            .model_catalog
+            .iter()
+            .map(|(name, specs)| (name.clone(), *specs))
+            .collect();
+        catalog.sort_by(|a, b| a.0.cmp(&b.0));
+
+        while self.used_market.len() < self.market_settings.count {
+            let id = self.next_listing_id;
+            let seed = self
+                .seed
+                .wrapping_add(id as u64)
+                .wrapping_add(now)
+                .wrapping_add(47);
+            let Some(listing) =
+                UsedListing::generate(seed, id, &airports, &catalog, &self.market_settings, now)
+            else {
+                break;
+            };
+            self.next_listing_id += 1;
+            self.used_market.push(listing);
+        }
+    }
+
+    /// Count a delivered contract load and pay the completion bonus when it finishes.
+    fn record_contract_delivery(&mut self, contract_id: usize, weight: f32) {
+        let Some(idx) = self.contracts.iter().position(|c| c.id == contract_id) else {
+            return;
+        };
+        if self.contracts[idx].record_delivery(weight) {
+            let contract = self.contracts.remove(idx);
+            println!(
+                "Contract {} complete, bonus ${:.2}",
+                contract.id, contract.completion_bonus
+            );
+            self.player.cash += contract.completion_bonus;
+            self.daily_income += contract.completion_bonus;
+        }
+    }
+
+    /// Load `weight` kg of a contract's cargo onto a plane parked at the contract origin.
+    ///
+    /// The load becomes a regular order paid at the contract rate on delivery.
+    ///
+    /// Parameters
+    /// - `contract_id`: Open contract to haul for.
+    /// - `weight`: Weight in kg to load.
+    /// - `plane_id`: Plane parked at the contract's origin airport.
+    ///
+    /// Returns
+    /// - `Ok(order_id)`: Id of the order created for this load.
+    /// - `Err(GameError)`: If the contract doesn't exist or expired, the weight is not
+    ///   between zero and the remaining weight, the plane is elsewhere, or loading fails.
+    pub fn load_contract_cargo(
+        &mut self,
+        contract_id: usize,
+        weight: f32,
+        plane_id: usize,
+    ) -> Result<usize, GameError> {
+        let contract = self
+            .contracts
+            .iter()
+            .find(|c| c.id == contract_id)
+            .cloned()
+            .ok_or(GameError::ContractIdInvalid { id: contract_id })?;
+        if contract.is_expired(self.time) {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Contract {} has expired", contract_id),
+            });
+        }
+        if !(weight > 0.0 && weight <= contract.remaining_weight()) {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Load must be between 0 and {:.0}kg for contract {}",
+                    contract.remaining_weight(),
+                    contract_id
+                ),
+            });
+        }
+        let (_, airport_id) = self.plane_and_airport(plane_id)?;
+        if airport_id != contract.origin_id {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} is not at airport {}, the origin of contract {}",
+                    plane_id, contract.origin_id, contract_id
+                ),
+            });
+        }
+
+        let order_id = self.map.allocate_order_id();
+        self.map
+            .airport_mut(airport_id)
+            .expect(ON_MAP)
+            .orders
+            .push(Order {
+                id: order_id,
+                payload: OrderPayload::Cargo {
+                    cargo_type: contract.cargo_type,
+                    weight,
+                },
+                value: (contract.rate_per_kg * weight).round(),
+                deadline: contract.expires_at.saturating_sub(self.time).max(1),
+                origin_id: contract.origin_id,
+                destination_id: contract.destination_id,
+                tier: OrderTier::Standard,
+                damage: 0.0,
+                shipment: None,
+                contract: Some(contract_id),
+                spare_parts: 0,
+                rare: None,
+                pallet: Vec::new(),
+            });
+
+        if let Err(e) = self.load_order(order_id, plane_id) {
+            self.map
+                .airport_mut(airport_id)
+                .expect(ON_MAP)
+                .orders
+                .retain(|o| o.id != order_id);
+            return Err(e);
+        }
+        Ok(order_id)
+    }
+
+    /// Print every open contract with its progress.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_contracts(&self) {
+        if self.contracts.is_empty() {
+            println!("No contracts on offer");
+            return;
+        }
+        for c in &self.contracts {
+            println!(
+                "[{}] {:?} {} -> {} | delivered: {:.0}/{:.0}kg | rate: ${:.2}/kg | bonus: ${:.2} | expires in: {}",
+                c.id,
+                c.cargo_type,
+                c.origin_id,
+                c.destination_id,
+                c.delivered_weight,
+                c.total_weight,
+                c.rate_per_kg,
+                c.completion_bonus,
+                self.days_and_hours(c.expires_at.saturating_sub(self.time)),
+            );
+        }
+    }
+
+    /// Print every plane in a group.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_group(&self, group: &str) -> Result<(), GameError> {
+        let members = self.group_planes(group)?;
+        println!("Group {} ({} planes):", group.to_lowercase(), members.len());
+        for plane_id in members {
+            self.list_airplane(plane_id)?;
+        }
+        Ok(())
+    }
+
+    /// Print the world-wide order board, best paying per kg first.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_all_orders(&self, filter: &OrderBoardFilter) {
+        let orders = self.find_orders(filter);
+        if orders.is_empty() {
+            println!("No orders match.");
+            return;
+        }
+        println!("Orders ({} matching):", orders.len());
+        for order in orders {
+            let payload_info = match &order.payload {
+                OrderPayload::Cargo { cargo_type, weight } => {
+                    format!("{:?} | weight: {:.1}kg", cargo_type, weight)
+                }
+                OrderPayload::Passengers { count } => format!("Passengers | count: {}", count),
+            };
+            println!(
+                "  [{}] {} -> {}{} | {} | value: ${:.2} (${:.2}/kg) | deadline: {}{}",
+                order.id,
+                self.airport_name(order.origin_id),
+                self.airport_name(order.destination_id),
+                self.country_label(order.destination_id),
+                payload_info,
+                order.value,
+                order.value_per_kg(),
+                self.days_and_hours(order.deadline),
+                if self.reserved_orders.contains(&order.id) {
+                    " | reserved"
+                } else {
+                    ""
+                }
+            );
+        }
+    }
+
+    /// Print every charter on offer and every group currently aboard a plane.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_charters(&self) {
+        if self.charters.is_empty() {
+            println!("No charters on offer");
+            return;
+        }
+        for c in &self.charters {
+            let state = match c.booking {
+                Some(booking) => format!(
+                    "aboard plane {} | due in: {}",
+                    booking.plane,
+                    self.days_and_hours(booking.due_at.saturating_sub(self.time))
+                ),
+                None => format!(
+                    "window: {}h | offer ends in: {}",
+                    c.window_hours,
+                    self.days_and_hours(c.offer_expires_at.saturating_sub(self.time))
+                ),
+            };
+            println!(
+                "[{}] {} passengers {} -> {} | pays: ${:.2} | {}",
+                c.id,
+                c.passengers,
+                self.airport_name(c.origin_id),
+                self.airport_name(c.destination_id),
+                c.payout,
+                state,
+            );
+        }
+    }
+
+    /// Print every lane deal on offer and every lane running.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_lanes(&self) {
+        if self.lane_deals.is_empty() {
+            println!("No lane deals on offer");
+            return;
+        }
+        for d in &self.lane_deals {
+            let forwarder = self
+                .forwarders
+                .iter()
+                .find(|f| f.id == d.forwarder)
+                .map_or("Unknown", |f| f.name.as_str());
+            let state = match d.accepted_at {
+                Some(_) => format!("running | loads: {}/{}", d.loads_issued, d.total_loads()),
+                None => format!(
+                    "{} weeks | offer ends in: {}",
+                    d.weeks,
+                    self.days_and_hours(d.offer_expires_at.saturating_sub(self.time))
+                ),
+            };
+            println!(
+                "[{}] {} | {} loads/week of {:.0}kg {:?} {} -> {} | ${:.2}/kg (${:.2} a load) | {}",
+                d.id,
+                forwarder,
+                d.loads_per_week,
+                d.load_weight,
+                d.cargo_type,
+                self.airport_name(d.origin_id),
+                self.airport_name(d.destination_id),
+                d.rate_per_kg,
+                d.load_value(),
+                state,
+            );
+        }
+    }
+
+    /// Print every auction open for bids.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_auctions(&self) {
+        if self.auctions.is_empty() {
+            println!("No auctions open");
+            return;
+        }
+        for a in &self.auctions {
+            println!(
+                "[{}] {:.0}kg {:?} {} -> {} | due within: {} | going rate: ${:.2} | your bid: {} | closes in: {}",
+                a.id,
+                a.weight,
+                a.cargo_type,
+                self.airport_name(a.origin_id),
+                self.airport_name(a.destination_id),
+                self.days_and_hours(a.deadline),
+                a.reference_value,
+                a.player_bid
+                    .map_or("none".to_string(), |bid| format!("${:.2}", bid)),
+                self.days_and_hours(a.closes_at.saturating_sub(self.time)),
+            );
+        }
+    }
+
+    /// Print today's passenger demand on every route leaving an airport, busiest first.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_demand(&self, airport_id: usize) -> Result<(), GameError> {
+        if !self.map.airports.contains(airport_id) {
+            return Err(GameError::AirportIdInvalid { id: airport_id });
+        }
+        let mut routes: Vec<_> = self.passenger_demand.from_airport(airport_id).collect();
+        if routes.is_empty() {
+            println!("No passenger demand from {}", self.airport_name(airport_id));
+            return Ok(());
+        }
+        routes.sort_by(|a, b| b.passengers.cmp(&a.passengers));
+        println!(
+            "Passenger demand from {} (day {}):",
+            self.airport_name(airport_id),
+            self.passenger_demand.day
+        );
+        for route in routes {
+            println!(
+                "  -> [{}] {} | {} passengers/day",
+                route.destination_id,
+                self.airport_name(route.destination_id),
+                route.passengers
+            );
+        }
+        Ok(())
+    }
+
+    /// Print every scheduled route with its fare, today's sales and takings.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_fares(&self) {
+        if self.route_fares.is_empty() {
+            println!("No scheduled routes");
+            return;
+        }
+        for route in &self.route_fares {
+            let reference = self
+                .reference_fare(route.origin_id, route.destination_id)
+                .unwrap_or(0.0);
+            let left = self
+                .route_tickets_left(route.origin_id, route.destination_id)
+                .unwrap_or(0);
+            println!(
+                "{} -> {} | fare: ${:.2} (reference ${:.2}) | sold today: {} | left today: {} | departures: {} | last load: {:.0}% | revenue: ${:.2}",
+                self.airport_name(route.origin_id),
+                self.airport_name(route.destination_id),
+                route.fare,
+                reference,
+                route.sold_today,
+                left,
+                route.departures,
+                route.last_load_factor * 100.0,
+                route.revenue,
+            );
+        }
+    }
+
+    /// Print every group with its member planes.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_groups(&self) {
+        if self.player.groups.is_empty() {
+            println!("No plane groups");
+            return;
+        }
+        for (name, members) in &self.player.groups {
+            let planes: Vec<String> = members
+                .iter()
+                .filter_map(|id| self.airplanes.iter().find(|p| p.id == *id))
+                .map(|p| format!("{} ({})", p.id, p.name))
+                .collect();
+            println!("{}: {}", name, planes.join(", "));
+        }
+    }
+
+    /// Print every used plane on offer.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_used_market(&self) {
+        if self.used_market.is_empty() {
+            println!("No used planes for sale");
+            return;
+        }
+        for l in &self.used_market {
+            println!(
+                "[{}] {} at airport {} | airframe: {}h | since maintenance: {}h{} | price: ${:.2} ({:.0}% off) | withdrawn in: {}",
+                l.id,
+                l.model,
+                l.airport_id,
+                l.airframe_hours,
+                l.hours_since_maintenance,
+                if l.needs_maintenance {
+                    " | needs repair"
+                } else {
+                    ""
+                },
+                l.price,
+                l.discount * 100.0,
+                self.days_and_hours(l.expires_at.saturating_sub(self.time)),
+            );
+        }
+    }
+
+    /// Drop split shipments whose parts no longer exist anywhere in the world.
+    fn prune_split_shipments(&mut self) {
+        if self.split_shipments.is_empty() {
+            return;
+        }
+        let airport_orders = self.map.airports.iter().flat_map(|(a, _)| a.orders.iter());
+        let plane_orders = self.airplanes.iter().flat_map(|p| p.manifest.iter());
+        let live: HashSet<usize> = airport_orders
+            .chain(plane_orders)
+            .filter_map(|o| o.shipment)
+            .collect();
+        self.split_shipments.retain(|id, _| live.contains(id));
+    }
+
+    /// Settle an order that was just taken off a plane at `airport_id`.
+    ///
+    /// Orders at their destination are paid out (minus customs when they crossed a border);
+    /// anything else is stored at the airport for a later pickup.
+    fn handle_unloaded(&mut self, delivery: Order, airport_id: usize) {
+        // pallets are broken down at their destination and each order settled on its own
+        if delivery.is_pallet() && delivery.destination_id == airport_id {
+            println!(
+                "Pallet {} broken down at airport {}",
+                delivery.id, airport_id
+            );
+            for order in delivery.pallet {
+                self.handle_unloaded(order, airport_id);
+            }
+            return;
+        }
+        // spare parts restock the airport instead of paying out
+        if delivery.destination_id == airport_id && delivery.spare_parts > 0 {
+            println!(
+                "Delivered {} spare-part kits to airport {}",
+                delivery.spare_parts, airport_id
+            );
+            self.map.airport_mut(airport_id).expect(ON_MAP).spare_parts += delivery.spare_parts;
+        }
+        // reached the destination and before deadline
+        else if delivery.destination_id == airport_id {
+            let contract_expired = delivery.contract.is_some_and(|id| {
+                self.contracts
+                    .iter()
+                    .find(|c| c.id == id)
+                    .is_none_or(|c| c.is_expired(self.time))
+            });
+            let on_time = delivery.deadline != 0 && !contract_expired;
+            if on_time {
+                let (payout, effects) =
+                    self.plugins
+                        .on_delivery(&delivery, airport_id, delivery.payout());
+                self.apply_plugin_effects(effects);
+                if delivery.damage > 0.0 {
+                    println!(
+                        "Successfully delivered order {} ({:.0}% damaged)",
+                        delivery.id,
+                        delivery.damage * 100.0
+                    );
+                } else {
+                    println!("Successfully delivered order {}", delivery.id);
+                }
+                self.player.cash += payout;
+                self.daily_income += payout;
+                self.player.record_delivery();
+                if let Some(weight) = delivery.cargo_weight() {
+                    self.map
+                        .lane_market
+                        .record_delivery(delivery.origin_id, airport_id, weight);
+                }
+                self.notify(GameEvent::Delivered {
+                    time: self.time,
+                    order: delivery.id,
+                    airport: airport_id,
+                    payout,
+                });
+                self.run_scripts(ScriptHook::Delivery {
+                    order: delivery.id,
+                    airport: airport_id,
+                    payout,
+                });
+
+                if self.map.is_cross_border(delivery.origin_id, airport_id) {
+                    let customs = payout * self.borders.customs_rate;
+                    if customs > 0.0 {
+                        println!("Customs charged on order {}: ${:.2}", delivery.id, customs);
+                        self.player.cash -= customs;
+                        self.daily_expenses += customs;
+                        self.daily_fees += customs;
+                    }
+                }
+            } else {
+                println!("Order {}: Deadline expired", delivery.id)
+            }
+
+            if let Some(shipment_id) = delivery.shipment {
+                self.settle_split_part(shipment_id, on_time);
+            }
+            if let (Some(contract_id), true) = (delivery.contract, on_time) {
+                self.record_contract_delivery(contract_id, delivery.cargo_weight().unwrap_or(0.0));
+            }
+        }
+        // not the destination so it goes into the stock at the airport
+        else {
+            println!(
+                "Order {} being stored at airport {}",
+                delivery.id, airport_id
+            );
+            self.staged_orders.insert(delivery.id, self.time);
+            self.map
+                .airport_mut(airport_id)
+                .expect(ON_MAP)
+                .orders
+                .push(delivery);
+        }
+    }
+
+    /// Unload all orders from the plane.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane ID.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked at an airport.
+    pub fn unload_all(&mut self, plane_id: usize) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let before = self.airplanes[plane_idx].handling_weight();
+
+        let mut deliveries = self.airplanes[plane_idx].unload_all();
+        let moved = before - self.airplanes[plane_idx].handling_weight();
+
+        // Check deliveries
+        for delivery in deliveries.drain(..) {
+            self.handle_unloaded(delivery, airport_id);
+        }
+
+        self.finish_cargo_handling(plane_idx, airport_id, GroundTask::Unloading, moved);
+
+        Ok(())
+    }
+
+    /// Unload a list of orders from a plane.
+    ///
+    /// Parameters
+    /// - `order_id`: A list of order IDs to unload.
+    /// - `plane_id`: Plane ID.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked at an airport.
+    pub fn unload_orders(
+        &mut self,
+        order_id: Vec<usize>,
+        plane_id: usize,
+    ) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let before = self.airplanes[plane_idx].handling_weight();
+
+        for order in order_id {
+            let delivery = self.airplanes[plane_idx].unload_order(order)?;
+            self.handle_unloaded(delivery, airport_id);
+        }
+        let moved = before - self.airplanes[plane_idx].handling_weight();
+        self.finish_cargo_handling(plane_idx, airport_id, GroundTask::Unloading, moved);
+
+        Ok(())
+    }
+
+    /// Unload a specific order from a plane.
+    ///
+    /// Parameters
+    /// - `order_id`: Order ID to unload.
+    /// - `plane_id`: Plane ID.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: If the plane doesn't exist or isn't parked.
+    pub fn unload_order(&mut self, order_id: usize, plane_id: usize) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let before = self.airplanes[plane_idx].handling_weight();
+
+        let delivery = self.airplanes[plane_idx].unload_order(order_id)?;
+        let moved = before - self.airplanes[plane_idx].handling_weight();
+        self.handle_unloaded(delivery, airport_id);
+
+        self.finish_cargo_handling(plane_idx, airport_id, GroundTask::Unloading, moved);
+
+        Ok(())
+    }
+
+    /// Move an order straight from one plane to another parked at the same airport.
+    ///
+    /// The load crosses the ramp once, so the job takes half the usual handling work
+    /// and keeps both planes busy until it is done.
+    ///
+    /// Parameters
+    /// - `order_id`: Order on the first plane's manifest.
+    /// - `from_plane`: Plane carrying the order.
+    /// - `to_plane`: Plane taking it over.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: If either plane doesn't exist or isn't at an airport, they are
+    ///   at different airports, the order isn't aboard, or the second plane can't take it.
+    pub fn transfer_order(
+        &mut self,
+        order_id: usize,
+        from_plane: usize,
+        to_plane: usize,
+    ) -> Result<(), GameError> {
+        if from_plane == to_plane {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Order {} is already on plane {}", order_id, to_plane),
+            });
+        }
+        let (from_idx, airport_id) = self.plane_and_airport(from_plane)?;
+        let (to_idx, to_airport) = self.plane_and_airport(to_plane)?;
+        if airport_id != to_airport {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Planes {} and {} are not at the same airport",
+                    from_plane, to_plane
+                ),
+            });
+        }
+        let order = self.airplanes[from_idx]
+            .manifest
+            .iter()
+            .find(|o| o.id == order_id)
+            .ok_or(GameError::OrderIdInvalid { id: order_id })?;
+        self.airplanes[to_idx].validate_payload(order)?;
+
+        let order = self.airplanes[from_idx].unload_order(order_id)?;
+        let moved = order.handling_weight() * TRANSFER_HANDLING_SHARE;
+        self.airplanes[to_idx].load_order(order)?;
+
+        let ready = self
+            .handling_done_at(from_idx, airport_id, moved)
+            .max(self.handling_done_at(to_idx, airport_id, moved));
+        self.start_ground_task(from_idx, GroundTask::Unloading, ready);
+        self.start_ground_task(to_idx, GroundTask::Loading, ready);
+
+        Ok(())
+    }
+
+    /// Depart a plane to another airport.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane ID to dispatch.
+    /// - `destination_id`: Destination airport ID.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: Invalid IDs, not parked, insufficient fuel, or runway issues.
+    pub fn depart_plane(
+        &mut self,
+        plane_id: usize,
+        destination_id: usize,
+    ) -> Result<(), GameError> {
+        self.depart_plane_with_speed(plane_id, destination_id, CruiseRegime::Normal)
+    }
+
+    /// Depart a plane to another airport at the given speed setting.
+    ///
+    /// Economy flights take longer but burn less fuel per km; dash flights arrive
+    /// sooner at a higher burn. Range checks use the chosen setting.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane ID to dispatch.
+    /// - `destination_id`: Destination airport ID.
+    /// - `regime`: Speed setting for this flight.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: Invalid IDs, not parked, insufficient fuel, or runway issues.
+    pub fn depart_plane_with_speed(
+        &mut self,
+        plane_id: usize,
+        destination_id: usize,
+        regime: CruiseRegime,
+    ) -> Result<(), GameError> {
+        let (plane_idx, origin_id) = self.plane_and_airport(plane_id)?;
+        let planes_on_ground = self.planes_on_ground(origin_id);
+        let plane = &mut self.airplanes[plane_idx];
+
+        if plane.on_hold {
+            return Err(GameError::PlaneOnHold { plane_id });
+        }
+        if let Some(check) = self.maintenance_settings.overdue(&plane.check_hours) {
+            return Err(GameError::CheckOverdue { plane_id, check });
+        }
+        // Guard rail: only depart when parked
+        if !matches!(plane.status, AirplaneStatus::Parked) {
+            return Err(GameError::PlaneNotReady {
+                plane_state: plane.status.clone(),
+            });
+        }
+        let (dest_airport, dest_coords) = &self
+            .map
+            .airports
+            .iter()
+            .find(|(a, _)| a.id == destination_id)
+            .ok_or(GameError::AirportIdInvalid { id: destination_id })?;
+
+        // consume fuel & get flight_hours
+        // check before if we can get there, else we don't charge
+        let distance = plane.distance_to(dest_coords);
+        plane.cruise = regime;
+
+        let pilot_idx = if self.pilot_settings.required {
+            let planned_hours = (distance / plane.cruise_speed()).ceil() as GameTime;
+            match select_pilot(
+                &self.player.pilots,
+                plane_id,
+                origin_id,
+                planned_hours,
+                self.time,
+                &self.pilot_settings,
+            ) {
+                Some(idx) => Some(idx),
+                None => {
+                    plane.cruise = CruiseRegime::Normal;
+                    return Err(GameError::NoRestedPilot { plane_id });
+                }
+            }
+        } else {
+            None
+        };
+        // scheduled-route passengers buy the free seats, up to what is left of today's demand
+        let route_idx = self
+            .route_fares
+            .iter()
+            .position(|r| r.origin_id == origin_id && r.destination_id == destination_id);
+        if let Some(route) = route_idx.map(|idx| &self.route_fares[idx]) {
+            let reference = distance * self.map.demand_params.passengers.fare_per_km;
+            let demand = priced_demand(
+                self.passenger_demand.passengers(origin_id, destination_id),
+                route.fare,
+                reference,
+                self.demand_settings.price_elasticity,
+            );
+            plane.scheduled_passengers = demand
+                .saturating_sub(route.sold_today)
+                .min(plane.free_seats());
+        }
+
+        let fuel_before = plane.current_fuel;
+        let flight_hours = match plane.consume_flight_fuel(dest_airport, dest_coords) {
+            Ok(hours) => hours,
+            Err(e) => {
+                plane.cruise = CruiseRegime::Normal;
+                plane.scheduled_passengers = 0;
+                return Err(e);
+            }
+        };
+        let origin_coord = plane.location;
+
+        // the chosen pilot takes over the plane and starts the leg
+        if let Some(idx) = pilot_idx {
+            let model = format!("{:?}", plane.model);
+            let pilot = &self.player.pilots[idx];
+            let saving = pilot.fuel_saving(&model, origin_id, destination_id, &self.pilot_settings);
+            plane.current_fuel += (fuel_before - plane.current_fuel) * saving;
+
+            let rest_hours = self.pilot_settings.rest_hours;
+            for (i, pilot) in self.player.pilots.iter_mut().enumerate() {
+                if i == idx {
+                    pilot.duty_hours = pilot.duty_hours_at(self.time, rest_hours);
+                    pilot.log_departure(origin_id, destination_id);
+                    pilot.plane = Some(plane_id);
+                } else if pilot.plane == Some(plane_id) {
+                    pilot.plane = None;
+                }
+            }
+        }
+
+        self.emissions
+            .record_use(&plane.specs, fuel_before - plane.current_fuel);
+
+        // crossing into another country
+        if self.map.is_cross_border(origin_id, destination_id) {
+            let overflight_fee = distance * self.borders.overflight_fee_per_km;
+            self.player.cash -= overflight_fee;
+            self.daily_expenses += overflight_fee;
+            self.daily_fees += overflight_fee;
+        }
+
+        // charge parking
+        let parked_since = *self.arrival_times.get(&plane_id).unwrap_or(&self.time);
         let parked_hours = (self.time - parked_since) as f32;
-        let parking_fee = self.map.airports[origin_idx].0.parking_fee * parked_hours;
+        let origin = self.map.airport(origin_id).expect(ON_MAP);
+        // once the stands are full, extra planes are towed to pricier remote stands
+        let stand_multiplier = if planes_on_ground > origin.parking_stands() {
+            REMOTE_STAND_MULTIPLIER
+        } else {
+            1.0
+        };
+        let parking_fee = origin.parking_fee
+            * stand_multiplier
+            * parked_hours
+            * self
+                .tax_settings
+                .fee_multiplier(self.map.country_of(origin_id))
+            * self
+                .loyalty
+                .fee_factor(origin_id, self.time, &self.loyalty_settings);
+        self.loyalty
+            .record(origin_id, self.time, self.loyalty_settings.window_hours);
         self.player.cash -= parking_fee;
         self.daily_expenses += parking_fee;
+        self.daily_fees += parking_fee;
+
+        if let Some(idx) = route_idx {
+            let tickets = plane.scheduled_passengers;
+            let route = &mut self.route_fares[idx];
+            let revenue = tickets as f32 * route.fare;
+            route.sold_today += tickets;
+            route.departures += 1;
+            route.revenue += revenue;
+            route.last_load_factor = load_factor(tickets, plane.specs.passenger_capacity);
+            self.player.cash += revenue;
+            self.daily_income += revenue;
+        }
+
+        // set the status (no location change here!)
+        plane.status = AirplaneStatus::InTransit {
+            hours_remaining: flight_hours,
+            destination: destination_id,
+            origin: origin_coord,
+            total_hours: flight_hours,
+        };
+
+        // kick off the first hourly tick
+        self.schedule(self.time + 1, Event::FlightProgress { plane: plane_id });
+        self.notify(GameEvent::Departed {
+            time: self.time,
+            plane: plane_id,
+            origin: origin_id,
+            destination: destination_id,
+        });
+
+        Ok(())
+    }
+
+    /// Hold a plane on the ground until it is released.
+    ///
+    /// A held plane can still be loaded, refueled and serviced, but refuses to depart.
+    /// Holding a plane that is already held does nothing.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to hold.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane is held.
+    /// - `Err(GameError)`: If the plane doesn't exist or is in flight.
+    pub fn hold_plane(&mut self, plane_id: usize) -> Result<(), GameError> {
+        let plane = self
+            .airplanes
+            .iter()
+            .find(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+        if matches!(plane.status, AirplaneStatus::InTransit { .. }) {
+            return Err(GameError::PlaneNotReady {
+                plane_state: plane.status.clone(),
+            });
+        }
+        self.set_on_hold(plane_id, true);
+        Ok(())
+    }
+
+    /// Release a held plane so it can depart again.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane was released.
+    /// - `Err(GameError)`: If the plane doesn't exist or isn't held.
+    pub fn release_plane(&mut self, plane_id: usize) -> Result<(), GameError> {
+        let plane = self
+            .airplanes
+            .iter()
+            .find(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+        if !plane.on_hold {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} is not on hold", plane_id),
+            });
+        }
+        self.set_on_hold(plane_id, false);
+        Ok(())
+    }
+
+    /// Add the day's profit to the tax period and, on the period's last day, pay the tax.
+    ///
+    /// Losses carry over into the next period; the tax is only due on a positive balance.
+    ///
+    /// Returns
+    /// - `f32`: Tax paid today, already added to the day's expenses.
+    fn assess_profit_tax(&mut self, day: u64) -> f32 {
+        let settings = &self.tax_settings;
+        if settings.profit_tax_rate <= 0.0 {
+            return 0.0;
+        }
+        self.taxable_profit += self.daily_income - self.daily_expenses;
+        if day % settings.period_days.max(1) != 0 || self.taxable_profit <= 0.0 {
+            return 0.0;
+        }
+
+        let profit = self.taxable_profit;
+        let tax = profit * settings.profit_tax_rate;
+        self.player.cash -= tax;
+        self.daily_expenses += tax;
+        self.taxable_profit = 0.0;
+        let message = format!(
+            "Day {}: paid ${:.2} profit tax on ${:.2} profit",
+            day, tax, profit
+        );
+        println!("{}", message);
+        self.log.push(message);
+        tax
+    }
+
+    /// Warn subscribers once cash sinks past the overdraft's warning level.
+    ///
+    /// The warning is sent again only after cash has recovered above that level.
+    fn check_overdraft(&mut self) {
+        let settings = &self.overdraft_settings;
+        let warning_level = -settings.limit * settings.warning_share;
+        let overdrawn = self.player.cash < 0.0 && self.player.cash <= warning_level;
+        if !overdrawn {
+            self.overdraft_warned = false;
+            return;
+        }
+        if self.overdraft_warned {
+            return;
+        }
+        self.overdraft_warned = true;
+        let limit = settings.limit;
+        let message = format!(
+            "Overdraft warning: cash at ${:.2} of a ${:.2} limit; purchases and refuels stop at the limit",
+            self.player.cash, limit
+        );
+        println!("{}", message);
+        self.log.push(message);
+        self.notify(GameEvent::OverdraftWarning {
+            time: self.time,
+            balance: self.player.cash,
+            limit,
+        });
+    }
+
+    /// Sum the daily reports of the month that just ended into a statement.
+    ///
+    /// Runs with the daily report written at midnight on the 1st, which covers the month's last day.
+    fn close_month(&mut self) {
+        let month = Date::from_time(self.time - 1).month_start();
+        let report = MonthlyReport::from_days(month, &self.stats_since(month));
+        let message = format!(
+            "{} {} closed: revenue ${:.2}, net ${:.2}",
+            report.date().month_name(),
+            report.year,
+            report.revenue,
+            report.net
+        );
+        println!("{}", message);
+        self.log.push(message);
+        self.monthly_reports.push(report.clone());
+        self.notify(GameEvent::MonthlyReport(report));
+
+        if month.month % 3 == 0 {
+            let quarter = Date {
+                month: month.month - 2,
+                ..month
+            };
+            let statement =
+                QuarterlyStatement::new(quarter, &self.stats_since(quarter), &self.valuation());
+            self.quarterly_statements.push(statement);
+        }
+    }
+
+    /// Daily reports covering the days from `start` up to now.
+    ///
+    /// A report is written at midnight and covers the day before it.
+    fn stats_since(&self, start: Date) -> Vec<DailyStats> {
+        let first_day = start.start() / 24;
+        let last_day = self.time / 24;
+        self.stats
+            .iter()
+            .filter(|s| s.day > first_day && s.day <= last_day)
+            .cloned()
+            .collect()
+    }
+
+    /// Add a ground task finishing at `ready` to a plane's turnaround and schedule its end.
+    ///
+    /// Planes flown by a pilot with enough hours on the model finish an hour early.
+    fn start_ground_task(&mut self, plane_idx: usize, task: GroundTask, ready: GameTime) {
+        let ready = if self.quick_turnaround(plane_idx) {
+            ready.saturating_sub(1).max(self.time)
+        } else {
+            ready
+        };
+        let plane = &mut self.airplanes[plane_idx];
+        plane.start_task(task, ready, self.time);
+        if ready > self.time {
+            let plane = plane.id;
+            let event = match task {
+                GroundTask::Refueling => Event::RefuelComplete { plane },
+                GroundTask::Charging => Event::ChargeComplete { plane },
+                GroundTask::Loading | GroundTask::Unloading => Event::LoadingEvent { plane },
+            };
+            self.schedule(ready, event);
+        }
+    }
+
+    /// Return `true` if the plane's pilot knows its model well enough to skip an hour of
+    /// ground handling.
+    fn quick_turnaround(&self, plane_idx: usize) -> bool {
+        let plane_id = self.airplanes[plane_idx].id;
+        let model = format!("{:?}", self.airplanes[plane_idx].model);
+        self.pilot_settings.required
+            && self.player.pilots.iter().any(|p| {
+                p.plane == Some(plane_id) && p.quick_turnaround(&model, &self.pilot_settings)
+            })
+    }
+
+    /// Keep a plane busy while `moved_kg` of cargo and passengers are handled at an
+    /// airport.
+    ///
+    /// Handling time grows with the tonnage and shrinks with the airport's size, with a
+    /// minimum of `handling_settings.min_hours`. Handling queued on the plane carries on
+    /// from where the previous job ends; refueling runs alongside it.
+    fn finish_cargo_handling(
+        &mut self,
+        plane_idx: usize,
+        airport_id: AirportId,
+        task: GroundTask,
+        moved_kg: f32,
+    ) {
+        let ready = self.handling_done_at(plane_idx, airport_id, moved_kg);
+        self.start_ground_task(plane_idx, task, ready);
+    }
+
+    /// Game time a handling job moving `moved_kg` at an airport would finish, once the
+    /// plane's ramp crew is free.
+    fn handling_done_at(&self, plane_idx: usize, airport_id: AirportId, moved_kg: f32) -> GameTime {
+        let airport = self.map.airport(airport_id).expect(ON_MAP);
+        let crew_bonus = 1.0 + self.handling_settings.crew_rate_bonus * airport.ground_crew as f32;
+        let rate = self.handling_settings.tons_per_hour
+            * airport.size().max(MIN_HANDLING_SIZE)
+            * crew_bonus;
+        let work = (moved_kg.abs() / 1_000.0 / rate).round() as GameTime;
+        let min_hours = self
+            .handling_settings
+            .min_hours
+            .saturating_sub(self.crew_hours_saved(airport_id));
+
+        let start = self.airplanes[plane_idx].ramp_free_at(self.time);
+        (start + work).max(self.time + min_hours)
+    }
+
+    /// Hours ground crew at `airport_id` take off refueling and the shortest handling job.
+    fn crew_hours_saved(&self, airport_id: AirportId) -> GameTime {
+        let staffed = self
+            .map
+            .airport(airport_id)
+            .is_some_and(|airport| airport.ground_crew > 0);
+        GameTime::from(staffed)
+    }
+
+    /// Give every plane in the starting fleet its own pilot.
+    fn crew_starting_fleet(&mut self) {
+        if !self.pilot_settings.required {
+            return;
+        }
+        for plane_idx in 0..self.airplanes.len() {
+            let Ok((_, airport_id)) = self.plane_and_airport(self.airplanes[plane_idx].id) else {
+                continue;
+            };
+            let mut pilot = Pilot::new(self.player.next_pilot_id, airport_id);
+            pilot.plane = Some(self.airplanes[plane_idx].id);
+            self.player.next_pilot_id += 1;
+            self.player.pilots.push(pilot);
+        }
+    }
+
+    /// Hire a pilot who waits at an airport until assigned.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport the pilot reports to.
+    ///
+    /// Returns
+    /// - `Ok(pilot_id)`: Id of the new pilot.
+    /// - `Err(GameError)`: If the airport doesn't exist or the player can't pay the hiring fee.
+    pub fn hire_pilot(&mut self, airport_id: usize) -> Result<usize, GameError> {
+        if !self.map.airports.iter().any(|(a, _)| a.id == airport_id) {
+            return Err(GameError::AirportIdInvalid { id: airport_id });
+        }
+        let fee = self.pilot_settings.hire_fee;
+        if self.player.available_funds() < fee {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: fee,
+            });
+        }
+        self.player.cash -= fee;
+        self.daily_expenses += fee;
+
+        let id = self.player.next_pilot_id;
+        self.player.next_pilot_id += 1;
+        self.player.pilots.push(Pilot::new(id, airport_id));
+        Ok(id)
+    }
+
+    /// Let a pilot go. Pilots can't be fired in mid-air.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the pilot left the payroll.
+    /// - `Err(GameError)`: If the pilot doesn't exist or is flying.
+    pub fn fire_pilot(&mut self, pilot_id: usize) -> Result<(), GameError> {
+        let idx = self
+            .player
+            .pilots
+            .iter()
+            .position(|p| p.id == pilot_id)
+            .ok_or(GameError::PilotIdInvalid { id: pilot_id })?;
+        if let Some(plane_id) = self.player.pilots[idx].plane {
+            if self.plane_in_flight(plane_id) {
+                return Err(GameError::InvalidCommand {
+                    msg: format!("Pilot {} is flying plane {}", pilot_id, plane_id),
+                });
+            }
+        }
+        self.player.pilots.remove(idx);
+        Ok(())
+    }
+
+    /// Hire ground crew at an airport.
+    ///
+    /// Each crew member speeds up loading and unloading there by
+    /// `handling_settings.crew_rate_bonus` of the base rate; a staffed airport also refuels
+    /// and turns small jobs around an hour sooner. Crew are paid
+    /// `handling_settings.crew_wage_per_day` each at the end of every day.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport to staff.
+    /// - `count`: Crew members to hire.
+    ///
+    /// Returns
+    /// - `Ok(u32)`: Crew now employed at the airport.
+    /// - `Err(GameError)`: If the airport doesn't exist, `count` is 0 or the airport would
+    ///   exceed `handling_settings.max_crew`.
+    pub fn hire_crew(&mut self, airport_id: usize, count: u32) -> Result<u32, GameError> {
+        let max_crew = self.handling_settings.max_crew;
+        let airport = self
+            .map
+            .airport_mut(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        if count == 0 {
+            return Err(GameError::InvalidCommand {
+                msg: "Hire at least one crew member".into(),
+            });
+        }
+        if airport.ground_crew + count > max_crew {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Airport {} can employ at most {} ground crew ({} on staff)",
+                    airport_id, max_crew, airport.ground_crew
+                ),
+            });
+        }
+        airport.ground_crew += count;
+        Ok(airport.ground_crew)
+    }
+
+    /// Let ground crew at an airport go.
+    ///
+    /// Returns
+    /// - `Ok(u32)`: Crew still employed at the airport.
+    /// - `Err(GameError)`: If the airport doesn't exist or has fewer than `count` crew.
+    pub fn fire_crew(&mut self, airport_id: usize, count: u32) -> Result<u32, GameError> {
+        let airport = self
+            .map
+            .airport_mut(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        if count == 0 || count > airport.ground_crew {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Airport {} has {} ground crew on staff",
+                    airport_id, airport.ground_crew
+                ),
+            });
+        }
+        airport.ground_crew -= count;
+        Ok(airport.ground_crew)
+    }
+
+    /// Ground crew employed across all airports.
+    pub fn total_ground_crew(&self) -> u32 {
+        self.map.airports.iter().map(|(a, _)| a.ground_crew).sum()
+    }
+
+    /// Assign a pilot to a plane at the same airport.
+    ///
+    /// The plane's previous pilot stays behind at the airport, unassigned.
+    ///
+    /// Parameters
+    /// - `pilot_id`: Pilot to assign.
+    /// - `plane_id`: Plane on the ground at the pilot's airport.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the pilot now flies the plane.
+    /// - `Err(GameError)`: If either doesn't exist, one of them is flying, or they are at
+    ///   different airports.
+    pub fn assign_pilot(&mut self, pilot_id: usize, plane_id: usize) -> Result<(), GameError> {
+        let idx = self
+            .player
+            .pilots
+            .iter()
+            .position(|p| p.id == pilot_id)
+            .ok_or(GameError::PilotIdInvalid { id: pilot_id })?;
+        if self.plane_in_flight(plane_id) {
+            return Err(GameError::PlaneNotAtAirport { plane_id });
+        }
+        let (_, airport_id) = self.plane_and_airport(plane_id)?;
+        let pilot = &self.player.pilots[idx];
+        if let Some(current) = pilot.plane.filter(|p| *p != plane_id) {
+            if self.plane_in_flight(current) {
+                return Err(GameError::InvalidCommand {
+                    msg: format!("Pilot {} is flying plane {}", pilot_id, current),
+                });
+            }
+        }
+        if pilot.airport_id != airport_id {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Pilot {} is at airport {}, not at airport {} with plane {}",
+                    pilot_id, pilot.airport_id, airport_id, plane_id
+                ),
+            });
+        }
+
+        for (i, pilot) in self.player.pilots.iter_mut().enumerate() {
+            if i == idx {
+                pilot.plane = Some(plane_id);
+            } else if pilot.plane == Some(plane_id) {
+                pilot.plane = None;
+            }
+        }
+        Ok(())
+    }
+
+    fn plane_in_flight(&self, plane_id: usize) -> bool {
+        self.airplanes
+            .iter()
+            .any(|p| p.id == plane_id && matches!(p.status, AirplaneStatus::InTransit { .. }))
+    }
+
+    /// Print every pilot with their whereabouts and duty time.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_pilots(&self) {
+        if self.player.pilots.is_empty() {
+            println!("No pilots on the payroll");
+            return;
+        }
+        let settings = &self.pilot_settings;
+        for pilot in &self.player.pilots {
+            let plane = match pilot.plane {
+                Some(plane_id) if self.plane_in_flight(plane_id) => {
+                    format!("flying plane {}", plane_id)
+                }
+                Some(plane_id) => format!("plane {}", plane_id),
+                None => "unassigned".to_string(),
+            };
+            let rest_left = (pilot.off_duty_since + settings.rest_hours).saturating_sub(self.time);
+            let ratings: Vec<&str> = pilot
+                .type_hours
+                .iter()
+                .filter(|(_, hours)| **hours >= settings.type_rating_hours)
+                .map(|(model, _)| model.as_str())
+                .collect();
+            println!(
+                "[{}] {} | airport {} | {} | duty: {}/{}h{} | experience: {}h{}",
+                pilot.id,
+                pilot.name,
+                pilot.airport_id,
+                plane,
+                pilot.duty_hours_at(self.time, settings.rest_hours),
+                settings.max_duty_hours,
+                if rest_left > 0 && pilot.duty_hours > 0 {
+                    format!(" | rested in {}h", rest_left)
+                } else {
+                    String::new()
+                },
+                pilot.experience_hours,
+                if ratings.is_empty() {
+                    String::new()
+                } else {
+                    format!(" | rated on {}", ratings.join(", "))
+                },
+            );
+        }
+    }
+
+    fn set_on_hold(&mut self, plane_id: usize, on_hold: bool) {
+        for plane in self
+            .airplanes
+            .iter_mut()
+            .chain(self.player.fleet.iter_mut())
+            .filter(|p| p.id == plane_id)
+        {
+            plane.on_hold = on_hold;
+        }
+    }
+
+    /// Send a plane that is already in the air to a different airport.
+    ///
+    /// Fuel for the unflown part of the current leg goes back into the range budget, and
+    /// the new leg is checked from the plane's current position like a departure: runway,
+    /// range and fuel reserve. The plane keeps its speed setting. Rerouting into another
+    /// country than originally planned charges the overflight fee for the new leg.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane in transit.
+    /// - `destination_id`: New destination airport.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the plane is now headed for `destination_id`.
+    /// - `Err(GameError)`: If the plane or airport doesn't exist, the plane isn't flying,
+    ///   it is already headed there, or it can't reach or land at the new destination.
+    pub fn reroute_plane(
+        &mut self,
+        plane_id: usize,
+        destination_id: usize,
+    ) -> Result<(), GameError> {
+        let plane_idx = self
+            .airplanes
+            .iter()
+            .position(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+        let AirplaneStatus::InTransit {
+            destination: current_destination,
+            ..
+        } = self.airplanes[plane_idx].status
+        else {
+            return Err(GameError::PlaneNotReady {
+                plane_state: self.airplanes[plane_idx].status.clone(),
+            });
+        };
+        let (dest_airport, dest_coords) = self
+            .map
+            .airports
+            .iter()
+            .find(|(a, _)| a.id == destination_id)
+            .ok_or(GameError::AirportIdInvalid { id: destination_id })?;
+        if destination_id == current_destination {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} is already headed to airport {}",
+                    plane_id, destination_id
+                ),
+            });
+        }
+
+        let plane = &mut self.airplanes[plane_idx];
+        let remaining_km = plane.distance_to(&self.map.coord(current_destination).expect(ON_MAP));
+        let fuel_before = plane.current_fuel;
+        plane.current_fuel += remaining_km / plane.cruise_speed() * plane.fuel_burn();
+        let flight_hours = match plane.consume_flight_fuel(dest_airport, dest_coords) {
+            Ok(hours) => hours.max(1),
+            Err(e) => {
+                plane.current_fuel = fuel_before;
+                return Err(e);
+            }
+        };
+        self.emissions
+            .record_use(&plane.specs, fuel_before - plane.current_fuel);
+        let distance = plane.distance_to(dest_coords);
+        plane.status = AirplaneStatus::InTransit {
+            hours_remaining: flight_hours,
+            destination: destination_id,
+            origin: plane.location,
+            total_hours: flight_hours,
+        };
+
+        if self
+            .map
+            .is_cross_border(current_destination, destination_id)
+        {
+            let overflight_fee = distance * self.borders.overflight_fee_per_km;
+            self.player.cash -= overflight_fee;
+            self.daily_expenses += overflight_fee;
+            self.daily_fees += overflight_fee;
+        }
+
+        // the hourly FlightProgress tick already scheduled carries on towards the new destination
+        Ok(())
+    }
+
+    /// Refuel a plane and charge the player. Only works if the airplane is not in transit.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to refuel.
+    ///
+    /// Returns
+    /// - `Ok(()))` on success.
+    /// - `Err(GameError)`: If plane is invalid, not parked, or funds are insufficient.
+    pub fn refuel_plane(&mut self, plane_id: usize) -> Result<(), GameError> {
+        self.refuel_plane_with(plane_id, FuelAmount::Full)
+    }
+
+    /// Refuel a plane by a given amount and charge the player for the liters pumped.
+    ///
+    /// Useful to tanker fuel where it is cheap, or to carry only what a short hop needs.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to refuel.
+    /// - `amount`: Full tank, a number of liters, or a target fill level.
+    ///
+    /// Returns
+    /// - `Ok(()))` on success.
+    /// - `Err(GameError)`: If plane is invalid, not parked, already holds the requested
+    ///   amount, or funds are insufficient.
+    pub fn refuel_plane_with(
+        &mut self,
+        plane_id: usize,
+        amount: FuelAmount,
+    ) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let price = self.player_fuel_price(self.map.airport(airport_id).expect(ON_MAP));
+        let plane = &mut self.airplanes[plane_idx];
+        if plane.specs.is_electric() {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} is electric; use CHARGE PLANE instead", plane_id),
+            });
+        }
+
+        let airport = self.map.airport(airport_id).expect(ON_MAP);
+        if let Some(grade) = plane
+            .specs
+            .fuel_grade()
+            .filter(|grade| !airport.class().sells(*grade))
+        {
+            return Err(GameError::FuelGradeUnavailable {
+                airport_id: airport.id,
+                grade,
+            });
+        }
+
+        let wanted = amount.liters_wanted(plane.current_fuel, plane.specs.fuel_capacity);
+        if amount != FuelAmount::Full && wanted <= 0.0 {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} already holds {:.0} L of fuel",
+                    plane_id, plane.current_fuel
+                ),
+            });
+        }
+
+        // fuel airplane and log liters for dynamic pricing
+        let loyalty = self
+            .loyalty
+            .fee_factor(airport_id, self.time, &self.loyalty_settings);
+        let airport = self.map.airport_mut(airport_id).expect(ON_MAP);
+        let liters = airport.deliverable_fuel(plane).min(wanted);
+        if liters <= 0.0 && plane.current_fuel < plane.specs.fuel_capacity {
+            return Err(GameError::FuelShortage {
+                airport_id: airport.id,
+            });
+        }
+        let fueling_fee = price * liters * loyalty;
+        if self.player.available_funds() < fueling_fee {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: fueling_fee,
+            });
+        }
+        airport.fuel_supply(liters);
+        plane.refuel_with(liters);
+        if airport.is_out_of_fuel() {
+            println!("Airport {} has run out of fuel", airport.name);
+        }
+
+        // charge the player
+        self.player.cash -= fueling_fee;
+        self.daily_expenses += fueling_fee;
+        self.daily_fuel += fueling_fee;
+
+        // refueling runs alongside any loading or unloading under way
+        let hours = 1u64.saturating_sub(self.crew_hours_saved(airport_id));
+        self.start_ground_task(plane_idx, GroundTask::Refueling, self.time + hours);
+
+        Ok(())
+    }
+
+    /// Refuel every plane in a group.
+    ///
+    /// Planes that can't be refueled right now (in flight, busy, short of fuel or cash)
+    /// are skipped with a message; the rest are refueled as with [`Game::refuel_plane`].
+    ///
+    /// Parameters
+    /// - `group`: Group name (case-insensitive).
+    ///
+    /// Returns
+    /// - `Ok(Vec<usize>)`: Ids of the planes that were refueled.
+    /// - `Err(GameError::UnknownGroup)`: If the group doesn't exist.
+    pub fn refuel_group(&mut self, group: &str) -> Result<Vec<usize>, GameError> {
+        let mut refueled = Vec::new();
+        for plane_id in self.group_planes(group)? {
+            match self.refuel_plane(plane_id) {
+                Ok(()) => refueled.push(plane_id),
+                Err(e) => println!("Plane {} not refueled: {}", plane_id, e),
+            }
+        }
+        Ok(refueled)
+    }
+
+    /// Charge an electric plane's battery to full at an airport with a charger.
+    ///
+    /// The player pays `electric_settings.price_per_kwh` for every kWh. The plane stays
+    /// on charge for as long as the charger needs to deliver them at
+    /// `electric_settings.charger_kw`, at least an hour; loading and unloading carry on
+    /// meanwhile.
+    ///
+    /// Parameters
+    /// - `plane_id`: Plane to charge.
+    ///
+    /// Returns
+    /// - `Ok(GameTime)`: Hours the plane stays on charge.
+    /// - `Err(GameError)`: If the plane is invalid, not parked, burns fuel, is already
+    ///   full, the airport has no charger or funds are insufficient.
+    pub fn charge_plane(&mut self, plane_id: usize) -> Result<GameTime, GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let plane = &self.airplanes[plane_idx];
+        if !plane.specs.is_electric() {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} burns fuel; use REFUEL PLANE instead", plane_id),
+            });
+        }
+        if !self.map.airport(airport_id).expect(ON_MAP).has_charger {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Airport {} has no charger; install one with INSTALL CHARGER {}",
+                    airport_id, airport_id
+                ),
+            });
+        }
+        let kwh = (plane.specs.fuel_capacity - plane.current_fuel).max(0.0);
+        if kwh <= 0.0 {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} is already fully charged", plane_id),
+            });
+        }
+        let cost = kwh * self.electric_settings.price_per_kwh;
+        if self.player.available_funds() < cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: cost,
+            });
+        }
+
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.daily_fuel += cost;
+        self.airplanes[plane_idx].charge_with(kwh);
+
+        let hours = ((kwh / self.electric_settings.charger_kw).ceil() as GameTime).max(1);
+        self.start_ground_task(plane_idx, GroundTask::Charging, self.time + hours);
+        Ok(hours)
+    }
+
+    /// Install a charger for electric planes at an airport.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport to equip.
+    ///
+    /// Returns
+    /// - `Ok(())` on success.
+    /// - `Err(GameError)`: If the airport doesn't exist, already has a charger or funds
+    ///   are insufficient.
+    pub fn install_charger(&mut self, airport_id: usize) -> Result<(), GameError> {
+        let cost = self.electric_settings.charger_cost;
+        let airport = self
+            .map
+            .airport_mut(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        if airport.has_charger {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Airport {} already has a charger", airport_id),
+            });
+        }
+        if self.player.available_funds() < cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: cost,
+            });
+        }
+        airport.has_charger = true;
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.daily_investing -= cost;
+        Ok(())
+    }
+
+    /// Start building an airstrip at `(x, y)` for `construction.airstrip_cost`.
+    ///
+    /// The airstrip opens `construction.airstrip_hours` later with a short runway, no
+    /// hangar and no fees for the player; extend it with [`Game::extend_runway`].
+    ///
+    /// Parameters
+    /// - `x`, `y`: Site on the map, each between 0 and 10,000.
+    ///
+    /// Returns
+    /// - `Ok(usize)`: Id of the construction project.
+    /// - `Err(GameError)`: If the map uses real-world coordinates, the site is off the map
+    ///   or too close to another airport or site, or funds are insufficient.
+    pub fn build_airport(&mut self, x: f32, y: f32) -> Result<usize, GameError> {
+        if !self.map.coordinate_system().is_planar() {
+            return Err(GameError::InvalidCommand {
+                msg: "Airstrips can't be built on a map of real-world coordinates".to_string(),
+            });
+        }
+        if !(0.0..=10_000.0).contains(&x) || !(0.0..=10_000.0).contains(&y) {
+            return Err(GameError::SiteOffMap { x, y });
+        }
+        let location = Coordinate::new(x, y);
+        let min_distance = self.construction_settings.min_spacing_km;
+        let sites = self.constructions.iter().filter_map(|c| match c.project {
+            Project::Airstrip { location } => Some(location),
+            Project::RunwayExtension { .. } => None,
+        });
+        let distance = self
+            .map
+            .airports
+            .iter()
+            .map(|(_, coord)| *coord)
+            .chain(sites)
+            .map(|coord| coord.distance_to(&location))
+            .fold(f32::INFINITY, f32::min);
+        if distance < min_distance {
+            return Err(GameError::SiteTooClose {
+                distance,
+                min_distance,
+            });
+        }
+        let cost = self.construction_settings.airstrip_cost;
+        let hours = self.construction_settings.airstrip_hours;
+        self.start_construction(Project::Airstrip { location }, cost, hours)
+    }
+
+    /// Start lengthening the runway of an airport the player built by
+    /// `construction.extension_m`, up to `construction.max_runway_m`.
+    ///
+    /// Once the runway reaches [`HANGAR_MIN_RUNWAY`] the airport gets a hangar and sells
+    /// jet fuel; its fuel storage grows with the runway.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport to extend.
+    ///
+    /// Returns
+    /// - `Ok(usize)`: Id of the construction project.
+    /// - `Err(GameError)`: If the airport doesn't exist or isn't the player's, its runway
+    ///   is as long as it can get or already being extended, or funds are insufficient.
+    pub fn extend_runway(&mut self, airport_id: usize) -> Result<usize, GameError> {
+        let airport = self
+            .map
+            .airport(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        if !airport.player_built {
+            return Err(GameError::AirportNotOwned { airport_id });
+        }
+        if airport.runway_length >= self.construction_settings.max_runway_m {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "The runway at airport {} is already {:.0}m, the longest you can build",
+                    airport_id, airport.runway_length
+                ),
+            });
+        }
+        if self.constructions.iter().any(|c| {
+            c.project
+                == Project::RunwayExtension {
+                    airport: airport_id,
+                }
+        }) {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "The runway at airport {} is already being extended",
+                    airport_id
+                ),
+            });
+        }
+        let cost = self.construction_settings.extension_cost;
+        let hours = self.construction_settings.extension_hours;
+        self.start_construction(
+            Project::RunwayExtension {
+                airport: airport_id,
+            },
+            cost,
+            hours,
+        )
+    }
+
+    /// Pay for a project and schedule its completion `hours` from now.
+    fn start_construction(
+        &mut self,
+        project: Project,
+        cost: f32,
+        hours: GameTime,
+    ) -> Result<usize, GameError> {
+        if self.player.available_funds() < cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: cost,
+            });
+        }
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.daily_investing -= cost;
+
+        let id = self.next_construction_id;
+        self.next_construction_id += 1;
+        let done_at = self.time + hours;
+        self.log.push(format!(
+            "Construction {}: {} for ${:.2}, done in {}",
+            id,
+            project,
+            cost,
+            self.days_and_hours(hours)
+        ));
+        self.schedule(done_at, Event::ConstructionComplete { construction: id });
+        self.constructions.push(Construction {
+            id,
+            project,
+            cost,
+            done_at,
+        });
+        Ok(id)
+    }
+
+    /// Finish a construction project: open the airstrip or lengthen the runway, and
+    /// announce it to subscribers.
+    fn complete_construction(&mut self, construction_id: usize) {
+        let Some(idx) = self
+            .constructions
+            .iter()
+            .position(|c| c.id == construction_id)
+        else {
+            return;
+        };
+        let construction = self.constructions.remove(idx);
+        let settings = &self.construction_settings;
+
+        let airport_id = match construction.project {
+            Project::Airstrip { location } => {
+                let id = self.map.next_airport_id();
+                let runway = settings.airstrip_runway_m;
+                let mut airport = Airport::with_runway(
+                    self.seed,
+                    id,
+                    names::airport_name(self.seed, id, runway),
+                    runway,
+                );
+                airport.code = Some(names::airport_code(id));
+                airport.landing_fee = 0.0;
+                airport.parking_fee = 0.0;
+                airport.player_built = true;
+                fit_out_airport(&mut airport, &self.fuel_settings, 0);
+                self.map.add_airport(airport, location);
+                id
+            }
+            Project::RunwayExtension { airport: id } => {
+                let fuel = &self.fuel_settings;
+                let airport = self.map.airport_mut(id).expect(ON_MAP);
+                airport.runway_length =
+                    (airport.runway_length + settings.extension_m).min(settings.max_runway_m);
+                airport.has_hangar |= airport.runway_length >= HANGAR_MIN_RUNWAY;
+                if airport.fuel_capacity.is_some() {
+                    airport.fuel_capacity =
+                        Some(default_fuel_capacity(airport.runway_length) * fuel.stock_multiplier);
+                }
+                id
+            }
+        };
+
+        let airport = self.map.airport(airport_id).expect(ON_MAP);
+        let message = format!(
+            "Construction {} finished: {} now has a {:.0}m runway",
+            construction.id, airport.name, airport.runway_length
+        );
+        println!("{}", message);
+        self.log.push(message);
+        self.notify(GameEvent::ConstructionComplete {
+            time: self.time,
+            construction: construction.id,
+            airport: airport_id,
+        });
+    }
+
+    /// Print the construction projects underway.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_construction(&self) {
+        if self.constructions.is_empty() {
+            println!("Nothing under construction");
+            return;
+        }
+        for c in &self.constructions {
+            println!(
+                "[{}] {} | paid: ${:.2} | done in: {}",
+                c.id,
+                c.project,
+                c.cost,
+                self.days_and_hours(c.done_at.saturating_sub(self.time)),
+            );
+        }
+    }
+
+    /// The player's fuel concession at `airport_id`, if they hold one.
+    pub fn concession(&self, airport_id: usize) -> Option<&FuelConcession> {
+        self.concessions.iter().find(|c| c.airport == airport_id)
+    }
+
+    /// Price of a fuel concession at `airport_id`: `concessions.cost_per_size` per unit
+    /// of airport size.
+    pub fn concession_price(&self, airport_id: usize) -> Result<f32, GameError> {
+        let airport = self
+            .map
+            .airport(airport_id)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        Ok(self.concession_settings.cost_per_size * airport.size())
+    }
+
+    /// Price per liter the player pays for fuel at `airport`. Where they hold a
+    /// concession it is fixed at the airport's base price less `concessions.discount`,
+    /// whatever demand has done to the posted price.
+    pub fn player_fuel_price(&self, airport: &Airport) -> f32 {
+        if self.concession(airport.id).is_some() {
+            airport.base_fuel_price * (1.0 - self.concession_settings.discount)
+        } else {
+            airport.fuel_price
+        }
+    }
+
+    /// Buy the fuel concession at an airport.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport to buy into.
+    ///
+    /// Returns
+    /// - `Ok(f32)`: Price paid.
+    /// - `Err(GameError)`: If the airport doesn't exist, the player already holds its
+    ///   concession or funds are insufficient.
+    pub fn buy_concession(&mut self, airport_id: usize) -> Result<f32, GameError> {
+        let cost = self.concession_price(airport_id)?;
+        if self.concession(airport_id).is_some() {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "You already hold the fuel concession at airport {}",
+                    airport_id
+                ),
+            });
+        }
+        if self.player.available_funds() < cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: cost,
+            });
+        }
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.daily_investing -= cost;
+        self.concessions.push(FuelConcession {
+            airport: airport_id,
+            price_paid: cost,
+            bought_at: self.time,
+            income: 0.0,
+        });
+        let message = format!(
+            "Bought the fuel concession at {} for ${:.2}",
+            self.airport_name(airport_id),
+            cost
+        );
+        self.log.push(message);
+        Ok(cost)
+    }
+
+    /// Pay out the day's cut of third-party fuel sales at every concession: on average
+    /// `concessions.income_per_size_per_day` per unit of airport size, varying with the
+    /// day's traffic. Each payout is announced to subscribers.
+    fn collect_concession_income(&mut self) {
+        let now = self.time;
+        let mut payouts = Vec::new();
+        for concession in &self.concessions {
+            let Some(airport) = self.map.airport(concession.airport) else {
+                continue;
+            };
+            let mut roll = StdRng::seed_from_u64(
+                self.seed
+                    .wrapping_add(now)
+                    .wrapping_add(concession.airport as u64)
+                    .wrapping_add(127),
+            );
+            let traffic = roll.gen_range(0.5..=1.5);
+            let amount =
+                self.concession_settings.income_per_size_per_day * airport.size() * traffic;
+            payouts.push((concession.airport, amount));
+        }
+
+        for (airport, amount) in payouts {
+            if amount <= 0.0 {
+                continue;
+            }
+            if let Some(concession) = self.concessions.iter_mut().find(|c| c.airport == airport) {
+                concession.income += amount;
+            }
+            self.player.cash += amount;
+            self.daily_income += amount;
+            self.notify(GameEvent::ConcessionIncome {
+                time: now,
+                airport,
+                amount,
+            });
+        }
+    }
+
+    /// Print the fuel concessions the player holds.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_concessions(&self) {
+        if self.concessions.is_empty() {
+            println!("You hold no fuel concessions");
+            return;
+        }
+        for c in &self.concessions {
+            let airport = self.map.airport(c.airport).expect(ON_MAP);
+            println!(
+                "{} [{}] | your fuel: ${:.2}/L | paid: ${:.2} | earned: ${:.2}",
+                airport.name,
+                airport.id,
+                self.player_fuel_price(airport),
+                c.price_paid,
+                c.income,
+            );
+        }
+    }
+
+    /// Perform maintenance on airplane.
+    ///
+    /// Airports with a hangar service the plane within an hour at no charge. Elsewhere a
+    /// mobile mechanic is called out for [`MOBILE_MECHANIC_FEE`] and the plane is grounded
+    /// for [`MOBILE_MECHANIC_HOURS`].
+    pub fn maintenance_on_airplane(&mut self, plane_id: usize) -> Result<(), GameError> {
+        let airplane = self
+            .airplanes
+            .get(plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+
+        // cannot perform maintenance when not at an airport
+        if matches!(
+            airplane.status,
+            AirplaneStatus::InTransit {
+                hours_remaining: _,
+                destination: _,
+                origin: _,
+                total_hours: _
+            }
+        ) {
+            return Err(GameError::PlaneNotAtAirport { plane_id });
+        }
+
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        self.ensure_repaired(plane_idx)?;
+        let airport = self.map.airport(airport_id).expect(ON_MAP);
+        let downtime = if airport.has_hangar {
+            1
+        } else {
+            if self.player.available_funds() < MOBILE_MECHANIC_FEE {
+                return Err(GameError::InsufficientFunds {
+                    have: self.player.available_funds(),
+                    need: MOBILE_MECHANIC_FEE,
+                });
+            }
+            println!(
+                "No hangar at {}: mobile mechanic called out for ${:.2}",
+                airport.name, MOBILE_MECHANIC_FEE
+            );
+            self.player.cash -= MOBILE_MECHANIC_FEE;
+            self.daily_expenses += MOBILE_MECHANIC_FEE;
+            MOBILE_MECHANIC_HOURS
+        };
+
+        self.airplanes[plane_idx].maintenance();
+        self.schedule(self.time + downtime, Event::Maintenance { plane: plane_id });
+        Ok(())
+    }
+
+    /// Fix a plane that broke down on landing.
+    ///
+    /// Charges the parts cost, a share of the plane's purchase price, and keeps the plane
+    /// in the workshop for the configured repair time.
+    ///
+    /// Parameters
+    /// - `plane_id`: Broken-down plane.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the repair has started.
+    /// - `Err(GameError)`: If the plane doesn't exist, isn't waiting for a repair, its
+    ///   airport lacks spare-part kits, or the player can't pay for the parts.
+    pub fn repair_plane(&mut self, plane_id: usize) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        let plane = &self.airplanes[plane_idx];
+        if !plane.awaiting_repair || plane.status != AirplaneStatus::Broken {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Plane {} has nothing to repair", plane_id),
+            });
+        }
+        let kits = self.maintenance_settings.repair_kits;
+        self.ensure_spare_kits(airport_id, kits)?;
+
+        let parts_cost = plane.specs.purchase_price * self.maintenance_settings.repair_cost_share;
+        if self.player.available_funds() < parts_cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: parts_cost,
+            });
+        }
+        self.player.cash -= parts_cost;
+        self.daily_expenses += parts_cost;
+        self.map.airport_mut(airport_id).expect(ON_MAP).spare_parts -= kits;
+
+        self.airplanes[plane_idx].status = AirplaneStatus::Maintenance;
+        self.schedule(
+            self.time + self.maintenance_settings.repair_hours,
+            Event::RepairComplete { plane: plane_id },
+        );
+        Ok(())
+    }
+
+    /// Fail unless the airport at `airport_id` stocks at least `need` spare-part kits.
+    fn ensure_spare_kits(&self, airport_id: usize, need: u32) -> Result<(), GameError> {
+        let airport = self.map.airport(airport_id).expect(ON_MAP);
+        if airport.spare_parts < need {
+            return Err(GameError::MissingSpareParts {
+                airport_id: airport.id,
+                have: airport.spare_parts,
+                need,
+            });
+        }
+        Ok(())
+    }
+
+    /// Buy spare-part kits at an airport with a hangar.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport that sells the kits and keeps them in stock.
+    /// - `kits`: Number of kits to buy.
+    ///
+    /// Returns
+    /// - `Ok(())`: If the kits were added to the airport's stock.
+    /// - `Err(GameError)`: If the airport doesn't exist or has no hangar, `kits` is zero,
+    ///   or the player can't pay.
+    pub fn buy_spare_parts(&mut self, airport_id: usize, kits: u32) -> Result<(), GameError> {
+        if !self.map.airports.contains(airport_id) {
+            return Err(GameError::AirportIdInvalid { id: airport_id });
+        }
+        if kits == 0 {
+            return Err(GameError::InvalidCommand {
+                msg: "Buy at least one spare-part kit".to_string(),
+            });
+        }
+        if !self.map.airport(airport_id).expect(ON_MAP).has_hangar {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Airport {} has no hangar to buy spare parts from; ship them in instead",
+                    airport_id
+                ),
+            });
+        }
+        let cost = self.maintenance_settings.spare_kit_price * kits as f32;
+        if self.player.available_funds() < cost {
+            return Err(GameError::InsufficientFunds {
+                have: self.player.available_funds(),
+                need: cost,
+            });
+        }
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.map.airport_mut(airport_id).expect(ON_MAP).spare_parts += kits;
+        Ok(())
+    }
+
+    /// Load spare-part kits from the plane's airport onto the plane, addressed to another
+    /// airport.
+    ///
+    /// The kits leave the airport's stock and travel as a cargo order worth nothing.
+    /// Unloading it at `destination_id` adds the kits to that airport's stock.
+    ///
+    /// Parameters
+    /// - `kits`: Number of kits to ship.
+    /// - `destination_id`: Airport that receives the kits.
+    /// - `plane_id`: Plane parked at the airport holding the kits.
+    ///
+    /// Returns
+    /// - `Ok(order_id)`: Id of the shipment order.
+    /// - `Err(GameError)`: If the airport lacks the kits, the destination doesn't exist, or
+    ///   loading fails.
+    pub fn ship_spare_parts(
+        &mut self,
+        kits: u32,
+        destination_id: usize,
+        plane_id: usize,
+    ) -> Result<usize, GameError> {
+        if kits == 0 {
+            return Err(GameError::InvalidCommand {
+                msg: "Ship at least one spare-part kit".to_string(),
+            });
+        }
+        if !self
+            .map
+            .airports
+            .iter()
+            .any(|(a, _)| a.id == destination_id)
+        {
+            return Err(GameError::AirportIdInvalid { id: destination_id });
+        }
+        let (_, airport_id) = self.plane_and_airport(plane_id)?;
+        let origin_id = airport_id;
+        if origin_id == destination_id {
+            return Err(GameError::SameAirport);
+        }
+        self.ensure_spare_kits(airport_id, kits)?;
 
-        // set the status (no location change here!)
-        plane.status = AirplaneStatus::InTransit {
-            hours_remaining: flight_hours,
-            destination: destination_id,
-            origin: origin_coord,
-            total_hours: flight_hours,
-        };
+        let order_id = self.map.allocate_order_id();
+        self.map
+            .airport_mut(airport_id)
+            .expect(ON_MAP)
+            .orders
+            .push(Order {
+                id: order_id,
+                payload: OrderPayload::Cargo {
+                    cargo_type: CargoType::Machines,
+                    weight: self.maintenance_settings.spare_kit_weight * kits as f32,
+                },
+                value: 0.0,
+                deadline: PARTS_SHIPMENT_DEADLINE,
+                origin_id,
+                destination_id,
+                tier: OrderTier::Standard,
+                damage: 0.0,
+                shipment: None,
+                contract: None,
+                spare_parts: kits,
+                rare: None,
+                pallet: Vec::new(),
+            });
 
-        // kick off the first hourly tick
-        self.schedule(self.time + 1, Event::FlightProgress { plane: plane_id });
+        if let Err(e) = self.load_order(order_id, plane_id) {
+            self.map
+                .airport_mut(airport_id)
+                .expect(ON_MAP)
+                .orders
+                .retain(|o| o.id != order_id);
+            return Err(e);
+        }
+        self.map.airport_mut(airport_id).expect(ON_MAP).spare_parts -= kits;
+        Ok(order_id)
+    }
+
+    /// Print the spare-part kits in stock at every airport that has some.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_spare_parts(&self) {
+        let stocked: Vec<_> = self
+            .map
+            .airports
+            .iter()
+            .filter(|(a, _)| a.spare_parts > 0)
+            .collect();
+        if stocked.is_empty() {
+            println!("No spare-part kits in stock anywhere");
+            return;
+        }
+        for (airport, _) in stocked {
+            println!(
+                "{} | {}: {} kits{}",
+                airport.id,
+                airport.name,
+                airport.spare_parts,
+                if airport.has_hangar { " | Hangar" } else { "" },
+            );
+        }
+    }
 
+    /// Refuse work on a plane that is still waiting for `REPAIR PLANE`.
+    fn ensure_repaired(&self, plane_idx: usize) -> Result<(), GameError> {
+        let plane = &self.airplanes[plane_idx];
+        if plane.awaiting_repair {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Plane {} is broken down; REPAIR PLANE {} first",
+                    plane.id, plane.id
+                ),
+            });
+        }
         Ok(())
     }
 
-    /// Refuel a plane and charge the player. Only works if the airplane is not in transit.
+    /// Put a plane through a scheduled A, B or C check.
+    ///
+    /// The check costs a share of the plane's purchase price and keeps it in the hangar
+    /// for the configured downtime. It also fixes any pending repair, and a heavier check
+    /// resets the counters of the lighter ones it covers.
     ///
     /// Parameters
-    /// - `plane_id`: Plane to refuel.
+    /// - `plane_id`: Plane parked (or broken down) at an airport.
+    /// - `check`: Check to perform.
     ///
     /// Returns
-    /// - `Ok(()))` on success.
-    /// - `Err(GameError)`: If plane is invalid, not parked, or funds are insufficient.
-    pub fn refuel_plane(&mut self, plane_id: usize) -> Result<(), GameError> {
-        let (plane_idx, airport_idx) = self.plane_and_airport_idx(plane_id)?;
-        let plane = &mut self.airplanes[plane_idx];
+    /// - `Ok(())`: If the check has started.
+    /// - `Err(GameError)`: If the plane is flying or busy, its airport lacks the spare-part
+    ///   kits the check needs, or the player can't pay.
+    pub fn perform_check(&mut self, plane_id: usize, check: CheckType) -> Result<(), GameError> {
+        let (plane_idx, airport_id) = self.plane_and_airport(plane_id)?;
+        self.ensure_repaired(plane_idx)?;
+        let plane = &self.airplanes[plane_idx];
+        if matches!(plane.status, AirplaneStatus::InTransit { .. }) {
+            return Err(GameError::PlaneNotAtAirport { plane_id });
+        }
+        if !matches!(
+            plane.status,
+            AirplaneStatus::Parked | AirplaneStatus::Broken
+        ) {
+            return Err(GameError::PlaneNotReady {
+                plane_state: plane.status.clone(),
+            });
+        }
 
-        // fuel airplane and log liters for dynamic pricing
-        let fueling_fee = self.map.airports[airport_idx].0.fueling_fee(plane);
-        if self.player.cash < fueling_fee {
+        if check
+            > self
+                .map
+                .airport(airport_id)
+                .expect(ON_MAP)
+                .class()
+                .max_check()
+        {
+            return Err(GameError::CheckUnavailable { airport_id, check });
+        }
+
+        let settings = self.maintenance_settings.check(check);
+        self.ensure_spare_kits(airport_id, settings.spare_kits)?;
+        let cost = plane.specs.purchase_price * settings.cost_share;
+        if self.player.available_funds() < cost {
             return Err(GameError::InsufficientFunds {
-                have: self.player.cash,
-                need: fueling_fee,
+                have: self.player.available_funds(),
+                need: cost,
             });
         }
-        self.map.airports[airport_idx].0.fuel_supply(plane);
-        plane.refuel();
+        self.player.cash -= cost;
+        self.daily_expenses += cost;
+        self.map.airport_mut(airport_id).expect(ON_MAP).spare_parts -= settings.spare_kits;
 
-        // charge the player
-        self.player.cash -= fueling_fee;
-        self.daily_expenses += fueling_fee;
+        let downtime = settings.downtime_hours;
+        self.airplanes[plane_idx].status = AirplaneStatus::Maintenance;
+        self.schedule(
+            self.time + downtime,
+            Event::CheckComplete {
+                plane: plane_id,
+                check,
+            },
+        );
+        Ok(())
+    }
 
-        // schedule fueling event
-        self.schedule(self.time + 1, Event::RefuelComplete { plane: plane_id });
+    /// Print how many flight hours each plane has left before its next A, B and C checks.
+    #[cfg(feature = "ui_prints")]
+    pub fn list_maintenance_plan(&self) {
+        let settings = &self.maintenance_settings;
+        for plane in &self.airplanes {
+            let due: Vec<String> = CheckType::iter()
+                .map(|check| {
+                    let left = settings.hours_until_due(&plane.check_hours, check);
+                    match left {
+                        1.. => format!("{}: {}h left", check, left),
+                        0 => format!("{}: due now", check),
+                        _ => format!("{}: OVERDUE by {}h", check, -left),
+                    }
+                })
+                .collect();
+            println!("ID: {} | {} | {}", plane.id, plane.name, due.join(" | "));
+        }
+    }
 
+    /// Add `amount` to the player's cash, or take it away when negative. Sandbox only;
+    /// the daily income and expense figures are left alone.
+    #[cfg(feature = "sandbox")]
+    pub fn give_cash(&mut self, amount: f32) -> Result<(), GameError> {
+        if !amount.is_finite() {
+            return Err(GameError::InvalidCommand {
+                msg: format!("Cannot give {} cash", amount),
+            });
+        }
+        self.player.cash += amount;
+        println!("Cash is now ${:.2}", self.player.cash);
         Ok(())
     }
 
-    /// Perform maintenance on airplane
-    pub fn maintenance_on_airplane(&mut self, plane_id: usize) -> Result<(), GameError> {
-        let airplane = &mut self.airplanes[plane_id];
+    /// Move a parked plane, and its pilot, to another airport instantly. Sandbox only.
+    #[cfg(feature = "sandbox")]
+    pub fn teleport_plane(&mut self, plane_id: usize, airport_id: usize) -> Result<(), GameError> {
+        let coord = self
+            .map
+            .airports
+            .iter()
+            .find(|(a, _)| a.id == airport_id)
+            .map(|(_, c)| *c)
+            .ok_or(GameError::AirportIdInvalid { id: airport_id })?;
+        let plane = self
+            .airplanes
+            .iter_mut()
+            .find(|p| p.id == plane_id)
+            .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
+        if plane.status != AirplaneStatus::Parked {
+            return Err(GameError::PlaneNotReady {
+                plane_state: plane.status.clone(),
+            });
+        }
+        plane.location = coord;
+        self.arrival_times.insert(plane_id, self.time);
+        for pilot in self
+            .player
+            .pilots
+            .iter_mut()
+            .filter(|p| p.plane == Some(plane_id))
+        {
+            pilot.airport_id = airport_id;
+        }
+        println!("Plane {} teleported to airport {}", plane_id, airport_id);
+        Ok(())
+    }
 
-        // cannot perform maintenance when not at an airport
-        if matches!(
-            airplane.status,
-            AirplaneStatus::InTransit {
-                hours_remaining: _,
-                destination: _,
-                origin: _,
-                total_hours: _
-            }
-        ) {
-            return Err(GameError::PlaneNotAtAirport { plane_id });
+    /// Add a cargo order at `origin`. Sandbox only.
+    ///
+    /// Returns
+    /// - `Ok(usize)`: The id of the new order.
+    /// - `Err(GameError)`: If the cargo type or an airport is unknown, or the numbers are invalid.
+    #[cfg(feature = "sandbox")]
+    pub fn spawn_order(
+        &mut self,
+        cargo: &str,
+        weight: f32,
+        origin: usize,
+        destination: usize,
+        value: f32,
+        deadline: Option<GameTime>,
+    ) -> Result<usize, GameError> {
+        let cargo_type = CargoType::iter()
+            .find(|c| format!("{:?}", c).eq_ignore_ascii_case(cargo))
+            .ok_or_else(|| GameError::InvalidCommand {
+                msg: format!("Unknown cargo type '{}'", cargo),
+            })?;
+        let valid = weight > 0.0 && weight.is_finite() && value >= 0.0 && value.is_finite();
+        if !valid {
+            return Err(GameError::InvalidCommand {
+                msg: "Order weight must be positive and its value non-negative".to_string(),
+            });
         }
+        if origin == destination {
+            return Err(GameError::SameAirport);
+        }
+        if !self.map.airports.contains(destination) {
+            return Err(GameError::AirportIdInvalid { id: destination });
+        }
+        if !self.map.airports.contains(origin) {
+            return Err(GameError::AirportIdInvalid { id: origin });
+        }
+
+        let id = self.map.allocate_order_id();
+        let airport = self.map.airport_mut(origin).expect("checked above");
+        airport.orders.push(Order {
+            id,
+            payload: OrderPayload::Cargo { cargo_type, weight },
+            value,
+            deadline: deadline.unwrap_or(SPAWNED_ORDER_DEADLINE_HOURS).max(1),
+            origin_id: origin,
+            destination_id: destination,
+            tier: OrderTier::Standard,
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+        });
+        println!("Spawned order {} at airport {}", id, origin);
+        Ok(id)
+    }
 
-        airplane.maintenance();
-        self.schedule(self.time + 1, Event::Maintenance { plane: plane_id });
+    /// Run the simulation forward to the absolute hour `hours`. Sandbox only.
+    ///
+    /// Everything scheduled in between happens as with [`Game::advance`]; the clock
+    /// cannot be turned back.
+    #[cfg(feature = "sandbox")]
+    pub fn set_time(&mut self, hours: GameTime) -> Result<(), GameError> {
+        if hours < self.time {
+            return Err(GameError::InvalidCommand {
+                msg: format!(
+                    "Cannot set the time back from {} to {}",
+                    self.days_and_hours(self.time),
+                    self.days_and_hours(hours)
+                ),
+            });
+        }
+        self.advance(hours - self.time);
         Ok(())
     }
 
@@ -2238,6 +7388,7 @@ impl Game {
     }
 
     pub fn execute(&mut self, cmd: Command) -> Result<(), GameError> {
+        self.record_command();
         match cmd {
             ShowAirports { .. }
             | ShowAirport { .. }
@@ -2245,16 +7396,70 @@ impl Game {
             | ShowAirplane { .. }
             | ShowDistances { .. }
             | ShowCash
+            | ShowValue
+            | ShowHash
             | ShowTime
             | ShowStats
+            | ShowReport
             | ShowModels
+            | ShowMap { .. }
+            | ShowAllOrders { .. }
+            | ShowContracts
+            | ShowCharters
+            | ShowLanes
+            | ShowAuctions
+            | ShowDemand { .. }
+            | ShowFares
+            | ShowMarket
+            | ShowGroup { .. }
+            | ShowGroups
+            | ShowMaintenance
+            | ShowParts
+            | ShowPilots
+            | ShowConstruction
+            | ShowConcessions
+            | ShowWarehouse
             | LoadConfig { .. }
+            | ExportConfig { .. }
+            | ExportRun { .. }
             | Exit => Ok(()),
             BuyPlane { model, airport } => self.buy_plane(&model, airport),
             SellPlane { plane } => {
                 self.sell_plane(plane)?;
                 Ok(())
             }
+            RenamePlane { plane, name } => self.rename_plane(plane, &name),
+            ReroutePlane { plane, dest } => self.reroute_plane(plane, dest),
+            RepairPlane { plane } => self.repair_plane(plane),
+            HirePilot { airport } => self.hire_pilot(airport).map(|_| ()),
+            FirePilot { pilot } => self.fire_pilot(pilot),
+            HireCrew { airport, count } => self.hire_crew(airport, count).map(|_| ()),
+            FireCrew { airport, count } => self.fire_crew(airport, count).map(|_| ()),
+            AssignPilot { pilot, plane } => self.assign_pilot(pilot, plane),
+            BuyParts { airport, kits } => self.buy_spare_parts(airport, kits),
+            ShipParts { kits, dest, plane } => self.ship_spare_parts(kits, dest, plane).map(|_| ()),
+            PerformCheck { plane, check } => {
+                let check = check
+                    .parse::<CheckType>()
+                    .map_err(|msg| GameError::InvalidCommand { msg })?;
+                self.perform_check(plane, check)
+            }
+            EmergencyLand { plane } => self.emergency_land(plane).map(|_| ()),
+            GroupPlane { plane, group } => self.group_plane(plane, &group),
+            UngroupPlane { plane, group } => self.ungroup_plane(plane, &group),
+            RefuelGroup { group } => {
+                self.refuel_group(&group)?;
+                Ok(())
+            }
+            ChargePlane { plane } => self.charge_plane(plane).map(|_| ()),
+            InstallCharger { airport } => self.install_charger(airport),
+            BuildAirport { x, y } => self.build_airport(x, y).map(|_| ()),
+            ExtendRunway { airport } => self.extend_runway(airport).map(|_| ()),
+            BuyConcession { airport } => self.buy_concession(airport).map(|_| ()),
+            BuyUsed { listing } => {
+                self.buy_used_plane(listing)?;
+                Ok(())
+            }
             LoadOrder { order, plane } => self.load_order(order, plane),
             LoadOrders { orders, plane } => {
                 for o in orders {
@@ -2263,6 +7468,7 @@ impl Game {
                 Ok(())
             }
             UnloadOrder { order, plane } => self.unload_order(order, plane),
+            TransferOrder { order, from, to } => self.transfer_order(order, from, to),
             UnloadOrders { orders, plane } => {
                 for o in orders {
                     self.unload_order(o, plane)?;
@@ -2270,38 +7476,260 @@ impl Game {
                 Ok(())
             }
             UnloadAll { plane } => self.unload_all(plane),
-            Refuel { plane } => self.refuel_plane(plane),
-            DepartPlane { plane, dest } => self.depart_plane(plane, dest),
-            HoldPlane { .. } => Ok(()),
+            SplitOrder { order, weight } => self.split_order(order, weight).map(|_| ()),
+            BuildPallet { orders } => self.build_pallet(&orders).map(|_| ()),
+            BreakPallet { pallet } => self.break_pallet(pallet).map(|_| ()),
+            ReserveOrder { order } => self.reserve_order(order).map(|_| ()),
+            LoadContract {
+                contract,
+                weight,
+                plane,
+            } => self
+                .load_contract_cargo(contract, weight, plane)
+                .map(|_| ()),
+            AcceptCharter { charter, plane } => self.accept_charter(charter, plane),
+            AcceptLane { deal } => self.accept_lane_deal(deal),
+            DeclineLane { deal } => self.decline_lane_deal(deal),
+            BidAuction { auction, amount } => self.bid_auction(auction, amount),
+            SetFare {
+                origin,
+                destination,
+                fare,
+            } => self.set_route_fare(origin, destination, fare),
+            ClearFare {
+                origin,
+                destination,
+            } => self.clear_route_fare(origin, destination),
+            Refuel { plane, amount } => {
+                let amount = match amount {
+                    Some(s) => s
+                        .parse::<FuelAmount>()
+                        .map_err(|msg| GameError::InvalidCommand { msg })?,
+                    None => FuelAmount::Full,
+                };
+                self.refuel_plane_with(plane, amount)
+            }
+            DepartPlane { plane, dest, speed } => {
+                let regime = match speed {
+                    Some(s) => s
+                        .parse::<CruiseRegime>()
+                        .map_err(|msg| GameError::InvalidCommand { msg })?,
+                    None => CruiseRegime::Normal,
+                };
+                self.depart_plane_with_speed(plane, dest, regime)
+            }
+            HoldPlane { plane } => self.hold_plane(plane),
+            ReleasePlane { plane } => self.release_plane(plane),
             Advance { hours } => {
                 self.advance(hours);
                 Ok(())
             }
-            SaveGame { name } => self
-                .save_game(&name)
+            #[cfg(feature = "sandbox")]
+            GiveCash { amount } => self.give_cash(amount),
+            #[cfg(feature = "sandbox")]
+            TeleportPlane { plane, airport } => self.teleport_plane(plane, airport),
+            #[cfg(feature = "sandbox")]
+            SpawnOrder {
+                cargo,
+                weight,
+                origin,
+                destination,
+                value,
+                deadline,
+            } => self
+                .spawn_order(&cargo, weight, origin, destination, value, deadline)
+                .map(|_| ()),
+            #[cfg(feature = "sandbox")]
+            SetTime { hours } => self.set_time(hours),
+            SaveGame {
+                name,
+                sealed,
+                passphrase,
+            } => self
+                .save_game_with(
+                    &name,
+                    &SaveOptions {
+                        checksum: sealed,
+                        passphrase,
+                    },
+                )
                 .map_err(|e| GameError::InvalidCommand { msg: e.to_string() }),
-            LoadGame { name } => {
-                *self = Game::load_game(&name)
+            LoadGame { name, passphrase } => {
+                *self = Game::load_game_with(&name, passphrase.as_deref())
                     .map_err(|e| GameError::InvalidCommand { msg: e.to_string() })?;
+                let path = history::path_for_save(&name);
+                if cfg!(feature = "history") && path.exists() {
+                    self.attach_history(&path)
+                        .map_err(|e| GameError::InvalidCommand { msg: e.to_string() })?;
+                }
                 Ok(())
             }
             Maintenance { plane_id } => self.maintenance_on_airplane(plane_id),
         }
     }
 
+    /// Draw the map as a `width` x `height` text grid inside a border.
+    ///
+    /// Airports show as their id in base 36 (`0`-`9`, `a`-`z`, wrapping after `z`),
+    /// planes in flight as `*` and their leg as `.`. A legend naming the airports and
+    /// listing every plane follows the grid.
+    pub fn render_ascii(&self, width: usize, height: usize) -> String {
+        let width = width.max(1);
+        let height = height.max(1);
+        let points: Vec<Coordinate> = self
+            .map
+            .airports
+            .iter()
+            .map(|(_, c)| c.projected())
+            .collect();
+        let (min_x, max_x, min_y, max_y) = points.iter().fold(
+            (
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(min_x, max_x, min_y, max_y), c| {
+                (
+                    min_x.min(c.x),
+                    max_x.max(c.x),
+                    min_y.min(c.y),
+                    max_y.max(c.y),
+                )
+            },
+        );
+        // world coordinate to (column, row), with north at the top
+        let cell = |coord: &Coordinate| -> (usize, usize) {
+            let c = coord.projected();
+            let fraction = |v: f32, min: f32, max: f32| {
+                if max > min {
+                    ((v - min) / (max - min)).clamp(0.0, 1.0)
+                } else {
+                    0.5
+                }
+            };
+            let col = (fraction(c.x, min_x, max_x) * (width - 1) as f32).round() as usize;
+            let row = ((1.0 - fraction(c.y, min_y, max_y)) * (height - 1) as f32).round() as usize;
+            (col, row)
+        };
+
+        let mut grid = vec![vec![' '; width]; height];
+        let mut legend = Vec::new();
+        for plane in &self.airplanes {
+            let name = plane.name.as_str();
+            if let AirplaneStatus::InTransit {
+                hours_remaining,
+                destination,
+                origin,
+                ..
+            } = plane.status
+            {
+                if let Some(target) = self.map.coord(destination) {
+                    let (x0, y0) = cell(&origin);
+                    let (x1, y1) = cell(&target);
+                    let steps = x0.abs_diff(x1).max(y0.abs_diff(y1)).max(1);
+                    for step in 0..=steps {
+                        let t = step as f32 / steps as f32;
+                        let x = (x0 as f32 + (x1 as f32 - x0 as f32) * t).round() as usize;
+                        let y = (y0 as f32 + (y1 as f32 - y0 as f32) * t).round() as usize;
+                        grid[y][x] = '.';
+                    }
+                }
+                legend.push(format!(
+                    "* {} {}: to {}, {} left",
+                    plane.id,
+                    name,
+                    self.airport_name(destination),
+                    self.days_and_hours(hours_remaining)
+                ));
+            } else {
+                let at = self
+                    .map
+                    .airports
+                    .iter()
+                    .find(|(_, c)| *c == plane.location)
+                    .map(|(a, _)| a.name.clone())
+                    .unwrap_or_default();
+                legend.push(format!(
+                    "  {} {}: {:?} at {}",
+                    plane.id, name, plane.status, at
+                ));
+            }
+        }
+        for plane in &self.airplanes {
+            if let AirplaneStatus::InTransit { .. } = plane.status {
+                let (x, y) = cell(&plane.location);
+                grid[y][x] = '*';
+            }
+        }
+        let mut airports = Vec::with_capacity(self.map.airports.len());
+        for (airport, coord) in &self.map.airports {
+            let (x, y) = cell(coord);
+            let label = std::char::from_digit((airport.id % 36) as u32, 36).unwrap_or('#');
+            grid[y][x] = label;
+            airports.push(format!("{} {}", label, airport.name));
+        }
+
+        let border = format!("+{}+", "-".repeat(width));
+        let mut out = String::new();
+        out.push_str(&border);
+        out.push('\n');
+        for row in grid {
+            out.push('|');
+            out.extend(row);
+            out.push_str("|\n");
+        }
+        out.push_str(&border);
+        out.push('\n');
+        out.push_str(&format!("Airports: {}\n", airports.join(", ")));
+        for line in legend {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(level = "debug", skip(self))
+    )]
     pub fn observe(&self) -> Observation {
         let airports = self
             .map
             .airports
             .iter()
-            .map(|(airport, coord)| AirportObs {
-                id: airport.id,
-                name: airport.name.clone(),
-                x: coord.x,
-                y: coord.y,
-                fuel_price: airport.fuel_price,
-                runway_length: airport.runway_length,
-                num_orders: airport.orders.len(),
+            .map(|(airport, coord)| {
+                let offset = airport
+                    .utc_offset
+                    .unwrap_or_else(|| default_utc_offset(coord));
+                AirportObs {
+                    id: airport.id,
+                    name: airport.name.clone(),
+                    code: airport.code.clone(),
+                    class: airport.class(),
+                    x: coord.x,
+                    y: coord.y,
+                    fuel_price: self.player_fuel_price(airport),
+                    fuel_stock: airport.fuel_capacity.map(|_| airport.fuel_stock),
+                    has_hangar: airport.has_hangar,
+                    spare_parts: airport.spare_parts,
+                    runway_length: airport.runway_length,
+                    population: airport.population,
+                    parking_stands: airport.parking_stands(),
+                    ground_crew: airport.ground_crew,
+                    has_charger: airport.has_charger,
+                    utc_offset: offset,
+                    local_hour: LocalTime::at(self.time, offset).hour,
+                    num_orders: airport.orders.len(),
+                    country: self.map.country_of(airport.id).map(str::to_string),
+                    player_built: airport.player_built,
+                    has_concession: self.concession(airport.id).is_some(),
+                    staged_weight: self
+                        .staged_orders_at(airport.id)
+                        .iter()
+                        .map(|o| o.payload_weight())
+                        .sum(),
+                }
             })
             .collect();
 
@@ -2319,6 +7747,9 @@ impl Game {
                 };
                 PlaneObs {
                     id: plane.id,
+                    name: plane.name.clone(),
+                    on_hold: plane.on_hold,
+                    awaiting_repair: plane.awaiting_repair,
                     model: format!("{:?}", plane.model),
                     x: plane.location.x,
                     y: plane.location.y,
@@ -2326,15 +7757,21 @@ impl Game {
                     fuel: FuelObs {
                         current: plane.current_fuel,
                         capacity: plane.specs.fuel_capacity,
+                        electric: plane.specs.is_electric(),
                     },
                     payload: PayloadObs {
                         cargo_current: plane.current_payload,
                         cargo_capacity: plane.specs.payload_capacity,
-                        passenger_current: plane.current_passengers,
+                        cargo_orders: plane.cargo_orders(),
+                        cargo_slots: plane.specs.cargo_slots,
+                        passenger_current: plane.seated_passengers(),
                         passenger_capacity: plane.specs.passenger_capacity,
+                        takeoff_weight: plane.takeoff_weight(),
+                        mtow: plane.specs.mtow,
                     },
                     destination,
                     hours_remaining,
+                    turnaround: plane.turnaround.clone(),
                 }
             })
             .collect();
@@ -2342,11 +7779,31 @@ impl Game {
         Observation {
             time: self.time,
             cash: self.player.cash,
+            company_value: self.company_value(),
             airports,
             planes,
+            used_market: self.used_market.clone(),
+            pilots: self.player.pilots.clone(),
         }
     }
 
+    /// What changed in [`Game::observe`] since state version `since`, plus the game
+    /// events since.
+    ///
+    /// Pass 0 for everything, e.g. after starting or loading a game, then the `version`
+    /// of the last patch. Clients keep their copy current with [`Observation::apply`].
+    pub fn observe_diff(&mut self, since: u64) -> ObservationPatch {
+        let obs = self.observe();
+        self.tracker.diff(obs, since)
+    }
+
+    /// Version of the observable state; it increases whenever [`Game::observe`] would
+    /// return something different or a game event fires.
+    pub fn state_version(&mut self) -> u64 {
+        let obs = self.observe();
+        self.tracker.update(obs)
+    }
+
     // ************************
     // ******* GUI APIs *******
     // ************************
@@ -2360,7 +7817,7 @@ impl Game {
     }
 
     pub fn airports(&self) -> &[(Airport, Coordinate)] {
-        &self.map.airports
+        self.map.airports.as_slice()
     }
 
     pub fn planes(&self) -> &Vec<Airplane> {
@@ -2378,6 +7835,211 @@ impl Game {
         v.sort_by(|a, b| a.1.purchase_price.partial_cmp(&b.1.purchase_price).unwrap());
         v
     }
+
+    /// Capture the current world as an editable [`WorldConfig`].
+    ///
+    /// Airports keep their locations, fees, countries and pending orders; the gameplay
+    /// tuning, custom airplane models and the current fleet are carried over as well.
+    /// The exported config disables initial order generation so the captured orders are
+    /// what a reload starts with. Cargo currently loaded on planes is not exported.
+    ///
+    /// Returns
+    /// - `WorldConfig`: Config that rebuilds a world equivalent to this one.
+    pub fn export_config(&self) -> WorldConfig {
+        let airports = self
+            .map
+            .airports
+            .iter()
+            .map(|(airport, coord)| AirportConfig {
+                id: airport.id,
+                name: airport.name.clone(),
+                location: Some(Location {
+                    x: coord.x,
+                    y: coord.y,
+                }),
+                runway_length_m: Some(airport.runway_length),
+                fuel_price_per_l: Some(airport.fuel_price),
+                landing_fee_per_ton: Some(airport.landing_fee),
+                parking_fee_per_hour: Some(airport.parking_fee),
+                has_hangar: Some(airport.has_hangar),
+                population: Some(airport.population),
+                utc_offset: airport.utc_offset,
+                country: self.map.country_of(airport.id).map(str::to_string),
+                orders: airport
+                    .orders
+                    .iter()
+                    .flat_map(|o| {
+                        if o.is_pallet() {
+                            o.pallet.iter()
+                        } else {
+                            std::slice::from_ref(o).iter()
+                        }
+                    })
+                    .filter(|o| o.deadline > 0)
+                    .map(|o| match o.payload {
+                        OrderPayload::Cargo { cargo_type, weight } => ManualOrderConfig::Cargo {
+                            cargo: cargo_type,
+                            weight,
+                            value: o.value,
+                            deadline_hours: o.deadline,
+                            destination_id: o.destination_id,
+                            tier: o.tier,
+                        },
+                        OrderPayload::Passengers { count } => ManualOrderConfig::Passengers {
+                            passengers: count,
+                            value: o.value,
+                            deadline_hours: o.deadline,
+                            destination_id: o.destination_id,
+                        },
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let cargo = &self.map.demand_params.cargo;
+        let passengers = &self.map.demand_params.passengers;
+        let gameplay = GameplayConfig {
+            restock_cycle_hours: self.restock_cycle,
+            fuel_interval_hours: self.fuel_interval,
+            orders: OrdersGameplay {
+                regenerate: self.regenerate_orders,
+                reservation_share: self.reservation_share,
+                generate_initial: false,
+                tuning: OrderTuning {
+                    max_deadline_hours: cargo.max_deadline_hours,
+                    min_weight: cargo.min_weight,
+                    max_weight: cargo.max_weight,
+                    alpha: cargo.alpha,
+                    beta: cargo.beta,
+                    express_share: cargo.express_share,
+                    express_multiplier: cargo.express_multiplier,
+                    economy_share: cargo.economy_share,
+                    economy_multiplier: cargo.economy_multiplier,
+                },
+                passengers: PassengerTuning {
+                    max_deadline_hours: passengers.max_deadline_hours,
+                    min_count: passengers.min_count,
+                    max_count: passengers.max_count,
+                    alpha: passengers.alpha,
+                    beta: passengers.beta,
+                    fare_per_km: passengers.fare_per_km,
+                },
+            },
+            fuel: self.fuel_settings.clone(),
+            borders: BordersGameplay {
+                countries: 0,
+                ..self.borders.clone()
+            },
+            contracts: self.contract_settings.clone(),
+            charters: self.charter_settings.clone(),
+            forwarders: self.forwarder_settings.clone(),
+            auctions: self.auction_settings.clone(),
+            rare_cargo: self.rare_cargo_settings.clone(),
+            world_growth: WorldGrowthGameplay {
+                max_new_airports: self
+                    .world_growth_settings
+                    .max_new_airports
+                    .saturating_sub(self.opened_airports.len()),
+                ..self.world_growth_settings.clone()
+            },
+            market: cargo.market.clone(),
+            loyalty: self.loyalty_settings.clone(),
+            passenger_demand: self.demand_settings.clone(),
+            handling: self.handling_settings.clone(),
+            warehouse: self.warehouse_settings.clone(),
+            electric: self.electric_settings.clone(),
+            construction: self.construction_settings.clone(),
+            concessions: self.concession_settings.clone(),
+            used_market: self.market_settings.clone(),
+            maintenance: self.maintenance_settings.clone(),
+            pilots: self.pilot_settings.clone(),
+            fixed_costs: self.fixed_costs.clone(),
+            taxes: self.tax_settings.clone(),
+            overdraft: self.overdraft_settings.clone(),
+        };
+
+        // Only models that differ from the built-in catalog need to be spelled out
+        let defaults = default_model_catalog();
+        let mut models: Vec<AirplaneModelConfig> = self
+            .available_models()
+            .into_iter()
+            .filter(|(name, specs)| self.models_replace || defaults.get(name) != Some(specs))
+            .map(|(name, specs)| AirplaneModelConfig {
+                name,
+                mtow: specs.mtow,
+                cruise_speed: specs.cruise_speed,
+                fuel_capacity: specs.fuel_capacity,
+                fuel_consumption: specs.fuel_consumption,
+                operating_cost: specs.operating_cost,
+                payload_capacity: specs.payload_capacity,
+                passenger_capacity: specs.passenger_capacity,
+                cargo_slots: specs.cargo_slots,
+                purchase_price: specs.purchase_price,
+                min_runway_length: specs.min_runway_length,
+                role: specs.role,
+                power: specs.power,
+            })
+            .collect();
+        models.sort_by(|a, b| a.name.cmp(&b.name));
+        let airplanes = (!models.is_empty()).then_some(AirplanesConfig {
+            strategy: if self.models_replace {
+                AirplaneCatalogStrategy::Replace
+            } else {
+                AirplaneCatalogStrategy::Add
+            },
+            models,
+        });
+
+        let starting_fleet = self
+            .airplanes
+            .iter()
+            .filter_map(|plane| {
+                let home_airport = match plane.status {
+                    AirplaneStatus::InTransit { destination, .. } => destination,
+                    _ => self
+                        .map
+                        .airports
+                        .iter()
+                        .find(|(_, c)| *c == plane.location)
+                        .map(|(a, _)| a.id)?,
+                };
+                let model = self
+                    .model_catalog
+                    .iter()
+                    .filter(|(_, specs)| **specs == plane.specs)
+                    .map(|(name, _)| name.clone())
+                    .min()
+                    .unwrap_or_else(|| format!("{:?}", plane.model));
+                Some(StartingPlaneConfig {
+                    model,
+                    home_airport,
+                    fuel_level: (plane.current_fuel / plane.specs.fuel_capacity).clamp(0.0, 1.0),
+                })
+            })
+            .collect();
+
+        WorldConfig {
+            seed: Some(self.seed),
+            starting_cash: self.player.cash,
+            airports,
+            num_airports: None,
+            airport_names: Vec::new(),
+            world_style: self.map.world_style,
+            airport_dataset: None,
+            gameplay,
+            airplanes,
+            starting_fleet,
+            scripts: self
+                .scripts
+                .iter()
+                .map(|script| ScriptConfig {
+                    name: Some(script.name.clone()),
+                    path: None,
+                    source: Some(script.source.clone()),
+                })
+                .collect(),
+        }
+    }
 }
 
 fn model_specs_from_config(m: &AirplaneModelConfig) -> AirplaneSpecs {
@@ -2389,68 +8051,86 @@ fn model_specs_from_config(m: &AirplaneModelConfig) -> AirplaneSpecs {
         operating_cost: m.operating_cost,
         payload_capacity: m.payload_capacity,
         passenger_capacity: m.passenger_capacity,
+        cargo_slots: m.cargo_slots,
         purchase_price: m.purchase_price,
         min_runway_length: m.min_runway_length,
         role: m.role,
+        power: m.power,
     }
 }
 
-fn validate_model_config(m: &AirplaneModelConfig) -> Result<(), GameError> {
-    if m.name.trim().is_empty() {
-        return Err(GameError::InvalidConfig {
-            msg: "airplane model name cannot be empty".into(),
-        });
-    }
-    if m.mtow <= 0.0
-        || m.cruise_speed <= 0.0
-        || m.fuel_capacity <= 0.0
-        || m.fuel_consumption <= 0.0
-        || m.purchase_price <= 0.0
-        || m.min_runway_length <= 0.0
-    {
-        return Err(GameError::InvalidConfig {
-            msg: format!(
-                "airplane '{}' has non-positive required numeric fields",
-                m.name
-            ),
-        });
-    }
-    if m.operating_cost < 0.0 || m.payload_capacity < 0.0 {
-        return Err(GameError::InvalidConfig {
-            msg: format!("airplane '{}' has negative cost or payload", m.name),
-        });
-    }
-    match m.role {
-        crate::utils::airplanes::models::AirplaneRole::Cargo => {
-            if m.payload_capacity <= 0.0 {
-                return Err(GameError::InvalidConfig {
-                    msg: format!(
-                        "airplane '{}' role cargo requires payload_capacity > 0",
-                        m.name
-                    ),
-                });
-            }
-        }
-        crate::utils::airplanes::models::AirplaneRole::Passenger => {
-            if m.passenger_capacity == 0 {
-                return Err(GameError::InvalidConfig {
-                    msg: format!(
-                        "airplane '{}' role passenger requires passenger_capacity > 0",
-                        m.name
-                    ),
-                });
-            }
-        }
-        crate::utils::airplanes::models::AirplaneRole::Mixed => {
-            if m.payload_capacity <= 0.0 || m.passenger_capacity == 0 {
-                return Err(GameError::InvalidConfig {
-                    msg: format!(
-                        "airplane '{}' role mixed requires both passenger_capacity and payload_capacity",
-                        m.name
-                    ),
-                });
-            }
+/// Build the configured starting fleet, validating models, airports, runways and fuel.
+/// Read the scenario's scripts, loading the ones given by path.
+fn read_scripts(configs: &[ScriptConfig]) -> Result<Vec<ScriptSource>, GameError> {
+    configs
+        .iter()
+        .enumerate()
+        .map(|(idx, cfg)| {
+            let source = match (&cfg.source, &cfg.path) {
+                (Some(source), _) => source.clone(),
+                (None, Some(path)) => {
+                    fs::read_to_string(path).map_err(|e| GameError::InvalidConfig {
+                        msg: format!("scripts[{}]: could not read '{}': {}", idx, path, e),
+                    })?
+                }
+                (None, None) => {
+                    return Err(GameError::InvalidConfig {
+                        msg: format!("scripts[{}] needs a path or source", idx),
+                    });
+                }
+            };
+            let name = cfg
+                .name
+                .clone()
+                .or_else(|| cfg.path.clone())
+                .unwrap_or_else(|| format!("script{}", idx));
+            Ok(ScriptSource { name, source })
+        })
+        .collect()
+}
+
+fn build_starting_fleet(
+    entries: &[StartingPlaneConfig],
+    map: &Map,
+    catalog: &HashMap<String, AirplaneSpecs>,
+) -> Result<Vec<Airplane>, GameError> {
+    let mut fleet = Vec::with_capacity(entries.len());
+    for (plane_id, entry) in entries.iter().enumerate() {
+        let (name, specs) = catalog
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(entry.model.trim()))
+            .ok_or_else(|| GameError::InvalidConfig {
+                msg: format!(
+                    "starting_fleet[{}] uses unknown airplane model '{}'",
+                    plane_id, entry.model
+                ),
+            })?;
+        let (airport, coord) = map
+            .airports
+            .iter()
+            .find(|(a, _)| a.id == entry.home_airport)
+            .ok_or_else(|| GameError::InvalidConfig {
+                msg: format!(
+                    "starting_fleet[{}] references unknown airport {}",
+                    plane_id, entry.home_airport
+                ),
+            })?;
+        if airport.runway_length < specs.min_runway_length {
+            return Err(GameError::InvalidConfig {
+                msg: format!(
+                    "starting_fleet[{}]: {} needs {:.0}m of runway but airport {} has {:.0}m",
+                    plane_id, name, specs.min_runway_length, airport.id, airport.runway_length
+                ),
+            });
         }
+
+        let model = AirplaneModel::iter()
+            .find(|m| format!("{:?}", m).eq_ignore_ascii_case(name))
+            .unwrap_or(AirplaneModel::SparrowLight);
+        let mut plane = Airplane::new(plane_id, model, *coord);
+        plane.specs = *specs;
+        plane.current_fuel = specs.fuel_capacity * entry.fuel_level;
+        fleet.push(plane);
     }
-    Ok(())
+    Ok(fleet)
 }
@@ -0,0 +1,183 @@
+//! Worlds generated to a chosen difficulty, for curriculum learning.
+//!
+//! [`WorldGenerator::generate`] turns a seed and a [`Difficulty`] into a [`WorldConfig`]:
+//! more airports, longer routes, tighter deadlines and less starting cash the harder it
+//! gets. Raising the difficulty step by step hardens an environment gradually:
+//!
+//! ```
+//! use rusty_runways_core::Game;
+//! use rusty_runways_core::generator::WorldGenerator;
+//!
+//! let generator = WorldGenerator::default();
+//! for step in 0..=4 {
+//!     let config = generator.generate(7, step as f32 / 4.0);
+//!     let game = Game::from_config(config).unwrap();
+//!     assert!(game.airports().len() >= generator.min_airports);
+//! }
+//! ```
+
+use crate::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use crate::features::WORLD_SIZE;
+use crate::utils::airport::Airport;
+use crate::utils::map::WorldStyle;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+/// Mean distance between two points drawn uniformly from a unit square.
+const MEAN_UNIT_SQUARE_DISTANCE: f32 = 0.5214;
+
+/// How hard each aspect of a world is, from 0.0 (easiest) to 1.0 (hardest).
+///
+/// A single number sets every axis: `Difficulty::from(0.3)`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Difficulty {
+    /// Number of airports to choose from
+    pub airports: f32,
+    /// Average distance between airports
+    pub route_length: f32,
+    /// How little slack order deadlines leave
+    pub deadlines: f32,
+    /// How little cash the company starts with
+    pub cash: f32,
+}
+
+impl Difficulty {
+    /// The same difficulty on every axis.
+    pub fn uniform(level: f32) -> Self {
+        Difficulty {
+            airports: level,
+            route_length: level,
+            deadlines: level,
+            cash: level,
+        }
+    }
+}
+
+impl From<f32> for Difficulty {
+    fn from(level: f32) -> Self {
+        Difficulty::uniform(level)
+    }
+}
+
+/// The easiest and hardest value of each [`Difficulty`] axis; levels in between are
+/// interpolated, cash geometrically.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldGenerator {
+    pub min_airports: usize,
+    pub max_airports: usize,
+    /// Average distance between airports in km
+    pub short_routes_km: f32,
+    pub long_routes_km: f32,
+    /// Longest cargo deadline in hours; passenger deadlines shrink in proportion
+    pub loose_deadline_hours: u64,
+    pub tight_deadline_hours: u64,
+    /// Share of cargo orders that are express
+    pub loose_express_share: f32,
+    pub tight_express_share: f32,
+    pub rich_cash: f32,
+    pub poor_cash: f32,
+}
+
+impl Default for WorldGenerator {
+    fn default() -> Self {
+        WorldGenerator {
+            min_airports: 4,
+            max_airports: 60,
+            short_routes_km: 300.0,
+            long_routes_km: 3_000.0,
+            loose_deadline_hours: 168,
+            tight_deadline_hours: 36,
+            loose_express_share: 0.0,
+            tight_express_share: 0.5,
+            rich_cash: 2_000_000.0,
+            poor_cash: 200_000.0,
+        }
+    }
+}
+
+fn lerp(easy: f32, hard: f32, level: f32) -> f32 {
+    easy + (hard - easy) * level
+}
+
+impl WorldGenerator {
+    /// A world of the given difficulty. The same seed and difficulty always give the
+    /// same config; levels outside 0.0-1.0 are clamped.
+    pub fn generate(&self, seed: u64, difficulty: impl Into<Difficulty>) -> WorldConfig {
+        let difficulty = difficulty.into();
+        let level = |x: f32| if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) };
+
+        let count = lerp(
+            self.min_airports as f32,
+            self.max_airports as f32,
+            level(difficulty.airports),
+        )
+        .round()
+        .max(2.0) as usize;
+        // uniform placement in a square gives the wanted average distance
+        let route_km = lerp(
+            self.short_routes_km,
+            self.long_routes_km,
+            level(difficulty.route_length),
+        );
+        let side = (route_km / MEAN_UNIT_SQUARE_DISTANCE).min(WORLD_SIZE);
+        let origin = (WORLD_SIZE - side) / 2.0;
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_mul(61).wrapping_add(5));
+        let airports = (0..count)
+            .map(|id| AirportConfig {
+                id,
+                name: Airport::generate_random(seed, id).name,
+                location: Some(Location {
+                    x: origin + rng.gen_range(0.0..=side),
+                    y: origin + rng.gen_range(0.0..=side),
+                }),
+                runway_length_m: None,
+                fuel_price_per_l: None,
+                landing_fee_per_ton: None,
+                parking_fee_per_hour: None,
+                has_hangar: None,
+                population: None,
+                utc_offset: None,
+                country: None,
+                orders: Vec::new(),
+            })
+            .collect();
+
+        let mut gameplay = GameplayConfig::default();
+        let deadlines = level(difficulty.deadlines);
+        let cargo_deadline = lerp(
+            self.loose_deadline_hours as f32,
+            self.tight_deadline_hours as f32,
+            deadlines,
+        )
+        .round()
+        .max(1.0);
+        let orders = &mut gameplay.orders;
+        let shrink = cargo_deadline / orders.tuning.max_deadline_hours as f32;
+        orders.passengers.max_deadline_hours =
+            ((orders.passengers.max_deadline_hours as f32 * shrink).round() as u64).max(1);
+        orders.tuning.max_deadline_hours = cargo_deadline as u64;
+        orders.tuning.express_share = lerp(
+            self.loose_express_share,
+            self.tight_express_share,
+            deadlines,
+        );
+
+        let starting_cash =
+            self.rich_cash * (self.poor_cash / self.rich_cash).powf(level(difficulty.cash));
+
+        WorldConfig {
+            seed: Some(seed),
+            starting_cash,
+            airports,
+            num_airports: None,
+            airport_names: Vec::new(),
+            world_style: WorldStyle::default(),
+            airport_dataset: None,
+            gameplay,
+            airplanes: None,
+            starting_fleet: Vec::new(),
+            scripts: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Persistent game history in a SQLite file.
+//!
+//! Attach a file with [`Game::attach_history`](crate::Game::attach_history) and every
+//! daily report, cash movement and paid delivery is appended to it as the game runs, so
+//! long runs can be analysed without keeping all of it in memory. Rows are buffered and
+//! written in one transaction when the daily report comes out, so queries see the game up
+//! to the last full day. The tables are plain SQL and can be read by any SQLite client:
+//!
+//! - `days(day, income, expenses, net_cash, fleet_size, total_deliveries, stats)`, where
+//!   `stats` holds the whole [`DailyStats`] as JSON.
+//! - `ledger(time, delta, balance)`: every [`GameEvent::CashChanged`].
+//! - `deliveries(time, order_id, airport, payout)`: every [`GameEvent::Delivered`].
+//!
+//! Writing the file needs the `history` feature; without it attaching always fails.
+
+use crate::events::{GameEvent, GameTime};
+use crate::statistics::DailyStats;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// One cash movement from the ledger table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub time: GameTime,
+    pub delta: f32,
+    /// Cash right after the movement
+    pub balance: f32,
+}
+
+/// One paid delivery from the deliveries table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeliveryRecord {
+    pub time: GameTime,
+    pub order: usize,
+    pub airport: usize,
+    pub payout: f32,
+}
+
+/// Errors opening, writing or querying a history file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryError {
+    /// SQLite reported an error
+    Storage(String),
+    /// This build was compiled without the `history` feature
+    Unsupported,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Storage(reason) => write!(f, "Game history failed: {}", reason),
+            HistoryError::Unsupported => write!(
+                f,
+                "Game history is not supported by this build (enable the `history` feature)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// Where the history of the save `name` lives: next to it, as
+/// `save_games/<name>.history.sqlite`.
+pub fn path_for_save(name: &str) -> PathBuf {
+    Path::new("save_games").join(format!("{}.history.sqlite", name))
+}
+
+#[cfg(feature = "history")]
+mod store {
+    use super::{
+        DailyStats, DeliveryRecord, GameEvent, GameTime, HistoryError, LedgerEntry, Path, PathBuf,
+    };
+    use rusqlite::{Connection, params};
+    use std::fmt;
+    use std::sync::{Mutex, MutexGuard};
+
+    const SCHEMA: &str = "
+        CREATE TABLE IF NOT EXISTS days (
+            day INTEGER PRIMARY KEY,
+            income REAL NOT NULL,
+            expenses REAL NOT NULL,
+            net_cash REAL NOT NULL,
+            fleet_size INTEGER NOT NULL,
+            total_deliveries INTEGER NOT NULL,
+            stats TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS ledger (
+            time INTEGER NOT NULL,
+            delta REAL NOT NULL,
+            balance REAL NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS ledger_time ON ledger (time);
+        CREATE TABLE IF NOT EXISTS deliveries (
+            time INTEGER NOT NULL,
+            order_id INTEGER NOT NULL,
+            airport INTEGER NOT NULL,
+            payout REAL NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS deliveries_time ON deliveries (time);
+    ";
+
+    fn storage(e: impl fmt::Display) -> HistoryError {
+        HistoryError::Storage(e.to_string())
+    }
+
+    /// SQLite integers are signed; `u64::MAX` and friends mean "no upper bound".
+    fn bound(value: u64) -> u64 {
+        value.min(i64::MAX as u64)
+    }
+
+    /// An open history file and the rows not written to it yet.
+    pub struct History {
+        path: PathBuf,
+        // behind a lock so games stay `Sync`; a SQLite connection is only `Send`
+        conn: Mutex<Connection>,
+        pending: Vec<GameEvent>,
+    }
+
+    impl fmt::Debug for History {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("History")
+                .field("path", &self.path)
+                .field("pending", &self.pending.len())
+                .finish()
+        }
+    }
+
+    impl History {
+        /// Open `path`, creating the file and its tables if needed.
+        pub fn open(path: &Path) -> Result<History, HistoryError> {
+            let conn = Connection::open(path).map_err(storage)?;
+            conn.execute_batch(SCHEMA).map_err(storage)?;
+            Ok(History {
+                path: path.to_path_buf(),
+                conn: Mutex::new(conn),
+                pending: Vec::new(),
+            })
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        fn conn(&self) -> MutexGuard<'_, Connection> {
+            // writes run in transactions, so a panic cannot leave a half-written day behind
+            self.conn.lock().unwrap_or_else(|e| e.into_inner())
+        }
+
+        /// Drop every row after `time`, e.g. those of a later run from the same save.
+        pub fn truncate_after(&mut self, time: GameTime) -> Result<(), HistoryError> {
+            self.pending.clear();
+            let conn = self.conn.get_mut().unwrap_or_else(|e| e.into_inner());
+            let tx = conn.transaction().map_err(storage)?;
+            tx.execute("DELETE FROM days WHERE day * 24 > ?1", params![bound(time)])
+                .map_err(storage)?;
+            tx.execute("DELETE FROM ledger WHERE time > ?1", params![bound(time)])
+                .map_err(storage)?;
+            tx.execute(
+                "DELETE FROM deliveries WHERE time > ?1",
+                params![bound(time)],
+            )
+            .map_err(storage)?;
+            tx.commit().map_err(storage)
+        }
+
+        /// Queue `event` if the history keeps it, writing the queue on a daily report.
+        pub fn record(&mut self, event: &GameEvent) -> Result<(), HistoryError> {
+            match event {
+                GameEvent::CashChanged { .. } | GameEvent::Delivered { .. } => {
+                    self.pending.push(event.clone());
+                    Ok(())
+                }
+                GameEvent::DailyReport(_) => {
+                    self.pending.push(event.clone());
+                    self.flush()
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// Write every queued row in one transaction.
+        pub fn flush(&mut self) -> Result<(), HistoryError> {
+            if self.pending.is_empty() {
+                return Ok(());
+            }
+            let conn = self.conn.get_mut().unwrap_or_else(|e| e.into_inner());
+            let tx = conn.transaction().map_err(storage)?;
+            for event in &self.pending {
+                match event {
+                    GameEvent::CashChanged {
+                        time,
+                        delta,
+                        balance,
+                    } => tx.execute(
+                        "INSERT INTO ledger (time, delta, balance) VALUES (?1, ?2, ?3)",
+                        params![time, delta, balance],
+                    ),
+                    GameEvent::Delivered {
+                        time,
+                        order,
+                        airport,
+                        payout,
+                    } => tx.execute(
+                        "INSERT INTO deliveries (time, order_id, airport, payout)
+                         VALUES (?1, ?2, ?3, ?4)",
+                        params![time, *order as u64, *airport as u64, payout],
+                    ),
+                    GameEvent::DailyReport(stats) => {
+                        let json = serde_json::to_string(stats).map_err(storage)?;
+                        tx.execute(
+                            "INSERT OR REPLACE INTO days
+                             (day, income, expenses, net_cash, fleet_size, total_deliveries, stats)
+                             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                            params![
+                                stats.day,
+                                stats.income,
+                                stats.expenses,
+                                stats.net_cash,
+                                stats.fleet_size as u64,
+                                stats.total_deliveries as u64,
+                                json
+                            ],
+                        )
+                    }
+                    _ => Ok(0),
+                }
+                .map_err(storage)?;
+            }
+            tx.commit().map_err(storage)?;
+            self.pending.clear();
+            Ok(())
+        }
+
+        /// Daily reports for days `first..=last`, oldest first.
+        pub fn days(&self, first: u64, last: u64) -> Result<Vec<DailyStats>, HistoryError> {
+            let conn = self.conn();
+            let mut stmt = conn
+                .prepare("SELECT stats FROM days WHERE day BETWEEN ?1 AND ?2 ORDER BY day")
+                .map_err(storage)?;
+            let rows = stmt
+                .query_map(params![bound(first), bound(last)], |row| {
+                    row.get::<_, String>(0)
+                })
+                .map_err(storage)?;
+            rows.map(|json| serde_json::from_str(&json.map_err(storage)?).map_err(storage))
+                .collect()
+        }
+
+        /// Number of daily reports written so far.
+        pub fn day_count(&self) -> Result<u64, HistoryError> {
+            self.conn()
+                .query_row("SELECT COUNT(*) FROM days", [], |row| row.get(0))
+                .map_err(storage)
+        }
+
+        /// Cash movements between `from` and `to` hours inclusive, oldest first.
+        pub fn ledger(
+            &self,
+            from: GameTime,
+            to: GameTime,
+        ) -> Result<Vec<LedgerEntry>, HistoryError> {
+            let conn = self.conn();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT time, delta, balance FROM ledger
+                     WHERE time BETWEEN ?1 AND ?2 ORDER BY rowid",
+                )
+                .map_err(storage)?;
+            stmt.query_map(params![bound(from), bound(to)], |row| {
+                Ok(LedgerEntry {
+                    time: row.get(0)?,
+                    delta: row.get(1)?,
+                    balance: row.get(2)?,
+                })
+            })
+            .map_err(storage)?
+            .map(|entry| entry.map_err(storage))
+            .collect()
+        }
+
+        /// Paid deliveries between `from` and `to` hours inclusive, oldest first.
+        pub fn deliveries(
+            &self,
+            from: GameTime,
+            to: GameTime,
+        ) -> Result<Vec<DeliveryRecord>, HistoryError> {
+            let conn = self.conn();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT time, order_id, airport, payout FROM deliveries
+                     WHERE time BETWEEN ?1 AND ?2 ORDER BY rowid",
+                )
+                .map_err(storage)?;
+            stmt.query_map(params![bound(from), bound(to)], |row| {
+                Ok(DeliveryRecord {
+                    time: row.get(0)?,
+                    order: row.get::<_, u64>(1)? as usize,
+                    airport: row.get::<_, u64>(2)? as usize,
+                    payout: row.get(3)?,
+                })
+            })
+            .map_err(storage)?
+            .map(|record| record.map_err(storage))
+            .collect()
+        }
+
+        /// Total paid out for deliveries between `from` and `to` hours inclusive.
+        pub fn delivery_revenue(&self, from: GameTime, to: GameTime) -> Result<f32, HistoryError> {
+            self.conn()
+                .query_row(
+                    "SELECT COALESCE(SUM(payout), 0) FROM deliveries WHERE time BETWEEN ?1 AND ?2",
+                    params![bound(from), bound(to)],
+                    |row| row.get::<_, f64>(0),
+                )
+                .map(|sum| sum as f32)
+                .map_err(storage)
+        }
+    }
+
+    impl Drop for History {
+        fn drop(&mut self) {
+            // rows of an unfinished day; nowhere to report a failure this late
+            let _ = self.flush();
+        }
+    }
+}
+
+#[cfg(feature = "history")]
+pub use store::History;
+
+/// Stand-in without the `history` feature: can never be opened.
+#[cfg(not(feature = "history"))]
+#[derive(Debug)]
+pub struct History {}
+
+#[cfg(not(feature = "history"))]
+impl History {
+    pub fn open(_path: &Path) -> Result<History, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn path(&self) -> &Path {
+        Path::new("")
+    }
+
+    pub fn truncate_after(&mut self, _time: GameTime) -> Result<(), HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn record(&mut self, _event: &GameEvent) -> Result<(), HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn flush(&mut self) -> Result<(), HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn days(&self, _first: u64, _last: u64) -> Result<Vec<DailyStats>, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn day_count(&self) -> Result<u64, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn ledger(&self, _from: GameTime, _to: GameTime) -> Result<Vec<LedgerEntry>, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn deliveries(
+        &self,
+        _from: GameTime,
+        _to: GameTime,
+    ) -> Result<Vec<DeliveryRecord>, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+
+    pub fn delivery_revenue(&self, _from: GameTime, _to: GameTime) -> Result<f32, HistoryError> {
+        Err(HistoryError::Unsupported)
+    }
+}
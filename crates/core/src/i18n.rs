@@ -0,0 +1,208 @@
+//! Translations of user-facing text.
+//!
+//! Messages are looked up by id in [Fluent](https://projectfluent.org) bundles. English
+//! is built in; community translations are `.ftl` files named after their locale, e.g.
+//! `locales/de.ftl`, loaded with [`load_locale`] or [`init_from_env`]. Messages missing
+//! from a translation fall back to English.
+//!
+//! Use the [`tr!`](crate::tr) macro to format a message:
+//!
+//! ```
+//! use rusty_runways_core::tr;
+//! assert_eq!(tr!("error-no-cargo"), "No cargo to unload");
+//! assert_eq!(tr!("error-airport-id-invalid", id = 4), "Airport with id 4 does not exist");
+//! ```
+
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+/// Locale of the built-in messages.
+pub const DEFAULT_LOCALE: &str = "en-US";
+/// Environment variable picking the locale, e.g. `RUSTY_RUNWAYS_LANG=de`.
+pub const LANG_ENV: &str = "RUSTY_RUNWAYS_LANG";
+/// Environment variable pointing at the directory holding translation bundles.
+pub const LOCALE_DIR_ENV: &str = "RUSTY_RUNWAYS_LOCALES";
+/// Directory searched for translation bundles when [`LOCALE_DIR_ENV`] is not set.
+pub const DEFAULT_LOCALE_DIR: &str = "locales";
+
+const ENGLISH: &str = include_str!("../locales/en-US.ftl");
+
+/// Problems loading a translation bundle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum I18nError {
+    /// The locale is not a valid language tag
+    InvalidLocale { locale: String },
+    /// The bundle file could not be read
+    Read { path: String, reason: String },
+    /// The bundle is not valid Fluent syntax
+    Parse { locale: String, reason: String },
+}
+
+impl fmt::Display for I18nError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            I18nError::InvalidLocale { locale } => write!(f, "`{}` is not a valid locale", locale),
+            I18nError::Read { path, reason } => {
+                write!(f, "Failed to read translations {}: {}", path, reason)
+            }
+            I18nError::Parse { locale, reason } => {
+                write!(f, "Invalid translations for {}: {}", locale, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for I18nError {}
+
+fn bundle(locale: &str, source: &str) -> Result<FluentBundle<FluentResource>, I18nError> {
+    let langid: LanguageIdentifier = locale.parse().map_err(|_| I18nError::InvalidLocale {
+        locale: locale.to_string(),
+    })?;
+    let resource =
+        FluentResource::try_new(source.to_string()).map_err(|(_, errors)| I18nError::Parse {
+            locale: locale.to_string(),
+            reason: format!("{:?}", errors[0]),
+        })?;
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // terminals and egui don't render the Unicode isolation marks around arguments
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .map_err(|errors| I18nError::Parse {
+            locale: locale.to_string(),
+            reason: format!("{:?}", errors[0]),
+        })?;
+    Ok(bundle)
+}
+
+/// Formats messages in one locale, falling back to English.
+pub struct Localizer {
+    locale: String,
+    translation: Option<FluentBundle<FluentResource>>,
+    english: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    /// The built-in English messages.
+    pub fn english() -> Self {
+        Localizer {
+            locale: DEFAULT_LOCALE.to_string(),
+            translation: None,
+            english: bundle(DEFAULT_LOCALE, ENGLISH).expect("built-in messages are valid"),
+        }
+    }
+
+    /// English overlaid with a translation bundle.
+    ///
+    /// Parameters
+    /// - `locale`: Language tag of the translation, e.g. `de` or `pt-BR`.
+    /// - `source`: Contents of the `.ftl` file.
+    pub fn with_translation(locale: &str, source: &str) -> Result<Self, I18nError> {
+        Ok(Localizer {
+            locale: locale.to_string(),
+            translation: Some(bundle(locale, source)?),
+            ..Localizer::english()
+        })
+    }
+
+    /// Language tag of the active translation.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Format message `id` with `args`.
+    ///
+    /// Returns the translation if it has the message, else the English text, else `id`.
+    pub fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in self.translation.iter().chain([&self.english]) {
+            if let Some(pattern) = bundle.get_message(id).and_then(|m| m.value()) {
+                let mut errors = Vec::new();
+                return bundle
+                    .format_pattern(pattern, args, &mut errors)
+                    .into_owned();
+            }
+        }
+        id.to_string()
+    }
+}
+
+fn active() -> &'static RwLock<Localizer> {
+    static ACTIVE: OnceLock<RwLock<Localizer>> = OnceLock::new();
+    ACTIVE.get_or_init(|| RwLock::new(Localizer::english()))
+}
+
+/// Use `localizer` for all messages from now on.
+pub fn set_localizer(localizer: Localizer) {
+    *active().write().unwrap_or_else(|e| e.into_inner()) = localizer;
+}
+
+/// Language tag of the messages in use.
+pub fn locale() -> String {
+    active()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .locale()
+        .to_string()
+}
+
+/// Switch to `locale`, reading `<dir>/<locale>.ftl`.
+///
+/// English (`en` or `en-US`) needs no file and drops any loaded translation.
+pub fn load_locale(locale: &str, dir: &Path) -> Result<(), I18nError> {
+    if locale == "en" || locale == DEFAULT_LOCALE {
+        set_localizer(Localizer::english());
+        return Ok(());
+    }
+    let path = dir.join(format!("{}.ftl", locale));
+    let source = std::fs::read_to_string(&path).map_err(|e| I18nError::Read {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    set_localizer(Localizer::with_translation(locale, &source)?);
+    Ok(())
+}
+
+/// Directory holding translation bundles: [`LOCALE_DIR_ENV`] or [`DEFAULT_LOCALE_DIR`].
+pub fn locale_dir() -> PathBuf {
+    std::env::var(LOCALE_DIR_ENV)
+        .unwrap_or_else(|_| DEFAULT_LOCALE_DIR.to_string())
+        .into()
+}
+
+/// Switch to the locale named by [`LANG_ENV`], if set, reading bundles from
+/// [`locale_dir`].
+pub fn init_from_env() -> Result<(), I18nError> {
+    let Ok(locale) = std::env::var(LANG_ENV) else {
+        return Ok(());
+    };
+    load_locale(locale.trim(), &locale_dir())
+}
+
+/// Format message `id` in the active locale.
+pub fn tr_args(id: &str, args: Option<&FluentArgs>) -> String {
+    active()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .format(id, args)
+}
+
+/// Format a message in the active locale: `tr!("id")` or `tr!("id", name = value, ...)`.
+///
+/// Values can be strings or numbers; format floats to the precision you want first.
+#[macro_export]
+macro_rules! tr {
+    ($id:expr) => {
+        $crate::i18n::tr_args($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr_args($id, Some(&args))
+    }};
+}
@@ -1,9 +1,21 @@
 #![allow(non_snake_case)]
 
+pub mod actions;
 pub mod config;
+pub mod diff;
+#[cfg(feature = "msgpack")]
+pub mod encoding;
 pub mod events;
+pub mod features;
 pub mod game;
+pub mod generator;
+pub mod history;
+pub mod i18n;
+pub mod metrics;
 pub mod player;
+pub mod plugins;
+pub mod save;
+pub mod scripting;
 pub mod statistics;
 pub mod utils;
 
@@ -0,0 +1,157 @@
+//! Prometheus metrics for servers hosting games.
+//!
+//! [`ServerMetrics`] counts what the server did; [`ServerMetrics::render`] adds gauges for
+//! each running game and writes everything in the Prometheus text format. [`serve`]
+//! answers `GET /metrics` with it on a plain HTTP listener, which is all a Prometheus
+//! scraper needs.
+
+use crate::Game;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Counters shared by every client of a server.
+#[derive(Debug)]
+pub struct ServerMetrics {
+    started: Instant,
+    hours: AtomicU64,
+    events: AtomicU64,
+    commands: AtomicU64,
+}
+
+impl Default for ServerMetrics {
+    fn default() -> Self {
+        ServerMetrics {
+            started: Instant::now(),
+            hours: AtomicU64::new(0),
+            events: AtomicU64::new(0),
+            commands: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ServerMetrics {
+    /// Count one handled request that simulated `hours` and caused `events` game events.
+    pub fn record(&self, hours: u64, events: u64) {
+        self.commands.fetch_add(1, Ordering::Relaxed);
+        self.hours.fetch_add(hours, Ordering::Relaxed);
+        self.events.fetch_add(events, Ordering::Relaxed);
+    }
+
+    /// The metrics in the Prometheus text format, with gauges for `games` by id.
+    pub fn render<'a>(&self, games: impl IntoIterator<Item = (u64, &'a Game)>) -> String {
+        let events = self.events.load(Ordering::Relaxed);
+        let uptime = self.started.elapsed().as_secs_f64();
+        let games: Vec<(u64, &Game)> = games.into_iter().collect();
+
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: Vec<(String, f64)>| {
+            let _ = writeln!(out, "# HELP rusty_runways_{} {}", name, help);
+            let _ = writeln!(out, "# TYPE rusty_runways_{} {}", name, kind);
+            for (labels, value) in samples {
+                let _ = writeln!(out, "rusty_runways_{}{} {}", name, labels, value);
+            }
+        };
+        metric(
+            "hours_simulated_total",
+            "counter",
+            "Game hours simulated across all games.",
+            vec![(String::new(), self.hours.load(Ordering::Relaxed) as f64)],
+        );
+        metric(
+            "events_total",
+            "counter",
+            "Game events emitted across all games.",
+            vec![(String::new(), events as f64)],
+        );
+        metric(
+            "events_per_second",
+            "gauge",
+            "Game events per second since the server started.",
+            vec![(
+                String::new(),
+                if uptime > 0.0 {
+                    events as f64 / uptime
+                } else {
+                    0.0
+                },
+            )],
+        );
+        metric(
+            "commands_total",
+            "counter",
+            "Requests handled.",
+            vec![(String::new(), self.commands.load(Ordering::Relaxed) as f64)],
+        );
+        metric(
+            "active_games",
+            "gauge",
+            "Games currently running.",
+            vec![(String::new(), games.len() as f64)],
+        );
+        let per_game = |value: fn(&Game) -> f64| {
+            games
+                .iter()
+                .map(|(id, game)| (format!("{{game=\"{}\"}}", id), value(game)))
+                .collect()
+        };
+        metric(
+            "game_cash",
+            "gauge",
+            "Cash of each game.",
+            per_game(|g| g.player.cash as f64),
+        );
+        metric(
+            "game_fleet_size",
+            "gauge",
+            "Planes owned in each game.",
+            per_game(|g| g.player.fleet_size as f64),
+        );
+        metric(
+            "game_time_hours",
+            "gauge",
+            "Game clock of each game, in hours.",
+            per_game(|g| g.time as f64),
+        );
+        out
+    }
+}
+
+/// Answer `GET /metrics` on `listener` with whatever `render` returns, one request per
+/// connection, forever. Any other path gets a 404.
+pub fn serve(listener: TcpListener, render: impl Fn() -> String) {
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        // a client that never finishes its request must not stall the next scrape
+        let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).is_err() {
+            continue;
+        }
+        // read the headers too, closing with unread data would reset the connection
+        let mut header = String::new();
+        while reader.read_line(&mut header).is_ok_and(|n| n > 0) && !header.trim().is_empty() {
+            header.clear();
+        }
+        let mut parts = request_line.split_whitespace();
+        let response = match (parts.next(), parts.next()) {
+            (Some("GET"), Some("/metrics")) => {
+                let body = render();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            }
+            _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                .to_string(),
+        };
+        // a scraper that hung up early just misses this sample
+        let _ = stream.write_all(response.as_bytes());
+    }
+}
@@ -1,14 +1,17 @@
+use crate::events::GameTime;
 use crate::utils::{
     airplanes::{
-        airplane::Airplane,
+        airplane::{Airplane, DEFAULT_RESERVE_HOURS},
         models::{AirplaneModel, AirplaneSpecs},
     },
     airport::Airport,
     coordinate::Coordinate,
     errors::GameError,
     map::Map,
+    pilots::Pilot,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use strum::IntoEnumIterator;
 
 /// Player/company state and operations.
@@ -24,9 +27,29 @@ pub struct Player {
     pub fleet: Vec<Airplane>,
     /// Total orders successfully delivered
     pub orders_delivered: usize,
+    /// Named groups of plane ids, e.g. `feeders` or `heavies`
+    #[serde(default)]
+    pub groups: BTreeMap<String, BTreeSet<usize>>,
+    /// Pilots on the payroll
+    #[serde(default)]
+    pub pilots: Vec<Pilot>,
+    /// Id handed to the next hired pilot
+    #[serde(default)]
+    pub next_pilot_id: usize,
+    /// Lower bound for the next plane id; ids of sold planes are never handed out again
+    #[serde(default)]
+    pub next_plane_id: usize,
+    /// How far below zero cash may be spent, set from the overdraft settings
+    #[serde(default)]
+    pub overdraft_limit: f32,
 }
 
 impl Player {
+    /// Cash that can still be spent, counting what is left of the overdraft.
+    pub fn available_funds(&self) -> f32 {
+        self.cash + self.overdraft_limit
+    }
+
     /// Create a new player with a starter airplane.
     ///
     /// The starter airplane is chosen to be affordable and able to operate between at
@@ -39,30 +62,32 @@ impl Player {
     /// Returns
     /// - `Player`: New player with one airplane and initial cash/fleet size set.
     pub fn new(starting_cash: f32, map: &Map) -> Self {
-        let (_min_dist, start_idx) = map.min_distance();
-        let start_coord = map.airports[start_idx].1;
-        let start_runway = map.airports[start_idx].0.runway_length;
+        let (_min_dist, start_id) = map.min_distance();
+        let (start_airport, start_coord) = map
+            .airports
+            .get(start_id)
+            .expect("min_distance returns an airport on the map");
+        let (start_coord, start_runway) = (*start_coord, start_airport.runway_length);
 
         // find all models that can both take off from start AND transit AND land at some other airport
+        // (electric models are left out: no airport has a charger yet)
         let candidates = AirplaneModel::iter()
             .filter(|model| {
                 let specs = model.specs();
-                let max_range = specs.fuel_capacity / specs.fuel_consumption * specs.cruise_speed;
+                let max_range = specs.full_range(DEFAULT_RESERVE_HOURS);
 
                 // start runway long enough?
-                if start_runway < specs.min_runway_length {
+                if specs.is_electric() || start_runway < specs.min_runway_length {
                     return false;
                 }
 
                 // can reach & land at other airport?
                 map.airports.iter().any(|(other_airport, other_coord)| {
-                    if other_airport.id == start_idx {
+                    if other_airport.id == start_id {
                         return false;
                     }
 
-                    let dx = other_coord.x - start_coord.x;
-                    let dy = other_coord.y - start_coord.y;
-                    let dist = (dx * dx + dy * dy).sqrt();
+                    let dist = start_coord.distance_to(other_coord);
 
                     dist <= max_range && other_airport.runway_length >= specs.min_runway_length
                 })
@@ -81,13 +106,17 @@ impl Player {
             .unwrap_or(AirplaneModel::CometRegional);
 
         // assign player new plane
-        let (_, start_coord) = map.airports[start_idx];
 
         Player {
             cash: starting_cash,
             fleet_size: 1,
             fleet: vec![Airplane::new(0, best_model, start_coord)],
             orders_delivered: 0,
+            groups: BTreeMap::new(),
+            pilots: Vec::new(),
+            next_pilot_id: 0,
+            next_plane_id: 1,
+            overdraft_limit: 0.0,
         }
     }
 
@@ -99,26 +128,27 @@ impl Player {
         map: &Map,
         catalog: &std::collections::HashMap<String, AirplaneSpecs>,
     ) -> Self {
-        let (_min_dist, start_idx) = map.min_distance();
-        let start_coord = map.airports[start_idx].1;
-        let start_runway = map.airports[start_idx].0.runway_length;
+        let (_min_dist, start_id) = map.min_distance();
+        let (start_airport, start_coord) = map
+            .airports
+            .get(start_id)
+            .expect("min_distance returns an airport on the map");
+        let (start_coord, start_runway) = (*start_coord, start_airport.runway_length);
 
         // find all catalog entries that can take off and land elsewhere
         let mut candidates: Vec<(&str, AirplaneSpecs)> = catalog
             .iter()
             .map(|(n, s)| (n.as_str(), *s))
             .filter(|(_, specs)| {
-                let max_range = specs.fuel_capacity / specs.fuel_consumption * specs.cruise_speed;
-                if start_runway < specs.min_runway_length {
+                let max_range = specs.full_range(DEFAULT_RESERVE_HOURS);
+                if specs.is_electric() || start_runway < specs.min_runway_length {
                     return false;
                 }
                 map.airports.iter().any(|(other_airport, other_coord)| {
-                    if other_airport.id == start_idx {
+                    if other_airport.id == start_id {
                         return false;
                     }
-                    let dx = other_coord.x - start_coord.x;
-                    let dy = other_coord.y - start_coord.y;
-                    let dist = (dx * dx + dy * dy).sqrt();
+                    let dist = start_coord.distance_to(other_coord);
                     dist <= max_range && other_airport.runway_length >= specs.min_runway_length
                 })
             })
@@ -138,6 +168,11 @@ impl Player {
                 fleet_size: 1,
                 fleet: vec![plane],
                 orders_delivered: 0,
+                groups: BTreeMap::new(),
+                pilots: Vec::new(),
+                next_pilot_id: 0,
+                next_plane_id: 1,
+                overdraft_limit: 0.0,
             };
         }
 
@@ -145,6 +180,38 @@ impl Player {
         Player::new(starting_cash, map)
     }
 
+    /// Create a player that already owns `fleet` (e.g. a configured starting fleet).
+    ///
+    /// Parameters
+    /// - `starting_cash`: Initial cash balance.
+    /// - `fleet`: Planes owned at the start, each with a unique id.
+    ///
+    /// Returns
+    /// - `Player`: New player with the given fleet.
+    pub fn with_fleet(starting_cash: f32, fleet: Vec<Airplane>) -> Self {
+        Player {
+            cash: starting_cash,
+            fleet_size: fleet.len(),
+            next_plane_id: fleet.iter().map(|p| p.id + 1).max().unwrap_or(0),
+            fleet,
+            orders_delivered: 0,
+            groups: BTreeMap::new(),
+            pilots: Vec::new(),
+            next_pilot_id: 0,
+            overdraft_limit: 0.0,
+        }
+    }
+
+    /// Reserve the next plane id not used by any plane in the fleet.
+    ///
+    /// Ids only grow, so a plane bought after a sale never takes over the sold plane's id.
+    pub fn allocate_plane_id(&mut self) -> usize {
+        let in_use = self.fleet.iter().map(|p| p.id + 1).max().unwrap_or(0);
+        let id = self.next_plane_id.max(in_use);
+        self.next_plane_id = id + 1;
+        id
+    }
+
     /// Purchase an additional plane of the given model at `home_coord`.
     ///
     /// Parameters
@@ -170,9 +237,9 @@ impl Player {
             })?;
 
         let specs = model.specs();
-        if self.cash < specs.purchase_price {
+        if self.available_funds() < specs.purchase_price {
             return Err(GameError::InsufficientFunds {
-                have: self.cash,
+                have: self.available_funds(),
                 need: specs.purchase_price,
             });
         }
@@ -183,8 +250,8 @@ impl Player {
             });
         }
         self.cash -= specs.purchase_price;
-        let plane_id = self.fleet_size;
-        let plane_coord = Coordinate::new(home_coord.x, home_coord.y);
+        let plane_id = self.allocate_plane_id();
+        let plane_coord = *home_coord;
         let plane = Airplane::new(plane_id, model, plane_coord);
         self.fleet.push(plane);
         self.fleet_size += 1;
@@ -199,9 +266,9 @@ impl Player {
         home_coord: &Coordinate,
         specs: AirplaneSpecs,
     ) -> Result<(), GameError> {
-        if self.cash < specs.purchase_price {
+        if self.available_funds() < specs.purchase_price {
             return Err(GameError::InsufficientFunds {
-                have: self.cash,
+                have: self.available_funds(),
                 need: specs.purchase_price,
             });
         }
@@ -213,8 +280,8 @@ impl Player {
         }
 
         self.cash -= specs.purchase_price;
-        let plane_id = self.fleet_size;
-        let plane_coord = Coordinate::new(home_coord.x, home_coord.y);
+        let plane_id = self.allocate_plane_id();
+        let plane_coord = *home_coord;
         let mut plane = Airplane::new(plane_id, AirplaneModel::SparrowLight, plane_coord);
         plane.specs = specs;
         plane.current_fuel = specs.fuel_capacity;
@@ -223,14 +290,19 @@ impl Player {
         Ok(())
     }
 
-    /// Sell a plane by id, returning the removed airplane and cash refund.
+    /// Sell a plane by id at its current resale value.
     ///
     /// Parameters
     /// - `plane_id`: ID of the plane to sell.
+    /// - `now`: Current game time, used to age the plane.
     ///
     /// Returns
     /// - `(Airplane, f32)`: The removed plane and refund amount.
-    pub fn sell_plane(&mut self, plane_id: usize) -> Result<(Airplane, f32), GameError> {
+    pub fn sell_plane(
+        &mut self,
+        plane_id: usize,
+        now: GameTime,
+    ) -> Result<(Airplane, f32), GameError> {
         let idx = self
             .fleet
             .iter()
@@ -238,13 +310,59 @@ impl Player {
             .ok_or(GameError::PlaneIdInvalid { id: plane_id })?;
 
         let plane = self.fleet.remove(idx);
-        let refund = plane.specs.purchase_price * 0.6;
+        let refund = plane.resale_value(now);
         self.cash += refund;
         self.fleet_size = self.fleet.len();
+        for members in self.groups.values_mut() {
+            members.remove(&plane_id);
+        }
+        self.groups.retain(|_, members| !members.is_empty());
 
         Ok((plane, refund))
     }
 
+    /// Add a plane to a group, creating the group if needed.
+    ///
+    /// Group names are case-insensitive and stored in lowercase.
+    pub fn add_to_group(&mut self, group: &str, plane_id: usize) {
+        self.groups
+            .entry(group.to_lowercase())
+            .or_default()
+            .insert(plane_id);
+    }
+
+    /// Remove a plane from a group; the group disappears once it is empty.
+    ///
+    /// Returns
+    /// - `bool`: Whether the plane was in the group.
+    pub fn remove_from_group(&mut self, group: &str, plane_id: usize) -> bool {
+        let key = group.to_lowercase();
+        let Some(members) = self.groups.get_mut(&key) else {
+            return false;
+        };
+        let removed = members.remove(&plane_id);
+        if members.is_empty() {
+            self.groups.remove(&key);
+        }
+        removed
+    }
+
+    /// Plane ids in a group, in ascending order.
+    pub fn group_members(&self, group: &str) -> Option<Vec<usize>> {
+        self.groups
+            .get(&group.to_lowercase())
+            .map(|members| members.iter().copied().collect())
+    }
+
+    /// Names of the groups a plane belongs to.
+    pub fn groups_of(&self, plane_id: usize) -> Vec<String> {
+        self.groups
+            .iter()
+            .filter(|(_, members)| members.contains(&plane_id))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
     /// Records that the player has delivered an order.
     ///
     /// Increments the `orders_delivered` counter by 1.
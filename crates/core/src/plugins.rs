@@ -0,0 +1,309 @@
+//! WASM plugins that hook into the simulation.
+//!
+//! A plugin is a WebAssembly module exporting any of these hooks; missing ones are skipped:
+//! - `on_day_end(day: i64, cash: f32)`: called when the daily report is written.
+//! - `on_order_generated(order: i64, origin: i64, destination: i64, value: f32) -> f32`:
+//!   called for every order a restock creates; returns the order's value.
+//! - `on_delivery(order: i64, airport: i64, payout: f32) -> f32`: called for every paid
+//!   delivery; returns the payout to credit.
+//!
+//! Hooks can act on the game through functions imported from the `host` module:
+//! - `add_cash(amount: f32)`: pay the player, or charge them with a negative amount.
+//! - `world_event(airport: i64, factor: f32, hours: i64)`: start a fuel price event at an
+//!   airport id, or at every airport when `airport` is negative.
+//!
+//! Each hook call gets [`PLUGIN_FUEL_PER_CALL`] units of fuel. A plugin that runs out or
+//! traps is unloaded and the failure is logged; the game carries on without it.
+//!
+//! Loading modules needs the `plugins` feature. Without it the host is always empty and
+//! every hook passes values through unchanged.
+
+use crate::events::GameTime;
+#[cfg(not(feature = "plugins"))]
+use crate::utils::orders::order::Order;
+use std::fmt;
+
+/// Fuel (roughly, wasm instructions) a plugin may use per hook call
+pub const PLUGIN_FUEL_PER_CALL: u64 = 10_000_000;
+
+/// Changes requested by plugins during one hook, applied by the game afterwards.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PluginEffects {
+    /// Net cash to credit (negative to charge)
+    pub cash: f32,
+    /// Fuel price events to start now: airport id (`None` for all), factor and hours
+    pub world_events: Vec<(Option<usize>, f32, GameTime)>,
+    /// Plugins that failed and were unloaded, with the reason
+    pub failures: Vec<String>,
+    /// Messages to print and log (only scripts send these)
+    pub messages: Vec<String>,
+}
+
+impl PluginEffects {
+    /// Add the effects of another hook call.
+    pub fn extend(&mut self, other: PluginEffects) {
+        self.cash += other.cash;
+        self.world_events.extend(other.world_events);
+        self.failures.extend(other.failures);
+        self.messages.extend(other.messages);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cash == 0.0
+            && self.world_events.is_empty()
+            && self.failures.is_empty()
+            && self.messages.is_empty()
+    }
+}
+
+/// Errors loading a plugin.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginError {
+    /// The module could not be compiled or instantiated
+    Load { name: String, reason: String },
+    /// This build was compiled without the `plugins` feature
+    Unsupported,
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Load { name, reason } => {
+                write!(f, "Could not load plugin '{}': {}", name, reason)
+            }
+            PluginError::Unsupported => write!(
+                f,
+                "Plugins are not supported by this build (enable the `plugins` feature)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+#[cfg(feature = "plugins")]
+mod runtime {
+    use super::{PLUGIN_FUEL_PER_CALL, PluginEffects, PluginError};
+    use crate::events::GameTime;
+    use crate::utils::orders::order::Order;
+    use std::fmt;
+    use wasmi::{Caller, Config, Engine, Linker, Module, Store, TypedFunc};
+
+    struct Plugin {
+        name: String,
+        store: Store<PluginEffects>,
+        on_day_end: Option<TypedFunc<(i64, f32), ()>>,
+        on_order_generated: Option<TypedFunc<(i64, i64, i64, f32), f32>>,
+        on_delivery: Option<TypedFunc<(i64, i64, f32), f32>>,
+    }
+
+    /// Loaded plugins, called in load order.
+    #[derive(Default)]
+    pub struct PluginHost {
+        plugins: Vec<Plugin>,
+    }
+
+    impl fmt::Debug for PluginHost {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.names()).finish()
+        }
+    }
+
+    fn load_error(name: &str, e: impl fmt::Display) -> PluginError {
+        PluginError::Load {
+            name: name.to_string(),
+            reason: e.to_string(),
+        }
+    }
+
+    impl PluginHost {
+        /// Compile and instantiate a plugin from WASM bytes.
+        pub fn load(&mut self, name: &str, wasm: &[u8]) -> Result<(), PluginError> {
+            let mut config = Config::default();
+            config.consume_fuel(true);
+            let engine = Engine::new(&config);
+            let module = Module::new(&engine, wasm).map_err(|e| load_error(name, e))?;
+
+            let mut linker = <Linker<PluginEffects>>::new(&engine);
+            linker
+                .func_wrap(
+                    "host",
+                    "add_cash",
+                    |mut caller: Caller<'_, PluginEffects>, amount: f32| {
+                        if amount.is_finite() {
+                            caller.data_mut().cash += amount;
+                        }
+                    },
+                )
+                .map_err(|e| load_error(name, e))?;
+            linker
+                .func_wrap(
+                    "host",
+                    "world_event",
+                    |mut caller: Caller<'_, PluginEffects>,
+                     airport: i64,
+                     factor: f32,
+                     hours: i64| {
+                        if factor.is_finite() && factor > 0.0 && hours > 0 {
+                            let airport = usize::try_from(airport).ok();
+                            caller.data_mut().world_events.push((
+                                airport,
+                                factor,
+                                hours as GameTime,
+                            ));
+                        }
+                    },
+                )
+                .map_err(|e| load_error(name, e))?;
+
+            let mut store = Store::new(&engine, PluginEffects::default());
+            store
+                .set_fuel(PLUGIN_FUEL_PER_CALL)
+                .map_err(|e| load_error(name, e))?;
+            let instance = linker
+                .instantiate(&mut store, &module)
+                .and_then(|pre| pre.start(&mut store))
+                .map_err(|e| load_error(name, e))?;
+
+            self.plugins.push(Plugin {
+                name: name.to_string(),
+                on_day_end: instance.get_typed_func(&store, "on_day_end").ok(),
+                on_order_generated: instance.get_typed_func(&store, "on_order_generated").ok(),
+                on_delivery: instance.get_typed_func(&store, "on_delivery").ok(),
+                store,
+            });
+            Ok(())
+        }
+
+        /// Names of the loaded plugins.
+        pub fn names(&self) -> Vec<&str> {
+            self.plugins.iter().map(|p| p.name.as_str()).collect()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.plugins.is_empty()
+        }
+
+        /// Run `call` on every plugin with a fresh fuel budget, collecting their effects.
+        /// Plugins whose call fails are unloaded.
+        fn each(
+            &mut self,
+            mut call: impl FnMut(&mut Plugin) -> Result<(), wasmi::Error>,
+        ) -> PluginEffects {
+            let mut effects = PluginEffects::default();
+            self.plugins.retain_mut(|plugin| {
+                let outcome = plugin
+                    .store
+                    .set_fuel(PLUGIN_FUEL_PER_CALL)
+                    .map_err(wasmi::Error::from)
+                    .and_then(|_| call(plugin));
+                let requested = std::mem::take(plugin.store.data_mut());
+                match outcome {
+                    Ok(()) => {
+                        effects.cash += requested.cash;
+                        effects.world_events.extend(requested.world_events);
+                        true
+                    }
+                    Err(e) => {
+                        effects.failures.push(format!(
+                            "Plugin '{}' failed and was unloaded: {}",
+                            plugin.name, e
+                        ));
+                        false
+                    }
+                }
+            });
+            effects
+        }
+
+        pub fn on_day_end(&mut self, day: u64, cash: f32) -> PluginEffects {
+            self.each(|plugin| match plugin.on_day_end {
+                Some(hook) => hook.call(&mut plugin.store, (day as i64, cash)),
+                None => Ok(()),
+            })
+        }
+
+        pub fn on_order_generated(&mut self, order: &Order) -> (f32, PluginEffects) {
+            let mut value = order.value;
+            let effects = self.each(|plugin| {
+                if let Some(hook) = plugin.on_order_generated {
+                    let args = (
+                        order.id as i64,
+                        order.origin_id as i64,
+                        order.destination_id as i64,
+                        value,
+                    );
+                    value = checked(hook.call(&mut plugin.store, args)?, value);
+                }
+                Ok(())
+            });
+            (value, effects)
+        }
+
+        pub fn on_delivery(
+            &mut self,
+            order: &Order,
+            airport: usize,
+            payout: f32,
+        ) -> (f32, PluginEffects) {
+            let mut payout = payout;
+            let effects = self.each(|plugin| {
+                if let Some(hook) = plugin.on_delivery {
+                    let args = (order.id as i64, airport as i64, payout);
+                    payout = checked(hook.call(&mut plugin.store, args)?, payout);
+                }
+                Ok(())
+            });
+            (payout, effects)
+        }
+    }
+
+    /// Ignore amounts the game cannot work with.
+    fn checked(returned: f32, previous: f32) -> f32 {
+        if returned.is_finite() && returned >= 0.0 {
+            returned
+        } else {
+            previous
+        }
+    }
+}
+
+#[cfg(feature = "plugins")]
+pub use runtime::PluginHost;
+
+/// Stand-in without the `plugins` feature: no plugins, values pass through.
+#[cfg(not(feature = "plugins"))]
+#[derive(Debug, Default)]
+pub struct PluginHost {}
+
+#[cfg(not(feature = "plugins"))]
+impl PluginHost {
+    pub fn load(&mut self, _name: &str, _wasm: &[u8]) -> Result<(), PluginError> {
+        Err(PluginError::Unsupported)
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        true
+    }
+
+    pub fn on_day_end(&mut self, _day: u64, _cash: f32) -> PluginEffects {
+        PluginEffects::default()
+    }
+
+    pub fn on_order_generated(&mut self, order: &Order) -> (f32, PluginEffects) {
+        (order.value, PluginEffects::default())
+    }
+
+    pub fn on_delivery(
+        &mut self,
+        _order: &Order,
+        _airport: usize,
+        payout: f32,
+    ) -> (f32, PluginEffects) {
+        (payout, PluginEffects::default())
+    }
+}
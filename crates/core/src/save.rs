@@ -0,0 +1,278 @@
+//! Save file envelopes with an integrity checksum and optional passphrase encryption.
+//!
+//! Plain saves are the game state as JSON. A sealed save wraps that JSON in a small
+//! envelope carrying a checksum, so corrupted or hand-edited files are reported as such
+//! instead of failing somewhere inside serde. With a passphrase the payload is also
+//! encrypted (ChaCha20-Poly1305, key derived with Argon2), which needs the `encryption`
+//! feature.
+
+use crate::statistics::fingerprint;
+use serde::{Deserialize, Serialize};
+use std::{fmt, io};
+
+/// Marker in the `format` field of sealed saves
+pub const SAVE_FORMAT: &str = "rusty-runways-save";
+/// Newest envelope version this build reads and the one it writes
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+/// Cipher recorded in encrypted saves
+#[cfg(feature = "encryption")]
+const CIPHER: &str = "chacha20poly1305-argon2id";
+
+/// How to write a save file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SaveOptions {
+    /// Wrap the state in an envelope with a checksum
+    pub checksum: bool,
+    /// Encrypt the state with this passphrase; implies `checksum`
+    pub passphrase: Option<String>,
+}
+
+impl SaveOptions {
+    /// Plain JSON, as written by [`Game::save_game`](crate::Game::save_game).
+    pub fn plain() -> Self {
+        SaveOptions::default()
+    }
+
+    /// JSON state with a checksum to detect corruption and edits.
+    pub fn sealed() -> Self {
+        SaveOptions {
+            checksum: true,
+            passphrase: None,
+        }
+    }
+
+    /// Encrypted state with a checksum; the passphrase is needed to load it again.
+    pub fn encrypted(passphrase: &str) -> Self {
+        SaveOptions {
+            checksum: true,
+            passphrase: Some(passphrase.to_string()),
+        }
+    }
+}
+
+/// Errors reading or writing save files.
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    /// The file is not a save this build can read
+    Corrupt {
+        reason: String,
+    },
+    /// The payload does not match the stored checksum
+    ChecksumMismatch,
+    /// The save is encrypted and no passphrase was given
+    PassphraseRequired,
+    /// The passphrase is wrong, or the encrypted payload was altered
+    WrongPassphrase,
+    /// Encrypted saves need the `encryption` feature
+    EncryptionUnsupported,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "{}", e),
+            SaveError::Corrupt { reason } => write!(f, "Save file is corrupt: {}", reason),
+            SaveError::ChecksumMismatch => write!(
+                f,
+                "Save file failed its integrity check; it was corrupted or edited"
+            ),
+            SaveError::PassphraseRequired => {
+                write!(f, "Save file is encrypted; a passphrase is required")
+            }
+            SaveError::WrongPassphrase => write!(
+                f,
+                "Could not decrypt save file: wrong passphrase or tampered file"
+            ),
+            SaveError::EncryptionUnsupported => write!(
+                f,
+                "Encrypted saves are not supported by this build (enable the `encryption` feature)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(e: io::Error) -> Self {
+        SaveError::Io(e)
+    }
+}
+
+impl From<SaveError> for io::Error {
+    fn from(e: SaveError) -> Self {
+        match e {
+            SaveError::Io(e) => e,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
+
+/// On-disk envelope of a sealed save.
+#[derive(Serialize, Deserialize)]
+struct SealedSave {
+    format: String,
+    version: u32,
+    /// Fingerprint of `payload` as stored
+    checksum: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cipher: Option<CipherParams>,
+    /// Game state JSON, or hex ciphertext when `cipher` is set
+    payload: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    algorithm: String,
+    salt: String,
+    nonce: String,
+}
+
+/// Turn the game state JSON into the contents of a save file.
+pub fn seal(state_json: String, options: &SaveOptions) -> Result<String, SaveError> {
+    if !options.checksum && options.passphrase.is_none() {
+        return Ok(state_json);
+    }
+
+    let (payload, cipher) = match &options.passphrase {
+        Some(passphrase) => {
+            let (ciphertext, params) = encrypt(state_json.as_bytes(), passphrase)?;
+            (to_hex(&ciphertext), Some(params))
+        }
+        None => (state_json, None),
+    };
+    let sealed = SealedSave {
+        format: SAVE_FORMAT.to_string(),
+        version: SAVE_FORMAT_VERSION,
+        checksum: fingerprint(payload.as_bytes()),
+        cipher,
+        payload,
+    };
+    serde_json::to_string_pretty(&sealed).map_err(|e| SaveError::Io(io::Error::other(e)))
+}
+
+/// Check and unwrap the contents of a save file, returning the game state JSON.
+///
+/// Plain saves are passed through unchanged.
+pub fn open(contents: String, passphrase: Option<&str>) -> Result<String, SaveError> {
+    let value: serde_json::Value = serde_json::from_str(&contents).map_err(corrupt)?;
+    if value.get("format").and_then(|f| f.as_str()) != Some(SAVE_FORMAT) {
+        return Ok(contents);
+    }
+
+    let sealed: SealedSave = serde_json::from_value(value).map_err(corrupt)?;
+    if sealed.version > SAVE_FORMAT_VERSION {
+        return Err(SaveError::Corrupt {
+            reason: format!(
+                "written by a newer version (format {}, this build reads up to {})",
+                sealed.version, SAVE_FORMAT_VERSION
+            ),
+        });
+    }
+    if fingerprint(sealed.payload.as_bytes()) != sealed.checksum {
+        return Err(SaveError::ChecksumMismatch);
+    }
+
+    match sealed.cipher {
+        None => Ok(sealed.payload),
+        Some(params) => {
+            let passphrase = passphrase.ok_or(SaveError::PassphraseRequired)?;
+            let ciphertext = from_hex(&sealed.payload)?;
+            let plaintext = decrypt(&ciphertext, &params, passphrase)?;
+            String::from_utf8(plaintext).map_err(corrupt)
+        }
+    }
+}
+
+fn corrupt(e: impl fmt::Display) -> SaveError {
+    SaveError::Corrupt {
+        reason: e.to_string(),
+    }
+}
+
+#[cfg(feature = "encryption")]
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], SaveError> {
+    let mut key = [0u8; 32];
+    argon2::Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(corrupt)?;
+    Ok(key)
+}
+
+#[cfg(feature = "encryption")]
+fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<(Vec<u8>, CipherParams), SaveError> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+    use rand::RngCore;
+
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| SaveError::Io(io::Error::other("encryption failed")))?;
+    let params = CipherParams {
+        algorithm: CIPHER.to_string(),
+        salt: to_hex(&salt),
+        nonce: to_hex(&nonce),
+    };
+    Ok((ciphertext, params))
+}
+
+#[cfg(feature = "encryption")]
+fn decrypt(
+    ciphertext: &[u8],
+    params: &CipherParams,
+    passphrase: &str,
+) -> Result<Vec<u8>, SaveError> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+    if params.algorithm != CIPHER {
+        return Err(corrupt(format!("unknown cipher `{}`", params.algorithm)));
+    }
+    let salt = from_hex(&params.salt)?;
+    let nonce = from_hex(&params.nonce)?;
+    if nonce.len() != 12 {
+        return Err(corrupt("bad nonce length"));
+    }
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext)
+        .map_err(|_| SaveError::WrongPassphrase)
+}
+
+#[cfg(not(feature = "encryption"))]
+fn encrypt(_plaintext: &[u8], _passphrase: &str) -> Result<(Vec<u8>, CipherParams), SaveError> {
+    Err(SaveError::EncryptionUnsupported)
+}
+
+#[cfg(not(feature = "encryption"))]
+fn decrypt(
+    _ciphertext: &[u8],
+    _params: &CipherParams,
+    _passphrase: &str,
+) -> Result<Vec<u8>, SaveError> {
+    Err(SaveError::EncryptionUnsupported)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(text: &str) -> Result<Vec<u8>, SaveError> {
+    if text.len() % 2 != 0 || !text.is_ascii() {
+        return Err(corrupt("payload is not valid hex"));
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).map_err(corrupt))
+        .collect()
+}
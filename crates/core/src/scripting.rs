@@ -0,0 +1,337 @@
+//! Lua scripts for scenario logic.
+//!
+//! Scenario files list scripts under `scripts`; each one may define any of these global
+//! functions, which the scheduler calls at the matching points:
+//! - `on_day_end(state)`: when the daily report is written.
+//! - `on_arrival(state, plane, airport)`: when a plane lands.
+//! - `on_delivery(state, order, airport, payout)`: after a paid delivery.
+//! - `on_restock(state)`: after airports receive new orders.
+//!
+//! `state` is a table with `time`, `day`, `cash`, `fleet_size` and `deliveries`. Scripts
+//! act on the game through the global `game` table:
+//! - `game.message(text)`: print a message and add it to the game log.
+//! - `game.add_cash(amount)`: pay the player, or charge them with a negative amount.
+//! - `game.world_event(airport, factor, hours)`: start a fuel price event at an airport
+//!   id, or at every airport when `airport` is `nil`.
+//! - `game.once(key)`: `true` the first time it is called with `key`, `false` afterwards.
+//!   Keys are saved with the game, so one-off triggers stay fired after a load.
+//!
+//! Only the `table`, `string` and `math` libraries are available. Each hook call may run
+//! [`SCRIPT_INSTRUCTIONS_PER_CALL`] instructions; a script that errors or runs past that
+//! is disabled and the failure is logged.
+//!
+//! Running scripts needs the `scripting` feature.
+
+use crate::events::GameTime;
+use crate::plugins::PluginEffects;
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "scripting"))]
+use std::collections::BTreeSet;
+
+/// Lua instructions a script may run per hook call
+pub const SCRIPT_INSTRUCTIONS_PER_CALL: u32 = 1_000_000;
+
+/// A script as stored with the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptSource {
+    pub name: String,
+    pub source: String,
+}
+
+/// Point in the scheduler where scripts run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScriptHook {
+    DayEnd,
+    Arrival {
+        plane: usize,
+        airport: usize,
+    },
+    Delivery {
+        order: usize,
+        airport: usize,
+        payout: f32,
+    },
+    Restock,
+}
+
+impl ScriptHook {
+    /// Name of the Lua function called for this hook
+    pub fn function(&self) -> &'static str {
+        match self {
+            ScriptHook::DayEnd => "on_day_end",
+            ScriptHook::Arrival { .. } => "on_arrival",
+            ScriptHook::Delivery { .. } => "on_delivery",
+            ScriptHook::Restock => "on_restock",
+        }
+    }
+}
+
+/// Snapshot of the game handed to every hook as `state`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScriptState {
+    pub time: GameTime,
+    pub day: GameTime,
+    pub cash: f32,
+    pub fleet_size: usize,
+    pub deliveries: usize,
+}
+
+#[cfg(feature = "scripting")]
+mod runtime {
+    use super::{
+        PluginEffects, SCRIPT_INSTRUCTIONS_PER_CALL, ScriptHook, ScriptSource, ScriptState,
+    };
+    use crate::events::GameTime;
+    use mlua::{AppDataRefMut, Function, HookTriggers, Lua, LuaOptions, MultiValue, StdLib, Value};
+    use std::collections::BTreeSet;
+    use std::fmt;
+    use std::sync::Mutex;
+
+    /// Instructions between two budget checks
+    const CHECK_EVERY: u32 = 1_000;
+
+    /// Data the `game` functions work on while a hook runs.
+    #[derive(Default)]
+    struct Session {
+        effects: PluginEffects,
+        flags: BTreeSet<String>,
+        instructions: u32,
+    }
+
+    fn session(lua: &Lua) -> mlua::Result<AppDataRefMut<'_, Session>> {
+        lua.app_data_mut::<Session>()
+            .ok_or_else(|| mlua::Error::runtime("game API used outside a hook"))
+    }
+
+    struct Script {
+        name: String,
+        // `Lua` is not `Sync`; the mutex keeps `Game` shareable (only `get_mut` is used)
+        lua: Mutex<Lua>,
+    }
+
+    /// Compiled scripts, run in scenario order.
+    #[derive(Default)]
+    pub struct ScriptHost {
+        scripts: Vec<Script>,
+        loaded: bool,
+    }
+
+    impl fmt::Debug for ScriptHost {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list()
+                .entries(self.scripts.iter().map(|s| &s.name))
+                .finish()
+        }
+    }
+
+    fn new_lua(script: &ScriptSource) -> mlua::Result<Lua> {
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH,
+            LuaOptions::new(),
+        )?;
+
+        let game = lua.create_table()?;
+        game.set(
+            "message",
+            lua.create_function(|lua, text: String| {
+                session(lua)?.effects.messages.push(text);
+                Ok(())
+            })?,
+        )?;
+        game.set(
+            "add_cash",
+            lua.create_function(|lua, amount: f32| {
+                if amount.is_finite() {
+                    session(lua)?.effects.cash += amount;
+                }
+                Ok(())
+            })?,
+        )?;
+        game.set(
+            "world_event",
+            lua.create_function(|lua, (airport, factor, hours): (Option<i64>, f32, i64)| {
+                if factor.is_finite() && factor > 0.0 && hours > 0 {
+                    let airport = match airport {
+                        Some(id) => Some(usize::try_from(id).map_err(mlua::Error::runtime)?),
+                        None => None,
+                    };
+                    session(lua)?
+                        .effects
+                        .world_events
+                        .push((airport, factor, hours as GameTime));
+                }
+                Ok(())
+            })?,
+        )?;
+        game.set(
+            "once",
+            lua.create_function(|lua, key: String| Ok(session(lua)?.flags.insert(key)))?,
+        )?;
+        lua.globals().set("game", game)?;
+
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(CHECK_EVERY),
+            |lua, _| {
+                let mut session = session(lua)?;
+                session.instructions += CHECK_EVERY;
+                if session.instructions > SCRIPT_INSTRUCTIONS_PER_CALL {
+                    return Err(mlua::Error::runtime("instruction limit exceeded"));
+                }
+                Ok(())
+            },
+        );
+
+        // the top level gets the same budget; anything it asks of the game is dropped
+        lua.set_app_data(Session::default());
+        let outcome = lua
+            .load(script.source.as_str())
+            .set_name(script.name.as_str())
+            .exec();
+        lua.remove_app_data::<Session>();
+        outcome.map(|_| lua)
+    }
+
+    impl ScriptHost {
+        /// Compile `script` and run its top level.
+        pub fn load(&mut self, script: &ScriptSource) -> Result<(), String> {
+            let lua = new_lua(script).map_err(|e| e.to_string())?;
+            self.scripts.push(Script {
+                name: script.name.clone(),
+                lua: Mutex::new(lua),
+            });
+            self.loaded = true;
+            Ok(())
+        }
+
+        /// Whether the scripts were compiled in this session.
+        pub fn is_loaded(&self) -> bool {
+            self.loaded
+        }
+
+        /// Compile every script, e.g. after a saved game was read back.
+        /// Returns a message for each script that failed.
+        pub fn load_all(&mut self, scripts: &[ScriptSource]) -> Vec<String> {
+            self.loaded = true;
+            scripts
+                .iter()
+                .filter_map(|script| {
+                    self.load(script)
+                        .err()
+                        .map(|e| format!("Script '{}' failed to load: {}", script.name, e))
+                })
+                .collect()
+        }
+
+        /// Call `hook` on every script that defines it. Failing scripts are disabled.
+        pub fn run(
+            &mut self,
+            hook: ScriptHook,
+            state: &ScriptState,
+            flags: &mut BTreeSet<String>,
+        ) -> PluginEffects {
+            let mut effects = PluginEffects::default();
+            self.scripts.retain_mut(|script| {
+                let lua = script.lua.get_mut().unwrap_or_else(|e| e.into_inner());
+                lua.set_app_data(Session {
+                    effects: PluginEffects::default(),
+                    flags: std::mem::take(flags),
+                    instructions: 0,
+                });
+                let outcome = call(lua, hook, state);
+                let session = lua.remove_app_data::<Session>().unwrap_or_default();
+                *flags = session.flags;
+                match outcome {
+                    Ok(()) => {
+                        effects.cash += session.effects.cash;
+                        effects.world_events.extend(session.effects.world_events);
+                        effects.messages.extend(session.effects.messages);
+                        true
+                    }
+                    Err(e) => {
+                        effects.failures.push(format!(
+                            "Script '{}' failed and was disabled: {}",
+                            script.name, e
+                        ));
+                        false
+                    }
+                }
+            });
+            effects
+        }
+    }
+
+    fn call(lua: &Lua, hook: ScriptHook, state: &ScriptState) -> mlua::Result<()> {
+        let Some(function) = lua.globals().get::<_, Option<Function>>(hook.function())? else {
+            return Ok(());
+        };
+
+        let table = lua.create_table()?;
+        table.set("time", state.time)?;
+        table.set("day", state.day)?;
+        table.set("cash", state.cash)?;
+        table.set("fleet_size", state.fleet_size)?;
+        table.set("deliveries", state.deliveries)?;
+
+        let mut args = vec![Value::Table(table)];
+        match hook {
+            ScriptHook::DayEnd | ScriptHook::Restock => {}
+            ScriptHook::Arrival { plane, airport } => {
+                args.push(Value::Integer(plane as i64));
+                args.push(Value::Integer(airport as i64));
+            }
+            ScriptHook::Delivery {
+                order,
+                airport,
+                payout,
+            } => {
+                args.push(Value::Integer(order as i64));
+                args.push(Value::Integer(airport as i64));
+                args.push(Value::Number(payout as f64));
+            }
+        }
+        function.call::<_, ()>(MultiValue::from_vec(args))
+    }
+}
+
+#[cfg(feature = "scripting")]
+pub use runtime::ScriptHost;
+
+/// Stand-in without the `scripting` feature: scenarios with scripts are rejected when
+/// the game is built, so there is never anything to run.
+#[cfg(not(feature = "scripting"))]
+#[derive(Debug, Default)]
+pub struct ScriptHost {
+    loaded: bool,
+}
+
+#[cfg(not(feature = "scripting"))]
+impl ScriptHost {
+    pub fn load(&mut self, _script: &ScriptSource) -> Result<(), String> {
+        Err("scripts are not supported by this build (enable the `scripting` feature)".into())
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    pub fn load_all(&mut self, scripts: &[ScriptSource]) -> Vec<String> {
+        self.loaded = true;
+        scripts
+            .iter()
+            .filter_map(|script| {
+                self.load(script)
+                    .err()
+                    .map(|e| format!("Script '{}' failed to load: {}", script.name, e))
+            })
+            .collect()
+    }
+
+    pub fn run(
+        &mut self,
+        _hook: ScriptHook,
+        _state: &ScriptState,
+        _flags: &mut BTreeSet<String>,
+    ) -> PluginEffects {
+        PluginEffects::default()
+    }
+}
@@ -1,5 +1,49 @@
+use crate::utils::airplanes::models::{AirplaneSpecs, CO2_KG_PER_KG_FUEL, FUEL_DENSITY_KG_PER_L};
+use crate::utils::calendar::Date;
 use serde::{Deserialize, Serialize};
 
+/// Company value added per order delivered, standing in for the goodwill of a track record.
+pub const REPUTATION_PER_DELIVERY: f32 = 250.0;
+
+/// CO2 released by the fleet's flying.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct Emissions {
+    /// Tonnes emitted since the last daily report
+    pub today_tons: f32,
+    /// Tonnes emitted since the start of the game
+    pub total_tons: f32,
+}
+
+impl Emissions {
+    /// Tonnes of CO2 from burning `liters` of jet fuel.
+    pub fn co2_tons(liters: f32) -> f32 {
+        liters * FUEL_DENSITY_KG_PER_L * CO2_KG_PER_KG_FUEL / 1_000.0
+    }
+
+    /// Count `liters` of fuel burned; a negative amount gives back fuel that a changed
+    /// flight plan no longer needs.
+    pub fn record_burn(&mut self, liters: f32) {
+        let tons = Self::co2_tons(liters);
+        self.today_tons += tons;
+        self.total_tons = (self.total_tons + tons).max(0.0);
+    }
+
+    /// Count the energy a plane with `specs` used: liters of fuel, or kWh of battery
+    /// for electric models, which emit nothing in flight.
+    pub fn record_use(&mut self, specs: &AirplaneSpecs, amount: f32) {
+        if !specs.is_electric() {
+            self.record_burn(amount);
+        }
+    }
+
+    /// Close the day, returning the tonnes emitted during it.
+    pub fn end_day(&mut self) -> f32 {
+        let tons = self.today_tons.max(0.0);
+        self.today_tons = 0.0;
+        tons
+    }
+}
+
 /// Records all of the main stats for the game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyStats {
@@ -9,4 +53,326 @@ pub struct DailyStats {
     pub net_cash: f32,
     pub fleet_size: usize,
     pub total_deliveries: usize,
+    /// Fuel bought and batteries charged, included in `expenses`
+    #[serde(default)]
+    pub fuel: f32,
+    /// Landing, parking, overflight and customs fees paid, included in `expenses`
+    #[serde(default)]
+    pub fees: f32,
+    /// Interest paid on borrowed money, included in `expenses`
+    #[serde(default)]
+    pub interest: f32,
+    /// Net cash from buying and selling planes and chargers, included in `income` and `expenses`
+    #[serde(default)]
+    pub investing: f32,
+    /// Pilot salaries paid, included in `expenses`
+    #[serde(default)]
+    pub salaries: f32,
+    /// CO2 emitted by the fleet over the day, in tonnes
+    #[serde(default)]
+    pub co2_tons: f32,
+    /// CO2 emitted since the start of the game, in tonnes
+    #[serde(default)]
+    pub total_co2_tons: f32,
+    /// Carbon tax paid on the day's emissions, included in `expenses`
+    #[serde(default)]
+    pub carbon_tax: f32,
+    /// Ground crew wages paid, included in `expenses`
+    #[serde(default)]
+    pub crew_wages: f32,
+    /// Hangarage paid for the fleet, included in `expenses`
+    #[serde(default)]
+    pub hangarage: f32,
+    /// Office overhead paid, included in `expenses`
+    #[serde(default)]
+    pub overhead: f32,
+    /// Profit tax paid, included in `expenses`
+    #[serde(default)]
+    pub taxes: f32,
+    /// Company value at the end of the day, see [`CompanyValue`]
+    #[serde(default)]
+    pub company_value: f32,
+}
+
+/// One day of [`DailyStats`] alongside the statement categories of [`MonthlyReport`],
+/// serialized as a single flat object so it can become a data frame row.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsRecord {
+    #[serde(flatten)]
+    pub stats: DailyStats,
+    /// Pilot salaries and ground crew wages
+    pub payroll: f32,
+    /// Hangarage and office overhead
+    pub total_overhead: f32,
+    /// Profit and carbon taxes
+    pub total_taxes: f32,
+    /// Everything else: operating costs, purchases, maintenance, penalties, ...
+    pub other: f32,
+    /// `income` minus `expenses`
+    pub net: f32,
+}
+
+impl From<&DailyStats> for StatsRecord {
+    fn from(d: &DailyStats) -> Self {
+        let payroll = d.salaries + d.crew_wages;
+        let total_overhead = d.hangarage + d.overhead;
+        let total_taxes = d.taxes + d.carbon_tax;
+        StatsRecord {
+            stats: d.clone(),
+            payroll,
+            total_overhead,
+            total_taxes,
+            other: d.expenses
+                - d.fuel
+                - d.fees
+                - payroll
+                - total_overhead
+                - total_taxes
+                - d.interest,
+            net: d.income - d.expenses,
+        }
+    }
+}
+
+/// Income statement for one calendar month, summed from its [`DailyStats`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MonthlyReport {
+    pub year: u32,
+    /// 1-12
+    pub month: u8,
+    pub revenue: f32,
+    pub fuel: f32,
+    pub fees: f32,
+    /// Pilot salaries and ground crew wages
+    pub payroll: f32,
+    /// Hangarage and office overhead
+    pub overhead: f32,
+    /// Profit and carbon taxes
+    pub taxes: f32,
+    pub interest: f32,
+    /// Everything else: operating costs, purchases, maintenance, penalties, ...
+    pub other: f32,
+    /// `revenue` minus all expenses
+    pub net: f32,
+    /// Cash at the end of the month
+    pub closing_cash: f32,
+}
+
+impl MonthlyReport {
+    /// Sum the daily reports of the month starting on `month`.
+    pub fn from_days(month: Date, days: &[DailyStats]) -> Self {
+        let sum = |f: fn(&DailyStats) -> f32| days.iter().map(f).sum::<f32>();
+        let revenue = sum(|d| d.income);
+        let expenses = sum(|d| d.expenses);
+        let fuel = sum(|d| d.fuel);
+        let fees = sum(|d| d.fees);
+        let payroll = sum(|d| d.salaries + d.crew_wages);
+        let overhead = sum(|d| d.hangarage + d.overhead);
+        let taxes = sum(|d| d.taxes + d.carbon_tax);
+        let interest = sum(|d| d.interest);
+        MonthlyReport {
+            year: month.year,
+            month: month.month,
+            revenue,
+            fuel,
+            fees,
+            payroll,
+            overhead,
+            taxes,
+            interest,
+            other: expenses - fuel - fees - payroll - overhead - taxes - interest,
+            net: revenue - expenses,
+            closing_cash: days.last().map_or(0.0, |d| d.net_cash),
+        }
+    }
+
+    /// Statement lines in display order, expenses negative, ending with the net result.
+    pub fn lines(&self) -> [(&'static str, f32); 9] {
+        [
+            ("Revenue", self.revenue),
+            ("Fuel", -self.fuel),
+            ("Fees", -self.fees),
+            ("Payroll", -self.payroll),
+            ("Overhead", -self.overhead),
+            ("Taxes", -self.taxes),
+            ("Interest", -self.interest),
+            ("Other", -self.other),
+            ("Net", self.net),
+        ]
+    }
+
+    /// First day of the month covered.
+    pub fn date(&self) -> Date {
+        Date {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        }
+    }
+}
+
+/// Balance sheet at the end of a calendar quarter and the cash flows over it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuarterlyStatement {
+    pub year: u32,
+    /// 1-4
+    pub quarter: u8,
+    /// Cash on hand, never negative
+    pub cash: f32,
+    /// Book value of the fleet at resale prices
+    pub fleet_value: f32,
+    /// `cash + fleet_value`
+    pub assets: f32,
+    /// Overdrawn cash owed; there are no loans or leases yet
+    pub liabilities: f32,
+    /// `assets - liabilities`
+    pub equity: f32,
+    /// Cash earned running the airline: everything but investing
+    pub operating_cash_flow: f32,
+    /// Cash spent on planes and chargers, less plane sales
+    pub investing_cash_flow: f32,
+    /// `operating_cash_flow + investing_cash_flow`
+    pub net_cash_flow: f32,
+}
+
+impl QuarterlyStatement {
+    /// Build the statement of the quarter starting on `quarter`.
+    ///
+    /// Parameters
+    /// - `quarter`: First day of the quarter.
+    /// - `days`: Daily reports of the quarter.
+    /// - `value`: Company valuation at the end of the quarter.
+    pub fn new(quarter: Date, days: &[DailyStats], value: &CompanyValue) -> Self {
+        let net_cash_flow: f32 = days.iter().map(|d| d.income - d.expenses).sum();
+        let investing_cash_flow: f32 = days.iter().map(|d| d.investing).sum();
+        let assets = value.cash + value.fleet_value;
+        QuarterlyStatement {
+            year: quarter.year,
+            quarter: quarter.month.div_ceil(3),
+            cash: value.cash,
+            fleet_value: value.fleet_value,
+            assets,
+            liabilities: value.debt,
+            equity: assets - value.debt,
+            operating_cash_flow: net_cash_flow - investing_cash_flow,
+            investing_cash_flow,
+            net_cash_flow,
+        }
+    }
+}
+
+/// Breakdown of what the company is worth.
+///
+/// The total is the canonical score of a run: scenarios, leaderboards and RL returns
+/// all compare companies by it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CompanyValue {
+    /// Cash on hand, never negative
+    pub cash: f32,
+    /// Book value of the fleet at current resale prices
+    pub fleet_value: f32,
+    /// Overdrawn cash owed
+    pub debt: f32,
+    /// Bonus for the orders delivered so far
+    pub reputation: f32,
+    /// `cash + fleet_value - debt + reputation`
+    pub total: f32,
+}
+
+impl CompanyValue {
+    /// Value a company from its cash balance, fleet book value and deliveries.
+    pub fn new(cash: f32, fleet_value: f32, orders_delivered: usize) -> Self {
+        let reputation = REPUTATION_PER_DELIVERY * orders_delivered as f32;
+        let debt = (-cash).max(0.0);
+        let cash = cash.max(0.0);
+        CompanyValue {
+            cash,
+            fleet_value,
+            debt,
+            reputation,
+            total: cash + fleet_value - debt + reputation,
+        }
+    }
+}
+
+/// Stable 64-bit FNV-1a hash of `bytes`.
+///
+/// Unlike `std`'s hasher the result never changes between Rust versions or platforms,
+/// so hashes can be compared across machines.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// [`fnv1a`] of `bytes` as 16 hex digits.
+pub fn fingerprint(bytes: &[u8]) -> String {
+    format!("{:016x}", fnv1a(bytes))
+}
+
+/// Compact record of a run for comparing results on identical seeds.
+///
+/// The checksum covers every other field, so a hand-edited summary no longer verifies.
+/// It only guards against accidental edits: anyone can recompute it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RunSummary {
+    /// Version of the game that produced the run
+    pub version: String,
+    pub seed: u64,
+    /// Fingerprint of the world the run started from
+    pub config_hash: String,
+    pub days_played: u64,
+    pub deliveries: usize,
+    /// Final score, see [`CompanyValue`]
+    pub company_value: f32,
+    /// Commands issued over the run
+    pub commands: u64,
+    pub checksum: String,
+}
+
+impl RunSummary {
+    /// Build a summary and seal it with its checksum.
+    pub fn new(
+        seed: u64,
+        config_hash: String,
+        days_played: u64,
+        deliveries: usize,
+        company_value: f32,
+        commands: u64,
+    ) -> Self {
+        let mut summary = RunSummary {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            seed,
+            config_hash,
+            days_played,
+            deliveries,
+            company_value,
+            commands,
+            checksum: String::new(),
+        };
+        summary.checksum = summary.compute_checksum();
+        summary
+    }
+
+    fn compute_checksum(&self) -> String {
+        let canonical = format!(
+            "{}|{}|{}|{}|{}|{:.2}|{}",
+            self.version,
+            self.seed,
+            self.config_hash,
+            self.days_played,
+            self.deliveries,
+            self.company_value,
+            self.commands
+        );
+        fingerprint(canonical.as_bytes())
+    }
+
+    /// Return `true` if the checksum still matches the other fields.
+    pub fn verify(&self) -> bool {
+        self.checksum == self.compute_checksum()
+    }
 }
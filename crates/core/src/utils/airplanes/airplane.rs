@@ -1,4 +1,8 @@
-use super::models::{AirplaneModel, AirplaneSpecs, AirplaneStatus};
+use super::checks::CheckHours;
+use super::models::{
+    AirplaneModel, AirplaneSpecs, AirplaneStatus, CruiseRegime, ELECTRIC_EMPTY_DRAW_SHARE,
+    ELECTRIC_RESERVE_SHARE, FUEL_DENSITY_KG_PER_L, GroundTask, PASSENGER_WEIGHT_KG, TurnaroundTask,
+};
 use crate::{
     events::GameTime,
     utils::{
@@ -13,10 +17,45 @@ use serde::{Deserialize, Serialize};
 const LAMBDA0: f32 = 0.005;
 const K: f32 = 0.01;
 
+/// Hours of burn every plane keeps in the tank on top of its trip fuel.
+pub const DEFAULT_RESERVE_HOURS: f32 = 0.75;
+
+/// Share of the purchase price a brand-new plane fetches on resale.
+pub const RESALE_NEW_SHARE: f32 = 0.8;
+/// Resale value never drops below this share of the purchase price.
+pub const RESALE_FLOOR_SHARE: f32 = 0.2;
+/// Value lost per 1,000 flight hours on the airframe.
+const AIRFRAME_DEPRECIATION_PER_1000H: f32 = 0.06;
+/// Value lost per year (8,760 hours) since the plane was bought.
+const AGE_DEPRECIATION_PER_YEAR: f32 = 0.1;
+/// Hours since maintenance at which the condition discount is at its largest.
+const CONDITION_HOURS: f32 = 500.0;
+/// Largest discount for an overdue maintenance interval.
+const CONDITION_DISCOUNT: f32 = 0.15;
+/// Extra discount while the plane needs repairs.
+const NEEDS_REPAIR_DISCOUNT: f32 = 0.15;
+/// How fast the landing failure chance grows per [`CONDITION_HOURS`] without maintenance.
+const LANDING_WEAR_FACTOR: f32 = 3.0;
+
+/// Longest name a plane can be given.
+pub const MAX_PLANE_NAME_LEN: usize = 32;
+
+fn default_reserve_hours() -> f32 {
+    DEFAULT_RESERVE_HOURS
+}
+
+/// Tail number painted on a new plane with the given id, e.g. `RR-003`.
+pub fn default_registration(id: usize) -> String {
+    format!("RR-{:03}", id)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// An airplane operating between airports, tracked by precise coordinates
 pub struct Airplane {
     pub id: usize,
+    /// Registration or custom name shown in plane listings
+    #[serde(default)]
+    pub name: String,
     pub model: AirplaneModel,
     pub specs: AirplaneSpecs,
     pub status: AirplaneStatus,
@@ -26,9 +65,39 @@ pub struct Airplane {
     pub current_payload: f32,
     #[serde(default)]
     pub current_passengers: u32,
+    /// Seats taken by a booked charter group, kept apart from ticketed passengers
+    #[serde(default)]
+    pub charter_passengers: u32,
+    /// Ticket holders on a scheduled route flight; they leave at the next landing
+    #[serde(default)]
+    pub scheduled_passengers: u32,
     pub manifest: Vec<Order>,
     pub hours_since_maintenance: GameTime,
     pub needs_maintenance: bool,
+    /// Total hours flown by this airframe
+    #[serde(default)]
+    pub airframe_hours: GameTime,
+    /// Flight hours since the last A, B and C check
+    #[serde(default)]
+    pub check_hours: CheckHours,
+    /// Broke down on landing and stays grounded until repaired
+    #[serde(default)]
+    pub awaiting_repair: bool,
+    /// Game time at which the plane joined the fleet
+    #[serde(default)]
+    pub acquired_at: GameTime,
+    /// Hours of burn that must stay in the tank after reaching the destination
+    #[serde(default = "default_reserve_hours")]
+    pub reserve_hours: f32,
+    /// Speed setting used for range checks and the current flight
+    #[serde(default)]
+    pub cruise: CruiseRegime,
+    /// Held on the ground: the plane refuses to depart until released
+    #[serde(default)]
+    pub on_hold: bool,
+    /// Ground tasks of the current turnaround that are still under way
+    #[serde(default)]
+    pub turnaround: Vec<TurnaroundTask>,
 }
 
 impl Airplane {
@@ -45,6 +114,7 @@ impl Airplane {
         let specs = model.specs();
         Airplane {
             id,
+            name: default_registration(id),
             model,
             specs,
             status: AirplaneStatus::Parked,
@@ -52,27 +122,98 @@ impl Airplane {
             current_fuel: specs.fuel_capacity,
             current_payload: 0.0,
             current_passengers: 0,
+            charter_passengers: 0,
+            scheduled_passengers: 0,
             manifest: Vec::new(),
             hours_since_maintenance: 0,
             needs_maintenance: false,
+            check_hours: CheckHours::default(),
+            awaiting_repair: false,
+            airframe_hours: 0,
+            acquired_at: 0,
+            reserve_hours: DEFAULT_RESERVE_HOURS,
+            cruise: CruiseRegime::Normal,
+            on_hold: false,
+            turnaround: Vec::new(),
         }
     }
 
-    /// Euclidean distance from current location to `target_coordinates`.
+    /// Distance (km) from current location to `target_coordinates`.
+    ///
+    /// Euclidean on planar maps, great-circle on geographic maps.
     pub fn distance_to(&self, target_coordinates: &Coordinate) -> f32 {
-        let dx = self.location.x - target_coordinates.x;
-        let dy = self.location.y - target_coordinates.y;
-        (dx * dx + dy * dy).sqrt()
+        self.location.distance_to(target_coordinates)
     }
 
-    /// How many hours can we fly on current fuel?
+    /// Liters that must remain in the tank on landing.
+    ///
+    /// Electric planes keep [`ELECTRIC_RESERVE_SHARE`] of the battery (kWh) instead of
+    /// a number of hours of flight.
+    pub fn reserve_fuel(&self) -> f32 {
+        if self.specs.is_electric() {
+            self.specs.fuel_capacity * ELECTRIC_RESERVE_SHARE
+        } else {
+            self.reserve_hours * self.specs.fuel_consumption
+        }
+    }
+
+    /// Cruise speed (km/h) at the current speed setting.
+    pub fn cruise_speed(&self) -> f32 {
+        self.specs.cruise_speed * self.cruise.speed_multiplier()
+    }
+
+    /// Fuel burn (L/h) at the current speed setting.
+    ///
+    /// For electric planes this is the power draw (kW), which grows with the weight
+    /// carried: an empty plane draws [`ELECTRIC_EMPTY_DRAW_SHARE`] of its rating and a
+    /// fully loaded one the full rating.
+    pub fn fuel_burn(&self) -> f32 {
+        let burn = self.specs.fuel_consumption * self.cruise.burn_multiplier();
+        if self.specs.is_electric() {
+            let max_load = (self.specs.mtow - self.specs.empty_weight()).max(1.0);
+            let load = (self.payload_weight() / max_load).clamp(0.0, 1.0);
+            burn * (ELECTRIC_EMPTY_DRAW_SHARE + (1.0 - ELECTRIC_EMPTY_DRAW_SHARE) * load)
+        } else {
+            burn
+        }
+    }
+
+    /// How many hours can we fly on current fuel without touching the reserve?
     pub fn endurance_hours(&self) -> f32 {
-        self.current_fuel / self.specs.fuel_consumption
+        (self.current_fuel - self.reserve_fuel()).max(0.0) / self.fuel_burn()
     }
 
     /// Maximum range (km) before refuel.
     pub fn max_range(&self) -> f32 {
-        self.endurance_hours() * self.specs.cruise_speed
+        self.endurance_hours() * self.cruise_speed()
+    }
+
+    /// Current takeoff weight (kg): empty weight plus fuel, cargo and passengers.
+    pub fn takeoff_weight(&self) -> f32 {
+        let fuel = if self.specs.is_electric() {
+            0.0
+        } else {
+            self.current_fuel * FUEL_DENSITY_KG_PER_L
+        };
+        self.specs.empty_weight() + fuel + self.payload_weight()
+    }
+
+    /// Weight (kg) of the cargo and passengers aboard.
+    pub fn payload_weight(&self) -> f32 {
+        self.current_payload + self.seated_passengers() as f32 * PASSENGER_WEIGHT_KG
+    }
+
+    /// Weight (kg) of the manifest as ground crews handle it, see [`Order::handling_weight`].
+    pub fn handling_weight(&self) -> f32 {
+        self.manifest.iter().map(Order::handling_weight).sum()
+    }
+
+    /// Cargo orders aboard, each taking one slot of [`AirplaneSpecs::cargo_slots`].
+    pub fn cargo_orders(&self) -> u32 {
+        self.manifest
+            .iter()
+            .filter(|order| matches!(order.payload, OrderPayload::Cargo { .. }))
+            .count() as u32
     }
 
     /// Check reachability and landing ability for a target airport.
@@ -167,6 +308,18 @@ impl Airplane {
                         payload: "cargo".into(),
                     });
                 }
+                if let Some(kind) = order.rare.filter(|kind| !kind.can_fly(&self.specs)) {
+                    return Err(GameError::PayloadTypeUnsupported {
+                        plane_model: format!("{:?}", self.model),
+                        payload: format!("{} (needs {})", kind, kind.handling()),
+                    });
+                }
+                if self.specs.cargo_slots > 0 && self.cargo_orders() >= self.specs.cargo_slots {
+                    return Err(GameError::CargoSlotsFull {
+                        plane_model: format!("{:?}", self.model),
+                        slots: self.specs.cargo_slots,
+                    });
+                }
                 if self.current_payload + weight > self.specs.payload_capacity + f32::EPSILON {
                     return Err(GameError::MaxPayloadReached {
                         current_capacity: self.current_payload,
@@ -182,19 +335,66 @@ impl Airplane {
                         payload: "passengers".into(),
                     });
                 }
-                let next = self.current_passengers.saturating_add(*count);
-                if next > self.specs.passenger_capacity {
-                    return Err(GameError::PassengerCapacityReached {
-                        current_capacity: self.current_passengers,
-                        maximum_capacity: self.specs.passenger_capacity,
-                        added_passengers: *count,
-                    });
-                }
+                self.check_seats(*count)?;
             }
         }
         Ok(())
     }
 
+    /// Passengers aboard: ticketed orders, any charter group and scheduled-route passengers.
+    pub fn seated_passengers(&self) -> u32 {
+        self.current_passengers
+            .saturating_add(self.charter_passengers)
+            .saturating_add(self.scheduled_passengers)
+    }
+
+    /// Check that `count` more passengers fit in the free seats.
+    fn check_seats(&self, count: u32) -> Result<(), GameError> {
+        if self.seated_passengers().saturating_add(count) > self.specs.passenger_capacity {
+            return Err(GameError::PassengerCapacityReached {
+                current_capacity: self.seated_passengers(),
+                maximum_capacity: self.specs.passenger_capacity,
+                added_passengers: count,
+            });
+        }
+        Ok(())
+    }
+
+    /// Seat a charter group of `count` passengers.
+    ///
+    /// Returns
+    /// - `Err(GameError::PayloadTypeUnsupported)` if the plane has no seats.
+    /// - `Err(GameError::PassengerCapacityReached)` if the group does not fit.
+    pub fn board_charter(&mut self, count: u32) -> Result<(), GameError> {
+        if self.specs.passenger_capacity == 0 {
+            return Err(GameError::PayloadTypeUnsupported {
+                plane_model: format!("{:?}", self.model),
+                payload: "passengers".into(),
+            });
+        }
+        self.check_seats(count)?;
+        self.charter_passengers += count;
+        self.status = AirplaneStatus::Loading;
+        Ok(())
+    }
+
+    /// Let a charter group of `count` passengers off the plane.
+    pub fn deboard_charter(&mut self, count: u32) {
+        self.charter_passengers = self.charter_passengers.saturating_sub(count);
+    }
+
+    /// Seats still free for scheduled-route passengers.
+    ///
+    /// Limited both by empty seats and by the weight the plane can still take off with.
+    pub fn free_seats(&self) -> u32 {
+        let seats = self
+            .specs
+            .passenger_capacity
+            .saturating_sub(self.seated_passengers());
+        let headroom = ((self.specs.mtow - self.takeoff_weight()) / PASSENGER_WEIGHT_KG).floor();
+        seats.min(headroom.max(0.0) as u32)
+    }
+
     //// Check runway & fuel, consume fuel, and return flight time in hours.
     pub fn consume_flight_fuel(
         &mut self,
@@ -204,14 +404,23 @@ impl Airplane {
         // runway & range check
         self.can_fly_to(airport, airport_coords)?;
 
+        // too heavy to get off the ground
+        let takeoff_weight = self.takeoff_weight();
+        if takeoff_weight > self.specs.mtow + f32::EPSILON {
+            return Err(GameError::OverMaxTakeoffWeight {
+                takeoff_weight,
+                mtow: self.specs.mtow,
+            });
+        }
+
         // distance & fuel
         let dist = self.distance_to(airport_coords);
-        let hours_f = dist / self.specs.cruise_speed;
-        let fuel_needed = hours_f * self.specs.fuel_consumption;
-        if fuel_needed > self.current_fuel {
+        let hours_f = dist / self.cruise_speed();
+        let fuel_needed = hours_f * self.fuel_burn();
+        if fuel_needed + self.reserve_fuel() > self.current_fuel {
             return Err(GameError::InsufficientFuel {
                 have: self.current_fuel,
-                need: fuel_needed,
+                need: fuel_needed + self.reserve_fuel(),
             });
         }
         // burn the fuel
@@ -225,6 +434,60 @@ impl Airplane {
         self.status = AirplaneStatus::Refueling;
     }
 
+    /// Pump `liters` into the tank (capped at capacity) and switch to `Refueling` status.
+    pub fn refuel_with(&mut self, liters: f32) {
+        self.current_fuel = (self.current_fuel + liters).min(self.specs.fuel_capacity);
+        self.status = AirplaneStatus::Refueling;
+    }
+
+    /// Charge `kwh` into the battery (capped at capacity) and switch to `Charging` status.
+    pub fn charge_with(&mut self, kwh: f32) {
+        self.current_fuel = (self.current_fuel + kwh).min(self.specs.fuel_capacity);
+        self.status = AirplaneStatus::Charging;
+    }
+
+    /// Game time from which the ramp crew is free for another loading or unloading job.
+    pub fn ramp_free_at(&self, now: GameTime) -> GameTime {
+        self.turnaround
+            .iter()
+            .filter(|t| t.task.uses_ramp())
+            .map(|t| t.until)
+            .fold(now, GameTime::max)
+    }
+
+    /// Add a ground task finishing at `until` to the turnaround.
+    ///
+    /// Tasks already done by `now` are not kept; the status follows the latest task
+    /// still under way.
+    pub fn start_task(&mut self, task: GroundTask, until: GameTime, now: GameTime) {
+        if until > now {
+            self.turnaround.push(TurnaroundTask { task, until });
+        }
+        self.finish_tasks(now);
+    }
+
+    /// Drop the ground tasks done by `now`, parking the plane once none are left.
+    ///
+    /// Planes that are not turning around (in flight, in maintenance, broken) keep
+    /// their status.
+    pub fn finish_tasks(&mut self, now: GameTime) {
+        self.turnaround.retain(|t| t.until > now);
+        if !matches!(
+            self.status,
+            AirplaneStatus::Parked
+                | AirplaneStatus::Refueling
+                | AirplaneStatus::Charging
+                | AirplaneStatus::Loading
+                | AirplaneStatus::Unloading
+        ) {
+            return;
+        }
+        self.status = self
+            .turnaround
+            .last()
+            .map_or(AirplaneStatus::Parked, |t| t.task.status());
+    }
+
     /// Perform maintenance, resetting the maintenance counter and switching to `Maintenance` status.
     pub fn maintenance(&mut self) {
         self.hours_since_maintenance = 0;
@@ -241,4 +504,38 @@ impl Airplane {
 
         1.0 - (-lambda).exp()
     }
+
+    /// Chance that the plane breaks down on landing.
+    ///
+    /// A freshly maintained plane fails with `base_chance`; the chance grows with the
+    /// hours since maintenance and doubles while the plane needs maintenance.
+    pub fn landing_failure_chance(&self, base_chance: f32) -> f32 {
+        let wear = (self.hours_since_maintenance as f32 / CONDITION_HOURS).min(2.0);
+        let mut chance = base_chance * (1.0 + LANDING_WEAR_FACTOR * wear);
+        if self.needs_maintenance {
+            chance *= 2.0;
+        }
+        chance.clamp(0.0, 1.0)
+    }
+
+    /// Market value of the plane at game time `now`.
+    ///
+    /// Starts at [`RESALE_NEW_SHARE`] of the purchase price and drops with
+    /// airframe hours, age and maintenance condition, down to
+    /// [`RESALE_FLOOR_SHARE`].
+    pub fn resale_value(&self, now: GameTime) -> f32 {
+        let airframe =
+            (1.0 - AIRFRAME_DEPRECIATION_PER_1000H * self.airframe_hours as f32 / 1000.0).max(0.0);
+        let age_years = now.saturating_sub(self.acquired_at) as f32 / (365.0 * 24.0);
+        let age = (1.0 - AGE_DEPRECIATION_PER_YEAR * age_years).max(0.0);
+
+        let overdue = (self.hours_since_maintenance as f32 / CONDITION_HOURS).min(1.0);
+        let mut condition = 1.0 - CONDITION_DISCOUNT * overdue;
+        if self.needs_maintenance {
+            condition -= NEEDS_REPAIR_DISCOUNT;
+        }
+
+        let share = (RESALE_NEW_SHARE * airframe * age * condition).max(RESALE_FLOOR_SHARE);
+        self.specs.purchase_price * share
+    }
 }
@@ -0,0 +1,91 @@
+use crate::events::GameTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use strum_macros::EnumIter;
+
+/// Scheduled maintenance check, from the quick A check to the heavy C check.
+///
+/// A heavier check covers the lighter ones: a C check also counts as a B and an A
+/// check, and a B check also counts as an A check.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord, EnumIter,
+)]
+pub enum CheckType {
+    A,
+    B,
+    C,
+}
+
+impl fmt::Display for CheckType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckType::A => write!(f, "A"),
+            CheckType::B => write!(f, "B"),
+            CheckType::C => write!(f, "C"),
+        }
+    }
+}
+
+impl FromStr for CheckType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "A" => Ok(CheckType::A),
+            "B" => Ok(CheckType::B),
+            "C" => Ok(CheckType::C),
+            other => Err(format!(
+                "unknown check `{}` (expected `A`, `B` or `C`)",
+                other
+            )),
+        }
+    }
+}
+
+/// Flight hours a plane has logged since its last check of each type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct CheckHours {
+    pub a: GameTime,
+    pub b: GameTime,
+    pub c: GameTime,
+}
+
+impl CheckHours {
+    /// Counters all starting at `hours`.
+    pub fn uniform(hours: GameTime) -> Self {
+        CheckHours {
+            a: hours,
+            b: hours,
+            c: hours,
+        }
+    }
+
+    /// Hours flown since the last check of type `check`.
+    pub fn since(&self, check: CheckType) -> GameTime {
+        match check {
+            CheckType::A => self.a,
+            CheckType::B => self.b,
+            CheckType::C => self.c,
+        }
+    }
+
+    /// Log one flight hour against every check.
+    pub fn add_flight_hour(&mut self) {
+        self.a += 1;
+        self.b += 1;
+        self.c += 1;
+    }
+
+    /// Reset the counter of `check` and of every lighter check it covers.
+    pub fn complete(&mut self, check: CheckType) {
+        self.a = 0;
+        if check >= CheckType::B {
+            self.b = 0;
+        }
+        if check == CheckType::C {
+            self.c = 0;
+        }
+    }
+}
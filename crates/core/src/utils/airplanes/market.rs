@@ -0,0 +1,131 @@
+use super::airplane::{Airplane, RESALE_NEW_SHARE};
+use super::checks::CheckHours;
+use super::models::{AirplaneModel, AirplaneSpecs};
+use crate::config::UsedMarketGameplay;
+use crate::events::GameTime;
+use crate::utils::coordinate::Coordinate;
+use crate::utils::orders::order::OrderAirportInfo;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// Most airframe hours a listed plane can have flown.
+const MAX_LISTED_AIRFRAME_HOURS: GameTime = 8_000;
+/// Most hours since its last maintenance a listed plane can have flown.
+const MAX_LISTED_HOURS_SINCE_MAINTENANCE: GameTime = 400;
+/// Chance that a listed plane is sold with a pending repair.
+const NEEDS_REPAIR_CHANCE: f64 = 0.15;
+
+/// A used airplane offered for sale at an airport.
+///
+/// Buying a listing adds the plane to the fleet with the listed wear: its airframe
+/// hours and maintenance state carry over, so it resells for less than a new one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UsedListing {
+    pub id: usize,
+    /// Airport where the plane is parked
+    pub airport_id: usize,
+    /// Catalog name of the model
+    pub model: String,
+    pub specs: AirplaneSpecs,
+    pub airframe_hours: GameTime,
+    pub hours_since_maintenance: GameTime,
+    pub needs_maintenance: bool,
+    /// Share knocked off the plane's fair value
+    pub discount: f32,
+    /// Asking price
+    pub price: f32,
+    /// Game time after which the listing is withdrawn
+    pub expires_at: GameTime,
+}
+
+impl UsedListing {
+    /// Draw a random listing at one of the large airports.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed for this listing.
+    /// - `id`: Listing id.
+    /// - `airports`: Airports the plane may be parked at.
+    /// - `catalog`: Models that can be listed, sorted by name.
+    /// - `params`: Market tuning.
+    /// - `now`: Current game time.
+    ///
+    /// Returns
+    /// - `Some(UsedListing)` when an airport with a long enough runway exists.
+    /// - `None` otherwise.
+    pub fn generate(
+        seed: u64,
+        id: usize,
+        airports: &[OrderAirportInfo],
+        catalog: &[(String, AirplaneSpecs)],
+        params: &UsedMarketGameplay,
+        now: GameTime,
+    ) -> Option<UsedListing> {
+        let hosts: Vec<&OrderAirportInfo> = airports
+            .iter()
+            .filter(|a| a.runway_length >= params.min_runway_m)
+            .collect();
+        if hosts.is_empty() {
+            return None;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let airport = hosts[rng.gen_range(0..hosts.len())];
+
+        let fitting: Vec<&(String, AirplaneSpecs)> = catalog
+            .iter()
+            .filter(|(_, specs)| specs.min_runway_length <= airport.runway_length)
+            .collect();
+        if fitting.is_empty() {
+            return None;
+        }
+        let (model, specs) = fitting[rng.gen_range(0..fitting.len())].clone();
+
+        let mut listing = UsedListing {
+            id,
+            airport_id: airport.id,
+            model,
+            specs,
+            airframe_hours: rng.gen_range(0..=MAX_LISTED_AIRFRAME_HOURS),
+            hours_since_maintenance: rng.gen_range(0..=MAX_LISTED_HOURS_SINCE_MAINTENANCE),
+            needs_maintenance: rng.gen_bool(NEEDS_REPAIR_CHANCE),
+            discount: rng.gen_range(0.0..=params.max_discount),
+            price: 0.0,
+            expires_at: now + params.listing_hours,
+        };
+
+        // Fair value is the resale value grossed back up to a new-plane price. The asking
+        // price never drops below the resale value, so flipping a listing can't turn a profit.
+        let resale = listing
+            .to_airplane(0, Coordinate::new(0.0, 0.0), now)
+            .resale_value(now);
+        let fair_value = resale / RESALE_NEW_SHARE;
+        listing.price = (fair_value * (1.0 - listing.discount)).max(resale).round();
+        Some(listing)
+    }
+
+    /// Return `true` if the listing has been withdrawn at time `now`.
+    pub fn is_expired(&self, now: GameTime) -> bool {
+        now > self.expires_at
+    }
+
+    /// Build the plane this listing describes, parked at `location`.
+    ///
+    /// Parameters
+    /// - `plane_id`: Id given to the plane.
+    /// - `location`: Coordinate of the listing's airport.
+    /// - `now`: Purchase time.
+    pub fn to_airplane(&self, plane_id: usize, location: Coordinate, now: GameTime) -> Airplane {
+        let model = AirplaneModel::iter()
+            .find(|m| format!("{:?}", m) == self.model)
+            .unwrap_or(AirplaneModel::SparrowLight);
+        let mut plane = Airplane::new(plane_id, model, location);
+        plane.specs = self.specs;
+        plane.current_fuel = self.specs.fuel_capacity;
+        plane.airframe_hours = self.airframe_hours;
+        plane.hours_since_maintenance = self.hours_since_maintenance;
+        plane.check_hours = CheckHours::uniform(self.hours_since_maintenance);
+        plane.needs_maintenance = self.needs_maintenance;
+        plane.acquired_at = now;
+        plane
+    }
+}
@@ -1,2 +1,4 @@
 pub mod airplane;
+pub mod checks;
+pub mod market;
 pub mod models;
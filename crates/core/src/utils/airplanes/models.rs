@@ -1,10 +1,29 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use strum_macros::EnumIter;
 
 use crate::{events::GameTime, utils::coordinate::Coordinate};
 
+/// Mass of one liter of jet fuel (kg).
+pub const FUEL_DENSITY_KG_PER_L: f32 = 0.8;
+/// CO2 released by burning one kg of jet fuel (kg).
+pub const CO2_KG_PER_KG_FUEL: f32 = 3.16;
+/// Average mass of a passenger with luggage (kg).
+pub const PASSENGER_WEIGHT_KG: f32 = 100.0;
+/// Share of a full tank that still fits under MTOW with a full payload.
+pub const FULL_PAYLOAD_FUEL_SHARE: f32 = 0.5;
+/// Share of the battery an electric plane must still hold on landing.
+pub const ELECTRIC_RESERVE_SHARE: f32 = 0.2;
+/// Share of its rated draw an electric plane needs when flying empty; the rest grows
+/// with the weight carried.
+pub const ELECTRIC_EMPTY_DRAW_SHARE: f32 = 0.7;
+/// Heaviest MTOW (kg) of a model that burns avgas; heavier ones burn Jet A-1.
+pub const AVGAS_MAX_MTOW: f32 = 5_700.0;
+
 /// The primary mission role an airplane model is optimized for.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
 pub enum AirplaneRole {
     #[default]
     Cargo,
@@ -12,6 +31,35 @@ pub enum AirplaneRole {
     Mixed,
 }
 
+/// What an airplane model flies on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+pub enum PowerSource {
+    /// Avgas or jet fuel, see [`AirplaneSpecs::fuel_grade`], taken on with a refuel at
+    /// any airport that sells the grade and has stock
+    #[default]
+    Fuel,
+    /// Batteries, recharged only at airports with a charger
+    Electric,
+}
+
+/// Grade of fuel a plane burns; rural strips only sell avgas.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum FuelGrade {
+    /// Aviation gasoline for light piston planes
+    Avgas,
+    /// Kerosene for turbine engines
+    JetA1,
+}
+
+impl fmt::Display for FuelGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuelGrade::Avgas => write!(f, "avgas"),
+            FuelGrade::JetA1 => write!(f, "Jet A-1"),
+        }
+    }
+}
+
 /// Catalog of available airplane models.
 #[derive(Debug, Clone, Serialize, Deserialize, EnumIter, PartialEq)]
 pub enum AirplaneModel {
@@ -25,18 +73,20 @@ pub enum AirplaneModel {
     Lightning,        // Supersonic small jet
     BisonFreighter,   // Medium cargo hauler
     TrailblazerCombi, // High-capacity combi aircraft
+    VoltCommuter,     // Electric commuter
+    AmpereHauler,     // Electric short-haul freighter
 }
 
 /// Static performance and economic specifications for an airplane model.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct AirplaneSpecs {
     /// Max take‑off weight (kg)
     pub mtow: f32,
     /// Cruise speed (km/h)
     pub cruise_speed: f32,
-    /// Fuel tank capacity (liters)
+    /// Fuel tank capacity (liters), or battery capacity (kWh) for electric models
     pub fuel_capacity: f32,
-    /// Fuel burn rate (liters per hour)
+    /// Fuel burn rate (liters per hour), or power draw (kW) for electric models
     pub fuel_consumption: f32,
     /// Operating cost ($ per hour)
     pub operating_cost: f32,
@@ -45,6 +95,9 @@ pub struct AirplaneSpecs {
     /// Passenger capacity (people)
     #[serde(default)]
     pub passenger_capacity: u32,
+    /// Distinct cargo orders the hold takes at once, whatever their weight (0 for no limit)
+    #[serde(default)]
+    pub cargo_slots: u32,
     /// Purchase price
     pub purchase_price: f32,
     /// Minimum runway length required (meters)
@@ -52,6 +105,60 @@ pub struct AirplaneSpecs {
     /// Primary mission role
     #[serde(default)]
     pub role: AirplaneRole,
+    /// Fuel or batteries
+    #[serde(default)]
+    pub power: PowerSource,
+}
+
+impl AirplaneSpecs {
+    /// Operating empty weight (kg), derived from MTOW.
+    ///
+    /// Sized so that a full payload only leaves room for
+    /// [`FULL_PAYLOAD_FUEL_SHARE`] of a full tank: carrying both to the limit
+    /// overshoots MTOW. Batteries weigh the same charged or flat, so an electric
+    /// model's empty weight includes them and it can take a full payload.
+    pub fn empty_weight(&self) -> f32 {
+        let full_payload =
+            self.payload_capacity + self.passenger_capacity as f32 * PASSENGER_WEIGHT_KG;
+        let fuel = match self.power {
+            PowerSource::Fuel => {
+                self.fuel_capacity * FUEL_DENSITY_KG_PER_L * FULL_PAYLOAD_FUEL_SHARE
+            }
+            PowerSource::Electric => 0.0,
+        };
+        (self.mtow - full_payload - fuel).max(0.0)
+    }
+
+    /// Return `true` for battery-powered models.
+    pub fn is_electric(&self) -> bool {
+        self.power == PowerSource::Electric
+    }
+
+    /// Grade of fuel the model burns: avgas up to [`AVGAS_MAX_MTOW`], Jet A-1 above;
+    /// `None` for electric models.
+    pub fn fuel_grade(&self) -> Option<FuelGrade> {
+        match self.power {
+            PowerSource::Electric => None,
+            PowerSource::Fuel if self.mtow <= AVGAS_MAX_MTOW => Some(FuelGrade::Avgas),
+            PowerSource::Fuel => Some(FuelGrade::JetA1),
+        }
+    }
+
+    /// Range (km) on a full tank or battery at normal cruise, keeping the reserve.
+    ///
+    /// Parameters
+    /// - `reserve_hours`: Hours of burn fuel planes keep in the tank; electric planes
+    ///   keep [`ELECTRIC_RESERVE_SHARE`] of the battery instead.
+    pub fn full_range(&self, reserve_hours: f32) -> f32 {
+        let hours = match self.power {
+            PowerSource::Fuel => self.fuel_capacity / self.fuel_consumption - reserve_hours,
+            PowerSource::Electric => {
+                self.fuel_capacity * (1.0 - ELECTRIC_RESERVE_SHARE)
+                    / (self.fuel_consumption * ELECTRIC_EMPTY_DRAW_SHARE)
+            }
+        };
+        hours * self.cruise_speed
+    }
 }
 
 impl AirplaneModel {
@@ -71,6 +178,7 @@ impl AirplaneModel {
             passenger_cap,
             purchase_price,
             role,
+            power,
         ) = match self {
             AirplaneModel::SparrowLight => (
                 5_200.0,
@@ -82,6 +190,7 @@ impl AirplaneModel {
                 6,
                 240_000.0,
                 AirplaneRole::Mixed,
+                PowerSource::Fuel,
             ),
             AirplaneModel::FalconJet => (
                 8_300.0,
@@ -93,6 +202,7 @@ impl AirplaneModel {
                 12,
                 1_700_000.0,
                 AirplaneRole::Passenger,
+                PowerSource::Fuel,
             ),
             AirplaneModel::CometRegional => (
                 24_000.0,
@@ -104,6 +214,7 @@ impl AirplaneModel {
                 78,
                 12_000_000.0,
                 AirplaneRole::Passenger,
+                PowerSource::Fuel,
             ),
             AirplaneModel::Atlas => (
                 42_000.0,
@@ -115,6 +226,7 @@ impl AirplaneModel {
                 68,
                 34_000_000.0,
                 AirplaneRole::Mixed,
+                PowerSource::Fuel,
             ),
             AirplaneModel::TitanHeavy => (
                 110_000.0,
@@ -126,6 +238,7 @@ impl AirplaneModel {
                 0,
                 68_000_000.0,
                 AirplaneRole::Cargo,
+                PowerSource::Fuel,
             ),
             AirplaneModel::Goliath => (
                 210_000.0,
@@ -137,6 +250,7 @@ impl AirplaneModel {
                 0,
                 130_000_000.0,
                 AirplaneRole::Cargo,
+                PowerSource::Fuel,
             ),
             AirplaneModel::Zephyr => (
                 82_000.0,
@@ -148,6 +262,7 @@ impl AirplaneModel {
                 210,
                 72_000_000.0,
                 AirplaneRole::Passenger,
+                PowerSource::Fuel,
             ),
             AirplaneModel::Lightning => (
                 18_500.0,
@@ -159,6 +274,7 @@ impl AirplaneModel {
                 32,
                 88_000_000.0,
                 AirplaneRole::Passenger,
+                PowerSource::Fuel,
             ),
             AirplaneModel::BisonFreighter => (
                 28_000.0,
@@ -170,6 +286,7 @@ impl AirplaneModel {
                 0,
                 18_000_000.0,
                 AirplaneRole::Cargo,
+                PowerSource::Fuel,
             ),
             AirplaneModel::TrailblazerCombi => (
                 65_000.0,
@@ -181,9 +298,50 @@ impl AirplaneModel {
                 120,
                 55_000_000.0,
                 AirplaneRole::Mixed,
+                PowerSource::Fuel,
+            ),
+            AirplaneModel::VoltCommuter => (
+                5_700.0,
+                300.0,
+                900.0,
+                450.0,
+                180.0,
+                800.0,
+                9,
+                3_200_000.0,
+                AirplaneRole::Mixed,
+                PowerSource::Electric,
+            ),
+            AirplaneModel::AmpereHauler => (
+                8_600.0,
+                340.0,
+                2_000.0,
+                950.0,
+                260.0,
+                2_000.0,
+                0,
+                5_500_000.0,
+                AirplaneRole::Cargo,
+                PowerSource::Electric,
             ),
         };
 
+        // Small holds fill up on order count long before weight
+        let cargo_slots = match self {
+            AirplaneModel::SparrowLight => 6,
+            AirplaneModel::FalconJet => 4,
+            AirplaneModel::CometRegional => 10,
+            AirplaneModel::Atlas => 24,
+            AirplaneModel::TitanHeavy => 40,
+            AirplaneModel::Goliath => 60,
+            AirplaneModel::Zephyr => 16,
+            AirplaneModel::Lightning => 4,
+            AirplaneModel::BisonFreighter => 20,
+            AirplaneModel::TrailblazerCombi => 30,
+            AirplaneModel::VoltCommuter => 4,
+            AirplaneModel::AmpereHauler => 8,
+        };
+
         // Cruise speed as m/s
         let cruise_ms: f32 = cruise_kmh * 1000.0 / 3600.0;
 
@@ -209,9 +367,130 @@ impl AirplaneModel {
             operating_cost: op_cost,
             payload_capacity: payload_cap,
             passenger_capacity: passenger_cap,
+            cargo_slots,
             purchase_price,
             min_runway_length,
             role,
+            power,
+        }
+    }
+}
+
+/// Speed setting chosen for a single flight, trading cruise speed against fuel burn.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum CruiseRegime {
+    /// Slower, but burns noticeably less fuel per km.
+    Economy,
+    #[default]
+    Normal,
+    /// Faster, at a steep fuel penalty.
+    Dash,
+}
+
+impl CruiseRegime {
+    /// Multiplier applied to the model's cruise speed.
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            CruiseRegime::Economy => 0.85,
+            CruiseRegime::Normal => 1.0,
+            CruiseRegime::Dash => 1.15,
+        }
+    }
+
+    /// Multiplier applied to the model's hourly fuel burn.
+    pub fn burn_multiplier(&self) -> f32 {
+        match self {
+            CruiseRegime::Economy => 0.7,
+            CruiseRegime::Normal => 1.0,
+            CruiseRegime::Dash => 1.4,
+        }
+    }
+}
+
+impl fmt::Display for CruiseRegime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CruiseRegime::Economy => write!(f, "economy"),
+            CruiseRegime::Normal => write!(f, "normal"),
+            CruiseRegime::Dash => write!(f, "dash"),
+        }
+    }
+}
+
+impl FromStr for CruiseRegime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "econ" | "economy" => Ok(CruiseRegime::Economy),
+            "normal" => Ok(CruiseRegime::Normal),
+            "dash" => Ok(CruiseRegime::Dash),
+            other => Err(format!(
+                "unknown speed `{}` (expected `econ`, `normal` or `dash`)",
+                other
+            )),
+        }
+    }
+}
+
+/// How much fuel to take on when refueling.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FuelAmount {
+    /// Fill the tank as far as the airport's stock allows.
+    #[default]
+    Full,
+    /// Pump this many liters (capped at the free tank space).
+    Liters(f32),
+    /// Top up until the tank holds this share of its capacity, in percent.
+    TargetPercent(f32),
+}
+
+impl FuelAmount {
+    /// Liters wanted for a tank holding `current` out of `capacity` liters.
+    pub fn liters_wanted(&self, current: f32, capacity: f32) -> f32 {
+        let free = (capacity - current).max(0.0);
+        match self {
+            FuelAmount::Full => free,
+            FuelAmount::Liters(liters) => liters.min(free),
+            FuelAmount::TargetPercent(pct) => (capacity * pct / 100.0 - current).clamp(0.0, free),
+        }
+    }
+}
+
+impl fmt::Display for FuelAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuelAmount::Full => write!(f, "full"),
+            FuelAmount::Liters(liters) => write!(f, "{}", liters),
+            FuelAmount::TargetPercent(pct) => write!(f, "{}%", pct),
+        }
+    }
+}
+
+impl FromStr for FuelAmount {
+    type Err = String;
+
+    /// Parse `full`, a number of liters like `500`, or a target level like `80%`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("full") {
+            return Ok(FuelAmount::Full);
+        }
+        if let Some(pct) = s.strip_suffix('%') {
+            return match pct.trim().parse::<f32>() {
+                Ok(pct) if pct > 0.0 && pct <= 100.0 => Ok(FuelAmount::TargetPercent(pct)),
+                _ => Err(format!(
+                    "bad fuel target `{}` (expected a percentage between 0% and 100%)",
+                    s
+                )),
+            };
+        }
+        match s.parse::<f32>() {
+            Ok(liters) if liters > 0.0 && liters.is_finite() => Ok(FuelAmount::Liters(liters)),
+            _ => Err(format!(
+                "bad fuel amount `{}` (expected liters like `500` or a target like `80%`)",
+                s
+            )),
         }
     }
 }
@@ -220,6 +499,7 @@ impl AirplaneModel {
 pub enum AirplaneStatus {
     Parked,
     Refueling,
+    Charging,
     Maintenance,
     Loading,
     Unloading,
@@ -231,3 +511,39 @@ pub enum AirplaneStatus {
     },
     Broken,
 }
+
+/// Ground operation making up part of a plane's turnaround.
+///
+/// Refueling and charging run alongside loading or unloading; loading and unloading
+/// share the ramp crew and follow one another.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum GroundTask {
+    Refueling,
+    Charging,
+    Loading,
+    Unloading,
+}
+
+impl GroundTask {
+    /// Status shown while this task is the latest one under way.
+    pub fn status(self) -> AirplaneStatus {
+        match self {
+            GroundTask::Refueling => AirplaneStatus::Refueling,
+            GroundTask::Charging => AirplaneStatus::Charging,
+            GroundTask::Loading => AirplaneStatus::Loading,
+            GroundTask::Unloading => AirplaneStatus::Unloading,
+        }
+    }
+
+    /// Return `true` if the task needs the ramp crew that moves cargo and passengers.
+    pub fn uses_ramp(self) -> bool {
+        matches!(self, GroundTask::Loading | GroundTask::Unloading)
+    }
+}
+
+/// A ground task under way and the game time it is done.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TurnaroundTask {
+    pub task: GroundTask,
+    pub until: GameTime,
+}
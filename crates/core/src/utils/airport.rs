@@ -1,19 +1,182 @@
+use crate::events::GameTime;
 use crate::utils::{
-    airplanes::airplane::Airplane,
+    airplanes::{airplane::Airplane, checks::CheckType, models::FuelGrade},
+    coordinate::{Coordinate, CoordinateSystem},
     errors::GameError,
-    orders::{DemandGenerationParams, Order, order::OrderAirportInfo},
+    names,
+    orders::{DemandGenerationParams, LaneMarket, Order, order::OrderAirportInfo},
 };
 use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
 use serde::{Deserialize, Serialize};
 
+/// Airports with a runway at least this long (m) have a maintenance hangar.
+pub const HANGAR_MIN_RUNWAY: f32 = 1_500.0;
+
+/// Shortest runway (m) of an international hub.
+pub const HUB_MIN_RUNWAY: f32 = 3_000.0;
+
+/// Smallest [`Airport::size`] of an international hub.
+pub const HUB_MIN_SIZE: f32 = 2.5;
+
+/// Residents per meter of runway, for airports without a configured population.
+pub const RESIDENTS_PER_RUNWAY_METER: f32 = 100.0;
+
+/// Residents of an airport of size 1.0; a derived population gives the runway length in km.
+pub const RESIDENTS_PER_SIZE: f32 = 100_000.0;
+
+/// Parking rate multiplier for planes beyond an airport's [`Airport::parking_stands`].
+pub const REMOTE_STAND_MULTIPLIER: f32 = 2.0;
+
+/// Furthest time zone west of UTC, in hours.
+pub const MIN_UTC_OFFSET: i32 = -12;
+/// Furthest time zone east of UTC, in hours.
+pub const MAX_UTC_OFFSET: i32 = 14;
+/// Width (km) of the planar world, split into 24 time zones.
+const PLANAR_WORLD_WIDTH_KM: f32 = 10_000.0;
+
+/// Hours ahead of UTC at `coord`.
+///
+/// On geographic maps each 15° of longitude is an hour. Planar maps are split into 24
+/// bands across their width, from UTC-12 on the left edge to UTC+12 on the right.
+pub fn default_utc_offset(coord: &Coordinate) -> i32 {
+    let offset = match coord.system {
+        CoordinateSystem::Geographic => (coord.x / 15.0).round() as i32,
+        CoordinateSystem::Planar => (coord.x / PLANAR_WORLD_WIDTH_KM * 24.0).round() as i32 - 12,
+    };
+    offset.clamp(MIN_UTC_OFFSET, MAX_UTC_OFFSET)
+}
+
+/// Wall-clock time at an airport.
+///
+/// The game starts at midnight UTC on day 0, so airports west of UTC start on the
+/// evening of day -1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LocalTime {
+    pub day: i64,
+    /// Hour of the local day, 0-23
+    pub hour: u8,
+    /// Hours ahead of UTC
+    pub utc_offset: i32,
+}
+
+impl LocalTime {
+    /// Local time `utc_offset` hours ahead of UTC at game time `time`.
+    pub fn at(time: GameTime, utc_offset: i32) -> Self {
+        let local = time as i64 + utc_offset as i64;
+        LocalTime {
+            day: local.div_euclid(24),
+            hour: local.rem_euclid(24) as u8,
+            utc_offset,
+        }
+    }
+}
+
+impl std::fmt::Display for LocalTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} {:02}:00 (UTC{:+})",
+            self.day, self.hour, self.utc_offset
+        )
+    }
+}
+
+/// Class of an airport, from its runway and size; sets which fuel grades it sells, the
+/// checks its hangar can do, its parking stands and how many orders it sees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AirportClass {
+    /// Runway under [`HANGAR_MIN_RUNWAY`]
+    Strip,
+    Regional,
+    /// Runway of at least [`HUB_MIN_RUNWAY`] and a size of at least [`HUB_MIN_SIZE`]
+    Hub,
+}
+
+impl AirportClass {
+    /// Fuel grades sold here: strips only stock avgas.
+    pub fn fuel_grades(self) -> &'static [FuelGrade] {
+        match self {
+            AirportClass::Strip => &[FuelGrade::Avgas],
+            AirportClass::Regional | AirportClass::Hub => &[FuelGrade::Avgas, FuelGrade::JetA1],
+        }
+    }
+
+    /// Return `true` if the airport sells `grade`.
+    pub fn sells(self, grade: FuelGrade) -> bool {
+        self.fuel_grades().contains(&grade)
+    }
+
+    /// Heaviest scheduled check the airport's hangar can do; C checks need a hub.
+    pub fn max_check(self) -> CheckType {
+        match self {
+            AirportClass::Strip => CheckType::A,
+            AirportClass::Regional => CheckType::B,
+            AirportClass::Hub => CheckType::C,
+        }
+    }
+
+    /// Parking stands per unit of [`Airport::size`].
+    pub fn stands_per_size(self) -> f32 {
+        match self {
+            AirportClass::Strip => 2.0,
+            AirportClass::Regional => 4.0,
+            AirportClass::Hub => 6.0,
+        }
+    }
+
+    /// Multiplier on the number of orders the airport's size would generate.
+    pub fn order_volume(self) -> f32 {
+        match self {
+            AirportClass::Strip => 0.75,
+            AirportClass::Regional => 1.0,
+            AirportClass::Hub => 1.25,
+        }
+    }
+}
+
+impl std::fmt::Display for AirportClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AirportClass::Strip => write!(f, "Rural strip"),
+            AirportClass::Regional => write!(f, "Regional"),
+            AirportClass::Hub => write!(f, "International hub"),
+        }
+    }
+}
+
+/// Population of an airport's catchment area, derived from its runway length.
+pub fn default_population(runway_length: f32) -> u32 {
+    (runway_length.max(0.0) * RESIDENTS_PER_RUNWAY_METER).round() as u32
+}
+
 fn default_base_fuel_price() -> f32 {
     0.0
 }
 
+fn default_has_hangar() -> bool {
+    true
+}
+
+/// Size of an airport's fuel storage in liters, based on its runway length.
+pub fn default_fuel_capacity(runway_length: f32) -> f32 {
+    match runway_length {
+        ..500.0 => 10_000.0,
+        500.0..1500.0 => 50_000.0,
+        1500.0..2500.0 => 150_000.0,
+        2500.0..3500.0 => 400_000.0,
+        _ => 1_000_000.0,
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Airport {
     pub id: usize,
     pub name: String,
+    /// ICAO code when the airport was imported from a real-world dataset, three-letter
+    /// code when it was generated
+    #[serde(default)]
+    pub code: Option<String>,
     pub runway_length: f32, // Limits the types of airplanes that can take off and land
     pub fuel_price: f32,    // price/L
     #[serde(default = "default_base_fuel_price")]
@@ -22,20 +185,42 @@ pub struct Airport {
     pub parking_fee: f32, // standard fee per hour
     pub orders: Vec<Order>, // list of current orders
     pub fuel_sold: f32,   // demand based on how much fuel was bought
+    /// Fuel storage size in liters; `None` means the airport never runs out
+    #[serde(default)]
+    pub fuel_capacity: Option<f32>,
+    /// Liters of fuel currently in storage
+    #[serde(default)]
+    pub fuel_stock: f32,
+    /// Whether planes can be serviced in a hangar here
+    #[serde(default = "default_has_hangar")]
+    pub has_hangar: bool,
+    /// Index into the map's country list; `None` in a borderless world
+    #[serde(default)]
+    pub country: Option<usize>,
+    /// Spare-part kits in stock for repairs and heavy checks
+    #[serde(default)]
+    pub spare_parts: u32,
+    /// Ground crew the player employs here; each speeds up loading and unloading
+    #[serde(default)]
+    pub ground_crew: u32,
+    /// Whether the player has installed a charger for electric planes here
+    #[serde(default)]
+    pub has_charger: bool,
+    /// Hours ahead of UTC; `None` follows the airport's location, see
+    /// [`default_utc_offset`]
+    #[serde(default)]
+    pub utc_offset: Option<i32>,
+    /// Residents of the catchment area; sets order counts, passenger demand, parking
+    /// stands and fees. `0` in older saves, see [`Airport::ensure_population`].
+    #[serde(default)]
+    pub population: u32,
+    /// Whether the player built the airport; they pay no landing or parking fees there
+    /// and can extend its runway
+    #[serde(default)]
+    pub player_built: bool,
 }
 
 impl Airport {
-    /// Helper function to generate unique names for each airport
-    fn generate_name(mut id: usize) -> String {
-        let mut bytes = [b'A'; 3];
-        for i in (0..3).rev() {
-            bytes[i] = b'A' + (id % 26) as u8;
-            id /= 26;
-        }
-
-        String::from_utf8(bytes.to_vec()).unwrap()
-    }
-
     /// Generate an airport using a seed and an id.
     ///
     /// Parameters
@@ -43,38 +228,92 @@ impl Airport {
     /// - `id`: Airport identifier to assign.
     ///
     /// Returns
-    /// - `Airport`: A randomly configured airport with plausible properties.
+    /// - `Airport`: A randomly configured airport with plausible properties, named after
+    ///   a generated city and coded `AAA`, `AAB`, ... by id.
     pub fn generate_random(seed: u64, id: usize) -> Self {
         let mut rng = StdRng::seed_from_u64(seed.wrapping_add(id as u64));
 
-        let name = Airport::generate_name(id);
-
         // Aiport runways can vary between 245 and 5500 m
         // Runway length will help us determine the other aspects about this airport
         let runway_length: f32 = rng.gen_range(245.0..=5500.0);
+        let name = names::airport_name(seed, id, runway_length);
+
+        let mut airport = Airport::derive(&mut rng, id, name, runway_length, None);
+        airport.code = Some(names::airport_code(id));
+        airport
+    }
+
+    /// Build an airport with a known runway length, deriving fuel price and fees from it.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed used for deterministic generation.
+    /// - `id`: Airport identifier to assign.
+    /// - `name`: Display name.
+    /// - `runway_length`: Runway length in meters.
+    ///
+    /// Returns
+    /// - `Airport`: An airport whose fees scale with its runway length.
+    pub fn with_runway(seed: u64, id: usize, name: String, runway_length: f32) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(id as u64));
+        Airport::derive(&mut rng, id, name, runway_length, None)
+    }
+
+    /// Build an airport with a known runway length and population, deriving fuel price
+    /// and fees from the population.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed used for deterministic generation.
+    /// - `id`: Airport identifier to assign.
+    /// - `name`: Display name.
+    /// - `runway_length`: Runway length in meters.
+    /// - `population`: Residents of the catchment area.
+    ///
+    /// Returns
+    /// - `Airport`: An airport whose fees scale with its population.
+    pub fn with_population(
+        seed: u64,
+        id: usize,
+        name: String,
+        runway_length: f32,
+        population: u32,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(id as u64));
+        Airport::derive(&mut rng, id, name, runway_length, Some(population))
+    }
+
+    fn derive(
+        rng: &mut StdRng,
+        id: usize,
+        name: String,
+        runway_length: f32,
+        population: Option<u32>,
+    ) -> Self {
+        let population = population.unwrap_or_else(|| default_population(runway_length));
+        let size = population as f32 / RESIDENTS_PER_SIZE;
 
         // Can be anywhere between 0.5 and 2.5 per liter
         let fuel_price: f32 = rng.gen_range(0.5..=2.5);
 
         // From research online I see from 2.4 / ton on small ones to 8 on large ones
-        let landing_fee: f32 = match runway_length {
-            245.0..500.0 => rng.gen_range(2.4..=3.0),
-            500.0..1500.0 => rng.gen_range(3.1..=4.0),
-            1500.0..2500.0 => rng.gen_range(4.1..=5.0),
-            2500.0..3500.0 => rng.gen_range(5.1..=6.0),
+        let landing_fee: f32 = match size {
+            ..0.5 => rng.gen_range(2.4..=3.0),
+            0.5..1.5 => rng.gen_range(3.1..=4.0),
+            1.5..2.5 => rng.gen_range(4.1..=5.0),
+            2.5..3.5 => rng.gen_range(5.1..=6.0),
             _ => rng.gen_range(6.1..=9.0),
         };
 
-        // Fee per hour based on the runway length (assume this is linked to the size of the airport)
-        let parking_fee = match runway_length {
-            245.0..=1000.0 => rng.gen_range(5.0..=15.0),
-            1000.0..=3000.0 => rng.gen_range(15.0..=30.0),
+        // Fee per hour grows with the size of the airport
+        let parking_fee = match size {
+            ..=1.0 => rng.gen_range(5.0..=15.0),
+            1.0..=3.0 => rng.gen_range(15.0..=30.0),
             _ => rng.gen_range(30.0..=50.0),
         };
 
         Airport {
             id,
             name,
+            code: None,
             runway_length,
             fuel_price,
             base_fuel_price: fuel_price,
@@ -82,43 +321,89 @@ impl Airport {
             parking_fee,
             orders: Vec::new(),
             fuel_sold: 0.0,
+            fuel_capacity: Some(default_fuel_capacity(runway_length)),
+            fuel_stock: default_fuel_capacity(runway_length),
+            has_hangar: runway_length >= HANGAR_MIN_RUNWAY,
+            country: None,
+            spare_parts: 0,
+            ground_crew: 0,
+            has_charger: false,
+            utc_offset: None,
+            population,
+            player_built: false,
+        }
+    }
+
+    /// Size of the airport: its population in units of [`RESIDENTS_PER_SIZE`].
+    ///
+    /// With a population derived from the runway this is the runway length in km.
+    pub fn size(&self) -> f32 {
+        self.population as f32 / RESIDENTS_PER_SIZE
+    }
+
+    /// Class of the airport, from its runway length and size.
+    pub fn class(&self) -> AirportClass {
+        if self.runway_length >= HUB_MIN_RUNWAY && self.size() >= HUB_MIN_SIZE {
+            AirportClass::Hub
+        } else if self.runway_length >= HANGAR_MIN_RUNWAY {
+            AirportClass::Regional
+        } else {
+            AirportClass::Strip
+        }
+    }
+
+    /// Planes that can park here at the standard rate; more pay for remote stands.
+    pub fn parking_stands(&self) -> usize {
+        ((self.size() * self.class().stands_per_size()).ceil() as usize).max(2)
+    }
+
+    /// Derive the population from the runway length if it is missing (older saves).
+    pub fn ensure_population(&mut self) {
+        if self.population == 0 {
+            self.population = default_population(self.runway_length);
         }
     }
 
     /// Generate orders randomly.
     ///
-    /// Larger airports generate more orders. Passenger orders are created in groups in
-    /// addition to cargo orders.
+    /// More populous airports generate more orders, scaled by the airport's
+    /// [`AirportClass::order_volume`]. Passenger orders are created in groups in addition to
+    /// cargo orders.
     ///
     /// Parameters
     /// - `seed`: RNG seed to produce reproducible orders.
     /// - `airports`: Metadata of all airports to choose valid destinations from.
     /// - `next_order_id`: Mutable counter; incremented as new orders are created.
     /// - `params`: Demand and tuning parameters.
+    /// - `market`: Recent player activity that cargo rates react to.
     pub fn generate_orders(
         &mut self,
         seed: u64,
         airports: &[OrderAirportInfo],
         next_order_id: &mut usize,
         params: &DemandGenerationParams,
+        market: &LaneMarket,
     ) {
         let mut rng = StdRng::seed_from_u64(seed.wrapping_add(self.id as u64));
 
-        let number_orders: usize = match self.runway_length {
-            245.0..500.0 => rng.gen_range(2..=4),
-            500.0..1500.0 => rng.gen_range(5..=8),
-            1500.0..2500.0 => rng.gen_range(9..=15),
-            2500.0..3500.0 => rng.gen_range(15..=24),
+        let number_orders: usize = match self.size() {
+            ..0.5 => rng.gen_range(2..=4),
+            0.5..1.5 => rng.gen_range(5..=8),
+            1.5..2.5 => rng.gen_range(9..=15),
+            2.5..3.5 => rng.gen_range(15..=24),
             _ => rng.gen_range(25..=40),
         };
 
-        let passenger_groups: usize = match self.runway_length {
-            245.0..500.0 => rng.gen_range(1..=2),
-            500.0..1500.0 => rng.gen_range(2..=4),
-            1500.0..2500.0 => rng.gen_range(4..=7),
-            2500.0..3500.0 => rng.gen_range(6..=10),
+        let passenger_groups: usize = match self.size() {
+            ..0.5 => rng.gen_range(1..=2),
+            0.5..1.5 => rng.gen_range(2..=4),
+            1.5..2.5 => rng.gen_range(4..=7),
+            2.5..3.5 => rng.gen_range(6..=10),
             _ => rng.gen_range(10..=18),
         };
+        let volume = self.class().order_volume();
+        let number_orders = ((number_orders as f32 * volume).round() as usize).max(1);
+        let passenger_groups = ((passenger_groups as f32 * volume).round() as usize).max(1);
 
         // Clear all orders within the airport
         self.orders.clear();
@@ -130,12 +415,13 @@ impl Airport {
             let order_seed = seed
                 .wrapping_add(self.id as u64)
                 .wrapping_add(order_id as u64);
-            self.orders.push(Order::new_cargo(
+            self.orders.push(Order::new_cargo_in_market(
                 order_seed,
                 order_id,
                 self.id,
                 airports,
                 &params.cargo,
+                market,
             ));
         }
 
@@ -181,21 +467,45 @@ impl Airport {
         self.landing_fee * (airplane.specs.mtow / 1000.0)
     }
 
+    /// Liters this airport can pump into `airplane`: enough to fill the tank, limited
+    /// by the fuel left in storage.
+    pub fn deliverable_fuel(&self, airplane: &Airplane) -> f32 {
+        let needed = (airplane.specs.fuel_capacity - airplane.current_fuel).max(0.0);
+        match self.fuel_capacity {
+            Some(_) => needed.min(self.fuel_stock.max(0.0)),
+            None => needed,
+        }
+    }
+
     /// Returns the fueling fee for a given airplane.
     ///
     /// Parameters
-    /// - `airplane`: The airplane to refuel to full.
+    /// - `airplane`: The airplane to refuel as far as the stock allows.
     ///
     /// Returns
     /// - `f32`: Total cost using current airport fuel price.
     pub fn fueling_fee(&self, airplane: &Airplane) -> f32 {
-        self.fuel_price * (airplane.specs.fuel_capacity - airplane.current_fuel)
+        self.fuel_price * self.deliverable_fuel(airplane)
+    }
+
+    /// Draw `liters` from storage and record them for dynamic price adjustments later.
+    pub fn fuel_supply(&mut self, liters: f32) {
+        self.fuel_sold += liters;
+        if self.fuel_capacity.is_some() {
+            self.fuel_stock = (self.fuel_stock - liters).max(0.0);
+        }
     }
 
-    /// Record fuel sold to enable dynamic price adjustments later.
-    pub fn fuel_supply(&mut self, airplane: &Airplane) {
-        let fuel_bought = airplane.specs.fuel_capacity - airplane.current_fuel;
-        self.fuel_sold += fuel_bought;
+    /// Return `true` if the airport has limited storage and it is empty.
+    pub fn is_out_of_fuel(&self) -> bool {
+        self.fuel_capacity.is_some() && self.fuel_stock <= 0.0
+    }
+
+    /// Refill the fuel storage to capacity.
+    pub fn resupply_fuel(&mut self) {
+        if let Some(capacity) = self.fuel_capacity {
+            self.fuel_stock = capacity;
+        }
     }
 
     /// Load a single order into the airplane.
@@ -287,19 +597,11 @@ mod tests {
 
     const TEST_ELASTICITY: f32 = 0.05;
 
-    #[test]
-    fn generate_name_check() {
-        assert_eq!(&Airport::generate_name(0), "AAA");
-        assert_eq!(&Airport::generate_name(1), "AAB");
-        assert_eq!(&Airport::generate_name(25), "AAZ");
-        assert_eq!(&Airport::generate_name(26), "ABA");
-        assert_eq!(&Airport::generate_name(26 * 26 + 26 + 1), "BBB");
-    }
-
     fn sample_airport() -> Airport {
         Airport {
             id: 0,
             name: "AAA".to_string(),
+            code: None,
             runway_length: 1000.0,
             fuel_price: 1.5,
             base_fuel_price: 1.5,
@@ -307,6 +609,16 @@ mod tests {
             parking_fee: 10.0,
             orders: Vec::new(),
             fuel_sold: 0.0,
+            fuel_capacity: None,
+            fuel_stock: 0.0,
+            has_hangar: true,
+            country: None,
+            spare_parts: 0,
+            ground_crew: 0,
+            has_charger: false,
+            utc_offset: None,
+            population: 100_000,
+            player_built: false,
         }
     }
 
@@ -0,0 +1,116 @@
+use crate::events::GameTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Hours in a calendar day.
+pub const HOURS_PER_DAY: GameTime = 24;
+/// Days in a calendar year; there are no leap years.
+pub const DAYS_PER_YEAR: u64 = 365;
+
+const MONTH_DAYS: [u64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Calendar date of a game time.
+///
+/// The game starts at midnight on 1 January of year 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Date {
+    pub year: u32,
+    /// 1-12
+    pub month: u8,
+    /// Day of the month, starting at 1
+    pub day: u8,
+}
+
+impl Date {
+    /// Date on which game time `time` falls.
+    pub fn from_time(time: GameTime) -> Self {
+        let days = time / HOURS_PER_DAY;
+        let year = days / DAYS_PER_YEAR;
+        let mut day_of_year = days % DAYS_PER_YEAR;
+        let mut month = 0;
+        while day_of_year >= MONTH_DAYS[month] {
+            day_of_year -= MONTH_DAYS[month];
+            month += 1;
+        }
+        Date {
+            year: year as u32 + 1,
+            month: month as u8 + 1,
+            day: day_of_year as u8 + 1,
+        }
+    }
+
+    /// Game time at midnight on this date.
+    pub fn start(&self) -> GameTime {
+        let days_before_month: u64 = MONTH_DAYS[..(self.month - 1) as usize].iter().sum();
+        let days = (self.year as u64 - 1) * DAYS_PER_YEAR + days_before_month + self.day as u64 - 1;
+        days * HOURS_PER_DAY
+    }
+
+    /// First day of this date's month.
+    pub fn month_start(&self) -> Date {
+        Date { day: 1, ..*self }
+    }
+
+    /// First day of the following month.
+    pub fn next_month(&self) -> Date {
+        if self.month == 12 {
+            Date {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        } else {
+            Date {
+                year: self.year,
+                month: self.month + 1,
+                day: 1,
+            }
+        }
+    }
+
+    /// Short month name, e.g. `Feb`.
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[(self.month - 1) as usize]
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}, year {}", self.day, self.month_name(), self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_roll_over_months_and_years() {
+        assert_eq!(
+            Date::from_time(0),
+            Date {
+                year: 1,
+                month: 1,
+                day: 1
+            }
+        );
+        assert_eq!(Date::from_time(31 * 24 - 1).month, 1);
+        assert_eq!(Date::from_time(31 * 24).month, 2);
+        let new_year = Date::from_time(365 * 24);
+        assert_eq!((new_year.year, new_year.month, new_year.day), (2, 1, 1));
+        assert_eq!(new_year.to_string(), "1 Jan, year 2");
+    }
+
+    #[test]
+    fn start_is_the_inverse_of_from_time() {
+        for day in [0, 30, 58, 59, 364, 365, 800] {
+            let date = Date::from_time(day * 24 + 5);
+            assert_eq!(date.start(), day * 24);
+        }
+        let dec = Date::from_time(340 * 24);
+        assert_eq!(dec.next_month().start(), 365 * 24);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::events::GameTime;
+use crate::utils::map::AirportId;
+use serde::{Deserialize, Serialize};
+
+/// The player's fuel concession at an airport: their fuel there is sold at a fixed
+/// discount, and other carriers' fuel sales pay them a daily cut.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FuelConcession {
+    pub airport: AirportId,
+    /// Price the player paid for it
+    pub price_paid: f32,
+    /// Game time it was bought
+    pub bought_at: GameTime,
+    /// Income collected from third-party traffic so far
+    pub income: f32,
+}
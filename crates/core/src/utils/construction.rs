@@ -0,0 +1,38 @@
+use crate::events::GameTime;
+use crate::utils::coordinate::Coordinate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a construction project builds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Project {
+    /// A new airstrip at a chosen spot; it gets an airport id when it opens
+    Airstrip { location: Coordinate },
+    /// A longer runway at an airport the player built
+    RunwayExtension { airport: usize },
+}
+
+impl fmt::Display for Project {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Project::Airstrip { location } => {
+                write!(f, "airstrip at ({:.0}, {:.0})", location.x, location.y)
+            }
+            Project::RunwayExtension { airport } => {
+                write!(f, "runway extension at airport {}", airport)
+            }
+        }
+    }
+}
+
+/// A paid-for project the player is waiting on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Construction {
+    pub id: usize,
+    pub project: Project,
+    /// Price paid up front
+    pub cost: f32,
+    /// Game time the project finishes
+    pub done_at: GameTime,
+}
@@ -1,10 +1,63 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Mean Earth radius in kilometers, used for great-circle distances.
+pub const EARTH_RADIUS_KM: f32 = 6_371.0;
+
+/// Kilometers per degree of latitude (and of longitude at the equator).
+pub const KM_PER_DEGREE: f32 = 111.32;
+
+/// How the `x`/`y` values of a [`Coordinate`] are interpreted.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoordinateSystem {
+    /// Flat world space in kilometers; distances are Euclidean.
+    #[default]
+    Planar,
+    /// `x` is longitude and `y` is latitude in degrees; distances are great-circle (haversine).
+    Geographic,
+}
+
+impl CoordinateSystem {
+    pub fn is_planar(&self) -> bool {
+        matches!(self, CoordinateSystem::Planar)
+    }
+}
+
+impl fmt::Display for CoordinateSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateSystem::Planar => write!(f, "planar"),
+            CoordinateSystem::Geographic => write!(f, "geographic"),
+        }
+    }
+}
+
+impl FromStr for CoordinateSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "planar" => Ok(CoordinateSystem::Planar),
+            "geographic" => Ok(CoordinateSystem::Geographic),
+            other => Err(format!(
+                "unknown coordinate system `{}` (expected planar or geographic)",
+                other
+            )),
+        }
+    }
+}
 
 /// 2D world-space coordinate (x, y) used for airports and airplanes.
+///
+/// In [`CoordinateSystem::Geographic`] mode `x` holds the longitude and `y` the latitude.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct Coordinate {
     pub x: f32,
     pub y: f32,
+    #[serde(default, skip_serializing_if = "CoordinateSystem::is_planar")]
+    pub system: CoordinateSystem,
 }
 
 impl Coordinate {
@@ -17,7 +70,27 @@ impl Coordinate {
     /// Returns
     /// - `Coordinate`: New coordinate.
     pub fn new(x: f32, y: f32) -> Self {
-        Coordinate { x, y }
+        Coordinate {
+            x,
+            y,
+            system: CoordinateSystem::Planar,
+        }
+    }
+
+    /// Create a geographic coordinate.
+    ///
+    /// Parameters
+    /// - `latitude`: Latitude in degrees.
+    /// - `longitude`: Longitude in degrees.
+    ///
+    /// Returns
+    /// - `Coordinate`: Coordinate with `x = longitude`, `y = latitude`.
+    pub fn geographic(latitude: f32, longitude: f32) -> Self {
+        Coordinate {
+            x: longitude,
+            y: latitude,
+            system: CoordinateSystem::Geographic,
+        }
     }
 
     /// Translate this coordinate by (dx, dy) in place.
@@ -29,4 +102,69 @@ impl Coordinate {
         self.x += dx;
         self.y += dy;
     }
+
+    /// Distance in kilometers to `other`.
+    ///
+    /// Euclidean for planar coordinates, haversine great-circle distance when either
+    /// coordinate is geographic.
+    pub fn distance_to(&self, other: &Coordinate) -> f32 {
+        if self.system.is_planar() && other.system.is_planar() {
+            let dx = self.x - other.x;
+            let dy = self.y - other.y;
+            return (dx * dx + dy * dy).sqrt();
+        }
+
+        let (lat1, lat2) = (self.y.to_radians(), other.y.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.x - self.x).to_radians();
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+
+    /// Point a `fraction` of the way from `self` to `other`.
+    ///
+    /// Planar coordinates are interpolated linearly; geographic ones follow the great circle.
+    pub fn interpolate(&self, other: &Coordinate, fraction: f32) -> Coordinate {
+        if self.system.is_planar() {
+            return Coordinate::new(
+                self.x + (other.x - self.x) * fraction,
+                self.y + (other.y - self.y) * fraction,
+            );
+        }
+
+        let angle = self.distance_to(other) / EARTH_RADIUS_KM;
+        if angle.abs() < 1e-6 {
+            return *self;
+        }
+        let to_vec = |c: &Coordinate| {
+            let (lat, lon) = (c.y.to_radians(), c.x.to_radians());
+            (lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin())
+        };
+        let (a, b) = (to_vec(self), to_vec(other));
+        let wa = ((1.0 - fraction) * angle).sin() / angle.sin();
+        let wb = (fraction * angle).sin() / angle.sin();
+        let (x, y, z) = (
+            wa * a.0 + wb * b.0,
+            wa * a.1 + wb * b.1,
+            wa * a.2 + wb * b.2,
+        );
+        Coordinate::geographic(
+            z.atan2((x * x + y * y).sqrt()).to_degrees(),
+            y.atan2(x).to_degrees(),
+        )
+    }
+
+    /// Flat position in kilometers suitable for drawing on a 2D map.
+    ///
+    /// Planar coordinates are returned unchanged; geographic ones use a sinusoidal
+    /// projection so regional maps keep their shape.
+    pub fn projected(&self) -> Coordinate {
+        match self.system {
+            CoordinateSystem::Planar => *self,
+            CoordinateSystem::Geographic => Coordinate::new(
+                self.x * self.y.to_radians().cos() * KM_PER_DEGREE,
+                self.y * KM_PER_DEGREE,
+            ),
+        }
+    }
 }
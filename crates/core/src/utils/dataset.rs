@@ -0,0 +1,248 @@
+use crate::utils::{
+    coordinate::{Coordinate, KM_PER_DEGREE},
+    errors::GameError,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Map extent used when projecting real-world positions into world space.
+const WORLD_SIZE: f32 = 10_000.0;
+const WORLD_MARGIN: f32 = 200.0;
+
+/// One airport row imported from an OurAirports-style CSV dataset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DatasetAirport {
+    /// ICAO code (`gps_code`, `icao_code` or `ident` column)
+    pub code: String,
+    pub name: String,
+    pub latitude: f32,
+    pub longitude: f32,
+    /// Airport type such as `large_airport`, `medium_airport` or `small_airport`
+    pub kind: String,
+    /// ISO country code, empty when the dataset has no `iso_country` column
+    pub country: String,
+    /// Runway length in meters, either read from the dataset or derived from `kind`
+    pub runway_length: f32,
+}
+
+impl DatasetAirport {
+    /// Rank used to keep the most important airports when the import is capped.
+    fn importance(&self) -> u8 {
+        match self.kind.as_str() {
+            "large_airport" => 3,
+            "medium_airport" => 2,
+            "small_airport" => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Typical runway length for an OurAirports airport type (meters).
+fn runway_for_kind(kind: &str) -> f32 {
+    match kind {
+        "large_airport" => 3_500.0,
+        "medium_airport" => 2_200.0,
+        "small_airport" => 900.0,
+        _ => 600.0,
+    }
+}
+
+fn column(headers: &csv::StringRecord, names: &[&str]) -> Option<usize> {
+    headers.iter().position(|h| {
+        let h = h.trim().to_lowercase();
+        names.iter().any(|n| *n == h)
+    })
+}
+
+/// Read airports from a CSV file using the OurAirports column layout.
+///
+/// Required columns are `name`, `latitude_deg` and `longitude_deg` plus one of `icao_code`,
+/// `gps_code` or `ident`. Closed airports, heliports, seaplane bases and balloonports are
+/// skipped. Runway lengths come from `runway_length_m`/`runway_length_ft`/`length_ft` when
+/// present and are otherwise derived from the airport `type`.
+///
+/// Parameters
+/// - `path`: CSV file to read.
+///
+/// Returns
+/// - `Ok(Vec<DatasetAirport>)` in file order.
+/// - `Err(GameError::InvalidConfig)` if the file cannot be read or lacks required columns.
+pub fn read_airport_csv<P: AsRef<Path>>(path: P) -> Result<Vec<DatasetAirport>, GameError> {
+    let path = path.as_ref();
+    let mut reader = csv::Reader::from_path(path).map_err(|e| GameError::InvalidConfig {
+        msg: format!("failed to read airport dataset {}: {}", path.display(), e),
+    })?;
+
+    let headers = reader
+        .headers()
+        .map_err(|e| GameError::InvalidConfig {
+            msg: format!("airport dataset {} has no header: {}", path.display(), e),
+        })?
+        .clone();
+
+    let missing = |name: &str| GameError::InvalidConfig {
+        msg: format!(
+            "airport dataset {} is missing a `{}` column",
+            path.display(),
+            name
+        ),
+    };
+    let name_col = column(&headers, &["name"]).ok_or_else(|| missing("name"))?;
+    let lat_col = column(&headers, &["latitude_deg", "latitude", "lat"])
+        .ok_or_else(|| missing("latitude_deg"))?;
+    let lon_col = column(&headers, &["longitude_deg", "longitude", "lon"])
+        .ok_or_else(|| missing("longitude_deg"))?;
+    let code_cols: Vec<usize> = ["icao_code", "gps_code", "ident"]
+        .iter()
+        .filter_map(|c| column(&headers, &[c]))
+        .collect();
+    if code_cols.is_empty() {
+        return Err(missing("ident"));
+    }
+    let kind_col = column(&headers, &["type"]);
+    let country_col = column(&headers, &["iso_country", "country"]);
+    let runway_m_col = column(&headers, &["runway_length_m", "length_m"]);
+    let runway_ft_col = column(&headers, &["runway_length_ft", "length_ft"]);
+
+    let mut airports = Vec::new();
+    for (line, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| GameError::InvalidConfig {
+            msg: format!("airport dataset {}: {}", path.display(), e),
+        })?;
+        let field = |idx: usize| record.get(idx).unwrap_or("").trim();
+
+        let kind = kind_col.map(field).unwrap_or("").to_lowercase();
+        if matches!(
+            kind.as_str(),
+            "closed" | "heliport" | "seaplane_base" | "balloonport"
+        ) {
+            continue;
+        }
+
+        let code = match code_cols.iter().map(|c| field(*c)).find(|c| !c.is_empty()) {
+            Some(code) => code.to_uppercase(),
+            None => continue,
+        };
+
+        let parse = |idx: usize, label: &str| -> Result<f32, GameError> {
+            field(idx)
+                .parse::<f32>()
+                .map_err(|_| GameError::InvalidConfig {
+                    msg: format!(
+                        "airport dataset {} row {}: invalid {} `{}`",
+                        path.display(),
+                        line + 2,
+                        label,
+                        field(idx)
+                    ),
+                })
+        };
+        let latitude = parse(lat_col, "latitude")?;
+        let longitude = parse(lon_col, "longitude")?;
+
+        let runway_length = runway_m_col
+            .and_then(|c| field(c).parse::<f32>().ok())
+            .or_else(|| {
+                runway_ft_col
+                    .and_then(|c| field(c).parse::<f32>().ok())
+                    .map(|ft| ft * 0.3048)
+            })
+            .filter(|len| *len > 0.0)
+            .unwrap_or_else(|| runway_for_kind(&kind))
+            .clamp(245.0, 5_500.0);
+
+        airports.push(DatasetAirport {
+            code,
+            name: field(name_col).to_string(),
+            latitude,
+            longitude,
+            kind,
+            country: country_col.map(field).unwrap_or("").to_uppercase(),
+            runway_length,
+        });
+    }
+
+    Ok(airports)
+}
+
+/// Keep airports from the given countries and cap the total, preferring larger airports.
+///
+/// Parameters
+/// - `airports`: Imported rows.
+/// - `countries`: ISO country codes to keep (case-insensitive); empty keeps all.
+/// - `max_airports`: Optional cap on the number of airports.
+///
+/// Returns
+/// - `Vec<DatasetAirport>`: Selected rows in their original order.
+pub fn select_airports(
+    airports: Vec<DatasetAirport>,
+    countries: &[String],
+    max_airports: Option<usize>,
+) -> Vec<DatasetAirport> {
+    let mut selected: Vec<(usize, DatasetAirport)> = airports
+        .into_iter()
+        .filter(|a| {
+            countries.is_empty() || countries.iter().any(|c| c.eq_ignore_ascii_case(&a.country))
+        })
+        .enumerate()
+        .collect();
+
+    if let Some(max) = max_airports {
+        if selected.len() > max {
+            selected.sort_by(|(ia, a), (ib, b)| {
+                b.importance()
+                    .cmp(&a.importance())
+                    .then(b.runway_length.total_cmp(&a.runway_length))
+                    .then(ia.cmp(ib))
+            });
+            selected.truncate(max);
+            selected.sort_by_key(|(idx, _)| *idx);
+        }
+    }
+
+    selected.into_iter().map(|(_, a)| a).collect()
+}
+
+/// Project latitude/longitude onto the flat world map, keeping real distances in km.
+///
+/// Uses an equirectangular projection around the dataset's mid latitude. Regions larger than
+/// the world map are scaled down uniformly so every airport stays in bounds.
+///
+/// Parameters
+/// - `positions`: `(latitude, longitude)` pairs in degrees.
+///
+/// Returns
+/// - `Vec<Coordinate>`: World coordinates in the same order.
+pub fn project_positions(positions: &[(f32, f32)]) -> Vec<Coordinate> {
+    if positions.is_empty() {
+        return Vec::new();
+    }
+
+    let (min_lat, max_lat) = positions
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), (lat, _)| {
+            (lo.min(*lat), hi.max(*lat))
+        });
+    let (min_lon, max_lon) = positions
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), (_, lon)| {
+            (lo.min(*lon), hi.max(*lon))
+        });
+
+    let mid_lat = ((min_lat + max_lat) / 2.0).to_radians();
+    let km_per_lon = KM_PER_DEGREE * mid_lat.cos().max(0.01);
+    let width = (max_lon - min_lon) * km_per_lon;
+    let height = (max_lat - min_lat) * KM_PER_DEGREE;
+    let usable = WORLD_SIZE - 2.0 * WORLD_MARGIN;
+    let scale = (usable / width.max(height).max(1.0)).min(1.0);
+
+    positions
+        .iter()
+        .map(|(lat, lon)| {
+            Coordinate::new(
+                WORLD_MARGIN + (lon - min_lon) * km_per_lon * scale,
+                WORLD_MARGIN + (lat - min_lat) * KM_PER_DEGREE * scale,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+use crate::config::PassengerDemandGameplay;
+use crate::utils::orders::order::OrderAirportInfo;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+/// Distances (km) below this count as this long, so neighbouring airports don't get
+/// unbounded demand.
+const MIN_DEMAND_DISTANCE: f32 = 50.0;
+
+/// Distance (km) at which the gravity model is calibrated by `scale`.
+const REFERENCE_DISTANCE: f32 = 100.0;
+
+/// Cheap tickets can at most multiply a route's demand by this much.
+const MAX_PRICE_BOOST: f32 = 2.0;
+
+/// Share of `seats` a departure fills when `demand` passengers want to travel.
+///
+/// Returns `0.0` when there are no seats and at most `1.0`.
+pub fn load_factor(demand: u32, seats: u32) -> f32 {
+    if seats == 0 {
+        return 0.0;
+    }
+    (demand as f32 / seats as f32).min(1.0)
+}
+
+/// Passengers still buying tickets at `fare` when `demand` would fly at `reference_fare`.
+///
+/// Demand scales with `(reference_fare / fare)^elasticity`: pricing above the reference
+/// loses passengers, pricing below wins some more, up to twice the demand.
+pub fn priced_demand(demand: u32, fare: f32, reference_fare: f32, elasticity: f32) -> u32 {
+    if fare <= 0.0 || reference_fare <= 0.0 {
+        return demand;
+    }
+    let factor = (reference_fare / fare)
+        .powf(elasticity)
+        .min(MAX_PRICE_BOOST);
+    (demand as f32 * factor).floor() as u32
+}
+
+/// Ticket price the player set for scheduled flights on one route, with its takings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RouteFare {
+    pub origin_id: usize,
+    pub destination_id: usize,
+    /// Price of one ticket
+    pub fare: f32,
+    /// Tickets sold on the route today; demand is shared by all of the day's departures
+    #[serde(default)]
+    pub sold_today: u32,
+    /// Departures flown on the route so far
+    #[serde(default)]
+    pub departures: u32,
+    /// Ticket revenue taken on the route so far
+    #[serde(default)]
+    pub revenue: f32,
+    /// Share of the seats filled on the latest departure
+    #[serde(default)]
+    pub last_load_factor: f32,
+}
+
+/// Passengers wanting to fly from one airport to another on the current day.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RouteDemand {
+    pub origin_id: usize,
+    pub destination_id: usize,
+    pub passengers: u32,
+}
+
+/// Daily passenger demand between every pair of airports.
+///
+/// Demand follows a gravity model: it grows with the size of both airports and falls off
+/// with the distance between them. Every day the model value moves by a small seeded
+/// amount, so a route's load factor is predictable from one day to the next.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PassengerDemand {
+    /// Game day the demand was drawn for
+    pub day: u64,
+    /// Routes with any demand, sorted by origin and destination
+    routes: Vec<RouteDemand>,
+}
+
+impl PassengerDemand {
+    /// Draw the demand between all airport pairs for `day`.
+    ///
+    /// Parameters
+    /// - `seed`: World seed; combined with `day` so every day draws its own variation.
+    /// - `day`: Game day the demand is for.
+    /// - `airports`: Airports to connect.
+    /// - `params`: Gravity model tuning.
+    ///
+    /// Returns
+    /// - `PassengerDemand`: Demand for every ordered pair with at least one passenger.
+    pub fn generate(
+        seed: u64,
+        day: u64,
+        airports: &[OrderAirportInfo],
+        params: &PassengerDemandGameplay,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(
+            seed.wrapping_mul(97)
+                .wrapping_add(day.wrapping_mul(31))
+                .wrapping_add(11),
+        );
+        let mut routes = Vec::new();
+        for origin in airports {
+            for destination in airports {
+                if origin.id == destination.id {
+                    continue;
+                }
+                let distance = origin
+                    .coordinate
+                    .distance_to(&destination.coordinate)
+                    .max(MIN_DEMAND_DISTANCE);
+                let base = params.scale * origin.size * destination.size
+                    / (distance / REFERENCE_DISTANCE).powf(params.distance_exponent);
+                let swing = if params.daily_variation > 0.0 {
+                    rng.gen_range(-params.daily_variation..=params.daily_variation)
+                } else {
+                    0.0
+                };
+                let passengers = (base * (1.0 + swing)).round().max(0.0) as u32;
+                if passengers > 0 {
+                    routes.push(RouteDemand {
+                        origin_id: origin.id,
+                        destination_id: destination.id,
+                        passengers,
+                    });
+                }
+            }
+        }
+        routes.sort_by_key(|r| (r.origin_id, r.destination_id));
+        PassengerDemand { day, routes }
+    }
+
+    /// Passengers wanting to fly from `origin_id` to `destination_id` today.
+    pub fn passengers(&self, origin_id: usize, destination_id: usize) -> u32 {
+        self.routes
+            .binary_search_by_key(&(origin_id, destination_id), |r| {
+                (r.origin_id, r.destination_id)
+            })
+            .map_or(0, |idx| self.routes[idx].passengers)
+    }
+
+    /// Routes leaving `origin_id`, by destination id.
+    pub fn from_airport(&self, origin_id: usize) -> impl Iterator<Item = &RouteDemand> {
+        self.routes.iter().filter(move |r| r.origin_id == origin_id)
+    }
+
+    /// All routes with demand, sorted by origin and destination.
+    pub fn routes(&self) -> &[RouteDemand] {
+        &self.routes
+    }
+}
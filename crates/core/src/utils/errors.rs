@@ -3,8 +3,12 @@ use std::fmt;
 use strsim::levenshtein;
 use strum::IntoEnumIterator;
 
+use crate::tr;
 use crate::utils::{
-    airplanes::models::{AirplaneModel, AirplaneStatus},
+    airplanes::{
+        checks::CheckType,
+        models::{AirplaneModel, AirplaneStatus, FuelGrade},
+    },
     coordinate::Coordinate,
 };
 
@@ -29,6 +33,10 @@ pub enum GameError {
         maximum_capacity: u32,
         added_passengers: u32,
     },
+    CargoSlotsFull {
+        plane_model: String,
+        slots: u32,
+    },
     PayloadTypeUnsupported {
         plane_model: String,
         payload: String,
@@ -36,6 +44,46 @@ pub enum GameError {
     OrderIdInvalid {
         id: usize,
     },
+    ContractIdInvalid {
+        id: usize,
+    },
+    CharterIdInvalid {
+        id: usize,
+    },
+    LaneIdInvalid {
+        id: usize,
+    },
+    AuctionIdInvalid {
+        id: usize,
+    },
+    ListingIdInvalid {
+        id: usize,
+    },
+    UnknownGroup {
+        name: String,
+    },
+    PlaneOnHold {
+        plane_id: usize,
+    },
+    CheckOverdue {
+        plane_id: usize,
+        check: CheckType,
+    },
+    PilotIdInvalid {
+        id: usize,
+    },
+    NoRestedPilot {
+        plane_id: usize,
+    },
+    MissingSpareParts {
+        airport_id: usize,
+        have: u32,
+        need: u32,
+    },
+    CheckUnavailable {
+        airport_id: usize,
+        check: CheckType,
+    },
     PlaneIdInvalid {
         id: usize,
     },
@@ -45,6 +93,17 @@ pub enum GameError {
     AirportLocationInvalid {
         location: Coordinate,
     },
+    SiteOffMap {
+        x: f32,
+        y: f32,
+    },
+    SiteTooClose {
+        distance: f32,
+        min_distance: f32,
+    },
+    AirportNotOwned {
+        airport_id: usize,
+    },
     PlaneNotAtAirport {
         plane_id: usize,
     },
@@ -59,6 +118,17 @@ pub enum GameError {
         have: f32,
         need: f32,
     },
+    FuelShortage {
+        airport_id: usize,
+    },
+    FuelGradeUnavailable {
+        airport_id: usize,
+        grade: FuelGrade,
+    },
+    OverMaxTakeoffWeight {
+        takeoff_weight: f32,
+        mtow: f32,
+    },
     UnknownModel {
         input: String,
         suggestion: Option<String>,
@@ -99,110 +169,163 @@ impl GameError {
 
 impl fmt::Display for GameError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            GameError::OutOfRange { distance, range } => {
-                write!(
-                    f,
-                    "Distance {:.2} is outside of the airplane range {:.2}",
-                    distance, range
-                )
-            }
+        let message = match self {
+            GameError::OutOfRange { distance, range } => tr!(
+                "error-out-of-range",
+                distance = format!("{:.2}", distance),
+                range = format!("{:.2}", range),
+            ),
             GameError::RunwayTooShort {
                 required,
                 available,
-            } => {
-                write!(
-                    f,
-                    "Airplane requires at least {:.2} m of runway. Destination has a length of {:.2}",
-                    required, available
-                )
-            }
+            } => tr!(
+                "error-runway-too-short",
+                required = format!("{:.2}", required),
+                available = format!("{:.2}", available),
+            ),
             GameError::MaxPayloadReached {
                 current_capacity,
                 maximum_capacity,
                 added_weight,
-            } => {
-                write!(
-                    f,
-                    "Cannot load order of weight {:.2}. Airplane capacity: {:.2}. Current Capacity: {:.2}",
-                    added_weight, maximum_capacity, current_capacity
-                )
-            }
+            } => tr!(
+                "error-max-payload-reached",
+                added_weight = format!("{:.2}", added_weight),
+                maximum_capacity = format!("{:.2}", maximum_capacity),
+                current_capacity = format!("{:.2}", current_capacity),
+            ),
             GameError::PassengerCapacityReached {
                 current_capacity,
                 maximum_capacity,
                 added_passengers,
-            } => {
-                write!(
-                    f,
-                    "Cannot board {} passengers. Seats available: {}. Currently occupied: {}",
-                    added_passengers, maximum_capacity, current_capacity
-                )
-            }
+            } => tr!(
+                "error-passenger-capacity-reached",
+                added_passengers = *added_passengers,
+                maximum_capacity = *maximum_capacity,
+                current_capacity = *current_capacity,
+            ),
+            GameError::CargoSlotsFull { plane_model, slots } => tr!(
+                "error-cargo-slots-full",
+                plane_model = plane_model.as_str(),
+                slots = *slots,
+            ),
             GameError::PayloadTypeUnsupported {
                 plane_model,
                 payload,
-            } => {
-                write!(f, "Plane {} cannot carry {} payloads", plane_model, payload)
+            } => tr!(
+                "error-payload-type-unsupported",
+                plane_model = plane_model.as_str(),
+                payload = payload.as_str(),
+            ),
+            GameError::OrderIdInvalid { id } => tr!("error-order-id-invalid", id = *id),
+            GameError::ContractIdInvalid { id } => tr!("error-contract-id-invalid", id = *id),
+            GameError::CharterIdInvalid { id } => tr!("error-charter-id-invalid", id = *id),
+            GameError::LaneIdInvalid { id } => tr!("error-lane-id-invalid", id = *id),
+            GameError::AuctionIdInvalid { id } => tr!("error-auction-id-invalid", id = *id),
+            GameError::ListingIdInvalid { id } => tr!("error-listing-id-invalid", id = *id),
+            GameError::UnknownGroup { name } => tr!("error-unknown-group", name = name.as_str()),
+            GameError::PlaneOnHold { plane_id } => {
+                tr!("error-plane-on-hold", plane_id = *plane_id)
             }
-            GameError::OrderIdInvalid { id } => {
-                write!(f, "Order with id {:?} does not exist", id)
-            }
-            GameError::PlaneIdInvalid { id } => {
-                write!(f, "Plan with id {:?} does not exist", id)
+            GameError::CheckOverdue { plane_id, check } => tr!(
+                "error-check-overdue",
+                plane_id = *plane_id,
+                check = check.to_string(),
+            ),
+            GameError::PilotIdInvalid { id } => tr!("error-pilot-id-invalid", id = *id),
+            GameError::NoRestedPilot { plane_id } => {
+                tr!("error-no-rested-pilot", plane_id = *plane_id)
             }
+            GameError::MissingSpareParts {
+                airport_id,
+                have,
+                need,
+            } => tr!(
+                "error-missing-spare-parts",
+                airport_id = *airport_id,
+                have = *have,
+                need = *need,
+            ),
+            GameError::CheckUnavailable { airport_id, check } => tr!(
+                "error-check-unavailable",
+                airport_id = *airport_id,
+                check = check.to_string(),
+            ),
+            GameError::PlaneIdInvalid { id } => tr!("error-plane-id-invalid", id = *id),
             GameError::PlaneNotAtAirport { plane_id } => {
-                write!(f, "Plane {} is not located at any known airport", plane_id)
-            }
-            GameError::AirportIdInvalid { id } => {
-                write!(f, "Airport with id {} does not exist", id)
-            }
-            GameError::AirportLocationInvalid { location } => {
-                write!(
-                    f,
-                    "No airport found at coordinate ({:.2}, {:.2})",
-                    location.x, location.y
-                )
+                tr!("error-plane-not-at-airport", plane_id = *plane_id)
             }
-            GameError::InsufficientFunds { have, need } => {
-                write!(
-                    f,
-                    "Insufficient funds. Need: ${:.2}. Currently have: ${:.2}",
-                    need, have
-                )
+            GameError::AirportIdInvalid { id } => tr!("error-airport-id-invalid", id = *id),
+            GameError::AirportLocationInvalid { location } => tr!(
+                "error-airport-location-invalid",
+                x = format!("{:.2}", location.x),
+                y = format!("{:.2}", location.y),
+            ),
+            GameError::SiteOffMap { x, y } => tr!(
+                "error-site-off-map",
+                x = format!("{:.0}", x),
+                y = format!("{:.0}", y),
+            ),
+            GameError::SiteTooClose {
+                distance,
+                min_distance,
+            } => tr!(
+                "error-site-too-close",
+                distance = format!("{:.0}", distance),
+                min_distance = format!("{:.0}", min_distance),
+            ),
+            GameError::AirportNotOwned { airport_id } => {
+                tr!("error-airport-not-owned", airport_id = *airport_id)
             }
-            GameError::InsufficientFuel { have, need } => {
-                write!(
-                    f,
-                    "Insufficient fuel. Need: {:.2}L. Currently have: {:.2}L",
-                    need, have
-                )
+            GameError::InsufficientFunds { have, need } => tr!(
+                "error-insufficient-funds",
+                need = format!("{:.2}", need),
+                have = format!("{:.2}", have),
+            ),
+            GameError::InsufficientFuel { have, need } => tr!(
+                "error-insufficient-fuel",
+                need = format!("{:.2}", need),
+                have = format!("{:.2}", have),
+            ),
+            GameError::FuelShortage { airport_id } => {
+                tr!("error-fuel-shortage", airport_id = *airport_id)
             }
+            GameError::FuelGradeUnavailable { airport_id, grade } => tr!(
+                "error-fuel-grade-unavailable",
+                airport_id = *airport_id,
+                grade = grade.to_string(),
+            ),
+            GameError::OverMaxTakeoffWeight {
+                takeoff_weight,
+                mtow,
+            } => tr!(
+                "error-over-max-takeoff-weight",
+                takeoff_weight = format!("{:.0}", takeoff_weight),
+                mtow = format!("{:.0}", mtow),
+            ),
             GameError::UnknownModel { input, suggestion } => {
                 let sug = suggestion
                     .clone()
                     .or_else(|| GameError::suggest_model(input));
-                if let Some(s) = sug {
-                    write!(f, "`{}` doesn't exist. Did you mean `{}`?", input, s)
-                } else {
-                    write!(f, "`{}` doesn't exist.", input)
+                match sug {
+                    Some(s) => tr!(
+                        "error-unknown-model-suggestion",
+                        input = input.as_str(),
+                        suggestion = s,
+                    ),
+                    None => tr!("error-unknown-model", input = input.as_str()),
                 }
             }
             GameError::PlaneNotReady { plane_state } => {
-                write!(f, "Airplane not ready. Current status: {:?}", plane_state)
-            }
-            GameError::NoCargo => {
-                write!(f, "No cargo to unload")
-            }
-            GameError::SameAirport => {
-                write!(f, "Cannot fly to the airport the plane is currently at")
-            }
-            GameError::InvalidCommand { msg } => {
-                write!(f, "{}", msg)
-            }
-            GameError::InvalidConfig { msg } => {
-                write!(f, "Invalid config: {}", msg)
+                tr!(
+                    "error-plane-not-ready",
+                    status = format!("{:?}", plane_state)
+                )
             }
-        }
+            GameError::NoCargo => tr!("error-no-cargo"),
+            GameError::SameAirport => tr!("error-same-airport"),
+            GameError::InvalidCommand { msg } => msg.clone(),
+            GameError::InvalidConfig { msg } => tr!("error-invalid-config", msg = msg.as_str()),
+        };
+        f.write_str(&message)
     }
 }
@@ -0,0 +1,50 @@
+use crate::config::{LoyaltyGameplay, LoyaltyTier};
+use crate::events::GameTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Landings and departures the player made at each airport, for loyalty discounts.
+///
+/// Only operations inside the settings' window count; older ones are dropped as new
+/// ones are recorded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AirportLoyalty {
+    operations: BTreeMap<usize, Vec<GameTime>>,
+}
+
+impl AirportLoyalty {
+    /// Count a landing or departure at `airport_id`.
+    ///
+    /// Parameters
+    /// - `airport_id`: Airport the plane used.
+    /// - `now`: Current game time.
+    /// - `window_hours`: Hours an operation keeps counting.
+    pub fn record(&mut self, airport_id: usize, now: GameTime, window_hours: GameTime) {
+        let times = self.operations.entry(airport_id).or_default();
+        times.retain(|t| t + window_hours > now);
+        times.push(now);
+    }
+
+    /// Operations at `airport_id` within the `window_hours` before `now`.
+    pub fn count(&self, airport_id: usize, now: GameTime, window_hours: GameTime) -> u32 {
+        self.operations.get(&airport_id).map_or(0, |times| {
+            times.iter().filter(|t| *t + window_hours > now).count() as u32
+        })
+    }
+
+    /// Tier the player holds at `airport_id`, if any.
+    pub fn tier<'a>(
+        &self,
+        airport_id: usize,
+        now: GameTime,
+        settings: &'a LoyaltyGameplay,
+    ) -> Option<&'a LoyaltyTier> {
+        settings.tier(self.count(airport_id, now, settings.window_hours))
+    }
+
+    /// Multiplier on landing, parking and fuel bills at `airport_id`.
+    pub fn fee_factor(&self, airport_id: usize, now: GameTime, settings: &LoyaltyGameplay) -> f32 {
+        self.tier(airport_id, now, settings)
+            .map_or(1.0, |t| 1.0 - t.discount)
+    }
+}
@@ -1,24 +1,220 @@
 use crate::utils::{
     airport::Airport,
-    coordinate::Coordinate,
-    orders::{DemandGenerationParams, order::OrderAirportInfo},
+    coordinate::{Coordinate, CoordinateSystem},
+    dataset::{DatasetAirport, project_positions, read_airport_csv},
+    errors::GameError,
+    orders::{DemandGenerationParams, LaneMarket, order::OrderAirportInfo},
 };
 use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
-use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
 use std::f32::consts::TAU;
+use std::fmt;
+use std::ops::Range;
+use std::path::Path;
+use std::str::FromStr;
+
+const COUNTRY_NAMES: [&str; 12] = [
+    "Avalonia",
+    "Borduria",
+    "Carpathia",
+    "Drakonia",
+    "Elbonia",
+    "Florin",
+    "Genovia",
+    "Hesperia",
+    "Illyria",
+    "Jotunheim",
+    "Kyrat",
+    "Latveria",
+];
+
+fn country_name(idx: usize) -> String {
+    let base = COUNTRY_NAMES[idx % COUNTRY_NAMES.len()];
+    match idx / COUNTRY_NAMES.len() {
+        0 => base.to_string(),
+        n => format!("{} {}", base, n + 1),
+    }
+}
+
+/// Layout strategy used when airport coordinates are generated.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WorldStyle {
+    /// Loose clusters of airports spread over the whole map.
+    #[default]
+    Clustered,
+    /// A handful of dense continents separated by long-haul gaps.
+    Continents,
+}
+
+impl fmt::Display for WorldStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorldStyle::Clustered => write!(f, "clustered"),
+            WorldStyle::Continents => write!(f, "continents"),
+        }
+    }
+}
+
+impl FromStr for WorldStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "clustered" => Ok(WorldStyle::Clustered),
+            "continents" => Ok(WorldStyle::Continents),
+            other => Err(format!(
+                "unknown world style `{}` (expected `clustered` or `continents`)",
+                other
+            )),
+        }
+    }
+}
+
+/// Id of an airport, as used by commands, orders and configs. Ids are not positions in the
+/// map: configs may use any unique ids, in any order.
+pub type AirportId = usize;
+
+/// The airports of a map in map order, with their coordinates, looked up by id.
+///
+/// There is deliberately no positional indexing; use [`Airports::get`] or the accessors on
+/// [`Map`]. Serialized as a plain list, like the `Vec` it replaces.
+#[derive(Debug, Clone, Default)]
+pub struct Airports {
+    entries: Vec<(Airport, Coordinate)>,
+    /// Position of every airport in `entries`, by id
+    index: HashMap<AirportId, usize>,
+}
+
+impl Airports {
+    /// Index `entries` by airport id. When ids repeat the first airport wins the lookups.
+    ///
+    /// Airports without a population (older saves) get one derived from their runway.
+    pub fn new(mut entries: Vec<(Airport, Coordinate)>) -> Self {
+        let mut index = HashMap::with_capacity(entries.len());
+        for (pos, (airport, _)) in entries.iter_mut().enumerate() {
+            airport.ensure_population();
+            index.entry(airport.id).or_insert(pos);
+        }
+        Airports { entries, index }
+    }
+
+    /// The airport with `id` and its coordinate.
+    pub fn get(&self, id: AirportId) -> Option<&(Airport, Coordinate)> {
+        self.index.get(&id).map(|&pos| &self.entries[pos])
+    }
+
+    /// The airport with `id`, for editing. Its id must not be changed.
+    pub fn get_mut(&mut self, id: AirportId) -> Option<&mut Airport> {
+        self.index.get(&id).map(|&pos| &mut self.entries[pos].0)
+    }
+
+    /// Add an airport at the end of the map order. Its id must not be taken yet.
+    pub fn push(&mut self, mut airport: Airport, coord: Coordinate) {
+        airport.ensure_population();
+        self.index.entry(airport.id).or_insert(self.entries.len());
+        self.entries.push((airport, coord));
+    }
+
+    pub fn contains(&self, id: AirportId) -> bool {
+        self.index.contains_key(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn first(&self) -> Option<&(Airport, Coordinate)> {
+        self.entries.first()
+    }
+
+    /// All airports in map order, as a slice.
+    pub fn as_slice(&self) -> &[(Airport, Coordinate)] {
+        &self.entries
+    }
+
+    /// Airports in map order.
+    pub fn iter(&self) -> std::slice::Iter<'_, (Airport, Coordinate)> {
+        self.entries.iter()
+    }
+
+    /// Airports in map order, for editing. Ids must not be changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&mut Airport, &Coordinate)> {
+        self.entries
+            .iter_mut()
+            .map(|(airport, coord)| (airport, &*coord))
+    }
+}
+
+impl<'a> IntoIterator for &'a Airports {
+    type Item = &'a (Airport, Coordinate);
+    type IntoIter = std::slice::Iter<'a, (Airport, Coordinate)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl From<Vec<(Airport, Coordinate)>> for Airports {
+    fn from(entries: Vec<(Airport, Coordinate)>) -> Self {
+        Airports::new(entries)
+    }
+}
+
+impl Serialize for Airports {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.entries.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Airports {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Airports::new)
+    }
+}
 
 /// A procedurally generated world map with airports and demand parameters.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Map {
     pub num_airports: usize,
-    pub airports: Vec<(Airport, Coordinate)>,
+    pub airports: Airports,
     pub seed: u64,
     next_order_id: usize,
     #[serde(default)]
     pub demand_params: DemandGenerationParams,
+    /// Layout used when the airports were generated
+    #[serde(default)]
+    pub world_style: WorldStyle,
+    /// Country names; airports refer to them by index
+    #[serde(default)]
+    pub countries: Vec<String>,
+    /// Recent deliveries and backlogs that new cargo orders are priced against
+    #[serde(default)]
+    pub lane_market: LaneMarket,
 }
 
 impl Map {
+    /// The airport with `id`.
+    pub fn airport(&self, id: AirportId) -> Option<&Airport> {
+        self.airports.get(id).map(|(airport, _)| airport)
+    }
+
+    /// The airport with `id`, for editing.
+    pub fn airport_mut(&mut self, id: AirportId) -> Option<&mut Airport> {
+        self.airports.get_mut(id)
+    }
+
+    /// Location of the airport with `id`.
+    pub fn coord(&self, id: AirportId) -> Option<Coordinate> {
+        self.airports.get(id).map(|(_, coord)| *coord)
+    }
+
     fn clustered_coordinates(seed: u64, count: usize) -> Vec<Coordinate> {
         if count == 0 {
             return Vec::new();
@@ -69,6 +265,111 @@ impl Map {
         coords
     }
 
+    fn continent_coordinates(seed: u64, count: usize) -> Vec<Coordinate> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_mul(53).wrapping_add(29));
+        let continent_count = ((count as f32 / 8.0).ceil() as usize)
+            .clamp(1, 5)
+            .min(count);
+
+        // Best-candidate sampling: every centroid is the candidate furthest away from the
+        // ones already placed, which leaves open ocean between the continents.
+        let mut centroids: Vec<Coordinate> = Vec::with_capacity(continent_count);
+        for _ in 0..continent_count {
+            let mut best = Coordinate::new(5_000.0, 5_000.0);
+            let mut best_score = f32::NEG_INFINITY;
+            for _ in 0..16 {
+                let candidate = Coordinate::new(
+                    rng.gen_range(1_500.0..=8_500.0),
+                    rng.gen_range(1_500.0..=8_500.0),
+                );
+                let score = centroids
+                    .iter()
+                    .map(|c| ((c.x - candidate.x).powi(2) + (c.y - candidate.y).powi(2)).sqrt())
+                    .fold(f32::INFINITY, f32::min);
+                if score > best_score {
+                    best_score = score;
+                    best = candidate;
+                }
+            }
+            centroids.push(best);
+        }
+
+        // Keep continents from bleeding into each other
+        let radii: Vec<f32> = centroids
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let nearest = centroids
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, o)| ((o.x - c.x).powi(2) + (o.y - c.y).powi(2)).sqrt())
+                    .fold(f32::INFINITY, f32::min);
+                rng.gen_range(900.0..=1_400.0_f32)
+                    .min(nearest * 0.3)
+                    .max(400.0)
+            })
+            .collect();
+
+        // every continent gets at least one airport, the rest are spread randomly
+        let mut sizes = vec![1usize; continent_count];
+        for _ in continent_count..count {
+            sizes[rng.gen_range(0..continent_count)] += 1;
+        }
+
+        let mut coords = Vec::with_capacity(count);
+        for ((center, radius), size) in centroids.iter().zip(radii.iter()).zip(sizes) {
+            // Poisson-disk sampling inside the continent disc
+            let mut spacing =
+                (radius * (std::f32::consts::PI / size as f32).sqrt() * 0.6).clamp(60.0, 400.0);
+            let mut placed: Vec<Coordinate> = Vec::with_capacity(size);
+            while placed.len() < size {
+                let mut accepted = false;
+                for _ in 0..30 {
+                    let angle = rng.gen_range(0.0..TAU);
+                    let distance = radius * rng.gen_range(0.0_f32..=1.0_f32).sqrt();
+                    let x = (center.x + distance * angle.cos()).clamp(0.0, 10_000.0);
+                    let y = (center.y + distance * angle.sin()).clamp(0.0, 10_000.0);
+                    if placed
+                        .iter()
+                        .all(|p| ((p.x - x).powi(2) + (p.y - y).powi(2)).sqrt() >= spacing)
+                    {
+                        placed.push(Coordinate::new(x, y));
+                        accepted = true;
+                        break;
+                    }
+                }
+                if !accepted {
+                    spacing *= 0.8;
+                }
+            }
+            coords.extend(placed);
+        }
+
+        coords.shuffle(&mut rng);
+        coords
+    }
+
+    /// Generate coordinates for a given count using the seed and the requested layout.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed.
+    /// - `count`: Number of coordinates.
+    /// - `style`: Layout strategy.
+    ///
+    /// Returns
+    /// - `Vec<Coordinate>`: Deterministic pseudo-random coordinates.
+    pub fn generate_coordinates(seed: u64, count: usize, style: WorldStyle) -> Vec<Coordinate> {
+        match style {
+            WorldStyle::Clustered => Self::clustered_coordinates(seed, count),
+            WorldStyle::Continents => Self::continent_coordinates(seed, count),
+        }
+    }
+
     /// Generate clustered coordinates for a given count using the seed.
     ///
     /// Parameters
@@ -90,9 +391,26 @@ impl Map {
     /// Returns
     /// - `Map`: New map with initial orders stocked.
     pub fn generate_from_seed(seed: u64, num_airports: Option<usize>) -> Self {
+        Self::generate_from_seed_with_style(seed, num_airports, WorldStyle::default())
+    }
+
+    /// Generate airports and orders from a random seed using a specific layout.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed.
+    /// - `num_airports`: Number of airports (default when `None`).
+    /// - `style`: Layout strategy for the airport coordinates.
+    ///
+    /// Returns
+    /// - `Map`: New map with initial orders stocked.
+    pub fn generate_from_seed_with_style(
+        seed: u64,
+        num_airports: Option<usize>,
+        style: WorldStyle,
+    ) -> Self {
         let num_airports = num_airports.unwrap_or(12);
 
-        let coordinates = Self::clustered_coordinates(seed, num_airports);
+        let coordinates = Self::generate_coordinates(seed, num_airports, style);
         let mut airport_list = Vec::with_capacity(num_airports);
 
         for (i, coordinate) in coordinates.into_iter().enumerate() {
@@ -102,10 +420,13 @@ impl Map {
 
         let mut map = Map {
             num_airports,
-            airports: airport_list,
+            airports: Airports::new(airport_list),
             seed,
             next_order_id: 0,
             demand_params: DemandGenerationParams::default(),
+            world_style: style,
+            countries: Vec::new(),
+            lane_market: LaneMarket::default(),
         };
 
         map.restock_airports();
@@ -113,17 +434,31 @@ impl Map {
         map
     }
 
-    /// Restock all airports with new orders using current demand parameters.
-    pub fn restock_airports(&mut self) {
-        let airport_infos: Vec<OrderAirportInfo> = self
-            .airports
+    /// Summaries of every airport used when generating orders and contracts.
+    pub fn order_airport_infos(&self) -> Vec<OrderAirportInfo> {
+        self.airports
             .iter()
             .map(|(airport, coord)| OrderAirportInfo {
                 id: airport.id,
                 runway_length: airport.runway_length,
+                size: airport.size(),
                 coordinate: *coord,
+                country: airport.country,
             })
-            .collect();
+            .collect()
+    }
+
+    /// Restock all airports with new orders using current demand parameters.
+    ///
+    /// Returns
+    /// - `Range<usize>`: Ids of the orders generated.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(level = "debug", skip(self))
+    )]
+    pub fn restock_airports(&mut self) -> Range<usize> {
+        let airport_infos = self.order_airport_infos();
+        let first_order_id = self.next_order_id;
 
         for (airport, _) in self.airports.iter_mut() {
             airport.generate_orders(
@@ -131,8 +466,88 @@ impl Map {
                 &airport_infos,
                 &mut self.next_order_id,
                 &self.demand_params,
+                &self.lane_market,
             );
         }
+
+        #[cfg(feature = "telemetry")]
+        tracing::debug!(
+            airports = self.airports.len(),
+            orders_generated = self.next_order_id - first_order_id,
+            "restocked"
+        );
+        first_order_id..self.next_order_id
+    }
+
+    /// Reserve the next order id not used by any order waiting at an airport.
+    pub fn allocate_order_id(&mut self) -> usize {
+        let in_use = self
+            .airports
+            .iter()
+            .flat_map(|(airport, _)| airport.orders.iter().map(|o| o.id + 1))
+            .max()
+            .unwrap_or(0);
+        let id = self.next_order_id.max(in_use);
+        self.next_order_id = id + 1;
+        id
+    }
+
+    /// Id the next airport added to the map gets: one past the highest id in use.
+    pub fn next_airport_id(&self) -> AirportId {
+        self.airports
+            .iter()
+            .map(|(a, _)| a.id + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Add an airport at `coord` to the end of the map. Unless it already has a country it
+    /// joins the one of the nearest airport.
+    ///
+    /// Parameters
+    /// - `airport`: New airport; its id must not be taken yet, see [`Map::next_airport_id`].
+    /// - `coord`: Where it lies.
+    pub fn add_airport(&mut self, mut airport: Airport, coord: Coordinate) {
+        if airport.country.is_none() {
+            airport.country = self
+                .airports
+                .iter()
+                .min_by(|(_, a), (_, b)| a.distance_to(&coord).total_cmp(&b.distance_to(&coord)))
+                .and_then(|(nearest, _)| nearest.country);
+        }
+        self.airports.push(airport, coord);
+        self.num_airports = self.airports.len();
+    }
+
+    /// Open a generated airport `distance` km from the airport `near`, in the direction
+    /// `bearing` (radians). It takes the next free id and starts without orders; the next
+    /// restock stocks it.
+    ///
+    /// Parameters
+    /// - `near`: Airport the new one grows out of.
+    /// - `distance`: Distance from `near` in km.
+    /// - `bearing`: Direction from `near`, in radians.
+    ///
+    /// Returns
+    /// - `Some(AirportId)` of the new airport.
+    /// - `None` if `near` is unknown or the map uses real-world coordinates.
+    pub fn open_airport(
+        &mut self,
+        near: AirportId,
+        distance: f32,
+        bearing: f32,
+    ) -> Option<AirportId> {
+        let anchor = self.coord(near)?;
+        if !anchor.system.is_planar() {
+            return None;
+        }
+        let coord = Coordinate::new(
+            (anchor.x + distance * bearing.cos()).clamp(0.0, 10_000.0),
+            (anchor.y + distance * bearing.sin()).clamp(0.0, 10_000.0),
+        );
+        let id = self.next_airport_id();
+        self.add_airport(Airport::generate_random(self.seed, id), coord);
+        Some(id)
     }
 
     /// Remove all orders from every airport and reset the order id counter.
@@ -143,27 +558,132 @@ impl Map {
         self.next_order_id = 0;
     }
 
-    /// Find the minimum distance between two airports and the index of one endpoint.
-    pub fn min_distance(&self) -> (f32, usize) {
+    /// Split the airports into `count` countries around well-spread capitals.
+    ///
+    /// Capitals are picked by farthest-point sampling and every airport joins the country of
+    /// its nearest capital. Existing assignments are replaced; `count == 0` removes borders.
+    ///
+    /// Parameters
+    /// - `count`: Number of countries (capped at the number of airports).
+    pub fn assign_countries(&mut self, count: usize) {
+        self.countries.clear();
+        for (airport, _) in self.airports.iter_mut() {
+            airport.country = None;
+        }
+        let count = count.min(self.airports.len());
+        if count == 0 {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_mul(7).wrapping_add(3));
+        let mut capitals = vec![rng.gen_range(0..self.airports.len())];
+        while capitals.len() < count {
+            let next = (0..self.airports.len())
+                .filter(|i| !capitals.contains(i))
+                .max_by(|a, b| {
+                    let da = self.distance_to_nearest(*a, &capitals);
+                    let db = self.distance_to_nearest(*b, &capitals);
+                    da.total_cmp(&db)
+                })
+                .expect("fewer capitals than airports");
+            capitals.push(next);
+        }
+
+        let assignment: Vec<usize> = (0..self.airports.len())
+            .map(|i| {
+                let coord = self.airports.entries[i].1;
+                capitals
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| {
+                        let da = coord.distance_to(&self.airports.entries[**a].1);
+                        let db = coord.distance_to(&self.airports.entries[**b].1);
+                        da.total_cmp(&db)
+                    })
+                    .map(|(country, _)| country)
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        self.countries = (0..count).map(country_name).collect();
+        for ((airport, _), country) in self.airports.iter_mut().zip(assignment) {
+            airport.country = Some(country);
+        }
+    }
+
+    fn distance_to_nearest(&self, idx: usize, others: &[usize]) -> f32 {
+        let coord = self.airports.entries[idx].1;
+        others
+            .iter()
+            .map(|o| coord.distance_to(&self.airports.entries[*o].1))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Index of the country called `name`, registering it if it is new.
+    pub fn country_index(&mut self, name: &str) -> usize {
+        if let Some(idx) = self
+            .countries
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(name))
+        {
+            return idx;
+        }
+        self.countries.push(name.to_string());
+        self.countries.len() - 1
+    }
+
+    /// Name of the country an airport belongs to.
+    pub fn country_of(&self, airport_id: usize) -> Option<&str> {
+        self.airports
+            .iter()
+            .find(|(a, _)| a.id == airport_id)
+            .and_then(|(a, _)| a.country)
+            .and_then(|c| self.countries.get(c))
+            .map(String::as_str)
+    }
+
+    /// Whether travelling between two airports crosses a border.
+    ///
+    /// Airports without a country never trigger border rules.
+    pub fn is_cross_border(&self, from_id: usize, to_id: usize) -> bool {
+        let country = |id: usize| {
+            self.airports
+                .iter()
+                .find(|(a, _)| a.id == id)
+                .and_then(|(a, _)| a.country)
+        };
+        match (country(from_id), country(to_id)) {
+            (Some(a), Some(b)) => a != b,
+            _ => false,
+        }
+    }
+
+    /// Coordinate system used by the airports on this map.
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.airports
+            .first()
+            .map(|(_, coord)| coord.system)
+            .unwrap_or_default()
+    }
+
+    /// Find the minimum distance between two airports and the id of one endpoint.
+    pub fn min_distance(&self) -> (f32, AirportId) {
         let mut min_distance = f32::INFINITY;
-        let mut start_index: usize = 0;
+        let mut start_id = self.airports.first().map_or(0, |(a, _)| a.id);
 
         for (airport1, coord1) in self.airports.iter() {
             for (airport2, coord2) in self.airports.iter() {
                 if airport1.id != airport2.id {
-                    let dx = (coord1.x - coord2.x).abs();
-                    let dy = (coord1.y - coord2.y).abs();
-
-                    let distance = (dx * dx + dy * dy).sqrt();
+                    let distance = coord1.distance_to(coord2);
                     if distance < min_distance {
                         min_distance = distance;
-                        start_index = airport1.id;
+                        start_id = airport1.id;
                     }
                 }
             }
         }
 
-        (min_distance, start_index)
+        (min_distance, start_id)
     }
 
     /// Build a map from explicit airport configs.
@@ -184,10 +704,13 @@ impl Map {
     ) -> Self {
         let mut map = Map {
             num_airports: airports.len(),
-            airports,
+            airports: Airports::new(airports),
             seed,
             next_order_id,
             demand_params,
+            world_style: WorldStyle::default(),
+            countries: Vec::new(),
+            lane_market: LaneMarket::default(),
         };
 
         for (airport, _) in map.airports.iter_mut() {
@@ -196,4 +719,89 @@ impl Map {
 
         map
     }
+
+    /// Build a map from an OurAirports-style CSV dataset.
+    ///
+    /// Positions are projected so distances stay in real kilometers, runway lengths come from
+    /// the dataset (or the airport type) and fees are derived from the runway length. The map
+    /// starts without orders.
+    ///
+    /// Parameters
+    /// - `path`: CSV file to import.
+    /// - `seed`: RNG seed for derived airport properties and future orders.
+    ///
+    /// Returns
+    /// - `Ok(Map)` with one airport per usable dataset row.
+    /// - `Err(GameError)` if the file is unreadable or yields fewer than two airports.
+    pub fn from_airport_csv<P: AsRef<Path>>(path: P, seed: u64) -> Result<Self, GameError> {
+        Self::from_dataset_airports(read_airport_csv(path)?, seed, CoordinateSystem::Planar)
+    }
+
+    /// Build a map from already imported dataset rows.
+    ///
+    /// Parameters
+    /// - `rows`: Imported airports, in the order their ids should be assigned.
+    /// - `seed`: RNG seed for derived airport properties and future orders.
+    /// - `system`: `Planar` projects positions onto the flat map; `Geographic` keeps latitude
+    ///   and longitude so distances are great-circle.
+    ///
+    /// Returns
+    /// - `Ok(Map)` without orders.
+    /// - `Err(GameError)` if fewer than two airports are provided.
+    pub fn from_dataset_airports(
+        rows: Vec<DatasetAirport>,
+        seed: u64,
+        system: CoordinateSystem,
+    ) -> Result<Self, GameError> {
+        if rows.len() < 2 {
+            return Err(GameError::InvalidConfig {
+                msg: format!(
+                    "airport dataset must provide at least 2 airports, found {}",
+                    rows.len()
+                ),
+            });
+        }
+
+        let positions: Vec<(f32, f32)> = rows.iter().map(|r| (r.latitude, r.longitude)).collect();
+        let coords = match system {
+            CoordinateSystem::Planar => project_positions(&positions),
+            CoordinateSystem::Geographic => positions
+                .iter()
+                .map(|(lat, lon)| Coordinate::geographic(*lat, *lon))
+                .collect(),
+        };
+
+        let mut names = HashSet::new();
+        let mut countries: Vec<String> = Vec::new();
+        let airports = rows
+            .into_iter()
+            .zip(coords)
+            .enumerate()
+            .map(|(id, (row, coord))| {
+                let mut name = if row.name.is_empty() {
+                    row.code.clone()
+                } else {
+                    row.name.clone()
+                };
+                if !names.insert(name.to_lowercase()) {
+                    name = format!("{} ({})", name, row.code);
+                    names.insert(name.to_lowercase());
+                }
+                let mut airport = Airport::with_runway(seed, id, name, row.runway_length);
+                airport.code = Some(row.code);
+                if !row.country.is_empty() {
+                    let idx = countries.iter().position(|c| *c == row.country);
+                    airport.country = Some(idx.unwrap_or_else(|| {
+                        countries.push(row.country.clone());
+                        countries.len() - 1
+                    }));
+                }
+                (airport, coord)
+            })
+            .collect();
+
+        let mut map = Map::from_airports(seed, airports, DemandGenerationParams::default(), 0);
+        map.countries = countries;
+        Ok(map)
+    }
 }
@@ -1,6 +1,14 @@
 pub mod airplanes;
 pub mod airport;
+pub mod calendar;
+pub mod concession;
+pub mod construction;
 pub mod coordinate;
+pub mod dataset;
+pub mod demand;
 pub mod errors;
+pub mod loyalty;
 pub mod map;
+pub mod names;
 pub mod orders;
+pub mod pilots;
@@ -0,0 +1,69 @@
+//! Names for generated airports.
+//!
+//! Each generated airport gets a made-up city and a kind that follows its runway, e.g.
+//! "Oakhaven International" or "Millby Airfield", plus a three-letter code (`AAA`, `AAB`,
+//! ...) that stays short enough for tables and maps. Themed worlds can list their own
+//! names in the config instead, see [`WorldConfig::airport_names`].
+//!
+//! [`WorldConfig::airport_names`]: crate::config::WorldConfig::airport_names
+
+const CITY_STARTS: [&str; 40] = [
+    "Ash", "Bel", "Black", "Bright", "Cedar", "Clear", "Cold", "Crest", "Deer", "East", "Elm",
+    "Fair", "Fox", "Glen", "Gold", "Green", "Hart", "High", "Iron", "Lake", "Long", "Maple",
+    "Mill", "North", "Oak", "Pine", "Red", "River", "Rock", "Silver", "South", "Spring", "Stone",
+    "Sun", "West", "White", "Wild", "Willow", "Wolf", "York",
+];
+
+const CITY_ENDS: [&str; 25] = [
+    "bridge", "brook", "bury", "by", "dale", "field", "ford", "gate", "haven", "hill", "holm",
+    "hurst", "ley", "mere", "mont", "mouth", "port", "ridge", "stead", "ton", "vale", "ville",
+    "wick", "wood", "worth",
+];
+
+/// Distinct city names before they repeat with a number.
+pub const CITY_COUNT: usize = CITY_STARTS.len() * CITY_ENDS.len();
+
+/// Step through the city list; coprime to [`CITY_COUNT`], so the first `CITY_COUNT` ids
+/// all get different cities.
+const CITY_STRIDE: usize = 617;
+
+/// Three-letter code of the airport with `id`: `AAA`, `AAB`, ... `ZZZ`, then around again.
+pub fn airport_code(mut id: usize) -> String {
+    let mut bytes = [b'A'; 3];
+    for byte in bytes.iter_mut().rev() {
+        *byte = b'A' + (id % 26) as u8;
+        id /= 26;
+    }
+    String::from_utf8(bytes.to_vec()).expect("ASCII letters")
+}
+
+/// City of the airport with `id` in the world generated from `seed`. Different for every
+/// id below [`CITY_COUNT`]; later ones repeat a city with a number, e.g. "Oakhaven 2".
+pub fn city_name(seed: u64, id: usize) -> String {
+    let offset = (seed % CITY_COUNT as u64) as usize;
+    let index = (id % CITY_COUNT * CITY_STRIDE + offset) % CITY_COUNT;
+    let city = format!(
+        "{}{}",
+        CITY_STARTS[index / CITY_ENDS.len()],
+        CITY_ENDS[index % CITY_ENDS.len()]
+    );
+    match id / CITY_COUNT {
+        0 => city,
+        round => format!("{} {}", city, round + 1),
+    }
+}
+
+/// What an airport with a runway of `runway_length` meters is called.
+pub fn airport_kind(runway_length: f32) -> &'static str {
+    match runway_length {
+        3_000.0.. => "International",
+        1_500.0.. => "Regional",
+        800.0.. => "Airport",
+        _ => "Airfield",
+    }
+}
+
+/// Full name of a generated airport, e.g. "Oakhaven International".
+pub fn airport_name(seed: u64, id: usize, runway_length: f32) -> String {
+    format!("{} {}", city_name(seed, id), airport_kind(runway_length))
+}
@@ -0,0 +1,139 @@
+use super::cargo::CargoType;
+use super::order::{OrderAirportInfo, base_rate_per_kg};
+use crate::config::AuctionsGameplay;
+use crate::events::GameTime;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// A spot-charter auction: an exceptional load that goes to whichever carrier asks the
+/// lowest price for flying it.
+///
+/// Competitor bids are drawn when the auction opens and stay hidden; the player's bid
+/// can be changed until bidding closes. The winner is paid its own bid on delivery.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Auction {
+    pub id: usize,
+    pub cargo_type: CargoType,
+    pub origin_id: usize,
+    pub destination_id: usize,
+    /// Weight of the load in kg
+    pub weight: f32,
+    /// Hours the winner has to deliver the load
+    pub deadline: GameTime,
+    /// Going rate for the load, from distance and urgency
+    pub reference_value: f32,
+    /// Game time bidding closes
+    pub closes_at: GameTime,
+    /// Price the player asks, if they bid
+    pub player_bid: Option<f32>,
+    /// Prices the other carriers ask
+    pub competitor_bids: Vec<f32>,
+}
+
+impl Auction {
+    /// Draw a random load between two distinct airports and the bids of its competitors.
+    ///
+    /// The going rate is the regular cargo rate for the distance, raised by
+    /// `urgency_premium × 24 / deadline`. Each competitor asks the going rate give or take
+    /// up to `bid_spread` of it.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed for this auction.
+    /// - `id`: Auction id.
+    /// - `airports`: Airports to pick the origin and destination from.
+    /// - `params`: Auction tuning.
+    /// - `now`: Current game time; bidding closes `bidding_hours` later.
+    ///
+    /// Returns
+    /// - `Some(Auction)` when there are at least two airports.
+    /// - `None` otherwise.
+    pub fn generate(
+        seed: u64,
+        id: usize,
+        airports: &[OrderAirportInfo],
+        params: &AuctionsGameplay,
+        now: GameTime,
+    ) -> Option<Auction> {
+        if airports.len() < 2 {
+            return None;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let origin = &airports[rng.gen_range(0..airports.len())];
+        let destination = loop {
+            let candidate = &airports[rng.gen_range(0..airports.len())];
+            if candidate.id != origin.id {
+                break candidate;
+            }
+        };
+
+        let cargo_count = CargoType::iter().count();
+        let cargo_type = CargoType::iter()
+            .nth(rng.gen_range(0..cargo_count))
+            .unwrap();
+        let weight = if params.max_weight > params.min_weight {
+            rng.gen_range(params.min_weight..=params.max_weight)
+        } else {
+            params.min_weight
+        };
+        // Round to whole 100 kg lots
+        let weight = ((weight / 100.0).round() * 100.0).max(100.0);
+        let deadline = rng.gen_range(params.min_deadline_hours..=params.max_deadline_hours);
+
+        let distance = origin.coordinate.distance_to(&destination.coordinate);
+        let urgency = 1.0 + params.urgency_premium * 24.0 / deadline as f32;
+        let reference_value = (base_rate_per_kg(distance, cargo_type) * weight * urgency).round();
+        let competitor_bids = (0..params.competitors)
+            .map(|_| {
+                let swing = if params.bid_spread > 0.0 {
+                    rng.gen_range(-params.bid_spread..=params.bid_spread)
+                } else {
+                    0.0
+                };
+                (reference_value * (1.0 + swing)).round()
+            })
+            .collect();
+
+        Some(Auction {
+            id,
+            cargo_type,
+            origin_id: origin.id,
+            destination_id: destination.id,
+            weight,
+            deadline,
+            reference_value,
+            closes_at: now + params.bidding_hours,
+            player_bid: None,
+            competitor_bids,
+        })
+    }
+
+    /// Lowest price asked by another carrier.
+    pub fn best_competitor_bid(&self) -> f32 {
+        self.competitor_bids
+            .iter()
+            .copied()
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Return `true` if the player bid and undercut every competitor; ties go to the
+    /// competitor.
+    pub fn player_wins(&self) -> bool {
+        self.player_bid
+            .is_some_and(|bid| bid < self.best_competitor_bid())
+    }
+
+    /// Price the load goes for: the player's bid if they win, else the best competitor's.
+    pub fn winning_bid(&self) -> f32 {
+        match self.player_bid {
+            Some(bid) if self.player_wins() => bid,
+            _ => self.best_competitor_bid(),
+        }
+    }
+
+    /// Return `true` while bids are accepted at time `now`.
+    pub fn is_open(&self, now: GameTime) -> bool {
+        now < self.closes_at
+    }
+}
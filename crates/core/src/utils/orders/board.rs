@@ -0,0 +1,34 @@
+use super::order::Order;
+use crate::events::GameTime;
+use serde::{Deserialize, Serialize};
+
+/// Criteria for picking orders off the world-wide order board.
+///
+/// Criteria left as `None` match every order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderBoardFilter {
+    /// Only orders bound for airports in this country (case-insensitive)
+    pub destination_country: Option<String>,
+    /// Only orders paying at least this much per kg of payload
+    pub min_value_per_kg: Option<f32>,
+    /// Only orders due within this many hours
+    pub max_deadline: Option<GameTime>,
+}
+
+impl OrderBoardFilter {
+    /// Whether `order` passes the filter.
+    ///
+    /// Parameters
+    /// - `order`: Order to check.
+    /// - `destination_country`: Country of the order's destination airport, if it has one.
+    pub fn matches(&self, order: &Order, destination_country: Option<&str>) -> bool {
+        let country_ok = self.destination_country.as_ref().is_none_or(|wanted| {
+            destination_country.is_some_and(|c| c.eq_ignore_ascii_case(wanted))
+        });
+        let value_ok = self
+            .min_value_per_kg
+            .is_none_or(|min| order.value_per_kg() >= min);
+        let deadline_ok = self.max_deadline.is_none_or(|max| order.deadline <= max);
+        country_ok && value_ok && deadline_ok
+    }
+}
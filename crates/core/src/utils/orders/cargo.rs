@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, EnumIter, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, EnumIter, PartialEq)]
 pub enum CargoType {
     Electronics,
     Furniture,
@@ -47,4 +48,17 @@ impl CargoType {
             CargoType::Machines | CargoType::HauntedMirrors => (20.00, 100.00),
         }
     }
+
+    /// Whether this cargo is damaged by hard landings and poorly maintained planes.
+    pub fn is_fragile(&self) -> bool {
+        matches!(
+            self,
+            CargoType::Electronics
+                | CargoType::Pharmaceuticals
+                | CargoType::HauntedMirrors
+                | CargoType::DiscoBalls
+                | CargoType::TimeMachines
+                | CargoType::QuantumWidgets
+        )
+    }
 }
@@ -0,0 +1,106 @@
+use super::order::OrderAirportInfo;
+use crate::config::ChartersGameplay;
+use crate::events::GameTime;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+/// A charter taken on by a plane: the group is aboard and must arrive by `due_at`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CharterBooking {
+    pub plane: usize,
+    pub due_at: GameTime,
+}
+
+/// A one-off request to fly a group of passengers between two airports.
+///
+/// The request sits on offer at its origin until `offer_expires_at`. Once accepted
+/// the whole group boards one plane and has `window_hours` to reach the destination;
+/// the `payout` is only made if it arrives in time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Charter {
+    pub id: usize,
+    pub origin_id: usize,
+    pub destination_id: usize,
+    /// Size of the group; it only travels together
+    pub passengers: u32,
+    /// Amount paid on an on-time arrival
+    pub payout: f32,
+    /// Game time after which an unaccepted request lapses
+    pub offer_expires_at: GameTime,
+    /// Hours from acceptance until the group must have arrived
+    pub window_hours: GameTime,
+    /// Plane carrying the group, once accepted
+    pub booking: Option<CharterBooking>,
+}
+
+impl Charter {
+    /// Draw a random charter between two distinct airports.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed for this charter.
+    /// - `id`: Charter id.
+    /// - `airports`: Airports to pick the origin and destination from.
+    /// - `params`: Charter tuning.
+    /// - `fare_per_km`: Regular passenger fare per km, scaled by `params.fare_multiplier`.
+    /// - `now`: Current game time; the offer lapses `offer_hours` later.
+    ///
+    /// Returns
+    /// - `Some(Charter)` when there are at least two airports.
+    /// - `None` otherwise.
+    pub fn generate(
+        seed: u64,
+        id: usize,
+        airports: &[OrderAirportInfo],
+        params: &ChartersGameplay,
+        fare_per_km: f32,
+        now: GameTime,
+    ) -> Option<Charter> {
+        if airports.len() < 2 {
+            return None;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let origin = &airports[rng.gen_range(0..airports.len())];
+        let destination = loop {
+            let candidate = &airports[rng.gen_range(0..airports.len())];
+            if candidate.id != origin.id {
+                break candidate;
+            }
+        };
+
+        let passengers = if params.max_passengers > params.min_passengers {
+            rng.gen_range(params.min_passengers..=params.max_passengers)
+        } else {
+            params.min_passengers
+        };
+
+        let distance = origin.coordinate.distance_to(&destination.coordinate);
+        let payout = (distance * passengers as f32 * fare_per_km * params.fare_multiplier).round();
+
+        Some(Charter {
+            id,
+            origin_id: origin.id,
+            destination_id: destination.id,
+            passengers,
+            payout,
+            offer_expires_at: now + params.offer_hours,
+            window_hours: params.window_hours,
+            booking: None,
+        })
+    }
+
+    /// Return `true` if the request is still waiting for a plane at time `now`.
+    pub fn is_open(&self, now: GameTime) -> bool {
+        self.booking.is_none() && now <= self.offer_expires_at
+    }
+
+    /// Return `true` if the request lapsed without being accepted.
+    pub fn is_lapsed(&self, now: GameTime) -> bool {
+        self.booking.is_none() && now > self.offer_expires_at
+    }
+
+    /// Return `true` if the group was accepted but has missed its arrival time.
+    pub fn is_overdue(&self, now: GameTime) -> bool {
+        self.booking.is_some_and(|b| now > b.due_at)
+    }
+}
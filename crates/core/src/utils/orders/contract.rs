@@ -0,0 +1,119 @@
+use super::cargo::CargoType;
+use super::order::{OrderAirportInfo, base_rate_per_kg};
+use crate::config::ContractsGameplay;
+use crate::events::GameTime;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// A bulk contract: a large amount of one cargo type hauled between two airports
+/// over many flights before a fixed time.
+///
+/// Every delivery is paid at `rate_per_kg`; the `completion_bonus` is paid once the
+/// full weight has arrived.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Contract {
+    pub id: usize,
+    pub cargo_type: CargoType,
+    pub origin_id: usize,
+    pub destination_id: usize,
+    /// Weight in kg the contract asks for
+    pub total_weight: f32,
+    /// Weight in kg delivered so far
+    pub delivered_weight: f32,
+    /// Amount paid per kg on each delivery
+    pub rate_per_kg: f32,
+    /// Amount paid once the full weight has been delivered
+    pub completion_bonus: f32,
+    /// Game time after which deliveries no longer count
+    pub expires_at: GameTime,
+}
+
+impl Contract {
+    /// Draw a random contract between two distinct airports.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed for this contract.
+    /// - `id`: Contract id.
+    /// - `airports`: Airports to pick the origin and destination from.
+    /// - `params`: Contract tuning.
+    /// - `now`: Current game time; the contract expires `duration_hours` later.
+    ///
+    /// Returns
+    /// - `Some(Contract)` when there are at least two airports.
+    /// - `None` otherwise.
+    pub fn generate(
+        seed: u64,
+        id: usize,
+        airports: &[OrderAirportInfo],
+        params: &ContractsGameplay,
+        now: GameTime,
+    ) -> Option<Contract> {
+        if airports.len() < 2 {
+            return None;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let origin = &airports[rng.gen_range(0..airports.len())];
+        let destination = loop {
+            let candidate = &airports[rng.gen_range(0..airports.len())];
+            if candidate.id != origin.id {
+                break candidate;
+            }
+        };
+
+        let cargo_count = CargoType::iter().count();
+        let cargo_type = CargoType::iter()
+            .nth(rng.gen_range(0..cargo_count))
+            .unwrap();
+
+        let total_weight = if params.max_weight > params.min_weight {
+            rng.gen_range(params.min_weight..=params.max_weight)
+        } else {
+            params.min_weight
+        };
+        // Round to whole 100 kg lots
+        let total_weight = ((total_weight / 100.0).round() * 100.0).max(100.0);
+
+        let distance = origin.coordinate.distance_to(&destination.coordinate);
+        let rate_per_kg = base_rate_per_kg(distance, cargo_type) * params.rate_multiplier;
+        let completion_bonus = (rate_per_kg * total_weight * params.completion_bonus).round();
+
+        Some(Contract {
+            id,
+            cargo_type,
+            origin_id: origin.id,
+            destination_id: destination.id,
+            total_weight,
+            delivered_weight: 0.0,
+            rate_per_kg,
+            completion_bonus,
+            expires_at: now + params.duration_hours,
+        })
+    }
+
+    /// Weight in kg still to be delivered.
+    pub fn remaining_weight(&self) -> f32 {
+        (self.total_weight - self.delivered_weight).max(0.0)
+    }
+
+    /// Return `true` once the full weight has been delivered.
+    pub fn is_complete(&self) -> bool {
+        self.remaining_weight() <= f32::EPSILON
+    }
+
+    /// Return `true` if deliveries at time `now` no longer count.
+    pub fn is_expired(&self, now: GameTime) -> bool {
+        now > self.expires_at
+    }
+
+    /// Record a delivery, capped at the contract's total weight.
+    ///
+    /// Returns
+    /// - `bool`: Whether this delivery completed the contract.
+    pub fn record_delivery(&mut self, weight: f32) -> bool {
+        let was_complete = self.is_complete();
+        self.delivered_weight = (self.delivered_weight + weight).min(self.total_weight);
+        !was_complete && self.is_complete()
+    }
+}
@@ -0,0 +1,173 @@
+use super::cargo::CargoType;
+use super::order::{OrderAirportInfo, base_rate_per_kg};
+use crate::config::ForwardersGameplay;
+use crate::events::GameTime;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// Hours in the week a lane's loads are spread over.
+pub const HOURS_PER_WEEK: GameTime = 168;
+
+const FORWARDER_NAMES: [&str; 6] = [
+    "Swift Freight",
+    "Blue Harbor Logistics",
+    "Northwind Cargo",
+    "Pelican Forwarding",
+    "Meridian Brokers",
+    "Lodestar Shipping",
+];
+
+/// A freight forwarder: a company that offers the player regular cargo lanes out of
+/// its hub.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Forwarder {
+    pub id: usize,
+    pub name: String,
+    /// Airport every lane of this forwarder starts at
+    pub hub_id: usize,
+    /// Lanes the player has accepted from this forwarder
+    pub lanes_accepted: u32,
+    /// Offers the player turned down
+    pub offers_declined: u32,
+}
+
+impl Forwarder {
+    /// Set up `count` forwarders at hubs drawn from `airports`.
+    ///
+    /// Parameters
+    /// - `seed`: World seed.
+    /// - `count`: Number of forwarders.
+    /// - `airports`: Airports to place the hubs at.
+    pub fn generate_all(seed: u64, count: usize, airports: &[OrderAirportInfo]) -> Vec<Forwarder> {
+        if airports.len() < 2 {
+            return Vec::new();
+        }
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(71));
+        (0..count)
+            .map(|id| Forwarder {
+                id,
+                name: FORWARDER_NAMES[id % FORWARDER_NAMES.len()].to_string(),
+                hub_id: airports[rng.gen_range(0..airports.len())].id,
+                lanes_accepted: 0,
+                offers_declined: 0,
+            })
+            .collect()
+    }
+}
+
+/// A lane deal: a fixed number of loads a week between two airports at a fixed rate.
+///
+/// The deal sits on offer until `offer_expires_at`. Once accepted, a load appears at
+/// the origin every `168 / loads_per_week` hours for `weeks` weeks, whether or not a
+/// plane is there to take it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaneDeal {
+    pub id: usize,
+    pub forwarder: usize,
+    pub cargo_type: CargoType,
+    pub origin_id: usize,
+    pub destination_id: usize,
+    pub loads_per_week: u32,
+    /// Weight in kg of every load
+    pub load_weight: f32,
+    /// Amount paid per kg delivered
+    pub rate_per_kg: f32,
+    /// Weeks the lane runs once accepted
+    pub weeks: u32,
+    /// Game time after which an unaccepted offer lapses
+    pub offer_expires_at: GameTime,
+    /// Game time the player accepted the deal
+    pub accepted_at: Option<GameTime>,
+    /// Loads handed over so far
+    pub loads_issued: u32,
+}
+
+impl LaneDeal {
+    /// Draw a lane from a forwarder's hub to another airport.
+    ///
+    /// Parameters
+    /// - `seed`: RNG seed for this deal.
+    /// - `id`: Deal id.
+    /// - `forwarder`: Forwarder making the offer.
+    /// - `airports`: Airports to pick the destination from.
+    /// - `params`: Forwarder tuning.
+    /// - `now`: Current game time; the offer lapses `offer_hours` later.
+    ///
+    /// Returns
+    /// - `Some(LaneDeal)` when the hub is on the map and there is another airport.
+    /// - `None` otherwise.
+    pub fn generate(
+        seed: u64,
+        id: usize,
+        forwarder: &Forwarder,
+        airports: &[OrderAirportInfo],
+        params: &ForwardersGameplay,
+        now: GameTime,
+    ) -> Option<LaneDeal> {
+        let origin = airports.iter().find(|a| a.id == forwarder.hub_id)?;
+        let destinations: Vec<&OrderAirportInfo> =
+            airports.iter().filter(|a| a.id != origin.id).collect();
+        if destinations.is_empty() {
+            return None;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let destination = destinations[rng.gen_range(0..destinations.len())];
+
+        let cargo_count = CargoType::iter().count();
+        let cargo_type = CargoType::iter()
+            .nth(rng.gen_range(0..cargo_count))
+            .unwrap();
+        let loads_per_week = rng.gen_range(params.min_loads_per_week..=params.max_loads_per_week);
+        let load_weight = if params.max_load_weight > params.min_load_weight {
+            rng.gen_range(params.min_load_weight..=params.max_load_weight)
+        } else {
+            params.min_load_weight
+        };
+        // Round to whole 50 kg lots
+        let load_weight = ((load_weight / 50.0).round() * 50.0).max(50.0);
+
+        let distance = origin.coordinate.distance_to(&destination.coordinate);
+        let rate_per_kg = base_rate_per_kg(distance, cargo_type) * params.rate_multiplier;
+
+        Some(LaneDeal {
+            id,
+            forwarder: forwarder.id,
+            cargo_type,
+            origin_id: origin.id,
+            destination_id: destination.id,
+            loads_per_week,
+            load_weight,
+            rate_per_kg,
+            weeks: params.weeks,
+            offer_expires_at: now + params.offer_hours,
+            accepted_at: None,
+            loads_issued: 0,
+        })
+    }
+
+    /// Hours between two loads.
+    pub fn load_interval(&self) -> GameTime {
+        (HOURS_PER_WEEK / self.loads_per_week.max(1) as GameTime).max(1)
+    }
+
+    /// Loads the lane hands over in total.
+    pub fn total_loads(&self) -> u32 {
+        self.loads_per_week * self.weeks
+    }
+
+    /// Amount paid for delivering one load.
+    pub fn load_value(&self) -> f32 {
+        (self.rate_per_kg * self.load_weight).round()
+    }
+
+    /// Return `true` while the deal waits for an answer at time `now`.
+    pub fn is_offered(&self, now: GameTime) -> bool {
+        self.accepted_at.is_none() && now <= self.offer_expires_at
+    }
+
+    /// Return `true` once the deal is accepted and every load has been handed over.
+    pub fn is_finished(&self) -> bool {
+        self.accepted_at.is_some() && self.loads_issued >= self.total_loads()
+    }
+}
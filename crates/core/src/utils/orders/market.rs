@@ -0,0 +1,105 @@
+use crate::config::MarketGameplay;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Tonnes the player recently delivered on one lane.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaneVolume {
+    pub origin_id: usize,
+    pub destination_id: usize,
+    pub tons: f32,
+}
+
+/// What the player has been doing to the cargo market.
+///
+/// Delivered tonnage is kept per lane and fades by `memory` at every restock. Every
+/// restock an airport goes without a pickup adds to its backlog; a pickup clears it.
+/// Newly generated cargo orders are priced with [`LaneMarket::price_factor`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LaneMarket {
+    lanes: Vec<LaneVolume>,
+    /// Restocks in a row each airport went without a pickup
+    backlogs: BTreeMap<usize, u32>,
+    /// Airports the player loaded cargo at since the last restock
+    picked_up: BTreeSet<usize>,
+}
+
+impl LaneMarket {
+    /// Count cargo delivered from `origin_id` to `destination_id`.
+    ///
+    /// Parameters
+    /// - `origin_id`: Airport the cargo was picked up at.
+    /// - `destination_id`: Airport it was delivered to.
+    /// - `weight`: Weight in kg.
+    pub fn record_delivery(&mut self, origin_id: usize, destination_id: usize, weight: f32) {
+        let tons = weight / 1_000.0;
+        match self
+            .lanes
+            .iter_mut()
+            .find(|l| l.origin_id == origin_id && l.destination_id == destination_id)
+        {
+            Some(lane) => lane.tons += tons,
+            None => self.lanes.push(LaneVolume {
+                origin_id,
+                destination_id,
+                tons,
+            }),
+        }
+    }
+
+    /// Note that the player picked up an order at `airport_id`.
+    pub fn record_pickup(&mut self, airport_id: usize) {
+        self.picked_up.insert(airport_id);
+        self.backlogs.remove(&airport_id);
+    }
+
+    /// Close a restock cycle: fade lane volumes and grow the backlog of every airport
+    /// the player did not pick up from.
+    ///
+    /// Parameters
+    /// - `airports`: Every airport on the map.
+    /// - `memory`: Share of each lane's tonnage kept.
+    pub fn end_cycle(&mut self, airports: impl IntoIterator<Item = usize>, memory: f32) {
+        for lane in self.lanes.iter_mut() {
+            lane.tons *= memory;
+        }
+        self.lanes.retain(|l| l.tons >= 0.01);
+        for id in airports {
+            if !self.picked_up.contains(&id) {
+                *self.backlogs.entry(id).or_insert(0) += 1;
+            }
+        }
+        self.picked_up.clear();
+    }
+
+    /// Recently delivered tonnes on a lane.
+    pub fn tons(&self, origin_id: usize, destination_id: usize) -> f32 {
+        self.lanes
+            .iter()
+            .find(|l| l.origin_id == origin_id && l.destination_id == destination_id)
+            .map_or(0.0, |l| l.tons)
+    }
+
+    /// Restocks in a row `airport_id` went without a pickup.
+    pub fn backlog(&self, airport_id: usize) -> u32 {
+        self.backlogs.get(&airport_id).copied().unwrap_or(0)
+    }
+
+    /// Multiplier on the value of a cargo order from `origin_id` to `destination_id`.
+    ///
+    /// The lane's rate falls by `max_discount × tons / (tons + saturation_tons)` and the
+    /// origin's backlog adds `backlog × backlog_premium`, at most `max_backlog_premium`.
+    pub fn price_factor(
+        &self,
+        origin_id: usize,
+        destination_id: usize,
+        params: &MarketGameplay,
+    ) -> f32 {
+        let tons = self.tons(origin_id, destination_id);
+        let saturation = tons / (tons + params.saturation_tons.max(f32::EPSILON));
+        let discount = 1.0 - params.max_discount * saturation;
+        let premium = (self.backlog(origin_id) as f32 * params.backlog_premium)
+            .min(params.max_backlog_premium);
+        discount * (1.0 + premium)
+    }
+}
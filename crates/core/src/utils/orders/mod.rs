@@ -1,5 +1,21 @@
+pub mod auction;
+pub mod board;
 pub mod cargo;
+pub mod charter;
+pub mod contract;
+pub mod forwarder;
+pub mod market;
 pub mod order;
+pub mod rare;
 
+pub use auction::Auction;
+pub use board::OrderBoardFilter;
 pub use cargo::CargoType;
-pub use order::{DemandGenerationParams, Order, OrderGenerationParams, PassengerGenerationParams};
+pub use charter::{Charter, CharterBooking};
+pub use contract::Contract;
+pub use forwarder::{Forwarder, LaneDeal};
+pub use market::LaneMarket;
+pub use order::{
+    DemandGenerationParams, Order, OrderGenerationParams, OrderTier, PassengerGenerationParams,
+};
+pub use rare::RareCargo;
@@ -1,11 +1,18 @@
 use super::cargo::CargoType;
+use super::market::LaneMarket;
+use super::rare::RareCargo;
+use crate::config::MarketGameplay;
+use crate::utils::airplanes::models::PASSENGER_WEIGHT_KG;
 use crate::{events::GameTime, utils::coordinate::Coordinate};
 use rand::{
     Rng, SeedableRng,
     distributions::{Distribution, WeightedIndex},
     rngs::StdRng,
+    seq::SliceRandom,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use strum::IntoEnumIterator;
 
 // ---- Cargo defaults ----
@@ -18,6 +25,8 @@ const VALUE_CAP: f32 = 8_000_000.0;
 const MIN_VALUE: f32 = 400.0;
 const BASE_TON_KM_RATE: f32 = 160.0;
 const REFERENCE_SPEED_KMH: f32 = 520.0;
+/// Share of its weight a pallet counts for in ground handling, since it moves as one unit
+pub const PALLET_HANDLING_SHARE: f32 = 0.6;
 
 // ---- Passenger defaults ----
 pub const DEFAULT_PASSENGER_MAX_DEADLINE_HOURS: u64 = 48;
@@ -29,12 +38,60 @@ pub const DEFAULT_FARE_PER_KM: f32 = 9.5;
 const PASSENGER_VALUE_CAP: f32 = 2_500_000.0;
 const PASSENGER_MIN_VALUE: f32 = 150.0;
 
+// ---- Tier defaults ----
+pub const DEFAULT_EXPRESS_SHARE: f32 = 0.1;
+pub const DEFAULT_EXPRESS_MULTIPLIER: f32 = 1.8;
+pub const DEFAULT_ECONOMY_SHARE: f32 = 0.15;
+pub const DEFAULT_ECONOMY_MULTIPLIER: f32 = 0.6;
+const EXPRESS_DEADLINE_FACTOR: f32 = 0.5;
+const ECONOMY_DEADLINE_FACTOR: f32 = 1.75;
+
+// ---- Fragile cargo ----
+/// Landing on a runway shorter than this multiple of the plane's requirement is a hard landing.
+pub const HARD_LANDING_RUNWAY_MARGIN: f32 = 1.2;
+/// Damage added to fragile cargo by a hard landing
+pub const HARD_LANDING_DAMAGE: f32 = 0.15;
+/// Damage added to fragile cargo per flight in a plane that needs maintenance
+pub const POOR_MAINTENANCE_DAMAGE: f32 = 0.1;
+/// Damage added to fragile cargo when an emergency landing goes badly
+pub const EMERGENCY_LANDING_DAMAGE: f32 = 0.2;
+
+// ---- Split shipments ----
+/// Share of an order's value held back until every part of a split shipment is delivered
+pub const SPLIT_COMPLETION_SHARE: f32 = 0.1;
+
+/// Service level of an order: how urgent it is and how much it pays.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderTier {
+    /// Relaxed deadline, discounted value
+    Economy,
+    #[default]
+    Standard,
+    /// Tight deadline, premium value
+    Express,
+}
+
+impl fmt::Display for OrderTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderTier::Economy => write!(f, "economy"),
+            OrderTier::Standard => write!(f, "standard"),
+            OrderTier::Express => write!(f, "express"),
+        }
+    }
+}
+
 /// Lightweight description of an airport useful for demand generation heuristics.
 #[derive(Clone, Copy, Debug)]
 pub struct OrderAirportInfo {
     pub id: usize,
     pub runway_length: f32,
+    /// Population-based size, see [`Airport::size`](crate::utils::airport::Airport::size)
+    pub size: f32,
     pub coordinate: Coordinate,
+    /// Index into the map's country list, if the world has borders
+    pub country: Option<usize>,
 }
 
 /// Parameters controlling random cargo order generation.
@@ -45,6 +102,40 @@ pub struct OrderGenerationParams {
     pub max_weight: f32,
     pub alpha: f32,
     pub beta: f32,
+    /// Cargo types that may not cross a border
+    #[serde(default)]
+    pub restricted_cargo: Vec<CargoType>,
+    /// How rates react to recent deliveries and neglected airports
+    #[serde(default = "MarketGameplay::none")]
+    pub market: MarketGameplay,
+    /// Share of orders generated as express
+    #[serde(default = "default_express_share")]
+    pub express_share: f32,
+    /// Value multiplier for express orders
+    #[serde(default = "default_express_multiplier")]
+    pub express_multiplier: f32,
+    /// Share of orders generated as economy
+    #[serde(default = "default_economy_share")]
+    pub economy_share: f32,
+    /// Value multiplier for economy orders
+    #[serde(default = "default_economy_multiplier")]
+    pub economy_multiplier: f32,
+}
+
+fn default_express_share() -> f32 {
+    DEFAULT_EXPRESS_SHARE
+}
+
+fn default_express_multiplier() -> f32 {
+    DEFAULT_EXPRESS_MULTIPLIER
+}
+
+fn default_economy_share() -> f32 {
+    DEFAULT_ECONOMY_SHARE
+}
+
+fn default_economy_multiplier() -> f32 {
+    DEFAULT_ECONOMY_MULTIPLIER
 }
 
 impl Default for OrderGenerationParams {
@@ -55,6 +146,12 @@ impl Default for OrderGenerationParams {
             max_weight: DEFAULT_MAX_WEIGHT,
             alpha: DEFAULT_ALPHA,
             beta: DEFAULT_BETA,
+            restricted_cargo: Vec::new(),
+            market: MarketGameplay::default(),
+            express_share: DEFAULT_EXPRESS_SHARE,
+            express_multiplier: DEFAULT_EXPRESS_MULTIPLIER,
+            economy_share: DEFAULT_ECONOMY_SHARE,
+            economy_multiplier: DEFAULT_ECONOMY_MULTIPLIER,
         }
     }
 }
@@ -160,9 +257,7 @@ fn controlling_class(origin: RunwayClass, dest: RunwayClass) -> RunwayClass {
 }
 
 fn chord_distance(a: Coordinate, b: Coordinate) -> f32 {
-    let dx = a.x - b.x;
-    let dy = a.y - b.y;
-    ((dx * dx) + (dy * dy)).sqrt().max(1.0)
+    a.distance_to(&b).max(1.0)
 }
 
 fn choose_destination(
@@ -283,6 +378,13 @@ fn compute_deadline(
     (deadline, travel_hours, buffer)
 }
 
+/// Base price per kg for hauling `cargo_type` over `distance_km`, before urgency.
+pub fn base_rate_per_kg(distance_km: f32, cargo_type: CargoType) -> f32 {
+    let (min_price, max_price) = cargo_type.price_range();
+    let cargo_multiplier = ((min_price + max_price) / 2.0 / 10.0).clamp(0.5, 5.0);
+    distance_km.max(1.0) / 1_000.0 * BASE_TON_KM_RATE * cargo_multiplier
+}
+
 fn compute_cargo_value(
     weight: f32,
     distance_km: f32,
@@ -329,6 +431,39 @@ fn compute_passenger_value(
         .round()
 }
 
+/// Roll the tier of a new order and adjust its deadline and value.
+///
+/// Express orders keep at least the bare travel time (rounded up) so they stay
+/// deliverable at the reference speed; economy deadlines still respect
+/// `max_deadline_hours`.
+fn apply_tier(
+    rng: &mut StdRng,
+    deadline: u64,
+    value: f32,
+    travel_hours: f32,
+    params: &OrderGenerationParams,
+) -> (OrderTier, u64, f32) {
+    let roll = rng.gen_range(0.0_f32..1.0_f32);
+    if roll < params.express_share {
+        let min_deadline = (travel_hours.ceil() as u64).max(1);
+        let tight = ((deadline as f32) * EXPRESS_DEADLINE_FACTOR).ceil() as u64;
+        (
+            OrderTier::Express,
+            tight.max(min_deadline).min(deadline),
+            (value * params.express_multiplier).round(),
+        )
+    } else if roll < params.express_share + params.economy_share {
+        let relaxed = ((deadline as f32) * ECONOMY_DEADLINE_FACTOR).ceil() as u64;
+        (
+            OrderTier::Economy,
+            relaxed.min(params.max_deadline_hours).max(deadline),
+            (value * params.economy_multiplier).round(),
+        )
+    } else {
+        (OrderTier::Standard, deadline, value)
+    }
+}
+
 /// The payload stored within an [`Order`].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "payload_kind", rename_all = "snake_case")]
@@ -382,6 +517,38 @@ pub struct Order {
     pub deadline: GameTime,
     pub origin_id: usize,
     pub destination_id: usize,
+    /// Service tier (economy, standard or express)
+    #[serde(default)]
+    pub tier: OrderTier,
+    /// Share of the value lost to damage in transit (0.0-1.0)
+    #[serde(default)]
+    pub damage: f32,
+    /// Id of the original order when this is one part of a split shipment
+    #[serde(default)]
+    pub shipment: Option<usize>,
+    /// Id of the bulk contract this cargo counts towards
+    #[serde(default)]
+    pub contract: Option<usize>,
+    /// Spare-part kits in a self-addressed parts shipment; `0` for paid cargo
+    #[serde(default)]
+    pub spare_parts: u32,
+    /// Kind of rare load, which only suitable planes may carry
+    #[serde(default)]
+    pub rare: Option<RareCargo>,
+    /// Orders consolidated into this pallet; empty unless this is a pallet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pallet: Vec<Order>,
+}
+
+/// Tracks the parts of an order that was split across several flights.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SplitShipment {
+    /// Parts that have not been delivered yet
+    pub parts_remaining: usize,
+    /// Amount paid once the last part arrives, unless a part failed
+    pub completion_bonus: f32,
+    /// Set when any part misses its deadline
+    pub failed: bool,
 }
 
 impl Order {
@@ -391,11 +558,36 @@ impl Order {
         origin_airport_id: usize,
         airports: &[OrderAirportInfo],
         params: &OrderGenerationParams,
+    ) -> Self {
+        Order::new_cargo_in_market(
+            seed,
+            order_id,
+            origin_airport_id,
+            airports,
+            params,
+            &LaneMarket::default(),
+        )
+    }
+
+    /// Draw a cargo order priced against the player's recent activity.
+    ///
+    /// Same as [`Order::new_cargo`], with the value scaled by
+    /// [`LaneMarket::price_factor`] for the chosen lane.
+    ///
+    /// Parameters
+    /// - `market`: Recent deliveries per lane and backlogs per airport.
+    pub fn new_cargo_in_market(
+        seed: u64,
+        order_id: usize,
+        origin_airport_id: usize,
+        airports: &[OrderAirportInfo],
+        params: &OrderGenerationParams,
+        market: &LaneMarket,
     ) -> Self {
         let mut rng = StdRng::seed_from_u64(seed);
 
         let cargo_count = CargoType::iter().count();
-        let cargo_type = CargoType::iter()
+        let mut cargo_type = CargoType::iter()
             .nth(rng.gen_range(0..cargo_count))
             .unwrap();
 
@@ -408,6 +600,19 @@ impl Order {
         let destination = choose_destination(&mut rng, &origin, airports);
         let distance = chord_distance(origin.coordinate, destination.coordinate);
 
+        // International shipments cannot carry restricted goods
+        let cross_border = origin.country.is_some()
+            && destination.country.is_some()
+            && origin.country != destination.country;
+        if cross_border && params.restricted_cargo.contains(&cargo_type) {
+            let allowed: Vec<CargoType> = CargoType::iter()
+                .filter(|c| !params.restricted_cargo.contains(c))
+                .collect();
+            if let Some(c) = allowed.choose(&mut rng) {
+                cargo_type = *c;
+            }
+        }
+
         let origin_class = classify_runway(origin.runway_length);
         let dest_class = classify_runway(destination.runway_length);
         let weight = sample_weight(&mut rng, origin_class, dest_class, params);
@@ -423,6 +628,10 @@ impl Order {
             cargo_type,
             params,
         );
+        let (tier, deadline, value) = apply_tier(&mut rng, deadline, value, travel_hours, params);
+        let value = (value
+            * market.price_factor(origin_airport_id, destination.id, &params.market))
+        .round();
 
         Order {
             id: order_id,
@@ -431,6 +640,13 @@ impl Order {
             deadline,
             origin_id: origin_airport_id,
             destination_id: destination.id,
+            tier,
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: Vec::new(),
         }
     }
 
@@ -468,6 +684,13 @@ impl Order {
             deadline,
             origin_id: origin_airport_id,
             destination_id: destination.id,
+            tier: OrderTier::Standard,
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: Vec::new(),
         }
     }
 
@@ -494,6 +717,156 @@ impl Order {
     pub fn is_passenger(&self) -> bool {
         matches!(self.payload, OrderPayload::Passengers { .. })
     }
+
+    /// Return `true` if this order carries fragile cargo; a pallet does if any order on
+    /// it does.
+    pub fn is_fragile(&self) -> bool {
+        if self.is_pallet() {
+            return self.pallet.iter().any(Order::is_fragile);
+        }
+        self.cargo_type().is_some_and(|c| c.is_fragile())
+    }
+
+    /// Add in-transit damage to fragile cargo; other orders are unaffected. On a pallet
+    /// each fragile order takes the damage.
+    ///
+    /// Parameters
+    /// - `amount`: Share of the value to lose, accumulated up to 1.0.
+    pub fn apply_damage(&mut self, amount: f32) {
+        if self.is_pallet() {
+            for order in &mut self.pallet {
+                order.apply_damage(amount);
+            }
+        } else if self.is_fragile() {
+            self.damage = (self.damage + amount).clamp(0.0, 1.0);
+        }
+    }
+
+    /// Amount paid on delivery after damage; a pallet pays what its orders do.
+    pub fn payout(&self) -> f32 {
+        if self.is_pallet() {
+            return self.pallet.iter().map(Order::payout).sum();
+        }
+        self.value * (1.0 - self.damage)
+    }
+
+    /// Return `true` if this is a pallet of consolidated orders.
+    pub fn is_pallet(&self) -> bool {
+        !self.pallet.is_empty()
+    }
+
+    /// Consolidate cargo orders bound for one destination into a pallet.
+    ///
+    /// The pallet weighs and is worth the sum of its orders and is due with the earliest
+    /// of them, whose cargo type and tier it shows. Pallets among `orders` are unpacked
+    /// into the new one.
+    ///
+    /// Parameters
+    /// - `id`: Id of the pallet.
+    /// - `origin_id`: Airport the pallet is made up at.
+    /// - `orders`: Orders to consolidate.
+    ///
+    /// Returns
+    /// - `Some(pallet)` for cargo orders that share a destination.
+    /// - `None` if `orders` is empty, holds passengers or has several destinations.
+    pub fn palletize(id: usize, origin_id: usize, orders: Vec<Order>) -> Option<Order> {
+        let mut contents = Vec::new();
+        for order in orders {
+            if order.is_pallet() {
+                contents.extend(order.pallet);
+            } else {
+                contents.push(order);
+            }
+        }
+        let first = contents.iter().min_by_key(|o| o.deadline)?;
+        let destination_id = first.destination_id;
+        if contents
+            .iter()
+            .any(|o| o.is_passenger() || o.destination_id != destination_id)
+        {
+            return None;
+        }
+        let cargo_type = first.cargo_type()?;
+        let (deadline, tier) = (first.deadline, first.tier);
+        Some(Order {
+            id,
+            payload: OrderPayload::Cargo {
+                cargo_type,
+                weight: contents.iter().map(Order::payload_weight).sum(),
+            },
+            value: contents.iter().map(|o| o.value).sum(),
+            deadline,
+            origin_id,
+            destination_id,
+            tier,
+            damage: 0.0,
+            shipment: None,
+            contract: None,
+            spare_parts: 0,
+            rare: None,
+            pallet: contents,
+        })
+    }
+
+    /// Weight counted when the order is loaded or unloaded: pallets count
+    /// [`PALLET_HANDLING_SHARE`] of theirs.
+    pub fn handling_weight(&self) -> f32 {
+        if self.is_pallet() {
+            self.payload_weight() * PALLET_HANDLING_SHARE
+        } else {
+            self.payload_weight()
+        }
+    }
+
+    /// Weight the order adds to a plane, counting 100 kg per passenger.
+    pub fn payload_weight(&self) -> f32 {
+        match &self.payload {
+            OrderPayload::Cargo { weight, .. } => *weight,
+            OrderPayload::Passengers { count } => *count as f32 * PASSENGER_WEIGHT_KG,
+        }
+    }
+
+    /// Value earned per kg of payload.
+    pub fn value_per_kg(&self) -> f32 {
+        self.value / self.payload_weight().max(1.0)
+    }
+
+    /// Split `weight` kg off this cargo order into a new part.
+    ///
+    /// The value is divided in proportion to weight; every other field is
+    /// copied to the new part.
+    ///
+    /// Parameters
+    /// - `weight`: Weight in kg to move into the new part.
+    /// - `new_id`: Id assigned to the new part.
+    ///
+    /// Returns
+    /// - `Some(part)` when this is a cargo order heavier than `weight`.
+    /// - `None` for passenger orders or weights outside `(0, current weight)`.
+    pub fn split_off(&mut self, weight: f32, new_id: usize) -> Option<Order> {
+        let OrderPayload::Cargo {
+            cargo_type,
+            weight: total,
+        } = self.payload
+        else {
+            return None;
+        };
+        if !(weight > 0.0 && weight < total) {
+            return None;
+        }
+        let part_value = self.value * weight / total;
+        self.value -= part_value;
+        self.payload = OrderPayload::Cargo {
+            cargo_type,
+            weight: total - weight,
+        };
+        Some(Order {
+            id: new_id,
+            payload: OrderPayload::Cargo { cargo_type, weight },
+            value: part_value,
+            ..self.clone()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -556,12 +929,16 @@ mod internal_tests {
             OrderAirportInfo {
                 id: 0,
                 runway_length: 1_000.0,
+                size: 1.0,
                 coordinate: Coordinate::new(0.0, 0.0),
+                country: None,
             },
             OrderAirportInfo {
                 id: 1,
                 runway_length: 3_000.0,
+                size: 3.0,
                 coordinate: Coordinate::new(1_000_000.0, 0.0),
+                country: None,
             },
         ];
         let params = OrderGenerationParams::default();
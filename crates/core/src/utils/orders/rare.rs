@@ -0,0 +1,156 @@
+use super::cargo::CargoType;
+use super::order::{Order, OrderAirportInfo, OrderPayload, OrderTier, base_rate_per_kg};
+use crate::config::RareCargoGameplay;
+use crate::events::GameTime;
+use crate::utils::airplanes::models::{AirplaneRole, AirplaneSpecs};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// Speed (km/h) rare-cargo deadlines are set against; slower planes rarely make it.
+const RARE_REFERENCE_SPEED_KMH: f32 = 700.0;
+
+/// Cruise speed (km/h) an organ transport needs.
+pub const ORGAN_MIN_CRUISE_KMH: f32 = 500.0;
+
+/// An exceptional load that turns up without warning, pays far above the going rate
+/// and needs a particular kind of plane.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum RareCargo {
+    /// A few kilos of organs for transplant, due within hours; needs a fast plane
+    OrganTransport,
+    /// A touring show's stage gear; needs a freighter or combi
+    ConcertEquipment,
+    /// Satellite components for a launch; needs a dedicated freighter
+    SatelliteParts,
+}
+
+impl fmt::Display for RareCargo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RareCargo::OrganTransport => write!(f, "organ transport"),
+            RareCargo::ConcertEquipment => write!(f, "concert equipment"),
+            RareCargo::SatelliteParts => write!(f, "satellite parts"),
+        }
+    }
+}
+
+impl RareCargo {
+    /// Cargo type the load is priced and handled as; all but the stage gear are fragile.
+    pub fn cargo_type(self) -> CargoType {
+        match self {
+            RareCargo::OrganTransport => CargoType::Pharmaceuticals,
+            RareCargo::ConcertEquipment => CargoType::Machines,
+            RareCargo::SatelliteParts => CargoType::Electronics,
+        }
+    }
+
+    /// Lightest and heaviest load in kg.
+    fn weight_range(self) -> (f32, f32) {
+        match self {
+            RareCargo::OrganTransport => (5.0, 40.0),
+            RareCargo::ConcertEquipment => (2_000.0, 6_000.0),
+            RareCargo::SatelliteParts => (800.0, 3_000.0),
+        }
+    }
+
+    /// Hours allowed on top of the flight at the reference speed.
+    fn slack_hours(self) -> (u64, u64) {
+        match self {
+            RareCargo::OrganTransport => (2, 4),
+            RareCargo::ConcertEquipment => (8, 16),
+            RareCargo::SatelliteParts => (6, 12),
+        }
+    }
+
+    /// What the load needs, e.g. "a dedicated freighter".
+    pub fn handling(self) -> String {
+        match self {
+            RareCargo::OrganTransport => {
+                format!(
+                    "a plane cruising at {:.0} km/h or more",
+                    ORGAN_MIN_CRUISE_KMH
+                )
+            }
+            RareCargo::ConcertEquipment => "a freighter or combi".to_string(),
+            RareCargo::SatelliteParts => "a dedicated freighter".to_string(),
+        }
+    }
+
+    /// Return `true` if a plane with `specs` meets the load's handling needs.
+    pub fn can_fly(self, specs: &AirplaneSpecs) -> bool {
+        match self {
+            RareCargo::OrganTransport => specs.cruise_speed >= ORGAN_MIN_CRUISE_KMH,
+            RareCargo::ConcertEquipment => specs.role != AirplaneRole::Passenger,
+            RareCargo::SatelliteParts => specs.role == AirplaneRole::Cargo,
+        }
+    }
+}
+
+/// Draw a rare load between two distinct airports.
+///
+/// The deadline is the flight at 700 km/h plus a few hours' slack, and the value the
+/// regular rate for the cargo times `value_multiplier`, but at least `min_value`.
+///
+/// Parameters
+/// - `seed`: RNG seed for this load.
+/// - `order_id`: Id of the new order.
+/// - `airports`: Airports to pick the origin and destination from.
+/// - `params`: Rare cargo tuning.
+///
+/// Returns
+/// - `Some(Order)` when there are at least two airports.
+/// - `None` otherwise.
+pub fn generate_rare_order(
+    seed: u64,
+    order_id: usize,
+    airports: &[OrderAirportInfo],
+    params: &RareCargoGameplay,
+) -> Option<Order> {
+    if airports.len() < 2 {
+        return None;
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let origin = &airports[rng.gen_range(0..airports.len())];
+    let destination = loop {
+        let candidate = &airports[rng.gen_range(0..airports.len())];
+        if candidate.id != origin.id {
+            break candidate;
+        }
+    };
+
+    let kinds: Vec<RareCargo> = RareCargo::iter().collect();
+    let kind = kinds[rng.gen_range(0..kinds.len())];
+    let (min_weight, max_weight) = kind.weight_range();
+    let weight = rng.gen_range(min_weight..=max_weight).round();
+    let (min_slack, max_slack) = kind.slack_hours();
+    let distance = origin.coordinate.distance_to(&destination.coordinate);
+    let flight_hours = (distance / RARE_REFERENCE_SPEED_KMH).ceil() as GameTime;
+    let deadline = flight_hours + rng.gen_range(min_slack..=max_slack);
+
+    let cargo_type = kind.cargo_type();
+    let value = (base_rate_per_kg(distance, cargo_type) * weight * params.value_multiplier)
+        .max(params.min_value)
+        .round();
+
+    Some(Order {
+        id: order_id,
+        payload: OrderPayload::Cargo { cargo_type, weight },
+        value,
+        deadline,
+        origin_id: origin.id,
+        destination_id: destination.id,
+        tier: OrderTier::Express,
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: Some(kind),
+        pallet: Vec::new(),
+    })
+}
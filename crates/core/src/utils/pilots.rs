@@ -0,0 +1,173 @@
+use crate::config::PilotsGameplay;
+use crate::events::GameTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Surnames handed out to new hires, in order.
+const PILOT_SURNAMES: [&str; 12] = [
+    "Earhart",
+    "Yeager",
+    "Lindbergh",
+    "Coleman",
+    "Bader",
+    "Wright",
+    "Cochran",
+    "Rutan",
+    "Hanna",
+    "Johnson",
+    "Sullenberger",
+    "Doolittle",
+];
+
+/// A pilot on the payroll.
+///
+/// Pilots fly the plane they are assigned to and travel with it. Flight hours count
+/// towards their duty time; once they have been on the ground for the configured rest
+/// period their duty time starts over.
+///
+/// Every flight hour also counts as experience, both overall and on the model flown.
+/// Pilots familiar with a model or route burn less fuel, and veterans on a model turn
+/// the plane around without the usual hour's wait.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Pilot {
+    pub id: usize,
+    pub name: String,
+    /// Airport the pilot is at, or was last at while flying
+    pub airport_id: usize,
+    /// Plane the pilot is assigned to
+    pub plane: Option<usize>,
+    /// Flight hours in the current duty period
+    pub duty_hours: GameTime,
+    /// Game time the pilot last landed
+    pub off_duty_since: GameTime,
+    /// Flight hours logged over the whole career
+    #[serde(default)]
+    pub experience_hours: GameTime,
+    /// Flight hours logged per airplane model
+    #[serde(default)]
+    pub type_hours: BTreeMap<String, GameTime>,
+    /// Departures flown per route, keyed by [`route_key`]
+    #[serde(default)]
+    pub route_flights: BTreeMap<String, u32>,
+}
+
+/// Key for the route between two airports, the same in both directions.
+pub fn route_key(a: usize, b: usize) -> String {
+    format!("{}-{}", a.min(b), a.max(b))
+}
+
+impl Pilot {
+    /// Hire a fresh, rested pilot waiting at `airport_id`.
+    pub fn new(id: usize, airport_id: usize) -> Self {
+        Pilot {
+            id,
+            name: format!("Capt. {}", PILOT_SURNAMES[id % PILOT_SURNAMES.len()]),
+            airport_id,
+            plane: None,
+            duty_hours: 0,
+            off_duty_since: 0,
+            experience_hours: 0,
+            type_hours: BTreeMap::new(),
+            route_flights: BTreeMap::new(),
+        }
+    }
+
+    /// Flight hours logged on `model`.
+    pub fn hours_on(&self, model: &str) -> GameTime {
+        self.type_hours.get(model).copied().unwrap_or(0)
+    }
+
+    /// Departures flown between airports `a` and `b`.
+    pub fn flights_on(&self, a: usize, b: usize) -> u32 {
+        self.route_flights
+            .get(&route_key(a, b))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Log one flight hour on `model`.
+    pub fn log_flight_hour(&mut self, model: &str) {
+        self.experience_hours += 1;
+        *self.type_hours.entry(model.to_string()).or_insert(0) += 1;
+    }
+
+    /// Log a departure between airports `a` and `b`.
+    pub fn log_departure(&mut self, a: usize, b: usize) {
+        *self.route_flights.entry(route_key(a, b)).or_insert(0) += 1;
+    }
+
+    /// Share of fuel saved when this pilot flies `model` from `origin` to `destination`.
+    pub fn fuel_saving(
+        &self,
+        model: &str,
+        origin: usize,
+        destination: usize,
+        settings: &PilotsGameplay,
+    ) -> f32 {
+        let mut saving = 0.0;
+        if self.hours_on(model) >= settings.type_rating_hours {
+            saving += settings.type_fuel_saving;
+        }
+        if self.flights_on(origin, destination) >= settings.familiar_route_flights {
+            saving += settings.route_fuel_saving;
+        }
+        saving
+    }
+
+    /// Return `true` if the pilot turns a `model` around without waiting an hour.
+    pub fn quick_turnaround(&self, model: &str, settings: &PilotsGameplay) -> bool {
+        self.hours_on(model) >= settings.quick_turnaround_hours
+    }
+
+    /// Return `true` if the pilot has rested long enough for the duty time to start over.
+    pub fn is_rested(&self, now: GameTime, rest_hours: GameTime) -> bool {
+        now.saturating_sub(self.off_duty_since) >= rest_hours
+    }
+
+    /// Duty hours that count against the next flight starting at `now`.
+    pub fn duty_hours_at(&self, now: GameTime, rest_hours: GameTime) -> GameTime {
+        if self.is_rested(now, rest_hours) {
+            0
+        } else {
+            self.duty_hours
+        }
+    }
+
+    /// Return `true` if the pilot can fly `flight_hours` more without breaking the duty limit.
+    pub fn can_fly(
+        &self,
+        now: GameTime,
+        flight_hours: GameTime,
+        settings: &PilotsGameplay,
+    ) -> bool {
+        self.duty_hours_at(now, settings.rest_hours) + flight_hours <= settings.max_duty_hours
+    }
+}
+
+/// Pick the pilot for a flight of `flight_hours` from `airport_id`.
+///
+/// The plane's own pilot flies if their duty time allows it; otherwise an unassigned pilot
+/// waiting at the airport takes over.
+///
+/// Returns
+/// - `Some(index)`: Index into `pilots` of the pilot who flies.
+/// - `None`: If nobody at the airport is rested enough.
+pub fn select_pilot(
+    pilots: &[Pilot],
+    plane_id: usize,
+    airport_id: usize,
+    flight_hours: GameTime,
+    now: GameTime,
+    settings: &PilotsGameplay,
+) -> Option<usize> {
+    let assigned = pilots
+        .iter()
+        .position(|p| p.plane == Some(plane_id) && p.can_fly(now, flight_hours, settings));
+    assigned.or_else(|| {
+        pilots.iter().position(|p| {
+            p.plane.is_none()
+                && p.airport_id == airport_id
+                && p.can_fly(now, flight_hours, settings)
+        })
+    })
+}
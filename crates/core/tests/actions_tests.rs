@@ -0,0 +1,145 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::actions::{Action, ActionSpace};
+
+#[test]
+fn every_enumerated_action_succeeds() {
+    for seed in [1, 7, 23] {
+        let mut game = Game::new(seed, Some(8), 650_000.0);
+        game.advance(30);
+        let actions = game.enumerate_actions();
+        assert!(actions.iter().any(|a| matches!(a, Action::Depart { .. })));
+        assert!(actions.iter().any(|a| matches!(a, Action::BuyPlane { .. })));
+        for action in actions {
+            assert!(
+                game.fork().execute_str(&action.command_line()).is_ok(),
+                "seed {}: {} failed",
+                seed,
+                action
+            );
+        }
+    }
+}
+
+#[test]
+fn departures_cover_every_reachable_airport() {
+    let game = Game::new(3, Some(10), 650_000.0);
+    let plane = &game.planes()[0];
+    let reachable: Vec<usize> = game
+        .airports()
+        .iter()
+        .filter(|(airport, _)| game.fork().depart_plane(plane.id, airport.id).is_ok())
+        .map(|(airport, _)| airport.id)
+        .collect();
+    let offered: Vec<usize> = game
+        .enumerate_actions()
+        .into_iter()
+        .filter_map(|a| match a {
+            Action::Depart { destination, .. } => Some(destination),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(offered, reachable);
+}
+
+#[test]
+fn planes_in_the_air_have_nothing_to_do() {
+    let mut game = Game::new(3, Some(10), 650_000.0);
+    let destination = game
+        .enumerate_actions()
+        .into_iter()
+        .find_map(|a| match a {
+            Action::Depart { destination, .. } => Some(destination),
+            _ => None,
+        })
+        .unwrap();
+    game.depart_plane(0, destination).unwrap();
+    game.advance(1);
+    assert!(
+        game.enumerate_actions()
+            .iter()
+            .all(|a| matches!(a, Action::BuyPlane { .. }))
+    );
+}
+
+#[test]
+fn only_affordable_planes_are_offered() {
+    let game = Game::new(3, Some(10), 650_000.0);
+    let funds = game.player.available_funds();
+    let models = game.available_models();
+    for action in game.enumerate_actions() {
+        if let Action::BuyPlane { model, .. } = action {
+            let (_, specs) = models.iter().find(|(name, _)| *name == model).unwrap();
+            assert!(specs.purchase_price <= funds);
+        }
+    }
+    assert!(models.iter().any(|(_, s)| s.purchase_price > funds));
+}
+
+#[test]
+fn actions_serialize_tagged() {
+    let action = Action::LoadOrder {
+        plane: 2,
+        order: 17,
+    };
+    let json = serde_json::to_string(&action).unwrap();
+    assert_eq!(json, r#"{"action":"load_order","plane":2,"order":17}"#);
+    assert_eq!(serde_json::from_str::<Action>(&json).unwrap(), action);
+    assert_eq!(action.to_string(), "LOAD ORDER 17 ON 2");
+}
+
+#[test]
+fn encoding_round_trips_every_action() {
+    let mut game = Game::new(7, Some(12), 5_000_000.0);
+    game.advance(30);
+    let space = ActionSpace::default();
+    let actions = game.enumerate_actions();
+    for action in &actions {
+        let index = space.encode_action(&game, action).unwrap();
+        assert!(index < space.size());
+        assert_eq!(space.decode_action(&game, index).as_ref(), Some(action));
+    }
+
+    let mask = space.action_mask(&game);
+    assert_eq!(mask.len(), space.size());
+    assert!(mask[ActionSpace::NOOP]);
+    assert_eq!(mask.iter().filter(|m| **m).count(), actions.len() + 1);
+}
+
+#[test]
+fn empty_slots_decode_to_nothing() {
+    let game = Game::new(7, Some(5), 650_000.0);
+    let space = ActionSpace::default();
+    assert_eq!(space.decode_action(&game, ActionSpace::NOOP), None);
+    assert_eq!(space.decode_action(&game, space.size()), None);
+    // the second plane slot is empty with a single starter plane
+    let second_plane = 1 + 3 + space.max_airports + space.max_orders;
+    assert_eq!(space.decode_action(&game, second_plane), None);
+    // and so is a departure to an airport slot past the map
+    assert_eq!(space.decode_action(&game, 1 + 3 + 5), None);
+}
+
+#[test]
+fn actions_outside_the_space_have_no_index() {
+    let game = Game::new(7, Some(12), 650_000.0);
+    let tiny = ActionSpace {
+        max_planes: 1,
+        max_airports: 4,
+        max_orders: 1,
+        max_models: 1,
+    };
+    assert_eq!(tiny.size(), 1 + (3 + 4 + 1) + 4);
+    let far = game.airports()[6].0.id;
+    let depart = Action::Depart {
+        plane: 0,
+        destination: far,
+    };
+    assert_eq!(tiny.encode_action(&game, &depart), None);
+    assert_eq!(
+        tiny.encode_action(&game, &Action::Maintenance { plane: 0 }),
+        Some(3)
+    );
+    assert_eq!(
+        tiny.encode_action(&game, &Action::Maintenance { plane: 9 }),
+        None
+    );
+}
@@ -29,6 +29,13 @@ fn make_cargo_order(id: usize, weight: f32, value: f32, dest: usize) -> Order {
         deadline: 10,
         origin_id: 0,
         destination_id: dest,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
     }
 }
 
@@ -40,14 +47,21 @@ fn make_passenger_order(id: usize, count: u32, value: f32, dest: usize) -> Order
         deadline: 12,
         origin_id: 0,
         destination_id: dest,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
     }
 }
 
 #[test]
 fn iter_models() {
-    // By default 10 configs
+    // By default 12 configs
     let variants: Vec<_> = AirplaneModel::iter().collect();
-    assert_eq!(variants.len(), 10);
+    assert_eq!(variants.len(), 12);
     for m in AirplaneModel::iter() {
         assert!(variants.contains(&m));
     }
@@ -191,6 +205,34 @@ fn passenger_capacity_enforced() {
     assert_eq!(mixed_plane.current_passengers, 0);
 }
 
+#[test]
+fn cargo_slots_limit_orders_not_weight() {
+    let home = Coordinate::new(0.0, 0.0);
+    let mut plane = Airplane::new(0, AirplaneModel::SparrowLight, home);
+    let slots = plane.specs.cargo_slots;
+    assert!(slots > 0);
+    for id in 0..slots as usize {
+        plane
+            .load_order(make_cargo_order(id, 10.0, 100.0, 1))
+            .unwrap();
+    }
+    assert_eq!(plane.cargo_orders(), slots);
+    assert!(matches!(
+        plane.load_order(make_cargo_order(99, 10.0, 100.0, 1)),
+        Err(GameError::CargoSlotsFull { .. })
+    ));
+
+    // passengers don't take a slot
+    plane
+        .load_order(make_passenger_order(100, 2, 500.0, 1))
+        .unwrap();
+
+    plane.specs.cargo_slots = 0;
+    plane
+        .load_order(make_cargo_order(99, 10.0, 100.0, 1))
+        .unwrap();
+}
+
 #[test]
 fn passenger_overload_rejected() {
     let home = Coordinate::new(0.0, 0.0);
@@ -228,3 +270,32 @@ fn refuel_check() {
     assert!(approx_eq(plane.current_fuel, plane.specs.fuel_capacity));
     assert!(matches!(plane.status, AirplaneStatus::Refueling));
 }
+
+#[test]
+fn full_payload_and_full_tank_exceed_mtow() {
+    let home = Coordinate::new(0.0, 0.0);
+    let (ap, coords) = sample_airport(4000.0, 100.0, 0.0);
+    let mut plane = Airplane::new(0, AirplaneModel::BisonFreighter, home);
+    plane.current_payload = plane.specs.payload_capacity;
+    assert!(plane.takeoff_weight() > plane.specs.mtow);
+
+    let fuel = plane.current_fuel;
+    assert!(matches!(
+        plane.consume_flight_fuel(&ap, &coords),
+        Err(GameError::OverMaxTakeoffWeight { .. })
+    ));
+    assert_eq!(plane.current_fuel, fuel);
+
+    // just under half a tank fits with a full payload
+    plane.current_fuel = plane.specs.fuel_capacity * 0.45;
+    assert!(plane.takeoff_weight() < plane.specs.mtow);
+    plane.consume_flight_fuel(&ap, &coords).unwrap();
+}
+
+#[test]
+fn empty_weight_is_positive_for_all_models() {
+    for model in AirplaneModel::iter() {
+        let specs = model.specs();
+        assert!(specs.empty_weight() > 0.0, "{:?}", model);
+    }
+}
@@ -1,7 +1,7 @@
 use rusty_runways_core::Game;
 use rusty_runways_core::config::{
     AirplaneCatalogStrategy, AirplaneModelConfig, AirplanesConfig, GameplayConfig, Location,
-    WorldConfig,
+    StartingPlaneConfig, WorldConfig,
 };
 
 fn airport(id: usize, name: &str, x: f32, y: f32) -> rusty_runways_core::config::AirportConfig {
@@ -13,6 +13,10 @@ fn airport(id: usize, name: &str, x: f32, y: f32) -> rusty_runways_core::config:
         fuel_price_per_l: Some(1.2),
         landing_fee_per_ton: Some(4.0),
         parking_fee_per_hour: Some(10.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
         orders: Vec::new(),
     }
 }
@@ -27,9 +31,11 @@ fn base_model(name: &str, price: f32) -> AirplaneModelConfig {
         operating_cost: 500.0,
         payload_capacity: 1_000.0,
         passenger_capacity: 0,
+        cargo_slots: 0,
         purchase_price: price,
         min_runway_length: 800.0,
         role: rusty_runways_core::utils::airplanes::models::AirplaneRole::Cargo,
+        power: Default::default(),
     }
 }
 
@@ -51,8 +57,13 @@ fn from_config_uses_replace_catalog_for_starter() {
         starting_cash: 1_000_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: Some(airplanes),
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     let game = Game::from_config(cfg).expect("config should build");
@@ -74,8 +85,13 @@ fn from_config_adds_catalog_and_picks_cheapest() {
         starting_cash: 1_000_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: Some(airplanes),
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     let game = Game::from_config(cfg).expect("config should build");
@@ -97,8 +113,13 @@ fn replace_disallows_unknown_model_purchase() {
         starting_cash: 2_000_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: Some(airplanes),
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let mut game = Game::from_config(cfg.clone()).expect("should build");
     // buying default should fail in replace mode
@@ -129,8 +150,13 @@ fn invalid_model_rejected() {
         starting_cash: 1_000_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: Some(airplanes),
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     if let rusty_runways_core::utils::errors::GameError::InvalidConfig { msg } = err {
@@ -139,3 +165,90 @@ fn invalid_model_rejected() {
         panic!("expected invalid config error");
     }
 }
+
+fn fleet_config(starting_fleet: Vec<StartingPlaneConfig>) -> WorldConfig {
+    let mut short = airport(2, "SHORT", 1500.0, 1500.0);
+    short.runway_length_m = Some(500.0);
+    WorldConfig {
+        seed: Some(3),
+        starting_cash: 500_000.0,
+        airports: vec![
+            airport(0, "HUB", 1000.0, 1000.0),
+            airport(1, "AAX", 1200.0, 1005.0),
+            short,
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay: GameplayConfig::default(),
+        airplanes: Some(AirplanesConfig {
+            strategy: AirplaneCatalogStrategy::Add,
+            models: vec![base_model("Workhorse", 300_000.0)],
+        }),
+        starting_fleet,
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn from_config_builds_starting_fleet() {
+    let cfg = fleet_config(vec![
+        StartingPlaneConfig {
+            model: "falconjet".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        },
+        StartingPlaneConfig {
+            model: "Workhorse".into(),
+            home_airport: 1,
+            fuel_level: 0.25,
+        },
+    ]);
+    let game = Game::from_config(cfg).expect("starting fleet is valid");
+
+    assert_eq!(game.player.fleet_size, 2);
+    assert_eq!(game.player.cash, 500_000.0);
+    let planes = game.planes();
+    assert_eq!(planes[0].id, 0);
+    assert_eq!(format!("{:?}", planes[0].model), "FalconJet");
+    assert_eq!(planes[0].location, game.map.coord(0).unwrap());
+    assert_eq!(planes[1].location, game.map.coord(1).unwrap());
+    assert!((planes[1].current_fuel - 500.0).abs() < 1e-3);
+}
+
+#[test]
+fn from_config_rejects_invalid_starting_fleet() {
+    let entry = |model: &str, home_airport: usize, fuel_level: f32| StartingPlaneConfig {
+        model: model.into(),
+        home_airport,
+        fuel_level,
+    };
+
+    let err = Game::from_config(fleet_config(vec![entry("Workhorse", 2, 1.0)])).unwrap_err();
+    assert!(err.to_string().contains("runway"));
+
+    let err = Game::from_config(fleet_config(vec![entry("Zeppelin", 0, 1.0)])).unwrap_err();
+    assert!(err.to_string().contains("unknown airplane model"));
+
+    let err = Game::from_config(fleet_config(vec![entry("Workhorse", 9, 1.0)])).unwrap_err();
+    assert!(err.to_string().contains("unknown airport"));
+
+    let err = Game::from_config(fleet_config(vec![entry("Workhorse", 0, 1.5)])).unwrap_err();
+    assert!(err.to_string().contains("fuel_level"));
+}
+
+#[test]
+fn starting_fleet_fuel_level_defaults_to_full() {
+    let json = r#"{
+        "num_airports": 5,
+        "starting_fleet": [
+            { "model": "SparrowLight", "home_airport": 0 },
+            { "model": "SparrowLight", "home_airport": 0, "fuel_level": 0.5 }
+        ]
+    }"#;
+    let cfg: WorldConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(cfg.starting_fleet.len(), 2);
+    assert_eq!(cfg.starting_fleet[0].fuel_level, 1.0);
+    assert_eq!(cfg.starting_fleet[1].fuel_level, 0.5);
+}
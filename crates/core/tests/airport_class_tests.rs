@@ -0,0 +1,111 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::checks::CheckType;
+use rusty_runways_core::utils::airplanes::models::FuelGrade;
+use rusty_runways_core::utils::airport::AirportClass;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, runway: f32, population: Option<u32>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("C{}", id),
+        location: Some(Location {
+            x: 1_000.0 + id as f32 * 300.0,
+            y: 1_000.0,
+        }),
+        runway_length_m: Some(runway),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// A hub, a regional airport, a strip and a long runway in a small town.
+fn world(home_airport: usize) -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 3_500.0, None),
+            airport(1, 2_000.0, Some(100_000)),
+            airport(2, 1_000.0, Some(100_000)),
+            airport(3, 3_500.0, Some(50_000)),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport,
+            fuel_level: 0.5,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn class_follows_runway_and_size() {
+    let game = Game::from_config(world(0)).unwrap();
+    let classes: Vec<AirportClass> = game.observe().airports.iter().map(|a| a.class).collect();
+    assert_eq!(
+        classes,
+        vec![
+            AirportClass::Hub,
+            AirportClass::Regional,
+            AirportClass::Strip,
+            AirportClass::Regional,
+        ]
+    );
+    assert_eq!(AirportClass::Hub.to_string(), "International hub");
+}
+
+#[test]
+fn stands_grow_with_class() {
+    let game = Game::from_config(world(0)).unwrap();
+    // both have a size of 1.0
+    assert_eq!(game.map.airport(1).unwrap().parking_stands(), 4);
+    assert_eq!(game.map.airport(2).unwrap().parking_stands(), 2);
+}
+
+#[test]
+fn strips_only_sell_avgas() {
+    let mut game = Game::from_config(world(2)).unwrap();
+    assert_eq!(game.airplanes[0].specs.fuel_grade(), Some(FuelGrade::Avgas));
+    game.fork().refuel_plane(0).unwrap();
+
+    // heavy enough to need turbine fuel
+    game.airplanes[0].specs.mtow = 8_000.0;
+    assert!(matches!(
+        game.refuel_plane(0),
+        Err(GameError::FuelGradeUnavailable {
+            airport_id: 2,
+            grade: FuelGrade::JetA1
+        })
+    ));
+}
+
+#[test]
+fn heavy_checks_need_a_hub() {
+    let mut game = Game::from_config(world(1)).unwrap();
+    game.map.airport_mut(1).unwrap().spare_parts = 20;
+    assert!(matches!(
+        game.fork().perform_check(0, CheckType::C),
+        Err(GameError::CheckUnavailable {
+            airport_id: 1,
+            check: CheckType::C
+        })
+    ));
+    game.perform_check(0, CheckType::B).unwrap();
+}
@@ -7,7 +7,7 @@ use rusty_runways_core::utils::{
     coordinate::Coordinate,
     errors::GameError,
     orders::{
-        Order,
+        LaneMarket, Order,
         order::{DemandGenerationParams, OrderAirportInfo, OrderGenerationParams},
     },
 };
@@ -73,17 +73,22 @@ fn generate_orders_counts_and_ids() {
     // with 1000 number_orders is in [15, 24]
     let mut ap = Airport::generate_random(0, 0);
     ap.runway_length = 1000.0;
+    ap.population = 100_000;
     let coords = [Coordinate::new(0., 0.), Coordinate::new(10., 10.)];
     let airport_infos = vec![
         OrderAirportInfo {
             id: 0,
             runway_length: ap.runway_length,
+            size: ap.size(),
             coordinate: coords[0],
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_400.0,
+            size: 2.4,
             coordinate: coords[1],
+            country: None,
         },
     ];
     let mut next_id = 0;
@@ -92,6 +97,7 @@ fn generate_orders_counts_and_ids() {
         &airport_infos,
         &mut next_id,
         &DemandGenerationParams::default(),
+        &LaneMarket::default(),
     );
 
     assert!(ap.orders.len() >= 7 && ap.orders.len() <= 12);
@@ -115,12 +121,16 @@ fn load_order_and_errors() {
         OrderAirportInfo {
             id: 0,
             runway_length: ap.runway_length,
+            size: ap.size(),
             coordinate: coords[0],
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_000.0,
+            size: 2.0,
             coordinate: coords[1],
+            country: None,
         },
     ];
     let mut next_id = 0;
@@ -129,6 +139,7 @@ fn load_order_and_errors() {
         &airport_infos,
         &mut next_id,
         &DemandGenerationParams::default(),
+        &LaneMarket::default(),
     );
 
     let idx = ap
@@ -167,12 +178,16 @@ fn load_orders_stops_on_error() {
         OrderAirportInfo {
             id: 0,
             runway_length: ap.runway_length,
+            size: ap.size(),
             coordinate: coords[0],
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_500.0,
+            size: 2.5,
             coordinate: coords[1],
+            country: None,
         },
     ];
 
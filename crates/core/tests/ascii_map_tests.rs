@@ -0,0 +1,57 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+
+#[test]
+fn map_has_a_border_and_every_airport() {
+    let game = Game::new(3, Some(6), 1_000_000.0);
+    let text = game.render_ascii(30, 8);
+    let lines: Vec<&str> = text.lines().collect();
+
+    let border = format!("+{}+", "-".repeat(30));
+    assert_eq!(lines[0], border);
+    assert_eq!(lines[9], border);
+    for row in &lines[1..9] {
+        assert!(row.starts_with('|') && row.ends_with('|'));
+        assert_eq!(row.chars().count(), 32);
+    }
+
+    let grid: String = lines[1..9].concat();
+    for id in 0..6 {
+        let label = std::char::from_digit(id, 36).unwrap();
+        assert!(grid.contains(label), "airport {} missing", id);
+    }
+    assert!(lines[10].starts_with("Airports: 0 "));
+    assert!(lines[11].contains("Parked at"));
+}
+
+#[test]
+fn planes_in_flight_draw_their_leg() {
+    let mut game = Game::new(3, Some(6), 1_000_000.0);
+    let here = game.airplanes[0].location;
+    let (destination, _) = game
+        .map
+        .airports
+        .iter()
+        .filter(|(_, c)| *c != here)
+        .min_by(|(_, a), (_, b)| here.distance_to(a).total_cmp(&here.distance_to(b)))
+        .unwrap();
+    let destination = destination.id;
+    game.depart_plane(0, destination).unwrap();
+    game.advance(1);
+    assert!(matches!(
+        game.airplanes[0].status,
+        AirplaneStatus::InTransit { .. }
+    ));
+
+    let text = game.render_ascii(60, 20);
+    assert!(text.contains('*'));
+    assert!(text.contains('.'));
+    assert!(text.lines().any(|l| l.starts_with("* 0 ")));
+}
+
+#[test]
+fn tiny_maps_do_not_panic() {
+    let game = Game::new(3, Some(6), 1_000_000.0);
+    let text = game.render_ascii(0, 0);
+    assert!(text.starts_with("+-+\n|"));
+}
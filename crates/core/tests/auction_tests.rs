@@ -0,0 +1,175 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, AuctionsGameplay, GameplayConfig, Location, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::coordinate::Coordinate;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::Auction;
+use rusty_runways_core::utils::orders::order::OrderAirportInfo;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("B{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// A game that opens an auction every day.
+fn auction_game(chance_per_day: f32) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.forwarders.count = 0;
+    gameplay.auctions.chance_per_day = chance_per_day;
+    let config = WorldConfig {
+        seed: Some(13),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_800.0),
+            airport(2, 2_600.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    Game::from_config(config).unwrap()
+}
+
+fn infos() -> Vec<OrderAirportInfo> {
+    (0..2)
+        .map(|id| OrderAirportInfo {
+            id,
+            runway_length: 3_000.0,
+            size: 1.0,
+            coordinate: Coordinate::new(1_000.0 + 800.0 * id as f32, 1_000.0),
+            country: None,
+        })
+        .collect()
+}
+
+#[test]
+fn competitor_bids_stay_around_the_going_rate() {
+    let params = AuctionsGameplay {
+        competitors: 5,
+        ..AuctionsGameplay::default()
+    };
+    for seed in 0..20 {
+        let auction = Auction::generate(seed, 0, &infos(), &params, 10).unwrap();
+        assert_eq!(auction.closes_at, 10 + params.bidding_hours);
+        assert_eq!(auction.competitor_bids.len(), 5);
+        for bid in &auction.competitor_bids {
+            assert!((bid / auction.reference_value - 1.0).abs() <= params.bid_spread + 0.01);
+        }
+        assert!(auction.player_bid.is_none());
+        assert!(!auction.player_wins());
+    }
+}
+
+#[test]
+fn urgent_loads_have_a_higher_going_rate() {
+    let urgent = AuctionsGameplay {
+        min_deadline_hours: 24,
+        max_deadline_hours: 24,
+        ..AuctionsGameplay::default()
+    };
+    let relaxed = AuctionsGameplay {
+        min_deadline_hours: 96,
+        max_deadline_hours: 96,
+        ..urgent.clone()
+    };
+    let a = Auction::generate(4, 0, &infos(), &urgent, 0).unwrap();
+    let b = Auction::generate(4, 0, &infos(), &relaxed, 0).unwrap();
+    assert_eq!(a.weight, b.weight);
+    assert!(a.reference_value > b.reference_value);
+}
+
+#[test]
+fn undercutting_every_competitor_wins_the_load() {
+    let mut game = auction_game(1.0);
+    let results = game.subscribe(EventFilter::only(&[GameEventKind::Auction]));
+    game.advance(24);
+    let auction = game.auctions()[0].clone();
+    let bid = auction.best_competitor_bid() - 1.0;
+    game.bid_auction(auction.id, bid).unwrap();
+
+    game.advance(auction.closes_at - game.time);
+    assert!(game.auctions().iter().all(|a| a.id != auction.id));
+    let order = game
+        .all_orders()
+        .into_iter()
+        .find(|o| o.origin_id == auction.origin_id && o.value == bid)
+        .cloned()
+        .unwrap();
+    assert_eq!(order.destination_id, auction.destination_id);
+    assert_eq!(order.deadline, auction.deadline);
+    assert!(game.reserved_orders.contains(&order.id));
+    assert!(matches!(
+        results.try_iter().next(),
+        Some(GameEvent::AuctionResolved { won: true, winning_bid, .. }) if winning_bid == bid
+    ));
+}
+
+#[test]
+fn losing_an_auction_costs_nothing() {
+    let mut game = auction_game(1.0);
+    let results = game.subscribe(EventFilter::only(&[GameEventKind::Auction]));
+    game.advance(24);
+    let auction = game.auctions()[0].clone();
+    // a tie goes to the competitor
+    game.bid_auction(auction.id, auction.best_competitor_bid())
+        .unwrap();
+    let cash = game.player.cash;
+
+    game.advance(auction.closes_at - game.time);
+    assert_eq!(game.player.cash, cash);
+    assert!(game.reserved_orders.is_empty());
+    assert!(matches!(
+        results.try_iter().next(),
+        Some(GameEvent::AuctionResolved { won: false, winning_bid, .. })
+            if winning_bid == auction.best_competitor_bid()
+    ));
+}
+
+#[test]
+fn bids_need_an_open_auction_and_a_positive_amount() {
+    let mut game = auction_game(1.0);
+    game.advance(24);
+    let auction = game.auctions()[0].clone();
+    assert!(matches!(
+        game.bid_auction(auction.id, 0.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.bid_auction(99, 1_000.0),
+        Err(GameError::AuctionIdInvalid { id: 99 })
+    ));
+    game.advance(auction.closes_at - game.time);
+    assert!(matches!(
+        game.bid_auction(auction.id, 1_000.0),
+        Err(GameError::AuctionIdInvalid { .. })
+    ));
+}
+
+#[test]
+fn no_auctions_without_a_chance() {
+    let mut game = auction_game(0.0);
+    game.advance(5 * 24);
+    assert!(game.auctions().is_empty());
+    assert_eq!(game.next_auction_id, 0);
+}
@@ -0,0 +1,149 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use rusty_runways_core::utils::orders::order::OrderPayload;
+use rusty_runways_core::utils::orders::{CargoType, Order};
+
+fn bordered_config(countries: usize) -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.borders.countries = countries;
+    WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: Vec::new(),
+        num_airports: Some(12),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    }
+}
+
+fn current_airport(game: &Game) -> usize {
+    let loc = game.airplanes[0].location;
+    game.map
+        .airports
+        .iter()
+        .find(|(_, c)| *c == loc)
+        .map(|(a, _)| a.id)
+        .expect("plane starts at an airport")
+}
+
+#[test]
+fn generated_maps_are_split_into_countries() {
+    let game = Game::from_config(bordered_config(3)).unwrap();
+    assert_eq!(game.map.countries.len(), 3);
+    assert!(game.map.airports.iter().all(|(a, _)| a.country.is_some()));
+    for country in 0..3 {
+        assert!(
+            game.map
+                .airports
+                .iter()
+                .any(|(a, _)| a.country == Some(country))
+        );
+    }
+}
+
+#[test]
+fn borderless_by_default() {
+    let game = Game::from_config(bordered_config(0)).unwrap();
+    assert!(game.map.countries.is_empty());
+    assert!(!game.map.is_cross_border(0, 1));
+}
+
+#[test]
+fn restricted_cargo_stays_domestic() {
+    let game = Game::from_config(bordered_config(4)).unwrap();
+    let restricted = &game.borders.restricted_cargo;
+    for (airport, _) in &game.map.airports {
+        for order in &airport.orders {
+            if let OrderPayload::Cargo { cargo_type, .. } = order.payload {
+                if restricted.contains(&cargo_type) {
+                    assert!(
+                        !game
+                            .map
+                            .is_cross_border(order.origin_id, order.destination_id)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn explicit_airports_use_configured_countries() {
+    let mut cfg = bordered_config(0);
+    cfg.num_airports = None;
+    cfg.airports = ["NL", "BE", "NL"]
+        .iter()
+        .enumerate()
+        .map(|(id, country)| AirportConfig {
+            id,
+            name: format!("A{}", id),
+            location: Some(Location {
+                x: 1_000.0 + id as f32 * 300.0,
+                y: 1_000.0,
+            }),
+            runway_length_m: Some(3_000.0),
+            fuel_price_per_l: None,
+            landing_fee_per_ton: None,
+            parking_fee_per_hour: None,
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: Some(country.to_string()),
+            orders: Vec::new(),
+        })
+        .collect();
+
+    let game = Game::from_config(cfg.clone()).unwrap();
+    assert_eq!(game.map.countries, vec!["NL".to_string(), "BE".to_string()]);
+    assert_eq!(game.map.country_of(2), Some("NL"));
+    assert!(game.map.is_cross_border(0, 1));
+    assert!(!game.map.is_cross_border(0, 2));
+
+    cfg.airports[1].country = None;
+    let err = Game::from_config(cfg).unwrap_err();
+    assert!(err.to_string().contains("no country"));
+}
+
+#[test]
+fn cross_border_delivery_pays_customs() {
+    let mut game = Game::from_config(bordered_config(2)).unwrap();
+    let here = current_airport(&game);
+    let home = game.map.airport(here).unwrap().country;
+    let foreign = game
+        .map
+        .airports
+        .iter()
+        .find(|(a, _)| a.country != home)
+        .map(|(a, _)| a.id)
+        .expect("another country exists");
+
+    let order = Order {
+        id: 9_999,
+        payload: OrderPayload::Cargo {
+            cargo_type: CargoType::Food,
+            weight: 10.0,
+        },
+        value: 10_000.0,
+        deadline: 48,
+        origin_id: foreign,
+        destination_id: here,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
+    };
+    game.airplanes[0].load_order(order).unwrap();
+
+    let cash = game.player.cash;
+    game.unload_order(9_999, 0).unwrap();
+    let expected = 10_000.0 * (1.0 - game.borders.customs_rate);
+    assert!((game.player.cash - cash - expected).abs() < 1e-2);
+}
@@ -0,0 +1,209 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, ChartersGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::{AirplaneStatus, PASSENGER_WEIGHT_KG};
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::Charter;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("H{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn charter_world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_300.0),
+            airport(2, 1_600.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// A game with a single hand-made charter for 5 passengers from airport 0 to 1.
+fn charter_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    let mut game = Game::from_config(charter_world(gameplay)).unwrap();
+    game.charters = vec![Charter {
+        id: 7,
+        origin_id: 0,
+        destination_id: 1,
+        passengers: 5,
+        payout: 10_000.0,
+        offer_expires_at: 48,
+        window_hours: 36,
+        booking: None,
+    }];
+    game
+}
+
+/// Fly plane 0 to `dest` and return the cash earned while in the air and on landing.
+fn fly(game: &mut Game, dest: usize) -> f32 {
+    game.depart_plane(0, dest).unwrap();
+    let cash = game.player.cash;
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    game.player.cash - cash
+}
+
+#[test]
+fn charters_are_offered_at_start() {
+    let game = Game::from_config(charter_world(GameplayConfig::default())).unwrap();
+    let settings = ChartersGameplay::default();
+    assert_eq!(game.charters.len(), settings.count);
+    for c in &game.charters {
+        assert_ne!(c.origin_id, c.destination_id);
+        assert!((settings.min_passengers..=settings.max_passengers).contains(&c.passengers));
+        assert!(c.payout > 0.0);
+        assert!(c.booking.is_none());
+    }
+}
+
+#[test]
+fn charter_pays_on_time_arrival() {
+    let mut game = charter_game();
+    game.accept_charter(7, 0).unwrap();
+    assert_eq!(game.airplanes[0].charter_passengers, 5);
+    assert_eq!(game.charters[0].booking.unwrap().due_at, 36);
+    game.advance(1);
+
+    let earned = fly(&mut game, 1);
+    assert!((earned - 10_000.0).abs() < 1e-2);
+    assert_eq!(game.airplanes[0].charter_passengers, 0);
+    assert!(game.charters.is_empty());
+}
+
+#[test]
+fn charter_stays_aboard_until_its_destination() {
+    let mut game = charter_game();
+    game.accept_charter(7, 0).unwrap();
+    game.advance(1);
+
+    assert_eq!(fly(&mut game, 2), 0.0);
+    assert_eq!(game.airplanes[0].charter_passengers, 5);
+    assert!(game.unload_all(0).is_ok());
+    assert_eq!(game.airplanes[0].charter_passengers, 5);
+    assert!(matches!(
+        game.sell_plane(0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn late_charters_are_not_paid() {
+    let mut game = charter_game();
+    game.charters[0].window_hours = 1;
+    game.accept_charter(7, 0).unwrap();
+    game.advance(1);
+
+    assert_eq!(fly(&mut game, 1), 0.0);
+    assert_eq!(game.airplanes[0].charter_passengers, 0);
+    assert!(game.charters.is_empty());
+}
+
+#[test]
+fn overdue_groups_leave_a_grounded_plane() {
+    let mut game = charter_game();
+    game.accept_charter(7, 0).unwrap();
+    game.advance(48);
+
+    assert!(game.charters.is_empty());
+    assert_eq!(game.airplanes[0].charter_passengers, 0);
+}
+
+#[test]
+fn unaccepted_offers_lapse() {
+    let mut game = charter_game();
+    game.advance(72);
+    assert!(game.charters.is_empty());
+}
+
+#[test]
+fn charter_acceptance_is_checked() {
+    let mut game = charter_game();
+    assert!(matches!(
+        game.accept_charter(3, 0),
+        Err(GameError::CharterIdInvalid { id: 3 })
+    ));
+
+    game.charters[0].passengers = 7;
+    assert!(matches!(
+        game.accept_charter(7, 0),
+        Err(GameError::PassengerCapacityReached { .. })
+    ));
+    game.charters[0].passengers = 5;
+
+    game.airplanes[0].specs.passenger_capacity = 0;
+    assert!(matches!(
+        game.accept_charter(7, 0),
+        Err(GameError::PayloadTypeUnsupported { .. })
+    ));
+    game.airplanes[0].specs.passenger_capacity = 6;
+
+    game.charters[0].origin_id = 2;
+    assert!(matches!(
+        game.accept_charter(7, 0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    game.charters[0].origin_id = 0;
+
+    game.accept_charter(7, 0).unwrap();
+    assert!(matches!(
+        game.accept_charter(7, 0),
+        Err(GameError::CharterIdInvalid { id: 7 })
+    ));
+}
+
+#[test]
+fn charter_seats_count_against_ticketed_passengers() {
+    let mut game = charter_game();
+    let empty = game.airplanes[0].takeoff_weight();
+    game.accept_charter(7, 0).unwrap();
+    let plane = &game.airplanes[0];
+    assert_eq!(plane.seated_passengers(), 5);
+    assert!((plane.takeoff_weight() - empty - 5.0 * PASSENGER_WEIGHT_KG).abs() < 1e-2);
+}
+
+#[test]
+fn invalid_charter_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.charters.max_passengers = 1;
+    let paths: Vec<String> = charter_world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(paths, vec!["gameplay.charters.max_passengers"]);
+}
@@ -0,0 +1,159 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::airplane::Airplane;
+use rusty_runways_core::utils::airplanes::checks::{CheckHours, CheckType};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("M{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Gameplay with an A check due every 4 flight hours and a B check every 8.
+fn short_interval_gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.a_check.interval_hours = 4;
+    gameplay.maintenance.b_check.interval_hours = 8;
+    gameplay
+}
+
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(8),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_800.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// Fly the 800km leg (4 hours in a SparrowLight) and refuel at the other end.
+fn fly_leg(game: &mut Game, dest: usize) {
+    game.depart_plane(0, dest).unwrap();
+    game.advance(4);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    game.refuel_plane(0).unwrap();
+    game.advance(1);
+}
+
+#[test]
+fn flight_hours_count_towards_every_check() {
+    let mut game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    assert_eq!(game.airplanes[0].check_hours, CheckHours::uniform(4));
+    // ground time doesn't count
+    game.advance(10);
+    assert_eq!(game.airplanes[0].check_hours.since(CheckType::A), 4);
+}
+
+#[test]
+fn overdue_check_grounds_the_plane() {
+    let mut game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    assert!(matches!(
+        game.depart_plane(0, 0),
+        Err(GameError::CheckOverdue {
+            plane_id: 0,
+            check: CheckType::A
+        })
+    ));
+}
+
+#[test]
+fn checks_cost_money_take_time_and_reset_lighter_checks() {
+    let mut game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    let settings = game.maintenance_settings.b_check.clone();
+    let price = game.airplanes[0].specs.purchase_price;
+
+    let cash = game.player.cash;
+    game.perform_check(0, CheckType::B).unwrap();
+    assert!((cash - game.player.cash - price * settings.cost_share).abs() < 1e-2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Maintenance);
+
+    game.advance(settings.downtime_hours);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    let hours = game.airplanes[0].check_hours;
+    assert_eq!((hours.a, hours.b, hours.c), (0, 0, 4));
+    game.depart_plane(0, 0).unwrap();
+}
+
+#[test]
+fn checks_need_a_plane_on_the_ground_and_the_cash() {
+    let mut game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    assert!(game.perform_check(0, CheckType::A).is_err());
+    game.advance(4);
+
+    game.player.cash = -game.player.overdraft_limit;
+    assert!(matches!(
+        game.perform_check(0, CheckType::C),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+}
+
+#[test]
+fn check_command_parses_the_check_type() {
+    let mut game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    game.execute_str("CHECK PLANE 0 a").unwrap();
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Maintenance);
+    assert!(matches!(
+        game.execute_str("CHECK PLANE 0 D"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn old_saves_start_with_fresh_check_counters() {
+    let game = Game::from_config(world(short_interval_gameplay())).unwrap();
+    let mut json = serde_json::to_value(&game.airplanes[0]).unwrap();
+    json.as_object_mut().unwrap().remove("check_hours");
+    let plane: Airplane = serde_json::from_value(json).unwrap();
+    assert_eq!(plane.check_hours, CheckHours::default());
+}
+
+#[test]
+fn invalid_check_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.maintenance.a_check.interval_hours = 0;
+    gameplay.maintenance.c_check.cost_share = -0.5;
+    let paths: Vec<String> = world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.maintenance.a_check.interval_hours",
+            "gameplay.maintenance.c_check.cost_share"
+        ]
+    );
+}
@@ -0,0 +1,119 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("F{}", id),
+        location: Some(Location { x, y: 3_000.0 }),
+        runway_length_m: Some(2_000.0),
+        fuel_price_per_l: Some(1.2),
+        landing_fee_per_ton: Some(5.0),
+        parking_fee_per_hour: Some(10.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.rare_cargo.chance_per_day = 0.0;
+    Game::from_config(WorldConfig {
+        seed: Some(4),
+        starting_cash: 5_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(2, 1_800.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 0.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn concession_is_priced_by_airport_size_and_bought_once() {
+    let mut game = game();
+    let price = game.concession_price(0).unwrap();
+    let size = game.map.airport(0).unwrap().size();
+    assert!((price - 1_000_000.0 * size).abs() < 1.0);
+
+    let cash = game.player.cash;
+    assert_eq!(game.buy_concession(0).unwrap(), price);
+    assert_eq!(game.player.cash, cash - price);
+    assert_eq!(game.concession(0).unwrap().bought_at, 0);
+    assert!(game.observe().airports[0].has_concession);
+
+    assert!(matches!(
+        game.buy_concession(0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.buy_concession(9),
+        Err(GameError::AirportIdInvalid { id: 9 })
+    ));
+}
+
+#[test]
+fn concession_fixes_fuel_below_the_base_price() {
+    let mut game = game();
+    game.buy_concession(0).unwrap();
+    // a demand surge doesn't reach the concession holder
+    let airport = game.map.airport_mut(0).unwrap();
+    airport.fuel_price = airport.base_fuel_price * 2.0;
+    let expected = airport.base_fuel_price * 0.75;
+    assert!((game.player_fuel_price(game.map.airport(0).unwrap()) - expected).abs() < 1e-4);
+    assert!((game.observe().airports[0].fuel_price - expected).abs() < 1e-4);
+
+    let cash = game.player.cash;
+    game.refuel_plane(0).unwrap();
+    let liters = game.airplanes[0].current_fuel;
+    assert!(liters > 0.0);
+    assert!((cash - game.player.cash - liters * expected).abs() < 0.5);
+
+    // airports without a concession charge the posted price
+    let other = game.map.airport(2).unwrap();
+    assert_eq!(game.player_fuel_price(other), other.fuel_price);
+}
+
+#[test]
+fn concession_pays_daily_income_to_subscribers() {
+    let mut game = game();
+    game.buy_concession(2).unwrap();
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::Concession]));
+    game.advance(24 * 3);
+
+    let events: Vec<GameEvent> = feed.try_iter().collect();
+    assert_eq!(events.len(), 3);
+    let size = game.map.airport(2).unwrap().size();
+    let mut total = 0.0;
+    for event in events {
+        let GameEvent::ConcessionIncome {
+            airport, amount, ..
+        } = event
+        else {
+            panic!("expected concession income");
+        };
+        assert_eq!(airport, 2);
+        assert!((1_000.0 * size..=3_000.0 * size).contains(&amount));
+        total += amount;
+    }
+    assert!((game.concession(2).unwrap().income - total).abs() < 1e-2);
+    assert!(game.stats.iter().all(|day| day.income > 0.0));
+}
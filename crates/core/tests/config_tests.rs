@@ -17,6 +17,10 @@ fn base_airports() -> Vec<AirportConfig> {
             fuel_price_per_l: Some(1.2),
             landing_fee_per_ton: Some(5.0),
             parking_fee_per_hour: Some(20.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
         AirportConfig {
@@ -30,6 +34,10 @@ fn base_airports() -> Vec<AirportConfig> {
             fuel_price_per_l: Some(1.8),
             landing_fee_per_ton: Some(4.5),
             parking_fee_per_hour: Some(15.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
     ]
@@ -42,8 +50,13 @@ fn from_config_generates_orders_when_enabled() {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let game = Game::from_config(cfg).expect("should build");
     // both airports should have non-empty orders generally
@@ -58,8 +71,13 @@ fn from_config_no_orders_when_disabled() {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     cfg.gameplay.orders.generate_initial = false;
     let game = Game::from_config(cfg).expect("should build");
@@ -75,8 +93,13 @@ fn from_config_duplicate_ids_is_error() {
         starting_cash: 650_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(
@@ -95,8 +118,13 @@ fn from_config_duplicate_names_is_error() {
         starting_cash: 650_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(
@@ -118,8 +146,13 @@ fn from_config_location_bounds_enforced() {
         starting_cash: 650_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(format!("{}", err).to_lowercase().contains("out of bounds"));
@@ -134,8 +167,13 @@ fn from_config_positive_values_required() {
         starting_cash: 650_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(format!("{}", err).to_lowercase().contains("runway_length"));
@@ -148,8 +186,13 @@ fn from_config_applies_gameplay_tuning() {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     cfg.gameplay.restock_cycle_hours = 72;
@@ -186,8 +229,13 @@ fn from_config_rejects_invalid_gameplay() {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     cfg.gameplay.orders.tuning.min_weight = 1_000.0;
     cfg.gameplay.orders.tuning.max_weight = 100.0; // invalid
@@ -205,8 +253,13 @@ fn from_config_generated_airports_when_requested() {
         starting_cash: 750_000.0,
         airports: Vec::new(),
         num_airports: Some(4),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let game = Game::from_config(cfg).expect("should build");
     assert_eq!(game.map.num_airports, 4);
@@ -219,8 +272,13 @@ fn from_config_requires_num_airports_when_none_provided() {
         starting_cash: 500_000.0,
         airports: Vec::new(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(format!("{}", err).contains("num_airports"));
@@ -233,8 +291,13 @@ fn from_config_rejects_num_airports_with_explicit_airports() {
         starting_cash: 500_000.0,
         airports: base_airports(),
         num_airports: Some(2),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     let err = Game::from_config(cfg).unwrap_err();
     assert!(format!("{}", err).contains("num_airports"));
@@ -247,8 +310,13 @@ fn from_config_regeneration_disabled_requires_orders() {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     cfg.gameplay.orders.regenerate = false;
     cfg.gameplay.orders.generate_initial = false;
@@ -267,6 +335,7 @@ fn from_config_uses_manual_orders_when_regeneration_disabled() {
         value: 2_500.0,
         deadline_hours: 48,
         destination_id: 1,
+        tier: Default::default(),
     }];
     airports[1].orders = vec![ManualOrderConfig::Cargo {
         cargo: CargoType::Electronics,
@@ -274,6 +343,7 @@ fn from_config_uses_manual_orders_when_regeneration_disabled() {
         value: 4_200.0,
         deadline_hours: 24,
         destination_id: 0,
+        tier: Default::default(),
     }];
 
     let mut cfg = WorldConfig {
@@ -281,8 +351,13 @@ fn from_config_uses_manual_orders_when_regeneration_disabled() {
         starting_cash: 650_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     cfg.gameplay.orders.regenerate = false;
     cfg.gameplay.orders.generate_initial = false;
@@ -320,6 +395,7 @@ fn from_config_supports_manual_passengers() {
         value: 1_800.0,
         deadline_hours: 30,
         destination_id: 0,
+        tier: Default::default(),
     }];
 
     let mut cfg = WorldConfig {
@@ -327,14 +403,19 @@ fn from_config_supports_manual_passengers() {
         starting_cash: 500_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
     cfg.gameplay.orders.regenerate = false;
     cfg.gameplay.orders.generate_initial = false;
 
     let game = Game::from_config(cfg).expect("should build");
-    let airport_orders = &game.map.airports[0].0.orders;
+    let airport_orders = &game.map.airport(0).unwrap().orders;
     assert_eq!(airport_orders.len(), 1);
     let order = &airport_orders[0];
     assert!(order.is_passenger());
@@ -353,6 +434,10 @@ fn from_config_generates_missing_fields_and_locations() {
             fuel_price_per_l: None,
             landing_fee_per_ton: None,
             parking_fee_per_hour: None,
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
         AirportConfig {
@@ -363,6 +448,10 @@ fn from_config_generates_missing_fields_and_locations() {
             fuel_price_per_l: Some(1.6),
             landing_fee_per_ton: None,
             parking_fee_per_hour: None,
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
     ];
@@ -371,20 +460,162 @@ fn from_config_generates_missing_fields_and_locations() {
         starting_cash: 750_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     let game = Game::from_config(cfg).expect("should build");
     assert_eq!(game.map.airports.len(), 2);
 
-    let (first, coord0) = &game.map.airports[0];
+    let (first, coord0) = game.map.airports.get(0).unwrap();
     assert!(coord0.x >= 0.0 && coord0.x <= 10_000.0);
     assert!(coord0.y >= 0.0 && coord0.y <= 10_000.0);
     assert!(first.runway_length > 0.0);
     assert!(first.fuel_price > 0.0);
 
-    let (second, _) = &game.map.airports[1];
+    let (second, _) = game.map.airports.get(1).unwrap();
     assert_eq!(second.fuel_price, 1.6);
     assert_eq!(second.runway_length, 2_400.0);
 }
+
+#[test]
+fn export_config_round_trips_world() {
+    let game = Game::new(21, Some(5), 750_000.0);
+    let cfg = game.export_config();
+    assert_eq!(cfg.airports.len(), 5);
+    assert_eq!(cfg.starting_fleet.len(), game.airplanes.len());
+
+    let reloaded = Game::from_config(cfg).expect("exported config should load");
+    assert_eq!(reloaded.player.cash, game.player.cash);
+    for ((a, ca), (b, cb)) in game.map.airports.iter().zip(reloaded.map.airports.iter()) {
+        assert_eq!(a.name, b.name);
+        assert_eq!(ca, cb);
+        assert_eq!(a.runway_length, b.runway_length);
+        assert_eq!(a.orders.len(), b.orders.len());
+    }
+    assert_eq!(reloaded.airplanes[0].location, game.airplanes[0].location);
+    assert_eq!(reloaded.airplanes[0].specs, game.airplanes[0].specs);
+}
+
+#[test]
+fn validate_reports_every_issue_with_its_path() {
+    let mut airports = base_airports();
+    airports[1].name = "aaa".into();
+    airports[1].location = Some(Location {
+        x: 12_000.0,
+        y: 50.0,
+    });
+    airports[0].orders.push(ManualOrderConfig::Cargo {
+        cargo: CargoType::Food,
+        weight: 0.0,
+        value: 100.0,
+        deadline_hours: 10,
+        destination_id: 9,
+        tier: Default::default(),
+    });
+    let gameplay = GameplayConfig {
+        restock_cycle_hours: 0,
+        ..Default::default()
+    };
+    let cfg = WorldConfig {
+        seed: Some(1),
+        starting_cash: 650_000.0,
+        airports,
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+
+    let paths: Vec<String> = cfg.validate().into_iter().map(|i| i.path).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.restock_cycle_hours",
+            "airports[1].name",
+            "airports[0].orders[0].weight",
+            "airports[0].orders[0].destination_id",
+            "airports[1].location",
+        ]
+    );
+
+    let err = Game::from_config(cfg).unwrap_err();
+    assert!(err.to_string().contains("restock_cycle_hours"));
+}
+
+#[test]
+fn validate_accepts_valid_config() {
+    let cfg = WorldConfig {
+        seed: Some(1),
+        starting_cash: 650_000.0,
+        airports: base_airports(),
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay: GameplayConfig::default(),
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    assert!(cfg.validate().is_empty());
+}
+
+#[test]
+fn world_config_schema_describes_all_fields() {
+    let schema = rusty_runways_core::config::world_config_schema();
+    assert_eq!(schema["title"], "WorldConfig");
+    let props = schema["properties"].as_object().expect("object schema");
+    for field in [
+        "seed",
+        "starting_cash",
+        "airports",
+        "num_airports",
+        "world_style",
+        "airport_dataset",
+        "gameplay",
+        "airplanes",
+        "starting_fleet",
+    ] {
+        assert!(props.contains_key(field), "missing {}", field);
+    }
+    let defs = schema["$defs"].as_object().expect("definitions");
+    assert!(defs.contains_key("AirportConfig"));
+    assert!(defs.contains_key("ManualOrderConfig"));
+    assert!(defs.contains_key("CargoType"));
+}
+
+#[test]
+fn manual_cargo_orders_keep_their_tier() {
+    let json = r#"{"cargo":"Food","weight":100.0,"value":500.0,"deadline_hours":10,"destination_id":1,"tier":"express"}"#;
+    let order: ManualOrderConfig = serde_json::from_str(json).unwrap();
+    let mut airports = base_airports();
+    airports[0].orders = vec![order];
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(1),
+        starting_cash: 650_000.0,
+        airports,
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    let game = Game::from_config(cfg).unwrap();
+    let tier = game.map.airport(0).unwrap().orders[0].tier;
+    assert_eq!(tier, rusty_runways_core::utils::orders::OrderTier::Express);
+}
@@ -0,0 +1,131 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::airport::AirportClass;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("B{}", id),
+        location: Some(Location { x, y: 2_000.0 }),
+        runway_length_m: Some(2_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(5.0),
+        parking_fee_per_hour: Some(10.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Two airports 1,000 km apart and plenty of cash.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.rare_cargo.chance_per_day = 0.0;
+    Game::from_config(WorldConfig {
+        seed: Some(6),
+        starting_cash: 10_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(3, 2_000.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn airstrip_opens_after_construction() {
+    let mut game = game();
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::Construction]));
+    let cash = game.player.cash;
+    let project = game.build_airport(1_500.0, 2_500.0).unwrap();
+    assert_eq!(game.player.cash, cash - 2_500_000.0);
+
+    game.advance(335);
+    assert_eq!(game.map.airports.len(), 2);
+    game.advance(1);
+
+    let events: Vec<GameEvent> = feed.try_iter().collect();
+    assert_eq!(events.len(), 1);
+    let GameEvent::ConstructionComplete {
+        time,
+        construction,
+        airport,
+    } = events[0]
+    else {
+        panic!("expected a construction event");
+    };
+    assert_eq!((time, construction, airport), (336, project, 4));
+    assert!(game.constructions.is_empty());
+
+    let strip = game.map.airport(4).unwrap();
+    assert!(strip.player_built);
+    assert_eq!(strip.runway_length, 800.0);
+    assert_eq!(strip.class(), AirportClass::Strip);
+    assert!(!strip.has_hangar);
+    assert_eq!((strip.landing_fee, strip.parking_fee), (0.0, 0.0));
+    assert!(game.observe().airports.iter().any(|a| a.player_built));
+}
+
+#[test]
+fn sites_must_be_on_the_map_and_apart() {
+    let mut game = game();
+    assert!(matches!(
+        game.build_airport(-5.0, 100.0),
+        Err(GameError::SiteOffMap { .. })
+    ));
+    assert!(matches!(
+        game.build_airport(1_020.0, 2_000.0),
+        Err(GameError::SiteTooClose { .. })
+    ));
+    game.build_airport(5_000.0, 5_000.0).unwrap();
+    assert!(matches!(
+        game.build_airport(5_010.0, 5_000.0),
+        Err(GameError::SiteTooClose { .. })
+    ));
+
+    game.player.cash = 1_000.0;
+    assert!(matches!(
+        game.build_airport(8_000.0, 8_000.0),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+}
+
+#[test]
+fn only_built_runways_can_be_extended() {
+    let mut game = game();
+    assert!(matches!(
+        game.extend_runway(0),
+        Err(GameError::AirportNotOwned { airport_id: 0 })
+    ));
+
+    game.build_airport(5_000.0, 5_000.0).unwrap();
+    game.advance(336);
+    game.extend_runway(4).unwrap();
+    assert!(game.extend_runway(4).is_err());
+    game.advance(168);
+
+    let strip = game.map.airport(4).unwrap();
+    assert_eq!(strip.runway_length, 1_500.0);
+    assert!(strip.has_hangar);
+    assert_eq!(strip.class(), AirportClass::Regional);
+
+    game.construction_settings.max_runway_m = 1_500.0;
+    assert!(game.extend_runway(4).is_err());
+}
@@ -0,0 +1,155 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, ContractsGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::{CargoType, Contract};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("C{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn contract_world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(9),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_300.0),
+            airport(2, 1_600.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// A game with a single hand-made contract for 1000kg of Food from airport 0 to 1.
+fn contract_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let mut game = Game::from_config(contract_world(gameplay)).unwrap();
+    game.contracts = vec![Contract {
+        id: 7,
+        cargo_type: CargoType::Food,
+        origin_id: 0,
+        destination_id: 1,
+        total_weight: 1_000.0,
+        delivered_weight: 0.0,
+        rate_per_kg: 10.0,
+        completion_bonus: 5_000.0,
+        expires_at: 500,
+    }];
+    game
+}
+
+/// Load `weight` kg for contract 7, fly to airport 1, unload and return the cash earned.
+fn haul(game: &mut Game, weight: f32) -> f32 {
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    game.load_contract_cargo(7, weight, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    let cash = game.player.cash;
+    game.unload_all(0).unwrap();
+    let earned = game.player.cash - cash;
+
+    // fly back empty for the next load
+    game.advance(1);
+    game.depart_plane(0, 0).unwrap();
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    earned
+}
+
+#[test]
+fn contracts_are_offered_at_start() {
+    let game = Game::from_config(contract_world(GameplayConfig::default())).unwrap();
+    assert_eq!(game.contracts.len(), ContractsGameplay::default().count);
+    for c in &game.contracts {
+        assert_ne!(c.origin_id, c.destination_id);
+        assert!(c.total_weight >= ContractsGameplay::default().min_weight);
+        assert!(c.rate_per_kg > 0.0);
+    }
+}
+
+#[test]
+fn contract_pays_per_delivery_and_bonus_on_completion() {
+    let mut game = contract_game();
+
+    let first = haul(&mut game, 400.0);
+    assert!((first - 4_000.0).abs() < 1e-2);
+    assert_eq!(game.contracts[0].delivered_weight, 400.0);
+
+    let second = haul(&mut game, 600.0);
+    assert!((second - 6_000.0 - 5_000.0).abs() < 1e-2);
+    assert!(game.contracts.is_empty());
+}
+
+#[test]
+fn late_contract_loads_are_not_paid() {
+    let mut game = contract_game();
+    game.contracts[0].expires_at = 2;
+
+    let earned = haul(&mut game, 400.0);
+    assert_eq!(earned, 0.0);
+    assert_eq!(game.contracts[0].delivered_weight, 0.0);
+}
+
+#[test]
+fn contract_loads_are_checked() {
+    let mut game = contract_game();
+    assert!(matches!(
+        game.load_contract_cargo(3, 100.0, 0),
+        Err(GameError::ContractIdInvalid { id: 3 })
+    ));
+    assert!(matches!(
+        game.load_contract_cargo(7, 2_000.0, 0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+
+    game.contracts[0].origin_id = 2;
+    assert!(matches!(
+        game.load_contract_cargo(7, 100.0, 0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(game.map.airport(0).unwrap().orders.is_empty());
+}
+
+#[test]
+fn invalid_contract_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.contracts.min_weight = 0.0;
+    let paths: Vec<String> = contract_world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(paths, vec!["gameplay.contracts.min_weight"]);
+}
@@ -1,4 +1,4 @@
-use rusty_runways_core::utils::coordinate::Coordinate;
+use rusty_runways_core::utils::coordinate::{Coordinate, CoordinateSystem};
 
 #[test]
 fn coordinate_new_and_update() {
@@ -7,3 +7,49 @@ fn coordinate_new_and_update() {
     assert!((c.x - 15.0).abs() < f32::EPSILON);
     assert!((c.y - 10.0).abs() < f32::EPSILON);
 }
+
+#[test]
+fn planar_distance_is_euclidean() {
+    let a = Coordinate::new(0.0, 0.0);
+    let b = Coordinate::new(3.0, 4.0);
+    assert!((a.distance_to(&b) - 5.0).abs() < 1e-4);
+    assert_eq!(a.projected(), a);
+}
+
+#[test]
+fn geographic_distance_uses_haversine() {
+    // Amsterdam Schiphol -> London Heathrow is roughly 370 km
+    let ams = Coordinate::geographic(52.3086, 4.7639);
+    let lhr = Coordinate::geographic(51.4706, -0.4619);
+    let d = ams.distance_to(&lhr);
+    assert!((d - 370.0).abs() < 5.0, "got {d}");
+
+    // a quarter of the equator
+    let a = Coordinate::geographic(0.0, 0.0);
+    let b = Coordinate::geographic(0.0, 90.0);
+    assert!((a.distance_to(&b) - 10_007.5).abs() < 5.0);
+}
+
+#[test]
+fn geographic_interpolation_follows_great_circle() {
+    let a = Coordinate::geographic(0.0, 0.0);
+    let b = Coordinate::geographic(0.0, 90.0);
+    let mid = a.interpolate(&b, 0.5);
+    assert!((mid.x - 45.0).abs() < 1e-3);
+    assert!(mid.y.abs() < 1e-3);
+    assert_eq!(mid.system, CoordinateSystem::Geographic);
+}
+
+#[test]
+fn planar_coordinates_serialize_without_system() {
+    let json = serde_json::to_string(&Coordinate::new(1.0, 2.0)).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0}"#);
+
+    let geo: Coordinate =
+        serde_json::from_str(r#"{"x":4.76,"y":52.31,"system":"geographic"}"#).unwrap();
+    assert_eq!(geo.system, CoordinateSystem::Geographic);
+    assert_eq!(
+        "geographic".parse::<CoordinateSystem>().unwrap(),
+        CoordinateSystem::Geographic
+    );
+}
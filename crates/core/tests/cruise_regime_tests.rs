@@ -0,0 +1,110 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::{AirplaneStatus, CruiseRegime};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("K{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Airport 1 is 800km from the start, airport 2 is 1800km away.
+fn cruise_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    let cfg = WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_800.0),
+            airport(2, 2_800.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+/// Depart to airport 1 and return (flight hours, fuel burned).
+fn hop(regime: CruiseRegime) -> (u64, f32) {
+    let mut game = cruise_game();
+    let before = game.airplanes[0].current_fuel;
+    game.depart_plane_with_speed(0, 1, regime).unwrap();
+    let AirplaneStatus::InTransit { total_hours, .. } = game.airplanes[0].status else {
+        panic!("plane should be in transit");
+    };
+    (total_hours, before - game.airplanes[0].current_fuel)
+}
+
+#[test]
+fn regimes_trade_speed_for_fuel() {
+    let (normal_hours, normal_fuel) = hop(CruiseRegime::Normal);
+    let (econ_hours, econ_fuel) = hop(CruiseRegime::Economy);
+    let (dash_hours, dash_fuel) = hop(CruiseRegime::Dash);
+
+    assert!(econ_hours >= normal_hours);
+    assert!(econ_fuel < normal_fuel);
+    assert!(dash_hours < normal_hours);
+    assert!(dash_fuel > normal_fuel);
+}
+
+#[test]
+fn economy_stretches_range() {
+    let mut game = cruise_game();
+    assert!(matches!(
+        game.depart_plane(0, 2),
+        Err(GameError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        game.depart_plane_with_speed(0, 2, CruiseRegime::Dash),
+        Err(GameError::OutOfRange { .. })
+    ));
+    assert_eq!(game.airplanes[0].cruise, CruiseRegime::Normal);
+
+    game.depart_plane_with_speed(0, 2, CruiseRegime::Economy)
+        .unwrap();
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    assert_eq!(game.airplanes[0].location, game.map.coord(2).unwrap());
+    assert_eq!(game.airplanes[0].cruise, CruiseRegime::Normal);
+}
+
+#[test]
+fn speed_is_parsed_from_commands() {
+    let mut game = cruise_game();
+    game.execute_str("DEPART PLANE 0 1 SPEED DASH").unwrap();
+    assert_eq!(game.airplanes[0].cruise, CruiseRegime::Dash);
+
+    let mut game = cruise_game();
+    assert!(matches!(
+        game.execute_str("DEPART PLANE 0 1 SPEED WARP"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
@@ -0,0 +1,143 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportDatasetConfig, GameplayConfig, WorldConfig};
+use rusty_runways_core::utils::coordinate::CoordinateSystem;
+use rusty_runways_core::utils::dataset::{project_positions, read_airport_csv, select_airports};
+use rusty_runways_core::utils::map::Map;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+const SAMPLE: &str = "\
+ident,type,name,latitude_deg,longitude_deg,iso_country,gps_code,runway_length_ft
+EHAM,large_airport,Amsterdam Airport Schiphol,52.3086,4.7639,NL,EHAM,12467
+EHRD,medium_airport,Rotterdam The Hague Airport,51.9569,4.4372,NL,EHRD,
+EBBR,large_airport,Brussels Airport,50.9014,4.4844,BE,EBBR,
+NL-0001,heliport,Rotterdam Heliport,51.9,4.5,NL,,
+EHTX,small_airport,Texel Airport,53.1153,4.8336,NL,EHTX,
+";
+
+fn sample_file() -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    file.write_all(SAMPLE.as_bytes()).expect("write csv");
+    file
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+#[test]
+fn read_airport_csv_skips_heliports_and_maps_runways() {
+    let file = sample_file();
+    let rows = read_airport_csv(file.path()).expect("csv parses");
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0].code, "EHAM");
+    assert!((rows[0].runway_length - 12_467.0 * 0.3048).abs() < 1.0);
+    // no runway column value: derived from the airport type
+    assert_eq!(rows[1].runway_length, 2_200.0);
+    assert_eq!(rows[2].country, "BE");
+}
+
+#[test]
+fn read_airport_csv_requires_coordinates() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(b"ident,name\nEHAM,Schiphol\n").unwrap();
+    let err = read_airport_csv(file.path()).unwrap_err();
+    assert!(err.to_string().contains("latitude_deg"));
+}
+
+#[test]
+fn select_airports_filters_countries_and_keeps_largest() {
+    let rows = read_airport_csv(sample_file().path()).unwrap();
+    let nl = select_airports(rows.clone(), &["nl".to_string()], None);
+    assert!(nl.iter().all(|a| a.country == "NL"));
+    assert_eq!(nl.len(), 3);
+
+    let capped = select_airports(rows, &[], Some(2));
+    let codes: Vec<&str> = capped.iter().map(|a| a.code.as_str()).collect();
+    assert_eq!(codes, vec!["EHAM", "EBBR"]);
+}
+
+#[test]
+fn projection_preserves_real_distances() {
+    // Amsterdam -> Brussels is roughly 160 km
+    let coords = project_positions(&[(52.3086, 4.7639), (50.9014, 4.4844)]);
+    let d = distance((coords[0].x, coords[0].y), (coords[1].x, coords[1].y));
+    assert!((150.0..175.0).contains(&d), "distance was {}", d);
+}
+
+#[test]
+fn map_from_airport_csv_derives_fees_and_codes() {
+    let map = Map::from_airport_csv(sample_file().path(), 3).expect("map builds");
+    assert_eq!(map.num_airports, 4);
+    let (schiphol, _) = map.airports.get(0).unwrap();
+    assert_eq!(schiphol.code.as_deref(), Some("EHAM"));
+    assert_eq!(schiphol.name, "Amsterdam Airport Schiphol");
+    assert!(schiphol.landing_fee >= 6.1);
+    assert!(map.airports.iter().all(|(a, _)| a.orders.is_empty()));
+}
+
+#[test]
+fn geographic_dataset_keeps_lat_lon() {
+    let rows = read_airport_csv(sample_file().path()).unwrap();
+    let map = Map::from_dataset_airports(rows, 3, CoordinateSystem::Geographic).unwrap();
+    assert_eq!(map.coordinate_system(), CoordinateSystem::Geographic);
+
+    let (_, schiphol) = *map.airports.get(0).unwrap();
+    assert!((schiphol.y - 52.3086).abs() < 1e-3);
+    assert!((schiphol.x - 4.7639).abs() < 1e-3);
+    // Schiphol -> Rotterdam is roughly 45 km great-circle
+    let (_, rotterdam) = *map.airports.get(1).unwrap();
+    let d = schiphol.distance_to(&rotterdam);
+    assert!((40.0..50.0).contains(&d), "distance was {}", d);
+}
+
+#[test]
+fn from_config_imports_dataset() {
+    let file = sample_file();
+    let cfg = WorldConfig {
+        seed: Some(2),
+        starting_cash: 650_000.0,
+        airports: Vec::new(),
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: Some(AirportDatasetConfig {
+            path: file.path().to_string_lossy().into_owned(),
+            countries: vec!["NL".into()],
+            max_airports: None,
+            coordinates: Default::default(),
+        }),
+        gameplay: GameplayConfig::default(),
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    let game = Game::from_config(cfg).expect("dataset config builds");
+    assert_eq!(game.airports().len(), 3);
+    assert!(game.airports().iter().any(|(a, _)| !a.orders.is_empty()));
+}
+
+#[test]
+fn from_config_rejects_dataset_with_num_airports() {
+    let file = sample_file();
+    let cfg = WorldConfig {
+        seed: Some(2),
+        starting_cash: 650_000.0,
+        airports: Vec::new(),
+        num_airports: Some(4),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: Some(AirportDatasetConfig {
+            path: file.path().to_string_lossy().into_owned(),
+            countries: Vec::new(),
+            max_airports: None,
+            coordinates: Default::default(),
+        }),
+        gameplay: GameplayConfig::default(),
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    let err = Game::from_config(cfg).unwrap_err();
+    assert!(err.to_string().contains("airport_dataset"));
+}
@@ -0,0 +1,126 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use rusty_runways_core::utils::demand::{PassengerDemand, load_factor};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32, runway: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("D{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(runway),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Two big airports close together, a small one near them and a big one far away.
+fn demand_game(gameplay: GameplayConfig) -> Game {
+    let cfg = WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0, 4_000.0),
+            airport(1, 1_300.0, 4_000.0),
+            airport(2, 1_300.0, 600.0),
+            airport(3, 6_000.0, 4_000.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn steady() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.passenger_demand.daily_variation = 0.0;
+    gameplay
+}
+
+#[test]
+fn demand_follows_gravity_model() {
+    let game = demand_game(steady());
+    // 40 * 4 * 4 / (300 / 100)
+    assert_eq!(game.route_demand(0, 1).unwrap(), 213);
+    assert_eq!(game.route_demand(1, 0).unwrap(), 213);
+    assert!(game.route_demand(1, 2).unwrap() < game.route_demand(1, 0).unwrap());
+    assert!(game.route_demand(0, 3).unwrap() < game.route_demand(0, 1).unwrap());
+}
+
+#[test]
+fn demand_is_redrawn_daily_within_variation() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.passenger_demand.daily_variation = 0.2;
+    let mut game = demand_game(gameplay);
+    assert_eq!(game.passenger_demand.day, 0);
+    let mut seen = Vec::new();
+    for day in 1..=5 {
+        game.advance(24);
+        assert_eq!(game.passenger_demand.day, day);
+        let demand = game.route_demand(0, 1).unwrap();
+        assert!(
+            (170..=256).contains(&demand),
+            "demand {} out of range",
+            demand
+        );
+        seen.push(demand);
+    }
+    assert!(seen.iter().any(|d| *d != seen[0]));
+}
+
+#[test]
+fn demand_is_deterministic_per_seed_and_day() {
+    let game = demand_game(GameplayConfig::default());
+    let airports = game.map.order_airport_infos();
+    let again = PassengerDemand::generate(3, 0, &airports, &game.demand_settings);
+    assert_eq!(game.passenger_demand, again);
+    let other_day = PassengerDemand::generate(3, 1, &airports, &game.demand_settings);
+    assert_ne!(game.passenger_demand, other_day);
+}
+
+#[test]
+fn zero_scale_disables_demand() {
+    let mut gameplay = steady();
+    gameplay.passenger_demand.scale = 0.0;
+    let game = demand_game(gameplay);
+    assert!(game.passenger_demand.routes().is_empty());
+    assert_eq!(game.route_demand(0, 1).unwrap(), 0);
+}
+
+#[test]
+fn load_factor_is_capped_by_seats() {
+    let game = demand_game(steady());
+    assert_eq!(game.route_load_factor(0, 1, 100).unwrap(), 1.0);
+    let lf = game.route_load_factor(0, 1, 426).unwrap();
+    assert!((lf - 0.5).abs() < 1e-6);
+    assert_eq!(load_factor(10, 0), 0.0);
+}
+
+#[test]
+fn unknown_airport_is_rejected() {
+    let game = demand_game(steady());
+    assert!(matches!(
+        game.route_demand(0, 9),
+        Err(GameError::AirportIdInvalid { id: 9 })
+    ));
+}
+
+#[test]
+fn invalid_demand_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.passenger_demand.daily_variation = 1.0;
+    let paths: Vec<String> = gameplay.validate().into_iter().map(|i| i.path).collect();
+    assert_eq!(paths, vec!["gameplay.passenger_demand.daily_variation"]);
+}
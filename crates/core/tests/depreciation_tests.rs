@@ -0,0 +1,90 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::utils::airplanes::airplane::{
+    Airplane, RESALE_FLOOR_SHARE, RESALE_NEW_SHARE,
+};
+use rusty_runways_core::utils::airplanes::models::{AirplaneModel, AirplaneStatus};
+use rusty_runways_core::utils::coordinate::Coordinate;
+use rusty_runways_core::utils::errors::GameError;
+
+fn plane() -> Airplane {
+    Airplane::new(0, AirplaneModel::FalconJet, Coordinate::new(0.0, 0.0))
+}
+
+#[test]
+fn new_plane_resells_at_new_share() {
+    let plane = plane();
+    let price = plane.specs.purchase_price;
+    assert!((plane.resale_value(0) - price * RESALE_NEW_SHARE).abs() < 1e-2);
+}
+
+#[test]
+fn hours_age_and_condition_reduce_value() {
+    let fresh = plane().resale_value(0);
+
+    let mut flown = plane();
+    flown.airframe_hours = 2_000;
+    assert!(flown.resale_value(0) < fresh);
+
+    let old = plane();
+    assert!(old.resale_value(24 * 365) < fresh);
+
+    let mut worn = plane();
+    worn.hours_since_maintenance = 400;
+    let overdue = worn.resale_value(0);
+    assert!(overdue < fresh);
+    worn.needs_maintenance = true;
+    assert!(worn.resale_value(0) < overdue);
+}
+
+#[test]
+fn value_never_drops_below_floor() {
+    let mut wreck = plane();
+    wreck.airframe_hours = 1_000_000;
+    wreck.needs_maintenance = true;
+    let floor = wreck.specs.purchase_price * RESALE_FLOOR_SHARE;
+    assert!((wreck.resale_value(1_000_000) - floor).abs() < 1e-2);
+}
+
+#[test]
+fn flights_add_airframe_hours_and_lower_the_appraisal() {
+    let mut game = Game::new(1, Some(5), 1_000_000.0);
+    let before = game.appraise_plane(0).unwrap();
+
+    let dest = (0..game.map.airports.len())
+        .find(|&id| {
+            let (airport, coord) = game.map.airports.get(id).unwrap();
+            game.airplanes[0].can_fly_to(airport, coord).is_ok()
+        })
+        .expect("starter plane reaches another airport");
+    game.depart_plane(0, dest).unwrap();
+    while matches!(game.airplanes[0].status, AirplaneStatus::InTransit { .. }) {
+        game.advance(1);
+    }
+
+    assert!(game.airplanes[0].airframe_hours > 0);
+    assert!(game.appraise_plane(0).unwrap() < before);
+}
+
+#[test]
+fn bought_planes_age_from_purchase_time() {
+    let mut game = Game::new(1, Some(5), 100_000_000.0);
+    game.advance(100);
+    let home = game
+        .map
+        .airports
+        .iter()
+        .position(|(_, c)| *c == game.airplanes[0].location)
+        .unwrap();
+    game.buy_plane(&"SparrowLight".to_string(), home).unwrap();
+    let bought = game.airplanes.last().unwrap();
+    assert_eq!(bought.acquired_at, 100);
+}
+
+#[test]
+fn appraising_unknown_plane_fails() {
+    let game = Game::new(1, Some(5), 1_000_000.0);
+    assert!(matches!(
+        game.appraise_plane(42),
+        Err(GameError::PlaneIdInvalid { id: 42 })
+    ));
+}
@@ -17,13 +17,20 @@ fn show_and_list_helpers_execute() {
     game.list_airports(false);
     game.list_airport(0, true).unwrap();
     // Insert a passenger order to exercise formatting branch
-    game.map.airports[0].0.orders.push(Order {
+    game.map.airport_mut(0).unwrap().orders.push(Order {
         id: 999,
         payload: OrderPayload::Passengers { count: 12 },
         value: 7_500.0,
         deadline: 10,
         origin_id: 0,
         destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
     });
     game.list_airports(true);
     game.list_airplane(0).unwrap();
@@ -36,21 +43,22 @@ fn show_and_list_helpers_execute() {
 
     let plane_id = 0usize;
     let origin_loc = game.airplanes[plane_id].location;
-    let origin_idx = game
+    let origin_id = game
         .airports()
         .iter()
-        .position(|(_, c)| *c == origin_loc)
+        .find(|(_, c)| *c == origin_loc)
+        .map(|(a, _)| a.id)
         .expect("origin airport must exist");
     let payload_cap = game.airplanes[plane_id].specs.payload_capacity;
 
     let mut candidate: Option<(usize, usize)> = None;
     for _ in 0..5 {
-        for order in &game.map.airports[origin_idx].0.orders {
+        for order in &game.map.airport(origin_id).unwrap().orders {
             let Some(weight) = order.cargo_weight() else {
                 continue;
             };
             if weight <= payload_cap {
-                let (airport, coord) = &game.airports()[order.destination_id];
+                let (airport, coord) = game.map.airports.get(order.destination_id).unwrap();
                 if game.planes()[plane_id].can_fly_to(airport, coord).is_ok() {
                     candidate = Some((order.id, order.destination_id));
                     break;
@@ -0,0 +1,122 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::airplane::Airplane;
+use rusty_runways_core::utils::airplanes::models::{AirplaneModel, AirplaneStatus};
+use rusty_runways_core::utils::coordinate::Coordinate;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("E{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(2_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// A single plane of `model` at airport 0, 300 km from airport 1.
+fn game(model: &str, fuel_level: f32) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    let cfg = WorldConfig {
+        seed: Some(8),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: model.into(),
+            home_airport: 0,
+            fuel_level,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn charging_needs_a_charger_and_takes_time() {
+    let mut game = game("VoltCommuter", 0.5);
+    assert!(game.charge_plane(0).is_err());
+
+    game.install_charger(0).unwrap();
+    assert!(game.map.airport(0).unwrap().has_charger);
+    assert!((game.player.cash - 600_000.0).abs() < 1.0);
+    assert!(game.install_charger(0).is_err());
+
+    // 450 kWh at 250 kW
+    assert_eq!(game.charge_plane(0).unwrap(), 2);
+    assert!((game.player.cash - (600_000.0 - 450.0 * 0.3)).abs() < 1.0);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Charging);
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Charging);
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!((game.airplanes[0].current_fuel - 900.0).abs() < 1e-3);
+    assert!(game.charge_plane(0).is_err());
+}
+
+#[test]
+fn fuel_and_batteries_do_not_mix() {
+    let mut electric = game("VoltCommuter", 0.5);
+    assert!(electric.refuel_plane(0).is_err());
+
+    let mut fuel = game("SparrowLight", 0.5);
+    fuel.install_charger(0).unwrap();
+    assert!(fuel.charge_plane(0).is_err());
+}
+
+#[test]
+fn battery_weight_is_fixed_and_load_cuts_range() {
+    let coord = Coordinate::new(0.0, 0.0);
+    let mut plane = Airplane::new(0, AirplaneModel::VoltCommuter, coord);
+    let full = plane.takeoff_weight();
+    let empty_range = plane.max_range();
+
+    plane.current_fuel = 0.0;
+    assert_eq!(plane.takeoff_weight(), full);
+
+    plane.current_fuel = plane.specs.fuel_capacity;
+    plane.current_payload = plane.specs.payload_capacity;
+    assert!(plane.max_range() < empty_range);
+    assert!((empty_range - plane.specs.full_range(0.0)).abs() < 1.0);
+}
+
+#[test]
+fn electric_flights_emit_no_co2() {
+    let mut game = game("VoltCommuter", 1.0);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!(game.airplanes[0].current_fuel < 900.0);
+    assert_eq!(game.emissions.total_tons, 0.0);
+}
+
+#[test]
+fn charger_power_must_be_positive() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.electric.charger_kw = 0.0;
+    let issues = gameplay.validate();
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.path == "gameplay.electric.charger_kw")
+    );
+}
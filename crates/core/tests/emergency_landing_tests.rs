@@ -0,0 +1,159 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::order::{EMERGENCY_LANDING_DAMAGE, OrderPayload};
+use rusty_runways_core::utils::orders::{CargoType, Order};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("E{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Airport 1 is 800km east of the start and airport 2 sits 300km along the way.
+fn emergency_game(gameplay: GameplayConfig) -> Game {
+    let cfg = WorldConfig {
+        seed: Some(6),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_800.0),
+            airport(2, 1_300.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn calm_gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay
+}
+
+fn electronics(id: usize) -> Order {
+    Order {
+        id,
+        payload: OrderPayload::Cargo {
+            cargo_type: CargoType::Electronics,
+            weight: 50.0,
+        },
+        value: 10_000.0,
+        deadline: 200,
+        origin_id: 0,
+        destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
+    }
+}
+
+#[test]
+fn emergency_landing_heads_for_the_nearest_airport_and_charges_the_fee() {
+    let mut game = emergency_game(calm_gameplay());
+    let fee = GameplayConfig::default().fuel.emergency_landing_fee;
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    let cash = game.player.cash;
+
+    // 260km out, airport 2 is 40km ahead
+    assert_eq!(game.emergency_land(0).unwrap(), 2);
+    assert!((cash - game.player.cash - fee).abs() < 1e-3);
+    assert!(matches!(
+        game.airplanes[0].status,
+        AirplaneStatus::InTransit {
+            destination: 2,
+            hours_remaining: 1,
+            ..
+        }
+    ));
+
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!((game.airplanes[0].location.x - 1_300.0).abs() < 1e-2);
+}
+
+#[test]
+fn emergency_landing_needs_a_plane_in_the_air() {
+    let mut game = emergency_game(calm_gameplay());
+    assert!(matches!(
+        game.emergency_land(0),
+        Err(GameError::PlaneNotReady { .. })
+    ));
+    assert!(matches!(
+        game.emergency_land(5),
+        Err(GameError::PlaneIdInvalid { id: 5 })
+    ));
+}
+
+#[test]
+fn emergency_landing_can_damage_fragile_cargo() {
+    let mut gameplay = calm_gameplay();
+    gameplay.fuel.emergency_damage_chance = 1.0;
+    let mut game = emergency_game(gameplay);
+    game.airplanes[0].load_order(electronics(1)).unwrap();
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    game.emergency_land(0).unwrap();
+    assert!((game.airplanes[0].manifest[0].damage - EMERGENCY_LANDING_DAMAGE).abs() < 1e-6);
+
+    let mut gameplay = calm_gameplay();
+    gameplay.fuel.emergency_damage_chance = 0.0;
+    let mut game = emergency_game(gameplay);
+    game.airplanes[0].load_order(electronics(1)).unwrap();
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    game.emergency_land(0).unwrap();
+    assert_eq!(game.airplanes[0].manifest[0].damage, 0.0);
+}
+
+#[test]
+fn running_dry_in_flight_triggers_an_emergency_landing() {
+    let mut gameplay = calm_gameplay();
+    gameplay.fuel.headwind_chance = 1.0;
+    gameplay.fuel.headwind_burn_minutes = 600.0;
+    let fee = gameplay.fuel.emergency_landing_fee;
+    let mut game = emergency_game(gameplay);
+    game.depart_plane(0, 1).unwrap();
+    let cash = game.player.cash;
+    game.advance(1);
+
+    assert!(matches!(
+        game.airplanes[0].status,
+        AirplaneStatus::InTransit { destination: 2, .. }
+    ));
+    // a plain headwind diversion is free, running dry costs the emergency fee
+    assert!((cash - game.player.cash - fee).abs() < 1e-3);
+}
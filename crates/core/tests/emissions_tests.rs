@@ -0,0 +1,106 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::statistics::Emissions;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("E{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn game(carbon_tax_per_ton: f32) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.pilots.required = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.taxes.carbon_tax_per_ton = carbon_tax_per_ton;
+    let cfg = WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_400.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn a_liter_of_fuel_emits_about_two_and_a_half_kilos() {
+    assert!((Emissions::co2_tons(1_000.0) - 2.528).abs() < 1e-3);
+}
+
+#[test]
+fn flights_add_up_in_the_daily_stats() {
+    let mut game = game(0.0);
+    let fuel = game.airplanes[0].current_fuel;
+    game.depart_plane(0, 1).unwrap();
+    let burned = fuel - game.airplanes[0].current_fuel;
+    let co2 = Emissions::co2_tons(burned);
+    assert!(co2 > 0.0);
+    assert!((game.emissions.total_tons - co2).abs() < 1e-4);
+
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert!((day.co2_tons - co2).abs() < 1e-4);
+    assert!((day.total_co2_tons - co2).abs() < 1e-4);
+    assert_eq!(day.carbon_tax, 0.0);
+
+    // a quiet day adds nothing, the total stays
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert_eq!(day.co2_tons, 0.0);
+    assert!((day.total_co2_tons - co2).abs() < 1e-4);
+}
+
+#[test]
+fn carbon_tax_is_charged_per_ton() {
+    let mut taxed = game(100.0);
+    taxed.depart_plane(0, 1).unwrap();
+    let co2 = taxed.emissions.today_tons;
+    taxed.advance(24);
+    let day = taxed.stats.last().unwrap();
+    assert!((day.carbon_tax - co2 * 100.0).abs() < 1e-2);
+
+    let mut untaxed = game(0.0);
+    untaxed.depart_plane(0, 1).unwrap();
+    untaxed.advance(24);
+    let gap = untaxed.player.cash - taxed.player.cash;
+    assert!((gap - co2 * 100.0).abs() < 1e-1);
+}
+
+#[test]
+fn negative_carbon_tax_is_rejected() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.taxes.carbon_tax_per_ton = -1.0;
+    assert!(
+        gameplay
+            .validate()
+            .iter()
+            .any(|i| i.path == "gameplay.taxes.carbon_tax_per_ton")
+    );
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "msgpack")]
+
+use rusty_runways_core::Game;
+use rusty_runways_core::encoding::{EncodingError, from_msgpack};
+
+#[test]
+fn state_round_trips() {
+    let mut game = Game::new(3, Some(6), 650_000.0);
+    game.advance(30);
+    let bytes = game.state_msgpack().unwrap();
+    let mut restored = Game::from_state_msgpack(&bytes).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&restored).unwrap(),
+        serde_json::to_value(&game).unwrap()
+    );
+    // the restored game keeps simulating the same way
+    game.advance(48);
+    restored.advance(48);
+    assert_eq!(restored.player.cash, game.player.cash);
+    assert_eq!(restored.time, game.time);
+}
+
+#[test]
+fn observation_is_smaller_than_json() {
+    let game = Game::new(3, Some(20), 650_000.0);
+    let bytes = game.observation_msgpack().unwrap();
+    let json = serde_json::to_vec(&game.observe()).unwrap();
+    assert!(bytes.len() < json.len());
+
+    let value: serde_json::Value = from_msgpack(&bytes).unwrap();
+    assert_eq!(value["airports"].as_array().unwrap().len(), 20);
+}
+
+#[test]
+fn garbage_is_rejected() {
+    assert!(matches!(
+        Game::from_state_msgpack(b"not msgpack"),
+        Err(EncodingError::Decode(_))
+    ));
+}
@@ -65,7 +65,7 @@ fn other_error_displays() {
         (GameError::AirportIdInvalid { id: 3 }, "Airport with id 3"),
         (
             GameError::AirportLocationInvalid {
-                location: Coordinate { x: 1.0, y: 2.0 },
+                location: Coordinate::new(1.0, 2.0),
             },
             "No airport found",
         ),
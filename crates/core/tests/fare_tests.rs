@@ -0,0 +1,150 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::demand::priced_demand;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("R{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(4_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Two big airports 300 km apart with 213 passengers a day each way and a 78-seat
+/// regional jet at airport 0.
+fn route_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.passenger_demand.daily_variation = 0.0;
+    gameplay.passenger_demand.price_elasticity = 1.0;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    let cfg = WorldConfig {
+        seed: Some(2),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "CometRegional".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn land(game: &mut Game) {
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+}
+
+#[test]
+fn elasticity_scales_demand_with_price() {
+    assert_eq!(priced_demand(100, 200.0, 200.0, 1.5), 100);
+    assert_eq!(priced_demand(100, 400.0, 200.0, 1.0), 50);
+    assert_eq!(priced_demand(100, 800.0, 200.0, 0.5), 50);
+    // cheap tickets win passengers, but at most twice the demand
+    assert_eq!(priced_demand(100, 150.0, 200.0, 1.0), 133);
+    assert_eq!(priced_demand(100, 1.0, 200.0, 1.0), 200);
+}
+
+#[test]
+fn departure_sells_free_seats_at_the_fare() {
+    let mut game = route_game();
+    let reference = game.reference_fare(0, 1).unwrap();
+    assert!((reference - 300.0 * 9.5).abs() < 0.5);
+    game.set_route_fare(0, 1, reference).unwrap();
+
+    let cash = game.player.cash;
+    game.depart_plane(0, 1).unwrap();
+    assert_eq!(game.airplanes[0].scheduled_passengers, 78);
+    assert!((game.player.cash - cash - 78.0 * reference).abs() < 1.0);
+
+    let route = game.route_fare(0, 1).unwrap();
+    assert_eq!(route.sold_today, 78);
+    assert_eq!(route.departures, 1);
+    assert_eq!(route.last_load_factor, 1.0);
+    assert_eq!(game.route_tickets_left(0, 1).unwrap(), 213 - 78);
+
+    land(&mut game);
+    assert_eq!(game.airplanes[0].scheduled_passengers, 0);
+}
+
+#[test]
+fn high_fare_lowers_load_factor() {
+    let mut game = route_game();
+    let reference = game.reference_fare(0, 1).unwrap();
+    game.set_route_fare(0, 1, reference * 4.0).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    let route = game.route_fare(0, 1).unwrap();
+    assert_eq!(route.sold_today, 53);
+    assert!((route.last_load_factor - 53.0 / 78.0).abs() < 1e-6);
+}
+
+#[test]
+fn demand_is_shared_by_the_days_departures_and_resets() {
+    let mut game = route_game();
+    let reference = game.reference_fare(0, 1).unwrap();
+    game.set_route_fare(0, 1, reference).unwrap();
+    game.route_fares[0].sold_today = 200;
+    game.depart_plane(0, 1).unwrap();
+    assert_eq!(game.airplanes[0].scheduled_passengers, 13);
+
+    land(&mut game);
+    game.advance(24);
+    assert_eq!(game.route_fare(0, 1).unwrap().sold_today, 0);
+    assert_eq!(game.route_tickets_left(0, 1).unwrap(), 213);
+}
+
+#[test]
+fn routes_without_fare_sell_nothing() {
+    let mut game = route_game();
+    let cash = game.player.cash;
+    game.depart_plane(0, 1).unwrap();
+    assert_eq!(game.airplanes[0].scheduled_passengers, 0);
+    assert!(game.player.cash <= cash);
+}
+
+#[test]
+fn fares_are_validated() {
+    let mut game = route_game();
+    assert!(matches!(
+        game.set_route_fare(0, 5, 100.0),
+        Err(GameError::AirportIdInvalid { id: 5 })
+    ));
+    assert!(matches!(
+        game.set_route_fare(0, 0, 100.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.set_route_fare(0, 1, 0.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(game.clear_route_fare(0, 1).is_err());
+
+    game.execute_str("SET FARE 0 1 500").unwrap();
+    assert_eq!(game.route_fare(0, 1).unwrap().fare, 500.0);
+    game.execute_str("CLEAR FARE 0 1").unwrap();
+    assert!(game.route_fare(0, 1).is_none());
+}
@@ -0,0 +1,79 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::features::{WORLD_SIZE, log_scale};
+use rusty_runways_core::utils::coordinate::Coordinate;
+
+fn in_unit(x: f32) -> bool {
+    (0.0..=1.0).contains(&x)
+}
+
+#[test]
+fn features_stay_in_range() {
+    for airports in [4, 40] {
+        let mut game = Game::new(5, Some(airports), 650_000.0);
+        game.advance(30);
+        let features = game.features();
+        assert_eq!(features.scales.world_size, WORLD_SIZE);
+        assert!(!features.scales.geographic);
+        assert!(in_unit(features.time_of_day));
+        assert!(
+            features
+                .airports
+                .iter()
+                .all(|a| in_unit(a.x) && in_unit(a.y) && in_unit(a.runway))
+        );
+        assert!(features.airports.iter().any(|a| a.runway == 1.0));
+        assert!(
+            features
+                .planes
+                .iter()
+                .all(|p| in_unit(p.fuel) && in_unit(p.payload) && in_unit(p.flight_progress))
+        );
+        assert!(!features.orders.is_empty());
+        assert!(features.orders.iter().all(|o| in_unit(o.deadline)
+            && o.distance > 0.0
+            && o.distance < 1.5
+            && o.weight > 0.0
+            && o.weight <= 1.0));
+    }
+}
+
+#[test]
+fn features_follow_the_game() {
+    let game = Game::new(5, Some(10), 650_000.0);
+    let features = game.features();
+    let scales = game.feature_scales();
+    assert_eq!(features.scales, scales);
+    assert_eq!(features.cash, log_scale(game.player.cash));
+    assert_eq!(features.airports.len(), game.airports().len());
+    assert_eq!(features.planes.len(), game.planes().len());
+    let waiting: usize = game.airports().iter().map(|(a, _)| a.orders.len()).sum();
+    assert_eq!(features.orders.len(), waiting);
+
+    let order = &features.orders[0];
+    let raw = game
+        .airports()
+        .iter()
+        .flat_map(|(a, _)| a.orders.iter())
+        .find(|o| o.id == order.id)
+        .unwrap();
+    assert_eq!(
+        order.deadline,
+        raw.deadline as f32 / scales.max_deadline as f32
+    );
+}
+
+#[test]
+fn log_scale_keeps_the_sign() {
+    assert_eq!(log_scale(0.0), 0.0);
+    assert!((log_scale(1_000_000.0) - 13.8155).abs() < 1e-3);
+    assert_eq!(log_scale(-1_000_000.0), -log_scale(1_000_000.0));
+}
+
+#[test]
+fn geographic_positions_scale_from_degrees() {
+    let scales = Game::new(5, Some(4), 650_000.0).feature_scales();
+    let (x, y) = scales.position(&Coordinate::geographic(-90.0, 180.0));
+    assert_eq!((x, y), (1.0, 0.0));
+    let (x, y) = scales.position(&Coordinate::new(2_500.0, 10_000.0));
+    assert_eq!((x, y), (0.25, 1.0));
+}
@@ -0,0 +1,121 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("F{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn sparrow() -> StartingPlaneConfig {
+    StartingPlaneConfig {
+        model: "SparrowLight".into(),
+        home_airport: 0,
+        fuel_level: 1.0,
+    }
+}
+
+fn world(gameplay: GameplayConfig, planes: usize) -> WorldConfig {
+    WorldConfig {
+        seed: Some(8),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_520.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![sparrow(); planes],
+        scripts: Vec::new(),
+    }
+}
+
+fn gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay
+}
+
+#[test]
+fn idle_fleet_pays_fixed_costs_every_day() {
+    let mut game = Game::from_config(world(gameplay(), 2)).unwrap();
+    let costs = game.fixed_costs.clone();
+    let salary = game.pilot_settings.salary_per_day;
+    let cash = game.player.cash;
+    game.advance(24);
+
+    let day = game.stats.last().unwrap();
+    assert!((day.salaries - 2.0 * salary).abs() < 1e-2);
+    assert!((day.hangarage - 2.0 * costs.hangarage_per_plane).abs() < 1e-2);
+    assert!((day.overhead - (costs.office_base + 2.0 * costs.office_per_plane)).abs() < 1e-2);
+
+    let fixed = day.salaries + day.hangarage + day.overhead;
+    assert!((day.expenses - fixed).abs() < 1e-2);
+    assert!((cash - game.player.cash - fixed).abs() < 1e-2);
+}
+
+#[test]
+fn bigger_fleets_cost_more_to_keep() {
+    let mut small = Game::from_config(world(gameplay(), 1)).unwrap();
+    let mut large = Game::from_config(world(gameplay(), 4)).unwrap();
+    small.advance(24);
+    large.advance(24);
+    let small = small.stats.last().unwrap();
+    let large = large.stats.last().unwrap();
+    assert!(large.hangarage > small.hangarage);
+    assert!(large.overhead > small.overhead);
+}
+
+#[test]
+fn fixed_costs_can_be_switched_off() {
+    let mut gameplay = gameplay();
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.pilots.required = false;
+    let mut game = Game::from_config(world(gameplay, 3)).unwrap();
+    let cash = game.player.cash;
+    game.advance(48);
+    assert_eq!(game.player.cash, cash);
+}
+
+#[test]
+fn old_saves_have_no_fixed_costs() {
+    let game = Game::from_config(world(gameplay(), 1)).unwrap();
+    let mut json = serde_json::to_value(&game).unwrap();
+    json.as_object_mut().unwrap().remove("fixed_costs");
+    let game: Game = serde_json::from_value(json).unwrap();
+    assert_eq!(game.fixed_costs.overhead(10), 0.0);
+    assert_eq!(game.fixed_costs.hangarage(10), 0.0);
+}
+
+#[test]
+fn invalid_fixed_costs_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.fixed_costs.hangarage_per_plane = -1.0;
+    gameplay.fixed_costs.office_per_plane = -5.0;
+    let paths: Vec<String> = world(gameplay, 1)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.fixed_costs.hangarage_per_plane",
+            "gameplay.fixed_costs.office_per_plane"
+        ]
+    );
+}
@@ -0,0 +1,120 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("G{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn sparrow(home_airport: usize, fuel_level: f32) -> StartingPlaneConfig {
+    StartingPlaneConfig {
+        model: "SparrowLight".into(),
+        home_airport,
+        fuel_level,
+    }
+}
+
+/// Three half-full SparrowLights parked at two airports.
+fn group_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(9),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![sparrow(0, 0.5), sparrow(0, 0.5), sparrow(1, 0.5)],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn planes_can_join_and_leave_groups() {
+    let mut game = group_game();
+    game.group_plane(0, "Feeders").unwrap();
+    game.group_plane(2, "feeders").unwrap();
+    game.group_plane(2, "heavies").unwrap();
+
+    assert_eq!(game.group_planes("FEEDERS").unwrap(), vec![0, 2]);
+    assert_eq!(game.player.groups_of(2), vec!["feeders", "heavies"]);
+
+    game.ungroup_plane(2, "heavies").unwrap();
+    assert!(matches!(
+        game.group_planes("heavies"),
+        Err(GameError::UnknownGroup { .. })
+    ));
+    assert!(matches!(
+        game.ungroup_plane(1, "feeders"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn invalid_group_requests_are_rejected() {
+    let mut game = group_game();
+    assert!(matches!(
+        game.group_plane(9, "feeders"),
+        Err(GameError::PlaneIdInvalid { id: 9 })
+    ));
+    assert!(matches!(
+        game.group_plane(0, "night shift"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.refuel_group("ghosts"),
+        Err(GameError::UnknownGroup { .. })
+    ));
+}
+
+#[test]
+fn refuel_group_refuels_members_and_skips_busy_planes() {
+    let mut game = group_game();
+    game.execute_str("GROUP PLANE 0 feeders").unwrap();
+    game.execute_str("GROUP PLANE 1 feeders").unwrap();
+    game.depart_plane(1, 1).unwrap();
+    game.advance(1);
+
+    let refueled = game.refuel_group("feeders").unwrap();
+    assert_eq!(refueled, vec![0]);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Refueling);
+    assert_eq!(
+        game.airplanes[0].current_fuel,
+        game.airplanes[0].specs.fuel_capacity
+    );
+    // the plane outside the group is untouched
+    assert!(game.airplanes[2].current_fuel < game.airplanes[2].specs.fuel_capacity);
+}
+
+#[test]
+fn sold_planes_leave_their_groups() {
+    let mut game = group_game();
+    game.group_plane(0, "feeders").unwrap();
+    game.group_plane(1, "feeders").unwrap();
+    game.group_plane(1, "spares").unwrap();
+    game.sell_plane(1).unwrap();
+
+    assert_eq!(game.group_planes("feeders").unwrap(), vec![0]);
+    assert!(game.group_planes("spares").is_err());
+}
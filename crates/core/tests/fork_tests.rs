@@ -0,0 +1,41 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::events::EventFilter;
+
+#[test]
+fn fork_plays_on_like_the_original() {
+    let mut game = Game::new(8, Some(6), 650_000.0);
+    game.advance(30);
+    let mut fork = game.fork();
+    assert!(game.diff(&fork).is_empty());
+
+    // random draws included: world events and order restocks come out the same
+    game.advance(24 * 20);
+    fork.advance(24 * 20);
+    assert_eq!(fork.state_hash(), game.state_hash());
+}
+
+#[test]
+fn fork_is_independent() {
+    let mut game = Game::new(8, Some(6), 650_000.0);
+    let mut fork = game.fork();
+    fork.execute_str("ADVANCE 48").unwrap();
+    assert_eq!(game.time, 0);
+
+    game.execute_str("ADVANCE 5").unwrap();
+    assert_eq!(fork.time, 48);
+}
+
+#[test]
+fn fork_leaves_runtime_state_behind() {
+    let mut game = Game::new(8, Some(6), 650_000.0);
+    let events = game.subscribe(EventFilter::all());
+    game.advance(24);
+    assert!(events.try_iter().count() > 0);
+
+    let mut fork = game.fork();
+    assert!(fork.drain_log().is_empty());
+    assert!(fork.history().is_none());
+    // the original's subscribers only hear about the original
+    fork.advance(24);
+    assert_eq!(events.try_iter().count(), 0);
+}
@@ -0,0 +1,161 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("F{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// A game whose forwarders offer a lane with exactly 7 loads a week for 2 weeks every day.
+fn forwarder_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.forwarders.offer_interval_days = 1;
+    gameplay.forwarders.min_loads_per_week = 7;
+    gameplay.forwarders.max_loads_per_week = 7;
+    gameplay.forwarders.weeks = 2;
+    gameplay.rare_cargo.chance_per_day = 0.0;
+    let config = WorldConfig {
+        seed: Some(8),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_500.0),
+            airport(2, 2_000.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    };
+    Game::from_config(config).unwrap()
+}
+
+/// Orders waiting at `airport_id` that belong to the given ids.
+fn waiting(game: &Game, airport_id: usize, ids: &[usize]) -> usize {
+    game.map
+        .airports
+        .iter()
+        .find(|(a, _)| a.id == airport_id)
+        .unwrap()
+        .0
+        .orders
+        .iter()
+        .filter(|o| ids.contains(&o.id))
+        .count()
+}
+
+#[test]
+fn forwarders_have_hubs_on_the_map() {
+    let game = forwarder_game();
+    assert_eq!(game.forwarders.len(), 2);
+    assert!(game.forwarders.iter().all(|f| f.hub_id <= 2));
+    assert!(game.lane_deals().is_empty());
+
+    let mut gameplay = GameplayConfig::default();
+    gameplay.forwarders.count = 0;
+    let mut config = forwarder_game().export_config();
+    config.gameplay = gameplay;
+    let mut game = Game::from_config(config).unwrap();
+    game.advance(72);
+    assert!(game.forwarders.is_empty());
+    assert!(game.lane_deals().is_empty());
+}
+
+#[test]
+fn forwarders_offer_lanes_out_of_their_hub() {
+    let mut game = forwarder_game();
+    let offers = game.subscribe(EventFilter::only(&[GameEventKind::LaneOffer]));
+    game.advance(24);
+
+    let deals = game.lane_deals();
+    assert_eq!(deals.len(), 2);
+    for deal in deals {
+        let hub = game.forwarders[deal.forwarder].hub_id;
+        assert_eq!(deal.origin_id, hub);
+        assert_ne!(deal.destination_id, hub);
+        assert_eq!(deal.loads_per_week, 7);
+        assert!(deal.rate_per_kg > 0.0);
+        assert!(deal.is_offered(game.time));
+    }
+    let events: Vec<GameEvent> = offers.try_iter().collect();
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], GameEvent::LaneOffered { time: 24, .. }));
+
+    // a forwarder with an offer pending waits for an answer before offering again
+    game.advance(24);
+    assert_eq!(game.lane_deals().len(), 2);
+}
+
+#[test]
+fn accepted_lane_issues_reserved_loads_every_day() {
+    let mut game = forwarder_game();
+    game.advance(24);
+    let deal = game.lane_deals()[0].clone();
+    game.accept_lane_deal(deal.id).unwrap();
+    assert_eq!(game.forwarders[deal.forwarder].lanes_accepted, 1);
+    assert!(matches!(
+        game.accept_lane_deal(deal.id),
+        Err(GameError::LaneIdInvalid { id }) if id == deal.id
+    ));
+
+    game.advance(1);
+    assert_eq!(game.reserved_orders.len(), 1);
+    let first_order = *game.reserved_orders.iter().next().unwrap();
+    let first = game
+        .all_orders()
+        .into_iter()
+        .find(|o| o.id == first_order)
+        .cloned()
+        .unwrap();
+    assert_eq!(first.origin_id, deal.origin_id);
+    assert_eq!(first.destination_id, deal.destination_id);
+    assert_eq!(first.value, deal.load_value());
+
+    // two weeks cover a restock; the lane's loads are still waiting at the origin
+    game.advance(2 * 168);
+    let lane_orders: Vec<usize> = game.reserved_orders.iter().copied().collect();
+    assert_eq!(lane_orders.len(), 14);
+    assert_eq!(waiting(&game, deal.origin_id, &lane_orders), 14);
+    assert!(game.lane_deals().iter().all(|d| d.id != deal.id));
+}
+
+#[test]
+fn declined_and_lapsed_offers_cannot_be_accepted() {
+    let mut game = forwarder_game();
+    game.advance(24);
+    let first = game.lane_deals()[0].clone();
+    let second = game.lane_deals()[1].clone();
+
+    game.decline_lane_deal(first.id).unwrap();
+    assert_eq!(game.forwarders[first.forwarder].offers_declined, 1);
+    assert!(game.lane_deals().iter().all(|d| d.id != first.id));
+    assert!(matches!(
+        game.decline_lane_deal(first.id),
+        Err(GameError::LaneIdInvalid { id }) if id == first.id
+    ));
+
+    game.advance(second.offer_expires_at - game.time + 1);
+    assert!(matches!(
+        game.accept_lane_deal(second.id),
+        Err(GameError::LaneIdInvalid { id }) if id == second.id
+    ));
+}
@@ -0,0 +1,141 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::orders::order::{
+    HARD_LANDING_DAMAGE, OrderPayload, POOR_MAINTENANCE_DAMAGE,
+};
+use rusty_runways_core::utils::orders::{CargoType, Order};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("F{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn two_airport_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn cargo_order(id: usize, cargo_type: CargoType) -> Order {
+    Order {
+        id,
+        payload: OrderPayload::Cargo {
+            cargo_type,
+            weight: 50.0,
+        },
+        value: 10_000.0,
+        deadline: 200,
+        origin_id: 0,
+        destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
+    }
+}
+
+fn fly_and_deliver(game: &mut Game) -> f32 {
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    game.depart_plane(0, 1).unwrap();
+    while !matches!(
+        game.airplanes[0].status,
+        AirplaneStatus::Parked | AirplaneStatus::Broken
+    ) {
+        game.advance(1);
+    }
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    let cash = game.player.cash;
+    game.unload_all(0).unwrap();
+    game.player.cash - cash
+}
+
+#[test]
+fn fragile_cargo_types() {
+    assert!(CargoType::Electronics.is_fragile());
+    assert!(!CargoType::Food.is_fragile());
+}
+
+#[test]
+fn smooth_flight_pays_in_full() {
+    let mut game = two_airport_game();
+    game.airplanes[0]
+        .load_order(cargo_order(1, CargoType::Electronics))
+        .unwrap();
+    let earned = fly_and_deliver(&mut game);
+    assert!((earned - 10_000.0).abs() < 1e-2);
+}
+
+#[test]
+fn hard_landing_damages_only_fragile_cargo() {
+    let mut game = two_airport_game();
+    game.map.airport_mut(1).unwrap().runway_length =
+        game.airplanes[0].specs.min_runway_length * 1.05;
+    game.airplanes[0]
+        .load_order(cargo_order(1, CargoType::Electronics))
+        .unwrap();
+    game.airplanes[0]
+        .load_order(cargo_order(2, CargoType::Food))
+        .unwrap();
+
+    let earned = fly_and_deliver(&mut game);
+    let expected = 10_000.0 * (1.0 - HARD_LANDING_DAMAGE) + 10_000.0;
+    assert!((earned - expected).abs() < 1e-2);
+}
+
+#[test]
+fn poorly_maintained_plane_damages_fragile_cargo() {
+    let mut game = two_airport_game();
+    game.airplanes[0]
+        .load_order(cargo_order(1, CargoType::QuantumWidgets))
+        .unwrap();
+    game.airplanes[0].needs_maintenance = true;
+
+    let earned = fly_and_deliver(&mut game);
+    let expected = 10_000.0 * (1.0 - POOR_MAINTENANCE_DAMAGE);
+    assert!((earned - expected).abs() < 1e-2);
+}
+
+#[test]
+fn damage_accumulates_and_caps() {
+    let mut order = cargo_order(1, CargoType::DiscoBalls);
+    for _ in 0..10 {
+        order.apply_damage(0.3);
+    }
+    assert_eq!(order.damage, 1.0);
+    assert_eq!(order.payout(), 0.0);
+}
@@ -0,0 +1,139 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("R{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Airports at x = 1000, 1300 and 2400 with a full SparrowLight at airport 0.
+fn reserve_world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(11),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_300.0),
+            airport(2, 2_400.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+fn reserve_game(gameplay: GameplayConfig) -> Game {
+    Game::from_config(reserve_world(gameplay)).unwrap()
+}
+
+fn calm_gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay
+}
+
+fn fly_until_parked(game: &mut Game) {
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+}
+
+#[test]
+fn departures_must_leave_the_reserve_in_the_tank() {
+    let mut game = reserve_game(calm_gameplay());
+    let plane = &game.airplanes[0];
+    assert_eq!(plane.reserve_hours, 0.75);
+
+    // exactly enough fuel for the 300km hop, but nothing left over
+    let trip_fuel = 300.0 / plane.specs.cruise_speed * plane.specs.fuel_consumption;
+    game.airplanes[0].current_fuel = trip_fuel;
+    assert!(matches!(
+        game.depart_plane(0, 1),
+        Err(GameError::OutOfRange { .. })
+    ));
+
+    game.airplanes[0].current_fuel = trip_fuel + game.airplanes[0].reserve_fuel();
+    game.depart_plane(0, 1).unwrap();
+    fly_until_parked(&mut game);
+    assert!((game.airplanes[0].current_fuel - game.airplanes[0].reserve_fuel()).abs() < 1e-2);
+}
+
+#[test]
+fn zero_reserve_allows_flying_dry() {
+    let mut gameplay = calm_gameplay();
+    gameplay.fuel.reserve_minutes = 0.0;
+    let mut game = reserve_game(gameplay);
+    let plane = &game.airplanes[0];
+    game.airplanes[0].current_fuel =
+        300.0 / plane.specs.cruise_speed * plane.specs.fuel_consumption;
+
+    game.depart_plane(0, 1).unwrap();
+    fly_until_parked(&mut game);
+    assert!(game.airplanes[0].current_fuel.abs() < 1e-2);
+}
+
+#[test]
+fn headwinds_into_the_reserve_divert_to_nearest_airport() {
+    let mut gameplay = calm_gameplay();
+    gameplay.fuel.headwind_chance = 1.0;
+    gameplay.fuel.headwind_burn_minutes = 150.0;
+    let mut game = reserve_game(gameplay);
+
+    game.depart_plane(0, 2).unwrap();
+    game.advance(1);
+    match game.airplanes[0].status {
+        AirplaneStatus::InTransit { destination, .. } => assert_eq!(destination, 1),
+        ref other => panic!("expected diversion, got {:?}", other),
+    }
+    let log = game.drain_log();
+    assert!(log.iter().any(|m| m.contains("diverting to R1")));
+
+    fly_until_parked(&mut game);
+    assert_eq!(game.airplanes[0].location, game.map.coord(1).unwrap());
+    assert!(game.airplanes[0].current_fuel > 0.0);
+}
+
+#[test]
+fn invalid_reserve_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.fuel.reserve_minutes = -1.0;
+    gameplay.fuel.headwind_chance = 2.0;
+    let paths: Vec<String> = reserve_world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.fuel.reserve_minutes",
+            "gameplay.fuel.headwind_chance"
+        ]
+    );
+}
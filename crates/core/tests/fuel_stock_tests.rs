@@ -0,0 +1,104 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::airport::default_fuel_capacity;
+use rusty_runways_core::utils::errors::GameError;
+
+fn stock_game(gameplay: GameplayConfig) -> Game {
+    let cfg = WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![AirportConfig {
+            id: 0,
+            name: "Strip".into(),
+            location: Some(Location {
+                x: 1_000.0,
+                y: 1_000.0,
+            }),
+            runway_length_m: Some(2_000.0),
+            fuel_price_per_l: Some(1.0),
+            landing_fee_per_ton: Some(0.0),
+            parking_fee_per_hour: Some(0.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
+            orders: Vec::new(),
+        }],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 0.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+/// Gameplay whose only airport stores exactly `liters` of fuel.
+fn gameplay_with_stock(liters: f32) -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.stock_multiplier = liters / default_fuel_capacity(2_000.0);
+    gameplay
+}
+
+#[test]
+fn airports_start_with_full_storage() {
+    let game = stock_game(gameplay_with_stock(default_fuel_capacity(2_000.0)));
+    let airport = game.map.airport(0).unwrap();
+    assert_eq!(airport.fuel_capacity, Some(default_fuel_capacity(2_000.0)));
+    assert_eq!(airport.fuel_stock, default_fuel_capacity(2_000.0));
+}
+
+#[test]
+fn refuelling_draws_down_stock_and_fills_partially() {
+    let mut game = stock_game(gameplay_with_stock(100.0));
+    let cash = game.player.cash;
+
+    game.refuel_plane(0).unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 100.0);
+    assert_eq!(game.map.airport(0).unwrap().fuel_stock, 0.0);
+    assert!((cash - game.player.cash - 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn empty_airport_refuses_until_resupplied() {
+    let mut game = stock_game(gameplay_with_stock(100.0));
+    game.refuel_plane(0).unwrap();
+    game.airplanes[0].status = AirplaneStatus::Parked;
+
+    assert!(matches!(
+        game.refuel_plane(0),
+        Err(GameError::FuelShortage { airport_id: 0 })
+    ));
+
+    game.advance(game.restock_cycle);
+    assert_eq!(game.map.airport(0).unwrap().fuel_stock, 100.0);
+    game.airplanes[0].status = AirplaneStatus::Parked;
+    game.refuel_plane(0).unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 200.0);
+}
+
+#[test]
+fn unlimited_stock_never_runs_out() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.unlimited_stock = true;
+    let mut game = stock_game(gameplay);
+    assert_eq!(game.map.airport(0).unwrap().fuel_capacity, None);
+
+    game.refuel_plane(0).unwrap();
+    assert_eq!(
+        game.airplanes[0].current_fuel,
+        game.airplanes[0].specs.fuel_capacity
+    );
+}
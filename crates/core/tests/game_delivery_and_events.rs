@@ -8,19 +8,20 @@ fn deliver_and_store_paths_and_world_events() {
     // Find a loadable order at origin that is reachable
     let plane_id = 0usize;
     let origin_loc = game.planes()[plane_id].location;
-    let origin_idx = game
+    let origin_id = game
         .airports()
         .iter()
-        .position(|(_, c)| *c == origin_loc)
+        .find(|(_, c)| *c == origin_loc)
+        .map(|(a, _)| a.id)
         .unwrap();
 
     // pick first order the plane can carry and reach
     let mut chosen: Option<(usize, usize)> = None;
-    for o in &game.map.airports[origin_idx].0.orders {
+    for o in &game.map.airport(origin_id).unwrap().orders {
         let Some(weight) = o.cargo_weight() else {
             continue;
         };
-        let (a, c) = &game.airports()[o.destination_id];
+        let (a, c) = game.map.airports.get(o.destination_id).unwrap();
         if game.planes()[plane_id].can_fly_to(a, c).is_ok()
             && (game.planes()[plane_id].current_payload + weight)
                 <= game.planes()[plane_id].specs.payload_capacity
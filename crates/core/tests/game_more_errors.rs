@@ -51,3 +51,14 @@ fn load_order_while_in_transit_is_plane_not_at_airport() {
     let err = game.load_order(123456, plane_id).unwrap_err();
     assert!(matches!(err, GameError::PlaneNotAtAirport { .. }));
 }
+
+#[test]
+fn unknown_ids_from_commands_are_errors_not_panics() {
+    let mut game = new_small_game();
+
+    let err = game.execute_str("MAINTENANCE 99").unwrap_err();
+    assert!(matches!(err, GameError::PlaneIdInvalid { id: 99 }));
+
+    let err = game.execute_str("BUY PLANE SparrowLight 99").unwrap_err();
+    assert!(matches!(err, GameError::AirportIdInvalid { id: 99 }));
+}
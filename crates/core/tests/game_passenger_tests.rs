@@ -1,7 +1,12 @@
 use rusty_runways_core::Game;
 use rusty_runways_core::config::{
-    AirportConfig, FuelGameplay, GameplayConfig, Location, ManualOrderConfig, OrderTuning,
-    OrdersGameplay, PassengerTuning, WorldConfig,
+    AirportConfig, AuctionsGameplay, BordersGameplay, ChartersGameplay, ConcessionsGameplay,
+    ConstructionGameplay, ContractsGameplay, DEFAULT_RESERVATION_SHARE, ElectricGameplay,
+    FixedCostsGameplay, ForwardersGameplay, FuelGameplay, GameplayConfig, HandlingGameplay,
+    Location, LoyaltyGameplay, MaintenanceGameplay, ManualOrderConfig, MarketGameplay, OrderTuning,
+    OrdersGameplay, OverdraftGameplay, PassengerDemandGameplay, PassengerTuning, PilotsGameplay,
+    RareCargoGameplay, TaxesGameplay, UsedMarketGameplay, WarehouseGameplay, WorldConfig,
+    WorldGrowthGameplay,
 };
 use rusty_runways_core::events::{Event, ScheduledEvent};
 use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
@@ -18,8 +23,30 @@ fn base_gameplay() -> GameplayConfig {
             generate_initial: true,
             tuning: OrderTuning::default(),
             passengers: PassengerTuning::default(),
+            reservation_share: DEFAULT_RESERVATION_SHARE,
         },
         fuel: FuelGameplay::default(),
+        borders: BordersGameplay::default(),
+        contracts: ContractsGameplay::default(),
+        charters: ChartersGameplay::default(),
+        forwarders: ForwardersGameplay::default(),
+        auctions: AuctionsGameplay::default(),
+        rare_cargo: RareCargoGameplay::default(),
+        world_growth: WorldGrowthGameplay::default(),
+        market: MarketGameplay::default(),
+        loyalty: LoyaltyGameplay::default(),
+        passenger_demand: PassengerDemandGameplay::default(),
+        handling: HandlingGameplay::default(),
+        warehouse: WarehouseGameplay::default(),
+        electric: ElectricGameplay::default(),
+        construction: ConstructionGameplay::default(),
+        concessions: ConcessionsGameplay::default(),
+        used_market: UsedMarketGameplay::default(),
+        maintenance: MaintenanceGameplay::default(),
+        pilots: PilotsGameplay::default(),
+        fixed_costs: FixedCostsGameplay::default(),
+        taxes: TaxesGameplay::default(),
+        overdraft: OverdraftGameplay::default(),
     }
 }
 
@@ -37,6 +64,10 @@ fn airport(
         fuel_price_per_l: Some(1.5),
         landing_fee_per_ton: Some(4.0),
         parking_fee_per_hour: Some(12.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
         orders,
     }
 }
@@ -50,8 +81,13 @@ fn config_rejects_zero_restock_cycle() {
         starting_cash: 400_000.0,
         airports: Vec::new(),
         num_airports: Some(2),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay,
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     let err = Game::from_config(cfg).unwrap_err();
@@ -90,6 +126,7 @@ fn config_accepts_manual_passenger_orders() {
                 value: 7_200.0,
                 deadline_hours: 30,
                 destination_id: 0,
+                tier: Default::default(),
             }],
         ),
     ];
@@ -98,27 +135,32 @@ fn config_accepts_manual_passenger_orders() {
         starting_cash: 600_000.0,
         airports,
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay,
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     };
 
     let game = Game::from_config(cfg).expect("config should build");
     assert_eq!(game.map.num_airports, 2);
 
-    let first_orders = &game.map.airports[0].0.orders;
+    let first_orders = &game.map.airport(0).unwrap().orders;
     assert!(
         first_orders
             .iter()
             .any(|o| matches!(o.payload, OrderPayload::Passengers { .. }))
     );
-    let second_orders = &game.map.airports[1].0.orders;
+    let second_orders = &game.map.airport(1).unwrap().orders;
     assert!(
         second_orders
             .iter()
             .any(|o| matches!(o.payload, OrderPayload::Cargo { .. }))
     );
 
-    let generated_coord = game.map.airports[1].1;
+    let generated_coord = game.map.coord(1).unwrap();
     assert!((generated_coord.x, generated_coord.y) != (0.0, 0.0));
 }
 
@@ -188,7 +230,7 @@ fn maintenance_cycle_marks_and_repairs_planes() {
 #[test]
 fn daily_stats_and_pricing_update() {
     let mut game = Game::new(7, Some(2), 90_000.0);
-    let baseline = game.map.airports[0].0.fuel_price;
+    let baseline = game.map.airport(0).unwrap().fuel_price;
     game.events.clear();
     game.daily_income = 500.0;
     game.daily_expenses = 200.0;
@@ -218,13 +260,20 @@ fn daily_stats_and_pricing_update() {
 #[test]
 fn observe_and_listing_cover_passengers() {
     let mut game = Game::new(11, Some(2), 120_000.0);
-    game.map.airports[0].0.orders.push(Order {
+    game.map.airport_mut(0).unwrap().orders.push(Order {
         id: 500,
         payload: OrderPayload::Passengers { count: 12 },
         value: 4_200.0,
         deadline: 18,
         origin_id: 0,
         destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
     });
 
     let obs = game.observe();
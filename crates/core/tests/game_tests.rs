@@ -127,7 +127,7 @@ fn advance_processes_all_events_at_same_time() {
 fn sell_plane_requires_plane_to_be_parked() {
     let mut game = Game::new(2, Some(3), 650_000.0);
     let origin = game.airplanes[0].location;
-    let destination = game.map.airports[1].0.id;
+    let destination = game.map.airport(1).unwrap().id;
     game.airplanes[0].status = AirplaneStatus::InTransit {
         hours_remaining: 4,
         destination,
@@ -152,6 +152,13 @@ fn sell_plane_requires_empty_manifest() {
         deadline: 12,
         origin_id: 0,
         destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
     });
     let err = game.sell_plane(0).unwrap_err();
     assert!(matches!(err, GameError::InvalidCommand { .. }));
@@ -170,7 +177,7 @@ fn observe_reports_passenger_payload() {
 #[test]
 fn world_event_cycle_updates_prices() {
     let mut game = Game::new(7, Some(4), 500_000.0);
-    let base_price = game.map.airports[0].0.fuel_price;
+    let base_price = game.map.airport(0).unwrap().fuel_price;
     game.events.push(ScheduledEvent {
         time: game.time,
         event: Event::WorldEvent {
@@ -180,7 +187,7 @@ fn world_event_cycle_updates_prices() {
         },
     });
     assert!(game.tick_event());
-    let increased = game.map.airports[0].0.fuel_price;
+    let increased = game.map.airport(0).unwrap().fuel_price;
     assert!((increased - base_price * 1.2).abs() < 1e-3);
 
     game.events.clear();
@@ -194,16 +201,16 @@ fn world_event_cycle_updates_prices() {
     game.time += 2;
     assert!(game.tick_event());
     game.events.clear();
-    let reset = game.map.airports[0].0.fuel_price;
+    let reset = game.map.airport(0).unwrap().fuel_price;
     assert!((reset - base_price).abs() < 1e-3);
 }
 
 #[test]
 fn sell_plane_updates_cash_and_daily_income() {
     let mut game = Game::new(4, Some(3), 650_000.0);
-    let price = game.airplanes[0].specs.purchase_price;
+    let appraisal = game.appraise_plane(0).unwrap();
     let refund = game.sell_plane(0).expect("sale should succeed");
-    assert!((refund - price * 0.6).abs() < f32::EPSILON);
+    assert!((refund - appraisal).abs() < f32::EPSILON);
     assert!(game.airplanes.iter().all(|plane| plane.id != 0));
     assert!(game.player.fleet.iter().all(|plane| plane.id != 0));
     assert!(!game.arrival_times.contains_key(&0));
@@ -215,9 +222,64 @@ fn sell_plane_updates_cash_and_daily_income() {
 #[test]
 fn refuel_plane_requires_sufficient_cash() {
     let mut game = Game::new(5, Some(3), 650_000.0);
-    game.player.cash = 1.0;
+    game.player.cash = 1.0 - game.player.overdraft_limit;
     game.airplanes[0].current_fuel = 0.0;
     let err = game.refuel_plane(0).unwrap_err();
     assert!(matches!(err, GameError::InsufficientFunds { .. }));
-    assert!(game.player.cash <= 1.0);
+    assert!(game.player.cash <= 1.0 - game.player.overdraft_limit);
+}
+
+#[test]
+fn planes_bought_after_a_sale_get_fresh_ids() {
+    let mut game = Game::new(4, Some(3), 2_000_000.0);
+    let home = game
+        .map
+        .airports
+        .iter()
+        .find(|(_, c)| *c == game.airplanes[0].location)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    let model = "SparrowLight".to_string();
+
+    game.buy_plane(&model, home).unwrap();
+    game.sell_plane(0).unwrap();
+    game.buy_plane(&model, home).unwrap();
+
+    let ids: Vec<usize> = game.airplanes.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    assert_eq!(game.player.fleet_size, 2);
+    assert!(game.arrival_times.contains_key(&1));
+    assert!(game.arrival_times.contains_key(&2));
+    assert!(!game.arrival_times.contains_key(&0));
+}
+
+#[test]
+fn flights_track_planes_by_id_after_a_sale() {
+    let mut game = Game::new(4, Some(3), 2_000_000.0);
+    let home = game
+        .map
+        .airports
+        .iter()
+        .find(|(_, c)| *c == game.airplanes[0].location)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    game.buy_plane(&"SparrowLight".to_string(), home).unwrap();
+    game.sell_plane(0).unwrap();
+
+    // plane 1 now sits at position 0 of the fleet
+    let plane = &game.airplanes[0];
+    let dest = game
+        .map
+        .airports
+        .iter()
+        .find(|(a, c)| a.id != home && plane.can_fly_to(a, c).is_ok())
+        .map(|(a, _)| a.id)
+        .expect("a reachable airport");
+    game.depart_plane(1, dest).unwrap();
+    game.advance(48);
+
+    let plane = &game.airplanes[0];
+    assert_eq!(plane.id, 1);
+    assert_eq!(plane.location, game.map.coord(dest).unwrap());
+    assert!(!matches!(plane.status, AirplaneStatus::InTransit { .. }));
 }
@@ -17,6 +17,10 @@ fn base_airports() -> Vec<AirportConfig> {
             fuel_price_per_l: Some(1.5),
             landing_fee_per_ton: Some(4.5),
             parking_fee_per_hour: Some(18.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
         AirportConfig {
@@ -30,6 +34,10 @@ fn base_airports() -> Vec<AirportConfig> {
             fuel_price_per_l: Some(1.8),
             landing_fee_per_ton: Some(4.0),
             parking_fee_per_hour: Some(16.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
             orders: Vec::new(),
         },
     ]
@@ -41,8 +49,13 @@ fn cfg_with_airports() -> WorldConfig {
         starting_cash: 650_000.0,
         airports: base_airports(),
         num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
         gameplay: GameplayConfig::default(),
         airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
     }
 }
 
@@ -66,6 +79,19 @@ fn gameplay_validation_branches() {
     let err = Game::from_config(cfg).unwrap_err();
     assert!(format!("{}", err).contains("orders.min_weight"));
 
+    // order tiers: shares must sum to at most 1
+    let mut cfg = cfg_with_airports();
+    cfg.gameplay.orders.tuning.express_share = 0.7;
+    cfg.gameplay.orders.tuning.economy_share = 0.5;
+    let err = Game::from_config(cfg).unwrap_err();
+    assert!(format!("{}", err).contains("orders.express_share"));
+
+    // order tiers: multipliers must be positive
+    let mut cfg = cfg_with_airports();
+    cfg.gameplay.orders.tuning.express_multiplier = 0.0;
+    let err = Game::from_config(cfg).unwrap_err();
+    assert!(format!("{}", err).contains("orders.express_multiplier"));
+
     // passenger tuning: max_deadline_hours == 0
     let mut cfg = cfg_with_airports();
     cfg.gameplay.orders.passengers.max_deadline_hours = 0;
@@ -134,6 +160,7 @@ fn manual_order_validation_errors() {
         value: 1000.0,
         deadline_hours: 0,
         destination_id: 1,
+        tier: Default::default(),
     }];
     let mut cfg = cfg_with_airports();
     cfg.airports = airports.clone();
@@ -150,6 +177,7 @@ fn manual_order_validation_errors() {
         value: 1000.0,
         deadline_hours: 24,
         destination_id: 1,
+        tier: Default::default(),
     }];
     let mut cfg = cfg_with_airports();
     cfg.airports = airports;
@@ -166,6 +194,7 @@ fn manual_order_validation_errors() {
         value: -1.0,
         deadline_hours: 24,
         destination_id: 1,
+        tier: Default::default(),
     }];
     let mut cfg = cfg_with_airports();
     cfg.airports = airports;
@@ -182,6 +211,7 @@ fn manual_order_validation_errors() {
         value: 1.0,
         deadline_hours: 24,
         destination_id: 0,
+        tier: Default::default(),
     }];
     let mut cfg = cfg_with_airports();
     cfg.airports = airports;
@@ -0,0 +1,100 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::generator::{Difficulty, WorldGenerator};
+
+fn mean_distance(config: &WorldConfig) -> f32 {
+    let locations: Vec<_> = config
+        .airports
+        .iter()
+        .map(|a| a.location.unwrap())
+        .collect();
+    let mut total = 0.0;
+    let mut pairs = 0;
+    for (i, a) in locations.iter().enumerate() {
+        for b in &locations[i + 1..] {
+            total += ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+            pairs += 1;
+        }
+    }
+    total / pairs as f32
+}
+
+#[test]
+fn generated_worlds_are_valid_and_playable() {
+    let generator = WorldGenerator::default();
+    for level in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let config = generator.generate(11, level);
+        assert!(config.validate().is_empty(), "level {}", level);
+        let mut game = Game::from_config(config).unwrap();
+        game.advance(24);
+        assert!(!game.planes().is_empty());
+    }
+}
+
+#[test]
+fn same_seed_same_world() {
+    let generator = WorldGenerator::default();
+    let json = |seed| serde_json::to_string(&generator.generate(seed, 0.4)).unwrap();
+    assert_eq!(json(3), json(3));
+    assert_ne!(json(3), json(4));
+}
+
+#[test]
+fn each_axis_hardens_its_part_of_the_world() {
+    let generator = WorldGenerator::default();
+    let easy = generator.generate(5, 0.0);
+    let hard = generator.generate(5, 1.0);
+
+    assert_eq!(easy.airports.len(), generator.min_airports);
+    assert_eq!(hard.airports.len(), generator.max_airports);
+    assert_eq!(easy.starting_cash, generator.rich_cash);
+    assert!((hard.starting_cash - generator.poor_cash).abs() < 1.0);
+    let (easy_orders, hard_orders) = (&easy.gameplay.orders, &hard.gameplay.orders);
+    assert_eq!(
+        easy_orders.tuning.max_deadline_hours,
+        generator.loose_deadline_hours
+    );
+    assert_eq!(
+        hard_orders.tuning.max_deadline_hours,
+        generator.tight_deadline_hours
+    );
+    assert!(hard_orders.passengers.max_deadline_hours < easy_orders.passengers.max_deadline_hours);
+    assert!(hard_orders.tuning.express_share > easy_orders.tuning.express_share);
+
+    // only the route length moves, and the average lands near the target
+    let long = generator.generate(
+        5,
+        Difficulty {
+            route_length: 1.0,
+            ..Difficulty::uniform(0.5)
+        },
+    );
+    let short = generator.generate(
+        5,
+        Difficulty {
+            route_length: 0.0,
+            ..Difficulty::uniform(0.5)
+        },
+    );
+    assert_eq!(long.airports.len(), short.airports.len());
+    assert_eq!(long.starting_cash, short.starting_cash);
+    let (long_km, short_km) = (mean_distance(&long), mean_distance(&short));
+    assert!(
+        (long_km / generator.long_routes_km - 1.0).abs() < 0.25,
+        "{}",
+        long_km
+    );
+    assert!(
+        (short_km / generator.short_routes_km - 1.0).abs() < 0.25,
+        "{}",
+        short_km
+    );
+}
+
+#[test]
+fn levels_outside_the_range_are_clamped() {
+    let generator = WorldGenerator::default();
+    let json = |level: f32| serde_json::to_string(&generator.generate(2, level)).unwrap();
+    assert_eq!(json(-3.0), json(0.0));
+    assert_eq!(json(7.0), json(1.0));
+}
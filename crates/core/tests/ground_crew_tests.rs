@@ -0,0 +1,137 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize, weight: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("G{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: Some(50_000),
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Electronics,
+            weight,
+            value: 50_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+/// A freighter at a small airport (5 t an hour) next to a 15 t order.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    let cfg = WorldConfig {
+        seed: Some(9),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0, 1, 15_000.0),
+            airport(1, 1_500.0, 0, 100.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "BisonFreighter".into(),
+            home_airport: 0,
+            fuel_level: 0.5,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn hours_until_parked(game: &mut Game) -> u64 {
+    let start = game.time;
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    game.time - start
+}
+
+#[test]
+fn crew_speed_up_loading() {
+    let mut unstaffed = game();
+    let order = unstaffed.map.airport(0).unwrap().orders[0].id;
+    unstaffed.load_order(order, 0).unwrap();
+    assert_eq!(hours_until_parked(&mut unstaffed), 3);
+
+    // two crew double the rate
+    let mut staffed = game();
+    assert_eq!(staffed.hire_crew(0, 2).unwrap(), 2);
+    let order = staffed.map.airport(0).unwrap().orders[0].id;
+    staffed.load_order(order, 0).unwrap();
+    assert_eq!(hours_until_parked(&mut staffed), 2);
+}
+
+#[test]
+fn staffed_airports_refuel_straight_away() {
+    let mut game = game();
+    game.hire_crew(0, 1).unwrap();
+    game.refuel_plane(0).unwrap();
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+
+    // the other airport has no crew
+    assert_eq!(game.observe().airports[1].ground_crew, 0);
+    assert_eq!(game.observe().airports[0].ground_crew, 1);
+}
+
+#[test]
+fn crew_are_paid_every_day() {
+    let mut game = game();
+    let wage = game.handling_settings.crew_wage_per_day;
+    game.hire_crew(0, 3).unwrap();
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert!((day.crew_wages - 3.0 * wage).abs() < 1e-2);
+
+    game.fire_crew(0, 3).unwrap();
+    game.advance(24);
+    assert_eq!(game.stats.last().unwrap().crew_wages, 0.0);
+}
+
+#[test]
+fn staffing_is_bounded() {
+    let mut game = game();
+    let max = game.handling_settings.max_crew;
+    assert!(matches!(
+        game.hire_crew(0, max + 1),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.fire_crew(0, 1),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.hire_crew(9, 1),
+        Err(GameError::AirportIdInvalid { id: 9 })
+    ));
+}
+
+#[test]
+fn staffing_survives_a_save() {
+    let mut game = game();
+    game.hire_crew(0, 2).unwrap();
+    let json = serde_json::to_string(&game).unwrap();
+    let loaded: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.map.airport(0).unwrap().ground_crew, 2);
+}
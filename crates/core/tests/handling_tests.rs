@@ -0,0 +1,129 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn cargo(weight: f32, destination_id: usize) -> ManualOrderConfig {
+    ManualOrderConfig::Cargo {
+        cargo: CargoType::Electronics,
+        weight,
+        value: 50_000.0,
+        deadline_hours: 200,
+        destination_id,
+        tier: Default::default(),
+    }
+}
+
+fn airport(id: usize, x: f32, population: u32, orders: Vec<ManualOrderConfig>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("H{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: Some(population),
+        utc_offset: None,
+        country: None,
+        orders,
+    }
+}
+
+/// A 20 t freighter at airport 0 with the given population and waiting orders.
+fn freighter_game(population: u32, orders: Vec<ManualOrderConfig>) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    let cfg = WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0, population, orders),
+            airport(1, 1_500.0, 100_000, vec![cargo(100.0, 0)]),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "BisonFreighter".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn order_ids(game: &Game) -> Vec<usize> {
+    game.map
+        .airport(0)
+        .unwrap()
+        .orders
+        .iter()
+        .map(|o| o.id)
+        .collect()
+}
+
+/// Hours until the plane is parked again.
+fn handling_hours(game: &mut Game) -> u64 {
+    let start = game.time;
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    game.time - start
+}
+
+#[test]
+fn heavy_loads_take_longer_at_small_airports() {
+    let mut small = freighter_game(50_000, vec![cargo(20_000.0, 1)]);
+    let order = order_ids(&small)[0];
+    small.load_order(order, 0).unwrap();
+    assert_eq!(small.airplanes[0].status, AirplaneStatus::Loading);
+    assert_eq!(handling_hours(&mut small), 4);
+
+    let mut hub = freighter_game(200_000, vec![cargo(20_000.0, 1)]);
+    let order = order_ids(&hub)[0];
+    hub.load_order(order, 0).unwrap();
+    assert_eq!(handling_hours(&mut hub), 1);
+}
+
+#[test]
+fn light_loads_take_the_minimum_time() {
+    let mut game = freighter_game(50_000, vec![cargo(200.0, 1)]);
+    let order = order_ids(&game)[0];
+    game.load_order(order, 0).unwrap();
+    assert_eq!(handling_hours(&mut game), 1);
+}
+
+#[test]
+fn queued_jobs_run_one_after_the_other() {
+    let mut game = freighter_game(50_000, vec![cargo(10_000.0, 1), cargo(10_000.0, 1)]);
+    for order in order_ids(&game) {
+        game.load_order(order, 0).unwrap();
+    }
+    assert_eq!(handling_hours(&mut game), 4);
+
+    game.unload_all(0).unwrap();
+    assert_eq!(handling_hours(&mut game), 4);
+}
+
+#[test]
+fn handling_rate_must_be_positive() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.handling.tons_per_hour = 0.0;
+    let issues = gameplay.validate();
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.path == "gameplay.handling.tons_per_hour")
+    );
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "history")]
+
+use rusty_runways_core::Game;
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::history::{DeliveryRecord, History, LedgerEntry, path_for_save};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+
+/// Load the first order the starter plane can carry, fly it there and unload it.
+fn deliver_one(game: &mut Game) {
+    let origin_loc = game.planes()[0].location;
+    let origin_id = game
+        .airports()
+        .iter()
+        .find(|(_, c)| *c == origin_loc)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    let plane = &game.planes()[0];
+    let chosen = game
+        .map
+        .airport(origin_id)
+        .unwrap()
+        .orders
+        .iter()
+        .find(|o| {
+            let (a, c) = game.map.airports.get(o.destination_id).unwrap();
+            o.cargo_weight().is_some_and(|w| {
+                plane.can_fly_to(a, c).is_ok()
+                    && plane.current_payload + w <= plane.specs.payload_capacity
+            })
+        })
+        .map(|o| (o.id, o.destination_id));
+    let Some((order, destination)) = chosen else {
+        return;
+    };
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, destination).unwrap();
+    if let AirplaneStatus::InTransit {
+        hours_remaining, ..
+    } = game.planes()[0].status
+    {
+        game.advance(hours_remaining);
+    }
+    game.unload_all(0).unwrap();
+}
+
+#[test]
+fn daily_reports_match_the_in_memory_stats() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut game = Game::new(4, Some(8), 650_000.0);
+    game.attach_history(dir.path().join("run.sqlite")).unwrap();
+    game.advance(24 * 5 + 3);
+
+    let history = game.history().unwrap();
+    assert_eq!(history.day_count().unwrap(), game.stats.len() as u64);
+    let days = history.days(0, u64::MAX).unwrap();
+    assert_eq!(
+        serde_json::to_value(&days).unwrap(),
+        serde_json::to_value(&game.stats).unwrap()
+    );
+    let last = game.stats.last().unwrap().day;
+    assert_eq!(history.days(last, last).unwrap().len(), 1);
+}
+
+#[test]
+fn ledger_and_deliveries_follow_the_events() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("run.sqlite");
+    let mut game = Game::new(2, Some(8), 5_000_000.0);
+    game.attach_history(&path).unwrap();
+    let events = game.subscribe(EventFilter::only(&[
+        GameEventKind::Cash,
+        GameEventKind::Delivery,
+    ]));
+    deliver_one(&mut game);
+    game.advance(72);
+    game.detach_history().unwrap();
+
+    let mut ledger = Vec::new();
+    let mut deliveries = Vec::new();
+    for event in events.try_iter() {
+        match event {
+            GameEvent::CashChanged {
+                time,
+                delta,
+                balance,
+            } => ledger.push(LedgerEntry {
+                time,
+                delta,
+                balance,
+            }),
+            GameEvent::Delivered {
+                time,
+                order,
+                airport,
+                payout,
+            } => deliveries.push(DeliveryRecord {
+                time,
+                order,
+                airport,
+                payout,
+            }),
+            _ => {}
+        }
+    }
+    assert!(!ledger.is_empty());
+
+    // detaching wrote everything, and the file reads back on its own
+    let history = History::open(&path).unwrap();
+    assert_eq!(history.ledger(0, game.time).unwrap(), ledger);
+    assert_eq!(history.deliveries(0, game.time).unwrap(), deliveries);
+    let revenue: f32 = deliveries.iter().map(|d| d.payout).sum();
+    assert!((history.delivery_revenue(0, game.time).unwrap() - revenue).abs() < 0.01);
+}
+
+#[test]
+fn attaching_drops_rows_from_later_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("run.sqlite");
+    let mut game = Game::new(4, Some(8), 650_000.0);
+    game.attach_history(&path).unwrap();
+    game.advance(24 * 6);
+    game.detach_history().unwrap();
+
+    let mut earlier = Game::new(4, Some(8), 650_000.0);
+    earlier.advance(24 * 2);
+    earlier.attach_history(&path).unwrap();
+    let history = earlier.history().unwrap();
+    assert_eq!(history.day_count().unwrap(), earlier.stats.len() as u64);
+    assert!(
+        history
+            .ledger(earlier.time + 1, u64::MAX)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn saves_keep_their_history_next_to_them() {
+    assert_eq!(
+        path_for_save("campaign"),
+        std::path::Path::new("save_games").join("campaign.history.sqlite")
+    );
+}
@@ -0,0 +1,113 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::airplane::Airplane;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn hold_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    let cfg = WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_500.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn held_plane_refuses_to_depart_until_released() {
+    let mut game = hold_game();
+    game.hold_plane(0).unwrap();
+    assert!(game.airplanes[0].on_hold);
+    assert!(game.player.fleet[0].on_hold);
+    assert!(matches!(
+        game.depart_plane(0, 1),
+        Err(GameError::PlaneOnHold { plane_id: 0 })
+    ));
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+
+    // ground work is still allowed while held
+    game.refuel_plane(0).unwrap();
+
+    game.release_plane(0).unwrap();
+    assert!(!game.airplanes[0].on_hold);
+    assert!(!game.player.fleet[0].on_hold);
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+}
+
+#[test]
+fn planes_in_flight_cannot_be_held() {
+    let mut game = hold_game();
+    game.depart_plane(0, 1).unwrap();
+    assert!(matches!(
+        game.hold_plane(0),
+        Err(GameError::PlaneNotReady { .. })
+    ));
+}
+
+#[test]
+fn releasing_a_plane_that_is_not_held_is_an_error() {
+    let mut game = hold_game();
+    assert!(matches!(
+        game.release_plane(0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.hold_plane(7),
+        Err(GameError::PlaneIdInvalid { id: 7 })
+    ));
+    // holding twice is fine
+    game.hold_plane(0).unwrap();
+    game.hold_plane(0).unwrap();
+}
+
+#[test]
+fn hold_survives_serialization_and_defaults_off_for_old_saves() {
+    let mut game = hold_game();
+    game.hold_plane(0).unwrap();
+    let json = serde_json::to_value(&game.airplanes[0]).unwrap();
+    let plane: Airplane = serde_json::from_value(json.clone()).unwrap();
+    assert!(plane.on_hold);
+
+    let mut legacy = json;
+    legacy.as_object_mut().unwrap().remove("on_hold");
+    let plane: Airplane = serde_json::from_value(legacy).unwrap();
+    assert!(!plane.on_hold);
+
+    let obs = game.observe();
+    assert!(obs.planes[0].on_hold);
+}
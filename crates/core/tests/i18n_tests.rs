@@ -0,0 +1,50 @@
+use rusty_runways_core::i18n::{FluentArgs, I18nError, Localizer};
+use rusty_runways_core::utils::errors::GameError;
+
+const GERMAN: &str = "
+error-no-cargo = Keine Fracht zum Entladen
+error-airport-id-invalid = Flughafen { $id } existiert nicht
+";
+
+#[test]
+fn english_is_built_in() {
+    let english = Localizer::english();
+    assert_eq!(english.locale(), "en-US");
+    assert_eq!(english.format("error-no-cargo", None), "No cargo to unload");
+    // the global localizer defaults to English as well
+    assert_eq!(GameError::NoCargo.to_string(), "No cargo to unload");
+}
+
+#[test]
+fn translations_fall_back_to_english() {
+    let german = Localizer::with_translation("de", GERMAN).unwrap();
+    assert_eq!(german.locale(), "de");
+    assert_eq!(
+        german.format("error-no-cargo", None),
+        "Keine Fracht zum Entladen"
+    );
+
+    let mut args = FluentArgs::new();
+    args.set("id", 3);
+    assert_eq!(
+        german.format("error-airport-id-invalid", Some(&args)),
+        "Flughafen 3 existiert nicht"
+    );
+
+    // missing from the translation, so English is used
+    assert_eq!(german.format("gui-save", None), "Save");
+    // missing everywhere, so the id is returned
+    assert_eq!(german.format("no-such-message", None), "no-such-message");
+}
+
+#[test]
+fn broken_bundles_are_rejected() {
+    assert!(matches!(
+        Localizer::with_translation("de", "error-no-cargo = { $"),
+        Err(I18nError::Parse { .. })
+    ));
+    assert!(matches!(
+        Localizer::with_translation("not a locale!", GERMAN),
+        Err(I18nError::InvalidLocale { .. })
+    ));
+}
@@ -0,0 +1,145 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, LoyaltyGameplay, LoyaltyTier,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::loyalty::AirportLoyalty;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("L{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(10.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// A single tier granting half off after one operation.
+fn one_tier() -> LoyaltyGameplay {
+    LoyaltyGameplay {
+        window_hours: 168,
+        tiers: vec![LoyaltyTier {
+            name: "Regular".into(),
+            min_operations: 1,
+            discount: 0.5,
+        }],
+    }
+}
+
+/// Two airports and a plane parked at the first.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.forwarders.count = 0;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.loyalty = one_tier();
+    Game::from_config(WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+/// Fly the plane to `destination` and return the fees paid on the way.
+fn fly(game: &mut Game, destination: usize) -> f32 {
+    let before = game.daily_fees;
+    game.depart_plane(0, destination).unwrap();
+    while matches!(game.airplanes[0].status, AirplaneStatus::InTransit { .. }) {
+        game.advance(1);
+    }
+    game.daily_fees - before
+}
+
+#[test]
+fn tiers_follow_recent_operations() {
+    let settings = LoyaltyGameplay::default();
+    let mut loyalty = AirportLoyalty::default();
+    assert!(loyalty.tier(0, 0, &settings).is_none());
+    assert_eq!(loyalty.fee_factor(0, 0, &settings), 1.0);
+
+    for hour in 0..10 {
+        loyalty.record(0, hour, settings.window_hours);
+    }
+    assert_eq!(loyalty.count(0, 10, settings.window_hours), 10);
+    assert_eq!(loyalty.tier(0, 10, &settings).unwrap().name, "Silver");
+    assert!((loyalty.fee_factor(0, 10, &settings) - 0.9).abs() < 1e-6);
+    assert_eq!(loyalty.count(1, 10, settings.window_hours), 0);
+
+    // operations older than the window stop counting
+    assert_eq!(loyalty.count(0, 172, settings.window_hours), 5);
+    assert_eq!(loyalty.tier(0, 172, &settings).unwrap().name, "Bronze");
+    assert!(loyalty.tier(0, 200, &settings).is_none());
+    assert!(loyalty.tier(0, 10, &LoyaltyGameplay::none()).is_none());
+}
+
+#[test]
+fn frequent_airports_charge_less_for_landing_and_fuel() {
+    let mut game = game();
+    assert!(game.loyalty_tier(1).is_none());
+
+    // the first landing at an airport pays in full
+    let full = fly(&mut game, 1);
+    assert!(full > 0.0);
+    assert_eq!(game.airport_operations(0), 1);
+    assert_eq!(game.airport_operations(1), 1);
+    assert_eq!(game.loyalty_tier(1).unwrap().name, "Regular");
+
+    // the way back lands where the plane departed before
+    let discounted = fly(&mut game, 0);
+    assert!((discounted - full / 2.0).abs() < 1e-3);
+
+    let fuel = game.airplanes[0].current_fuel;
+    let price = game.map.airport(0).unwrap().fuel_price;
+    let cash = game.player.cash;
+    game.refuel_plane(0).unwrap();
+    let liters = game.airplanes[0].current_fuel - fuel;
+    assert!(liters > 0.0);
+    assert!((cash - game.player.cash - liters * price / 2.0).abs() < 1e-2);
+}
+
+#[test]
+fn tiers_lapse_after_a_quiet_week() {
+    let mut game = game();
+    fly(&mut game, 1);
+    assert!(game.loyalty_tier(1).is_some());
+    game.advance(168);
+    assert_eq!(game.airport_operations(1), 0);
+    assert!(game.loyalty_tier(1).is_none());
+}
+
+#[test]
+fn tiers_must_be_sorted_and_discounts_below_one() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.loyalty.tiers[1].min_operations = 2;
+    gameplay.loyalty.tiers[2].discount = 1.0;
+    let issues = gameplay.validate();
+    let paths: Vec<&str> = issues.iter().map(|i| i.path.as_str()).collect();
+    assert!(paths.contains(&"gameplay.loyalty.tiers[1].min_operations"));
+    assert!(paths.contains(&"gameplay.loyalty.tiers[2].discount"));
+}
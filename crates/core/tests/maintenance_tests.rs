@@ -12,3 +12,81 @@ fn maintenance_event_schedules_and_resolves() {
     game.advance(1);
     assert!(matches!(game.planes()[0].status, AirplaneStatus::Parked));
 }
+
+fn strip_game(has_hangar: Option<bool>, cash: f32) -> Game {
+    use rusty_runways_core::config::{
+        AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+    };
+
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(2),
+        starting_cash: cash,
+        airports: vec![AirportConfig {
+            id: 0,
+            name: "Strip".into(),
+            location: Some(Location { x: 500.0, y: 500.0 }),
+            runway_length_m: Some(1_000.0),
+            fuel_price_per_l: Some(1.0),
+            landing_fee_per_ton: Some(0.0),
+            parking_fee_per_hour: Some(0.0),
+            has_hangar,
+            population: None,
+            utc_offset: None,
+            country: None,
+            orders: Vec::new(),
+        }],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn small_airports_call_a_mobile_mechanic() {
+    use rusty_runways_core::game::{MOBILE_MECHANIC_FEE, MOBILE_MECHANIC_HOURS};
+
+    let mut game = strip_game(None, 100_000.0);
+    assert!(!game.map.airport(0).unwrap().has_hangar);
+
+    game.maintenance_on_airplane(0).unwrap();
+    assert!((game.player.cash - (100_000.0 - MOBILE_MECHANIC_FEE)).abs() < 1e-3);
+    game.advance(MOBILE_MECHANIC_HOURS - 1);
+    assert!(matches!(
+        game.planes()[0].status,
+        AirplaneStatus::Maintenance
+    ));
+    game.advance(1);
+    assert!(matches!(game.planes()[0].status, AirplaneStatus::Parked));
+}
+
+#[test]
+fn configured_hangar_services_for_free() {
+    let mut game = strip_game(Some(true), 100_000.0);
+    game.maintenance_on_airplane(0).unwrap();
+    assert_eq!(game.player.cash, 100_000.0);
+    game.advance(1);
+    assert!(matches!(game.planes()[0].status, AirplaneStatus::Parked));
+}
+
+#[test]
+fn mobile_mechanic_needs_cash() {
+    let mut game = strip_game(None, 100.0);
+    game.player.overdraft_limit = 0.0;
+    assert!(matches!(
+        game.maintenance_on_airplane(0),
+        Err(rusty_runways_core::utils::errors::GameError::InsufficientFunds { .. })
+    ));
+    assert!(matches!(game.planes()[0].status, AirplaneStatus::Parked));
+}
@@ -1,4 +1,4 @@
-use rusty_runways_core::utils::map::Map;
+use rusty_runways_core::utils::map::{Map, WorldStyle};
 
 #[test]
 fn map_generation_is_deterministic() {
@@ -33,3 +33,68 @@ fn clustered_generation_produces_local_neighbors() {
         );
     }
 }
+
+fn nearest_neighbor_distances(map: &Map) -> Vec<f32> {
+    map.airports
+        .iter()
+        .map(|(airport, coord)| {
+            map.airports
+                .iter()
+                .filter(|(other, _)| other.id != airport.id)
+                .map(|(_, other)| {
+                    ((coord.x - other.x).powi(2) + (coord.y - other.y).powi(2)).sqrt()
+                })
+                .fold(f32::INFINITY, f32::min)
+        })
+        .collect()
+}
+
+#[test]
+fn continents_generation_is_deterministic_and_in_bounds() {
+    let map1 = Map::generate_from_seed_with_style(3, Some(20), WorldStyle::Continents);
+    let map2 = Map::generate_from_seed_with_style(3, Some(20), WorldStyle::Continents);
+    assert_eq!(
+        serde_json::to_string(&map1).unwrap(),
+        serde_json::to_string(&map2).unwrap()
+    );
+    assert_eq!(map1.airports.len(), 20);
+    assert_eq!(map1.world_style, WorldStyle::Continents);
+    for (_, coord) in &map1.airports {
+        assert!((0.0..=10_000.0).contains(&coord.x));
+        assert!((0.0..=10_000.0).contains(&coord.y));
+    }
+}
+
+#[test]
+fn continents_have_dense_regions_and_long_haul_gaps() {
+    for seed in [1_u64, 9, 27] {
+        let map = Map::generate_from_seed_with_style(seed, Some(24), WorldStyle::Continents);
+        let nearest = nearest_neighbor_distances(&map);
+        assert!(nearest.iter().all(|d| *d < 1_500.0));
+
+        let mut widest = 0.0_f32;
+        for (_, a) in &map.airports {
+            for (_, b) in &map.airports {
+                widest = widest.max(((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt());
+            }
+        }
+        assert!(
+            widest > 3_000.0,
+            "expected a long-haul gap for seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn world_style_parses_from_str() {
+    assert_eq!(
+        "Continents".parse::<WorldStyle>().unwrap(),
+        WorldStyle::Continents
+    );
+    assert_eq!(
+        "clustered".parse::<WorldStyle>().unwrap(),
+        WorldStyle::Clustered
+    );
+    assert!("islands".parse::<WorldStyle>().is_err());
+}
@@ -0,0 +1,160 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig, MarketGameplay,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::coordinate::Coordinate;
+use rusty_runways_core::utils::orders::order::{OrderAirportInfo, OrderGenerationParams};
+use rusty_runways_core::utils::orders::{CargoType, LaneMarket, Order};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("M{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn infos() -> Vec<OrderAirportInfo> {
+    (0..2)
+        .map(|id| OrderAirportInfo {
+            id,
+            runway_length: 3_000.0,
+            size: 1.0,
+            coordinate: Coordinate::new(1_000.0 + 500.0 * id as f32, 1_000.0),
+            country: None,
+        })
+        .collect()
+}
+
+/// Two airports, one plane at the first and a 1 t order waiting for it.
+fn game() -> Game {
+    let mut origin = airport(0, 1_000.0);
+    origin.orders = vec![ManualOrderConfig::Cargo {
+        cargo: CargoType::Food,
+        weight: 1_000.0,
+        value: 20_000.0,
+        deadline_hours: 200,
+        destination_id: 1,
+        tier: Default::default(),
+    }];
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    Game::from_config(WorldConfig {
+        seed: Some(6),
+        starting_cash: 1_000_000.0,
+        airports: vec![origin, airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn busy_lanes_pay_less_and_recover_over_time() {
+    let params = MarketGameplay::default();
+    let mut market = LaneMarket::default();
+    assert_eq!(market.price_factor(0, 1, &params), 1.0);
+
+    market.record_delivery(0, 1, 20_000.0);
+    assert_eq!(market.tons(0, 1), 20.0);
+    // at the saturation tonnage half the discount applies
+    let factor = market.price_factor(0, 1, &params);
+    assert!((factor - (1.0 - params.max_discount / 2.0)).abs() < 1e-4);
+    // the way back and other lanes are unaffected
+    assert_eq!(market.price_factor(1, 0, &params), 1.0);
+
+    market.record_delivery(0, 1, 1_000_000.0);
+    assert!(market.price_factor(0, 1, &params) > 1.0 - params.max_discount);
+
+    market.record_pickup(0);
+    market.record_pickup(1);
+    market.end_cycle([0, 1], 0.5);
+    assert_eq!(market.tons(0, 1), 510.0);
+}
+
+#[test]
+fn neglected_airports_build_up_a_capped_premium() {
+    let params = MarketGameplay::default();
+    let mut market = LaneMarket::default();
+    market.end_cycle([0, 1], params.memory);
+    market.record_pickup(1);
+    market.end_cycle([0, 1], params.memory);
+    assert_eq!(market.backlog(0), 2);
+    assert_eq!(market.backlog(1), 0);
+    let factor = market.price_factor(0, 1, &params);
+    assert!((factor - (1.0 + 2.0 * params.backlog_premium)).abs() < 1e-4);
+
+    for _ in 0..20 {
+        market.end_cycle([0, 1], params.memory);
+    }
+    let factor = market.price_factor(0, 1, &params);
+    assert!((factor - (1.0 + params.max_backlog_premium)).abs() < 1e-4);
+
+    market.record_pickup(0);
+    assert_eq!(market.backlog(0), 0);
+    assert_eq!(MarketGameplay::none().max_backlog_premium, 0.0);
+    assert_eq!(market.price_factor(0, 1, &MarketGameplay::none()), 1.0);
+}
+
+#[test]
+fn generated_cargo_is_priced_against_the_market() {
+    let params = OrderGenerationParams::default();
+    let mut saturated = LaneMarket::default();
+    saturated.record_delivery(0, 1, 100_000.0);
+    let mut neglected = LaneMarket::default();
+    neglected.end_cycle([0], params.market.memory);
+
+    for seed in 0..10 {
+        let plain = Order::new_cargo(seed, 1, 0, &infos(), &params);
+        let same =
+            Order::new_cargo_in_market(seed, 1, 0, &infos(), &params, &LaneMarket::default());
+        assert_eq!(plain.value, same.value);
+        let cheap = Order::new_cargo_in_market(seed, 1, 0, &infos(), &params, &saturated);
+        assert!(cheap.value < plain.value);
+        let dear = Order::new_cargo_in_market(seed, 1, 0, &infos(), &params, &neglected);
+        assert!(dear.value > plain.value);
+    }
+}
+
+#[test]
+fn game_tracks_deliveries_and_pickups() {
+    let mut game = game();
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(10);
+    game.unload_all(0).unwrap();
+    game.advance(2);
+    assert_eq!(game.map.lane_market.tons(0, 1), 1.0);
+
+    // the restock remembers the pickup at airport 0 but not at airport 1
+    game.advance(168 - game.time);
+    assert_eq!(game.map.lane_market.backlog(0), 0);
+    assert_eq!(game.map.lane_market.backlog(1), 1);
+    assert_eq!(game.map.lane_market.tons(0, 1), 0.5);
+}
@@ -0,0 +1,45 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::metrics::{ServerMetrics, serve};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+#[test]
+fn render_reports_counters_and_games() {
+    let metrics = ServerMetrics::default();
+    metrics.record(24, 5);
+    metrics.record(0, 1);
+    let mut game = Game::new(1, Some(4), 650_000.0);
+    game.advance(3);
+
+    let text = metrics.render([(7, &game)]);
+    assert!(text.contains("# TYPE rusty_runways_hours_simulated_total counter\n"));
+    assert!(text.contains("rusty_runways_hours_simulated_total 24\n"));
+    assert!(text.contains("rusty_runways_events_total 6\n"));
+    assert!(text.contains("rusty_runways_commands_total 2\n"));
+    assert!(text.contains("rusty_runways_active_games 1\n"));
+    assert!(text.contains(&format!(
+        "rusty_runways_game_cash{{game=\"7\"}} {}\n",
+        game.player.cash as f64
+    )));
+    assert!(text.contains("rusty_runways_game_fleet_size{game=\"7\"} 1\n"));
+    assert!(text.contains("rusty_runways_game_time_hours{game=\"7\"} 3\n"));
+}
+
+#[test]
+fn serves_metrics_over_http() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || serve(listener, || "rusty_runways_active_games 0\n".into()));
+
+    let get = |path: &str| {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+    let response = get("/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with("\r\n\r\nrusty_runways_active_games 0\n"));
+    assert!(get("/").starts_with("HTTP/1.1 404"));
+}
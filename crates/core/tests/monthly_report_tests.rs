@@ -0,0 +1,143 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::calendar::Date;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("M{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(5.0),
+        parking_fee_per_hour: Some(10.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    Game::from_config(WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 2_000.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn a_statement_is_written_when_the_month_ends() {
+    let mut game = game();
+    let reports = game.subscribe(EventFilter::only(&[GameEventKind::MonthlyReport]));
+    game.advance(30 * 24);
+    assert!(game.monthly_reports.is_empty());
+
+    game.advance(24);
+    assert_eq!(Date::from_time(game.time).month, 2);
+    assert_eq!(game.monthly_reports.len(), 1);
+    let report = &game.monthly_reports[0];
+    assert_eq!((report.year, report.month), (1, 1));
+    assert_eq!(report.closing_cash, game.player.cash);
+
+    let days: Vec<_> = game.stats.iter().filter(|s| s.day >= 1).collect();
+    assert_eq!(days.len(), 31);
+    let expenses: f32 = days.iter().map(|s| s.expenses).sum();
+    let income: f32 = days.iter().map(|s| s.income).sum();
+    assert!((report.net - (income - expenses)).abs() < 1.0);
+    assert!(report.overhead > 0.0);
+
+    let events: Vec<_> = reports.try_iter().collect();
+    assert!(matches!(events.as_slice(), [GameEvent::MonthlyReport(r)] if r == report));
+}
+
+#[test]
+fn fuel_and_fees_are_broken_out() {
+    let mut game = game();
+    let plane = game.airplanes[0].id;
+    game.depart_plane(plane, 1).unwrap();
+    game.advance(12);
+    game.refuel_plane(plane).unwrap();
+    game.advance(24 * 31);
+
+    let report = &game.monthly_reports[0];
+    assert!(report.fuel > 0.0);
+    assert!(report.fees > 0.0);
+    let lines = report.lines();
+    let sum: f32 = lines[..8].iter().map(|(_, amount)| amount).sum();
+    assert!((sum - report.net).abs() < 1.0);
+    assert_eq!(lines[8], ("Net", report.net));
+}
+
+#[test]
+fn quarters_close_with_a_balance_sheet_and_cash_flows() {
+    let mut game = game();
+    game.advance(24 * 10);
+    let before = game.player.cash;
+    game.buy_plane(&"SparrowLight".to_string(), 0).unwrap();
+    let price = before - game.player.cash;
+
+    game.advance(24 * 79);
+    assert!(game.financials().is_empty());
+    game.advance(24);
+    assert_eq!(Date::from_time(game.time).month, 4);
+
+    let statement = &game.financials()[0];
+    assert_eq!((statement.year, statement.quarter), (1, 1));
+    assert!((statement.investing_cash_flow + price).abs() < 1.0);
+    assert!(statement.operating_cash_flow < 0.0);
+    assert!(
+        (statement.net_cash_flow - statement.operating_cash_flow - statement.investing_cash_flow)
+            .abs()
+            < 1.0
+    );
+    assert_eq!(statement.cash, game.player.cash);
+    assert!(statement.fleet_value > 0.0);
+    assert_eq!(statement.assets, statement.cash + statement.fleet_value);
+    assert_eq!(statement.liabilities, 0.0);
+    assert_eq!(statement.equity, statement.assets);
+    assert_eq!(game.monthly_reports.len(), 3);
+}
+
+#[test]
+fn stats_records_add_statement_categories_to_each_day() {
+    let mut game = game();
+    assert!(game.stats_records().is_empty());
+    game.advance(24 * 3);
+
+    let records = game.stats_records();
+    assert_eq!(records.len(), game.stats.len());
+    for (record, day) in records.iter().zip(&game.stats) {
+        assert_eq!(record.stats.day, day.day);
+        assert_eq!(record.payroll, day.salaries + day.crew_wages);
+        assert_eq!(record.net, day.income - day.expenses);
+        let categorized = day.fuel
+            + day.fees
+            + record.payroll
+            + record.total_overhead
+            + record.total_taxes
+            + day.interest
+            + record.other;
+        assert!((categorized - day.expenses).abs() < 1e-2);
+    }
+
+    // flat, so each record becomes one data frame row
+    let row = serde_json::to_value(&records[0]).unwrap();
+    assert!(row.get("income").is_some());
+    assert!(row.get("other").is_some());
+    assert!(row.get("stats").is_none());
+}
@@ -0,0 +1,110 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{GameplayConfig, WorldConfig};
+use rusty_runways_core::utils::names::{
+    CITY_COUNT, airport_code, airport_kind, airport_name, city_name,
+};
+use std::collections::HashSet;
+
+fn generated(names: Vec<String>) -> WorldConfig {
+    WorldConfig {
+        seed: Some(4),
+        starting_cash: 650_000.0,
+        airports: vec![],
+        num_airports: Some(5),
+        airport_names: names,
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay: GameplayConfig::default(),
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn codes_count_up_by_id() {
+    assert_eq!(airport_code(0), "AAA");
+    assert_eq!(airport_code(1), "AAB");
+    assert_eq!(airport_code(25), "AAZ");
+    assert_eq!(airport_code(26), "ABA");
+    assert_eq!(airport_code(26 * 26 + 26 + 1), "BBB");
+}
+
+#[test]
+fn cities_are_unique_until_the_list_runs_out() {
+    let cities: HashSet<String> = (0..CITY_COUNT).map(|id| city_name(9, id)).collect();
+    assert_eq!(cities.len(), CITY_COUNT);
+    assert_eq!(
+        city_name(9, CITY_COUNT + 3),
+        format!("{} 2", city_name(9, 3))
+    );
+    // the seed picks which cities a world gets
+    assert_ne!(city_name(1, 0), city_name(2, 0));
+}
+
+#[test]
+fn kind_follows_the_runway() {
+    assert_eq!(airport_kind(4_000.0), "International");
+    assert_eq!(airport_kind(2_000.0), "Regional");
+    assert_eq!(airport_kind(1_000.0), "Airport");
+    assert_eq!(airport_kind(400.0), "Airfield");
+    assert_eq!(
+        airport_name(3, 7, 3_200.0),
+        format!("{} International", city_name(3, 7))
+    );
+}
+
+#[test]
+fn generated_airports_get_names_and_codes() {
+    let game = Game::new(12, Some(30), 650_000.0);
+    let names: HashSet<&str> = game
+        .airports()
+        .iter()
+        .map(|(a, _)| a.name.as_str())
+        .collect();
+    assert_eq!(names.len(), 30);
+    for (airport, _) in game.airports() {
+        assert!(airport.name.ends_with(airport_kind(airport.runway_length)));
+        assert_eq!(
+            airport.code.as_deref(),
+            Some(airport_code(airport.id).as_str())
+        );
+    }
+    assert_eq!(game.observe().airports[1].code.as_deref(), Some("AAB"));
+}
+
+#[test]
+fn config_names_theme_the_map() {
+    let game = Game::from_config(generated(vec![
+        "Olympus Mons Spaceport".into(),
+        "Hellas Basin Field".into(),
+    ]))
+    .unwrap();
+    let names: Vec<&str> = game
+        .airports()
+        .iter()
+        .map(|(a, _)| a.name.as_str())
+        .collect();
+    assert_eq!(names[0], "Olympus Mons Spaceport");
+    assert_eq!(names[1], "Hellas Basin Field");
+    // the rest fall back to generated names
+    assert!(names[2].starts_with(&city_name(4, 2)));
+    assert_eq!(game.airports()[0].0.code.as_deref(), Some("AAA"));
+}
+
+#[test]
+fn config_names_are_validated() {
+    let duplicates = generated(vec!["Base".into(), "base".into()]);
+    let issues = duplicates.validate();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].path, "airport_names[1]");
+
+    let mut without_generation = generated(vec!["Base".into()]);
+    without_generation.num_airports = None;
+    assert!(
+        without_generation
+            .validate()
+            .iter()
+            .any(|i| i.path == "airport_names")
+    );
+}
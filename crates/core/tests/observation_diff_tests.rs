@@ -0,0 +1,93 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::events::GameEvent;
+use rusty_runways_core::game::Observation;
+
+fn game_with_plane() -> Game {
+    let mut game = Game::new(1, Some(5), 10_000_000.0);
+    let home = game.map.airports.iter().next().unwrap().0.id;
+    game.buy_plane(&"SparrowLight".to_string(), home).unwrap();
+    game
+}
+
+#[test]
+fn full_patch_rebuilds_observation() {
+    let mut game = game_with_plane();
+    let mut view = Observation::default();
+    view.apply(game.observe_diff(0));
+    assert_eq!(view, game.observe());
+}
+
+#[test]
+fn incremental_patches_keep_view_current() {
+    let mut game = game_with_plane();
+    let mut view = Observation::default();
+    let patch = game.observe_diff(0);
+    let since = patch.version;
+    view.apply(patch);
+
+    let ids: Vec<usize> = game.map.airports.iter().map(|(a, _)| a.id).collect();
+    assert!(
+        ids.into_iter().any(|id| game.depart_plane(0, id).is_ok()),
+        "some airport in range"
+    );
+    game.advance(1);
+    let patch = game.observe_diff(since);
+    assert_eq!(patch.planes.len(), 1);
+    assert!(
+        patch
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::Departed { plane: 0, .. }))
+    );
+    view.apply(patch);
+    assert_eq!(view, game.observe());
+}
+
+#[test]
+fn unchanged_state_yields_empty_patch() {
+    let mut game = game_with_plane();
+    let since = game.observe_diff(0).version;
+    let patch = game.observe_diff(since);
+    assert!(patch.planes.is_empty());
+    assert!(patch.airports.is_empty());
+    assert!(patch.used_market.is_none());
+    assert!(patch.pilots.is_none());
+    assert!(patch.events.is_empty());
+}
+
+#[test]
+fn sold_planes_are_removed() {
+    let mut game = game_with_plane();
+    let mut view = Observation::default();
+    let patch = game.observe_diff(0);
+    let since = patch.version;
+    view.apply(patch);
+
+    game.sell_plane(0).unwrap();
+    let patch = game.observe_diff(since);
+    assert_eq!(patch.removed_planes, vec![0]);
+    view.apply(patch);
+    assert!(view.planes.iter().all(|p| p.id != 0));
+    assert_eq!(view, game.observe());
+}
+
+#[test]
+fn version_moves_only_on_change() {
+    let mut game = game_with_plane();
+    let version = game.state_version();
+    assert_eq!(game.state_version(), version);
+    assert_eq!(game.observe_diff(version).version, version);
+
+    game.advance(1);
+    let advanced = game.state_version();
+    assert!(advanced > version);
+    assert_eq!(game.state_version(), advanced);
+}
+
+#[test]
+fn unknown_version_returns_everything() {
+    let mut game = game_with_plane();
+    let patch = game.observe_diff(u64::MAX);
+    assert_eq!(patch.planes.len(), game.airplanes.len());
+    assert_eq!(patch.airports.len(), game.map.num_airports);
+}
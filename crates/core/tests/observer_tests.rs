@@ -0,0 +1,150 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(10.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn food_to(destination_id: usize) -> ManualOrderConfig {
+    ManualOrderConfig::Cargo {
+        cargo: CargoType::Food,
+        weight: 200.0,
+        value: 2_000.0,
+        deadline_hours: 200,
+        destination_id,
+        tier: Default::default(),
+    }
+}
+
+/// Two airports with a food order each way and one plane at the first.
+fn game() -> Game {
+    let mut origin = airport(0, 1_000.0);
+    origin.orders = vec![food_to(1)];
+    let mut destination = airport(1, 1_300.0);
+    destination.orders = vec![food_to(0)];
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+
+    Game::from_config(WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![origin, destination],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+fn fly_order(game: &mut Game) {
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(10);
+    game.unload_all(0).unwrap();
+}
+
+#[test]
+fn subscriber_sees_departure_arrival_and_delivery() {
+    let mut game = game();
+    let events = game.subscribe(EventFilter::all());
+    fly_order(&mut game);
+
+    let events: Vec<GameEvent> = events.try_iter().collect();
+    assert!(events.iter().any(|e| matches!(
+        e,
+        GameEvent::Departed {
+            plane: 0,
+            origin: 0,
+            destination: 1,
+            ..
+        }
+    )));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        GameEvent::Arrived {
+            plane: 0,
+            airport: 1,
+            ..
+        }
+    )));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        GameEvent::Delivered { airport: 1, payout, .. } if *payout > 0.0
+    )));
+    // the landing fee is charged during `advance`
+    assert!(events.iter().any(|e| matches!(
+        e,
+        GameEvent::CashChanged { delta, .. } if *delta < 0.0
+    )));
+}
+
+#[test]
+fn filter_limits_events_to_requested_kinds() {
+    let mut game = game();
+    let deliveries = game.subscribe(EventFilter::only(&[GameEventKind::Delivery]));
+    fly_order(&mut game);
+    game.advance(24);
+
+    let kinds: Vec<GameEventKind> = deliveries.try_iter().map(|e| e.kind()).collect();
+    assert_eq!(kinds, vec![GameEventKind::Delivery]);
+}
+
+#[test]
+fn daily_report_matches_stats() {
+    let mut game = game();
+    let reports = game.subscribe(EventFilter::only(&[GameEventKind::DailyReport]));
+    game.advance(24);
+
+    match reports.try_recv().unwrap() {
+        GameEvent::DailyReport(stats) => {
+            assert_eq!(stats.day, game.stats.last().unwrap().day);
+            assert_eq!(stats.net_cash, game.stats.last().unwrap().net_cash);
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+}
+
+#[test]
+fn dropped_receiver_does_not_break_the_game() {
+    let mut game = game();
+    drop(game.subscribe(EventFilter::all()));
+    let kept = game.subscribe(EventFilter::only(&[GameEventKind::Departure]));
+    fly_order(&mut game);
+
+    assert_eq!(kept.try_iter().count(), 1);
+    // subscriptions are runtime-only and never end up in saves
+    assert!(serde_json::to_string(&game).is_ok());
+}
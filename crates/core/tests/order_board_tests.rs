@@ -0,0 +1,138 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig, WorldConfig,
+};
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::OrderBoardFilter;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn cargo(weight: f32, value: f32, deadline_hours: u64, destination_id: usize) -> ManualOrderConfig {
+    ManualOrderConfig::Cargo {
+        cargo: CargoType::Food,
+        weight,
+        value,
+        deadline_hours,
+        destination_id,
+        tier: Default::default(),
+    }
+}
+
+fn airport(id: usize, x: f32, country: &str, orders: Vec<ManualOrderConfig>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("B{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: Some(country.into()),
+        orders,
+    }
+}
+
+/// Three airports in two countries, restocking every day.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig {
+        restock_cycle_hours: 24,
+        ..Default::default()
+    };
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    Game::from_config(WorldConfig {
+        seed: Some(3),
+        starting_cash: 100_000.0,
+        airports: vec![
+            airport(0, 1_000.0, "France", vec![cargo(1_000.0, 2_000.0, 100, 1)]),
+            airport(1, 1_500.0, "France", vec![cargo(500.0, 5_000.0, 30, 2)]),
+            airport(
+                2,
+                2_000.0,
+                "Spain",
+                vec![cargo(200.0, 1_000.0, 60, 0), cargo(300.0, 600.0, 200, 1)],
+            ),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+fn ids(orders: Vec<&rusty_runways_core::utils::orders::Order>) -> Vec<usize> {
+    orders.iter().map(|o| o.id).collect()
+}
+
+#[test]
+fn the_board_lists_every_airport_best_paying_first() {
+    let game = game();
+    assert_eq!(game.all_orders().len(), 4);
+
+    let board = game.find_orders(&OrderBoardFilter::default());
+    let per_kg: Vec<f32> = board.iter().map(|o| o.value_per_kg()).collect();
+    assert_eq!(per_kg, vec![10.0, 5.0, 2.0, 2.0]);
+}
+
+#[test]
+fn the_board_filters_by_country_value_and_deadline() {
+    let game = game();
+    let to_spain = game.find_orders(&OrderBoardFilter {
+        destination_country: Some("spain".into()),
+        ..Default::default()
+    });
+    assert_eq!(to_spain.len(), 1);
+    assert_eq!(to_spain[0].destination_id, 2);
+
+    let rich = game.find_orders(&OrderBoardFilter {
+        min_value_per_kg: Some(4.0),
+        ..Default::default()
+    });
+    assert_eq!(rich.len(), 2);
+
+    let urgent = game.find_orders(&OrderBoardFilter {
+        destination_country: Some("France".into()),
+        max_deadline: Some(60),
+        ..Default::default()
+    });
+    assert_eq!(urgent.len(), 1);
+    assert_eq!(urgent[0].value, 1_000.0);
+}
+
+#[test]
+fn reserved_orders_survive_restocks() {
+    let mut game = game();
+    let board = ids(game.all_orders());
+    let (kept, dropped) = (board[0], board[1]);
+
+    let fee = game.reserve_order(kept).unwrap();
+    assert!((fee - 100.0).abs() < 1e-3);
+    assert!((game.player.cash - 99_900.0).abs() < 1e-2);
+    assert!(matches!(
+        game.reserve_order(kept),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.reserve_order(999),
+        Err(GameError::OrderIdInvalid { id: 999 })
+    ));
+
+    game.advance(24);
+    let after = ids(game.all_orders());
+    assert!(after.contains(&kept));
+    assert!(!after.contains(&dropped));
+    let order = game
+        .all_orders()
+        .into_iter()
+        .find(|o| o.id == kept)
+        .unwrap();
+    assert_eq!(order.origin_id, 0);
+}
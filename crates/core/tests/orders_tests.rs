@@ -1,7 +1,7 @@
 use rusty_runways_core::utils::{
     coordinate::Coordinate,
     orders::{
-        Order,
+        Order, OrderTier,
         cargo::CargoType,
         order::{
             DEFAULT_MAX_DEADLINE_HOURS, DEFAULT_MAX_WEIGHT, DEFAULT_MIN_WEIGHT,
@@ -25,17 +25,23 @@ fn sample_airports() -> Vec<OrderAirportInfo> {
         OrderAirportInfo {
             id: 0,
             runway_length: 1_200.0,
+            size: 1.2,
             coordinate: Coordinate::new(0.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_400.0,
+            size: 2.4,
             coordinate: Coordinate::new(1_000.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 2,
             runway_length: 3_800.0,
+            size: 3.8,
             coordinate: Coordinate::new(0.0, 1_400.0),
+            country: None,
         },
     ]
 }
@@ -97,12 +103,16 @@ fn cannot_arrive_at_origin() {
         OrderAirportInfo {
             id: 0,
             runway_length: 1_200.0,
+            size: 1.2,
             coordinate: Coordinate::new(0.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 1_300.0,
+            size: 1.3,
             coordinate: Coordinate::new(1.0, 1.0),
+            country: None,
         },
     ];
     let origin = 1;
@@ -143,24 +153,32 @@ fn deadlines_and_value_scale_with_distance() {
         OrderAirportInfo {
             id: 0,
             runway_length: 2_500.0,
+            size: 2.5,
             coordinate: Coordinate::new(0.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_500.0,
+            size: 2.5,
             coordinate: Coordinate::new(120.0, 0.0),
+            country: None,
         },
     ];
     let far_airports = vec![
         OrderAirportInfo {
             id: 0,
             runway_length: 2_500.0,
+            size: 2.5,
             coordinate: Coordinate::new(0.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 2_500.0,
+            size: 2.5,
             coordinate: Coordinate::new(2_400.0, 0.0),
+            country: None,
         },
     ];
     let params = OrderGenerationParams::default();
@@ -177,12 +195,16 @@ fn deadlines_clamped_to_config_max() {
         OrderAirportInfo {
             id: 0,
             runway_length: 3_600.0,
+            size: 3.6,
             coordinate: Coordinate::new(0.0, 0.0),
+            country: None,
         },
         OrderAirportInfo {
             id: 1,
             runway_length: 3_200.0,
+            size: 3.2,
             coordinate: Coordinate::new(8_000.0, 0.0),
+            country: None,
         },
     ];
     let params = OrderGenerationParams {
@@ -234,3 +256,47 @@ fn passenger_deadlines_clamped() {
     let count = order.passenger_count().unwrap();
     assert!((DEFAULT_PASSENGER_MIN_COUNT..=DEFAULT_PASSENGER_MAX_COUNT).contains(&count));
 }
+
+#[test]
+fn tiers_adjust_deadline_and_value() {
+    let airports = sample_airports();
+    let no_tiers = OrderGenerationParams {
+        express_share: 0.0,
+        economy_share: 0.0,
+        ..OrderGenerationParams::default()
+    };
+    let all_express = OrderGenerationParams {
+        express_share: 1.0,
+        economy_share: 0.0,
+        ..OrderGenerationParams::default()
+    };
+    let all_economy = OrderGenerationParams {
+        express_share: 0.0,
+        economy_share: 1.0,
+        ..OrderGenerationParams::default()
+    };
+
+    for seed in 0..10 {
+        let standard = Order::new_cargo(seed, 1, 0, &airports, &no_tiers);
+        let express = Order::new_cargo(seed, 1, 0, &airports, &all_express);
+        let economy = Order::new_cargo(seed, 1, 0, &airports, &all_economy);
+
+        assert_eq!(standard.tier, OrderTier::Standard);
+        assert_eq!(express.tier, OrderTier::Express);
+        assert_eq!(economy.tier, OrderTier::Economy);
+        assert_eq!(express.destination_id, standard.destination_id);
+
+        assert!(express.deadline <= standard.deadline);
+        assert!(express.value > standard.value);
+        assert!(economy.deadline >= standard.deadline);
+        assert!(economy.deadline <= DEFAULT_MAX_DEADLINE_HOURS);
+        assert!(economy.value < standard.value);
+    }
+}
+
+#[test]
+fn passenger_orders_are_standard_tier() {
+    let airports = sample_airports();
+    let order = Order::new_passenger(4, 1, 0, &airports, &PassengerGenerationParams::default());
+    assert_eq!(order.tier, OrderTier::Standard);
+}
@@ -0,0 +1,113 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("O{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn game(starting_cash: f32) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.overdraft.limit = 10_000.0;
+    gameplay.overdraft.daily_interest_rate = 0.01;
+    Game::from_config(WorldConfig {
+        seed: Some(6),
+        starting_cash,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_500.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn refuels_can_draw_on_the_overdraft_up_to_its_limit() {
+    let mut game = game(0.0);
+    game.airplanes[0].current_fuel = 0.0;
+    game.refuel_plane(0).unwrap();
+    assert!(game.player.cash < 0.0);
+    assert!(game.player.cash >= -10_000.0);
+
+    game.advance(2);
+    game.player.cash = -9_999.0;
+    game.airplanes[0].current_fuel = 0.0;
+    assert!(matches!(
+        game.refuel_plane(0),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+    assert_eq!(game.player.cash, -9_999.0);
+}
+
+#[test]
+fn interest_is_charged_on_the_overdrawn_amount() {
+    let mut game = game(-5_000.0);
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert!((day.interest - 50.0).abs() < 1e-3);
+    assert!((game.player.cash + 5_050.0).abs() < 1e-2);
+
+    game.player.cash = 1_000.0;
+    game.advance(24);
+    assert_eq!(game.stats.last().unwrap().interest, 0.0);
+}
+
+#[test]
+fn a_warning_is_sent_once_before_the_limit() {
+    let mut game = game(-7_400.0);
+    let warnings = game.subscribe(EventFilter::only(&[GameEventKind::Overdraft]));
+    game.advance(24);
+    assert_eq!(warnings.try_iter().count(), 0);
+
+    game.advance(24 * 3);
+    let events: Vec<_> = warnings.try_iter().collect();
+    assert!(matches!(
+        events.as_slice(),
+        [GameEvent::OverdraftWarning { limit, balance, .. }] if *limit == 10_000.0 && *balance <= -7_500.0
+    ));
+
+    game.player.cash = 100.0;
+    game.advance(24);
+    game.player.cash = -8_000.0;
+    game.advance(24);
+    assert_eq!(warnings.try_iter().count(), 1);
+}
+
+#[test]
+fn overdraft_settings_are_validated() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.overdraft.limit = -1.0;
+    gameplay.overdraft.warning_share = 1.5;
+    let issues = gameplay.validate();
+    assert!(issues.iter().any(|i| i.path == "gameplay.overdraft.limit"));
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.path == "gameplay.overdraft.warning_share")
+    );
+}
@@ -0,0 +1,194 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn cargo(weight: f32, value: f32, deadline_hours: u64, destination_id: usize) -> ManualOrderConfig {
+    ManualOrderConfig::Cargo {
+        cargo: CargoType::Furniture,
+        weight,
+        value,
+        deadline_hours,
+        destination_id,
+        tier: Default::default(),
+    }
+}
+
+fn airport(id: usize, x: f32, orders: Vec<ManualOrderConfig>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("P{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: Some(50_000),
+        utc_offset: None,
+        country: None,
+        orders,
+    }
+}
+
+/// A freighter at a small airport (5 t an hour) with two loads for the next airport, one
+/// for the third and a passenger group.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    let mut origin = airport(
+        0,
+        1_000.0,
+        vec![
+            cargo(10_000.0, 30_000.0, 90, 1),
+            cargo(5_000.0, 20_000.0, 60, 1),
+            cargo(1_000.0, 5_000.0, 90, 2),
+        ],
+    );
+    origin.orders.push(ManualOrderConfig::Passengers {
+        passengers: 10,
+        value: 4_000.0,
+        deadline_hours: 90,
+        destination_id: 1,
+    });
+    Game::from_config(WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            origin,
+            airport(1, 1_400.0, vec![cargo(100.0, 1_000.0, 90, 0)]),
+            airport(2, 1_800.0, vec![cargo(100.0, 1_000.0, 90, 0)]),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "BisonFreighter".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+/// Ids of the orders waiting at airport 0, in config order.
+fn order_ids(game: &Game) -> Vec<usize> {
+    game.map
+        .airport(0)
+        .unwrap()
+        .orders
+        .iter()
+        .map(|o| o.id)
+        .collect()
+}
+
+fn hours_until_parked(game: &mut Game) -> u64 {
+    let start = game.time;
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    game.time - start
+}
+
+#[test]
+fn pallet_sums_its_orders_and_loads_faster() {
+    let mut game = game();
+    let ids = order_ids(&game);
+    let pallet_id = game.build_pallet(&ids[..2]).unwrap();
+
+    let airport = game.map.airport(0).unwrap();
+    assert_eq!(airport.orders.len(), 3);
+    let pallet = airport.orders.iter().find(|o| o.id == pallet_id).unwrap();
+    assert!(pallet.is_pallet());
+    assert_eq!(pallet.pallet.len(), 2);
+    assert_eq!(pallet.payload_weight(), 15_000.0);
+    assert_eq!(pallet.value, 50_000.0);
+    assert_eq!(pallet.deadline, 60);
+    assert_eq!(pallet.destination_id, 1);
+
+    // 15 t would take 3 hours loose
+    game.load_order(pallet_id, 0).unwrap();
+    assert_eq!(game.airplanes[0].manifest.len(), 1);
+    assert_eq!(game.airplanes[0].current_payload, 15_000.0);
+    assert_eq!(hours_until_parked(&mut game), 2);
+}
+
+#[test]
+fn pallet_is_broken_down_and_paid_at_its_destination() {
+    let mut game = game();
+    let ids = order_ids(&game);
+    let pallet_id = game.build_pallet(&ids[..2]).unwrap();
+    game.load_order(pallet_id, 0).unwrap();
+    hours_until_parked(&mut game);
+    game.depart_plane(0, 1).unwrap();
+    hours_until_parked(&mut game);
+
+    let cash = game.player.cash;
+    game.unload_all(0).unwrap();
+    assert_eq!(game.player.orders_delivered, 2);
+    assert!((game.player.cash - cash - 50_000.0).abs() < 1.0);
+    assert!(game.airplanes[0].manifest.is_empty());
+    assert!(game.staged_orders.is_empty());
+}
+
+#[test]
+fn pallets_only_take_matching_cargo() {
+    let mut game = game();
+    let ids = order_ids(&game);
+    assert!(matches!(
+        game.build_pallet(&ids[..1]),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.build_pallet(&[ids[0], ids[2]]),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.build_pallet(&[ids[0], ids[3]]),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    let elsewhere = game.map.airport(1).unwrap().orders[0].id;
+    assert!(matches!(
+        game.build_pallet(&[ids[0], elsewhere]),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.build_pallet(&[ids[0], 9_999]),
+        Err(GameError::OrderIdInvalid { id: 9_999 })
+    ));
+    assert_eq!(order_ids(&game), ids);
+}
+
+#[test]
+fn breaking_a_pallet_restores_its_orders_and_reservation() {
+    let mut game = game();
+    let ids = order_ids(&game);
+    game.reserve_order(ids[0]).unwrap();
+    let pallet_id = game.build_pallet(&ids[..2]).unwrap();
+    assert!(game.reserved_orders.contains(&pallet_id));
+    assert!(matches!(
+        game.split_order(pallet_id, 1_000.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+
+    let mut restored = game.break_pallet(pallet_id).unwrap();
+    restored.sort();
+    assert_eq!(restored, ids[..2]);
+    assert!(!game.reserved_orders.contains(&pallet_id));
+    assert!(game.reserved_orders.contains(&ids[0]));
+    assert!(game.reserved_orders.contains(&ids[1]));
+    assert!(matches!(
+        game.break_pallet(ids[2]),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
@@ -0,0 +1,106 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::FuelAmount;
+use rusty_runways_core::utils::errors::GameError;
+
+/// One airport with plenty of fuel at 1.0 per liter and an empty SparrowLight (240 L tank).
+fn empty_tank_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![AirportConfig {
+            id: 0,
+            name: "Pump".into(),
+            location: Some(Location {
+                x: 1_000.0,
+                y: 1_000.0,
+            }),
+            runway_length_m: Some(2_000.0),
+            fuel_price_per_l: Some(1.0),
+            landing_fee_per_ton: Some(0.0),
+            parking_fee_per_hour: Some(0.0),
+            has_hangar: None,
+            population: None,
+            utc_offset: None,
+            country: None,
+            orders: Vec::new(),
+        }],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 0.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn fuel_amounts_parse_from_liters_and_percentages() {
+    assert_eq!("500".parse::<FuelAmount>(), Ok(FuelAmount::Liters(500.0)));
+    assert_eq!(
+        "80%".parse::<FuelAmount>(),
+        Ok(FuelAmount::TargetPercent(80.0))
+    );
+    assert_eq!("full".parse::<FuelAmount>(), Ok(FuelAmount::Full));
+    assert!("0%".parse::<FuelAmount>().is_err());
+    assert!("120%".parse::<FuelAmount>().is_err());
+    assert!("-5".parse::<FuelAmount>().is_err());
+    assert!("lots".parse::<FuelAmount>().is_err());
+}
+
+#[test]
+fn refuel_by_liters_charges_only_what_was_pumped() {
+    let mut game = empty_tank_game();
+    let cash = game.player.cash;
+    game.refuel_plane_with(0, FuelAmount::Liters(50.0)).unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 50.0);
+    assert!((cash - game.player.cash - 50.0).abs() < 1e-3);
+    assert_eq!(game.map.airport(0).unwrap().fuel_sold, 50.0);
+
+    // more than the tank holds is capped at the free space
+    game.advance(1);
+    let cash = game.player.cash;
+    game.refuel_plane_with(0, FuelAmount::Liters(1_000.0))
+        .unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 240.0);
+    assert!((cash - game.player.cash - 190.0).abs() < 1e-3);
+}
+
+#[test]
+fn refuel_to_target_tops_up_to_the_requested_level() {
+    let mut game = empty_tank_game();
+    game.refuel_plane_with(0, FuelAmount::TargetPercent(50.0))
+        .unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 120.0);
+
+    game.advance(1);
+    assert!(matches!(
+        game.refuel_plane_with(0, FuelAmount::TargetPercent(25.0)),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert_eq!(game.airplanes[0].current_fuel, 120.0);
+}
+
+#[test]
+fn refuel_command_accepts_amounts() {
+    let mut game = empty_tank_game();
+    game.execute_str("REFUEL PLANE 0 60").unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 60.0);
+    game.advance(1);
+    game.execute_str("REFUEL PLANE 0 TO 75%").unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 180.0);
+    game.advance(1);
+    game.execute_str("REFUEL PLANE 0").unwrap();
+    assert_eq!(game.airplanes[0].current_fuel, 240.0);
+}
@@ -0,0 +1,268 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::pilots::Pilot;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("C{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Pilots may fly 4 hours before a 6 hour rest.
+fn gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.max_duty_hours = 4;
+    gameplay.pilots.rest_hours = 6;
+    gameplay
+}
+
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(12),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_520.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// Fly the 520km leg (2 hours in a SparrowLight) and refuel at the other end.
+fn fly_leg(game: &mut Game, dest: usize) {
+    game.depart_plane(0, dest).unwrap();
+    game.advance(2);
+    game.refuel_plane(0).unwrap();
+    game.advance(1);
+}
+
+#[test]
+fn starting_planes_come_with_a_pilot() {
+    let game = Game::from_config(world(gameplay())).unwrap();
+    assert_eq!(game.player.pilots.len(), 1);
+    let pilot = &game.player.pilots[0];
+    assert_eq!(pilot.plane, Some(0));
+    assert_eq!(pilot.airport_id, 0);
+}
+
+#[test]
+fn pilots_follow_their_plane_and_log_duty_hours() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    let pilot = &game.player.pilots[0];
+    assert_eq!(pilot.airport_id, 1);
+    assert_eq!(pilot.duty_hours, 2);
+}
+
+#[test]
+fn tired_pilots_must_rest_before_flying_again() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    fly_leg(&mut game, 0);
+    assert!(matches!(
+        game.depart_plane(0, 1),
+        Err(GameError::NoRestedPilot { plane_id: 0 })
+    ));
+    // the failed departure burns no fuel
+    assert_eq!(
+        game.airplanes[0].current_fuel,
+        game.airplanes[0].specs.fuel_capacity
+    );
+
+    game.advance(6);
+    game.depart_plane(0, 1).unwrap();
+    assert_eq!(game.player.pilots[0].duty_hours, 0);
+}
+
+#[test]
+fn a_rested_pilot_at_the_airport_takes_over() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    fly_leg(&mut game, 0);
+    let fresh = game.hire_pilot(0).unwrap();
+    game.depart_plane(0, 1).unwrap();
+
+    let flying = game.player.pilots.iter().find(|p| p.id == fresh).unwrap();
+    assert_eq!(flying.plane, Some(0));
+    assert_eq!(game.player.pilots[0].plane, None);
+    assert_eq!(game.player.pilots[0].airport_id, 0);
+}
+
+#[test]
+fn pilots_are_assigned_at_the_same_airport() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let remote = game.hire_pilot(1).unwrap();
+    assert!(matches!(
+        game.assign_pilot(remote, 0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.assign_pilot(42, 0),
+        Err(GameError::PilotIdInvalid { id: 42 })
+    ));
+
+    let local = game.hire_pilot(0).unwrap();
+    game.assign_pilot(local, 0).unwrap();
+    assert_eq!(game.player.pilots[0].plane, None);
+
+    game.depart_plane(0, 1).unwrap();
+    assert!(matches!(
+        game.fire_pilot(local),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    game.fire_pilot(0).unwrap();
+    assert_eq!(game.player.pilots.len(), 2);
+}
+
+#[test]
+fn hiring_costs_a_fee_and_salaries_are_paid_daily() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let settings = game.pilot_settings.clone();
+    let cash = game.player.cash;
+    game.hire_pilot(1).unwrap();
+    assert!((cash - game.player.cash - settings.hire_fee).abs() < 1e-2);
+
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert!((day.salaries - 2.0 * settings.salary_per_day).abs() < 1e-2);
+
+    game.player.cash = -game.player.overdraft_limit;
+    assert!(matches!(
+        game.hire_pilot(0),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+}
+
+#[test]
+fn planes_fly_without_pilots_when_not_required() {
+    let mut gameplay = gameplay();
+    gameplay.pilots.required = false;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    assert!(game.player.pilots.is_empty());
+    fly_leg(&mut game, 1);
+    fly_leg(&mut game, 0);
+    fly_leg(&mut game, 1);
+}
+
+#[test]
+fn old_saves_do_not_require_pilots() {
+    let game = Game::from_config(world(gameplay())).unwrap();
+    let mut json = serde_json::to_value(&game).unwrap();
+    let save = json.as_object_mut().unwrap();
+    save.remove("pilot_settings");
+    save["player"].as_object_mut().unwrap().remove("pilots");
+    let mut game: Game = serde_json::from_value(json).unwrap();
+    assert!(!game.pilot_settings.required);
+    game.depart_plane(0, 1).unwrap();
+}
+
+#[test]
+fn pilots_gain_experience_on_models_and_routes() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    fly_leg(&mut game, 1);
+    fly_leg(&mut game, 0);
+    let pilot = &game.player.pilots[0];
+    assert_eq!(pilot.experience_hours, 4);
+    assert_eq!(pilot.hours_on("SparrowLight"), 4);
+    assert_eq!(pilot.hours_on("Atlas"), 0);
+    // both directions count towards the same route
+    assert_eq!(pilot.flights_on(0, 1), 2);
+    assert_eq!(pilot.flights_on(1, 0), 2);
+}
+
+/// Fuel burnt on the first leg out of airport 0.
+fn fuel_burnt(gameplay: GameplayConfig) -> f32 {
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    game.airplanes[0].specs.fuel_capacity - game.airplanes[0].current_fuel
+}
+
+#[test]
+fn rated_pilots_burn_less_fuel() {
+    let mut unrated = gameplay();
+    unrated.pilots.type_rating_hours = 1_000;
+    let mut rated = gameplay();
+    rated.pilots.type_rating_hours = 0;
+    let mut veteran = gameplay();
+    veteran.pilots.type_rating_hours = 0;
+    veteran.pilots.familiar_route_flights = 0;
+
+    let base = fuel_burnt(unrated);
+    assert!((fuel_burnt(rated) - base * 0.97).abs() < 1e-2);
+    assert!((fuel_burnt(veteran) - base * 0.95).abs() < 1e-2);
+}
+
+#[test]
+fn veterans_turn_planes_around_straight_away() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    game.refuel_plane(0).unwrap();
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Refueling);
+
+    let mut gameplay = gameplay();
+    gameplay.pilots.quick_turnaround_hours = 2;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    game.refuel_plane(0).unwrap();
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    game.depart_plane(0, 0).unwrap();
+}
+
+#[test]
+fn old_pilots_start_without_experience() {
+    let mut value = serde_json::to_value(Pilot::new(3, 1)).unwrap();
+    let fields = value.as_object_mut().unwrap();
+    for field in ["experience_hours", "type_hours", "route_flights"] {
+        fields.remove(field);
+    }
+    let pilot: Pilot = serde_json::from_value(value).unwrap();
+    assert_eq!(pilot.experience_hours, 0);
+    assert!(pilot.type_hours.is_empty());
+}
+
+#[test]
+fn invalid_pilot_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.pilots.salary_per_day = -1.0;
+    gameplay.pilots.max_duty_hours = 0;
+    let paths: Vec<String> = world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.pilots.salary_per_day",
+            "gameplay.pilots.max_duty_hours"
+        ]
+    );
+}
@@ -0,0 +1,91 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::utils::errors::GameError;
+use std::fs;
+
+#[test]
+fn planes_get_default_tail_numbers() {
+    let mut game = Game::new(7, Some(6), 10_000_000.0);
+    assert_eq!(game.airplanes[0].name, "RR-000");
+
+    let airport = game
+        .airports()
+        .iter()
+        .find(|(a, _)| a.runway_length >= 1_000.0)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    game.buy_plane(&"SparrowLight".to_string(), airport)
+        .unwrap();
+    assert_eq!(game.airplanes[1].name, "RR-001");
+    assert_eq!(game.player.fleet[1].name, "RR-001");
+    assert_eq!(game.observe().planes[1].name, "RR-001");
+}
+
+#[test]
+fn rename_command_keeps_spaces_and_persists() {
+    let mut game = Game::new(7, Some(6), 650_000.0);
+    game.execute_str("RENAME PLANE 0 \"Spirit of AAB\"")
+        .unwrap();
+    assert_eq!(game.airplanes[0].name, "Spirit of AAB");
+    assert_eq!(game.player.fleet[0].name, "Spirit of AAB");
+
+    let json = serde_json::to_string(&game).unwrap();
+    let restored: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.airplanes[0].name, "Spirit of AAB");
+}
+
+#[test]
+fn invalid_names_are_rejected() {
+    let mut game = Game::new(7, Some(6), 10_000_000.0);
+    assert!(matches!(
+        game.rename_plane(0, "   "),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.rename_plane(0, &"X".repeat(33)),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.rename_plane(5, "Ghost"),
+        Err(GameError::PlaneIdInvalid { id: 5 })
+    ));
+
+    let airport = game
+        .airports()
+        .iter()
+        .find(|(a, _)| a.runway_length >= 1_000.0)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    game.buy_plane(&"SparrowLight".to_string(), airport)
+        .unwrap();
+    game.rename_plane(1, "Workhorse").unwrap();
+    assert!(matches!(
+        game.rename_plane(0, "workhorse"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    // Re-applying a plane's own name is fine
+    game.rename_plane(1, "Workhorse").unwrap();
+}
+
+#[test]
+fn saves_without_names_get_tail_numbers_on_load() {
+    let game = Game::new(7, Some(6), 650_000.0);
+    let mut value = serde_json::to_value(&game).unwrap();
+    value["airplanes"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("name");
+    value["player"]["fleet"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("name");
+
+    let name = "plane_name_legacy_save";
+    let path = format!("save_games/{}.json", name);
+    fs::create_dir_all("save_games").unwrap();
+    fs::write(&path, serde_json::to_string(&value).unwrap()).unwrap();
+    let loaded = Game::load_game(name).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.airplanes[0].name, "RR-000");
+    assert_eq!(loaded.player.fleet[0].name, "RR-000");
+}
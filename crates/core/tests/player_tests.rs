@@ -1,4 +1,5 @@
 use rusty_runways_core::player::Player;
+use rusty_runways_core::utils::airplanes::airplane::RESALE_NEW_SHARE;
 use rusty_runways_core::utils::airport::Airport;
 use rusty_runways_core::utils::coordinate::Coordinate;
 use rusty_runways_core::utils::errors::GameError;
@@ -13,7 +14,7 @@ fn player_initialization_gives_single_plane() {
     assert_eq!(player.orders_delivered, 0);
 
     let (_min_distance, start_index) = map.min_distance();
-    let expected = map.airports[start_index].1;
+    let expected = map.coord(start_index).unwrap();
     let plane = &player.fleet[0];
     assert_eq!(plane.location.x, expected.x);
     assert_eq!(plane.location.y, expected.y);
@@ -101,10 +102,30 @@ fn sell_plane_returns_refund_and_updates_state() {
     let map = Map::generate_from_seed(7, Some(3));
     let mut player = Player::new(650_000.0, &map);
     let starting_cash = player.cash;
-    let (plane, refund) = player.sell_plane(0).expect("plane 0 should exist");
+    let (plane, refund) = player.sell_plane(0, 0).expect("plane 0 should exist");
     assert_eq!(plane.id, 0);
-    assert!((refund - plane.specs.purchase_price * 0.6).abs() < f32::EPSILON);
+    assert!((refund - plane.specs.purchase_price * RESALE_NEW_SHARE).abs() < f32::EPSILON);
     assert!((player.cash - (starting_cash + refund)).abs() < f32::EPSILON);
     assert_eq!(player.fleet_size, player.fleet.len());
     assert!(player.fleet.iter().all(|p| p.id != 0));
 }
+
+#[test]
+fn plane_ids_are_not_reused_after_a_sale() {
+    let map = Map::generate_from_seed(6, Some(2));
+    let mut player = Player::new(2_000_000.0, &map);
+    let mut airport = Airport::generate_random(6, 10);
+    airport.runway_length = 4000.0;
+    let coord = Coordinate::new(0.0, 0.0);
+    let model = "SparrowLight".to_string();
+
+    player.buy_plane(&model, &mut airport, &coord).unwrap();
+    player.sell_plane(1, 0).unwrap();
+    player.buy_plane(&model, &mut airport, &coord).unwrap();
+    player.sell_plane(0, 0).unwrap();
+    player.buy_plane(&model, &mut airport, &coord).unwrap();
+
+    let ids: Vec<usize> = player.fleet.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+    assert_eq!(player.fleet_size, 2);
+}
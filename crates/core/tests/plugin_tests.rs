@@ -0,0 +1,194 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    RareCargoGameplay, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::plugins::PluginError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Food,
+            weight: 200.0,
+            value: 2_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+/// Two airports with a food order each way, one plane and no running costs.
+fn game(regenerate: bool) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = regenerate;
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.rare_cargo = RareCargoGameplay::none();
+
+    Game::from_config(WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, 1), airport(1, 1_300.0, 0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[cfg(feature = "plugins")]
+fn wasm(source: &str) -> Vec<u8> {
+    wat::parse_str(source).unwrap()
+}
+
+#[cfg(feature = "plugins")]
+fn deliver(game: &mut Game) -> f32 {
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(10);
+    let before = game.player.cash;
+    game.unload_all(0).unwrap();
+    game.player.cash - before
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn on_delivery_adjusts_the_payout() {
+    let mut plain = game(false);
+    let base = deliver(&mut plain);
+
+    let mut game = game(false);
+    game.load_plugin(
+        "double",
+        &wasm(
+            r#"(module
+                (func (export "on_delivery") (param i64 i64 f32) (result f32)
+                    local.get 2
+                    f32.const 2
+                    f32.mul))"#,
+        ),
+    )
+    .unwrap();
+    assert_eq!(game.plugin_names(), vec!["double"]);
+    assert!((deliver(&mut game) - 2.0 * base).abs() < 0.01);
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn on_day_end_can_pay_the_player() {
+    let mut game = game(false);
+    game.load_plugin(
+        "subsidy",
+        &wasm(
+            r#"(module
+                (import "host" "add_cash" (func $add_cash (param f32)))
+                (func (export "on_day_end") (param i64 f32)
+                    f32.const 500
+                    call $add_cash))"#,
+        ),
+    )
+    .unwrap();
+    let cash = game.player.cash;
+    game.advance(24);
+
+    let report = game.stats.last().unwrap();
+    assert_eq!(report.income, 500.0);
+    assert!(game.player.cash > cash + 499.0);
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn on_order_generated_reprices_restocked_orders() {
+    let mut game = game(true);
+    game.load_plugin(
+        "flat",
+        &wasm(
+            r#"(module
+                (func (export "on_order_generated") (param i64 i64 i64 f32) (result f32)
+                    f32.const 1234))"#,
+        ),
+    )
+    .unwrap();
+    game.advance(game.restock_cycle + 1);
+
+    // the two orders from the config predate the plugin
+    let repriced: Vec<f32> = game
+        .map
+        .airports
+        .iter()
+        .flat_map(|(a, _)| a.orders.iter())
+        .filter(|o| o.id > 1)
+        .map(|o| o.value)
+        .collect();
+    assert!(!repriced.is_empty());
+    assert!(repriced.iter().all(|v| *v == 1234.0));
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn runaway_plugin_is_unloaded() {
+    let mut game = game(false);
+    game.load_plugin(
+        "spin",
+        &wasm(
+            r#"(module
+                (func (export "on_day_end") (param i64 f32)
+                    (loop $forever (br $forever))))"#,
+        ),
+    )
+    .unwrap();
+    game.advance(48);
+
+    assert!(game.plugin_names().is_empty());
+    assert_eq!(game.stats.len(), 2);
+    assert!(
+        game.drain_log()
+            .iter()
+            .any(|m| m.contains("Plugin 'spin' failed"))
+    );
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn invalid_module_is_rejected() {
+    let mut game = game(false);
+    let err = game.load_plugin("junk", b"not wasm").unwrap_err();
+    assert!(matches!(err, PluginError::Load { name, .. } if name == "junk"));
+}
+
+#[cfg(not(feature = "plugins"))]
+#[test]
+fn plugins_need_the_feature() {
+    let mut game = game(false);
+    assert_eq!(
+        game.load_plugin("any", b"\0asm").unwrap_err(),
+        PluginError::Unsupported
+    );
+}
@@ -0,0 +1,98 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airport::{Airport, default_population};
+
+fn airport(id: usize, x: f32, population: Option<u32>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("P{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(100.0),
+        has_hangar: None,
+        population,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn world(population: Option<u32>, planes: usize) -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    gameplay.fuel.headwind_chance = 0.0;
+    WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, population), airport(1, 1_400.0, None)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![
+            StartingPlaneConfig {
+                model: "SparrowLight".into(),
+                home_airport: 0,
+                fuel_level: 1.0,
+            };
+            planes
+        ],
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn population_defaults_to_runway_length() {
+    let game = Game::from_config(world(None, 1)).unwrap();
+    let airport = game.map.airport(0).unwrap();
+    assert_eq!(airport.population, default_population(3_000.0));
+    assert!((airport.size() - 3.0).abs() < 1e-4);
+    // a 3 km runway at size 3 makes a hub, with six stands per unit of size
+    assert_eq!(airport.parking_stands(), 18);
+}
+
+#[test]
+fn configured_population_is_observed_and_exported() {
+    let game = Game::from_config(world(Some(40_000), 1)).unwrap();
+    let obs = game.observe();
+    let airport = obs.airports.iter().find(|a| a.id == 0).unwrap();
+    assert_eq!(airport.population, 40_000);
+    assert_eq!(airport.parking_stands, 2);
+
+    let exported = game.export_config();
+    assert_eq!(exported.airports[0].population, Some(40_000));
+}
+
+#[test]
+fn small_population_means_cheaper_fees() {
+    let small = Airport::with_population(1, 0, "Small".into(), 3_000.0, 20_000);
+    let large = Airport::with_population(1, 0, "Large".into(), 3_000.0, 300_000);
+    assert!(small.landing_fee < large.landing_fee);
+    assert!(small.parking_fee < large.parking_fee);
+}
+
+#[test]
+fn zero_population_is_rejected() {
+    let issues = world(Some(0), 1).validate();
+    assert!(issues.iter().any(|i| i.path == "airports[0].population"));
+}
+
+#[test]
+fn planes_beyond_the_stands_pay_remote_parking() {
+    let parking_cost = |planes: usize| {
+        let mut game = Game::from_config(world(Some(40_000), planes)).unwrap();
+        game.advance(5);
+        let cash = game.player.cash;
+        game.depart_plane(0, 1).unwrap();
+        cash - game.player.cash
+    };
+    assert!((parking_cost(2) - 500.0).abs() < 1.0);
+    assert!((parking_cost(3) - 1_000.0).abs() < 1.0);
+}
@@ -0,0 +1,149 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, RareCargoGameplay, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+use rusty_runways_core::utils::airplanes::models::AirplaneModel;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::RareCargo;
+use rusty_runways_core::utils::orders::order::{Order, OrderPayload, OrderTier};
+use rusty_runways_core::utils::orders::rare::generate_rare_order;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("R{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Three airports, a SparrowLight at the first and a rare load every day.
+fn rare_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.rare_cargo.chance_per_day = 1.0;
+    Game::from_config(WorldConfig {
+        seed: Some(12),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_800.0),
+            airport(2, 3_000.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn rare_loads_pay_well_and_leave_little_slack() {
+    let game = rare_game();
+    let airports = game.map.order_airport_infos();
+    let params = RareCargoGameplay::default();
+    for seed in 0..50 {
+        let order = generate_rare_order(seed, 7, &airports, &params).unwrap();
+        let kind = order.rare.unwrap();
+        assert_eq!(order.cargo_type(), Some(kind.cargo_type()));
+        assert_eq!(order.tier, OrderTier::Express);
+        assert_ne!(order.origin_id, order.destination_id);
+        assert!(order.value >= params.min_value);
+        assert!(order.deadline <= 24, "seed {}: {}h", seed, order.deadline);
+    }
+    assert!(generate_rare_order(1, 0, &airports[..1], &params).is_none());
+}
+
+#[test]
+fn rare_loads_are_announced_and_wait_reserved() {
+    let mut game = rare_game();
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::RareCargo]));
+    game.advance(24);
+
+    let events: Vec<GameEvent> = feed.try_iter().collect();
+    assert_eq!(events.len(), 1);
+    let GameEvent::RareCargo {
+        time,
+        order,
+        airport,
+        kind,
+    } = events[0]
+    else {
+        panic!("expected a rare cargo event");
+    };
+    assert_eq!(time, 24);
+    assert!(game.reserved_orders.contains(&order));
+    let waiting = game
+        .map
+        .airport(airport)
+        .unwrap()
+        .orders
+        .iter()
+        .find(|o| o.id == order)
+        .unwrap();
+    assert_eq!(waiting.rare, Some(kind));
+}
+
+#[test]
+fn rare_loads_need_a_suitable_plane() {
+    let sparrow = AirplaneModel::SparrowLight.specs();
+    assert!(!RareCargo::OrganTransport.can_fly(&sparrow));
+    assert!(RareCargo::OrganTransport.can_fly(&AirplaneModel::FalconJet.specs()));
+    assert!(RareCargo::ConcertEquipment.can_fly(&sparrow));
+    assert!(!RareCargo::SatelliteParts.can_fly(&sparrow));
+    assert!(RareCargo::SatelliteParts.can_fly(&AirplaneModel::BisonFreighter.specs()));
+
+    let mut game = rare_game();
+    let order_id = game.map.allocate_order_id();
+    game.map.airport_mut(0).unwrap().orders.push(Order {
+        id: order_id,
+        payload: OrderPayload::Cargo {
+            cargo_type: RareCargo::SatelliteParts.cargo_type(),
+            weight: 500.0,
+        },
+        value: 90_000.0,
+        deadline: 12,
+        origin_id: 0,
+        destination_id: 1,
+        tier: OrderTier::Express,
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: Some(RareCargo::SatelliteParts),
+        pallet: Vec::new(),
+    });
+    assert!(matches!(
+        game.load_order(order_id, 0),
+        Err(GameError::PayloadTypeUnsupported { .. })
+    ));
+}
+
+#[test]
+fn rare_cargo_can_be_switched_off() {
+    let mut config = rare_game().export_config();
+    config.gameplay.rare_cargo.chance_per_day = 0.0;
+    let mut game = Game::from_config(config).unwrap();
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::RareCargo]));
+    game.advance(24 * 5);
+    assert_eq!(feed.try_iter().count(), 0);
+}
@@ -0,0 +1,163 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::airplane::Airplane;
+use rusty_runways_core::utils::airplanes::checks::CheckType;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("R{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// World where every landing ends in a breakdown.
+fn breakdown_world() -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 1.0;
+    world(gameplay)
+}
+
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(21),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_520.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// Fly the 520km leg (2 hours in a SparrowLight) and land broken.
+fn land_broken() -> Game {
+    let mut game = Game::from_config(breakdown_world()).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Broken);
+    assert!(game.airplanes[0].awaiting_repair);
+    game
+}
+
+#[test]
+fn broken_plane_stays_grounded_until_repaired() {
+    let mut game = land_broken();
+    // no automatic repair
+    game.advance(24);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Broken);
+    assert!(matches!(
+        game.depart_plane(0, 0),
+        Err(GameError::PlaneNotReady { .. })
+    ));
+}
+
+#[test]
+fn repair_costs_parts_and_takes_time() {
+    let mut game = land_broken();
+    let settings = game.maintenance_settings.clone();
+    let cash = game.player.cash;
+    let price = game.airplanes[0].specs.purchase_price;
+
+    game.repair_plane(0).unwrap();
+    assert!((cash - game.player.cash - price * settings.repair_cost_share).abs() < 1e-2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Maintenance);
+
+    game.advance(settings.repair_hours - 1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Maintenance);
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!(!game.airplanes[0].awaiting_repair);
+
+    // nothing left to fix
+    assert!(matches!(
+        game.repair_plane(0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn repair_needs_cash_and_blocks_other_maintenance() {
+    let mut game = land_broken();
+    assert!(matches!(
+        game.maintenance_on_airplane(0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.perform_check(0, CheckType::A),
+        Err(GameError::InvalidCommand { .. })
+    ));
+
+    game.player.cash = -game.player.overdraft_limit;
+    assert!(matches!(
+        game.repair_plane(0),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+    assert!(game.airplanes[0].awaiting_repair);
+}
+
+#[test]
+fn worn_planes_fail_more_often() {
+    let mut game = Game::from_config(world(GameplayConfig::default())).unwrap();
+    let plane = &mut game.airplanes[0];
+    let fresh = plane.landing_failure_chance(0.01);
+    plane.hours_since_maintenance = 200;
+    let worn = plane.landing_failure_chance(0.01);
+    plane.needs_maintenance = true;
+    let broken = plane.landing_failure_chance(0.01);
+    assert!((fresh - 0.01).abs() < 1e-6);
+    assert!(worn > fresh);
+    assert!((broken - 2.0 * worn).abs() < 1e-6);
+    assert_eq!(plane.landing_failure_chance(1.0), 1.0);
+}
+
+#[test]
+fn legacy_saves_default_to_no_pending_repair() {
+    let game = Game::from_config(world(GameplayConfig::default())).unwrap();
+    let mut value = serde_json::to_value(&game.airplanes[0]).unwrap();
+    value.as_object_mut().unwrap().remove("awaiting_repair");
+    let plane: Airplane = serde_json::from_value(value).unwrap();
+    assert!(!plane.awaiting_repair);
+}
+
+#[test]
+fn invalid_repair_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.maintenance.landing_failure_chance = 1.5;
+    gameplay.maintenance.repair_hours = 0;
+    let paths: Vec<String> = world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.maintenance.landing_failure_chance",
+            "gameplay.maintenance.repair_hours"
+        ]
+    );
+}
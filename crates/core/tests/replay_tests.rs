@@ -0,0 +1,103 @@
+//! Replays recorded games and compares the final `state_hash()` with a golden value.
+//!
+//! Each file in `tests/replays` starts with `key: value` headers (`seed`, `airports`,
+//! `cash`, `hash`) followed by one command per line; `#` starts a comment. A changed hash
+//! means the simulation no longer plays out the same way. If that is intended, rerun with
+//! `BLESS_REPLAYS=1` to record the new values.
+
+use rusty_runways_core::Game;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Replay {
+    seed: u64,
+    airports: usize,
+    cash: f32,
+    hash: String,
+    commands: Vec<String>,
+}
+
+fn parse(text: &str) -> Replay {
+    let mut replay = Replay {
+        seed: 0,
+        airports: 5,
+        cash: 650_000.0,
+        hash: String::new(),
+        commands: Vec::new(),
+    };
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once(':') {
+            Some(("seed", v)) => replay.seed = v.trim().parse().unwrap(),
+            Some(("airports", v)) => replay.airports = v.trim().parse().unwrap(),
+            Some(("cash", v)) => replay.cash = v.trim().parse().unwrap(),
+            Some(("hash", v)) => replay.hash = v.trim().to_string(),
+            _ => replay.commands.push(line.to_string()),
+        }
+    }
+    replay
+}
+
+fn play(path: &Path) -> (Replay, String) {
+    let replay = parse(&fs::read_to_string(path).unwrap());
+    let mut game = Game::new(replay.seed, Some(replay.airports), replay.cash);
+    for command in &replay.commands {
+        if let Err(e) = game.execute_str(command) {
+            panic!("{}: `{}` failed: {}", path.display(), command, e);
+        }
+    }
+    let hash = game.state_hash_hex();
+    (replay, hash)
+}
+
+fn corpus() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/replays");
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "replay"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn replays_match_golden_hashes() {
+    let bless = std::env::var_os("BLESS_REPLAYS").is_some();
+    let files = corpus();
+    assert!(!files.is_empty(), "no replays found");
+
+    let mut mismatches = Vec::new();
+    for path in files {
+        let (replay, hash) = play(&path);
+        if hash == replay.hash {
+            continue;
+        }
+        if bless {
+            let text = fs::read_to_string(&path).unwrap();
+            let old = format!("hash: {}", replay.hash);
+            fs::write(&path, text.replacen(&old, &format!("hash: {}", hash), 1)).unwrap();
+        } else {
+            mismatches.push(format!(
+                "{}: expected {}, got {}",
+                path.display(),
+                replay.hash,
+                hash
+            ));
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "replays diverged (rerun with BLESS_REPLAYS=1 if intended):\n{}",
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn replays_are_deterministic() {
+    for path in corpus() {
+        assert_eq!(play(&path).1, play(&path).1, "{}", path.display());
+    }
+}
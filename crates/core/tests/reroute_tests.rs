@@ -0,0 +1,136 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Airport 1 is 800km east of the start, airport 2 sits 300km along the way and
+/// airport 3 is far beyond the SparrowLight's range.
+fn reroute_game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    let cfg = WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0),
+            airport(1, 1_800.0),
+            airport(2, 1_300.0),
+            airport(3, 9_000.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+#[test]
+fn rerouted_plane_lands_at_the_new_destination() {
+    let mut game = reroute_game();
+    let start_fuel = game.airplanes[0].current_fuel;
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    // a quarter of the way: 200km flown, 100km short of airport 2
+    assert!((game.airplanes[0].location.x - 1_200.0).abs() < 1e-2);
+
+    game.reroute_plane(0, 2).unwrap();
+    match game.airplanes[0].status {
+        AirplaneStatus::InTransit {
+            destination,
+            total_hours,
+            ..
+        } => {
+            assert_eq!(destination, 2);
+            assert_eq!(total_hours, 1);
+        }
+        ref other => panic!("expected transit, got {:?}", other),
+    }
+
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    let plane = &game.airplanes[0];
+    assert_eq!(plane.location, game.map.coord(2).unwrap());
+    let burned = 300.0 / plane.specs.cruise_speed * plane.specs.fuel_consumption;
+    assert!((start_fuel - plane.current_fuel - burned).abs() < 1e-2);
+}
+
+#[test]
+fn only_planes_in_flight_can_be_rerouted() {
+    let mut game = reroute_game();
+    assert!(matches!(
+        game.reroute_plane(0, 2),
+        Err(GameError::PlaneNotReady { .. })
+    ));
+
+    game.depart_plane(0, 1).unwrap();
+    assert!(matches!(
+        game.reroute_plane(0, 1),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.reroute_plane(0, 42),
+        Err(GameError::AirportIdInvalid { id: 42 })
+    ));
+    assert!(matches!(
+        game.reroute_plane(7, 2),
+        Err(GameError::PlaneIdInvalid { id: 7 })
+    ));
+}
+
+#[test]
+fn unreachable_reroutes_leave_the_flight_untouched() {
+    let mut game = reroute_game();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    let fuel = game.airplanes[0].current_fuel;
+    let status = game.airplanes[0].status.clone();
+
+    assert!(matches!(
+        game.reroute_plane(0, 3),
+        Err(GameError::OutOfRange { .. })
+    ));
+    assert_eq!(game.airplanes[0].current_fuel, fuel);
+    assert_eq!(game.airplanes[0].status, status);
+}
+
+#[test]
+fn reroute_command_changes_destination() {
+    let mut game = reroute_game();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(1);
+    game.execute_str("REROUTE PLANE 0 TO 0").unwrap();
+    while game.airplanes[0].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    assert_eq!(game.airplanes[0].location, game.map.coord(0).unwrap());
+}
@@ -0,0 +1,84 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{GameplayConfig, WorldConfig};
+use rusty_runways_core::statistics::fingerprint;
+
+fn generated(seed: u64) -> WorldConfig {
+    WorldConfig {
+        seed: Some(seed),
+        starting_cash: 650_000.0,
+        airports: vec![],
+        num_airports: Some(5),
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay: GameplayConfig::default(),
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn fingerprints_are_stable() {
+    assert_eq!(fingerprint(b""), "cbf29ce484222325");
+    assert_eq!(fingerprint(b"a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn identical_worlds_share_a_config_hash() {
+    let a = Game::from_config(generated(4)).unwrap();
+    let b = Game::from_config(generated(4)).unwrap();
+    let c = Game::from_config(generated(5)).unwrap();
+    assert_eq!(a.config_hash, b.config_hash);
+    assert_ne!(a.config_hash, c.config_hash);
+
+    assert_eq!(
+        Game::new(4, Some(5), 1.0).config_hash,
+        Game::new(4, Some(5), 1.0).config_hash
+    );
+    assert_ne!(
+        Game::new(4, Some(5), 1.0).config_hash,
+        Game::new(4, Some(5), 2.0).config_hash
+    );
+}
+
+#[test]
+fn summary_tracks_the_run() {
+    let mut game = Game::new(9, Some(5), 650_000.0);
+    game.execute_str("ADVANCE 30").unwrap();
+    game.execute_str("SHOW CASH").unwrap();
+    game.execute_str("ADVANCE 20").unwrap();
+
+    let summary = game.run_summary();
+    assert_eq!(summary.seed, 9);
+    assert_eq!(summary.config_hash, game.config_hash);
+    assert_eq!(summary.days_played, 2);
+    assert_eq!(summary.deliveries, 0);
+    assert_eq!(summary.commands, 3);
+    assert_eq!(summary.company_value, game.company_value());
+    assert!(summary.verify());
+}
+
+#[test]
+fn edited_summaries_fail_verification() {
+    let game = Game::new(9, Some(5), 650_000.0);
+    let mut summary = game.run_summary();
+    summary.company_value *= 2.0;
+    assert!(!summary.verify());
+
+    let mut summary = game.run_summary();
+    summary.seed = 10;
+    assert!(!summary.verify());
+}
+
+#[test]
+fn old_saves_start_counting_from_zero() {
+    let game = Game::new(9, Some(5), 650_000.0);
+    let mut json = serde_json::to_value(&game).unwrap();
+    let save = json.as_object_mut().unwrap();
+    save.remove("config_hash");
+    save.remove("commands_issued");
+    let game: Game = serde_json::from_value(json).unwrap();
+    assert_eq!(game.commands_issued, 0);
+    assert!(game.run_summary().verify());
+}
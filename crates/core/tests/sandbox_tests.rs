@@ -0,0 +1,136 @@
+#![cfg(feature = "sandbox")]
+
+use rusty_runways_core::Game;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+use rusty_runways_core::utils::orders::order::OrderPayload;
+
+fn game() -> Game {
+    Game::new(7, Some(5), 650_000.0)
+}
+
+/// An airport other than the one plane 0 is parked at.
+fn elsewhere(game: &Game) -> usize {
+    let here = game.airplanes[0].location;
+    game.map
+        .airports
+        .iter()
+        .find(|(_, c)| *c != here)
+        .map(|(a, _)| a.id)
+        .unwrap()
+}
+
+#[test]
+fn give_cash_adds_and_takes_money() {
+    let mut game = game();
+    game.execute_str("GIVE CASH 1e6").unwrap();
+    assert_eq!(game.player.cash, 1_650_000.0);
+
+    game.execute_str("GIVE CASH -50000").unwrap();
+    assert_eq!(game.player.cash, 1_600_000.0);
+
+    assert!(matches!(
+        game.execute_str("GIVE CASH lots"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn teleport_moves_a_parked_plane() {
+    let mut game = game();
+    let target = elsewhere(&game);
+    game.execute_str(&format!("TELEPORT PLANE 0 {}", target))
+        .unwrap();
+
+    let coord = game
+        .map
+        .airports
+        .iter()
+        .find(|(a, _)| a.id == target)
+        .unwrap()
+        .1;
+    assert_eq!(game.airplanes[0].location, coord);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!(matches!(
+        game.execute_str("TELEPORT PLANE 9 0"),
+        Err(GameError::PlaneIdInvalid { id: 9 })
+    ));
+    assert!(matches!(
+        game.execute_str("TELEPORT PLANE 0 99"),
+        Err(GameError::AirportIdInvalid { id: 99 })
+    ));
+}
+
+#[test]
+fn teleport_rejects_planes_that_are_busy() {
+    let mut game = game();
+    game.execute_str("REFUEL PLANE 0").unwrap();
+    let target = elsewhere(&game);
+    assert!(matches!(
+        game.execute_str(&format!("TELEPORT PLANE 0 {}", target)),
+        Err(GameError::PlaneNotReady { .. })
+    ));
+}
+
+#[test]
+fn spawn_order_adds_a_cargo_order() {
+    let mut game = game();
+    game.execute_str("SPAWN ORDER electronics 500 FROM 0 TO 2 VALUE 20000 DEADLINE 48")
+        .unwrap();
+
+    let order = game.map.airport(0).unwrap().orders.last().unwrap();
+    assert_eq!(
+        order.payload,
+        OrderPayload::Cargo {
+            cargo_type: CargoType::Electronics,
+            weight: 500.0
+        }
+    );
+    assert_eq!(order.value, 20_000.0);
+    assert_eq!(order.deadline, 48);
+    assert_eq!(order.destination_id, 2);
+
+    let ids: Vec<usize> = game
+        .map
+        .airports
+        .iter()
+        .flat_map(|(a, _)| a.orders.iter().map(|o| o.id))
+        .collect();
+    assert_eq!(ids.iter().filter(|&&id| id == order.id).count(), 1);
+}
+
+#[test]
+fn spawn_order_validates_its_input() {
+    let mut game = game();
+    assert!(matches!(
+        game.execute_str("SPAWN ORDER unobtainium 500 FROM 0 TO 2 VALUE 100"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.execute_str("SPAWN ORDER Food 500 FROM 1 TO 1 VALUE 100"),
+        Err(GameError::SameAirport)
+    ));
+    assert!(matches!(
+        game.execute_str("SPAWN ORDER Food 500 FROM 0 TO 42 VALUE 100"),
+        Err(GameError::AirportIdInvalid { id: 42 })
+    ));
+    assert!(matches!(
+        game.execute_str("SPAWN ORDER Food 0 FROM 0 TO 1 VALUE 100"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn set_time_runs_the_simulation_forward_only() {
+    let mut game = game();
+    game.execute_str("SET TIME 48").unwrap();
+    assert_eq!(game.time, 48);
+    assert_eq!(game.stats.len(), 2);
+
+    assert!(matches!(
+        game.execute_str("SET TIME 10"),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert_eq!(game.time, 48);
+}
@@ -0,0 +1,87 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::save::{SaveError, SaveOptions};
+use std::fs;
+use std::path::PathBuf;
+
+fn save_path(name: &str) -> PathBuf {
+    PathBuf::from("save_games").join(format!("{}.json", name))
+}
+
+#[test]
+fn sealed_save_round_trips() {
+    let mut game = Game::new(3, Some(4), 650_000.0);
+    game.advance(30);
+    game.save_game_with("sealed_round_trip", &SaveOptions::sealed())
+        .unwrap();
+
+    let text = fs::read_to_string(save_path("sealed_round_trip")).unwrap();
+    assert!(text.contains("\"checksum\""));
+    let loaded = Game::load_game("sealed_round_trip").unwrap();
+    assert_eq!(loaded.time, game.time);
+    assert_eq!(loaded.player.cash, game.player.cash);
+    fs::remove_file(save_path("sealed_round_trip")).unwrap();
+}
+
+#[test]
+fn edited_sealed_save_fails_integrity_check() {
+    let game = Game::new(3, Some(4), 650_000.0);
+    game.save_game_with("sealed_tampered", &SaveOptions::sealed())
+        .unwrap();
+
+    let path = save_path("sealed_tampered");
+    let text = fs::read_to_string(&path).unwrap();
+    fs::write(&path, text.replacen("650000", "950000", 1)).unwrap();
+
+    let err = Game::load_game_with("sealed_tampered", None).unwrap_err();
+    assert!(matches!(err, SaveError::ChecksumMismatch), "{}", err);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn truncated_save_is_reported_as_corrupt() {
+    let game = Game::new(3, Some(4), 650_000.0);
+    game.save_game("plain_truncated").unwrap();
+
+    let path = save_path("plain_truncated");
+    let text = fs::read_to_string(&path).unwrap();
+    fs::write(&path, &text[..text.len() / 2]).unwrap();
+
+    let err = Game::load_game_with("plain_truncated", None).unwrap_err();
+    assert!(matches!(err, SaveError::Corrupt { .. }));
+    let err = Game::load_game("plain_truncated").unwrap_err();
+    assert!(err.to_string().starts_with("Save file is corrupt"));
+    fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypted_save_needs_the_right_passphrase() {
+    let game = Game::new(3, Some(4), 650_000.0);
+    game.save_game_with("encrypted", &SaveOptions::encrypted("runway-27"))
+        .unwrap();
+
+    let text = fs::read_to_string(save_path("encrypted")).unwrap();
+    assert!(!text.contains("airports"));
+
+    assert!(matches!(
+        Game::load_game_with("encrypted", None).unwrap_err(),
+        SaveError::PassphraseRequired
+    ));
+    assert!(matches!(
+        Game::load_game_with("encrypted", Some("runway-09")).unwrap_err(),
+        SaveError::WrongPassphrase
+    ));
+    let loaded = Game::load_game_with("encrypted", Some("runway-27")).unwrap();
+    assert_eq!(loaded.seed(), game.seed());
+    fs::remove_file(save_path("encrypted")).unwrap();
+}
+
+#[cfg(not(feature = "encryption"))]
+#[test]
+fn encryption_needs_the_feature() {
+    let game = Game::new(3, Some(4), 650_000.0);
+    let err = game
+        .save_game_with("encrypted_unsupported", &SaveOptions::encrypted("x"))
+        .unwrap_err();
+    assert!(matches!(err, SaveError::EncryptionUnsupported));
+}
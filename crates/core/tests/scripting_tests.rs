@@ -0,0 +1,199 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig, ScriptConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Food,
+            weight: 200.0,
+            value: 2_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+fn config(scripts: Vec<ScriptConfig>) -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+
+    WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, 1), airport(1, 1_300.0, 0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts,
+    }
+}
+
+fn inline(name: &str, source: &str) -> ScriptConfig {
+    ScriptConfig {
+        name: Some(name.to_string()),
+        path: None,
+        source: Some(source.to_string()),
+    }
+}
+
+#[cfg(feature = "scripting")]
+const RICH: &str = r#"
+function on_day_end(state)
+    if state.cash > 500000 and game.once("rich") then
+        game.message("A rival airline has noticed you")
+        game.add_cash(1000)
+    end
+end
+"#;
+
+#[cfg(feature = "scripting")]
+fn count(game: &mut Game, text: &str) -> usize {
+    game.drain_log().iter().filter(|m| m.contains(text)).count()
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn cash_trigger_fires_once() {
+    let mut game = Game::from_config(config(vec![inline("rich", RICH)])).unwrap();
+    game.advance(72);
+
+    assert_eq!(count(&mut game, "A rival airline has noticed you"), 1);
+    assert_eq!(game.player.cash, 1_001_000.0);
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn arrival_and_delivery_hooks_see_the_flight() {
+    let script = r#"
+    function on_arrival(state, plane, airport)
+        game.message("plane " .. plane .. " landed at " .. airport)
+    end
+    function on_delivery(state, order, airport, payout)
+        if payout > 0 then
+            game.message("order " .. order .. " paid at " .. airport)
+        end
+    end
+    "#;
+    let mut game = Game::from_config(config(vec![inline("watch", script)])).unwrap();
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(10);
+    game.unload_all(0).unwrap();
+    game.advance(1);
+
+    let log = game.drain_log();
+    assert!(log.iter().any(|m| m == "plane 0 landed at 1"));
+    assert!(
+        log.iter()
+            .any(|m| *m == format!("order {} paid at 1", order))
+    );
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn fired_triggers_survive_save_and_load() {
+    let mut game = Game::from_config(config(vec![inline("rich", RICH)])).unwrap();
+    game.advance(24);
+    assert_eq!(count(&mut game, "A rival airline"), 1);
+    game.save_game("scripting_roundtrip").unwrap();
+
+    let mut loaded = Game::load_game("scripting_roundtrip").unwrap();
+    std::fs::remove_file("save_games/scripting_roundtrip.json").unwrap();
+    loaded.advance(48);
+
+    assert_eq!(count(&mut loaded, "A rival airline"), 0);
+    assert_eq!(loaded.player.cash, 1_001_000.0);
+    assert_eq!(loaded.export_config().scripts.len(), 1);
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn runaway_script_is_disabled() {
+    let spin = "function on_day_end() while true do end end";
+    let mut game = Game::from_config(config(vec![inline("spin", spin)])).unwrap();
+    game.advance(48);
+
+    assert_eq!(game.stats.len(), 2);
+    assert_eq!(count(&mut game, "Script 'spin' failed"), 1);
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn scripts_load_from_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bonus.lua");
+    std::fs::write(&path, "function on_day_end() game.add_cash(250) end").unwrap();
+    let script = ScriptConfig {
+        name: None,
+        path: Some(path.to_string_lossy().into_owned()),
+        source: None,
+    };
+    let mut game = Game::from_config(config(vec![script])).unwrap();
+    game.advance(24);
+
+    assert_eq!(game.player.cash, 1_000_250.0);
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn broken_script_is_rejected() {
+    let err = Game::from_config(config(vec![inline("bad", "function (")])).unwrap_err();
+    assert!(
+        matches!(&err, GameError::InvalidConfig { msg } if msg.starts_with("scripts[0] (bad)")),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn script_needs_exactly_one_source() {
+    let both = ScriptConfig {
+        name: None,
+        path: Some("a.lua".into()),
+        source: Some("".into()),
+    };
+    let issues = config(vec![both]).validate();
+    assert!(issues.iter().any(|i| i.path == "scripts[0]"));
+}
+
+#[cfg(not(feature = "scripting"))]
+#[test]
+fn scripts_need_the_feature() {
+    let err = Game::from_config(config(vec![inline("any", "")])).unwrap_err();
+    assert!(
+        matches!(&err, GameError::InvalidConfig { msg } if msg.contains("scripting")),
+        "{:?}",
+        err
+    );
+}
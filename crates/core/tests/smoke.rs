@@ -32,7 +32,7 @@ fn buy_new_plane() {
     assert_eq!(game.player.fleet_size, 2);
 
     let new_plane = game.airplanes.last().unwrap();
-    let target_airport = game.map.airports[0].1;
+    let target_airport = game.map.coord(0).unwrap();
     assert_eq!(new_plane.location, target_airport);
 }
 
@@ -42,17 +42,18 @@ fn load_check() {
 
     let plane_id = 0usize;
     let origin_loc = game.airplanes[plane_id].location;
-    let origin_idx = game
+    let origin_id = game
         .airports()
         .iter()
-        .position(|(_, c)| *c == origin_loc)
+        .find(|(_, c)| *c == origin_loc)
+        .map(|(a, _)| a.id)
         .expect("origin airport should exist");
     let payload_cap = game.airplanes[plane_id].specs.payload_capacity;
 
     let mut heavy_id: Option<usize> = None;
 
     for _ in 0..4 {
-        for order in &game.map.airports[origin_idx].0.orders {
+        for order in &game.map.airport(origin_id).unwrap().orders {
             let Some(weight) = order.cargo_weight() else {
                 continue;
             };
@@ -76,11 +77,12 @@ fn load_check() {
 
     let mut candidate: Option<(usize, usize)> = None;
     for _ in 0..5 {
-        candidate = game.map.airports.get(origin_idx).and_then(|(airport, _)| {
+        candidate = game.map.airports.get(origin_id).and_then(|(airport, _)| {
             airport.orders.iter().find_map(|order| {
                 let weight = order.cargo_weight()?;
                 let plane = &game.planes()[plane_id];
-                let (airport_dest, coord_dest) = &game.airports()[order.destination_id];
+                let (airport_dest, coord_dest) =
+                    game.map.airports.get(order.destination_id).unwrap();
                 if weight <= payload_cap && plane.can_fly_to(airport_dest, coord_dest).is_ok() {
                     Some((order.id, order.destination_id))
                 } else {
@@ -112,20 +114,22 @@ fn delivery_cycle() {
 
     let plane_id = 0usize;
     let origin_loc = game.airplanes[plane_id].location;
-    let origin_idx = game
+    let origin_id = game
         .airports()
         .iter()
-        .position(|(_, c)| *c == origin_loc)
+        .find(|(_, c)| *c == origin_loc)
+        .map(|(a, _)| a.id)
         .expect("origin airport should exist");
     let payload_cap = game.airplanes[plane_id].specs.payload_capacity;
 
     let mut candidate: Option<(usize, usize, f32)> = None;
     for _ in 0..5 {
-        candidate = game.map.airports.get(origin_idx).and_then(|(airport, _)| {
+        candidate = game.map.airports.get(origin_id).and_then(|(airport, _)| {
             airport.orders.iter().find_map(|order| {
                 let weight = order.cargo_weight()?;
                 let plane = &game.planes()[plane_id];
-                let (airport_dest, coord_dest) = &game.airports()[order.destination_id];
+                let (airport_dest, coord_dest) =
+                    game.map.airports.get(order.destination_id).unwrap();
                 if weight <= payload_cap && plane.can_fly_to(airport_dest, coord_dest).is_ok() {
                     Some((order.id, order.destination_id, order.value))
                 } else {
@@ -166,10 +170,10 @@ fn delivery_cycle() {
     assert_eq!(game.arrival_times.get(&plane_id), Some(&game.time));
     assert_eq!(
         game.airplanes[plane_id].location,
-        game.map.airports[dest_idx].1
+        game.map.coord(dest_idx).unwrap()
     );
-    let landing_fee =
-        (game.airplanes[plane_id].specs.mtow / 1000.0) * game.map.airports[dest_idx].0.landing_fee;
+    let landing_fee = (game.airplanes[plane_id].specs.mtow / 1000.0)
+        * game.map.airport(dest_idx).unwrap().landing_fee;
     assert!((before_landing - game.player.cash - landing_fee).abs() < 1.0);
 
     let before_unload = game.player.cash;
@@ -185,7 +189,7 @@ fn delivery_cycle() {
     let cash_before_refuel = game.player.cash;
     let fueling_fee = (game.airplanes[plane_id].specs.fuel_capacity
         - game.airplanes[plane_id].current_fuel)
-        * game.map.airports[dest_idx].0.fuel_price;
+        * game.map.airport(dest_idx).unwrap().fuel_price;
     game.refuel_plane(plane_id).unwrap();
     assert_eq!(game.airplanes[plane_id].status, AirplaneStatus::Refueling);
 
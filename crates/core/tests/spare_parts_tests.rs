@@ -0,0 +1,189 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::checks::CheckType;
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32, has_hangar: bool) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("P{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: Some(has_hangar),
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Hub with a hangar at airport 0 and an outstation without one 520km away.
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(4),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, true), airport(1, 1_520.0, false)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+fn gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay
+}
+
+fn kits(game: &Game, airport: usize) -> u32 {
+    game.map.airport(airport).unwrap().spare_parts
+}
+
+#[test]
+fn hangars_start_stocked_and_sell_kits() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let starting = game.maintenance_settings.starting_spare_kits;
+    assert_eq!(kits(&game, 0), starting);
+    assert_eq!(kits(&game, 1), 0);
+
+    let cash = game.player.cash;
+    game.buy_spare_parts(0, 2).unwrap();
+    assert_eq!(kits(&game, 0), starting + 2);
+    let price = game.maintenance_settings.spare_kit_price;
+    assert!((cash - game.player.cash - 2.0 * price).abs() < 1e-2);
+
+    assert!(matches!(
+        game.buy_spare_parts(1, 1),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    game.player.cash = -game.player.overdraft_limit;
+    assert!(matches!(
+        game.buy_spare_parts(0, 1),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+}
+
+#[test]
+fn heavy_checks_use_up_kits() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let c_kits = game.maintenance_settings.c_check.spare_kits;
+    game.map.airport_mut(0).unwrap().spare_parts = c_kits - 1;
+    assert!(matches!(
+        game.perform_check(0, CheckType::C),
+        Err(GameError::MissingSpareParts {
+            airport_id: 0,
+            have,
+            need,
+        }) if have == c_kits - 1 && need == c_kits
+    ));
+
+    game.map.airport_mut(0).unwrap().spare_parts = c_kits;
+    game.perform_check(0, CheckType::C).unwrap();
+    assert_eq!(kits(&game, 0), 0);
+}
+
+#[test]
+fn shipped_kits_restock_the_destination() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let starting = kits(&game, 0);
+    let order = game.ship_spare_parts(2, 1, 0).unwrap();
+    assert_eq!(kits(&game, 0), starting - 2);
+    let weight = game.airplanes[0].current_payload;
+    assert!((weight - 2.0 * game.maintenance_settings.spare_kit_weight).abs() < 1e-2);
+    assert!(game.airplanes[0].manifest.iter().any(|o| o.id == order));
+
+    game.advance(1);
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    let income = game.daily_income;
+    game.unload_all(0).unwrap();
+    game.advance(1);
+    assert_eq!(kits(&game, 1), 2);
+    assert_eq!(game.daily_income, income);
+}
+
+#[test]
+fn shipping_needs_kits_at_the_plane() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let starting = kits(&game, 0);
+    assert!(matches!(
+        game.ship_spare_parts(starting + 1, 1, 0),
+        Err(GameError::MissingSpareParts { .. })
+    ));
+    assert!(matches!(
+        game.ship_spare_parts(1, 0, 0),
+        Err(GameError::SameAirport)
+    ));
+    assert!(game.ship_spare_parts(0, 1, 0).is_err());
+    assert_eq!(kits(&game, 0), starting);
+    assert!(game.map.airport(0).unwrap().orders.is_empty());
+}
+
+#[test]
+fn repairs_wait_for_parts_to_arrive() {
+    let mut gameplay = gameplay();
+    gameplay.maintenance.landing_failure_chance = 1.0;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    assert!(game.airplanes[0].awaiting_repair);
+
+    assert!(matches!(
+        game.repair_plane(0),
+        Err(GameError::MissingSpareParts { airport_id: 1, .. })
+    ));
+    game.map.airport_mut(1).unwrap().spare_parts = 1;
+    game.repair_plane(0).unwrap();
+    assert_eq!(kits(&game, 1), 0);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Maintenance);
+}
+
+#[test]
+fn parts_shipments_cannot_be_split() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    let order = game.ship_spare_parts(2, 1, 0).unwrap();
+    game.advance(1);
+    game.unload_all(0).unwrap();
+    game.advance(1);
+    // stored back at the origin as an order
+    assert!(matches!(
+        game.split_order(order, 10.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
+
+#[test]
+fn invalid_spare_part_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.maintenance.spare_kit_price = -1.0;
+    gameplay.maintenance.spare_kit_weight = 0.0;
+    let paths: Vec<String> = world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.maintenance.spare_kit_price",
+            "gameplay.maintenance.spare_kit_weight"
+        ]
+    );
+}
@@ -0,0 +1,116 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::map::Map;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Food,
+            weight: 200.0,
+            value: 2_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+/// Airports 10, 20 and 30 with the plane based at 20, the second entry.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+
+    let config = WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(10, 1_000.0, 20),
+            airport(20, 1_300.0, 30),
+            airport(30, 1_600.0, 10),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 20,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(config).unwrap()
+}
+
+#[test]
+fn lookups_go_by_id_not_position() {
+    let game = game();
+    assert_eq!(game.map.airport(20).unwrap().name, "T20");
+    assert_eq!(game.map.coord(30).unwrap().x, 1_600.0);
+    assert!(game.map.airport(1).is_none());
+    assert!(game.map.coord(2).is_none());
+    assert_eq!(game.airplanes[0].location, game.map.coord(20).unwrap());
+}
+
+#[test]
+fn flights_and_deliveries_use_airport_ids() {
+    let mut game = game();
+    let order = game.map.airport(20).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(1);
+
+    assert!(matches!(
+        game.depart_plane(0, 2),
+        Err(GameError::AirportIdInvalid { id: 2 })
+    ));
+    game.depart_plane(0, 30).unwrap();
+    game.advance(10);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert_eq!(game.airplanes[0].location, game.map.coord(30).unwrap());
+
+    let cash = game.player.cash;
+    game.unload_all(0).unwrap();
+    game.advance(1);
+    assert!(game.player.cash > cash);
+    assert!(
+        game.map
+            .airport(30)
+            .unwrap()
+            .orders
+            .iter()
+            .all(|o| o.id != order)
+    );
+}
+
+#[test]
+fn saved_maps_keep_id_lookups() {
+    let game = game();
+    let json = serde_json::to_string(&game.map).unwrap();
+    let map: Map = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(map.airport(30).unwrap().name, "T30");
+    assert!(map.airport(0).is_none());
+}
@@ -0,0 +1,151 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::order::{OrderPayload, SPLIT_COMPLETION_SHARE};
+use rusty_runways_core::utils::orders::{CargoType, Order};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("S{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn plane() -> StartingPlaneConfig {
+    StartingPlaneConfig {
+        model: "SparrowLight".into(),
+        home_airport: 0,
+        fuel_level: 1.0,
+    }
+}
+
+fn split_game(deadline: u64) -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    let cfg = WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0), airport(1, 1_300.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![plane(), plane()],
+        scripts: Vec::new(),
+    };
+    let mut game = Game::from_config(cfg).unwrap();
+    game.map.airport_mut(0).unwrap().orders.push(Order {
+        id: 100,
+        payload: OrderPayload::Cargo {
+            cargo_type: CargoType::Food,
+            weight: 400.0,
+        },
+        value: 10_000.0,
+        deadline,
+        origin_id: 0,
+        destination_id: 1,
+        tier: Default::default(),
+        damage: 0.0,
+        shipment: None,
+        contract: None,
+        spare_parts: 0,
+        rare: None,
+        pallet: Vec::new(),
+    });
+    game
+}
+
+fn deliver(game: &mut Game, plane: usize, order: usize) -> f32 {
+    game.airplanes[plane].status = AirplaneStatus::Parked;
+    game.load_order(order, plane).unwrap();
+    game.advance(1);
+    game.depart_plane(plane, 1).unwrap();
+    while game.airplanes[plane].status != AirplaneStatus::Parked {
+        game.advance(1);
+    }
+    let cash = game.player.cash;
+    game.unload_all(plane).unwrap();
+    game.player.cash - cash
+}
+
+#[test]
+fn split_parts_share_weight_and_value() {
+    let mut game = split_game(200);
+    let part = game.split_order(100, 150.0).unwrap();
+
+    let orders = &game.map.airport(0).unwrap().orders;
+    let original = orders.iter().find(|o| o.id == 100).unwrap();
+    let new_part = orders.iter().find(|o| o.id == part).unwrap();
+    assert_eq!(original.cargo_weight(), Some(250.0));
+    assert_eq!(new_part.cargo_weight(), Some(150.0));
+    assert_eq!(new_part.shipment, Some(100));
+    let held = 10_000.0 * (1.0 - SPLIT_COMPLETION_SHARE);
+    assert!((original.value + new_part.value - held).abs() < 1e-2);
+    assert_eq!(game.split_shipments[&100].parts_remaining, 2);
+}
+
+#[test]
+fn delivering_every_part_pays_full_value() {
+    let mut game = split_game(200);
+    let part = game.split_order(100, 150.0).unwrap();
+
+    let first = deliver(&mut game, 0, 100);
+    let second = deliver(&mut game, 1, part);
+    assert!((first + second - 10_000.0).abs() < 1e-2);
+    assert!(game.split_shipments.is_empty());
+}
+
+#[test]
+fn late_part_forfeits_completion_bonus() {
+    let mut game = split_game(200);
+    let part = game.split_order(100, 150.0).unwrap();
+    for order in game.map.airport_mut(0).unwrap().orders.iter_mut() {
+        if order.id == part {
+            order.deadline = 0;
+        }
+    }
+
+    let first = deliver(&mut game, 0, 100);
+    let second = deliver(&mut game, 1, part);
+    assert_eq!(second, 0.0);
+    assert!((first - 10_000.0 * (1.0 - SPLIT_COMPLETION_SHARE) * 250.0 / 400.0).abs() < 1e-2);
+    assert!(game.split_shipments.is_empty());
+}
+
+#[test]
+fn split_rejects_bad_requests() {
+    let mut game = split_game(200);
+    assert!(matches!(
+        game.split_order(100, 400.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.split_order(100, 0.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.split_order(999, 10.0),
+        Err(GameError::OrderIdInvalid { id: 999 })
+    ));
+
+    game.map.airport_mut(0).unwrap().orders[0].payload = OrderPayload::Passengers { count: 10 };
+    assert!(matches!(
+        game.split_order(100, 1.0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+}
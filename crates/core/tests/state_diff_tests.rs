@@ -0,0 +1,71 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::diff::OrderPlace;
+use rusty_runways_core::events::Event;
+
+/// An independent copy of `game` to compare against later.
+fn snapshot(game: &Game) -> Game {
+    serde_json::from_value(serde_json::to_value(game).unwrap()).unwrap()
+}
+
+#[test]
+fn identical_runs_have_no_diff() {
+    let mut a = Game::new(9, Some(6), 650_000.0);
+    let mut b = Game::new(9, Some(6), 650_000.0);
+    a.advance(40);
+    b.advance(40);
+    let diff = a.diff(&b);
+    assert!(diff.is_empty(), "{}", diff);
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn advancing_reports_time_cash_and_fired_events() {
+    let mut game = Game::new(9, Some(6), 650_000.0);
+    let before = snapshot(&game);
+    game.advance(24);
+
+    let diff = before.diff(&game);
+    assert_eq!(diff.time, Some((0, 24)));
+    assert_eq!(diff.cash.map(|(_, after)| after), Some(game.player.cash));
+    assert!(
+        diff.fired_events
+            .iter()
+            .any(|e| e.time == 24 && matches!(e.event, Event::DailyStats))
+    );
+    // the next daily report was queued in its place
+    assert!(
+        diff.scheduled_events
+            .iter()
+            .any(|e| e.time == 48 && matches!(e.event, Event::DailyStats))
+    );
+    assert!(diff.to_string().contains("time: 0 -> 24\n"));
+}
+
+#[test]
+fn loading_and_buying_show_up_as_moves_and_planes() {
+    let mut game = Game::new(2, Some(8), 5_000_000.0);
+    let origin = game.planes()[0].location;
+    let airport = game
+        .airports()
+        .iter()
+        .find(|(_, c)| *c == origin)
+        .map(|(a, _)| a.id)
+        .unwrap();
+    let order = game.map.airport(airport).unwrap().orders[0].id;
+    let before = snapshot(&game);
+    game.load_order(order, 0).unwrap();
+
+    let diff = before.diff(&game);
+    let moved = diff.orders.iter().find(|m| m.order == order).unwrap();
+    assert_eq!(moved.from, OrderPlace::Airport(airport));
+    assert_eq!(moved.to, OrderPlace::Plane(0));
+    assert_eq!(diff.planes.len(), 1);
+    assert!(diff.planes[0].status.is_some());
+    assert!(diff.planes[0].location.is_none());
+
+    let model = format!("{:?}", game.planes()[0].model);
+    let before = snapshot(&game);
+    game.buy_plane(&model, airport).unwrap();
+    assert_eq!(before.diff(&game).added_planes, vec![1]);
+    assert_eq!(game.diff(&before).removed_planes, vec![1]);
+}
@@ -0,0 +1,38 @@
+use rusty_runways_core::Game;
+
+#[test]
+fn same_seed_and_commands_give_the_same_hash() {
+    let mut a = Game::new(11, Some(6), 650_000.0);
+    let mut b = Game::new(11, Some(6), 650_000.0);
+    for game in [&mut a, &mut b] {
+        game.execute_str("ADVANCE 30").unwrap();
+    }
+    assert_eq!(a.state_hash(), b.state_hash());
+    assert_eq!(a.state_hash_hex(), format!("{:016x}", a.state_hash()));
+
+    b.execute_str("ADVANCE 1").unwrap();
+    assert_ne!(a.state_hash(), b.state_hash());
+    assert_ne!(
+        Game::new(12, Some(6), 650_000.0).state_hash(),
+        Game::new(11, Some(6), 650_000.0).state_hash()
+    );
+}
+
+#[test]
+fn float_noise_below_the_precision_is_ignored() {
+    let mut a = Game::new(11, Some(6), 1.0);
+    let mut b = Game::new(11, Some(6), 1.0);
+    a.player.cash = 1.25;
+    b.player.cash = 1.2501;
+    assert_eq!(a.state_hash(), b.state_hash());
+    b.player.cash = 1.26;
+    assert_ne!(a.state_hash(), b.state_hash());
+}
+
+#[test]
+fn hash_survives_a_json_round_trip() {
+    let mut game = Game::new(5, Some(5), 650_000.0);
+    game.advance(50);
+    let restored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+    assert_eq!(restored.state_hash(), game.state_hash());
+}
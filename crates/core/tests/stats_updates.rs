@@ -16,7 +16,8 @@ fn find_reachable_order_mut(game: &mut Game, plane_id: usize) -> (usize, usize,
                 airport.orders.iter().find_map(|order| {
                     let weight = order.cargo_weight()?;
                     let plane = &game.planes()[plane_id];
-                    let (airport_dest, coord_dest) = &game.airports()[order.destination_id];
+                    let (airport_dest, coord_dest) =
+                        game.map.airports.get(order.destination_id).unwrap();
                     if weight <= payload_cap && plane.can_fly_to(airport_dest, coord_dest).is_ok() {
                         Some((order.id, order.destination_id, order.value))
                     } else {
@@ -0,0 +1,142 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+
+fn airport(id: usize, x: f32, country: &str) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(10.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: Some(country.into()),
+        orders: Vec::new(),
+    }
+}
+
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(5),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, "Avalon"), airport(1, 1_520.0, "Brava")],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+/// 25% profit tax over two-day periods, with no other running costs.
+fn gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.fuel.headwind_chance = 0.0;
+    gameplay.maintenance.landing_failure_chance = 0.0;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    gameplay.borders.overflight_fee_per_km = 0.0;
+    gameplay.taxes.profit_tax_rate = 0.25;
+    gameplay.taxes.period_days = 2;
+    gameplay
+}
+
+#[test]
+fn profit_tax_is_paid_at_the_end_of_the_period() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    game.daily_income = 1_000.0;
+    game.advance(24);
+    assert_eq!(game.stats.last().unwrap().taxes, 0.0);
+
+    let cash = game.player.cash;
+    game.daily_income = 500.0;
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    assert!((day.taxes - 375.0).abs() < 1e-2);
+    assert!((day.expenses - 375.0).abs() < 1e-2);
+    assert!((cash - game.player.cash - 375.0).abs() < 1e-2);
+    assert!(game.drain_log().iter().any(|m| m.contains("profit tax")));
+    assert_eq!(game.taxable_profit, 0.0);
+}
+
+#[test]
+fn losses_carry_over_to_the_next_period() {
+    let mut game = Game::from_config(world(gameplay())).unwrap();
+    game.daily_expenses = 1_000.0;
+    game.advance(24);
+    game.daily_income = 600.0;
+    game.advance(24);
+    assert_eq!(game.stats.last().unwrap().taxes, 0.0);
+    assert!((game.taxable_profit + 400.0).abs() < 1e-2);
+
+    game.daily_income = 1_400.0;
+    game.advance(48);
+    let taxes: f32 = game.stats.iter().map(|s| s.taxes).sum();
+    assert!((taxes - 250.0).abs() < 1e-2);
+}
+
+#[test]
+fn no_tax_by_default() {
+    let mut gameplay = gameplay();
+    gameplay.taxes = Default::default();
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    game.daily_income = 10_000.0;
+    game.advance(24 * 60);
+    assert!(game.stats.iter().all(|s| s.taxes == 0.0));
+}
+
+/// Cash spent flying into Brava with its fees scaled by `multiplier`.
+fn cost_of_flight(multiplier: f32) -> f32 {
+    let mut gameplay = gameplay();
+    gameplay
+        .taxes
+        .fee_multipliers
+        .insert("Brava".into(), multiplier);
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    let cash = game.player.cash;
+    game.depart_plane(0, 1).unwrap();
+    game.advance(2);
+    cash - game.player.cash
+}
+
+#[test]
+fn regional_multipliers_scale_airport_fees() {
+    let free = cost_of_flight(0.0);
+    let base = cost_of_flight(1.0) - free;
+    assert!(base > 0.0);
+    assert!((cost_of_flight(3.0) - free - 3.0 * base).abs() < 1e-1);
+}
+
+#[test]
+fn invalid_tax_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.taxes.profit_tax_rate = 1.5;
+    gameplay.taxes.period_days = 0;
+    gameplay.taxes.fee_multipliers.insert("Brava".into(), -1.0);
+    let paths: Vec<String> = world(gameplay)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.taxes.profit_tax_rate",
+            "gameplay.taxes.period_days",
+            "gameplay.taxes.fee_multipliers"
+        ]
+    );
+}
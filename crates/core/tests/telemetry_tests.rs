@@ -0,0 +1,42 @@
+#![cfg(feature = "telemetry")]
+
+use rusty_runways_core::Game;
+use std::sync::{Arc, Mutex};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Subscriber that remembers the names of the spans it sees.
+#[derive(Clone, Default)]
+struct SpanNames(Arc<Mutex<Vec<String>>>);
+
+impl Subscriber for SpanNames {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut names = self.0.lock().unwrap();
+        names.push(span.metadata().name().to_string());
+        Id::from_u64(names.len() as u64)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, _: &Event<'_>) {}
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn hot_paths_emit_spans() {
+    let names = SpanNames::default();
+    tracing::subscriber::with_default(names.clone(), || {
+        let mut game = Game::new(1, Some(5), 650_000.0);
+        game.advance(24);
+        game.map.restock_airports();
+        game.observe();
+    });
+
+    let names = names.0.lock().unwrap();
+    for expected in ["advance", "tick_event", "restock_airports", "observe"] {
+        assert!(names.iter().any(|n| n == expected), "no {} span", expected);
+    }
+}
@@ -0,0 +1,112 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{AirportConfig, GameplayConfig, Location, WorldConfig};
+use rusty_runways_core::utils::airport::{LocalTime, default_utc_offset};
+use rusty_runways_core::utils::coordinate::Coordinate;
+
+fn airport(id: usize, x: f32, utc_offset: Option<i32>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("Z{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn world(airports: Vec<AirportConfig>) -> WorldConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.charters.count = 0;
+    WorldConfig {
+        seed: Some(2),
+        starting_cash: 1_000_000.0,
+        airports,
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: Vec::new(),
+        scripts: Vec::new(),
+    }
+}
+
+#[test]
+fn offsets_follow_the_map_from_west_to_east() {
+    let game = Game::from_config(world(vec![
+        airport(0, 0.0, None),
+        airport(1, 5_000.0, None),
+        airport(2, 10_000.0, None),
+    ]))
+    .unwrap();
+    assert_eq!(game.utc_offset(0).unwrap(), -12);
+    assert_eq!(game.utc_offset(1).unwrap(), 0);
+    assert_eq!(game.utc_offset(2).unwrap(), 12);
+
+    assert_eq!(default_utc_offset(&Coordinate::geographic(48.0, 2.3)), 0);
+    assert_eq!(default_utc_offset(&Coordinate::geographic(35.7, 139.7)), 9);
+    assert_eq!(default_utc_offset(&Coordinate::geographic(40.7, -74.0)), -5);
+}
+
+#[test]
+fn local_time_moves_with_the_clock() {
+    let mut game = Game::from_config(world(vec![
+        airport(0, 0.0, None),
+        airport(1, 5_000.0, Some(3)),
+    ]))
+    .unwrap();
+
+    let west = game.local_time(0).unwrap();
+    assert_eq!((west.day, west.hour), (-1, 12));
+    let east = game.local_time(1).unwrap();
+    assert_eq!((east.day, east.hour), (0, 3));
+    assert_eq!(east.to_string(), "day 0 03:00 (UTC+3)");
+
+    game.advance(22);
+    let east = game.local_time(1).unwrap();
+    assert_eq!((east.day, east.hour), (1, 1));
+    assert!(game.local_time(7).is_err());
+
+    let obs = game.observe();
+    let obs = obs.airports.iter().find(|a| a.id == 1).unwrap();
+    assert_eq!((obs.utc_offset, obs.local_hour), (3, 1));
+}
+
+#[test]
+fn configured_offsets_are_exported() {
+    let game = Game::from_config(world(vec![
+        airport(0, 0.0, None),
+        airport(1, 5_000.0, Some(-4)),
+    ]))
+    .unwrap();
+    let exported = game.export_config();
+    assert_eq!(exported.airports[0].utc_offset, None);
+    assert_eq!(exported.airports[1].utc_offset, Some(-4));
+}
+
+#[test]
+fn local_time_wraps_across_midnight() {
+    assert_eq!(
+        LocalTime::at(0, -1),
+        LocalTime {
+            day: -1,
+            hour: 23,
+            utc_offset: -1
+        }
+    );
+    assert_eq!(LocalTime::at(47, 2).day, 2);
+}
+
+#[test]
+fn offsets_outside_the_world_clock_are_rejected() {
+    let issues = world(vec![airport(0, 0.0, Some(15)), airport(1, 100.0, None)]).validate();
+    assert!(issues.iter().any(|i| i.path == "airports[0].utc_offset"));
+}
@@ -0,0 +1,119 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::AirplaneStatus;
+use rusty_runways_core::utils::errors::GameError;
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: Some(50_000),
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Electronics,
+            weight: 15_000.0,
+            value: 50_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+fn plane(model: &str, home_airport: usize) -> StartingPlaneConfig {
+    StartingPlaneConfig {
+        model: model.into(),
+        home_airport,
+        fuel_level: 0.5,
+    }
+}
+
+/// Two freighters and a light plane at a small airport (5 t an hour), and a freighter
+/// at the next one.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    Game::from_config(WorldConfig {
+        seed: Some(9),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 1_000.0, 1), airport(1, 1_500.0, 0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![
+            plane("BisonFreighter", 0),
+            plane("BisonFreighter", 0),
+            plane("SparrowLight", 0),
+            plane("BisonFreighter", 1),
+        ],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+/// Load the airport's 15 t order onto plane 0 and wait until it is aboard.
+fn loaded() -> (Game, usize) {
+    let mut game = game();
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.advance(3);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    (game, order)
+}
+
+#[test]
+fn transfer_moves_the_order_in_half_the_handling_time() {
+    let (mut game, order) = loaded();
+    game.transfer_order(order, 0, 1).unwrap();
+
+    assert!(game.airplanes[0].manifest.is_empty());
+    assert_eq!(game.airplanes[0].current_payload, 0.0);
+    assert_eq!(game.airplanes[1].manifest[0].id, order);
+    assert_eq!(game.airplanes[1].current_payload, 15_000.0);
+    assert!(game.map.airport(0).unwrap().orders.is_empty());
+    assert!(game.staged_orders.is_empty());
+
+    // 7.5 t of work at 5 t an hour instead of 3 hours off and 3 hours on
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Unloading);
+    assert_eq!(game.airplanes[1].status, AirplaneStatus::Loading);
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert_eq!(game.airplanes[1].status, AirplaneStatus::Parked);
+}
+
+#[test]
+fn transfer_checks_the_receiving_plane() {
+    let (mut game, order) = loaded();
+    assert!(game.transfer_order(order, 0, 2).is_err());
+    assert!(matches!(
+        game.transfer_order(order, 0, 3),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.transfer_order(order, 0, 0),
+        Err(GameError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        game.transfer_order(order + 1, 0, 1),
+        Err(GameError::OrderIdInvalid { .. })
+    ));
+    assert_eq!(game.airplanes[0].manifest[0].id, order);
+    assert!(game.airplanes[1].manifest.is_empty());
+}
@@ -0,0 +1,115 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, FixedCostsGameplay, GameplayConfig, Location, ManualOrderConfig,
+    StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::airplanes::models::{AirplaneStatus, GroundTask};
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, destination_id: usize, weight: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("T{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: Some(50_000),
+        utc_offset: None,
+        country: None,
+        orders: vec![ManualOrderConfig::Cargo {
+            cargo: CargoType::Electronics,
+            weight,
+            value: 50_000.0,
+            deadline_hours: 200,
+            destination_id,
+            tier: Default::default(),
+        }],
+    }
+}
+
+/// A freighter at a small airport next to a 15 t order, with half a tank.
+fn game() -> Game {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.pilots.required = false;
+    gameplay.fixed_costs = FixedCostsGameplay::none();
+    let cfg = WorldConfig {
+        seed: Some(6),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0, 1, 15_000.0),
+            airport(1, 1_500.0, 0, 100.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "BisonFreighter".into(),
+            home_airport: 0,
+            fuel_level: 0.5,
+        }],
+        scripts: Vec::new(),
+    };
+    Game::from_config(cfg).unwrap()
+}
+
+fn load_heavy_order(game: &mut Game) {
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+}
+
+#[test]
+fn refueling_overlaps_loading() {
+    let mut game = game();
+    load_heavy_order(&mut game);
+    game.refuel_plane(0).unwrap();
+    let tasks: Vec<_> = game.airplanes[0]
+        .turnaround
+        .iter()
+        .map(|t| t.task)
+        .collect();
+    assert_eq!(tasks, vec![GroundTask::Loading, GroundTask::Refueling]);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Refueling);
+
+    // refueling is done after an hour, the loading carries on
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Loading);
+    assert_eq!(game.airplanes[0].turnaround.len(), 1);
+
+    // the whole turnaround takes as long as the loading alone
+    game.advance(2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    assert!(game.airplanes[0].turnaround.is_empty());
+}
+
+#[test]
+fn refueling_first_does_not_cut_loading_short() {
+    let mut game = game();
+    game.refuel_plane(0).unwrap();
+    load_heavy_order(&mut game);
+
+    game.advance(1);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Loading);
+    assert!(game.depart_plane(0, 1).is_err());
+
+    game.advance(2);
+    assert_eq!(game.airplanes[0].status, AirplaneStatus::Parked);
+    game.depart_plane(0, 1).unwrap();
+}
+
+#[test]
+fn observation_lists_the_turnaround() {
+    let mut game = game();
+    load_heavy_order(&mut game);
+    let obs = game.observe();
+    let tasks = &obs.planes[0].turnaround;
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].task, GroundTask::Loading);
+    assert_eq!(tasks[0].until, game.time + 3);
+}
@@ -10,7 +10,7 @@ fn reachable_order(game: &Game, plane_id: usize) -> Option<(usize, usize, f32)>
         airport.orders.iter().find_map(|order| {
             let weight = order.cargo_weight()?;
             let plane = &game.planes()[plane_id];
-            let (a, c) = &game.airports()[order.destination_id];
+            let (a, c) = game.map.airports.get(order.destination_id).unwrap();
             if weight <= payload_cap && plane.can_fly_to(a, c).is_ok() {
                 Some((order.id, order.destination_id, order.value))
             } else {
@@ -0,0 +1,160 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::errors::GameError;
+
+fn airport(id: usize, x: f32, runway: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("M{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(runway),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+/// Airport 1 is the only one long enough to host listings.
+fn market_world(gameplay: GameplayConfig, cash: f32) -> WorldConfig {
+    WorldConfig {
+        seed: Some(5),
+        starting_cash: cash,
+        airports: vec![
+            airport(0, 1_000.0, 1_500.0),
+            airport(1, 1_500.0, 4_000.0),
+            airport(2, 2_000.0, 2_000.0),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+fn quiet_gameplay() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay
+}
+
+fn market_game(cash: f32) -> Game {
+    Game::from_config(market_world(quiet_gameplay(), cash)).unwrap()
+}
+
+#[test]
+fn listings_only_appear_at_large_airports() {
+    let game = market_game(1_000_000.0);
+    assert_eq!(game.used_market.len(), 3);
+    for listing in &game.used_market {
+        assert_eq!(listing.airport_id, 1);
+        assert!(listing.specs.min_runway_length <= 4_000.0);
+        assert!(listing.price > 0.0);
+        assert!(listing.price <= listing.specs.purchase_price);
+        assert!((0.0..=0.25).contains(&listing.discount));
+    }
+
+    let observed = game.observe();
+    assert_eq!(observed.used_market, game.used_market);
+}
+
+#[test]
+fn no_large_airport_means_no_market() {
+    let mut gameplay = quiet_gameplay();
+    gameplay.used_market.min_runway_m = 5_000.0;
+    let game = Game::from_config(market_world(gameplay, 1_000_000.0)).unwrap();
+    assert!(game.used_market.is_empty());
+}
+
+#[test]
+fn buying_a_listing_adds_the_worn_plane() {
+    let mut game = market_game(100_000_000.0);
+    let listing = game.used_market[0].clone();
+    let cash = game.player.cash;
+
+    let plane_id = game.buy_used_plane(listing.id).unwrap();
+    assert_eq!(plane_id, 1);
+    assert_eq!(game.player.cash, cash - listing.price);
+    assert_eq!(game.used_market.len(), 2);
+    assert!(game.used_market.iter().all(|l| l.id != listing.id));
+
+    let plane = &game.airplanes[1];
+    assert_eq!(plane.location, game.map.coord(1).unwrap());
+    assert_eq!(plane.airframe_hours, listing.airframe_hours);
+    assert_eq!(plane.needs_maintenance, listing.needs_maintenance);
+    assert_eq!(game.player.fleet.len(), 2);
+
+    // Flipping the plane right away never pays more than it cost
+    assert!(game.appraise_plane(plane_id).unwrap() <= listing.price);
+}
+
+#[test]
+fn invalid_and_unaffordable_listings_are_rejected() {
+    let mut game = market_game(0.0);
+    assert!(matches!(
+        game.buy_used_plane(999),
+        Err(GameError::ListingIdInvalid { id: 999 })
+    ));
+    let id = game.used_market[0].id;
+    assert!(matches!(
+        game.buy_used_plane(id),
+        Err(GameError::InsufficientFunds { .. })
+    ));
+    assert_eq!(game.used_market.len(), 3);
+}
+
+#[test]
+fn restocks_replace_withdrawn_listings() {
+    let mut gameplay = quiet_gameplay();
+    gameplay.restock_cycle_hours = 24;
+    gameplay.used_market.listing_hours = 12;
+    let mut game = Game::from_config(market_world(gameplay, 1_000_000.0)).unwrap();
+    let first: Vec<usize> = game.used_market.iter().map(|l| l.id).collect();
+    assert_eq!(first, vec![0, 1, 2]);
+
+    game.advance(24);
+    let second: Vec<usize> = game.used_market.iter().map(|l| l.id).collect();
+    assert_eq!(second, vec![3, 4, 5]);
+}
+
+#[test]
+fn buy_used_command_purchases_the_listing() {
+    let mut game = market_game(100_000_000.0);
+    let id = game.used_market[1].id;
+    game.execute_str(&format!("BUY USED {}", id)).unwrap();
+    assert_eq!(game.airplanes.len(), 2);
+    assert!(game.used_market.iter().all(|l| l.id != id));
+}
+
+#[test]
+fn invalid_market_settings_are_reported() {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.used_market.max_discount = 1.0;
+    gameplay.used_market.listing_hours = 0;
+    let paths: Vec<String> = market_world(gameplay, 0.0)
+        .validate()
+        .into_iter()
+        .map(|i| i.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "gameplay.used_market.max_discount",
+            "gameplay.used_market.listing_hours"
+        ]
+    );
+}
@@ -0,0 +1,47 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::statistics::{CompanyValue, REPUTATION_PER_DELIVERY};
+
+#[test]
+fn new_company_is_worth_its_cash_and_fleet() {
+    let game = Game::new(3, Some(6), 500_000.0);
+    let value = game.valuation();
+    let fleet: f32 = game
+        .airplanes
+        .iter()
+        .map(|p| p.resale_value(game.time))
+        .sum();
+    assert!(fleet > 0.0);
+    assert_eq!(value.cash, game.player.cash);
+    assert!((value.fleet_value - fleet).abs() < 1e-2);
+    assert_eq!(value.debt, 0.0);
+    assert_eq!(value.reputation, 0.0);
+    assert!((game.company_value() - (game.player.cash + fleet)).abs() < 1e-2);
+}
+
+#[test]
+fn overdrawn_cash_counts_as_debt() {
+    let value = CompanyValue::new(-1_000.0, 50_000.0, 4);
+    assert_eq!(value.cash, 0.0);
+    assert_eq!(value.debt, 1_000.0);
+    assert_eq!(value.reputation, 4.0 * REPUTATION_PER_DELIVERY);
+    assert_eq!(value.total, 49_000.0 + 4.0 * REPUTATION_PER_DELIVERY);
+}
+
+#[test]
+fn deliveries_add_reputation() {
+    let mut game = Game::new(3, Some(6), 500_000.0);
+    let before = game.company_value();
+    game.player.orders_delivered = 10;
+    assert!((game.company_value() - before - 10.0 * REPUTATION_PER_DELIVERY).abs() < 1e-2);
+}
+
+#[test]
+fn daily_stats_record_the_company_value() {
+    let mut game = Game::new(3, Some(6), 500_000.0);
+    game.advance(24);
+    let day = game.stats.last().unwrap();
+    // the planes keep wearing after the report was written
+    let now = game.company_value();
+    assert!(day.company_value >= now && day.company_value - now < 0.01 * now);
+    assert_eq!(game.observe().company_value, game.company_value());
+}
@@ -0,0 +1,109 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, ManualOrderConfig, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::utils::orders::cargo::CargoType;
+
+fn airport(id: usize, x: f32, orders: Vec<ManualOrderConfig>) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("W{}", id),
+        location: Some(Location { x, y: 1_000.0 }),
+        runway_length_m: Some(3_500.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders,
+    }
+}
+
+/// A freighter next to a 2 t order bound for the other airport.
+fn game(gameplay: GameplayConfig) -> Game {
+    let order = |destination_id| ManualOrderConfig::Cargo {
+        cargo: CargoType::Electronics,
+        weight: 2_000.0,
+        value: 40_000.0,
+        deadline_hours: 200,
+        destination_id,
+        tier: Default::default(),
+    };
+    Game::from_config(WorldConfig {
+        seed: Some(3),
+        starting_cash: 1_000_000.0,
+        airports: vec![
+            airport(0, 1_000.0, vec![order(1)]),
+            airport(1, 1_600.0, vec![order(0)]),
+        ],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "BisonFreighter".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    })
+    .unwrap()
+}
+
+fn quiet() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.regenerate = false;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.rare_cargo.chance_per_day = 0.0;
+    gameplay
+}
+
+/// Load the order and put it straight back down short of its destination.
+fn stage(game: &mut Game) -> usize {
+    let order = game.map.airport(0).unwrap().orders[0].id;
+    game.load_order(order, 0).unwrap();
+    game.unload_order(order, 0).unwrap();
+    order
+}
+
+#[test]
+fn staged_cargo_pays_by_weight_after_the_free_day() {
+    let mut game = game(quiet());
+    let order = stage(&mut game);
+    assert_eq!(game.staged_orders_at(0)[0].id, order);
+    assert_eq!(game.observe().airports[0].staged_weight, 2_000.0);
+
+    game.advance(48);
+    assert_eq!(game.stats[0].fees, 0.0);
+    assert!((game.stats[1].fees - 80.0).abs() < 1e-3);
+    assert!((game.warehouse_fee(0) - 80.0).abs() < 1e-3);
+}
+
+#[test]
+fn staged_cargo_survives_restocks_until_picked_up() {
+    let mut gameplay = quiet();
+    gameplay.orders.regenerate = true;
+    gameplay.orders.generate_initial = false;
+    gameplay.restock_cycle_hours = 24;
+    let mut game = game(gameplay);
+    let order = stage(&mut game);
+
+    game.advance(50);
+    assert!(
+        game.map
+            .airport(0)
+            .unwrap()
+            .orders
+            .iter()
+            .any(|o| o.id == order)
+    );
+
+    game.load_order(order, 0).unwrap();
+    game.advance(24);
+    assert!(game.staged_orders.is_empty());
+    assert_eq!(game.warehouse_fee(0), 0.0);
+}
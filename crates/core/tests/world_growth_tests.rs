@@ -0,0 +1,125 @@
+use rusty_runways_core::Game;
+use rusty_runways_core::config::{
+    AirportConfig, GameplayConfig, Location, StartingPlaneConfig, WorldConfig,
+};
+use rusty_runways_core::events::{EventFilter, GameEvent, GameEventKind};
+
+fn airport(id: usize, x: f32) -> AirportConfig {
+    AirportConfig {
+        id,
+        name: format!("G{}", id),
+        location: Some(Location { x, y: 5_000.0 }),
+        runway_length_m: Some(3_000.0),
+        fuel_price_per_l: Some(1.0),
+        landing_fee_per_ton: Some(0.0),
+        parking_fee_per_hour: Some(0.0),
+        has_hangar: None,
+        population: None,
+        utc_offset: None,
+        country: None,
+        orders: Vec::new(),
+    }
+}
+
+fn world(gameplay: GameplayConfig) -> WorldConfig {
+    WorldConfig {
+        seed: Some(8),
+        starting_cash: 1_000_000.0,
+        airports: vec![airport(0, 4_000.0), airport(5, 6_000.0)],
+        num_airports: None,
+        airport_names: Vec::new(),
+        world_style: Default::default(),
+        airport_dataset: None,
+        gameplay,
+        airplanes: None,
+        starting_fleet: vec![StartingPlaneConfig {
+            model: "SparrowLight".into(),
+            home_airport: 0,
+            fuel_level: 1.0,
+        }],
+        scripts: Vec::new(),
+    }
+}
+
+fn quiet() -> GameplayConfig {
+    let mut gameplay = GameplayConfig::default();
+    gameplay.orders.generate_initial = false;
+    gameplay.auctions.chance_per_day = 0.0;
+    gameplay.rare_cargo.chance_per_day = 0.0;
+    gameplay
+}
+
+#[test]
+fn worlds_stay_static_by_default() {
+    let mut game = Game::from_config(world(quiet())).unwrap();
+    game.advance(24 * 10);
+    assert_eq!(game.map.airports.len(), 2);
+    assert!(game.opened_airports.is_empty());
+}
+
+#[test]
+fn scheduled_airports_open_nearby_up_to_the_cap() {
+    let mut gameplay = quiet();
+    gameplay.world_growth.chance_per_day = 1.0;
+    gameplay.world_growth.max_new_airports = 3;
+    gameplay.world_growth.radius_km = 400.0;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::AirportOpened]));
+    game.advance(24 * 5);
+
+    let events: Vec<GameEvent> = feed.try_iter().collect();
+    assert_eq!(events.len(), 3);
+    assert_eq!(game.opened_airports, vec![6, 7, 8]);
+    assert_eq!(game.map.num_airports, 5);
+    for event in events {
+        let GameEvent::AirportOpened { airport, near, .. } = event else {
+            panic!("expected an airport opening");
+        };
+        let distance = game
+            .map
+            .coord(airport)
+            .unwrap()
+            .distance_to(&game.map.coord(near).unwrap());
+        assert!((100.0..=400.5).contains(&distance), "{}km", distance);
+        let opened = game.map.airport(airport).unwrap();
+        assert!(opened.code.is_some());
+    }
+    assert!(game.observe().airports.iter().any(|a| a.id == 8));
+}
+
+#[test]
+fn busy_airports_grow_a_neighbour_once() {
+    let mut gameplay = quiet();
+    gameplay.world_growth.activity_threshold = 3;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    for hour in 0..3 {
+        game.loyalty
+            .record(5, hour, game.loyalty_settings.window_hours);
+    }
+    let feed = game.subscribe(EventFilter::only(&[GameEventKind::AirportOpened]));
+    game.advance(24 * 3);
+
+    let events: Vec<GameEvent> = feed.try_iter().collect();
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        events[0],
+        GameEvent::AirportOpened {
+            time: 24,
+            airport: 6,
+            near: 5
+        }
+    ));
+}
+
+#[test]
+fn exported_config_counts_down_the_cap() {
+    let mut gameplay = quiet();
+    gameplay.world_growth.chance_per_day = 1.0;
+    gameplay.world_growth.max_new_airports = 3;
+    let mut game = Game::from_config(world(gameplay)).unwrap();
+    game.advance(24 * 2);
+
+    let config = game.export_config();
+    assert_eq!(config.airports.len(), 4);
+    assert_eq!(config.gameplay.world_growth.max_new_airports, 1);
+}
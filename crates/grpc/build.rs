@@ -0,0 +1,7 @@
+// protox compiles the schema in Rust, so building does not need `protoc` installed
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let descriptors = protox::compile(["proto/rusty_runways.proto"], ["proto"])?;
+    tonic_build::configure().compile_fds(descriptors)?;
+    println!("cargo:rerun-if-changed=proto/rusty_runways.proto");
+    Ok(())
+}
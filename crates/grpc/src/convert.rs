@@ -0,0 +1,211 @@
+//! Conversions from core types to their protobuf messages.
+
+use rusty_runways_core::events::GameEvent;
+use rusty_runways_core::game::{AirportObs, Observation, PlaneObs};
+
+use crate::pb;
+use crate::pb::game_event::Kind;
+
+impl From<Observation> for pb::Observation {
+    fn from(obs: Observation) -> Self {
+        let json = serde_json::to_string(&obs).expect("observation serializes");
+        pb::Observation {
+            time: obs.time,
+            cash: obs.cash,
+            company_value: obs.company_value,
+            airports: obs.airports.into_iter().map(Into::into).collect(),
+            planes: obs.planes.into_iter().map(Into::into).collect(),
+            json,
+        }
+    }
+}
+
+impl From<AirportObs> for pb::Airport {
+    fn from(a: AirportObs) -> Self {
+        pb::Airport {
+            id: a.id as u64,
+            name: a.name,
+            x: a.x,
+            y: a.y,
+            fuel_price: a.fuel_price,
+            fuel_stock: a.fuel_stock,
+            has_hangar: a.has_hangar,
+            spare_parts: a.spare_parts,
+            runway_length: a.runway_length,
+            population: a.population,
+            parking_stands: a.parking_stands as u64,
+            ground_crew: a.ground_crew,
+            has_charger: a.has_charger,
+            utc_offset: a.utc_offset,
+            local_hour: a.local_hour.into(),
+            num_orders: a.num_orders as u64,
+            country: a.country,
+            code: a.code,
+            class: a.class.to_string(),
+            player_built: a.player_built,
+            has_concession: a.has_concession,
+            staged_weight: a.staged_weight,
+        }
+    }
+}
+
+impl From<PlaneObs> for pb::Plane {
+    fn from(p: PlaneObs) -> Self {
+        pb::Plane {
+            id: p.id as u64,
+            name: p.name,
+            on_hold: p.on_hold,
+            awaiting_repair: p.awaiting_repair,
+            model: p.model,
+            x: p.x,
+            y: p.y,
+            status: p.status,
+            fuel_current: p.fuel.current,
+            fuel_capacity: p.fuel.capacity,
+            electric: p.fuel.electric,
+            cargo_current: p.payload.cargo_current,
+            cargo_capacity: p.payload.cargo_capacity,
+            cargo_orders: p.payload.cargo_orders,
+            cargo_slots: p.payload.cargo_slots,
+            passenger_current: p.payload.passenger_current,
+            passenger_capacity: p.payload.passenger_capacity,
+            takeoff_weight: p.payload.takeoff_weight,
+            mtow: p.payload.mtow,
+            destination: p.destination.map(|d| d as u64),
+            hours_remaining: p.hours_remaining,
+        }
+    }
+}
+
+impl From<&GameEvent> for pb::GameEvent {
+    fn from(event: &GameEvent) -> Self {
+        let kind = match event {
+            GameEvent::Departed {
+                time,
+                plane,
+                origin,
+                destination,
+            } => Kind::Departed(pb::Departed {
+                time: *time,
+                plane: *plane as u64,
+                origin: *origin as u64,
+                destination: *destination as u64,
+            }),
+            GameEvent::Arrived {
+                time,
+                plane,
+                airport,
+            } => Kind::Arrived(pb::Arrived {
+                time: *time,
+                plane: *plane as u64,
+                airport: *airport as u64,
+            }),
+            GameEvent::Delivered {
+                time,
+                order,
+                airport,
+                payout,
+            } => Kind::Delivered(pb::Delivered {
+                time: *time,
+                order: *order as u64,
+                airport: *airport as u64,
+                payout: *payout,
+            }),
+            GameEvent::CashChanged {
+                time,
+                delta,
+                balance,
+            } => Kind::CashChanged(pb::CashChanged {
+                time: *time,
+                delta: *delta,
+                balance: *balance,
+            }),
+            GameEvent::OverdraftWarning {
+                time,
+                balance,
+                limit,
+            } => Kind::OverdraftWarning(pb::OverdraftWarning {
+                time: *time,
+                balance: *balance,
+                limit: *limit,
+            }),
+            GameEvent::LaneOffered {
+                time,
+                deal,
+                forwarder,
+            } => Kind::LaneOffered(pb::LaneOffered {
+                time: *time,
+                deal: *deal as u64,
+                forwarder: *forwarder as u64,
+            }),
+            GameEvent::AuctionResolved {
+                time,
+                auction,
+                won,
+                winning_bid,
+            } => Kind::AuctionResolved(pb::AuctionResolved {
+                time: *time,
+                auction: *auction as u64,
+                won: *won,
+                winning_bid: *winning_bid,
+            }),
+            GameEvent::RareCargo {
+                time,
+                order,
+                airport,
+                kind,
+            } => Kind::RareCargo(pb::RareCargo {
+                time: *time,
+                order: *order as u64,
+                airport: *airport as u64,
+                kind: kind.to_string(),
+            }),
+            GameEvent::AirportOpened {
+                time,
+                airport,
+                near,
+            } => Kind::AirportOpened(pb::AirportOpened {
+                time: *time,
+                airport: *airport as u64,
+                near: *near as u64,
+            }),
+            GameEvent::ConstructionComplete {
+                time,
+                construction,
+                airport,
+            } => Kind::ConstructionComplete(pb::ConstructionComplete {
+                time: *time,
+                construction: *construction as u64,
+                airport: *airport as u64,
+            }),
+            GameEvent::ConcessionIncome {
+                time,
+                airport,
+                amount,
+            } => Kind::ConcessionIncome(pb::ConcessionIncome {
+                time: *time,
+                airport: *airport as u64,
+                amount: *amount,
+            }),
+            GameEvent::DailyReport(stats) => Kind::DailyReport(pb::DailyReport {
+                day: stats.day,
+                income: stats.income,
+                expenses: stats.expenses,
+                net_cash: stats.net_cash,
+                fleet_size: stats.fleet_size as u64,
+                total_deliveries: stats.total_deliveries as u64,
+            }),
+            GameEvent::MonthlyReport(report) => Kind::MonthlyReport(pb::MonthlyReport {
+                year: report.year,
+                month: report.month.into(),
+                revenue: report.revenue,
+                net: report.net,
+                closing_cash: report.closing_cash,
+            }),
+        };
+        pb::GameEvent {
+            kind: Some(kind),
+            json: serde_json::to_string(event).expect("event serializes"),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! gRPC server for driving RustyRunways from any language.
+//!
+//! The schema lives in `proto/rusty_runways.proto`; generate a client from it for Go,
+//! C#, Java or anything else with protobuf support. [`SimulationService`] keeps any
+//! number of games in memory, each addressed by the id [`NewGame`] returns.
+//!
+//! [`NewGame`]: pb::simulation_server::Simulation::new_game
+// every handler returns tonic's `Status`, which is large by design
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rusty_runways_core::Game;
+use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::events::EventFilter;
+use rusty_runways_core::metrics::ServerMetrics;
+use rusty_runways_core::utils::errors::GameError;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+pub mod convert;
+
+/// Code generated from `proto/rusty_runways.proto`.
+pub mod pb {
+    tonic::include_proto!("rusty_runways.v1");
+}
+
+use pb::simulation_server::{Simulation, SimulationServer};
+
+/// Address the server binary listens on unless told otherwise.
+pub const DEFAULT_ADDR: &str = "127.0.0.1:50051";
+
+/// Events kept for `StreamEvents` clients that read slower than the game runs; older
+/// ones are skipped.
+pub const EVENT_BUFFER: usize = 1024;
+
+const DEFAULT_STARTING_CASH: f32 = 650_000.0;
+
+/// One running game and the channel its events are published on.
+struct Session {
+    game: Mutex<Game>,
+    events: broadcast::Sender<pb::GameEvent>,
+    metrics: Arc<ServerMetrics>,
+}
+
+impl Session {
+    /// Run `f` on the game and publish the events it caused, which are also returned.
+    /// The request is counted in the server's metrics.
+    fn run<T>(
+        &self,
+        f: impl FnOnce(&mut Game) -> Result<T, Status>,
+    ) -> Result<(T, Vec<pb::GameEvent>), Status> {
+        let mut game = self
+            .game
+            .lock()
+            .map_err(|_| Status::internal("game lock poisoned"))?;
+        let receiver = game.subscribe(EventFilter::all());
+        let before = game.time;
+        let result = f(&mut game);
+        let events: Vec<pb::GameEvent> = receiver.try_iter().map(|e| (&e).into()).collect();
+        self.metrics
+            .record(game.time.saturating_sub(before), events.len() as u64);
+        for event in &events {
+            // nobody streaming is fine
+            let _ = self.events.send(event.clone());
+        }
+        Ok((result?, events))
+    }
+}
+
+/// The `Simulation` service; serve it with [`server`].
+#[derive(Default)]
+pub struct SimulationService {
+    sessions: Mutex<HashMap<u64, Arc<Session>>>,
+    next_id: AtomicU64,
+    metrics: Arc<ServerMetrics>,
+}
+
+/// A tonic service wrapping a fresh [`SimulationService`].
+pub fn server() -> SimulationServer<SimulationService> {
+    SimulationServer::new(SimulationService::default())
+}
+
+impl SimulationService {
+    fn session(&self, game_id: u64) -> Result<Arc<Session>, Status> {
+        self.sessions
+            .lock()
+            .map_err(|_| Status::internal("session lock poisoned"))?
+            .get(&game_id)
+            .cloned()
+            .ok_or_else(|| Status::not_found(format!("no game with id {}", game_id)))
+    }
+
+    /// Prometheus metrics for the server and every running game, labelled by game id.
+    pub fn render_metrics(&self) -> String {
+        let sessions: Vec<(u64, Arc<Session>)> = match self.sessions.lock() {
+            Ok(sessions) => sessions
+                .iter()
+                .map(|(id, s)| (*id, Arc::clone(s)))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        // games whose lock is poisoned are left out rather than failing the scrape
+        let games: Vec<_> = sessions
+            .iter()
+            .filter_map(|(id, session)| session.game.lock().ok().map(|game| (*id, game)))
+            .collect();
+        self.metrics
+            .render(games.iter().map(|(id, game)| (*id, &**game)))
+    }
+}
+
+fn game_status(error: GameError) -> Status {
+    match error {
+        GameError::InvalidCommand { msg } => Status::invalid_argument(msg),
+        other => Status::failed_precondition(other.to_string()),
+    }
+}
+
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Run blocking simulation work off the async runtime.
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T, Status> + Send + 'static,
+) -> Result<T, Status> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<pb::GameEvent, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Simulation for SimulationService {
+    async fn new_game(
+        &self,
+        request: Request<pb::NewGameRequest>,
+    ) -> Result<Response<pb::GameHandle>, Status> {
+        let req = request.into_inner();
+        let game = blocking(move || match (req.state_msgpack, req.config_yaml) {
+            (Some(state), _) => Game::from_state_msgpack(&state)
+                .map_err(|e| Status::invalid_argument(e.to_string())),
+            (None, Some(yaml)) => {
+                let config: WorldConfig = serde_yaml::from_str(&yaml)
+                    .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                Game::from_config(config).map_err(|e| Status::invalid_argument(e.to_string()))
+            }
+            (None, None) => Ok(Game::new(
+                req.seed.unwrap_or_else(random_seed),
+                req.num_airports.map(|n| n as usize),
+                req.starting_cash.unwrap_or(DEFAULT_STARTING_CASH),
+            )),
+        })
+        .await?;
+
+        let game_id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+        let session = Arc::new(Session {
+            game: Mutex::new(game),
+            events,
+            metrics: Arc::clone(&self.metrics),
+        });
+        self.sessions
+            .lock()
+            .map_err(|_| Status::internal("session lock poisoned"))?
+            .insert(game_id, session);
+        Ok(Response::new(pb::GameHandle { game_id }))
+    }
+
+    async fn execute(
+        &self,
+        request: Request<pb::ExecuteRequest>,
+    ) -> Result<Response<pb::ExecuteResponse>, Status> {
+        let req = request.into_inner();
+        let session = self.session(req.game_id)?;
+        let ((), events) = blocking(move || {
+            session.run(|game| game.execute_str(&req.command).map_err(game_status))
+        })
+        .await?;
+        Ok(Response::new(pb::ExecuteResponse { events }))
+    }
+
+    async fn advance(
+        &self,
+        request: Request<pb::AdvanceRequest>,
+    ) -> Result<Response<pb::Observation>, Status> {
+        let req = request.into_inner();
+        let session = self.session(req.game_id)?;
+        let (observation, _) = blocking(move || {
+            session.run(|game| {
+                game.advance(req.hours);
+                Ok(game.observe())
+            })
+        })
+        .await?;
+        Ok(Response::new(observation.into()))
+    }
+
+    async fn observe(
+        &self,
+        request: Request<pb::GameHandle>,
+    ) -> Result<Response<pb::Observation>, Status> {
+        let session = self.session(request.into_inner().game_id)?;
+        let (observation, _) = session.run(|game| Ok(game.observe()))?;
+        Ok(Response::new(observation.into()))
+    }
+
+    type StreamEventsStream = EventStream;
+
+    async fn stream_events(
+        &self,
+        request: Request<pb::GameHandle>,
+    ) -> Result<Response<Self::StreamEventsStream>, Status> {
+        let session = self.session(request.into_inner().game_id)?;
+        // events dropped because the client lagged are skipped
+        let stream =
+            BroadcastStream::new(session.events.subscribe()).filter_map(|e| e.ok().map(Ok));
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn end_game(
+        &self,
+        request: Request<pb::GameHandle>,
+    ) -> Result<Response<pb::EndGameResponse>, Status> {
+        let game_id = request.into_inner().game_id;
+        self.sessions
+            .lock()
+            .map_err(|_| Status::internal("session lock poisoned"))?
+            .remove(&game_id)
+            .ok_or_else(|| Status::not_found(format!("no game with id {}", game_id)))?;
+        Ok(Response::new(pb::EndGameResponse {}))
+    }
+
+    async fn export_state(
+        &self,
+        request: Request<pb::GameHandle>,
+    ) -> Result<Response<pb::GameState>, Status> {
+        let session = self.session(request.into_inner().game_id)?;
+        let (msgpack, _) = blocking(move || {
+            session.run(|game| {
+                game.state_msgpack()
+                    .map_err(|e| Status::internal(e.to_string()))
+            })
+        })
+        .await?;
+        Ok(Response::new(pb::GameState { msgpack }))
+    }
+}
@@ -0,0 +1,21 @@
+use clap::Parser;
+use rusty_runways_grpc::{DEFAULT_ADDR, server};
+
+/// Serve RustyRunways games over gRPC.
+#[derive(Parser, Debug)]
+struct Args {
+    /// Address to listen on
+    #[arg(long, default_value = DEFAULT_ADDR)]
+    addr: std::net::SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    eprintln!("Serving RustyRunways over gRPC on {}", args.addr);
+    tonic::transport::Server::builder()
+        .add_service(server())
+        .serve(args.addr)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use rusty_runways_grpc::SimulationService;
+use rusty_runways_grpc::pb::game_event::Kind;
+use rusty_runways_grpc::pb::simulation_server::Simulation;
+use rusty_runways_grpc::pb::{AdvanceRequest, ExecuteRequest, GameHandle, NewGameRequest};
+use tokio_stream::StreamExt;
+use tonic::{Code, Request};
+
+async fn new_game(service: &SimulationService) -> u64 {
+    let request = NewGameRequest {
+        seed: Some(1),
+        num_airports: Some(5),
+        starting_cash: None,
+        config_yaml: None,
+        state_msgpack: None,
+    };
+    service
+        .new_game(Request::new(request))
+        .await
+        .unwrap()
+        .into_inner()
+        .game_id
+}
+
+#[tokio::test]
+async fn new_game_and_observe() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    let obs = service
+        .observe(Request::new(GameHandle { game_id }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(obs.airports.len(), 5);
+    assert_eq!(obs.cash, 650_000.0);
+    assert!(obs.json.contains("\"used_market\""));
+
+    let other = new_game(&service).await;
+    assert_ne!(other, game_id);
+}
+
+#[tokio::test]
+async fn advance_returns_observation() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    let obs = service
+        .advance(Request::new(AdvanceRequest { game_id, hours: 5 }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(obs.time, 5);
+}
+
+#[tokio::test]
+async fn execute_reports_events_and_errors() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    let response = service
+        .execute(Request::new(ExecuteRequest {
+            game_id,
+            command: "ADVANCE 24".into(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        response
+            .events
+            .iter()
+            .any(|e| matches!(e.kind, Some(Kind::DailyReport(_))))
+    );
+
+    let err = service
+        .execute(Request::new(ExecuteRequest {
+            game_id,
+            command: "FLY AWAY".into(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+
+    let err = service
+        .execute(Request::new(ExecuteRequest {
+            game_id,
+            command: "SELL PLANE 99".into(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn stream_sees_events_from_other_calls() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    let mut stream = service
+        .stream_events(Request::new(GameHandle { game_id }))
+        .await
+        .unwrap()
+        .into_inner();
+    service
+        .advance(Request::new(AdvanceRequest { game_id, hours: 24 }))
+        .await
+        .unwrap();
+    let event = stream.next().await.unwrap().unwrap();
+    assert!(event.kind.is_some());
+    assert!(!event.json.is_empty());
+}
+
+#[tokio::test]
+async fn ended_games_are_gone() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    service
+        .end_game(Request::new(GameHandle { game_id }))
+        .await
+        .unwrap();
+    let err = service
+        .observe(Request::new(GameHandle { game_id }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn exported_state_restores_the_game() {
+    let service = SimulationService::default();
+    let game_id = new_game(&service).await;
+    service
+        .advance(Request::new(AdvanceRequest { game_id, hours: 30 }))
+        .await
+        .unwrap();
+    let state = service
+        .export_state(Request::new(GameHandle { game_id }))
+        .await
+        .unwrap()
+        .into_inner();
+    let restored = service
+        .new_game(Request::new(NewGameRequest {
+            state_msgpack: Some(state.msgpack),
+            ..Default::default()
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .game_id;
+    let obs = service
+        .observe(Request::new(GameHandle { game_id: restored }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(obs.time, 30);
+}
+
+#[tokio::test]
+async fn metrics_cover_every_game() {
+    let service = SimulationService::default();
+    let first = new_game(&service).await;
+    let second = new_game(&service).await;
+    service
+        .advance(Request::new(AdvanceRequest {
+            game_id: first,
+            hours: 5,
+        }))
+        .await
+        .unwrap();
+
+    let text = service.render_metrics();
+    assert!(text.contains("rusty_runways_active_games 2\n"));
+    assert!(text.contains("rusty_runways_hours_simulated_total 5\n"));
+    assert!(text.contains(&format!(
+        "rusty_runways_game_time_hours{{game=\"{}\"}} 5\n",
+        first
+    )));
+    assert!(text.contains(&format!(
+        "rusty_runways_game_time_hours{{game=\"{}\"}} 0\n",
+        second
+    )));
+}
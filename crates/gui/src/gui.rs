@@ -1,13 +1,26 @@
 use eframe::egui::{
-    self, CornerRadius, Id, Pos2, Rect, ScrollArea, Sense, SidePanel, TopBottomPanel, Vec2, Window,
+    self, CornerRadius, Id, Pos2, Rect, ScrollArea, Sense, SidePanel, TopBottomPanel, Vec2,
+    ViewportClass, ViewportId, Window,
 };
 use rand::Rng;
 use rusty_runways_core::Game;
 use rusty_runways_core::config::WorldConfig;
-use rusty_runways_core::utils::airplanes::models::{AirplaneModel, AirplaneStatus};
+use rusty_runways_core::tr;
+use rusty_runways_core::utils::airplanes::checks::CheckType;
+use rusty_runways_core::utils::airplanes::models::{
+    AirplaneModel, AirplaneStatus, CruiseRegime, FuelAmount,
+};
+use rusty_runways_core::utils::coordinate::Coordinate;
+use rusty_runways_core::utils::map::AirportId;
 use rusty_runways_core::utils::orders::order::OrderPayload;
 
-use crate::transforms::{map_transforms, world_to_screen};
+use crate::transforms::{camera_transform, map_transforms, screen_to_world, world_to_screen};
+
+/// Furthest the world map zooms in, relative to showing the whole world.
+const MAX_MAP_ZOOM: f32 = 20.0;
+
+/// Size of the overview minimap in the corner of the world map.
+const MINIMAP_SIZE: Vec2 = Vec2::new(180.0, 120.0);
 
 enum Screen {
     MainMenu,
@@ -16,10 +29,78 @@ enum Screen {
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum ClickItem {
-    Airport(usize),
+    Airport(AirportId),
     Plane(usize),
 }
 
+/// Show `contents` in a floating window, or in an OS window of its own when `detached`.
+///
+/// A Pop out / Dock button at the top moves the window between the two; closing either
+/// kind of window clears `open`. Backends without multi-viewport support keep detached
+/// windows floating inside the main one.
+fn show_detachable(
+    ctx: &egui::Context,
+    id: Id,
+    title: &str,
+    size: Vec2,
+    open: &mut bool,
+    detached: &mut bool,
+    mut contents: impl FnMut(&mut egui::Ui),
+) {
+    let was_detached = *detached;
+    let mut toggle = false;
+    let mut body = |ui: &mut egui::Ui| {
+        let label = if was_detached {
+            tr!("gui-dock")
+        } else {
+            tr!("gui-pop-out")
+        };
+        if ui.small_button(label).clicked() {
+            toggle = true;
+        }
+        contents(ui);
+    };
+    if was_detached {
+        let builder = egui::ViewportBuilder::default()
+            .with_title(title)
+            .with_inner_size(size);
+        ctx.show_viewport_immediate(ViewportId::from_hash_of(id), builder, |ctx, class| {
+            if class == ViewportClass::Embedded {
+                Window::new(title)
+                    .id(id)
+                    .open(open)
+                    .collapsible(false)
+                    .default_size(size)
+                    .resizable(true)
+                    .show(ctx, &mut body);
+                return;
+            }
+            egui::CentralPanel::default().show(ctx, &mut body);
+            if ctx.input(|i| i.viewport().close_requested()) {
+                *open = false;
+            }
+        });
+    } else {
+        Window::new(title)
+            .id(id)
+            .open(open)
+            .collapsible(false)
+            .default_size(size)
+            .resizable(true)
+            .show(ctx, body);
+    }
+    if toggle {
+        *detached = !*detached;
+    }
+}
+
+/// Name of the airport with `id` for labels, `?` if the map has no such airport.
+fn airport_name(game: &Option<Game>, id: AirportId) -> &str {
+    game.as_ref()
+        .and_then(|g| g.map.airport(id))
+        .map_or("?", |airport| airport.name.as_str())
+}
+
 pub struct RustyRunwaysGui {
     // global
     screen: Screen,
@@ -48,12 +129,27 @@ pub struct RustyRunwaysGui {
     load_dialog: bool,
     save_input: String,
     load_input: String,
+    // monthly statement dialog
+    report_dialog: bool,
+    // spot-charter auctions dialog, with the bid typed in for each auction
+    auction_dialog: bool,
+    auction_bids: std::collections::BTreeMap<usize, f32>,
 
     // In Game selection
-    hovered_airport: Option<usize>,
-    selected_airport: Option<usize>,
+    hovered_airport: Option<AirportId>,
+    selected_airport: Option<AirportId>,
     hovered_airplane: Option<usize>,
     selected_airplane: Option<usize>,
+    rename_input: String,
+    refuel_input: String,
+    group_input: String,
+    fleet_group_filter: Option<String>,
+
+    // world map camera: zoom over the whole world, the point at the center (None for
+    // the middle of the world) and the plane kept centered
+    map_zoom: f32,
+    map_focus: Option<Coordinate>,
+    follow_plane: Option<usize>,
 
     overlap_menu_open: bool,
     overlap_menu_items: Vec<ClickItem>,
@@ -64,6 +160,7 @@ pub struct RustyRunwaysGui {
     airport_plane_selection: Option<usize>,
     plane_order_selection: Option<usize>,
     plane_destination: Option<usize>,
+    plane_speed: CruiseRegime,
     // multi-select for orders
     airport_order_multi: std::collections::BTreeSet<usize>,
     plane_order_multi: std::collections::BTreeSet<usize>,
@@ -71,14 +168,20 @@ pub struct RustyRunwaysGui {
     plane_filter_dest: Option<usize>,
     plane_filter_min_w: f32,
     plane_filter_max_w: f32,
+    // scheduled route fares (airport window)
+    fare_destination: Option<usize>,
+    fare_input: f32,
     // buy plane dialog
     buy_dialog: bool,
     buy_model: Option<AirplaneModel>,
     buy_airport: Option<usize>,
 
-    // Additional windows
+    // Additional windows, and whether each is popped out into an OS window of its own
     airport_panel: bool,
     plane_panel: bool,
+    airport_detached: bool,
+    plane_detached: bool,
+    report_detached: bool,
 }
 
 impl Default for RustyRunwaysGui {
@@ -101,10 +204,20 @@ impl Default for RustyRunwaysGui {
             load_dialog: false,
             save_input: String::new(),
             load_input: String::new(),
+            report_dialog: false,
+            auction_dialog: false,
+            auction_bids: std::collections::BTreeMap::new(),
             hovered_airport: None,
             selected_airport: None,
             hovered_airplane: None,
             selected_airplane: None,
+            rename_input: String::new(),
+            refuel_input: String::new(),
+            group_input: String::new(),
+            fleet_group_filter: None,
+            map_zoom: 1.0,
+            map_focus: None,
+            follow_plane: None,
             overlap_menu_open: false,
             overlap_menu_items: Vec::new(),
             overlap_menu_pos: Pos2::ZERO,
@@ -112,16 +225,22 @@ impl Default for RustyRunwaysGui {
             airport_plane_selection: None,
             plane_order_selection: None,
             plane_destination: None,
+            plane_speed: CruiseRegime::Normal,
             airport_order_multi: Default::default(),
             plane_order_multi: Default::default(),
             plane_filter_dest: None,
             plane_filter_min_w: 0.0,
             plane_filter_max_w: 1_000_000.0,
+            fare_destination: None,
+            fare_input: 0.0,
             buy_dialog: false,
             buy_model: None,
             buy_airport: None,
             airport_panel: false,
             plane_panel: false,
+            airport_detached: false,
+            plane_detached: false,
+            report_detached: false,
         }
     }
 }
@@ -143,32 +262,32 @@ impl RustyRunwaysGui {
             ui.vertical_centered(|ui| {
                 ui.add_space(12.0);
                 ui.heading("RustyRunways");
-                ui.small("Build your aviation empire · Manage logistics · Conquer the skies");
+                ui.small(tr!("gui-tagline"));
                 ui.add_space(24.0);
             });
 
             ui.columns(2, |cols| {
                 // Left column for new game
                 cols[0].group(|ui| {
-                    ui.heading("Start New Game");
+                    ui.heading(tr!("gui-start-new-game"));
                     ui.add_space(12.0);
 
-                    ui.label("Game Seed");
+                    ui.label(tr!("gui-game-seed"));
                     ui.text_edit_singleline(&mut self.seed_str);
                     ui.add_space(6.0);
 
-                    ui.label("Number of Airports");
+                    ui.label(tr!("gui-number-of-airports"));
                     ui.text_edit_singleline(&mut self.airports_str);
                     ui.add_space(6.0);
 
-                    ui.label("Starting Cash ($)");
+                    ui.label(tr!("gui-starting-cash"));
                     ui.text_edit_singleline(&mut self.cash_str);
 
                     ui.add_space(12.0);
 
                     // Game from inputs
                     if ui
-                        .add_sized([150.0, 30.0], egui::Button::new("Launch Game"))
+                        .add_sized([150.0, 30.0], egui::Button::new(tr!("gui-launch-game")))
                         .clicked()
                     {
                         self.error = None;
@@ -178,21 +297,22 @@ impl RustyRunwaysGui {
                         let seed = match self.seed_str.parse::<u64>() {
                             Ok(s) => s,
                             Err(e) => {
-                                self.error = Some(format!("Invalid seed: {}", e));
+                                self.error = Some(tr!("gui-invalid-seed", error = e.to_string()));
                                 return;
                             }
                         };
                         let airports = match self.airports_str.parse::<usize>() {
                             Ok(n) => n,
                             Err(e) => {
-                                self.error = Some(format!("Invalid # of airports: {}", e));
+                                self.error =
+                                    Some(tr!("gui-invalid-airports", error = e.to_string()));
                                 return;
                             }
                         };
                         let cash = match self.cash_str.parse::<f32>() {
                             Ok(c) => c,
                             Err(e) => {
-                                self.error = Some(format!("Invalid starting cash: {}", e));
+                                self.error = Some(tr!("gui-invalid-cash", error = e.to_string()));
                                 return;
                             }
                         };
@@ -206,21 +326,21 @@ impl RustyRunwaysGui {
 
                 // middle column for loading game
                 cols[1].group(|ui| {
-                    ui.heading("Load Saved Game");
+                    ui.heading(tr!("gui-load-saved-game"));
                     ui.add_space(12.0);
 
-                    ui.label("Save Game Name");
+                    ui.label(tr!("gui-save-game-name"));
                     ui.text_edit_singleline(&mut self.save_name);
                     ui.add_space(6.0);
 
-                    ui.label("Recent Saves");
+                    ui.label(tr!("gui-recent-saves"));
                     for name in &self.recent_saves {
                         ui.label(format!("• {}", name));
                     }
 
                     ui.add_space(12.0);
                     if ui
-                        .add_sized([150.0, 30.0], egui::Button::new("Load Game"))
+                        .add_sized([150.0, 30.0], egui::Button::new(tr!("gui-load-game")))
                         .clicked()
                     {
                         self.game = match Game::load_game(&self.save_name) {
@@ -239,12 +359,12 @@ impl RustyRunwaysGui {
 
             ui.add_space(12.0);
             ui.group(|ui| {
-                ui.heading("Start From Config");
+                ui.heading(tr!("gui-start-from-config"));
                 ui.add_space(12.0);
-                ui.label("Config path (.yaml)");
+                ui.label(tr!("gui-config-path"));
                 ui.horizontal(|ui| {
                     ui.text_edit_singleline(&mut self.config_path);
-                    if ui.button("Browse").clicked() {
+                    if ui.button(tr!("gui-browse")).clicked() {
                         if let Some(path) = rfd::FileDialog::new()
                             .add_filter("YAML", &["yaml", "yml"])
                             .pick_file()
@@ -256,7 +376,7 @@ impl RustyRunwaysGui {
                     }
                 });
                 ui.add_space(6.0);
-                if ui.button("Preview").clicked() {
+                if ui.button(tr!("gui-preview")).clicked() {
                     match std::fs::read_to_string(&self.config_path) {
                         Ok(text) => match serde_yaml::from_str::<WorldConfig>(&text) {
                             Ok(cfg) => {
@@ -269,7 +389,7 @@ impl RustyRunwaysGui {
                         Err(e) => self.error = Some(format!("Read error: {}", e)),
                     }
                 }
-                if ui.button("Start").clicked() {
+                if ui.button(tr!("gui-start")).clicked() {
                     match std::fs::read_to_string(&self.config_path) {
                         Ok(text) => match serde_yaml::from_str::<WorldConfig>(&text) {
                             Ok(cfg) => match Game::from_config(cfg) {
@@ -292,7 +412,7 @@ impl RustyRunwaysGui {
 
                 // Random game
                 if ui
-                    .add_sized([150.0, 30.0], egui::Button::new("Random Game"))
+                    .add_sized([150.0, 30.0], egui::Button::new(tr!("gui-random-game")))
                     .clicked()
                 {
                     let seed: u64 = rand::thread_rng().r#gen();
@@ -314,7 +434,7 @@ impl RustyRunwaysGui {
         // Config preview window
         if self.preview_open {
             let mut open = true;
-            Window::new("Config Preview")
+            Window::new(tr!("gui-config-preview"))
                 .open(&mut open)
                 .resizable(true)
                 .default_size(Vec2::new(640.0, 420.0))
@@ -371,7 +491,7 @@ impl RustyRunwaysGui {
                             }
                         });
                         ui.separator();
-                        if ui.button("Start Game").clicked() {
+                        if ui.button(tr!("gui-start-game")).clicked() {
                             if let Some(cfg2) = self.preview_cfg.clone() {
                                 match Game::from_config(cfg2) {
                                     Ok(g) => {
@@ -397,7 +517,7 @@ impl RustyRunwaysGui {
             if i.key_pressed(egui::Key::Space) {
                 if let Some(g) = self.game.as_mut() {
                     g.advance(1);
-                    self.log.push("Advanced 1h".to_string());
+                    self.log.push(tr!("gui-advanced-hour"));
                     self.scroll_log = true;
                 }
             }
@@ -417,25 +537,30 @@ impl RustyRunwaysGui {
                 ui.separator();
                 ui.label(format!("${:.0}", self.game.as_ref().unwrap().get_cash()));
                 ui.separator();
+                ui.label(tr!(
+                    "gui-company-value",
+                    value = format!("{:.0}", self.game.as_ref().unwrap().company_value())
+                ));
+                ui.separator();
                 ui.label(self.game.as_ref().unwrap().get_time().to_string());
                 ui.separator();
-                ui.label(format!(
-                    "{} planes",
-                    self.game.as_ref().unwrap().player.fleet_size
+                ui.label(tr!(
+                    "gui-fleet-size",
+                    count = self.game.as_ref().unwrap().player.fleet_size
                 ));
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button("Save").clicked() {
+                    if ui.button(tr!("gui-save")).clicked() {
                         self.save_dialog = true;
                         self.save_input.clear();
                     }
-                    if ui.button("Load").clicked() {
+                    if ui.button(tr!("gui-load")).clicked() {
                         self.load_dialog = true;
                         self.load_input.clear();
                     }
-                    if ui.button("Menu").clicked() {
+                    if ui.button(tr!("gui-menu")).clicked() {
                         self.screen = Screen::MainMenu;
                     }
-                    if ui.button("Exit").clicked() {
+                    if ui.button(tr!("gui-exit")).clicked() {
                         std::process::exit(0);
                     }
                 });
@@ -496,6 +621,109 @@ impl RustyRunwaysGui {
             self.load_dialog = open && !close;
         }
 
+        if self.report_dialog {
+            let mut open = true;
+            show_detachable(
+                ctx,
+                Id::new("monthly_report_window"),
+                "Monthly Report",
+                Vec2::new(320.0, 260.0),
+                &mut open,
+                &mut self.report_detached,
+                |ui| match self.game.as_ref().unwrap().monthly_reports.last() {
+                    Some(report) => {
+                        ui.heading(format!(
+                            "{} year {}",
+                            report.date().month_name(),
+                            report.year
+                        ));
+                        egui::Grid::new("monthly_report").show(ui, |ui| {
+                            for (label, amount) in report.lines() {
+                                ui.label(label);
+                                ui.label(format!("${:.2}", amount));
+                                ui.end_row();
+                            }
+                            ui.label("Cash");
+                            ui.label(format!("${:.2}", report.closing_cash));
+                            ui.end_row();
+                        });
+                    }
+                    None => {
+                        ui.label("No month has closed yet.");
+                    }
+                },
+            );
+            self.report_dialog = open;
+        }
+
+        if self.auction_dialog {
+            let mut open = true;
+            Window::new("Auctions")
+                .collapsible(false)
+                .resizable(false)
+                .default_size(Vec2::new(380.0, 260.0))
+                .open(&mut open)
+                .show(ctx, |ui| {
+                    let auctions = self.game.as_ref().unwrap().auctions().to_vec();
+                    if auctions.is_empty() {
+                        ui.label("No auctions open.");
+                    }
+                    self.auction_bids
+                        .retain(|id, _| auctions.iter().any(|a| a.id == *id));
+                    for auction in auctions {
+                        ui.group(|ui| {
+                            let time = self.game.as_ref().unwrap().time;
+                            ui.label(format!(
+                                "[{}] {:.0}kg {:?} {} -> {}",
+                                auction.id,
+                                auction.weight,
+                                auction.cargo_type,
+                                airport_name(&self.game, auction.origin_id),
+                                airport_name(&self.game, auction.destination_id),
+                            ));
+                            ui.label(format!(
+                                "Due within {}h | going rate: ${:.2} | closes in {}h",
+                                auction.deadline,
+                                auction.reference_value,
+                                auction.closes_at.saturating_sub(time)
+                            ));
+                            if let Some(bid) = auction.player_bid {
+                                ui.label(format!("Your bid: ${:.2}", bid));
+                            }
+                            let bid = self
+                                .auction_bids
+                                .entry(auction.id)
+                                .or_insert(auction.reference_value);
+                            ui.horizontal(|ui| {
+                                ui.add(
+                                    egui::DragValue::new(bid)
+                                        .speed(10.0)
+                                        .range(1.0..=f32::MAX)
+                                        .prefix("$"),
+                                );
+                                if ui.button("Bid").clicked() {
+                                    let amount = *bid;
+                                    match self
+                                        .game
+                                        .as_mut()
+                                        .unwrap()
+                                        .bid_auction(auction.id, amount)
+                                    {
+                                        Ok(()) => self.log.push(format!(
+                                            "Bid ${:.2} on auction {}",
+                                            amount, auction.id
+                                        )),
+                                        Err(e) => self.log.push(format!("Bid failed: {}", e)),
+                                    }
+                                    self.scroll_log = true;
+                                }
+                            });
+                        });
+                    }
+                });
+            self.auction_dialog = open;
+        }
+
         // Right sidebar for stats/overviews
         SidePanel::right("sidebar")
             .resizable(true)
@@ -504,24 +732,64 @@ impl RustyRunwaysGui {
                 // stats, quick actions & planes
                 ui.group(|ui| {
                     // STATS
-                    ui.heading("Game Stats");
-                    ui.label(format!(
-                        "Income: ${:.2}\nExpenses: ${:.2}\nDeliveries: {}",
-                        self.game.as_ref().unwrap().daily_income,
-                        self.game.as_ref().unwrap().daily_expenses,
-                        self.game.as_ref().unwrap().player.orders_delivered
+                    ui.heading(tr!("gui-game-stats"));
+                    ui.label(tr!(
+                        "gui-daily-stats",
+                        income = format!("{:.2}", self.game.as_ref().unwrap().daily_income),
+                        expenses = format!("{:.2}", self.game.as_ref().unwrap().daily_expenses),
+                        deliveries = self.game.as_ref().unwrap().player.orders_delivered,
                     ));
+                    ui.horizontal(|ui| {
+                        if ui.button(tr!("gui-monthly-report")).clicked() {
+                            self.report_dialog = true;
+                        }
+                        if ui.button(tr!("gui-auctions")).clicked() {
+                            self.auction_dialog = true;
+                        }
+                    });
                     ui.separator();
 
                     // Fleet overview
                     ui.horizontal(|ui| {
-                        ui.heading("Fleet Overview");
+                        ui.heading(tr!("gui-fleet-overview"));
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Buy new plane").clicked() {
+                            if ui.button(tr!("gui-buy-new-plane")).clicked() {
                                 self.buy_dialog = true;
                             }
                         });
                     });
+                    let group_names: Vec<String> = self
+                        .game
+                        .as_ref()
+                        .unwrap()
+                        .player
+                        .groups
+                        .keys()
+                        .cloned()
+                        .collect();
+                    if self
+                        .fleet_group_filter
+                        .as_ref()
+                        .is_some_and(|g| !group_names.contains(g))
+                    {
+                        self.fleet_group_filter = None;
+                    }
+                    egui::ComboBox::from_label("Group")
+                        .selected_text(
+                            self.fleet_group_filter
+                                .clone()
+                                .unwrap_or_else(|| "All planes".into()),
+                        )
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.fleet_group_filter, None, "All planes");
+                            for name in &group_names {
+                                ui.selectable_value(
+                                    &mut self.fleet_group_filter,
+                                    Some(name.clone()),
+                                    name,
+                                );
+                            }
+                        });
                     let h = ui.available_height().min(300.0);
                     ScrollArea::vertical()
                         .id_salt("Fleet Overview")
@@ -529,10 +797,18 @@ impl RustyRunwaysGui {
                         .show(ui, |ui| {
                             let g = self.game.as_ref().unwrap();
                             let airports = g.airports().to_vec();
+                            let members = self
+                                .fleet_group_filter
+                                .as_ref()
+                                .and_then(|name| g.group_planes(name).ok());
                             for plane in g.planes() {
+                                if members.as_ref().is_some_and(|m| !m.contains(&plane.id)) {
+                                    continue;
+                                }
                                 let status = match &plane.status {
                                     AirplaneStatus::Parked => "Parked".into(),
                                     AirplaneStatus::Refueling => "Refueling".into(),
+                                    AirplaneStatus::Charging => "Charging".into(),
                                     AirplaneStatus::Loading => "Loading".into(),
                                     AirplaneStatus::Unloading => "Unloading".into(),
                                     AirplaneStatus::Maintenance => "Maintenance".into(),
@@ -543,6 +819,11 @@ impl RustyRunwaysGui {
                                     }
                                     AirplaneStatus::Broken => "Broken".into(),
                                 };
+                                let status = if plane.on_hold {
+                                    format!("{} (on hold)", status)
+                                } else {
+                                    status
+                                };
 
                                 let at_airport =
                                     !matches!(plane.status, AirplaneStatus::InTransit { .. });
@@ -558,11 +839,14 @@ impl RustyRunwaysGui {
 
                                 let label = if at_airport {
                                     format!(
-                                        "{} | {:?} | {} | at {}",
-                                        plane.id, plane.model, status, loc_text
+                                        "{} | {} | {:?} | {} | at {}",
+                                        plane.id, plane.name, plane.model, status, loc_text
                                     )
                                 } else {
-                                    format!("{} | {:?} | {}", plane.id, plane.model, status)
+                                    format!(
+                                        "{} | {} | {:?} | {}",
+                                        plane.id, plane.name, plane.model, status
+                                    )
                                 };
 
                                 if ui.button(label).clicked() {
@@ -571,6 +855,57 @@ impl RustyRunwaysGui {
                                 }
                             }
                         });
+                    egui::CollapsingHeader::new("Maintenance planner")
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            let g = self.game.as_ref().unwrap();
+                            let settings = &g.maintenance_settings;
+                            for plane in g.planes() {
+                                let due: Vec<String> = [CheckType::A, CheckType::B, CheckType::C]
+                                    .into_iter()
+                                    .map(|check| {
+                                        let left =
+                                            settings.hours_until_due(&plane.check_hours, check);
+                                        if left > 0 {
+                                            format!("{} in {}h", check, left)
+                                        } else {
+                                            format!("{} OVERDUE", check)
+                                        }
+                                    })
+                                    .collect();
+                                ui.label(format!("{} | {}", plane.name, due.join(" | ")));
+                            }
+                        });
+                    egui::CollapsingHeader::new("Crew")
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            let g = self.game.as_ref().unwrap();
+                            let settings = &g.pilot_settings;
+                            for pilot in &g.player.pilots {
+                                let plane = pilot
+                                    .plane
+                                    .map(|p| format!("plane {}", p))
+                                    .unwrap_or_else(|| "unassigned".into());
+                                let types: Vec<String> = pilot
+                                    .type_hours
+                                    .iter()
+                                    .map(|(model, hours)| {
+                                        if *hours >= settings.type_rating_hours {
+                                            format!("{} {}h (rated)", model, hours)
+                                        } else {
+                                            format!("{} {}h", model, hours)
+                                        }
+                                    })
+                                    .collect();
+                                ui.label(format!(
+                                    "{} | {} | {}h flown | {}",
+                                    pilot.name,
+                                    plane,
+                                    pilot.experience_hours,
+                                    types.join(", ")
+                                ));
+                            }
+                        });
                     ui.separator();
 
                     // Airport overview
@@ -580,14 +915,12 @@ impl RustyRunwaysGui {
                         .id_salt("Airport Overview")
                         .max_height(h2)
                         .show(ui, |ui| {
-                            for (idx, (airport, _)) in
-                                self.game.as_ref().unwrap().airports().iter().enumerate()
-                            {
+                            for (airport, _) in self.game.as_ref().unwrap().airports() {
                                 if ui
                                     .button(format!("{} | {}", airport.id, airport.name))
                                     .clicked()
                                 {
-                                    self.selected_airport = Some(idx);
+                                    self.selected_airport = Some(airport.id);
                                     self.airport_panel = true;
                                 }
                             }
@@ -698,6 +1031,43 @@ impl RustyRunwaysGui {
                         });
                     });
 
+                    ui.add_space(8.0);
+                    egui::CollapsingHeader::new("Used market").default_open(false).show(ui, |ui| {
+                        let (listings, cash) = {
+                            let g = self.game.as_ref().unwrap();
+                            (g.used_market.clone(), g.get_cash())
+                        };
+                        if listings.is_empty() {
+                            ui.label("No used planes for sale");
+                        }
+                        for listing in listings {
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    "[{}] {} at airport {} | {}h airframe | {}h since maint{} | ${:.0} ({:.0}% off)",
+                                    listing.id,
+                                    listing.model,
+                                    listing.airport_id,
+                                    listing.airframe_hours,
+                                    listing.hours_since_maintenance,
+                                    if listing.needs_maintenance { " | needs repair" } else { "" },
+                                    listing.price,
+                                    listing.discount * 100.0
+                                ));
+                                if ui.add_enabled(cash >= listing.price, egui::Button::new("Buy")).clicked() {
+                                    match self.game.as_mut().unwrap().buy_used_plane(listing.id) {
+                                        Ok(plane) => self.log.push(format!(
+                                            "Bought used {} as plane {}",
+                                            listing.model, plane
+                                        )),
+                                        Err(e) => self.log.push(format!("Purchase failed: {}", e)),
+                                    }
+                                    self.scroll_log = true;
+                                    close = true;
+                                }
+                            });
+                        }
+                    });
+
                     // Balance indicator
                     if let Some(model) = &self.buy_model {
                         let price = model.specs().purchase_price;
@@ -793,7 +1163,7 @@ impl RustyRunwaysGui {
                 ui.add_space(4.0);
                 ui.separator();
                 ui.add_space(4.0);
-                ui.heading("Game Log");
+                ui.heading(tr!("gui-game-log"));
                 ScrollArea::vertical().stick_to_bottom(true).show(ui, |ui| {
                     for entry in &self.log {
                         ui.label(entry);
@@ -808,10 +1178,20 @@ impl RustyRunwaysGui {
         // Main content: world map fills remaining space
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.group(|ui| {
-                ui.heading("World Map");
+                ui.horizontal(|ui| {
+                    ui.heading(tr!("gui-world-map"));
+                    if ui.button(tr!("gui-reset-view")).clicked() {
+                        self.map_zoom = 1.0;
+                        self.map_focus = None;
+                        self.follow_plane = None;
+                    }
+                    if let Some(pid) = self.follow_plane {
+                        ui.label(tr!("gui-following-plane", plane = pid));
+                    }
+                });
 
                 let rect_size = ui.available_size();
-                let (rect, _response) = ui.allocate_exact_size(rect_size, Sense::hover());
+                let (rect, response) = ui.allocate_exact_size(rect_size, Sense::drag());
                 let painter = ui.painter().with_clip_rect(rect);
 
                 // get structs
@@ -824,18 +1204,51 @@ impl RustyRunwaysGui {
                     g.planes().clone()
                 };
 
-                // calculate transforms
-                let transform = map_transforms(&airports, rect, 8.0);
+                // calculate transforms: fit the whole world, then apply the camera
+                let world = map_transforms(&airports, rect, 8.0);
+                if let Some(pid) = self.follow_plane {
+                    match airplanes.iter().find(|p| p.id == pid) {
+                        Some(plane) => self.map_focus = Some(plane.location.projected()),
+                        None => self.follow_plane = None,
+                    }
+                }
+                if response.hovered() {
+                    let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+                    if scroll != 0.0 {
+                        self.map_zoom =
+                            (self.map_zoom * (scroll * 0.005).exp()).clamp(1.0, MAX_MAP_ZOOM);
+                    }
+                }
+                let focus = self
+                    .map_focus
+                    .unwrap_or_else(|| screen_to_world(rect.center(), world));
+                let mut transform = camera_transform(world, rect, self.map_zoom, &focus);
+                if response.dragged() {
+                    // dragging the map takes over from following a plane
+                    self.follow_plane = None;
+                    let focus = screen_to_world(rect.center() - response.drag_delta(), transform);
+                    transform = camera_transform(world, rect, self.map_zoom, &focus);
+                    self.map_focus = Some(focus);
+                }
+
+                let minimap_rect = Rect::from_min_size(
+                    rect.right_bottom() - MINIMAP_SIZE - Vec2::splat(8.0),
+                    MINIMAP_SIZE,
+                );
 
                 // background
                 painter.rect_filled(rect, CornerRadius::same(0), ui.visuals().extreme_bg_color);
 
-                if let Some(pos) = ui.ctx().input(|i| i.pointer.interact_pos()) {
+                if let Some(pos) = ui
+                    .ctx()
+                    .input(|i| i.pointer.interact_pos())
+                    .filter(|pos| !minimap_rect.contains(*pos))
+                {
                     let mut hits = Vec::new();
-                    for (idx, (_ap, coord)) in airports.iter().enumerate() {
+                    for (airport, coord) in airports.iter() {
                         let screen = world_to_screen(coord, transform);
                         if screen.distance(pos) < 6.0 {
-                            hits.push(ClickItem::Airport(idx));
+                            hits.push(ClickItem::Airport(airport.id));
                         }
                     }
                     for plane in airplanes.iter() {
@@ -857,11 +1270,12 @@ impl RustyRunwaysGui {
                 }
 
                 // airports
-                for (idx, (airport, coord)) in airports.iter().enumerate() {
+                for (airport, coord) in airports.iter() {
                     let screen_pos = world_to_screen(coord, transform);
 
                     let hit_rect = Rect::from_center_size(screen_pos, Vec2::splat(12.0));
-                    let resp = ui.interact(hit_rect, Id::new(("airport", idx)), Sense::hover());
+                    let resp =
+                        ui.interact(hit_rect, Id::new(("airport", airport.id)), Sense::hover());
                     let hovered = resp.hovered();
                     resp.on_hover_text(format!(
                         "{}\nFuel ${:.2}/L",
@@ -869,7 +1283,7 @@ impl RustyRunwaysGui {
                     ));
 
                     if hovered {
-                        self.hovered_airport = Some(idx);
+                        self.hovered_airport = Some(airport.id);
                         painter.circle_stroke(screen_pos, 6.0, (2.0, egui::Color32::LIGHT_BLUE));
                     }
                     painter.circle_filled(screen_pos, 4.0, egui::Color32::BLUE);
@@ -885,8 +1299,11 @@ impl RustyRunwaysGui {
                     } = plane.status
                     {
                         let pos0 = world_to_screen(&origin, transform);
-                        let pos1 = world_to_screen(&airports[destination].1, transform);
-                        painter.line_segment([pos0, pos1], (1.0, egui::Color32::YELLOW));
+                        if let Some((_, dest)) = airports.iter().find(|(a, _)| a.id == destination)
+                        {
+                            let pos1 = world_to_screen(dest, transform);
+                            painter.line_segment([pos0, pos1], (1.0, egui::Color32::YELLOW));
+                        }
                     }
                 }
                 for (idx, plane) in airplanes.iter().enumerate() {
@@ -895,8 +1312,9 @@ impl RustyRunwaysGui {
                     let resp = ui.interact(rect, Id::new(("plane", idx)), Sense::hover());
                     let hovered = resp.hovered();
                     resp.on_hover_text(format!(
-                        "Plane {}\nFuel {:.0}/{:.0}L\nPayload {:.0}/{:.0}kg",
+                        "Plane {} ({})\nFuel {:.0}/{:.0}L\nPayload {:.0}/{:.0}kg",
                         plane.id,
+                        plane.name,
                         plane.current_fuel,
                         plane.specs.fuel_capacity,
                         plane.current_payload,
@@ -908,6 +1326,53 @@ impl RustyRunwaysGui {
                     }
                     painter.circle_filled(p, 5.0, egui::Color32::WHITE);
                 }
+
+                // minimap of the whole world with the part in view outlined
+                let minimap = map_transforms(&airports, minimap_rect, 4.0);
+                painter.rect_filled(
+                    minimap_rect,
+                    CornerRadius::same(2),
+                    ui.visuals().window_fill(),
+                );
+                painter.rect_stroke(
+                    minimap_rect,
+                    CornerRadius::same(2),
+                    (1.0, ui.visuals().weak_text_color()),
+                    egui::StrokeKind::Inside,
+                );
+                for (_, coord) in airports.iter() {
+                    painter.circle_filled(
+                        world_to_screen(coord, minimap),
+                        1.5,
+                        egui::Color32::BLUE,
+                    );
+                }
+                for plane in &airplanes {
+                    painter.circle_filled(
+                        world_to_screen(&plane.location, minimap),
+                        2.0,
+                        egui::Color32::WHITE,
+                    );
+                }
+                let viewport = Rect::from_two_pos(
+                    world_to_screen(&screen_to_world(rect.min, transform), minimap),
+                    world_to_screen(&screen_to_world(rect.max, transform), minimap),
+                )
+                .intersect(minimap_rect);
+                painter.rect_stroke(
+                    viewport,
+                    CornerRadius::same(0),
+                    (1.0, egui::Color32::YELLOW),
+                    egui::StrokeKind::Inside,
+                );
+                let minimap_response =
+                    ui.interact(minimap_rect, Id::new("minimap"), Sense::click_and_drag());
+                if minimap_response.clicked() || minimap_response.dragged() {
+                    if let Some(pos) = minimap_response.interact_pointer_pos() {
+                        self.follow_plane = None;
+                        self.map_focus = Some(screen_to_world(pos, minimap));
+                    }
+                }
             });
 
             if self.overlap_menu_open {
@@ -947,13 +1412,12 @@ impl RustyRunwaysGui {
 
         // Airport window with information
 
-        if let Some(idx) = self.selected_airport {
+        let selected = self.selected_airport.and_then(|id| {
+            let g = self.game.as_ref()?;
+            g.map.airports.get(id).map(|(a, c)| (a.clone(), *c))
+        });
+        if let Some((airport_clone, coord)) = selected {
             if self.airport_panel {
-                let (airport_clone, coord) = {
-                    let g = self.game.as_ref().unwrap();
-                    let (a, c) = &g.map.airports[idx];
-                    (a.clone(), *c)
-                };
                 let planes_here: Vec<usize> = {
                     let g = self.game.as_ref().unwrap();
                     g.planes()
@@ -962,23 +1426,221 @@ impl RustyRunwaysGui {
                         .map(|p| p.id)
                         .collect()
                 };
-                Window::new(format!("Airport: {}", airport_clone.name))
-                    .open(&mut self.airport_panel)
-                    .collapsible(false)
-                    .min_width(600.0)
-                    .min_height(360.0)
-                    .default_size(Vec2::new(720.0, 520.0))
-                    .resizable(true)
-                    .show(ctx, |ui| {
+                let local_time = self
+                    .game
+                    .as_ref()
+                    .unwrap()
+                    .local_time(airport_clone.id)
+                    .map(|t| t.to_string())
+                    .unwrap_or_default();
+                show_detachable(
+                    ctx,
+                    Id::new(("airport_window", airport_clone.id)),
+                    &format!("Airport: {}", airport_clone.name),
+                    Vec2::new(720.0, 520.0),
+                    &mut self.airport_panel,
+                    &mut self.airport_detached,
+                    |ui| {
                         ui.label(format!("ID: {}", airport_clone.id));
+                        ui.label(format!("Class: {}", airport_clone.class()));
                         ui.label(format!("Location: ({:.1}, {:.1})", coord.x, coord.y));
+                        ui.label(format!("Local time: {}", local_time));
                         ui.label(format!("Runway: {:.0}m", airport_clone.runway_length));
+                        ui.label(format!(
+                            "Population: {} ({} parking stands)",
+                            airport_clone.population,
+                            airport_clone.parking_stands()
+                        ));
                         ui.label(format!("Fuel price: ${:.2}/L", airport_clone.fuel_price));
+                        if airport_clone.fuel_capacity.is_some() {
+                            ui.label(format!("Fuel stock: {:.0}L", airport_clone.fuel_stock));
+                        }
+                        ui.label(if airport_clone.has_hangar {
+                            "Maintenance hangar: yes"
+                        } else {
+                            "Maintenance hangar: no (mobile mechanic only)"
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Spare-part kits: {}", airport_clone.spare_parts));
+                            if airport_clone.has_hangar && ui.button("Buy kit").clicked() {
+                                match self
+                                    .game
+                                    .as_mut()
+                                    .unwrap()
+                                    .buy_spare_parts(airport_clone.id, 1)
+                                {
+                                    Ok(()) => self.log.push(format!(
+                                        "Bought a spare-part kit at {}",
+                                        airport_clone.name
+                                    )),
+                                    Err(e) => self.log.push(format!("Purchase failed: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                            if ui.button("Hire pilot").clicked() {
+                                match self.game.as_mut().unwrap().hire_pilot(airport_clone.id) {
+                                    Ok(pilot) => self.log.push(format!(
+                                        "Hired pilot {} at {}",
+                                        pilot, airport_clone.name
+                                    )),
+                                    Err(e) => self.log.push(format!("Hiring failed: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Ground crew: {}", airport_clone.ground_crew));
+                            if ui.button("Hire crew").clicked() {
+                                match self.game.as_mut().unwrap().hire_crew(airport_clone.id, 1) {
+                                    Ok(crew) => self.log.push(format!(
+                                        "{} now has {} ground crew",
+                                        airport_clone.name, crew
+                                    )),
+                                    Err(e) => self.log.push(format!("Hiring failed: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                            if airport_clone.ground_crew > 0 && ui.button("Let crew go").clicked() {
+                                match self.game.as_mut().unwrap().fire_crew(airport_clone.id, 1) {
+                                    Ok(crew) => self.log.push(format!(
+                                        "{} now has {} ground crew",
+                                        airport_clone.name, crew
+                                    )),
+                                    Err(e) => self.log.push(format!("Cannot let crew go: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(if airport_clone.has_charger {
+                                "Charger: installed"
+                            } else {
+                                "Charger: none"
+                            });
+                            if !airport_clone.has_charger && ui.button("Install charger").clicked()
+                            {
+                                match self
+                                    .game
+                                    .as_mut()
+                                    .unwrap()
+                                    .install_charger(airport_clone.id)
+                                {
+                                    Ok(()) => self.log.push(format!(
+                                        "Installed a charger at {}",
+                                        airport_clone.name
+                                    )),
+                                    Err(e) => self.log.push(format!("Installation failed: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                        });
                         ui.label(format!("Parking fee: ${:.2}/hr", airport_clone.parking_fee));
                         ui.label(format!(
                             "Landing fee: ${:.2}/ton",
                             airport_clone.landing_fee
                         ));
+                        let loyalty = {
+                            let g = self.game.as_ref().unwrap();
+                            let operations = g.airport_operations(airport_clone.id);
+                            match g.loyalty_tier(airport_clone.id) {
+                                Some(tier) => format!(
+                                    "Loyalty: {} ({} operations, {:.0}% off fees)",
+                                    tier.name,
+                                    operations,
+                                    tier.discount * 100.0
+                                ),
+                                None => format!("Loyalty: none ({} operations)", operations),
+                            }
+                        };
+                        ui.label(loyalty);
+                        ui.separator();
+                        ui.heading("Scheduled Routes");
+                        let destinations: Vec<(usize, String)> = {
+                            let g = self.game.as_ref().unwrap();
+                            g.airports()
+                                .iter()
+                                .filter(|(a, _)| a.id != airport_clone.id)
+                                .map(|(a, _)| (a.id, a.name.clone()))
+                                .collect()
+                        };
+                        let selected_text = self
+                            .fare_destination
+                            .map_or("Select", |id| airport_name(&self.game, id));
+                        let previous = self.fare_destination;
+                        egui::ComboBox::from_label("Destination")
+                            .selected_text(selected_text)
+                            .show_ui(ui, |ui| {
+                                for (id, name) in &destinations {
+                                    ui.selectable_value(
+                                        &mut self.fare_destination,
+                                        Some(*id),
+                                        name,
+                                    );
+                                }
+                            });
+                        if let Some(dest) = self.fare_destination {
+                            let g = self.game.as_ref().unwrap();
+                            let reference = g.reference_fare(airport_clone.id, dest).unwrap_or(0.0);
+                            let current = g.route_fare(airport_clone.id, dest).cloned();
+                            if previous != self.fare_destination {
+                                self.fare_input =
+                                    current.as_ref().map_or(reference.round(), |r| r.fare);
+                            }
+                            ui.label(format!(
+                                "Demand today: {} passengers | reference fare: ${:.2}",
+                                g.route_demand(airport_clone.id, dest).unwrap_or(0),
+                                reference
+                            ));
+                            if let Some(route) = &current {
+                                ui.label(format!(
+                                    "Fare: ${:.2} | tickets left today: {} | departures: {} | last load: {:.0}% | revenue: ${:.2}",
+                                    route.fare,
+                                    g.route_tickets_left(airport_clone.id, dest).unwrap_or(0),
+                                    route.departures,
+                                    route.last_load_factor * 100.0,
+                                    route.revenue
+                                ));
+                            }
+                            ui.horizontal(|ui| {
+                                ui.label("Ticket price:");
+                                ui.add(
+                                    egui::DragValue::new(&mut self.fare_input)
+                                        .speed(1.0)
+                                        .range(1.0..=f32::MAX)
+                                        .prefix("$"),
+                                );
+                                if ui.button("Set fare").clicked() {
+                                    let result = self.game.as_mut().unwrap().set_route_fare(
+                                        airport_clone.id,
+                                        dest,
+                                        self.fare_input,
+                                    );
+                                    match result {
+                                        Ok(()) => self.log.push(format!(
+                                            "Tickets from {} to {} now cost ${:.2}",
+                                            airport_clone.name,
+                                            airport_name(&self.game, dest),
+                                            self.fare_input
+                                        )),
+                                        Err(e) => {
+                                            self.log.push(format!("Setting fare failed: {}", e))
+                                        }
+                                    }
+                                    self.scroll_log = true;
+                                }
+                                if current.is_some() && ui.button("Stop selling").clicked() {
+                                    if let Err(e) = self
+                                        .game
+                                        .as_mut()
+                                        .unwrap()
+                                        .clear_route_fare(airport_clone.id, dest)
+                                    {
+                                        self.log.push(format!("Clearing fare failed: {}", e));
+                                        self.scroll_log = true;
+                                    }
+                                }
+                            });
+                        }
                         ui.separator();
                         ui.heading("Outstanding Orders");
                         ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
@@ -989,10 +1651,8 @@ impl RustyRunwaysGui {
                                 for order in &airport_clone.orders {
                                     ui.group(|group_ui| {
                                         group_ui.set_width(full_width);
-                                        let dest_name = &self.game.as_ref().unwrap().map.airports
-                                            [order.destination_id]
-                                            .0
-                                            .name;
+                                        let dest_name =
+                                            airport_name(&self.game, order.destination_id);
                                         let (payload_label, detail_label) = match &order.payload {
                                             OrderPayload::Cargo { cargo_type, weight } => (
                                                 format!("{:?}", cargo_type),
@@ -1003,7 +1663,10 @@ impl RustyRunwaysGui {
                                             }
                                         };
                                         group_ui.horizontal(|ui| {
-                                            ui.strong(format!("[{}] {}", order.id, payload_label));
+                                            ui.strong(format!(
+                                                "[{}] {} {}",
+                                                order.id, order.tier, payload_label
+                                            ));
                                             ui.separator();
                                             ui.label("Dest:");
                                             ui.label(dest_name);
@@ -1029,10 +1692,7 @@ impl RustyRunwaysGui {
                             // Single-select with detailed labels
                             let selected_text = if let Some(sel) = self.airport_order_selection {
                                 if let Some(o) = airport_clone.orders.iter().find(|o| o.id == sel) {
-                                    let dest_name = &self.game.as_ref().unwrap().map.airports
-                                        [o.destination_id]
-                                        .0
-                                        .name;
+                                    let dest_name = airport_name(&self.game, o.destination_id);
                                     let (payload_label, detail_label) = match &o.payload {
                                         OrderPayload::Cargo { cargo_type, weight } => (
                                             format!("{:?}", cargo_type),
@@ -1043,8 +1703,9 @@ impl RustyRunwaysGui {
                                         }
                                     };
                                     format!(
-                                        "[{}] {} | {} | dest {} | dl {} | ${:.2}",
+                                        "[{}] {} {} | {} | dest {} | dl {} | ${:.2}",
                                         o.id,
+                                        o.tier,
                                         payload_label,
                                         detail_label,
                                         dest_name,
@@ -1061,10 +1722,7 @@ impl RustyRunwaysGui {
                                 .selected_text(selected_text)
                                 .show_ui(ui, |ui| {
                                     for o in &airport_clone.orders {
-                                        let dest_name = &self.game.as_ref().unwrap().map.airports
-                                            [o.destination_id]
-                                            .0
-                                            .name;
+                                        let dest_name = airport_name(&self.game, o.destination_id);
                                         let (payload_label, detail_label) = match &o.payload {
                                             OrderPayload::Cargo { cargo_type, weight } => (
                                                 format!("{:?}", cargo_type),
@@ -1075,8 +1733,9 @@ impl RustyRunwaysGui {
                                             }
                                         };
                                         let label = format!(
-                                            "[{}] {} | {} | dest {} | dl {} | ${:.2}",
+                                            "[{}] {} {} | {} | dest {} | dl {} | ${:.2}",
                                             o.id,
+                                            o.tier,
                                             payload_label,
                                             detail_label,
                                             dest_name,
@@ -1177,7 +1836,8 @@ impl RustyRunwaysGui {
                                 }
                             });
                         }
-                    });
+                    },
+                );
             }
         }
 
@@ -1210,26 +1870,106 @@ impl RustyRunwaysGui {
                     };
 
                     let mut sold_plane = false;
-                    let _plane_window = Window::new(format!("Plane {}", pid))
-                        .open(&mut self.plane_panel)
-                        .collapsible(false)
-                        .default_size(Vec2::new(440.0, 520.0))
-                        .resizable(true)
-                        .show(ctx, |ui| {
+                    show_detachable(
+                        ctx,
+                        Id::new(("plane_window", pid)),
+                        &format!("Plane {} ({})", pid, plane_clone.name),
+                        Vec2::new(440.0, 520.0),
+                        &mut self.plane_panel,
+                        &mut self.plane_detached,
+                        |ui| {
                             ui.label(format!("Model: {:?}", plane_clone.model));
+                            let pilot = self
+                                .game
+                                .as_ref()
+                                .unwrap()
+                                .player
+                                .pilots
+                                .iter()
+                                .find(|p| p.plane == Some(pid))
+                                .map(|p| format!("{} (duty {}h)", p.name, p.duty_hours));
                             ui.label(format!(
-                                "Fuel: {:.0}/{:.0}L",
-                                plane_clone.current_fuel, plane_clone.specs.fuel_capacity
+                                "Pilot: {}",
+                                pilot.unwrap_or_else(|| "none".to_string())
                             ));
+                            ui.horizontal(|ui| {
+                                ui.label("Name:");
+                                ui.text_edit_singleline(&mut self.rename_input);
+                                if ui.button("Rename").clicked() {
+                                    match self
+                                        .game
+                                        .as_mut()
+                                        .unwrap()
+                                        .rename_plane(pid, &self.rename_input)
+                                    {
+                                        Ok(()) => self.log.push(format!(
+                                            "Plane {} renamed to {}",
+                                            pid,
+                                            self.rename_input.trim()
+                                        )),
+                                        Err(e) => self.log.push(format!("Rename failed: {}", e)),
+                                    }
+                                    self.rename_input.clear();
+                                    self.scroll_log = true;
+                                }
+                            });
+                            let plane_groups = self.game.as_ref().unwrap().player.groups_of(pid);
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label("Groups:");
+                                for name in &plane_groups {
+                                    if ui.small_button(format!("{} ✕", name)).clicked() {
+                                        if let Err(e) =
+                                            self.game.as_mut().unwrap().ungroup_plane(pid, name)
+                                        {
+                                            self.log.push(format!("Ungrouping failed: {}", e));
+                                            self.scroll_log = true;
+                                        }
+                                    }
+                                }
+                                ui.text_edit_singleline(&mut self.group_input);
+                                if ui.button("Add to group").clicked() {
+                                    match self
+                                        .game
+                                        .as_mut()
+                                        .unwrap()
+                                        .group_plane(pid, self.group_input.trim())
+                                    {
+                                        Ok(()) => self.log.push(format!(
+                                            "Plane {} added to group {}",
+                                            pid,
+                                            self.group_input.trim().to_lowercase()
+                                        )),
+                                        Err(e) => self.log.push(format!("Grouping failed: {}", e)),
+                                    }
+                                    self.group_input.clear();
+                                    self.scroll_log = true;
+                                }
+                            });
+                            if plane_clone.specs.is_electric() {
+                                ui.label(format!(
+                                    "Battery: {:.0}/{:.0}kWh",
+                                    plane_clone.current_fuel, plane_clone.specs.fuel_capacity
+                                ));
+                            } else {
+                                ui.label(format!(
+                                    "Fuel: {:.0}/{:.0}L",
+                                    plane_clone.current_fuel, plane_clone.specs.fuel_capacity
+                                ));
+                            }
                             ui.label(format!(
                                 "Payload: {:.0}/{:.0}kg",
                                 plane_clone.current_payload, plane_clone.specs.payload_capacity
                             ));
                             ui.label(format!(
                                 "Passengers: {}/{}",
-                                plane_clone.current_passengers,
+                                plane_clone.seated_passengers(),
                                 plane_clone.specs.passenger_capacity
                             ));
+                            ui.label(format!(
+                                "Takeoff weight: {:.0}/{:.0}kg",
+                                plane_clone.takeoff_weight(),
+                                plane_clone.specs.mtow
+                            ));
                             ui.separator();
                             ui.heading("Manifest");
                             ScrollArea::vertical()
@@ -1252,8 +1992,9 @@ impl RustyRunwaysGui {
                                                 ),
                                             };
                                             ui.label(format!(
-                                                "[{}] {} {} val ${:.2} dl {}",
+                                                "[{}] {} {} {} val ${:.2} dl {}",
                                                 order.id,
+                                                order.tier,
                                                 payload_label,
                                                 detail_label,
                                                 order.value,
@@ -1289,6 +2030,37 @@ impl RustyRunwaysGui {
                                     }
                                     self.scroll_log = true;
                                 }
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.refuel_input)
+                                        .hint_text("500 or 80%")
+                                        .desired_width(70.0),
+                                );
+                                if ui.button("Refuel amount").clicked() {
+                                    let result = match self.refuel_input.parse::<FuelAmount>() {
+                                        Ok(amount) => self
+                                            .game
+                                            .as_mut()
+                                            .unwrap()
+                                            .refuel_plane_with(pid, amount)
+                                            .map_err(|e| e.to_string()),
+                                        Err(msg) => Err(msg),
+                                    };
+                                    match result {
+                                        Ok(_) => self.log.push(format!("Plane {} refueling", pid)),
+                                        Err(e) => self.log.push(format!("Refuel failed: {}", e)),
+                                    }
+                                    self.scroll_log = true;
+                                }
+                                if plane_clone.specs.is_electric() && ui.button("Charge").clicked()
+                                {
+                                    match self.game.as_mut().unwrap().charge_plane(pid) {
+                                        Ok(hours) => self
+                                            .log
+                                            .push(format!("Plane {} charging for {}h", pid, hours)),
+                                        Err(e) => self.log.push(format!("Charging failed: {}", e)),
+                                    }
+                                    self.scroll_log = true;
+                                }
                                 if ui.button("Unload All").clicked() {
                                     match self.game.as_mut().unwrap().unload_all(pid) {
                                         Ok(_) => self.log.push(format!("Plane {} unloading", pid)),
@@ -1307,7 +2079,27 @@ impl RustyRunwaysGui {
                                     }
                                     self.scroll_log = true;
                                 }
-                                if ui.button("Sell Plane").clicked() {
+                                for check in [CheckType::A, CheckType::B, CheckType::C] {
+                                    if ui.button(format!("{} check", check)).clicked() {
+                                        match self.game.as_mut().unwrap().perform_check(pid, check)
+                                        {
+                                            Ok(_) => self.log.push(format!(
+                                                "Plane {} in for its {} check",
+                                                pid, check
+                                            )),
+                                            Err(e) => self
+                                                .log
+                                                .push(format!("{} check failed: {}", check, e)),
+                                        }
+                                        self.scroll_log = true;
+                                    }
+                                }
+                                let resale = self
+                                    .game
+                                    .as_ref()
+                                    .and_then(|g| g.appraise_plane(pid).ok())
+                                    .unwrap_or(0.0);
+                                if ui.button(format!("Sell Plane (${:.0})", resale)).clicked() {
                                     match self.game.as_mut().unwrap().sell_plane(pid) {
                                         Ok(refund) => {
                                             self.log.push(format!(
@@ -1397,10 +2189,7 @@ impl RustyRunwaysGui {
                                 // single-select with detailed labels
                                 let selected_text = if let Some(sel) = self.plane_order_selection {
                                     if let Some(o) = filtered_orders.iter().find(|o| o.id == sel) {
-                                        let dest_name = &self.game.as_ref().unwrap().map.airports
-                                            [o.destination_id]
-                                            .0
-                                            .name;
+                                        let dest_name = airport_name(&self.game, o.destination_id);
                                         let (payload_label, detail_label) = match &o.payload {
                                             OrderPayload::Cargo { cargo_type, weight } => (
                                                 format!("{:?}", cargo_type),
@@ -1411,8 +2200,9 @@ impl RustyRunwaysGui {
                                             }
                                         };
                                         format!(
-                                            "[{}] {} | {} | dest {} | dl {} | ${:.2}",
+                                            "[{}] {} {} | {} | dest {} | dl {} | ${:.2}",
                                             o.id,
+                                            o.tier,
                                             payload_label,
                                             detail_label,
                                             dest_name,
@@ -1430,10 +2220,7 @@ impl RustyRunwaysGui {
                                     .show_ui(ui, |ui| {
                                         for o in &filtered_orders {
                                             let dest_name =
-                                                &self.game.as_ref().unwrap().map.airports
-                                                    [o.destination_id]
-                                                    .0
-                                                    .name;
+                                                airport_name(&self.game, o.destination_id);
                                             let (payload_label, detail_label) = match &o.payload {
                                                 OrderPayload::Cargo { cargo_type, weight } => (
                                                     format!("{:?}", cargo_type),
@@ -1445,8 +2232,9 @@ impl RustyRunwaysGui {
                                                 ),
                                             };
                                             let label = format!(
-                                                "[{}] {} | {} | dest {} | dl {} | ${:.2}",
+                                                "[{}] {} {} | {} | dest {} | dl {} | ${:.2}",
                                                 o.id,
+                                                o.tier,
                                                 payload_label,
                                                 detail_label,
                                                 dest_name,
@@ -1471,10 +2259,7 @@ impl RustyRunwaysGui {
                                             let mut checked =
                                                 self.plane_order_multi.contains(&o.id);
                                             let dest_name =
-                                                &self.game.as_ref().unwrap().map.airports
-                                                    [o.destination_id]
-                                                    .0
-                                                    .name;
+                                                airport_name(&self.game, o.destination_id);
                                             let (payload_label, detail_label) = match &o.payload {
                                                 OrderPayload::Cargo { cargo_type, weight } => (
                                                     format!("{:?}", cargo_type),
@@ -1486,8 +2271,9 @@ impl RustyRunwaysGui {
                                                 ),
                                             };
                                             let label = format!(
-                                                "[{}] {} | {} | dest {} | dl {} | ${:.2}",
+                                                "[{}] {} {} | {} | dest {} | dl {} | ${:.2}",
                                                 o.id,
+                                                o.tier,
                                                 payload_label,
                                                 detail_label,
                                                 dest_name,
@@ -1560,18 +2346,123 @@ impl RustyRunwaysGui {
                                         );
                                     }
                                 });
+                            egui::ComboBox::from_label("Speed")
+                                .selected_text(self.plane_speed.to_string())
+                                .show_ui(ui, |ui| {
+                                    for regime in [
+                                        CruiseRegime::Economy,
+                                        CruiseRegime::Normal,
+                                        CruiseRegime::Dash,
+                                    ] {
+                                        ui.selectable_value(
+                                            &mut self.plane_speed,
+                                            regime,
+                                            regime.to_string(),
+                                        );
+                                    }
+                                });
                             if ui.button("Depart").clicked() {
                                 if let Some(dest) = self.plane_destination {
-                                    match self.game.as_mut().unwrap().depart_plane(pid, dest) {
+                                    match self.game.as_mut().unwrap().depart_plane_with_speed(
+                                        pid,
+                                        dest,
+                                        self.plane_speed,
+                                    ) {
+                                        Ok(_) => self.log.push(format!(
+                                            "Plane {} departing to {} ({})",
+                                            pid, dest, self.plane_speed
+                                        )),
+                                        Err(e) => self.log.push(format!("Depart failed: {}", e)),
+                                    }
+                                    self.scroll_log = true;
+                                }
+                            }
+                            let in_transit =
+                                matches!(plane_clone.status, AirplaneStatus::InTransit { .. });
+                            let mut following = self.follow_plane == Some(pid);
+                            if ui
+                                .add_enabled(
+                                    in_transit || following,
+                                    egui::Checkbox::new(&mut following, "Follow on map"),
+                                )
+                                .changed()
+                            {
+                                self.follow_plane = following.then_some(pid);
+                            }
+                            if ui
+                                .add_enabled(in_transit, egui::Button::new("Reroute"))
+                                .clicked()
+                            {
+                                if let Some(dest) = self.plane_destination {
+                                    match self.game.as_mut().unwrap().reroute_plane(pid, dest) {
                                         Ok(_) => self
                                             .log
-                                            .push(format!("Plane {} departing to {}", pid, dest)),
-                                        Err(e) => self.log.push(format!("Depart failed: {}", e)),
+                                            .push(format!("Plane {} rerouted to {}", pid, dest)),
+                                        Err(e) => self.log.push(format!("Reroute failed: {}", e)),
                                     }
                                     self.scroll_log = true;
                                 }
                             }
-                        });
+                            if ui
+                                .add_enabled(in_transit, egui::Button::new("Emergency land"))
+                                .clicked()
+                            {
+                                match self.game.as_mut().unwrap().emergency_land(pid) {
+                                    Ok(airport) => self.log.push(format!(
+                                        "Plane {} making an emergency landing at {}",
+                                        pid, airport
+                                    )),
+                                    Err(e) => {
+                                        self.log.push(format!("Emergency landing failed: {}", e))
+                                    }
+                                }
+                                self.scroll_log = true;
+                            }
+                            if ui
+                                .add_enabled(
+                                    plane_clone.awaiting_repair,
+                                    egui::Button::new("Repair"),
+                                )
+                                .clicked()
+                            {
+                                match self.game.as_mut().unwrap().repair_plane(pid) {
+                                    Ok(()) => {
+                                        self.log.push(format!("Plane {} is in for repairs", pid))
+                                    }
+                                    Err(e) => self.log.push(format!("Repair failed: {}", e)),
+                                }
+                                self.scroll_log = true;
+                            }
+                            let hold_label = if plane_clone.on_hold {
+                                "Release"
+                            } else {
+                                "Hold"
+                            };
+                            if ui.button(hold_label).clicked() {
+                                let game = self.game.as_mut().unwrap();
+                                let result = if plane_clone.on_hold {
+                                    game.release_plane(pid)
+                                } else {
+                                    game.hold_plane(pid)
+                                };
+                                match result {
+                                    Ok(()) => self.log.push(format!(
+                                        "Plane {} {}",
+                                        pid,
+                                        if plane_clone.on_hold {
+                                            "released"
+                                        } else {
+                                            "held on the ground"
+                                        }
+                                    )),
+                                    Err(e) => {
+                                        self.log.push(format!("{} failed: {}", hold_label, e))
+                                    }
+                                }
+                                self.scroll_log = true;
+                            }
+                        },
+                    );
                     if sold_plane {
                         self.selected_airplane = None;
                         self.plane_panel = false;
@@ -1583,8 +2474,8 @@ impl RustyRunwaysGui {
 
     fn handle_click_item(&mut self, item: ClickItem) {
         match item {
-            ClickItem::Airport(idx) => {
-                self.selected_airport = Some(idx);
+            ClickItem::Airport(id) => {
+                self.selected_airport = Some(id);
                 self.airport_panel = true;
             }
             ClickItem::Plane(id) => {
@@ -1598,6 +2489,9 @@ impl RustyRunwaysGui {
 #[cfg(test)]
 mod tests {
     use super::{ClickItem, RustyRunwaysGui, Screen};
+    use crate::transforms::{camera_transform, screen_to_world, world_to_screen};
+    use eframe::egui::{Pos2, Rect};
+    use rusty_runways_core::utils::coordinate::Coordinate;
 
     #[test]
     fn handle_click_item_airport() {
@@ -1633,4 +2527,24 @@ mod tests {
         assert_eq!(gui.airports_str, "12");
         assert_eq!(gui.cash_str, "650000");
     }
+
+    #[test]
+    fn camera_centers_the_focus() {
+        let rect = Rect::from_min_max(Pos2::new(0.0, 0.0), Pos2::new(400.0, 200.0));
+        let focus = Coordinate::new(1_500.0, 700.0);
+        let transform = camera_transform((0.1, 5.0, 5.0), rect, 4.0, &focus);
+        assert_eq!(transform.0, 0.4);
+        assert_eq!(world_to_screen(&focus, transform), rect.center());
+
+        let back = screen_to_world(Pos2::new(120.0, 30.0), transform);
+        assert!((world_to_screen(&back, transform) - Pos2::new(120.0, 30.0)).length() < 1e-3);
+    }
+
+    #[test]
+    fn windows_start_docked() {
+        let gui = RustyRunwaysGui::default();
+        assert!(!gui.airport_detached);
+        assert!(!gui.plane_detached);
+        assert!(!gui.report_detached);
+    }
 }
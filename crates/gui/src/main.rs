@@ -1,7 +1,12 @@
 use eframe::NativeOptions;
+use rusty_runways_core::i18n;
 use rusty_runways_gui::gui::RustyRunwaysGui;
 
 fn main() {
+    if let Err(e) = i18n::init_from_env() {
+        eprintln!("{}", e);
+    }
+
     let options = NativeOptions::default();
 
     eframe::run_native(
@@ -14,14 +14,14 @@ pub fn map_transforms(
     // world bounds
     let (min_x, max_x) = airports
         .iter()
-        .map(|(_, c)| c.x)
+        .map(|(_, c)| c.projected().x)
         .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
             (min.min(x), max.max(x))
         });
 
     let (min_y, max_y) = airports
         .iter()
-        .map(|(_, c)| c.y)
+        .map(|(_, c)| c.projected().y)
         .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), y| {
             (min.min(y), max.max(y))
         });
@@ -46,12 +46,39 @@ pub fn map_transforms(
 }
 
 /// maps world coordinates to position on the screen
+/// (geographic coordinates are projected to kilometers first)
 pub fn world_to_screen(
     coord: &Coordinate,
     (scale, offset_x, offset_y): (f32, f32, f32),
 ) -> egui::Pos2 {
+    let coord = coord.projected();
     egui::Pos2 {
         x: offset_x + coord.x * scale,
         y: offset_y - coord.y * scale,
     }
 }
+
+/// maps a position on the screen back to projected world coordinates
+pub fn screen_to_world(
+    pos: egui::Pos2,
+    (scale, offset_x, offset_y): (f32, f32, f32),
+) -> Coordinate {
+    Coordinate::new((pos.x - offset_x) / scale, (offset_y - pos.y) / scale)
+}
+
+/// Zoom a fitted transform in by `zoom` and move it so the projected point `focus`
+/// sits at the center of `target`.
+pub fn camera_transform(
+    (scale, _, _): (f32, f32, f32),
+    target: egui::Rect,
+    zoom: f32,
+    focus: &Coordinate,
+) -> (f32, f32, f32) {
+    let scale = scale * zoom;
+    let center = target.center();
+    (
+        scale,
+        center.x - focus.x * scale,
+        center.y + focus.y * scale,
+    )
+}
@@ -1,4 +1,4 @@
-from rusty_runways_py import GameEnv, VectorGameEnv
+from rusty_runways_py import ActionSpace, GameEnv, VectorGameEnv, generate_world
 
 # Optional Gym wrappers: load lazily and give a clear error if Gymnasium is missing.
 try:
@@ -24,6 +24,8 @@ __all__ = [
     "RustyRunwaysGymEnv",
     "make_sb3_envs",
     "RustyRunwaysGymVectorEnv",
+    "ActionSpace",
     "GameEnv",
     "VectorGameEnv",
+    "generate_world",
 ]
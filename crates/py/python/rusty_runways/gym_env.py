@@ -132,6 +132,8 @@ class RustyRunwaysGymEnv(gym.Env):
         Episode truncation horizon in hours.
     reward_fn : Callable[[dict, dict], float] | None
         Optional custom reward: takes (state, prev_state) dicts from state_json.
+    render_mode : str | None
+        ``"ansi"`` makes ``render()`` return a text drawing of the map.
 
     Observation
     -----------
@@ -143,7 +145,7 @@ class RustyRunwaysGymEnv(gym.Env):
     0 ADVANCE, 1 REFUEL, 2 UNLOAD_ALL, 3 MAINTENANCE, 4 DEPART_BY_INDEX, 5 LOAD_ORDER.
     """
 
-    metadata = {"render.modes": []}
+    metadata = {"render_modes": ["ansi"]}
 
     def __init__(
         self,
@@ -153,8 +155,10 @@ class RustyRunwaysGymEnv(gym.Env):
         config_path: Optional[str] = None,
         max_hours: int = 1000,
         reward_fn: Optional[Callable[[dict, dict], float]] = None,
+        render_mode: Optional[str] = None,
     ) -> None:
         super().__init__()
+        self.render_mode = render_mode
         self._params = dict(
             seed=seed,
             num_airports=num_airports,
@@ -189,7 +193,7 @@ class RustyRunwaysGymEnv(gym.Env):
             [self.N_OPS, self.MAX_PLANES, self.MAX_SELECT, self.MAX_AIRPORTS]
         )
 
-        self._last_cash = float(self._state_cache.get("cash", 0.0))
+        self._last_value = float(self._state_cache.get("company_value", 0.0))
 
     # ----------- Gym API -----------
     def reset(
@@ -230,7 +234,7 @@ class RustyRunwaysGymEnv(gym.Env):
         self._env.reset(**params)  # type: ignore[arg-type]
         self._elapsed = 0
         obs = self._observe()
-        self._last_cash = float(self._state_cache.get("cash", 0.0))
+        self._last_value = float(self._state_cache.get("company_value", 0.0))
         return obs, {}
 
     def step(self, action) -> Tuple[np.ndarray, float, bool, bool, dict]:
@@ -246,7 +250,7 @@ class RustyRunwaysGymEnv(gym.Env):
         obs : np.ndarray
             Next observation.
         reward : float
-            Delta cash (or custom reward) for this transition.
+            Change in company value (or custom reward) for this transition.
         terminated : bool
             Always False (no terminal condition yet).
         truncated : bool
@@ -264,8 +268,9 @@ class RustyRunwaysGymEnv(gym.Env):
         else:
             op, plane, sel, dest_idx = int(arr.flat[0]), 0, 0, 0
 
+        state = None
         if op == 0:
-            self._env.step(1)
+            state = self._env.step(1)
             self._elapsed += 1
         elif op == 1:
             # Refuel plane 0
@@ -273,21 +278,21 @@ class RustyRunwaysGymEnv(gym.Env):
                 self._env.execute(f"REFUEL PLANE {plane}")
             except Exception:
                 pass
-            self._env.step(1)
+            state = self._env.step(1)
             self._elapsed += 1
         elif op == 2:
             try:
                 self._env.execute(f"UNLOAD ALL FROM {plane}")
             except Exception:
                 pass
-            self._env.step(1)
+            state = self._env.step(1)
             self._elapsed += 1
         elif op == 3:
             try:
                 self._env.execute(f"MAINTENANCE {plane}")
             except Exception:
                 pass
-            self._env.step(1)
+            state = self._env.step(1)
             self._elapsed += 1
         elif op == 4:
             # depart to selected airport id if available
@@ -305,7 +310,7 @@ class RustyRunwaysGymEnv(gym.Env):
                 except Exception:
                     pass
                 # let time tick for departure handling
-                self._env.step(1)
+                state = self._env.step(1)
                 self._elapsed += 1
         elif op == 5:
             # LOAD ORDER based on selector index
@@ -316,31 +321,32 @@ class RustyRunwaysGymEnv(gym.Env):
                     self._env.execute(f"LOAD ORDER {order_id} ON {plane}")
             except Exception:
                 pass
-            self._env.step(1)
+            state = self._env.step(1)
             self._elapsed += 1
 
-        obs = self._observe()
-        cash = float(self._state_cache.get("cash", 0.0))
+        obs = self._observe(state)
+        value = float(self._state_cache.get("company_value", 0.0))
         if self._reward_fn is not None:
             reward = float(self._reward_fn(self._state_cache, self._prev_state_cache))
         else:
-            reward = float(cash - self._last_cash)
-        self._last_cash = cash
+            reward = float(value - self._last_value)
+        self._last_value = value
 
         terminated = False
         truncated = self._elapsed >= self.max_hours
         info = {}
         return obs, reward, terminated, truncated, info
 
-    def render(self) -> None:
-        """No-op renderer (use the GUI binary for visualization)."""
+    def render(self) -> Optional[str]:
+        """Text map of the world in ``"ansi"`` mode; otherwise a no-op (use the GUI binary)."""
+        if self.render_mode == "ansi":
+            return self._env.render()
         return None
 
     # ----------- Helpers -----------
-    def _observe(self) -> np.ndarray:
-        """Compute observation vector from current state_json."""
-        state_json = self._env.state_json()
-        s = json.loads(state_json)
+    def _observe(self, state: Optional[dict] = None) -> np.ndarray:
+        """Compute observation vector from `state`, or the current state_json if not given."""
+        s = state if state is not None else json.loads(self._env.state_json())
         self._prev_state_cache = getattr(self, "_state_cache", s)
         self._state_cache = s
         return _build_obs_from_state(s)
@@ -454,9 +460,11 @@ class RustyRunwaysGymVectorEnv(gym.vector.VectorEnv):
         self._venv = VectorGameEnv(self.n_envs, **self._params)  # type: ignore[arg-type]
         self._elapsed = np.zeros((self.n_envs,), dtype=np.int64)
         self._max_hours = int(max_hours)
-        # last cash per env for reward shaping
+        # last company value per env for reward shaping
         states = [json.loads(s) for s in self._venv.state_all_json()]
-        self._last_cash = np.array([float(s.get("cash", 0.0)) for s in states], dtype=np.float32)
+        self._last_value = np.array(
+            [float(s.get("company_value", 0.0)) for s in states], dtype=np.float32
+        )
         self._last_states = states
         self._actions = None
 
@@ -470,7 +478,9 @@ class RustyRunwaysGymVectorEnv(gym.vector.VectorEnv):
         self._elapsed[:] = 0
         states = [json.loads(s) for s in self._venv.state_all_json()]
         self._last_states = states
-        self._last_cash = np.array([float(s.get("cash", 0.0)) for s in states], dtype=np.float32)
+        self._last_value = np.array(
+            [float(s.get("company_value", 0.0)) for s in states], dtype=np.float32
+        )
         obs = np.stack([self._obs_from(s) for s in states], axis=0)
         return obs, {}
 
@@ -535,9 +545,9 @@ class RustyRunwaysGymVectorEnv(gym.vector.VectorEnv):
         # Get observations and rewards
         states = [json.loads(s) for s in self._venv.state_all_json()]
         obs = np.stack([self._obs_from(s) for s in states], axis=0)
-        cash = np.array([float(s.get("cash", 0.0)) for s in states], dtype=np.float32)
-        rewards = (cash - self._last_cash).astype(np.float32)
-        self._last_cash = cash
+        value = np.array([float(s.get("company_value", 0.0)) for s in states], dtype=np.float32)
+        rewards = (value - self._last_value).astype(np.float32)
+        self._last_value = value
         self._last_states = states
 
         terminated = np.zeros((self.n_envs,), dtype=bool)
@@ -1,12 +1,39 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use rayon::prelude::*;
 use rusty_runways_core::Game;
+use rusty_runways_core::actions::{Action, ActionSpace};
 use rusty_runways_core::config::WorldConfig;
+use rusty_runways_core::encoding::EncodingError;
+use rusty_runways_core::events::EventFilter;
+use rusty_runways_core::generator::{Difficulty, WorldGenerator};
+
+/// Parse a world config given as YAML text, or as the path of a YAML file if it is a
+/// single line.
+fn load_world_config(path_or_yaml: &str) -> PyResult<WorldConfig> {
+    let text = if path_or_yaml.contains('\n') {
+        path_or_yaml.to_string()
+    } else {
+        std::fs::read_to_string(path_or_yaml)
+            .map_err(|e| PyValueError::new_err(format!("read {}: {}", path_or_yaml, e)))?
+    };
+    serde_yaml::from_str(&text).map_err(|e| PyValueError::new_err(format!("yaml: {}", e)))
+}
+
+fn msgpack_err(e: EncodingError) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+fn game_from_config(cfg: WorldConfig) -> PyResult<Game> {
+    Game::from_config(cfg).map_err(|e| PyValueError::new_err(e.to_string()))
+}
 
 #[pyclass]
 pub struct GameEnv {
     game: Game,
+    /// Python callables registered with `on_event`
+    callbacks: Vec<PyObject>,
 }
 
 #[pymethods]
@@ -21,15 +48,21 @@ impl GameEnv {
         config_path: Option<String>,
     ) -> PyResult<Self> {
         if let Some(path) = config_path {
-            let text = std::fs::read_to_string(&path)
-                .map_err(|e| PyValueError::new_err(format!("read {}: {}", path, e)))?;
-            let cfg: WorldConfig = serde_yaml::from_str(&text)
-                .map_err(|e| PyValueError::new_err(format!("yaml: {}", e)))?;
-            let game = Game::from_config(cfg).map_err(|e| PyValueError::new_err(e.to_string()))?;
-            return Ok(GameEnv { game });
+            return GameEnv::from_config(&path);
         }
         Ok(GameEnv {
             game: Game::new(seed.unwrap_or(0), num_airports, cash.unwrap_or(650_000.0)),
+            callbacks: Vec::new(),
+        })
+    }
+
+    /// Build a game from a world config: a YAML file path, or the YAML text itself.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path_or_yaml)")]
+    fn from_config(path_or_yaml: &str) -> PyResult<Self> {
+        Ok(GameEnv {
+            game: game_from_config(load_world_config(path_or_yaml)?)?,
+            callbacks: Vec::new(),
         })
     }
 
@@ -43,24 +76,58 @@ impl GameEnv {
         config_path: Option<String>,
     ) -> PyResult<()> {
         if let Some(path) = config_path {
-            let text = std::fs::read_to_string(&path)
-                .map_err(|e| PyValueError::new_err(format!("read {}: {}", path, e)))?;
-            let cfg: WorldConfig = serde_yaml::from_str(&text)
-                .map_err(|e| PyValueError::new_err(format!("yaml: {}", e)))?;
-            self.game = Game::from_config(cfg).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            self.game = game_from_config(load_world_config(&path)?)?;
             return Ok(());
         }
         self.game = Game::new(seed.unwrap_or(0), num_airports, cash.unwrap_or(650_000.0));
         Ok(())
     }
 
-    fn step(&mut self, hours: u64) {
-        self.game.advance(hours);
+    /// Advance `hours` and return the new observation, as `state_py()` would.
+    ///
+    /// The simulation runs without the GIL, so other Python threads keep going meanwhile.
+    fn step(slf: &Bound<'_, Self>, hours: u64) -> PyResult<PyObject> {
+        let py = slf.py();
+        let (events, callbacks) = {
+            let mut env = slf.borrow_mut();
+            let receiver =
+                (!env.callbacks.is_empty()).then(|| env.game.subscribe(EventFilter::all()));
+            let game = &mut env.game;
+            py.allow_threads(|| game.advance(hours));
+            let events: Vec<String> = match receiver {
+                Some(receiver) => receiver
+                    .try_iter()
+                    .map(|e| serde_json::to_string(&e))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?,
+                None => Vec::new(),
+            };
+            let callbacks: Vec<PyObject> =
+                env.callbacks.iter().map(|cb| cb.clone_ref(py)).collect();
+            // the borrow ends here so callbacks may inspect the env
+            (events, callbacks)
+        };
+
+        let json = py.import("json")?;
+        for event in events {
+            let event = json.call_method1("loads", (event,))?;
+            for callback in &callbacks {
+                callback.call1(py, (event.clone(),))?;
+            }
+        }
+        slf.borrow().state_py(py)
+    }
+
+    /// Call `callback(event: dict)` for every game event that happens during `step`.
+    #[pyo3(text_signature = "(callback)")]
+    fn on_event(&mut self, callback: PyObject) {
+        self.callbacks.push(callback);
     }
 
-    fn execute(&mut self, cmd: &str) -> PyResult<()> {
-        self.game
-            .execute_str(cmd)
+    /// Run a CLI command without holding the GIL.
+    fn execute(&mut self, py: Python, cmd: &str) -> PyResult<()> {
+        let game = &mut self.game;
+        py.allow_threads(|| game.execute_str(cmd))
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
@@ -71,6 +138,34 @@ impl GameEnv {
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    #[pyo3(text_signature = "(plane_id)")]
+    fn appraise_plane(&self, plane_id: usize) -> PyResult<f32> {
+        self.game
+            .appraise_plane(plane_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(text_signature = "(plane_id, name)")]
+    fn rename_plane(&mut self, plane_id: usize, name: &str) -> PyResult<()> {
+        self.game
+            .rename_plane(plane_id, name)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(text_signature = "(listing_id)")]
+    fn buy_used_plane(&mut self, listing_id: usize) -> PyResult<usize> {
+        self.game
+            .buy_used_plane(listing_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Text drawing of the map: airports, planes in flight and their legs.
+    #[pyo3(signature = (width=80, height=24))]
+    #[pyo3(text_signature = "(width=80, height=24)")]
+    fn render(&self, width: usize, height: usize) -> String {
+        self.game.render_ascii(width, height)
+    }
+
     fn state_json(&self) -> PyResult<String> {
         serde_json::to_string(&self.game.observe())
             .map_err(|e| PyValueError::new_err(e.to_string()))
@@ -83,6 +178,11 @@ impl GameEnv {
         json.call_method1("loads", (s,)).map(|o| o.into())
     }
 
+    /// Daily stats as a list of flat dicts, ready for `pandas.DataFrame(...)`.
+    fn stats_records(&self, py: Python) -> PyResult<PyObject> {
+        stats_records_py(py, &self.game)
+    }
+
     fn full_state_json(&self) -> PyResult<String> {
         serde_json::to_string(&self.game).map_err(|e| PyValueError::new_err(e.to_string()))
     }
@@ -93,6 +193,24 @@ impl GameEnv {
         Ok(())
     }
 
+    /// The observation as MessagePack bytes; smaller and faster to parse than
+    /// `state_json()` on large worlds.
+    fn state_msgpack<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = self.game.observation_msgpack().map_err(msgpack_err)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// The whole game state as MessagePack bytes, for `load_full_state_msgpack`.
+    fn full_state_msgpack<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = self.game.state_msgpack().map_err(msgpack_err)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    fn load_full_state_msgpack(&mut self, data: &[u8]) -> PyResult<()> {
+        self.game = Game::from_state_msgpack(data).map_err(msgpack_err)?;
+        Ok(())
+    }
+
     fn models_json(&self) -> PyResult<String> {
         #[derive(serde::Serialize)]
         struct ModelDto {
@@ -135,6 +253,31 @@ impl GameEnv {
         json.call_method1("loads", (s,)).map(|o| o.into())
     }
 
+    /// The state scaled for learning agents, with the scales used, as JSON; see
+    /// `Game::features`.
+    fn features_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.game.features())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn features_py(&self, py: Python) -> PyResult<PyObject> {
+        let s = self.features_json()?;
+        let json = py.import("json")?;
+        json.call_method1("loads", (s,)).map(|o| o.into())
+    }
+
+    /// Every command that would succeed now, as JSON; see `Game::enumerate_actions`.
+    fn actions_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.game.enumerate_actions())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn actions_py(&self, py: Python) -> PyResult<PyObject> {
+        let s = self.actions_json()?;
+        let json = py.import("json")?;
+        json.call_method1("loads", (s,)).map(|o| o.into())
+    }
+
     fn time(&self) -> u64 {
         self.game.time
     }
@@ -143,10 +286,34 @@ impl GameEnv {
         self.game.player.cash
     }
 
+    /// Company value (cash + fleet book value - debt + reputation bonus), the score of the run.
+    fn company_value(&self) -> f32 {
+        self.game.company_value()
+    }
+
+    /// Run summary (seed, world fingerprint, days, deliveries, value, commands) as JSON.
+    fn run_summary_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.game.run_summary())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn seed(&self) -> u64 {
         self.game.seed()
     }
 
+    /// Hash of the whole game state; equal for two runs that played out identically.
+    fn state_hash(&self) -> u64 {
+        self.game.state_hash()
+    }
+
+    /// Independent copy for look-ahead search; `on_event` callbacks are not copied.
+    fn fork(&self) -> GameEnv {
+        GameEnv {
+            game: self.game.fork(),
+            callbacks: Vec::new(),
+        }
+    }
+
     fn drain_log(&mut self) -> Vec<String> {
         self.game.drain_log()
     }
@@ -191,10 +358,32 @@ impl GameEnv {
     }
 }
 
+impl VectorGameEnv {
+    /// A fresh game with `seed`: the config world if there is one, else a generated one.
+    fn build(&self, seed: u64, num_airports: Option<usize>, cash: f32) -> PyResult<Game> {
+        match &self.config {
+            Some(config) => game_from_config(WorldConfig {
+                seed: Some(seed),
+                ..config.clone()
+            }),
+            None => Ok(Game::new(seed, num_airports, cash)),
+        }
+    }
+}
+
+fn stats_records_py(py: Python, game: &Game) -> PyResult<PyObject> {
+    let s = serde_json::to_string(&game.stats_records())
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let json = py.import("json")?;
+    json.call_method1("loads", (s,)).map(|o| o.into())
+}
+
 #[pyclass]
 pub struct VectorGameEnv {
     envs: Vec<Game>,
     seeds: Vec<u64>,
+    /// World the envs are built from by `from_config`, reseeded on reset
+    config: Option<WorldConfig>,
 }
 
 fn parse_arg<T: Clone + for<'a> FromPyObject<'a>>(
@@ -278,7 +467,37 @@ impl VectorGameEnv {
             envs.push(Game::new(s, num_airports, cash.unwrap_or(650_000.0)));
             seeds.push(s);
         }
-        VectorGameEnv { envs, seeds }
+        VectorGameEnv {
+            envs,
+            seeds,
+            config: None,
+        }
+    }
+
+    /// `n_envs` games of the world config at `path_or_yaml` (a YAML file path, or the
+    /// YAML text itself).
+    ///
+    /// With `seed`, env `i` uses `seed + i` instead of the config's seed, so the envs
+    /// see different orders and events; resets keep using the config.
+    #[staticmethod]
+    #[pyo3(signature = (n_envs, path_or_yaml, seed=None))]
+    #[pyo3(text_signature = "(n_envs, path_or_yaml, seed=None)")]
+    fn from_config(n_envs: usize, path_or_yaml: &str, seed: Option<u64>) -> PyResult<Self> {
+        let config = load_world_config(path_or_yaml)?;
+        let mut env = VectorGameEnv {
+            envs: Vec::with_capacity(n_envs),
+            seeds: Vec::with_capacity(n_envs),
+            config: Some(config.clone()),
+        };
+        for i in 0..n_envs {
+            let game = match seed {
+                Some(base) => env.build(base + i as u64, None, 0.0)?,
+                None => game_from_config(config.clone())?,
+            };
+            env.seeds.push(game.seed());
+            env.envs.push(game);
+        }
+        Ok(env)
     }
 
     fn env_count(&self) -> usize {
@@ -325,7 +544,7 @@ impl VectorGameEnv {
         let cashes = parse_arg(py, cash, n, vec![650_000.0; n])?;
         self.seeds = seeds.clone();
         for i in 0..n {
-            self.envs[i] = Game::new(seeds[i], airports[i], cashes[i]);
+            self.envs[i] = self.build(seeds[i], airports[i], cashes[i])?;
         }
         Ok(())
     }
@@ -336,11 +555,12 @@ impl VectorGameEnv {
         seed: Option<u64>,
         num_airports: Option<usize>,
         cash: Option<f32>,
-    ) {
+    ) -> PyResult<()> {
         let s = seed.unwrap_or(self.seeds[idx]);
         self.seeds[idx] = s;
         let c = cash.unwrap_or(650_000.0);
-        self.envs[idx] = Game::new(s, num_airports, c);
+        self.envs[idx] = self.build(s, num_airports, c)?;
+        Ok(())
     }
 
     #[pyo3(signature = (hours, parallel=None))]
@@ -438,6 +658,17 @@ impl VectorGameEnv {
             .collect()
     }
 
+    /// `state_msgpack()` of every env.
+    fn state_all_msgpack<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+        self.envs
+            .iter()
+            .map(|g| {
+                let bytes = g.observation_msgpack().map_err(msgpack_err)?;
+                Ok(PyBytes::new(py, &bytes))
+            })
+            .collect()
+    }
+
     fn state_all_py(&self, py: Python) -> PyResult<Vec<PyObject>> {
         let json = py.import("json")?;
         self.envs
@@ -450,6 +681,11 @@ impl VectorGameEnv {
             .collect()
     }
 
+    /// `stats_records()` of every env.
+    fn stats_records_all(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        self.envs.iter().map(|g| stats_records_py(py, g)).collect()
+    }
+
     fn times(&self) -> Vec<u64> {
         self.envs.iter().map(|g| g.time).collect()
     }
@@ -458,6 +694,38 @@ impl VectorGameEnv {
         self.envs.iter().map(|g| g.player.cash).collect()
     }
 
+    fn company_values(&self) -> Vec<f32> {
+        self.envs.iter().map(|g| g.company_value()).collect()
+    }
+
+    fn state_hashes(&self) -> Vec<u64> {
+        self.envs.iter().map(|g| g.state_hash()).collect()
+    }
+
+    /// `features_py()` of every env.
+    fn features_all_py(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        let json = py.import("json")?;
+        self.envs
+            .iter()
+            .map(|g| {
+                let s = serde_json::to_string(&g.features())
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                json.call_method1("loads", (s,)).map(|o| o.into())
+            })
+            .collect()
+    }
+
+    /// `actions_json()` of every env.
+    fn actions_all_json(&self) -> PyResult<Vec<String>> {
+        self.envs
+            .iter()
+            .map(|g| {
+                serde_json::to_string(&g.enumerate_actions())
+                    .map_err(|e| PyValueError::new_err(e.to_string()))
+            })
+            .collect()
+    }
+
     fn drain_logs(&mut self) -> Vec<Vec<String>> {
         self.envs.iter_mut().map(|g| g.drain_log()).collect()
     }
@@ -512,11 +780,129 @@ impl VectorGameEnv {
         env.sell_plane(plane_id)
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
+
+    #[pyo3(text_signature = "(env_idx, plane_id)")]
+    fn appraise_plane(&self, env_idx: usize, plane_id: usize) -> PyResult<f32> {
+        let env = self
+            .envs
+            .get(env_idx)
+            .ok_or_else(|| PyValueError::new_err("env index out of range"))?;
+        env.appraise_plane(plane_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(text_signature = "(env_idx, listing_id)")]
+    fn buy_used_plane(&mut self, env_idx: usize, listing_id: usize) -> PyResult<usize> {
+        let env = self
+            .envs
+            .get_mut(env_idx)
+            .ok_or_else(|| PyValueError::new_err("env index out of range"))?;
+        env.buy_used_plane(listing_id)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+/// Fixed integer numbering of actions for discrete action heads; see
+/// `rusty_runways_core::actions::ActionSpace`.
+#[pyclass(name = "ActionSpace")]
+pub struct PyActionSpace {
+    space: ActionSpace,
+}
+
+#[pymethods]
+impl PyActionSpace {
+    #[new]
+    #[pyo3(signature = (max_planes=16, max_airports=64, max_orders=64, max_models=16))]
+    fn new(max_planes: usize, max_airports: usize, max_orders: usize, max_models: usize) -> Self {
+        PyActionSpace {
+            space: ActionSpace {
+                max_planes,
+                max_airports,
+                max_orders,
+                max_models,
+            },
+        }
+    }
+
+    #[classattr]
+    const NOOP: usize = ActionSpace::NOOP;
+
+    fn size(&self) -> usize {
+        self.space.size()
+    }
+
+    fn __len__(&self) -> usize {
+        self.space.size()
+    }
+
+    /// Index of an action from `actions_py()` (a dict) or `actions_json()` (one entry as
+    /// JSON), or `None` if it doesn't fit the space.
+    fn encode_action(
+        &self,
+        py: Python,
+        env: &GameEnv,
+        action: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<usize>> {
+        let text: String = match action.extract::<String>() {
+            Ok(text) => text,
+            Err(_) => py
+                .import("json")?
+                .call_method1("dumps", (action,))?
+                .extract()?,
+        };
+        let action: Action =
+            serde_json::from_str(&text).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(self.space.encode_action(&env.game, &action))
+    }
+
+    /// The command at `index`, ready for `execute`, or `None` for `NOOP` and empty slots.
+    fn decode_action(&self, env: &GameEnv, index: usize) -> Option<String> {
+        self.space
+            .decode_action(&env.game, index)
+            .map(|a| a.command_line())
+    }
+
+    /// Which indices would succeed now; `NOOP` always does.
+    fn action_mask(&self, env: &GameEnv) -> Vec<bool> {
+        self.space.action_mask(&env.game)
+    }
+
+    /// `action_mask` of every env.
+    fn action_masks(&self, envs: &VectorGameEnv) -> Vec<Vec<bool>> {
+        envs.envs
+            .iter()
+            .map(|g| self.space.action_mask(g))
+            .collect()
+    }
+}
+
+/// A world config in YAML for `from_config`, generated to a difficulty from 0.0 (easiest)
+/// to 1.0 (hardest). The per-axis arguments override `difficulty` for that axis.
+#[pyfunction]
+#[pyo3(signature = (seed, difficulty=0.5, airports=None, route_length=None, deadlines=None, cash=None))]
+fn generate_world(
+    seed: u64,
+    difficulty: f32,
+    airports: Option<f32>,
+    route_length: Option<f32>,
+    deadlines: Option<f32>,
+    cash: Option<f32>,
+) -> PyResult<String> {
+    let difficulty = Difficulty {
+        airports: airports.unwrap_or(difficulty),
+        route_length: route_length.unwrap_or(difficulty),
+        deadlines: deadlines.unwrap_or(difficulty),
+        cash: cash.unwrap_or(difficulty),
+    };
+    let config = WorldGenerator::default().generate(seed, difficulty);
+    serde_yaml::to_string(&config).map_err(|e| PyValueError::new_err(e.to_string()))
 }
 
 #[pymodule]
 fn rusty_runways_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<GameEnv>()?;
     m.add_class::<VectorGameEnv>()?;
+    m.add_class::<PyActionSpace>()?;
+    m.add_function(wrap_pyfunction!(generate_world, m)?)?;
     Ok(())
 }
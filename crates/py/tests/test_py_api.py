@@ -1,6 +1,6 @@
 import json
 
-from rusty_runways_py import GameEnv, VectorGameEnv
+from rusty_runways_py import ActionSpace, GameEnv, VectorGameEnv, generate_world
 
 
 def test_single_env_step():
@@ -9,6 +9,68 @@ def test_single_env_step():
     assert g.time() == 1
 
 
+def test_step_returns_observation():
+    g = GameEnv(seed=1)
+    obs = g.step(5)
+    assert obs["time"] == 5
+    assert obs == g.state_py()
+
+
+def test_airports_carry_their_class():
+    g = GameEnv(seed=1)
+    classes = {a["class"] for a in g.state_py()["airports"]}
+    assert classes <= {"hub", "regional", "strip"}
+
+
+WORLD = """
+seed: 7
+starting_cash: 500000.0
+airports:
+  - id: 0
+    name: AAA
+    location: {x: 1000.0, y: 1000.0}
+    runway_length_m: 3000.0
+    fuel_price_per_l: 1.0
+    landing_fee_per_ton: 5.0
+    parking_fee_per_hour: 10.0
+  - id: 1
+    name: AAB
+    location: {x: 1500.0, y: 1000.0}
+    runway_length_m: 3000.0
+    fuel_price_per_l: 1.0
+    landing_fee_per_ton: 5.0
+    parking_fee_per_hour: 10.0
+"""
+
+
+def test_from_config_accepts_yaml_or_path(tmp_path):
+    g = GameEnv.from_config(WORLD)
+    assert g.seed() == 7
+    assert g.cash() == 500000.0
+    assert g.airport_ids() == [0, 1]
+
+    path = tmp_path / "world.yaml"
+    path.write_text(WORLD)
+    assert GameEnv.from_config(str(path)).airport_ids() == [0, 1]
+
+    try:
+        GameEnv.from_config(str(tmp_path / "missing.yaml"))
+    except ValueError:
+        pass
+    else:
+        assert False
+
+
+def test_vector_from_config_reseeds_envs():
+    env = VectorGameEnv.from_config(3, WORLD, seed=100)
+    assert env.seeds() == [100, 101, 102]
+    assert env.airport_ids_all() == [[0, 1]] * 3
+    env.reset_all(seed=5)
+    assert env.seeds() == [5, 6, 7]
+    assert env.airport_ids_all() == [[0, 1]] * 3
+    assert VectorGameEnv.from_config(2, WORLD).seeds() == [7, 7]
+
+
 def test_execute_success_and_error():
     g = GameEnv(seed=1)
     g.execute("ADVANCE 1")
@@ -27,6 +89,15 @@ def test_state_json_schema():
     assert {"time", "cash", "airports", "planes"}.issubset(data.keys())
 
 
+def test_render_draws_the_map():
+    g = GameEnv(seed=1, num_airports=5)
+    text = g.render(40, 10)
+    lines = text.splitlines()
+    assert lines[0] == "+" + "-" * 40 + "+"
+    assert len(lines[1]) == 42
+    assert lines[12].startswith("Airports: 0 ")
+
+
 def test_full_state_roundtrip():
     g = GameEnv(seed=1)
     g.step(2)
@@ -37,12 +108,40 @@ def test_full_state_roundtrip():
     assert g2.cash() == g.cash()
 
 
+def test_full_state_msgpack_roundtrip():
+    g = GameEnv(seed=1)
+    g.step(2)
+    dump = g.full_state_msgpack()
+    assert isinstance(dump, bytes)
+    assert len(g.state_msgpack()) < len(g.state_json())
+    g2 = GameEnv(seed=2)
+    g2.load_full_state_msgpack(dump)
+    assert g2.time() == g.time()
+    assert g2.cash() == g.cash()
+    assert len(VectorGameEnv(2, seed=1).state_all_msgpack()) == 2
+
+
 def test_vector_env_basic():
     env = VectorGameEnv(4, seed=1)
     env.step_all(2, parallel=True)
     assert env.times() == [2, 2, 2, 2]
 
 
+def test_stats_records_are_flat_rows():
+    g = GameEnv(seed=1, num_airports=5)
+    assert g.stats_records() == []
+    g.step(48)
+    records = g.stats_records()
+    assert [r["day"] for r in records] == [1, 2]
+    for r in records:
+        assert {"income", "expenses", "fuel", "payroll", "other", "net"}.issubset(r)
+        assert abs(r["net"] - (r["income"] - r["expenses"])) < 1e-3
+
+    env = VectorGameEnv(2, seed=1)
+    env.step_all(24)
+    assert [len(rs) for rs in env.stats_records_all()] == [1, 1]
+
+
 def test_vector_env_execute_all():
     env = VectorGameEnv(2, seed=1)
     res = env.execute_all(["ADVANCE 1", "BAD"], parallel=False)
@@ -78,6 +177,29 @@ def test_determinism():
     assert env1.state_all_json() == env2.state_all_json()
 
 
+def test_state_hash_tracks_divergence():
+    g1 = GameEnv(seed=42)
+    g2 = GameEnv(seed=42)
+    g1.execute("ADVANCE 5")
+    g2.execute("ADVANCE 5")
+    assert g1.state_hash() == g2.state_hash()
+    g2.execute("ADVANCE 1")
+    assert g1.state_hash() != g2.state_hash()
+
+    env = VectorGameEnv(2, seed=3)
+    assert env.state_hashes()[0] != env.state_hashes()[1]
+
+
+def test_fork_is_independent_and_deterministic():
+    g = GameEnv(seed=4)
+    g.execute("ADVANCE 3")
+    fork = g.fork()
+    fork.execute("ADVANCE 10")
+    assert g.time() == 3
+    g.execute("ADVANCE 10")
+    assert g.state_hash() == fork.state_hash()
+
+
 def test_reset_resets_state():
     g = GameEnv(seed=1, num_airports=3, cash=500.0)
     g.step(5)
@@ -153,3 +275,35 @@ def test_vector_env_step_all_zero():
     env = VectorGameEnv(3, seed=1)
     env.step_all(0, parallel=False)
     assert env.times() == [0, 0, 0]
+
+
+def test_action_space_round_trip():
+    g = GameEnv(seed=1, num_airports=8)
+    space = ActionSpace()
+    actions = g.actions_py()
+    mask = space.action_mask(g)
+    assert len(mask) == len(space) and mask[ActionSpace.NOOP]
+    assert sum(mask) == len(actions) + 1
+    for action in actions:
+        index = space.encode_action(g, action)
+        assert mask[index]
+        g.fork().execute(space.decode_action(g, index))
+    assert space.decode_action(g, ActionSpace.NOOP) is None
+
+
+def test_features_are_scaled():
+    g = GameEnv(seed=1, num_airports=8)
+    f = g.features_py()
+    assert f["scales"]["world_size"] == 10000.0
+    assert all(0.0 <= a["x"] <= 1.0 for a in f["airports"])
+    assert all(0.0 <= o["deadline"] <= 1.0 for o in f["orders"])
+    assert len(VectorGameEnv(2, seed=1).features_all_py()) == 2
+
+
+def test_generated_worlds_harden():
+    easy = GameEnv.from_config(generate_world(3, 0.0))
+    hard = GameEnv.from_config(generate_world(3, 1.0))
+    assert len(hard.airport_ids()) > len(easy.airport_ids())
+    assert hard.cash() < easy.cash()
+    same_size = GameEnv.from_config(generate_world(3, 1.0, airports=0.0))
+    assert len(same_size.airport_ids()) == len(easy.airport_ids())
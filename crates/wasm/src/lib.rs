@@ -1,7 +1,22 @@
+use js_sys::{Function, Promise, Uint8Array};
 use once_cell::sync::OnceCell;
 use rusty_runways_core::Game;
+use rusty_runways_core::save::{self, SaveOptions};
+use rusty_runways_core::utils::airplanes::models::{CruiseRegime, FuelAmount};
 use strum::IntoEnumIterator;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Hours simulated between yields in [`advance_async`] unless the caller picks another
+/// chunk size.
+const DEFAULT_ADVANCE_CHUNK_HOURS: u64 = 24;
+
+#[wasm_bindgen]
+extern "C" {
+    // global in both windows and web workers
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, ms: i32);
+}
 
 static GAME: OnceCell<std::sync::Mutex<Game>> = OnceCell::new();
 
@@ -27,6 +42,34 @@ pub fn observe() -> Result<JsValue, JsValue> {
     with_game(|g| Ok(serde_wasm_bindgen::to_value(&g.observe()).unwrap()))
 }
 
+/// Changes since state version `version` (0 for everything); see `Game::observe_diff`.
+#[wasm_bindgen]
+pub fn observe_since(version: u64) -> Result<JsValue, JsValue> {
+    with_game(|g| Ok(serde_wasm_bindgen::to_value(&g.observe_diff(version)).unwrap()))
+}
+
+#[wasm_bindgen]
+pub fn state_version() -> Result<u64, JsValue> {
+    with_game(|g| Ok(g.state_version()))
+}
+
+#[wasm_bindgen]
+pub fn state_hash() -> Result<u64, JsValue> {
+    with_game(|g| Ok(g.state_hash()))
+}
+
+/// The state scaled for learning agents; see `Game::features`.
+#[wasm_bindgen]
+pub fn features() -> Result<JsValue, JsValue> {
+    with_game(|g| Ok(serde_wasm_bindgen::to_value(&g.features()).unwrap()))
+}
+
+/// Every command that would succeed now; see `Game::enumerate_actions`.
+#[wasm_bindgen]
+pub fn enumerate_actions() -> Result<JsValue, JsValue> {
+    with_game(|g| Ok(serde_wasm_bindgen::to_value(&g.enumerate_actions()).unwrap()))
+}
+
 #[wasm_bindgen]
 pub fn stats() -> Result<JsValue, JsValue> {
     #[derive(serde::Serialize)]
@@ -39,6 +82,11 @@ pub fn stats() -> Result<JsValue, JsValue> {
     })
 }
 
+#[wasm_bindgen]
+pub fn run_summary() -> Result<JsValue, JsValue> {
+    with_game(|g| Ok(serde_wasm_bindgen::to_value(&g.run_summary()).unwrap()))
+}
+
 #[wasm_bindgen]
 pub fn player_snapshot() -> Result<JsValue, JsValue> {
     #[derive(serde::Serialize)]
@@ -71,6 +119,109 @@ pub fn advance(hours: u64) -> Result<JsValue, JsValue> {
     })
 }
 
+/// Hand control back to the event loop, so the page can repaint or a worker can take
+/// messages.
+async fn yield_now() -> Result<(), JsValue> {
+    let tick = Promise::new(&mut |resolve, _| set_timeout(&resolve, 0));
+    JsFuture::from(tick).await.map(|_| ())
+}
+
+/// Advance `hours` like [`advance`], yielding to the event loop every `chunk_hours`
+/// (24 by default) so long runs don't freeze the page.
+///
+/// `on_progress(hours_done, hours)` is called after each chunk. Other calls may run
+/// between chunks and see the game part way through.
+#[wasm_bindgen]
+pub async fn advance_async(
+    hours: u64,
+    chunk_hours: Option<u64>,
+    on_progress: Option<Function>,
+) -> Result<JsValue, JsValue> {
+    let chunk = chunk_hours.unwrap_or(DEFAULT_ADVANCE_CHUNK_HOURS).max(1);
+    let mut done = 0;
+    while done < hours {
+        let step = chunk.min(hours - done);
+        with_game(|g| {
+            g.advance(step);
+            Ok(())
+        })?;
+        done += step;
+        if let Some(callback) = &on_progress {
+            callback.call2(
+                &JsValue::NULL,
+                &JsValue::from_f64(done as f64),
+                &JsValue::from_f64(hours as f64),
+            )?;
+        }
+        if done < hours {
+            yield_now().await?;
+        }
+    }
+    observe()
+}
+
+/// The full game state as a sealed save in a `Uint8Array`.
+///
+/// Its buffer can be transferred with `postMessage`, e.g. to move a game between a worker
+/// and the page; [`import_state`] restores it.
+#[wasm_bindgen]
+pub fn export_state() -> Result<Uint8Array, JsValue> {
+    with_game(|g| {
+        let json = serde_json::to_string(&*g).map_err(|e| e.to_string())?;
+        let sealed = save::seal(json, &SaveOptions::sealed()).map_err(|e| e.to_string())?;
+        Ok(Uint8Array::from(sealed.as_bytes()))
+    })
+}
+
+/// Replace the game with a state from [`export_state`], creating it if needed.
+#[wasm_bindgen]
+pub fn import_state(blob: &[u8]) -> Result<(), JsValue> {
+    let contents =
+        String::from_utf8(blob.to_vec()).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let json = save::open(contents, None).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mut game: Game =
+        serde_json::from_str(&json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    game.reset_runtime();
+    replace_game(game)
+}
+
+/// The observation as MessagePack; smaller and faster to decode than [`observe`] on
+/// large worlds.
+#[wasm_bindgen]
+pub fn observe_msgpack() -> Result<Uint8Array, JsValue> {
+    with_game(|g| {
+        let bytes = g.observation_msgpack().map_err(|e| e.to_string())?;
+        Ok(Uint8Array::from(bytes.as_slice()))
+    })
+}
+
+/// The full game state as MessagePack, for [`load_state_msgpack`].
+#[wasm_bindgen]
+pub fn state_msgpack() -> Result<Uint8Array, JsValue> {
+    with_game(|g| {
+        let bytes = g.state_msgpack().map_err(|e| e.to_string())?;
+        Ok(Uint8Array::from(bytes.as_slice()))
+    })
+}
+
+/// Replace the game with a state from [`state_msgpack`], creating it if needed.
+#[wasm_bindgen]
+pub fn load_state_msgpack(bytes: &[u8]) -> Result<(), JsValue> {
+    let game = Game::from_state_msgpack(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    replace_game(game)
+}
+
+fn replace_game(game: Game) -> Result<(), JsValue> {
+    if let Err(game) = GAME.set(std::sync::Mutex::new(game)) {
+        let game = game.into_inner().unwrap_or_else(|e| e.into_inner());
+        with_game(|g| {
+            *g = game;
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
 #[wasm_bindgen]
 pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
     with_game(|g| {
@@ -83,13 +234,17 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
         let current_airport_id = g
             .airports()
             .iter()
-            .position(|(_, coord)| *coord == plane.location);
+            .find(|(_, coord)| *coord == plane.location)
+            .map(|(airport, _)| airport.id);
         #[derive(serde::Serialize)]
         struct OrderDto {
             id: usize,
             destination_id: usize,
             value: f32,
             deadline: u64,
+            tier: String,
+            damage: f32,
+            rare: Option<String>,
             payload_kind: String,
             cargo_type: Option<String>,
             weight: Option<f32>,
@@ -98,8 +253,11 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
         #[derive(serde::Serialize)]
         struct PlaneInfoDto {
             id: usize,
+            name: String,
             model: String,
             status: String,
+            on_hold: bool,
+            awaiting_repair: bool,
             x: f32,
             y: f32,
             fuel_current: f32,
@@ -108,6 +266,9 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
             payload_capacity: f32,
             passenger_current: u32,
             passenger_capacity: u32,
+            takeoff_weight: f32,
+            mtow: f32,
+            resale_value: f32,
             current_airport_id: Option<usize>,
             manifest: Vec<OrderDto>,
         }
@@ -119,6 +280,9 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
                 destination_id: o.destination_id,
                 value: o.value,
                 deadline: o.deadline,
+                tier: o.tier.to_string(),
+                damage: o.damage,
+                rare: o.rare.map(|kind| kind.to_string()),
                 payload_kind: o.payload.kind_label().to_string(),
                 cargo_type: o.cargo_type().map(|c| format!("{:?}", c)),
                 weight: o.cargo_weight(),
@@ -127,6 +291,9 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
             .collect();
         let dto = PlaneInfoDto {
             id: plane.id,
+            name: plane.name.clone(),
+            on_hold: plane.on_hold,
+            awaiting_repair: plane.awaiting_repair,
             model: format!("{:?}", plane.model),
             status: format!("{:?}", plane.status),
             x: plane.location.x,
@@ -135,8 +302,11 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
             fuel_capacity: plane.specs.fuel_capacity,
             payload_current: plane.current_payload,
             payload_capacity: plane.specs.payload_capacity,
-            passenger_current: plane.current_passengers,
+            passenger_current: plane.seated_passengers(),
             passenger_capacity: plane.specs.passenger_capacity,
+            takeoff_weight: plane.takeoff_weight(),
+            mtow: plane.specs.mtow,
+            resale_value: g.appraise_plane(plane.id).map_err(|e| e.to_string())?,
             current_airport_id,
             manifest,
         };
@@ -148,9 +318,9 @@ pub fn plane_info(plane_id: usize) -> Result<JsValue, JsValue> {
 pub fn airport_orders(airport_id: usize) -> Result<JsValue, JsValue> {
     with_game(|g| {
         let (airport, _) = g
-            .airports()
-            .iter()
-            .find(|(a, _)| a.id == airport_id)
+            .map
+            .airports
+            .get(airport_id)
             .ok_or_else(|| "airport not found".to_string())?;
         #[derive(serde::Serialize)]
         struct OrderDto {
@@ -158,6 +328,9 @@ pub fn airport_orders(airport_id: usize) -> Result<JsValue, JsValue> {
             destination_id: usize,
             value: f32,
             deadline: u64,
+            tier: String,
+            damage: f32,
+            rare: Option<String>,
             payload_kind: String,
             cargo_type: Option<String>,
             weight: Option<f32>,
@@ -171,6 +344,9 @@ pub fn airport_orders(airport_id: usize) -> Result<JsValue, JsValue> {
                 destination_id: o.destination_id,
                 value: o.value,
                 deadline: o.deadline,
+                tier: o.tier.to_string(),
+                damage: o.damage,
+                rare: o.rare.map(|kind| kind.to_string()),
                 payload_kind: o.payload.kind_label().to_string(),
                 cargo_type: o.cargo_type().map(|c| format!("{:?}", c)),
                 weight: o.cargo_weight(),
@@ -182,17 +358,82 @@ pub fn airport_orders(airport_id: usize) -> Result<JsValue, JsValue> {
 }
 
 #[wasm_bindgen]
-pub fn depart_plane(plane: usize, dest: usize) -> Result<(), JsValue> {
+pub fn depart_plane(plane: usize, dest: usize, speed: Option<String>) -> Result<(), JsValue> {
     with_game(|g| {
-        g.depart_plane(plane, dest)
+        let regime = match speed {
+            Some(s) => s.parse::<CruiseRegime>()?,
+            None => CruiseRegime::Normal,
+        };
+        g.depart_plane_with_speed(plane, dest, regime)
             .map_err(|e| e.to_string())
             .map(|_| ())
     })
 }
 
 #[wasm_bindgen]
-pub fn refuel_plane(plane: usize) -> Result<(), JsValue> {
-    with_game(|g| g.refuel_plane(plane).map_err(|e| e.to_string()).map(|_| ()))
+pub fn reroute_plane(plane: usize, dest: usize) -> Result<(), JsValue> {
+    with_game(|g| g.reroute_plane(plane, dest).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn emergency_land(plane: usize) -> Result<usize, JsValue> {
+    with_game(|g| g.emergency_land(plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn repair_plane(plane: usize) -> Result<(), JsValue> {
+    with_game(|g| g.repair_plane(plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn buy_spare_parts(airport: usize, kits: u32) -> Result<(), JsValue> {
+    with_game(|g| g.buy_spare_parts(airport, kits).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn ship_spare_parts(kits: u32, dest: usize, plane: usize) -> Result<usize, JsValue> {
+    with_game(|g| {
+        g.ship_spare_parts(kits, dest, plane)
+            .map_err(|e| e.to_string())
+    })
+}
+
+#[wasm_bindgen]
+pub fn hire_pilot(airport: usize) -> Result<usize, JsValue> {
+    with_game(|g| g.hire_pilot(airport).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn fire_pilot(pilot: usize) -> Result<(), JsValue> {
+    with_game(|g| g.fire_pilot(pilot).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn assign_pilot(pilot: usize, plane: usize) -> Result<(), JsValue> {
+    with_game(|g| g.assign_pilot(pilot, plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn hold_plane(plane: usize) -> Result<(), JsValue> {
+    with_game(|g| g.hold_plane(plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn release_plane(plane: usize) -> Result<(), JsValue> {
+    with_game(|g| g.release_plane(plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn refuel_plane(plane: usize, amount: Option<String>) -> Result<(), JsValue> {
+    with_game(|g| {
+        let amount = match amount {
+            Some(s) => s.parse::<FuelAmount>()?,
+            None => FuelAmount::Full,
+        };
+        g.refuel_plane_with(plane, amount)
+            .map_err(|e| e.to_string())
+            .map(|_| ())
+    })
 }
 
 #[wasm_bindgen]
@@ -200,6 +441,21 @@ pub fn sell_plane(plane: usize) -> Result<f32, JsValue> {
     with_game(|g| g.sell_plane(plane).map_err(|e| e.to_string()))
 }
 
+#[wasm_bindgen]
+pub fn appraise_plane(plane: usize) -> Result<f32, JsValue> {
+    with_game(|g| g.appraise_plane(plane).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn rename_plane(plane: usize, name: String) -> Result<(), JsValue> {
+    with_game(|g| g.rename_plane(plane, &name).map_err(|e| e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn buy_used_plane(listing: usize) -> Result<usize, JsValue> {
+    with_game(|g| g.buy_used_plane(listing).map_err(|e| e.to_string()))
+}
+
 #[wasm_bindgen]
 pub fn maintenance(plane: usize) -> Result<(), JsValue> {
     with_game(|g| {
@@ -257,6 +513,7 @@ pub fn list_models() -> Result<JsValue, JsValue> {
         purchase_price: f32,
         min_runway_length: f32,
         role: String,
+        power: String,
     }
     let models: Vec<ModelDto> = AirplaneModel::iter()
         .map(|m| {
@@ -273,6 +530,7 @@ pub fn list_models() -> Result<JsValue, JsValue> {
                 purchase_price: s.purchase_price,
                 min_runway_length: s.min_runway_length,
                 role: format!("{:?}", s.role),
+                power: format!("{:?}", s.power),
             }
         })
         .collect();
@@ -297,9 +555,9 @@ pub fn plane_can_fly_to(plane_id: usize, dest_id: usize) -> Result<bool, JsValue
             .find(|p| p.id == plane_id)
             .ok_or_else(|| "plane not found".to_string())?;
         let (airport, coord) = g
-            .airports()
-            .iter()
-            .find(|(a, _)| a.id == dest_id)
+            .map
+            .airports
+            .get(dest_id)
             .ok_or_else(|| "airport not found".to_string())?;
         Ok(plane.can_fly_to(airport, coord).is_ok())
     })
@@ -319,9 +577,9 @@ pub fn plane_reachability(plane_id: usize, dest_id: usize) -> Result<JsValue, Js
             .find(|p| p.id == plane_id)
             .ok_or_else(|| "plane not found".to_string())?;
         let (airport, coord) = g
-            .airports()
-            .iter()
-            .find(|(a, _)| a.id == dest_id)
+            .map
+            .airports
+            .get(dest_id)
             .ok_or_else(|| "airport not found".to_string())?;
         let dto = match plane.can_fly_to(airport, coord) {
             Ok(_) => FeasibilityDto {
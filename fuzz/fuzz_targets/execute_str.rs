@@ -0,0 +1,35 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rusty_runways_commands::{Command, parse_command};
+use rusty_runways_core::Game;
+
+/// Longest `ADVANCE` a single input may ask for, so inputs stay fast
+const MAX_ADVANCE_HOURS: u64 = 72;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let mut game = Game::new(1, Some(4), 650_000.0);
+
+    // one command per line, all against the same game
+    for line in text.lines().take(32) {
+        match parse_command(line) {
+            // touch the filesystem or leave the game
+            Ok(
+                Command::SaveGame { .. }
+                | Command::LoadGame { .. }
+                | Command::LoadConfig { .. }
+                | Command::ExportConfig { .. }
+                | Command::ExportRun { .. }
+                | Command::Exit,
+            ) => continue,
+            Ok(Command::Advance { hours }) if hours > MAX_ADVANCE_HOURS => continue,
+            _ => {}
+        }
+        if let Err(e) = game.execute_str(line) {
+            assert!(!e.to_string().is_empty());
+        }
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rusty_runways_commands::parse_command;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(line) = std::str::from_utf8(data) else {
+        return;
+    };
+    // any input either parses or is rejected with a message, never a panic
+    if let Err(e) = parse_command(line) {
+        assert!(!e.to_string().is_empty());
+    }
+});